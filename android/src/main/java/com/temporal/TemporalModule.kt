@@ -1,5 +1,10 @@
 package com.temporal
 
+import android.content.BroadcastReceiver
+import android.content.Context
+import android.content.Intent
+import android.content.IntentFilter
+import android.os.Build
 import com.facebook.react.bridge.ReactApplicationContext
 import com.facebook.react.bridge.WritableArray
 import com.facebook.react.bridge.WritableNativeArray
@@ -19,6 +24,26 @@ class TemporalTypeError(message: String) : Exception(message)
 class TemporalModule(reactContext: ReactApplicationContext) :
   NativeTemporalSpec(reactContext) {
 
+  private val timeZoneChangedReceiver = object : BroadcastReceiver() {
+    override fun onReceive(context: Context, intent: Intent) {
+      TemporalNative.notifyTimeZoneChanged()
+    }
+  }
+
+  init {
+    val filter = IntentFilter(Intent.ACTION_TIMEZONE_CHANGED)
+    if (Build.VERSION.SDK_INT >= Build.VERSION_CODES.TIRAMISU) {
+      reactContext.registerReceiver(timeZoneChangedReceiver, filter, Context.RECEIVER_NOT_EXPORTED)
+    } else {
+      reactContext.registerReceiver(timeZoneChangedReceiver, filter)
+    }
+  }
+
+  override fun invalidate() {
+    reactApplicationContext.unregisterReceiver(timeZoneChangedReceiver)
+    super.invalidate()
+  }
+
   override fun getName(): String {
     return NAME
   }
@@ -180,12 +205,7 @@ class TemporalModule(reactContext: ReactApplicationContext) :
   }
 
   override fun plainDateGetAllComponents(s: String): WritableArray {
-    val components = TemporalNative.plainDateGetAllComponents(s)
-    val result = WritableNativeArray()
-    for (value in components) {
-      result.pushDouble(value.toDouble())
-    }
-    return result
+    return componentsWithWeekInfo(TemporalNative.plainDateGetAllComponents(s))
   }
 
   override fun plainDateGetMonthCode(s: String): String {
@@ -247,12 +267,7 @@ class TemporalModule(reactContext: ReactApplicationContext) :
   }
 
   override fun plainDateTimeGetAllComponents(s: String): WritableArray {
-    val components = TemporalNative.plainDateTimeGetAllComponents(s)
-    val result = WritableNativeArray()
-    for (value in components) {
-      result.pushDouble(value.toDouble())
-    }
-    return result
+    return componentsWithWeekInfo(TemporalNative.plainDateTimeGetAllComponents(s))
   }
 
   override fun plainDateTimeGetMonthCode(s: String): String {
@@ -326,12 +341,12 @@ class TemporalModule(reactContext: ReactApplicationContext) :
     return TemporalNative.plainYearMonthGetCalendar(s)
   }
 
-  override fun plainYearMonthAdd(ym: String, duration: String): String {
-    return TemporalNative.plainYearMonthAdd(ym, duration)
+  override fun plainYearMonthAdd(ym: String, duration: String, overflow: String?): String {
+    return TemporalNative.plainYearMonthAdd(ym, duration, overflow)
   }
 
-  override fun plainYearMonthSubtract(ym: String, duration: String): String {
-    return TemporalNative.plainYearMonthSubtract(ym, duration)
+  override fun plainYearMonthSubtract(ym: String, duration: String, overflow: String?): String {
+    return TemporalNative.plainYearMonthSubtract(ym, duration, overflow)
   }
 
   override fun plainYearMonthCompare(a: String, b: String): Double {
@@ -360,8 +375,8 @@ class TemporalModule(reactContext: ReactApplicationContext) :
     return TemporalNative.plainMonthDayFromString(s)
   }
 
-  override fun plainMonthDayFromComponents(month: Double, day: Double, calendarId: String?, referenceYear: Double): String {
-    return TemporalNative.plainMonthDayFromComponents(month.toInt(), day.toInt(), calendarId, referenceYear.toInt())
+  override fun plainMonthDayFromComponents(month: Double, day: Double, calendarId: String?, referenceYear: Double, overflow: String?): String {
+    return TemporalNative.plainMonthDayFromComponents(month.toInt(), day.toInt(), calendarId, referenceYear.toInt(), overflow)
   }
 
   override fun plainMonthDayGetAllComponents(s: String): WritableArray {
@@ -440,12 +455,7 @@ class TemporalModule(reactContext: ReactApplicationContext) :
   }
 
   override fun zonedDateTimeGetAllComponents(s: String): WritableArray {
-    val components = TemporalNative.zonedDateTimeGetAllComponents(s)
-    val result = WritableNativeArray()
-    for (value in components) {
-      result.pushDouble(value.toDouble())
-    }
-    return result
+    return componentsWithWeekInfo(TemporalNative.zonedDateTimeGetAllComponents(s))
   }
 
   override fun zonedDateTimeEpochMilliseconds(s: String): Double {
@@ -469,8 +479,20 @@ class TemporalModule(reactContext: ReactApplicationContext) :
     return TemporalNative.zonedDateTimeGetOffset(s)
   }
 
-  override fun zonedDateTimeAdd(zdt: String, duration: String): String {
-    return TemporalNative.zonedDateTimeAdd(zdt, duration)
+  override fun zonedDateTimeGetMonthCode(s: String): String {
+    return TemporalNative.zonedDateTimeGetMonthCode(s)
+  }
+
+  override fun zonedDateTimeGetEra(s: String): String {
+    return TemporalNative.zonedDateTimeGetEra(s)
+  }
+
+  override fun zonedDateTimeGetEraYear(s: String): String {
+    return TemporalNative.zonedDateTimeGetEraYear(s)
+  }
+
+  override fun zonedDateTimeAdd(zdt: String, duration: String, arithmetic: String?): String {
+    return TemporalNative.zonedDateTimeAdd(zdt, duration, arithmetic)
   }
 
   override fun zonedDateTimeSubtract(zdt: String, duration: String): String {
@@ -499,12 +521,12 @@ class TemporalModule(reactContext: ReactApplicationContext) :
     )
   }
 
-  override fun zonedDateTimeUntil(one: String, two: String): String {
-    return TemporalNative.zonedDateTimeUntil(one, two)
+  override fun zonedDateTimeUntil(one: String, two: String, largestUnit: String?, smallestUnit: String?, roundingIncrement: Double, roundingMode: String?): String {
+    return TemporalNative.zonedDateTimeUntil(one, two, largestUnit, smallestUnit, roundingIncrement.toLong(), roundingMode)
   }
 
-  override fun zonedDateTimeSince(one: String, two: String): String {
-    return TemporalNative.zonedDateTimeSince(one, two)
+  override fun zonedDateTimeSince(one: String, two: String, largestUnit: String?, smallestUnit: String?, roundingIncrement: Double, roundingMode: String?): String {
+    return TemporalNative.zonedDateTimeSince(one, two, largestUnit, smallestUnit, roundingIncrement.toLong(), roundingMode)
   }
 
   override fun zonedDateTimeRound(zdt: String, smallestUnit: String, roundingIncrement: Double, roundingMode: String?): String {
@@ -572,9 +594,18 @@ class TemporalModule(reactContext: ReactApplicationContext) :
   override fun durationGetAllComponents(durationStr: String): WritableArray {
     val components = TemporalNative.durationGetAllComponents(durationStr)
     val result = WritableNativeArray()
-    for (value in components) {
-      result.pushDouble(value.toDouble())
+    for (index in 0 until 12) {
+      result.pushDouble(components[index].toDouble())
     }
+    // The unsigned low halves of the 128-bit microseconds/nanoseconds don't fit in a
+    // double, so each one goes over as two 32-bit words that Duration.ts reassembles.
+    for (index in intArrayOf(12, 14)) {
+      val low = components[index + 1]
+      result.pushDouble(components[index].toDouble())
+      result.pushDouble((low ushr 32).toDouble())
+      result.pushDouble((low and 0xFFFFFFFFL).toDouble())
+    }
+    result.pushDouble(components[16].toDouble())
     return result
   }
 
@@ -626,6 +657,28 @@ class TemporalModule(reactContext: ReactApplicationContext) :
     )
   }
 
+  /**
+   * Converts a date component array to JS, dropping the trailing week validity
+   * mask and reporting missing week numbers as null.
+   */
+  private fun componentsWithWeekInfo(components: LongArray): WritableArray {
+    val mask = components.last()
+    val result = WritableNativeArray()
+    for (index in 0 until components.size - 1) {
+      val valid = when (index) {
+        TemporalNative.WEEK_OF_YEAR_INDEX -> (mask and TemporalNative.WEEK_OF_YEAR_VALID) != 0L
+        TemporalNative.YEAR_OF_WEEK_INDEX -> (mask and TemporalNative.YEAR_OF_WEEK_VALID) != 0L
+        else -> true
+      }
+      if (valid) {
+        result.pushDouble(components[index].toDouble())
+      } else {
+        result.pushNull()
+      }
+    }
+    return result
+  }
+
   companion object {
     const val NAME = "Temporal"
   }
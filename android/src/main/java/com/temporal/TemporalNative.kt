@@ -1,13 +1,101 @@
 package com.temporal
 
+import org.json.JSONObject
+
 /**
  * JNI bridge to the temporal_rn Rust library.
  */
 object TemporalNative {
+    /**
+     * FFI version these bindings were written against; must match the loaded
+     * library's `temporal_ffi_api_version()`.
+     */
+    const val FFI_API_VERSION = 8
+
     init {
         System.loadLibrary("temporal_rn")
+        val nativeVersion = ffiApiVersion()
+        check(nativeVersion == FFI_API_VERSION) {
+            "temporal_rn native library has FFI version $nativeVersion but these bindings expect " +
+                "$FFI_API_VERSION; rebuild the native library"
+        }
+        checkArraySchema(JSONObject(schemaJson()))
     }
 
+    /**
+     * Returns the FFI version of the loaded native library.
+     */
+    external fun ffiApiVersion(): Int
+
+    /**
+     * Index of the weekOfYear/yearOfWeek slots in the PlainDate, PlainDateTime
+     * and ZonedDateTime component arrays.
+     */
+    const val WEEK_OF_YEAR_INDEX = 5
+    const val YEAR_OF_WEEK_INDEX = 6
+
+    /**
+     * Bits of the validity mask stored as the last element of those arrays.
+     * When a bit is clear the calendar does not define week numbering and the
+     * matching slot holds 0.
+     */
+    const val WEEK_OF_YEAR_VALID: Long = 1L
+    const val YEAR_OF_WEEK_VALID: Long = 2L
+
+    /**
+     * Returns the index layout of the LongArray results as JSON: the slot names of
+     * each array under "arrays" and the weekMask bits under "weekMask".
+     */
+    external fun schemaJson(): String
+
+    /**
+     * Fails at load time if the native library lays out the arrays differently from
+     * the indices read here and in [TemporalParcels].
+     */
+    private fun checkArraySchema(schema: JSONObject) {
+        val arrays = schema.getJSONObject("arrays")
+        for (name in listOf("plainDate", "plainDateTime", "zonedDateTime")) {
+            val slots = arrays.getJSONArray(name)
+            check(
+                slots.getString(WEEK_OF_YEAR_INDEX) == "weekOfYear" &&
+                    slots.getString(YEAR_OF_WEEK_INDEX) == "yearOfWeek" &&
+                    slots.getString(slots.length() - 1) == "weekMask"
+            ) { "temporal_rn native library lays out $name as $slots; rebuild the native library" }
+        }
+        val weekMask = schema.getJSONObject("weekMask")
+        check(weekMask.getLong("weekOfYear") == WEEK_OF_YEAR_VALID && weekMask.getLong("yearOfWeek") == YEAR_OF_WEEK_VALID) {
+            "temporal_rn native library uses weekMask bits $weekMask; rebuild the native library"
+        }
+        val parts = arrays.getJSONArray("epochNanosecondsParts")
+        check(parts.length() == 2 && parts.getString(0) == "high" && parts.getString(1) == "low") {
+            "temporal_rn native library lays out epochNanosecondsParts as $parts; rebuild the native library"
+        }
+    }
+
+    /**
+     * Returns the time zone generation, bumped on every device time zone change.
+     */
+    external fun timeZoneGeneration(): Long
+
+    /**
+     * Returns the native counters as JSON: per-kind parse calls and cache counters
+     * under "parse", error counts by type under "errors", and "timeZoneChanges".
+     */
+    external fun metricsSnapshot(): String
+
+    /**
+     * Records a device time zone change and returns the new generation.
+     */
+    external fun notifyTimeZoneChanged(): Long
+
+    /**
+     * Builds the time zone database and loads the listed zones (newline-delimited, null
+     * for none) plus the device zone. Call from a background thread at app start.
+     * Returns the number of listed zones loaded. Throws TemporalRangeError for an invalid id.
+     */
+    @Throws(TemporalRangeError::class, TemporalTypeError::class)
+    external fun warmup(tzIds: String?): Int
+
     /**
      * Returns the current instant as an ISO 8601 string.
      * Example: "2024-01-15T10:30:45.123456789Z"
@@ -16,6 +104,17 @@ object TemporalNative {
     @Throws(TemporalRangeError::class)
     external fun instantNow(): String
 
+    /**
+     * Returns the current time as epoch milliseconds, without formatting an
+     * instant string. Use for cheap timestamps.
+     */
+    external fun nowEpochMilliseconds(): Long
+
+    /**
+     * Returns the current time as epoch nanoseconds in a decimal string.
+     */
+    external fun nowEpochNanosecondsString(): String
+
     @Throws(TemporalRangeError::class, TemporalTypeError::class)
     external fun instantFromString(s: String): String
 
@@ -31,6 +130,19 @@ object TemporalNative {
     @Throws(TemporalRangeError::class, TemporalTypeError::class)
     external fun instantEpochNanoseconds(s: String): String
 
+    /**
+     * Epoch nanoseconds without a decimal string: returns [high, low], the value
+     * being high * 2^64 + low with low read as unsigned.
+     */
+    @Throws(TemporalRangeError::class, TemporalTypeError::class)
+    external fun instantEpochNanosecondsParts(s: String): LongArray
+
+    /**
+     * Creates an Instant from epoch nanoseconds given as high * 2^64 + low (low unsigned).
+     */
+    @Throws(TemporalRangeError::class)
+    external fun instantFromEpochNanosecondsParts(high: Long, low: Long): String
+
     @Throws(TemporalRangeError::class, TemporalTypeError::class)
     external fun instantAdd(instant: String, duration: String): String
 
@@ -115,6 +227,17 @@ object TemporalNative {
     @Throws(TemporalRangeError::class, TemporalTypeError::class)
     external fun plainDateFromComponents(year: Int, month: Int, day: Int, calendarId: String?): String
 
+    /**
+     * Year, month and day as `year shl 16 or month shl 8 or day`, in the date's calendar.
+     * Packed dates of one calendar sort like the dates.
+     */
+    @Throws(TemporalRangeError::class, TemporalTypeError::class)
+    external fun plainDatePack(s: String): Long
+
+    /** Inverse of [plainDatePack]; [calendarId] (null = iso8601) must be the packing calendar. */
+    @Throws(TemporalRangeError::class, TemporalTypeError::class)
+    external fun plainDateUnpack(packed: Long, calendarId: String?): String
+
     @Throws(TemporalRangeError::class, TemporalTypeError::class)
     external fun plainDateGetAllComponents(s: String): LongArray
 
@@ -130,6 +253,10 @@ object TemporalNative {
     @Throws(TemporalRangeError::class, TemporalTypeError::class)
     external fun plainDateSubtract(date: String, duration: String): String
 
+    /** Adds [months], clamping the day to a shorter month (Jan 31 + 1 month = Feb 28/29). */
+    @Throws(TemporalRangeError::class, TemporalTypeError::class)
+    external fun plainDateAddMonthsConstrained(date: String, months: Long): String
+
     @Throws(TemporalRangeError::class, TemporalTypeError::class)
     external fun plainDateCompare(a: String, b: String): Int
 
@@ -208,10 +335,22 @@ object TemporalNative {
     external fun plainYearMonthGetCalendar(s: String): String
 
     @Throws(TemporalRangeError::class, TemporalTypeError::class)
-    external fun plainYearMonthAdd(ym: String, duration: String): String
+    external fun plainYearMonthAdd(ym: String, duration: String, overflow: String?): String
 
     @Throws(TemporalRangeError::class, TemporalTypeError::class)
-    external fun plainYearMonthSubtract(ym: String, duration: String): String
+    external fun plainYearMonthSubtract(ym: String, duration: String, overflow: String?): String
+
+    /**
+     * Adds [duration] from day [referenceDay] of the month (0: the first day for a
+     * non-negative duration, the last for a negative one; larger days are constrained
+     * to the month), so weeks and days may be added as well.
+     */
+    @Throws(TemporalRangeError::class, TemporalTypeError::class)
+    external fun plainYearMonthAddWithReferenceDay(ym: String, duration: String, overflow: String?, referenceDay: Int): String
+
+    /** As [plainYearMonthAddWithReferenceDay], subtracting [duration]. */
+    @Throws(TemporalRangeError::class, TemporalTypeError::class)
+    external fun plainYearMonthSubtractWithReferenceDay(ym: String, duration: String, overflow: String?, referenceDay: Int): String
 
     @Throws(TemporalRangeError::class, TemporalTypeError::class)
     external fun plainYearMonthCompare(a: String, b: String): Int
@@ -234,8 +373,11 @@ object TemporalNative {
     @Throws(TemporalRangeError::class, TemporalTypeError::class)
     external fun plainMonthDayFromString(s: String): String
 
+    /**
+     * Pass Int.MIN_VALUE as referenceYear when there is none; 0 is the ISO year 0.
+     */
     @Throws(TemporalRangeError::class, TemporalTypeError::class)
-    external fun plainMonthDayFromComponents(month: Int, day: Int, calendarId: String?, referenceYear: Int): String
+    external fun plainMonthDayFromComponents(month: Int, day: Int, calendarId: String?, referenceYear: Int, overflow: String?): String
 
     @Throws(TemporalRangeError::class, TemporalTypeError::class)
     external fun plainMonthDayGetAllComponents(s: String): LongArray
@@ -270,6 +412,13 @@ object TemporalNative {
     @Throws(TemporalRangeError::class, TemporalTypeError::class)
     external fun timeZoneGetInstantFor(tzId: String, dtStr: String, disambiguation: String?): String
 
+    /**
+     * Compares [dateTime] interpreted in [timeZoneId] with [instant]: -1, 0 or 1 as the
+     * local time is before, at or after it. A null [disambiguation] means "compatible".
+     */
+    @Throws(TemporalRangeError::class, TemporalTypeError::class)
+    external fun compareDateTimeInZoneToInstant(dateTime: String, timeZoneId: String, disambiguation: String?, instant: String): Int
+
     @Throws(TemporalRangeError::class, TemporalTypeError::class)
     external fun timeZoneGetNextTransition(tzId: String, instantStr: String): String?
 
@@ -293,12 +442,38 @@ object TemporalNative {
     @Throws(TemporalRangeError::class, TemporalTypeError::class)
     external fun zonedDateTimeGetAllComponents(s: String): LongArray
 
+    /**
+     * Returns [components, timeZoneId, calendarId] in one call: the LongArray from
+     * zonedDateTimeGetAllComponents followed by the two identifier strings.
+     */
+    @Throws(TemporalRangeError::class, TemporalTypeError::class)
+    external fun zonedDateTimeGetFullComponents(s: String): Array<Any>
+
     @Throws(TemporalRangeError::class, TemporalTypeError::class)
     external fun zonedDateTimeEpochMilliseconds(s: String): String
 
     @Throws(TemporalRangeError::class, TemporalTypeError::class)
     external fun zonedDateTimeEpochNanoseconds(s: String): String
 
+    /**
+     * Returns [high, low] epoch nanoseconds, as instantEpochNanosecondsParts.
+     */
+    @Throws(TemporalRangeError::class, TemporalTypeError::class)
+    external fun zonedDateTimeEpochNanosecondsParts(s: String): LongArray
+
+    /**
+     * Creates a ZonedDateTime from high/low epoch nanoseconds. A null calendarId is iso8601.
+     */
+    @Throws(TemporalRangeError::class, TemporalTypeError::class)
+    external fun zonedDateTimeFromEpochNanosecondsParts(high: Long, low: Long, timeZoneId: String, calendarId: String?): String
+
+    /**
+     * Returns [[high, low], timeZoneId, calendarId] in one call: everything
+     * zonedDateTimeFromEpochNanosecondsParts needs to rebuild the value.
+     */
+    @Throws(TemporalRangeError::class, TemporalTypeError::class)
+    external fun zonedDateTimeToEpochParts(s: String): Array<Any>
+
     @Throws(TemporalRangeError::class, TemporalTypeError::class)
     external fun zonedDateTimeGetCalendar(s: String): String
 
@@ -309,7 +484,18 @@ object TemporalNative {
     external fun zonedDateTimeGetOffset(s: String): String
 
     @Throws(TemporalRangeError::class, TemporalTypeError::class)
-    external fun zonedDateTimeAdd(zdt: String, duration: String): String
+    external fun zonedDateTimeGetMonthCode(s: String): String
+
+    /** Empty when the calendar has no eras. */
+    @Throws(TemporalRangeError::class, TemporalTypeError::class)
+    external fun zonedDateTimeGetEra(s: String): String
+
+    /** Decimal era year, or empty when the calendar has no eras. */
+    @Throws(TemporalRangeError::class, TemporalTypeError::class)
+    external fun zonedDateTimeGetEraYear(s: String): String
+
+    @Throws(TemporalRangeError::class, TemporalTypeError::class)
+    external fun zonedDateTimeAdd(zdt: String, duration: String, arithmetic: String?): String
 
     @Throws(TemporalRangeError::class, TemporalTypeError::class)
     external fun zonedDateTimeSubtract(zdt: String, duration: String): String
@@ -328,10 +514,10 @@ object TemporalNative {
     ): String
 
     @Throws(TemporalRangeError::class, TemporalTypeError::class)
-    external fun zonedDateTimeUntil(one: String, two: String): String
+    external fun zonedDateTimeUntil(one: String, two: String, largestUnit: String?, smallestUnit: String?, roundingIncrement: Long, roundingMode: String?): String
 
     @Throws(TemporalRangeError::class, TemporalTypeError::class)
-    external fun zonedDateTimeSince(one: String, two: String): String
+    external fun zonedDateTimeSince(one: String, two: String, largestUnit: String?, smallestUnit: String?, roundingIncrement: Long, roundingMode: String?): String
 
     @Throws(TemporalRangeError::class, TemporalTypeError::class)
     external fun zonedDateTimeRound(zdtStr: String, smallestUnit: String, roundingIncrement: Long, roundingMode: String?): String
@@ -391,7 +577,11 @@ object TemporalNative {
     /**
      * Gets all component values from a duration string in a single call.
      * Returns a long array: [years, months, weeks, days, hours, minutes, seconds,
-     *                        milliseconds, microseconds, nanoseconds, sign, blank]
+     *                        milliseconds, microseconds, nanoseconds, sign, blank,
+     *                        microsecondsHigh, microsecondsLow, nanosecondsHigh,
+     *                        nanosecondsLow, clamped]
+     * microseconds/nanoseconds are clamped to the Long range (clamped = 1 when that
+     * happened); the full values are high * 2^64 + low, with low read as unsigned.
      * Throws TemporalRangeError for invalid duration, TemporalTypeError for null input.
      */
     @Throws(TemporalRangeError::class, TemporalTypeError::class)
@@ -452,4 +642,42 @@ object TemporalNative {
         microseconds: Long,
         nanoseconds: Long
     ): String
+
+    // ========== Non-throwing variants ==========
+    // Hot getters in an OrNull form that never throws: on error they return null (0 for
+    // offsets) and record the error, readable via lastErrorType()/lastErrorMessage().
+    // The last error is cleared at the start of every OrNull call.
+
+    /** Error type of the last failed OrNull call on this thread: 0 none, 1 RangeError, 2 TypeError. */
+    external fun lastErrorType(): Int
+
+    /** Message of the last failed OrNull call on this thread, or null if it succeeded. */
+    external fun lastErrorMessage(): String?
+
+    external fun instantEpochMillisecondsOrNull(s: String?): String?
+
+    external fun instantEpochNanosecondsOrNull(s: String?): String?
+
+    external fun plainTimeGetAllComponentsOrNull(s: String?): LongArray?
+
+    external fun plainDateGetAllComponentsOrNull(s: String?): LongArray?
+
+    external fun plainDateTimeGetAllComponentsOrNull(s: String?): LongArray?
+
+    external fun plainYearMonthGetAllComponentsOrNull(s: String?): LongArray?
+
+    external fun plainMonthDayGetAllComponentsOrNull(s: String?): LongArray?
+
+    external fun durationGetAllComponentsOrNull(durationStr: String?): LongArray?
+
+    external fun zonedDateTimeGetAllComponentsOrNull(s: String?): LongArray?
+
+    external fun zonedDateTimeGetFullComponentsOrNull(s: String?): Array<Any>?
+
+    external fun zonedDateTimeEpochMillisecondsOrNull(s: String?): String?
+
+    external fun zonedDateTimeEpochNanosecondsOrNull(s: String?): String?
+
+    /** Returns 0 on error; check lastErrorType() to tell that apart from a zero offset. */
+    external fun timeZoneGetOffsetNanosecondsForOrNull(tzId: String?, instantStr: String?): Long
 }
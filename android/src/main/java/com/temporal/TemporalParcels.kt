@@ -0,0 +1,85 @@
+package com.temporal
+
+import android.os.Bundle
+import android.os.Parcel
+
+/**
+ * Writes Instants and ZonedDateTimes to Parcels and Bundles as epoch nanosecond
+ * [high, low] long pairs (see [TemporalNative.instantEpochNanosecondsParts]) rather
+ * than ISO strings, so passing them between components skips string formatting and
+ * parsing of the exact time. A ZonedDateTime also carries its time zone and calendar ids.
+ */
+object TemporalParcels {
+    private const val HIGH = "epochNanosecondsHigh"
+    private const val LOW = "epochNanosecondsLow"
+    private const val TIME_ZONE = "timeZone"
+    private const val CALENDAR = "calendar"
+
+    @Throws(TemporalRangeError::class, TemporalTypeError::class)
+    fun writeInstant(parcel: Parcel, instant: String) {
+        val (high, low) = TemporalNative.instantEpochNanosecondsParts(instant)
+        parcel.writeLong(high)
+        parcel.writeLong(low)
+    }
+
+    @Throws(TemporalRangeError::class)
+    fun readInstant(parcel: Parcel): String {
+        val high = parcel.readLong()
+        return TemporalNative.instantFromEpochNanosecondsParts(high, parcel.readLong())
+    }
+
+    @Throws(TemporalRangeError::class, TemporalTypeError::class)
+    fun writeZonedDateTime(parcel: Parcel, zonedDateTime: String) {
+        val (parts, timeZoneId, calendarId) = TemporalNative.zonedDateTimeToEpochParts(zonedDateTime)
+        val (high, low) = parts as LongArray
+        parcel.writeLong(high)
+        parcel.writeLong(low)
+        parcel.writeString(timeZoneId as String)
+        parcel.writeString(calendarId as String)
+    }
+
+    @Throws(TemporalRangeError::class, TemporalTypeError::class)
+    fun readZonedDateTime(parcel: Parcel): String {
+        val high = parcel.readLong()
+        val low = parcel.readLong()
+        val timeZoneId = checkNotNull(parcel.readString()) { "Missing time zone id in parcel" }
+        return TemporalNative.zonedDateTimeFromEpochNanosecondsParts(high, low, timeZoneId, parcel.readString())
+    }
+
+    @Throws(TemporalRangeError::class, TemporalTypeError::class)
+    fun putInstant(bundle: Bundle, key: String, instant: String) {
+        bundle.putLongArray(key, TemporalNative.instantEpochNanosecondsParts(instant))
+    }
+
+    /** Returns null if [key] holds no Instant. */
+    @Throws(TemporalRangeError::class)
+    fun getInstant(bundle: Bundle, key: String): String? {
+        val parts = bundle.getLongArray(key)?.takeIf { it.size == 2 } ?: return null
+        return TemporalNative.instantFromEpochNanosecondsParts(parts[0], parts[1])
+    }
+
+    @Throws(TemporalRangeError::class, TemporalTypeError::class)
+    fun putZonedDateTime(bundle: Bundle, key: String, zonedDateTime: String) {
+        val (parts, timeZoneId, calendarId) = TemporalNative.zonedDateTimeToEpochParts(zonedDateTime)
+        val (high, low) = parts as LongArray
+        bundle.putBundle(key, Bundle(4).apply {
+            putLong(HIGH, high)
+            putLong(LOW, low)
+            putString(TIME_ZONE, timeZoneId as String)
+            putString(CALENDAR, calendarId as String)
+        })
+    }
+
+    /** Returns null if [key] holds no ZonedDateTime. */
+    @Throws(TemporalRangeError::class, TemporalTypeError::class)
+    fun getZonedDateTime(bundle: Bundle, key: String): String? {
+        val fields = bundle.getBundle(key) ?: return null
+        val timeZoneId = fields.getString(TIME_ZONE) ?: return null
+        return TemporalNative.zonedDateTimeFromEpochNanosecondsParts(
+            fields.getLong(HIGH),
+            fields.getLong(LOW),
+            timeZoneId,
+            fields.getString(CALENDAR)
+        )
+    }
+}
@@ -28,7 +28,7 @@ static void throwTemporalError(TemporalResult *result) {
     // Free the result before throwing
     temporal_free_result(result);
 
-    if (errorType == TEMPORAL_ERROR_RANGE) {
+    if (errorType == TEMPORAL_ERROR_RANGE || errorType == TEMPORAL_ERROR_INPUT_TOO_LONG) {
         THROW_RANGE_ERROR(baseMessage);
     } else {
         THROW_TYPE_ERROR(baseMessage);
@@ -50,7 +50,7 @@ static void throwCompareError(CompareResult *result) {
     // Free the result before throwing
     temporal_free_compare_result(result);
 
-    if (errorType == TEMPORAL_ERROR_RANGE) {
+    if (errorType == TEMPORAL_ERROR_RANGE || errorType == TEMPORAL_ERROR_INPUT_TOO_LONG) {
         THROW_RANGE_ERROR(baseMessage);
     } else {
         THROW_TYPE_ERROR(baseMessage);
@@ -72,8 +72,59 @@ static NSString *extractResultValue(TemporalResult result) {
     return value;
 }
 
+// Fails fast if the native library was built with different struct layouts
+static void checkStructSizes(void) {
+    TemporalStructSizes sizes = temporal_struct_sizes();
+    BOOL matches = sizes.temporal_result == sizeof(TemporalResult)
+        && sizes.compare_result == sizeof(CompareResult)
+        && sizes.plain_time_components == sizeof(PlainTimeComponents)
+        && sizes.plain_date_components == sizeof(PlainDateComponents)
+        && sizes.plain_date_time_components == sizeof(PlainDateTimeComponents)
+        && sizes.plain_year_month_components == sizeof(PlainYearMonthComponents)
+        && sizes.plain_month_day_components == sizeof(PlainMonthDayComponents)
+        && sizes.duration_components == sizeof(DurationComponents)
+        && sizes.duration_components_f64 == sizeof(DurationComponentsF64)
+        && sizes.zoned_date_time_components == sizeof(ZonedDateTimeComponents)
+        && sizes.clock_fields == sizeof(ClockFields)
+        && sizes.epoch_nanoseconds_parts == sizeof(EpochNanosecondsParts)
+        && sizes.time_zone_abbreviation_info == sizeof(TimeZoneAbbreviationInfo);
+    if (!matches) {
+        @throw [NSException exceptionWithName:NSInternalInconsistencyException
+                                       reason:@"temporal_rn native library struct layouts do not match temporal_rn.h; rebuild the native library"
+                                     userInfo:nil];
+    }
+}
+
 @implementation Temporal
 
+- (instancetype)init {
+    if (self = [super init]) {
+        uint32_t nativeVersion = temporal_ffi_api_version();
+        if (nativeVersion != TEMPORAL_FFI_API_VERSION) {
+            @throw [NSException exceptionWithName:NSInternalInconsistencyException
+                                           reason:[NSString stringWithFormat:@"temporal_rn native library has FFI version %u but these bindings expect %d; rebuild the native library",
+                                                   nativeVersion, TEMPORAL_FFI_API_VERSION]
+                                         userInfo:nil];
+        }
+        checkStructSizes();
+        [[NSNotificationCenter defaultCenter] addObserver:self
+                                                 selector:@selector(systemTimeZoneDidChange:)
+                                                     name:NSSystemTimeZoneDidChangeNotification
+                                                   object:nil];
+    }
+    return self;
+}
+
+- (void)dealloc {
+    [[NSNotificationCenter defaultCenter] removeObserver:self];
+}
+
+- (void)systemTimeZoneDidChange:(NSNotification *)notification {
+    // Drop Foundation's cached system zone so localTimeZone picks up the new one.
+    [NSTimeZone resetSystemTimeZone];
+    temporal_notify_tz_changed();
+}
+
 - (NSNumber *)multiply:(double)a b:(double)b {
     NSNumber *result = @(a * b);
     return result;
@@ -427,9 +478,12 @@ static NSString *extractResultValue(TemporalResult result) {
 
     return @[
         @(c.year), @(c.month), @(c.day),
-        @(c.day_of_week), @(c.day_of_year), @(c.week_of_year), @(c.year_of_week),
+        @(c.day_of_week), @(c.day_of_year),
+        (c.has_week_info ? (id)@(c.week_of_year) : (id)[NSNull null]),
+        (c.has_week_info ? (id)@(c.year_of_week) : (id)[NSNull null]),
         @(c.days_in_week), @(c.days_in_month), @(c.days_in_year), @(c.months_in_year),
-        @(c.in_leap_year)
+        @(c.in_leap_year),
+        @(c.quarter), @(c.day_of_quarter)
     ];
 }
 
@@ -543,11 +597,14 @@ static NSString *extractResultValue(TemporalResult result) {
 
     return @[
         @(c.year), @(c.month), @(c.day),
-        @(c.day_of_week), @(c.day_of_year), @(c.week_of_year), @(c.year_of_week),
+        @(c.day_of_week), @(c.day_of_year),
+        (c.has_week_info ? (id)@(c.week_of_year) : (id)[NSNull null]),
+        (c.has_week_info ? (id)@(c.year_of_week) : (id)[NSNull null]),
         @(c.days_in_week), @(c.days_in_month), @(c.days_in_year), @(c.months_in_year),
         @(c.in_leap_year),
         @(c.hour), @(c.minute), @(c.second),
-        @(c.millisecond), @(c.microsecond), @(c.nanosecond)
+        @(c.millisecond), @(c.microsecond), @(c.nanosecond),
+        @(c.quarter), @(c.day_of_quarter)
     ];
 }
 
@@ -665,15 +722,17 @@ static NSString *extractResultValue(TemporalResult result) {
     return extractResultValue(result);
 }
 
-- (NSString *)plainYearMonthAdd:(NSString *)ym duration:(NSString *)duration {
+- (NSString *)plainYearMonthAdd:(NSString *)ym duration:(NSString *)duration overflow:(NSString *)overflow {
     if (!ym || !duration) THROW_TYPE_ERROR(@"Arguments cannot be null");
-    TemporalResult result = temporal_plain_year_month_add([ym UTF8String], [duration UTF8String]);
+    const char *overflowCStr = overflow ? [overflow UTF8String] : NULL;
+    TemporalResult result = temporal_plain_year_month_add([ym UTF8String], [duration UTF8String], overflowCStr);
     return extractResultValue(result);
 }
 
-- (NSString *)plainYearMonthSubtract:(NSString *)ym duration:(NSString *)duration {
+- (NSString *)plainYearMonthSubtract:(NSString *)ym duration:(NSString *)duration overflow:(NSString *)overflow {
     if (!ym || !duration) THROW_TYPE_ERROR(@"Arguments cannot be null");
-    TemporalResult result = temporal_plain_year_month_subtract([ym UTF8String], [duration UTF8String]);
+    const char *overflowCStr = overflow ? [overflow UTF8String] : NULL;
+    TemporalResult result = temporal_plain_year_month_subtract([ym UTF8String], [duration UTF8String], overflowCStr);
     return extractResultValue(result);
 }
 
@@ -723,10 +782,11 @@ static NSString *extractResultValue(TemporalResult result) {
     return extractResultValue(result);
 }
 
-- (NSString *)plainMonthDayFromComponents:(double)month day:(double)day calendarId:(NSString *)calendarId referenceYear:(double)referenceYear {
+- (NSString *)plainMonthDayFromComponents:(double)month day:(double)day calendarId:(NSString *)calendarId referenceYear:(double)referenceYear overflow:(NSString *)overflow {
     const char *cIdCStr = calendarId ? [calendarId UTF8String] : NULL;
+    const char *overflowCStr = overflow ? [overflow UTF8String] : NULL;
     TemporalResult result = temporal_plain_month_day_from_components(
-        (uint8_t)month, (uint8_t)day, cIdCStr, (int32_t)referenceYear
+        (uint8_t)month, (uint8_t)day, cIdCStr, (int32_t)referenceYear, overflowCStr
     );
     return extractResultValue(result);
 }
@@ -771,7 +831,7 @@ static NSString *extractResultValue(TemporalResult result) {
     if (idCStr == NULL) {
         THROW_TYPE_ERROR(@"Invalid calendar identifier encoding");
     }
-    TemporalResult result = temporal_calendar_from(idCStr);
+    TemporalResult result = temporal_calendar_from(idCStr, 0);
     return extractResultValue(result);
 }
 
@@ -843,7 +903,9 @@ static NSString *extractResultValue(TemporalResult result) {
         THROW_RANGE_ERROR([NSString stringWithFormat:@"Invalid duration: %@", durationStr]);
     }
 
-    // Return array: [years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds, sign, blank]
+    // Return array: [years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds, sign, blank,
+    //                microsecondsHigh, microsecondsMid, microsecondsLow, nanosecondsHigh, nanosecondsMid, nanosecondsLow, clamped]
+    // The uint64 low halves don't fit in a double, so each is split into two 32-bit words.
     return @[
         @(components.years),
         @(components.months),
@@ -856,7 +918,14 @@ static NSString *extractResultValue(TemporalResult result) {
         @(components.microseconds),
         @(components.nanoseconds),
         @(components.sign),
-        @(components.sign == 0 ? 1 : 0)  // blank = true if sign is 0
+        @(components.sign == 0 ? 1 : 0),  // blank = true if sign is 0
+        @(components.microseconds_high),
+        @(components.microseconds_low >> 32),
+        @(components.microseconds_low & 0xFFFFFFFFu),
+        @(components.nanoseconds_high),
+        @(components.nanoseconds_low >> 32),
+        @(components.nanoseconds_low & 0xFFFFFFFFu),
+        @(components.is_clamped)
     ];
 }
 
@@ -976,7 +1045,7 @@ static NSString *extractResultValue(TemporalResult result) {
 
 - (NSString *)timeZoneFromString:(NSString *)s {
     if (s == nil) THROW_TYPE_ERROR(@"String cannot be null");
-    TemporalResult result = temporal_time_zone_from_string([s UTF8String]);
+    TemporalResult result = temporal_time_zone_from_string([s UTF8String], 0);
     return extractResultValue(result);
 }
 
@@ -1060,7 +1129,9 @@ static NSString *extractResultValue(TemporalResult result) {
     
     return @[
         @(c.year), @(c.month), @(c.day),
-        @(c.day_of_week), @(c.day_of_year), @(c.week_of_year), @(c.year_of_week),
+        @(c.day_of_week), @(c.day_of_year),
+        (c.has_week_info ? (id)@(c.week_of_year) : (id)[NSNull null]),
+        (c.has_week_info ? (id)@(c.year_of_week) : (id)[NSNull null]),
         @(c.days_in_week), @(c.days_in_month), @(c.days_in_year), @(c.months_in_year),
         @(c.in_leap_year),
         @(c.hour), @(c.minute), @(c.second),
@@ -1100,9 +1171,28 @@ static NSString *extractResultValue(TemporalResult result) {
     return extractResultValue(result);
 }
 
-- (NSString *)zonedDateTimeAdd:(NSString *)zdt duration:(NSString *)duration {
+- (NSString *)zonedDateTimeGetMonthCode:(NSString *)s {
+    if (s == nil) return @"";
+    TemporalResult result = temporal_zoned_date_time_get_month_code([s UTF8String]);
+    return extractResultValue(result);
+}
+
+- (NSString *)zonedDateTimeGetEra:(NSString *)s {
+    if (s == nil) return @"";
+    TemporalResult result = temporal_zoned_date_time_get_era([s UTF8String]);
+    return extractResultValue(result);
+}
+
+- (NSString *)zonedDateTimeGetEraYear:(NSString *)s {
+    if (s == nil) return @"";
+    TemporalResult result = temporal_zoned_date_time_get_era_year([s UTF8String]);
+    return extractResultValue(result);
+}
+
+- (NSString *)zonedDateTimeAdd:(NSString *)zdt duration:(NSString *)duration arithmetic:(NSString *)arithmetic {
     if (!zdt || !duration) THROW_TYPE_ERROR(@"Arguments cannot be null");
-    TemporalResult result = temporal_zoned_date_time_add([zdt UTF8String], [duration UTF8String]);
+    const char *arithmeticCStr = arithmetic ? [arithmetic UTF8String] : NULL;
+    TemporalResult result = temporal_zoned_date_time_add([zdt UTF8String], [duration UTF8String], arithmeticCStr);
     return extractResultValue(result);
 }
 
@@ -1140,15 +1230,23 @@ static NSString *extractResultValue(TemporalResult result) {
     return extractResultValue(result);
 }
 
-- (NSString *)zonedDateTimeUntil:(NSString *)one two:(NSString *)two {
+- (NSString *)zonedDateTimeUntil:(NSString *)one two:(NSString *)two largestUnit:(NSString *)largestUnit smallestUnit:(NSString *)smallestUnit roundingIncrement:(double)roundingIncrement roundingMode:(NSString *)roundingMode {
     if (!one || !two) THROW_TYPE_ERROR(@"Arguments cannot be null");
-    TemporalResult result = temporal_zoned_date_time_until([one UTF8String], [two UTF8String]);
+    const char *largestCStr = largestUnit ? [largestUnit UTF8String] : NULL;
+    const char *smallestCStr = smallestUnit ? [smallestUnit UTF8String] : NULL;
+    const char *modeCStr = roundingMode ? [roundingMode UTF8String] : NULL;
+
+    TemporalResult result = temporal_zoned_date_time_until([one UTF8String], [two UTF8String], largestCStr, smallestCStr, (int64_t)roundingIncrement, modeCStr);
     return extractResultValue(result);
 }
 
-- (NSString *)zonedDateTimeSince:(NSString *)one two:(NSString *)two {
+- (NSString *)zonedDateTimeSince:(NSString *)one two:(NSString *)two largestUnit:(NSString *)largestUnit smallestUnit:(NSString *)smallestUnit roundingIncrement:(double)roundingIncrement roundingMode:(NSString *)roundingMode {
     if (!one || !two) THROW_TYPE_ERROR(@"Arguments cannot be null");
-    TemporalResult result = temporal_zoned_date_time_since([one UTF8String], [two UTF8String]);
+    const char *largestCStr = largestUnit ? [largestUnit UTF8String] : NULL;
+    const char *smallestCStr = smallestUnit ? [smallestUnit UTF8String] : NULL;
+    const char *modeCStr = roundingMode ? [roundingMode UTF8String] : NULL;
+
+    TemporalResult result = temporal_zoned_date_time_since([one UTF8String], [two UTF8String], largestCStr, smallestCStr, (int64_t)roundingIncrement, modeCStr);
     return extractResultValue(result);
 }
 
@@ -1,8 +1,14 @@
-/* temporal-rn C bindings */
+/* temporal-rn C bindings
+ *
+ * Threading: every function may be called from any thread, including concurrently.
+ * The last error (temporal_last_error_type/temporal_last_error_message) is per
+ * thread and describes the calling thread's own last call.
+ */
 #ifndef TEMPORAL_RN_H
 #define TEMPORAL_RN_H
 
 #include <stdint.h>
+#include <stddef.h>
 
 #ifdef __cplusplus
 extern "C" {
@@ -19,10 +25,12 @@ typedef enum {
     TEMPORAL_ERROR_NONE = 0,       // No error
     TEMPORAL_ERROR_RANGE = 1,      // RangeError - value out of range or invalid format
     TEMPORAL_ERROR_TYPE = 2,       // TypeError - wrong type or invalid argument
+    TEMPORAL_ERROR_INPUT_TOO_LONG = 3, // Input over the max input length; raise as RangeError
 } TemporalErrorType;
 
 /**
  * Result structure for operations that can fail.
+ * Strings are NUL-terminated; any NUL inside a value or message is replaced with U+FFFD.
  */
 typedef struct {
     char *value;           // Result value (NULL if error)
@@ -39,6 +47,60 @@ typedef struct {
     char *error_message;   // Error message (NULL if success)
 } CompareResult;
 
+/**
+ * FFI version these bindings were written against. Compare with
+ * temporal_ffi_api_version() to detect a mismatched native library.
+ */
+#define TEMPORAL_FFI_API_VERSION 8
+
+/**
+ * Returns the FFI version of the loaded library.
+ */
+uint32_t temporal_ffi_api_version(void);
+
+/**
+ * Sets the display options the *_to_string functions use for arguments left NULL.
+ * All other functions keep returning spec-formatted strings that parse back to the
+ * same value. NULL strings and a negative fractional_second_digits keep the spec
+ * default ("auto").
+ * calendar_name: auto|always|never|critical, offset: auto|never,
+ * time_zone_name: auto|never|critical, fractional_second_digits: 0-9.
+ * Returns 0 on success or -1 on error (see temporal_last_error_type/_message).
+ */
+int32_t temporal_set_default_display_options(
+    const char *calendar_name, const char *offset,
+    const char *time_zone_name, int32_t fractional_second_digits
+);
+
+/**
+ * Sizes in bytes of the shared structs as compiled into the library. Compare with
+ * sizeof on the C side to detect a layout mismatch.
+ */
+typedef struct {
+    uint32_t temporal_result;
+    uint32_t compare_result;
+    uint32_t plain_time_components;
+    uint32_t plain_date_components;
+    uint32_t plain_date_time_components;
+    uint32_t plain_year_month_components;
+    uint32_t plain_month_day_components;
+    uint32_t duration_components;
+    uint32_t duration_components_f64;
+    uint32_t zoned_date_time_components;
+    uint32_t clock_fields;
+    uint32_t epoch_nanoseconds_parts;
+    uint32_t time_zone_abbreviation_info;
+} TemporalStructSizes;
+
+TemporalStructSizes temporal_struct_sizes(void);
+
+/**
+ * Index layout of the Android JNI long[] results, as JSON:
+ * {"version":N,"arrays":{"plainTime":["hour",...],...},"weekMask":{"weekOfYear":1,...}}.
+ * Native modules sharing array-based bridges can check their indices against it.
+ */
+TemporalResult temporal_schema_json(void);
+
 /**
  * Frees a TemporalResult's allocated strings.
  */
@@ -49,6 +111,42 @@ void temporal_free_result(TemporalResult *result);
  */
 void temporal_free_compare_result(CompareResult *result);
 
+/**
+ * Move the value / error message out of a result, leaving NULL in its place so
+ * temporal_free_result will not free it. The caller must free the returned string
+ * with temporal_free_string. Returns NULL if there is nothing to take.
+ */
+char *temporal_result_take_value(TemporalResult *result);
+char *temporal_result_take_error_message(TemporalResult *result);
+
+/**
+ * Returns the error type set by the last code-returning call on this thread (0 = none).
+ */
+int32_t temporal_last_error_type(void);
+
+/**
+ * Returns a copy of the last error message on this thread, or NULL if there is none.
+ * The caller must free the returned string with `temporal_free_string`.
+ */
+char *temporal_last_error_message(void);
+
+/**
+ * Longest string argument in bytes (default TEMPORAL_DEFAULT_MAX_INPUT_LENGTH)
+ * before a call fails with TEMPORAL_ERROR_INPUT_TOO_LONG. Newline-delimited lists,
+ * batch input and JSON documents use the bulk limit below instead; batch items are
+ * checked one by one against this one. 0 restores the default. Returns the
+ * previous limit.
+ */
+#define TEMPORAL_DEFAULT_MAX_INPUT_LENGTH 4096
+size_t temporal_set_max_input_length(size_t max_bytes);
+
+/**
+ * Longest bulk argument in bytes (default TEMPORAL_DEFAULT_MAX_BULK_INPUT_LENGTH,
+ * 1 MiB). 0 restores the default. Returns the previous limit.
+ */
+#define TEMPORAL_DEFAULT_MAX_BULK_INPUT_LENGTH 1048576
+size_t temporal_set_max_bulk_input_length(size_t max_bytes);
+
 // ============================================================================
 // Instant API
 // ============================================================================
@@ -66,6 +164,27 @@ char *temporal_instant_now(void);
  */
 TemporalResult temporal_instant_from_string(const char *s);
 
+/**
+ * Formats an Instant with an explicit precision: "auto", "0"-"9" fractional
+ * second digits, or a smallest unit ("minute" ... "nanosecond"). The value is
+ * truncated. NULL precision uses the default display options.
+ * offset_style is "Z" (NULL) or "numeric" for an RFC 3339 "+00:00" offset.
+ */
+TemporalResult temporal_instant_to_string(const char *s, const char *precision, const char *offset_style);
+
+/**
+ * Returns the current instant formatted with an explicit precision
+ * (see temporal_instant_to_string).
+ */
+TemporalResult temporal_instant_now_with_precision(const char *precision);
+
+/**
+ * The current time read straight from the system clock, without formatting:
+ * floored epoch milliseconds, or epoch nanoseconds as a decimal string.
+ */
+int64_t temporal_now_epoch_milliseconds(void);
+TemporalResult temporal_now_epoch_nanoseconds_string(void);
+
 /**
  * Creates an Instant from epoch milliseconds.
  */
@@ -76,6 +195,18 @@ TemporalResult temporal_instant_from_epoch_milliseconds(int64_t ms);
  */
 TemporalResult temporal_instant_from_epoch_nanoseconds(const char *ns_str);
 
+/**
+ * Epoch nanoseconds split into 64-bit halves: the value is high * 2^64 + low.
+ * Avoids decimal strings for callers without a 128-bit integer type.
+ */
+typedef struct {
+    int64_t high;
+    uint64_t low;
+    int8_t is_valid;
+} EpochNanosecondsParts;
+
+TemporalResult temporal_instant_from_epoch_nanoseconds_parts(int64_t high, uint64_t low);
+
 /**
  * Returns the epoch milliseconds of an Instant (as string).
  */
@@ -86,6 +217,11 @@ TemporalResult temporal_instant_epoch_milliseconds(const char *s);
  */
 TemporalResult temporal_instant_epoch_nanoseconds(const char *s);
 
+/**
+ * Epoch nanoseconds of an Instant as high/low halves. is_valid is 0 on error.
+ */
+void temporal_instant_epoch_nanoseconds_parts(const char *s, EpochNanosecondsParts *out);
+
 /**
  * Adds a duration to an instant.
  */
@@ -96,6 +232,23 @@ TemporalResult temporal_instant_add(const char *instant_str, const char *duratio
  */
 TemporalResult temporal_instant_subtract(const char *instant_str, const char *duration_str);
 CompareResult temporal_instant_compare(const char *a, const char *b);
+
+/**
+ * Returns the earliest/latest instant from a newline-delimited list.
+ */
+TemporalResult temporal_instant_min(const char *list);
+TemporalResult temporal_instant_max(const char *list);
+
+/**
+ * Clamps an instant into the inclusive range [min, max]. min after max is a RangeError.
+ */
+TemporalResult temporal_instant_clamp(const char *value, const char *min, const char *max);
+
+/**
+ * A rounding_increment of 0 means 1. As in the spec, it must divide the unit above
+ * smallest_unit evenly (a whole day for Instant round), so 7 minutes or a negative
+ * increment is a RangeError. The same holds for every until/since/round function.
+ */
 TemporalResult temporal_instant_until(
     const char *one_str,
     const char *two_str,
@@ -147,6 +300,30 @@ TemporalResult temporal_now_plain_time_iso(const char *tz_id);
  */
 TemporalResult temporal_now_zoned_date_time_iso(const char *tz_id);
 
+// ============================================================================
+// Time zone change tracking
+// ============================================================================
+
+/**
+ * Generation counter bumped on every device time zone change. Caches of values
+ * derived from the device zone should be rebuilt when it changes.
+ */
+uint64_t temporal_tz_generation(void);
+
+/**
+ * Records a device time zone change; returns the new generation.
+ */
+uint64_t temporal_notify_tz_changed(void);
+
+/**
+ * Builds the time zone database and loads the listed zones (newline-delimited,
+ * NULL for none) plus the device zone. Call from a background thread at app start.
+ * Returns the number of listed zones loaded, or -1 on the first invalid id; details
+ * are available via temporal_last_error_type/temporal_last_error_message. The list
+ * is held to the bulk input limit and each id to the per-string one.
+ */
+int32_t temporal_warmup(const char* tz_ids_joined);
+
 // ============================================================================
 // PlainTime API
 // ============================================================================
@@ -192,6 +369,10 @@ TemporalResult temporal_plain_time_add(const char *time_str, const char *duratio
  * Subtracts a duration from a PlainTime.
  */
 TemporalResult temporal_plain_time_subtract(const char *time_str, const char *duration_str);
+/**
+ * Compares two PlainTimes field by field down to the nanosecond, so values that
+ * differ only in the last fractional digit never compare equal.
+ */
 CompareResult temporal_plain_time_compare(const char *a, const char *b);
 TemporalResult temporal_plain_time_until(
     const char *one_str,
@@ -232,17 +413,100 @@ typedef struct {
     uint16_t days_in_year;
     uint16_t months_in_year;
     int8_t in_leap_year;
+    int8_t has_week_info;  /* 0 when week_of_year/year_of_week are undefined */
+    uint8_t quarter;       /* 1-4, or 0 for calendars without 12 months */
+    uint8_t day_of_quarter;
     int8_t is_valid;
 } PlainDateComponents;
 
 TemporalResult temporal_plain_date_from_string(const char *s);
+/**
+ * Formats with an explicit calendar_name: "auto", "always", "never" or "critical"
+ * ("[!u-ca=...]", even for ISO 8601). NULL uses the default display options.
+ */
+TemporalResult temporal_plain_date_to_string(const char *s, const char *calendar_name);
+/**
+ * Like temporal_plain_date_from_string, but also accepts "YY-MM-DD" when
+ * pivot_year >= 0, resolving the year into the 100 years ending at pivot_year
+ * (pivot 2049: "49" -> 2049, "50" -> 1950). A negative pivot_year rejects
+ * two-digit years with a RangeError.
+ */
+TemporalResult temporal_plain_date_from_string_lenient(const char *s, int32_t pivot_year);
 TemporalResult temporal_plain_date_from_components(int32_t year, uint8_t month, uint8_t day, const char *calendar_id);
+
+/**
+ * Packs a PlainDate's calendar year, ordinal month and day into
+ * year << 16 | month << 8 | day (year signed), so lists can carry dates as plain
+ * numbers: year = packed >> 16, month = (packed >> 8) & 0xff, day = packed & 0xff.
+ * Packed dates of one calendar sort like the dates and fit in 2^53 (exact as a JS
+ * number). Returns TEMPORAL_PACKED_DATE_ERROR on error (see temporal_last_error_*).
+ */
+#define TEMPORAL_PACKED_DATE_ERROR INT64_MIN
+int64_t temporal_plain_date_pack(const char *s);
+
+/**
+ * Inverse of temporal_plain_date_pack; calendar_id (NULL = iso8601) must be the
+ * calendar the date was packed in.
+ */
+TemporalResult temporal_plain_date_unpack(int64_t packed, const char *calendar_id);
+/**
+ * ISO 8601 ordinal dates. from_ordinal takes a 1-based day of the year in calendar_id
+ * (NULL = iso8601); the string forms are "YYYY-DDD" (or "YYYYDDD", and a signed
+ * six-digit year outside 0000-9999) in the ISO calendar.
+ */
+TemporalResult temporal_plain_date_from_ordinal(int32_t year, uint16_t day_of_year, const char *calendar_id);
+TemporalResult temporal_plain_date_from_ordinal_string(const char *s);
+TemporalResult temporal_plain_date_to_ordinal_string(const char *s);
 void temporal_plain_date_get_components(const char *s, PlainDateComponents *out);
 TemporalResult temporal_plain_date_get_month_code(const char *s);
 TemporalResult temporal_plain_date_get_calendar(const char *s);
 TemporalResult temporal_plain_date_add(const char *date_str, const char *duration_str);
 TemporalResult temporal_plain_date_subtract(const char *date_str, const char *duration_str);
+/**
+ * Adds whole months with overflow "constrain": Jan 31 plus one month is the last day
+ * of February. Negative months go back.
+ */
+TemporalResult temporal_plain_date_add_months_constrained(const char *date_str, int64_t months);
 CompareResult temporal_plain_date_compare(const char *a, const char *b);
+
+/**
+ * compare_iso orders by ISO fields only and ignores the calendar (like compare).
+ * equals also requires the same calendar: returns 1 if equal, 0 if not, -1 on
+ * error (see temporal_last_error_*).
+ */
+CompareResult temporal_plain_date_compare_iso(const char *a, const char *b);
+int8_t temporal_plain_date_equals(const char *a, const char *b);
+TemporalResult temporal_plain_date_min(const char *list); /* newline-delimited */
+TemporalResult temporal_plain_date_max(const char *list); /* newline-delimited */
+TemporalResult temporal_plain_date_clamp(const char *value, const char *min, const char *max);
+TemporalResult temporal_plain_date_start_of_quarter(const char *s);
+TemporalResult temporal_plain_date_end_of_quarter(const char *s);
+
+/**
+ * Start/end (first/last day) of the "day", "week", "month", or "year" containing
+ * the date. Weeks start on first_day_of_week (1 = Monday ... 7 = Sunday).
+ */
+TemporalResult temporal_plain_date_start_of(const char *s, const char *unit, uint8_t first_day_of_week);
+TemporalResult temporal_plain_date_end_of(const char *s, const char *unit, uint8_t first_day_of_week);
+
+/**
+ * Counts calendar-week boundaries from a to b, with weeks starting on
+ * first_day_of_week (1 = Monday ... 7 = Sunday). Returns
+ * TEMPORAL_WEEKS_BETWEEN_ERROR on error (see temporal_last_error_*).
+ */
+#define TEMPORAL_WEEKS_BETWEEN_ERROR INT64_MIN
+int64_t temporal_plain_date_weeks_between(const char *a, const char *b, uint8_t first_day_of_week);
+
+/**
+ * Fiscal year and quarter (1-4) of a date, for fiscal years starting on the first
+ * of fiscal_start_month (1-12). Fiscal years are named after the calendar year they
+ * end in (start month 10: 2024-10-01 is FY2025). Returns TEMPORAL_FISCAL_ERROR on
+ * error, including lunisolar and 13-month calendars (see temporal_last_error_*).
+ */
+#define TEMPORAL_FISCAL_ERROR INT32_MIN
+int32_t temporal_fiscal_year_of(const char *date, uint8_t fiscal_start_month);
+int32_t temporal_fiscal_quarter_of(const char *date, uint8_t fiscal_start_month);
+
 TemporalResult temporal_plain_date_with(const char *date_str, int32_t year, int32_t month, int32_t day, const char *calendar_id);
 TemporalResult temporal_plain_date_until(const char *one_str, const char *two_str);
 TemporalResult temporal_plain_date_since(const char *one_str, const char *two_str);
@@ -270,10 +534,19 @@ typedef struct {
     uint16_t millisecond;
     uint16_t microsecond;
     uint16_t nanosecond;
+    int8_t has_week_info;
+    uint8_t quarter;
+    uint8_t day_of_quarter;
     int8_t is_valid;
 } PlainDateTimeComponents;
 
 TemporalResult temporal_plain_date_time_from_string(const char *s);
+/**
+ * Formats with an explicit precision (see temporal_instant_to_string) and
+ * calendar_name (see temporal_plain_date_to_string); NULL uses the defaults.
+ */
+TemporalResult temporal_plain_date_time_to_string(const char *s, const char *precision,
+                                                  const char *calendar_name);
 TemporalResult temporal_plain_date_time_from_components(
     int32_t year, uint8_t month, uint8_t day,
     uint8_t hour, uint8_t minute, uint8_t second,
@@ -285,6 +558,10 @@ TemporalResult temporal_plain_date_time_get_month_code(const char *s);
 TemporalResult temporal_plain_date_time_get_calendar(const char *s);
 TemporalResult temporal_plain_date_time_add(const char *dt_str, const char *duration_str);
 TemporalResult temporal_plain_date_time_subtract(const char *dt_str, const char *duration_str);
+/**
+ * Compares two PlainDateTimes by their ISO fields down to the nanosecond; the
+ * calendar is ignored.
+ */
 CompareResult temporal_plain_date_time_compare(const char *a, const char *b);
 TemporalResult temporal_plain_date_time_with(
     const char *dt_str,
@@ -296,6 +573,16 @@ TemporalResult temporal_plain_date_time_with(
 TemporalResult temporal_plain_date_time_until(const char *one_str, const char *two_str);
 TemporalResult temporal_plain_date_time_since(const char *one_str, const char *two_str);
 
+/**
+ * Splits a PlainDateTime into "date\ntime" (PlainDate and PlainTime strings).
+ */
+TemporalResult temporal_plain_date_time_split(const char *s);
+
+/**
+ * Combines a PlainDate and a PlainTime (NULL = midnight) into a PlainDateTime.
+ */
+TemporalResult temporal_plain_date_time_combine(const char *date, const char *time);
+
 // ============================================================================
 // PlainYearMonth API
 // ============================================================================
@@ -313,14 +600,34 @@ typedef struct {
 } PlainYearMonthComponents;
 
 TemporalResult temporal_plain_year_month_from_string(const char *s);
+/** Formats with an explicit calendar_name; see temporal_plain_date_to_string. */
+TemporalResult temporal_plain_year_month_to_string(const char *s, const char *calendar_name);
 TemporalResult temporal_plain_year_month_from_components(
     int32_t year, uint8_t month, const char *calendar_id, uint8_t reference_day
 );
 void temporal_plain_year_month_get_components(const char *s, PlainYearMonthComponents *out);
 TemporalResult temporal_plain_year_month_get_month_code(const char *s);
 TemporalResult temporal_plain_year_month_get_calendar(const char *s);
-TemporalResult temporal_plain_year_month_add(const char *ym_str, const char *duration_str);
-TemporalResult temporal_plain_year_month_subtract(const char *ym_str, const char *duration_str);
+/**
+ * overflow is "constrain" or "reject"; NULL means "constrain". Per spec the month
+ * is entered on its first day and only years and months may be added.
+ */
+TemporalResult temporal_plain_year_month_add(const char *ym_str, const char *duration_str, const char *overflow);
+TemporalResult temporal_plain_year_month_subtract(const char *ym_str, const char *duration_str, const char *overflow);
+/**
+ * Add/subtract from day reference_day of the month, so weeks and days may be used
+ * (time units are a RangeError). reference_day 0 is the first day for a non-negative
+ * duration and the last day for a negative one; 1-31 is constrained to the month's
+ * length. With overflow "reject", 2024-01 + P1M from day 31 fails.
+ */
+TemporalResult temporal_plain_year_month_add_with_reference_day(
+    const char *ym_str, const char *duration_str,
+    const char *overflow, int32_t reference_day
+);
+TemporalResult temporal_plain_year_month_subtract_with_reference_day(
+    const char *ym_str, const char *duration_str,
+    const char *overflow, int32_t reference_day
+);
 CompareResult temporal_plain_year_month_compare(const char *a, const char *b);
 TemporalResult temporal_plain_year_month_with(
     const char *ym_str, int32_t year, int32_t month, const char *calendar_id
@@ -340,28 +647,104 @@ typedef struct {
 } PlainMonthDayComponents;
 
 TemporalResult temporal_plain_month_day_from_string(const char *s);
+/** Formats with an explicit calendar_name; see temporal_plain_date_to_string. */
+TemporalResult temporal_plain_month_day_to_string(const char *s, const char *calendar_name);
+/**
+ * reference_year (TEMPORAL_NO_REFERENCE_YEAR = none) is used to validate leap
+ * days; year 0 is a real year. overflow is "constrain" or "reject" (NULL = "constrain").
+ */
+#define TEMPORAL_NO_REFERENCE_YEAR INT32_MIN
 TemporalResult temporal_plain_month_day_from_components(
-    uint8_t month, uint8_t day, const char *calendar_id, int32_t reference_year
+    uint8_t month, uint8_t day, const char *calendar_id, int32_t reference_year,
+    const char *overflow
 );
 void temporal_plain_month_day_get_components(const char *s, PlainMonthDayComponents *out);
 TemporalResult temporal_plain_month_day_get_month_code(const char *s);
 TemporalResult temporal_plain_month_day_get_calendar(const char *s);
 TemporalResult temporal_plain_month_day_to_plain_date(const char *md_str, int32_t year);
 
+/**
+ * First date strictly after after_date (last strictly before before_date) on the
+ * month-day, for birthdays and anniversaries. The month code and day are looked up
+ * in calendar_id (NULL = the month-day's calendar) and the result is in it.
+ * policy handles years without the day: "previous-day" (NULL, Feb 28),
+ * "next-day" (Mar 1) or "strict" (skip the year).
+ */
+TemporalResult temporal_next_occurrence_of_month_day(
+    const char *md_str, const char *after_date, const char *calendar_id, const char *policy
+);
+TemporalResult temporal_previous_occurrence_of_month_day(
+    const char *md_str, const char *before_date, const char *calendar_id, const char *policy
+);
+
 // ============================================================================
 // Calendar API
 // ============================================================================
 
 /**
- * Gets a Calendar from a string identifier.
+ * Gets a Calendar from a string identifier. With lenient != 0 the id is trimmed
+ * and lowercased before lookup.
  */
-TemporalResult temporal_calendar_from(const char *id);
+TemporalResult temporal_calendar_from(const char *id, int8_t lenient);
 
 /**
  * Gets the identifier of a calendar.
  */
 TemporalResult temporal_calendar_id(const char *id);
 
+/**
+ * Gets the number of days in a month (1-based ordinal) of a calendar year.
+ * A NULL calendar_id means ISO 8601. Returns 0 on error (see temporal_last_error_*).
+ */
+uint16_t temporal_calendar_days_in_month(const char *calendar_id, int32_t year, uint8_t month);
+
+/**
+ * Gets the number of days in a calendar year. Returns 0 on error.
+ */
+uint16_t temporal_calendar_days_in_year(const char *calendar_id, int32_t year);
+
+/**
+ * Returns 1 if the year is a leap year in the calendar, 0 if not, -1 on error.
+ */
+int8_t temporal_calendar_in_leap_year(const char *calendar_id, int32_t year);
+
+/**
+ * Gets the number of months in a calendar year (13 for Hebrew leap years).
+ * Returns 0 on error.
+ */
+uint16_t temporal_calendar_months_in_year(const char *calendar_id, int32_t year);
+
+/**
+ * Gets the number of valid days in a month of a calendar year. Returns 0 on error.
+ */
+uint8_t temporal_calendar_valid_days_in_month(const char *calendar_id, int32_t year, uint8_t month);
+
+/**
+ * Writes the day count of every month in a calendar year to out (index 0 = month 1).
+ * Returns the month count, or -1 on error. Nothing is written if out_len is too small.
+ */
+int32_t temporal_calendar_valid_days_in_year(
+    const char *calendar_id, int32_t year, uint8_t *out, size_t out_len
+);
+
+/**
+ * The calendar's dateUntil: the difference from one to two in the calendar's years,
+ * months, weeks and days. Both PlainDates are read in calendar_id (NULL = ISO 8601).
+ * largest_unit is "year", "month", "week" or "day" (default for NULL).
+ */
+TemporalResult temporal_calendar_date_until(
+    const char *calendar_id, const char *one, const char *two, const char *largest_unit
+);
+
+/**
+ * Lists a calendar's eras in chronological order, one per line:
+ * "code,start,end,direction", e.g. "heisei,1989-01-08,2019-04-30,1". start and end
+ * are the ISO first and last days, empty where the era is unbounded; direction is
+ * -1 for eras counting backwards (bce, broc, bh), else 1. Calendars without eras
+ * (iso8601, chinese, dangi) give "". NULL calendar_id means ISO 8601.
+ */
+TemporalResult temporal_calendar_eras(const char *calendar_id);
+
 /**
  * Frees a string allocated by temporal functions.
  */
@@ -383,14 +766,23 @@ typedef struct {
     int64_t minutes;
     int64_t seconds;
     int64_t milliseconds;
-    int64_t microseconds;
-    int64_t nanoseconds;
+    int64_t microseconds;  // Clamped to int64 range, see is_clamped
+    int64_t nanoseconds;   // Clamped to int64 range, see is_clamped
     int8_t sign;
     int8_t is_valid;
+    // Full 128-bit values: high * 2^64 + low
+    int64_t microseconds_high;
+    uint64_t microseconds_low;
+    int64_t nanoseconds_high;
+    uint64_t nanoseconds_low;
+    int8_t is_clamped;     // 1 if microseconds/nanoseconds did not fit in int64
 } DurationComponents;
 
 /**
- * Parses an ISO 8601 duration string and returns a TemporalResult.
+ * Parses an ISO 8601 duration string and returns a TemporalResult. The last time
+ * unit may have a fraction, which is carried into smaller units ("PT0.5H" is
+ * "PT30M"); a fraction on any earlier unit ("PT0.5H30M") or on a date unit is a
+ * RangeError.
  */
 TemporalResult temporal_duration_from_string(const char *s);
 
@@ -417,6 +809,46 @@ TemporalResult temporal_duration_from_components(
  */
 void temporal_duration_get_components(const char *s, DurationComponents *out);
 
+/**
+ * Duration components as doubles, the type JS Numbers use. Components that are
+ * not exactly representable (beyond 2^53) are rounded and flagged.
+ */
+typedef struct {
+    double years;
+    double months;
+    double weeks;
+    double days;
+    double hours;
+    double minutes;
+    double seconds;
+    double milliseconds;
+    double microseconds;
+    double nanoseconds;
+    int8_t sign;            // -1, 0, or 1
+    int8_t is_valid;        // 1 if valid, 0 if parsing failed
+    int8_t is_exact;        // 1 if no component was rounded
+    uint16_t inexact_fields; // bit i (0 = years ... 9 = nanoseconds) set when rounded
+} DurationComponentsF64;
+
+/**
+ * Gets all component values from a duration string as doubles.
+ * Sets out->is_valid to 1 on success, 0 on error.
+ */
+void temporal_duration_get_components_f64(const char *s, DurationComponentsF64 *out);
+
+/**
+ * Milliseconds in a time-only duration, with any sub-millisecond fraction, for
+ * Animated and timer APIs. Days count as 24 hours; years, months or weeks are a
+ * RangeError. Returns NaN on error (see temporal_last_error_type/message).
+ */
+double temporal_duration_to_millis_f64(const char *s);
+
+/**
+ * Duration from milliseconds (fraction kept to the nanosecond), e.g. 1500 -> "PT1.5S".
+ * NaN, infinities and out-of-range values are a RangeError.
+ */
+TemporalResult temporal_duration_from_millis(double ms);
+
 /**
  * Adds two durations and returns a TemporalResult.
  */
@@ -462,19 +894,580 @@ TemporalResult temporal_duration_with(
     int64_t nanoseconds
 );
 
+/**
+ * Sums a newline-delimited list of durations. relative_to (a PlainDate or
+ * PlainDateTime string) is required when any duration has years, months, or weeks;
+ * pass NULL for time-only sums.
+ */
+TemporalResult temporal_duration_sum(const char *durations, const char *relative_to);
+
+/**
+ * Splits a duration into a whole number of units and the remainder: P17D by "week"
+ * writes 2 to quotient (which may be NULL) and returns "P3D". The count truncates
+ * toward zero and the remainder is balanced from the next smaller unit down,
+ * skipping weeks. relative_to (a PlainDate or PlainDateTime string) is required when
+ * the duration or unit has years, months, or weeks; with NULL a day is 24 hours.
+ */
+TemporalResult temporal_duration_divmod(
+    const char *duration,
+    const char *unit,
+    const char *relative_to,
+    int64_t *quotient
+);
+
+// ============================================================================
+// Formatting API
+// ============================================================================
+
+/**
+ * Formats a duration with English labels. style is "long", "short" (default when
+ * NULL), "narrow", or "digital" ("1:30:05"). There is no localized variant.
+ */
+TemporalResult temporal_format_duration_en(const char *duration, const char *style);
+
+/**
+ * Parses input such as "31/12/2024" with a pattern such as "dd/MM/yyyy" into a
+ * PlainDate. Tokens: yyyy, yy, y, MM, M, dd, d; quote literal text with '...'.
+ */
+TemporalResult temporal_parse_with_pattern(const char *input, const char *pattern, const char *calendar_id);
+
+/**
+ * Formats a value with a fixed pattern. value_kind is "PlainDate", "PlainTime",
+ * "PlainDateTime", "ZonedDateTime", or "Instant" (UTC). Tokens: yyyy, yy, MM, M,
+ * dd, d, HH, H, mm, ss, SSS, ZZZZ ("GMT+01:00", or "GMT+00:09:21" for historical
+ * offsets with seconds); quote literal text with '...'.
+ */
+TemporalResult temporal_format_pattern(const char *value_kind, const char *value, const char *pattern);
+
+// ============================================================================
+// Holidays API
+// ============================================================================
+
+/**
+ * Registers (or replaces) a holiday set from JSON: an array of ISO dates, or
+ * {"dates": [...], "rules": [{"month": 12, "day": 25},
+ *                            {"month": 11, "weekday": 4, "nth": 4}]}.
+ * weekday is 1 = Monday ... 7 = Sunday; a negative nth counts from the month end.
+ * Returns the number of entries, or -1 on error (see temporal_last_error_*).
+ */
+int32_t temporal_holidays_register(const char *set_id, const char *json);
+
+/**
+ * Removes a holiday set. Returns 1 if it existed, 0 otherwise.
+ */
+int8_t temporal_holidays_unregister(const char *set_id);
+
+/**
+ * Returns 1 if the PlainDate is a holiday in the set, 0 if not, -1 on error.
+ */
+int8_t temporal_is_holiday(const char *date, const char *set_id);
+
+/**
+ * Returns the first holiday in the set strictly after the PlainDate.
+ */
+TemporalResult temporal_next_holiday(const char *date, const char *set_id);
+
+// ============================================================================
+// Meetings API
+// ============================================================================
+
+/**
+ * UTC windows on a date where every participant's local working hours overlap.
+ * participants_json is an array of {"timeZone", "start", "end"} objects, or
+ * {"timeZone", "windows": [{"start", "end"}, ...]}; times are local to the
+ * participant on date, and an end not after its start runs into the next day.
+ * Returns one "start/end" Instant interval per line, or "" when there is none.
+ */
+TemporalResult temporal_overlap_daily_windows(const char *participants_json, const char *date);
+
+// ============================================================================
+// Custom Calendar API (experimental)
+// ============================================================================
+
+/**
+ * Callbacks implementing a calendar CLDR does not define, e.g. a 4-4-5 fiscal
+ * calendar. All but release are required. The conversion callbacks write the
+ * converted fields and return 1, or return 0 outside the supported range;
+ * date_to_iso is only called with a month and day that exist in that year, and
+ * date_from_iso must write one (anything else is reported as a RangeError).
+ *
+ * Callbacks may run on any thread, concurrently, and must not call back into
+ * the custom calendar functions. release (optional) is called with the context
+ * once the calendar is unregistered or replaced and no call still uses it.
+ */
+typedef struct {
+    void *context;
+    int8_t (*date_from_iso)(void *context, int32_t iso_year, uint8_t iso_month, uint8_t iso_day,
+                            int32_t *year, uint8_t *month, uint8_t *day);
+    int8_t (*date_to_iso)(void *context, int32_t year, uint8_t month, uint8_t day,
+                          int32_t *iso_year, uint8_t *iso_month, uint8_t *iso_day);
+    uint8_t (*months_in_year)(void *context, int32_t year);
+    uint8_t (*days_in_month)(void *context, int32_t year, uint8_t month);
+    void (*release)(void *context);
+} TemporalCustomCalendarVTable;
+
+/**
+ * Registers (or replaces) a custom calendar. id uses a-z, 0-9 and '-' and must not
+ * name a built-in calendar; the vtable is copied. Returns 0 on success or -1 on
+ * error (see temporal_last_error_*).
+ */
+int32_t temporal_register_custom_calendar(const char *id, const TemporalCustomCalendarVTable *vtable);
+/** Removes a custom calendar. Returns 1 if it existed, 0 otherwise. */
+int8_t temporal_unregister_custom_calendar(const char *id);
+
+/*
+ * A custom calendar date is an ISO PlainDate with the calendar's annotation,
+ * "2024-02-04[u-ca=fiscal-445]". temporal_plain_date_from_string, _get_components,
+ * _get_calendar, _add and _subtract accept it and work in the custom calendar.
+ */
+
+/**
+ * The functions below take an ISO PlainDate and the calendar id instead, reading
+ * fields and doing month and year arithmetic in the custom calendar. Week numbering
+ * and leap years are not defined (0), and quarters only for 12-month years.
+ */
+void temporal_custom_calendar_get_components(const char *id, const char *date, PlainDateComponents *out);
+/** Creates an ISO PlainDate from custom fields. overflow: "constrain" (default) or "reject". */
+TemporalResult temporal_custom_calendar_date_to_iso(const char *id, int32_t year, int32_t month, int32_t day,
+                                                    const char *overflow);
+/** Adds years and months in the custom calendar, then weeks and days. Returns an ISO PlainDate. */
+TemporalResult temporal_custom_calendar_add(const char *id, const char *date, const char *duration,
+                                            const char *overflow);
+
+// ============================================================================
+// Formatter API
+// ============================================================================
+
+/**
+ * Locale-aware formatters that are built once and reused by handle, for list
+ * views. options_json is an object with optional "dateStyle" (full, long, medium,
+ * short), "timeStyle" (medium, short) and "timeZone" (default "UTC", used for
+ * instants and epoch values); NULL means {"dateStyle":"short"}. A NULL locale is "en".
+ *
+ * temporal_formatter_create returns 0 on error (see temporal_last_error_*).
+ * Handles are process-wide and safe to use from several threads at once.
+ */
+uint64_t temporal_formatter_create(const char *locale, const char *options_json);
+/**
+ * Formats a PlainDate, PlainTime, PlainDateTime or ZonedDateTime (wall-clock time)
+ * or an Instant (in the formatter's time zone). An unknown handle is a TypeError;
+ * a value in a calendar other than iso8601 is a RangeError.
+ */
+TemporalResult temporal_formatter_format(uint64_t handle, const char *value);
+TemporalResult temporal_formatter_format_epoch_ns(uint64_t handle, int64_t epoch_ns);
+/** Releases a formatter. Returns 1 if the handle existed, 0 otherwise. */
+int8_t temporal_formatter_destroy(uint64_t handle);
+
+// ============================================================================
+// Astronomy API
+// ============================================================================
+
+/**
+ * Julian day number of a PlainDate as a decimal string (2024-01-15 -> "2460325"),
+ * and back. A NULL calendar_id defaults to iso8601.
+ */
+TemporalResult temporal_plain_date_to_julian_day(const char *date);
+TemporalResult temporal_plain_date_from_julian_day(int64_t jdn, const char *calendar_id);
+
+/**
+ * Julian date (day number plus fraction, days start at .5) of a PlainDateTime as
+ * a decimal string, and back; the reverse conversion rounds to the millisecond.
+ */
+TemporalResult temporal_plain_date_time_to_julian_date(const char *dt);
+TemporalResult temporal_plain_date_time_from_julian_date(double jd, const char *calendar_id);
+
+/**
+ * Local mean solar time at a longitude (degrees, east positive): UT shifted by
+ * 4 minutes per degree. The offset is returned as a Duration string
+ * (15 -> "PT1H"); the conversions go between a ZonedDateTime and a PlainDateTime
+ * in local mean time. temporal_solar_mean_noon returns 12:00 local mean time on
+ * the date as a ZonedDateTime in tz_id.
+ */
+TemporalResult temporal_solar_mean_time_offset(double longitude);
+TemporalResult temporal_zoned_date_time_to_local_mean_time(const char *zdt, double longitude);
+TemporalResult temporal_local_mean_time_to_zoned_date_time(const char *dt, double longitude, const char *tz_id);
+TemporalResult temporal_solar_mean_noon(const char *date, double longitude, const char *tz_id);
+
+// ============================================================================
+// Binary encoding API
+// ============================================================================
+
+/**
+ * Compact versioned binary encoding for persistence: version byte, kind byte,
+ * big-endian i128 epoch nanoseconds, and for ZonedDateTime the length-prefixed
+ * time zone and calendar ids. Decoders reject versions newer than they support.
+ *
+ * to_bytes works like snprintf: it returns the encoded length and writes only if
+ * that fits in out_len (pass NULL/0 to size the buffer). Returns 0 on error
+ * (see temporal_last_error_*).
+ */
+size_t temporal_instant_to_bytes(const char *s, uint8_t *out, size_t out_len);
+TemporalResult temporal_instant_from_bytes(const uint8_t *data, size_t len);
+size_t temporal_zoned_date_time_to_bytes(const char *s, uint8_t *out, size_t out_len);
+TemporalResult temporal_zoned_date_time_from_bytes(const uint8_t *data, size_t len);
+
+// ============================================================================
+// Batch API
+// ============================================================================
+
+/**
+ * Parses each delimiter-separated item of joined_input as kind ("Instant",
+ * "PlainDate", "PlainTime", "PlainDateTime", "PlainYearMonth", "PlainMonthDay",
+ * "ZonedDateTime" or "Duration"). Returns the normalized values joined by the
+ * delimiter, with an empty slot for items that failed; per-item error types
+ * (0 = ok, 1 = RangeError, 3 = input too long) are written to error_codes (may be NULL) up to
+ * error_codes_len entries.
+ */
+TemporalResult temporal_batch_parse(const char *kind, const char *joined_input, const char *delimiter,
+                                    int32_t *error_codes, size_t error_codes_len);
+
+/**
+ * Checks whether s parses as kind (same kinds as temporal_batch_parse) without
+ * returning a result string. It only parses; nothing is formatted or cached.
+ * Returns 0 when valid, 1 (RangeError) for invalid
+ * input or an unknown kind, 2 (TypeError) for a NULL argument, 3 for input over
+ * the max input length; the message is available via temporal_last_error_message.
+ */
+int32_t temporal_validate(const char *kind, const char *s);
+
+/**
+ * The *_from_string functions and temporal_batch_parse cache the normalized output
+ * of the last 64 inputs per kind. Returns the cache counters as JSON, one object per
+ * kind: {"Instant":{"hits":3,"misses":1,"evictions":0,"resets":0,"entries":1},...}.
+ */
+TemporalResult temporal_canonical_cache_stats(void);
+
+/**
+ * Returns the native counters since process start as JSON:
+ * {"parse":{"Instant":{"calls":4,"cacheHits":3,"cacheEvictions":0,"cacheResets":0,
+ * "cacheEntries":1},...},"errors":{"RangeError":2,"TypeError":0,"InputTooLong":0},
+ * "timeZoneChanges":1}. "parse" counts the *_from_string calls and
+ * temporal_batch_parse items per kind. The time zone database is compiled in and
+ * never rebuilt; "timeZoneChanges" counts temporal_notify_tz_changed calls.
+ */
+TemporalResult temporal_metrics_snapshot(void);
+
+/**
+ * Rewrites the string values at the given JSON pointers (newline-delimited,
+ * "*" matches every array element or object member) to canonical IXDTF and
+ * returns the document as compact JSON. Missing paths and non-string values are
+ * skipped; an invalid timestamp fails the call with its pointer in the message.
+ */
+TemporalResult temporal_normalize_json_timestamps(const char *json, const char *pointer_paths);
+
+// ============================================================================
+// Compare codes (allocation-free error reporting)
+// ============================================================================
+
+/**
+ * Returned by the *_compare_code functions when comparison failed.
+ * Details are available via temporal_last_error_type/temporal_last_error_message.
+ */
+#define TEMPORAL_COMPARE_ERROR INT32_MIN
+
+int32_t temporal_instant_compare_code(const char *a, const char *b);
+int32_t temporal_plain_time_compare_code(const char *a, const char *b);
+int32_t temporal_plain_date_compare_code(const char *a, const char *b);
+int32_t temporal_plain_date_time_compare_code(const char *a, const char *b);
+int32_t temporal_plain_year_month_compare_code(const char *a, const char *b);
+int32_t temporal_zoned_date_time_compare_code(const char *a, const char *b);
+int32_t temporal_duration_compare_code(const char *a, const char *b);
+
+// ============================================================================
+// Integer-coded option variants
+// ============================================================================
+
+/**
+ * Passed for an optional coded option that should use its default.
+ */
+#define TEMPORAL_OPTION_UNSET (-1)
+
+typedef enum {
+    TEMPORAL_UNIT_AUTO = 0,
+    TEMPORAL_UNIT_NANOSECOND = 1,
+    TEMPORAL_UNIT_MICROSECOND = 2,
+    TEMPORAL_UNIT_MILLISECOND = 3,
+    TEMPORAL_UNIT_SECOND = 4,
+    TEMPORAL_UNIT_MINUTE = 5,
+    TEMPORAL_UNIT_HOUR = 6,
+    TEMPORAL_UNIT_DAY = 7,
+    TEMPORAL_UNIT_WEEK = 8,
+    TEMPORAL_UNIT_MONTH = 9,
+    TEMPORAL_UNIT_YEAR = 10,
+} TemporalUnitCode;
+
+typedef enum {
+    TEMPORAL_ROUNDING_MODE_CEIL = 0,
+    TEMPORAL_ROUNDING_MODE_FLOOR = 1,
+    TEMPORAL_ROUNDING_MODE_EXPAND = 2,
+    TEMPORAL_ROUNDING_MODE_TRUNC = 3,
+    TEMPORAL_ROUNDING_MODE_HALF_CEIL = 4,
+    TEMPORAL_ROUNDING_MODE_HALF_FLOOR = 5,
+    TEMPORAL_ROUNDING_MODE_HALF_EXPAND = 6,
+    TEMPORAL_ROUNDING_MODE_HALF_TRUNC = 7,
+    TEMPORAL_ROUNDING_MODE_HALF_EVEN = 8,
+} TemporalRoundingModeCode;
+
+typedef enum {
+    TEMPORAL_DISAMBIGUATION_COMPATIBLE = 0,
+    TEMPORAL_DISAMBIGUATION_EARLIER = 1,
+    TEMPORAL_DISAMBIGUATION_LATER = 2,
+    TEMPORAL_DISAMBIGUATION_REJECT = 3,
+} TemporalDisambiguationCode;
+
+typedef enum {
+    TEMPORAL_OFFSET_USE = 0,
+    TEMPORAL_OFFSET_PREFER = 1,
+    TEMPORAL_OFFSET_IGNORE = 2,
+    TEMPORAL_OFFSET_REJECT = 3,
+} TemporalOffsetOptionCode;
+
+/**
+ * Returns the rounding mode names accepted by the string-option functions,
+ * newline-separated, in the order of TemporalRoundingModeCode.
+ */
+TemporalResult temporal_supported_rounding_modes(void);
+
+/**
+ * Returns the unit names (singular, largest first) accepted for a type's
+ * until/since/round options, newline-separated. context_kind is a type name
+ * such as "PlainTime" or "ZonedDateTime"; NULL returns every unit.
+ */
+TemporalResult temporal_supported_units(const char *context_kind);
+
+/**
+ * Same as the string-option functions, but options are passed as the codes above.
+ * Unknown codes return a RangeError instead of falling back to a default.
+ * An unset largest_unit takes the spec default for the type: "hour" for
+ * ZonedDateTime and PlainTime, "day" for PlainDate and PlainDateTime, "year" for
+ * PlainYearMonth and "second" for Instant.
+ */
+TemporalResult temporal_instant_until_coded(
+    const char *one_str, const char *two_str,
+    int32_t largest_unit, int32_t smallest_unit,
+    int64_t rounding_increment, int32_t rounding_mode
+);
+TemporalResult temporal_instant_since_coded(
+    const char *one_str, const char *two_str,
+    int32_t largest_unit, int32_t smallest_unit,
+    int64_t rounding_increment, int32_t rounding_mode
+);
+TemporalResult temporal_instant_round_coded(
+    const char *instant_str, int32_t smallest_unit,
+    int64_t rounding_increment, int32_t rounding_mode
+);
+TemporalResult temporal_plain_time_until_coded(
+    const char *one_str, const char *two_str,
+    int32_t largest_unit, int32_t smallest_unit,
+    int64_t rounding_increment, int32_t rounding_mode
+);
+TemporalResult temporal_plain_time_since_coded(
+    const char *one_str, const char *two_str,
+    int32_t largest_unit, int32_t smallest_unit,
+    int64_t rounding_increment, int32_t rounding_mode
+);
+TemporalResult temporal_plain_time_round_coded(
+    const char *time_str, int32_t smallest_unit,
+    int64_t rounding_increment, int32_t rounding_mode
+);
+TemporalResult temporal_plain_date_until_coded(
+    const char *one_str, const char *two_str,
+    int32_t largest_unit, int32_t smallest_unit,
+    int64_t rounding_increment, int32_t rounding_mode
+);
+TemporalResult temporal_plain_date_since_coded(
+    const char *one_str, const char *two_str,
+    int32_t largest_unit, int32_t smallest_unit,
+    int64_t rounding_increment, int32_t rounding_mode
+);
+TemporalResult temporal_plain_date_time_until_coded(
+    const char *one_str, const char *two_str,
+    int32_t largest_unit, int32_t smallest_unit,
+    int64_t rounding_increment, int32_t rounding_mode
+);
+TemporalResult temporal_plain_date_time_since_coded(
+    const char *one_str, const char *two_str,
+    int32_t largest_unit, int32_t smallest_unit,
+    int64_t rounding_increment, int32_t rounding_mode
+);
+TemporalResult temporal_plain_year_month_until_coded(
+    const char *one_str, const char *two_str,
+    int32_t largest_unit, int32_t smallest_unit,
+    int64_t rounding_increment, int32_t rounding_mode
+);
+TemporalResult temporal_plain_year_month_since_coded(
+    const char *one_str, const char *two_str,
+    int32_t largest_unit, int32_t smallest_unit,
+    int64_t rounding_increment, int32_t rounding_mode
+);
+TemporalResult temporal_zoned_date_time_until_coded(
+    const char *one_str, const char *two_str,
+    int32_t largest_unit, int32_t smallest_unit,
+    int64_t rounding_increment, int32_t rounding_mode
+);
+TemporalResult temporal_zoned_date_time_since_coded(
+    const char *one_str, const char *two_str,
+    int32_t largest_unit, int32_t smallest_unit,
+    int64_t rounding_increment, int32_t rounding_mode
+);
+TemporalResult temporal_zoned_date_time_round_coded(
+    const char *zdt_str, int32_t smallest_unit,
+    int64_t rounding_increment, int32_t rounding_mode
+);
+TemporalResult temporal_time_zone_get_instant_for_coded(const char *tz_id, const char *dt_str, int32_t disambiguation);
+
+// ============================================================================
+// Option structs
+// ============================================================================
+
+/**
+ * Per-call options passed by pointer. Set `size` to sizeof the struct; fields past
+ * it keep their defaults, so later versions can append fields without changing any
+ * signature. A size larger than the library knows is a RangeError, and any other
+ * size that is not sizeof some released version of the struct is a TypeError. NULL
+ * uses all defaults. Codes are those above; TEMPORAL_OPTION_UNSET selects the default.
+ */
+typedef struct {
+    uint32_t size;
+    int32_t disambiguation;      // TemporalDisambiguationCode, default compatible
+    int32_t offset;              // TemporalOffsetOptionCode, default reject
+    int8_t has_offset;           // non-zero if offset_nanoseconds is set
+    int64_t offset_nanoseconds;
+} ZdtFromOptions;
+
+typedef struct {
+    uint32_t size;
+    int32_t largest_unit;
+    int32_t smallest_unit;
+    int32_t rounding_mode;
+    int64_t rounding_increment;  // 0 is 1; negative is a RangeError
+} DiffOptions;
+
+typedef struct {
+    uint32_t size;
+    int32_t smallest_unit;       // required
+    int32_t rounding_mode;       // default halfExpand
+    int64_t rounding_increment;  // 0 is 1; negative is a RangeError
+} RoundOptions;
+
+/**
+ * Same as temporal_zoned_date_time_from_components (ISO fields), resolving DST
+ * gaps and overlaps with options->disambiguation and checking an explicit offset
+ * with options->offset.
+ */
+TemporalResult temporal_zoned_date_time_from_components_with_options(
+    int32_t year, uint8_t month, uint8_t day,
+    uint8_t hour, uint8_t minute, uint8_t second,
+    uint16_t millisecond, uint16_t microsecond, uint16_t nanosecond,
+    const char *calendar_id, const char *time_zone_id,
+    const ZdtFromOptions *options
+);
+
+/**
+ * Same as the coded until/since/round variants, with the options in a struct.
+ */
+TemporalResult temporal_instant_until_with_options(const char *one_str, const char *two_str, const DiffOptions *options);
+TemporalResult temporal_instant_since_with_options(const char *one_str, const char *two_str, const DiffOptions *options);
+TemporalResult temporal_plain_time_until_with_options(const char *one_str, const char *two_str, const DiffOptions *options);
+TemporalResult temporal_plain_time_since_with_options(const char *one_str, const char *two_str, const DiffOptions *options);
+TemporalResult temporal_plain_date_until_with_options(const char *one_str, const char *two_str, const DiffOptions *options);
+TemporalResult temporal_plain_date_since_with_options(const char *one_str, const char *two_str, const DiffOptions *options);
+TemporalResult temporal_plain_date_time_until_with_options(const char *one_str, const char *two_str, const DiffOptions *options);
+TemporalResult temporal_plain_date_time_since_with_options(const char *one_str, const char *two_str, const DiffOptions *options);
+TemporalResult temporal_plain_year_month_until_with_options(const char *one_str, const char *two_str, const DiffOptions *options);
+TemporalResult temporal_plain_year_month_since_with_options(const char *one_str, const char *two_str, const DiffOptions *options);
+TemporalResult temporal_zoned_date_time_until_with_options(const char *one_str, const char *two_str, const DiffOptions *options);
+TemporalResult temporal_zoned_date_time_since_with_options(const char *one_str, const char *two_str, const DiffOptions *options);
+TemporalResult temporal_instant_round_with_options(const char *instant_str, const RoundOptions *options);
+TemporalResult temporal_plain_time_round_with_options(const char *time_str, const RoundOptions *options);
+TemporalResult temporal_zoned_date_time_round_with_options(const char *zdt_str, const RoundOptions *options);
+
 // ============================================================================
 // TimeZone API
 // ============================================================================
 
-TemporalResult temporal_time_zone_from_string(const char *s);
+/**
+ * Gets a TimeZone from a string identifier. With lenient != 0 the id is trimmed
+ * and case-folded before lookup.
+ */
+TemporalResult temporal_time_zone_from_string(const char *s, int8_t lenient);
 TemporalResult temporal_time_zone_get_id(const char *s);
+/**
+ * Resolves links to the primary identifier ("US/Pacific" -> "America/Los_Angeles").
+ * With preserve_original != 0 the given identifier is kept, only case-normalized.
+ */
+TemporalResult temporal_time_zone_canonicalize(const char *id, int8_t preserve_original);
+/**
+ * Spec TimeZoneEquals: 1 if equal, 0 if not, -1 on error. Named zones compare by
+ * primary identifier ("UTC" == "Etc/UTC" == "GMT"); a named zone never equals an
+ * offset zone ("UTC" != "+00:00").
+ */
+int8_t temporal_time_zone_equals(const char *a, const char *b);
 TemporalResult temporal_time_zone_get_offset_nanoseconds_for(const char *tz_id, const char *instant_str);
 TemporalResult temporal_time_zone_get_offset_string_for(const char *tz_id, const char *instant_str);
 TemporalResult temporal_time_zone_get_plain_date_time_for(const char *tz_id, const char *instant_str, const char *calendar_id);
 TemporalResult temporal_time_zone_get_instant_for(const char *tz_id, const char *dt_str, const char *disambiguation);
+/**
+ * Interprets a PlainDateTime in a time zone and returns its epoch nanoseconds as a
+ * decimal string. disambiguation may be NULL ("compatible").
+ */
+TemporalResult temporal_plain_date_time_to_epoch_ns(const char *dt_str, const char *tz_id, const char *disambiguation);
+/**
+ * Compares a PlainDateTime interpreted in a time zone with an instant: -1, 0 or 1 as
+ * the local time is before, at or after it. disambiguation may be NULL ("compatible").
+ */
+CompareResult temporal_compare_pdt_in_zone_to_instant(
+    const char *dt_str, const char *tz_id,
+    const char *disambiguation, const char *instant_str
+);
 TemporalResult temporal_time_zone_get_next_transition(const char *tz_id, const char *instant_str);
 TemporalResult temporal_time_zone_get_previous_transition(const char *tz_id, const char *instant_str);
 
+#define TEMPORAL_SECONDS_UNTIL_TRANSITION_ERROR INT64_MIN
+
+/**
+ * Whole seconds from instant_str until the next UTC offset change in tz_id, or -1
+ * if there is none. Returns TEMPORAL_SECONDS_UNTIL_TRANSITION_ERROR on error (see
+ * temporal_last_error_type/message).
+ */
+int64_t temporal_time_zone_seconds_until_next_transition(const char *tz_id, const char *instant_str);
+
+/**
+ * Localized time zone name, e.g. "Pacific Standard Time", "PT", "GMT+2".
+ * style is "long", "short", "longGeneric", "shortGeneric", "longOffset" or
+ * "shortOffset" (as in Intl.DateTimeFormat's timeZoneName). locale is a BCP 47
+ * tag (NULL = "en"); instant selects standard vs daylight time (NULL = now).
+ */
+TemporalResult temporal_time_zone_display_name(const char *tz_id, const char *locale,
+                                               const char *style, const char *instant_str);
+
+/**
+ * Offsets used by the zone in an ISO year. First line: distinct offsets,
+ * comma-separated ("+01:00,+02:00"); following lines: each transition in the
+ * year as a ZonedDateTime string carrying the new offset.
+ */
+TemporalResult temporal_time_zone_offsets_for_year(const char *tz_id, int32_t year);
+
+/**
+ * Details returned with a time zone abbreviation. The offset is exact; the
+ * abbreviation is not.
+ */
+typedef struct {
+    int64_t offset_nanoseconds;  // UTC offset in effect
+    int8_t is_dst;               // 1 on daylight saving time
+    int8_t non_authoritative;    // 1 if the tz database has no abbreviation in use
+                                 // and the string just restates the offset ("+0545")
+} TimeZoneAbbreviationInfo;
+
+/**
+ * The tz database abbreviation for a zone at an instant ("PST", "CEST"), for logs
+ * and compact UIs. instant_str NULL means now; info may be NULL. Abbreviations are
+ * ambiguous ("IST" is India, Ireland and Israel): display them, never parse them.
+ * Fixed-offset zones return the numeric form with non_authoritative set.
+ */
+TemporalResult temporal_time_zone_abbreviation(const char *tz_id, const char *instant_str,
+                                               TimeZoneAbbreviationInfo *info);
+
 // ============================================================================
 // ZonedDateTime API
 // ============================================================================
@@ -499,23 +1492,108 @@ typedef struct {
     uint16_t microsecond;
     uint16_t nanosecond;
     int64_t offset_nanoseconds;
+    int8_t has_week_info;
     int8_t is_valid;
 } ZonedDateTimeComponents;
 
 TemporalResult temporal_zoned_date_time_from_string(const char *s);
+/**
+ * Formats with an explicit precision (see temporal_instant_to_string) and
+ * calendar_name (see temporal_plain_date_to_string); offset and time zone follow
+ * the default display options. NULL uses the defaults.
+ */
+TemporalResult temporal_zoned_date_time_to_string(const char *s, const char *precision,
+                                                  const char *calendar_name);
 TemporalResult temporal_zoned_date_time_from_components(
     int32_t year, uint8_t month, uint8_t day,
     uint8_t hour, uint8_t minute, uint8_t second,
     uint16_t millisecond, uint16_t microsecond, uint16_t nanosecond,
     const char *calendar_id, const char *time_zone_id, int64_t offset_nanoseconds
 );
+TemporalResult temporal_zoned_date_time_from_epoch_ms(int64_t ms, const char *time_zone_id, const char *calendar_id);
+TemporalResult temporal_zoned_date_time_from_epoch_ns(const char *ns_str, const char *time_zone_id, const char *calendar_id);
+TemporalResult temporal_zoned_date_time_from_epoch_ns_parts(int64_t high, uint64_t low, const char *time_zone_id, const char *calendar_id);
 void temporal_zoned_date_time_get_components(const char *s, ZonedDateTimeComponents *out);
+
+/**
+ * Wall-clock time and offset only, for clocks that refresh every second.
+ */
+typedef struct {
+    int64_t offset_nanoseconds;
+    uint8_t hour;
+    uint8_t minute;
+    uint8_t second;
+    int8_t is_valid;
+} ClockFields;
+
+/**
+ * Fills out with hour/minute/second/offset without calendar computations.
+ * With tz_id NULL, s is a ZonedDateTime string; otherwise s is epoch nanoseconds
+ * (decimal string) interpreted in tz_id. is_valid is 0 on error.
+ */
+void temporal_zoned_date_time_clock_fields(const char *s, const char *tz_id, ClockFields *out);
+
+/**
+ * withTimeZone from a stored epoch: components of epoch_ns_str (decimal string) in
+ * to_tz and calendar_id (NULL = iso8601), without building or parsing a source
+ * ZonedDateTime. is_valid is 0 on error.
+ */
+void temporal_convert_zone(const char *epoch_ns_str, const char *to_tz, const char *calendar_id, ZonedDateTimeComponents *out);
+
+/**
+ * Local components of one instant in each zone of a newline-delimited tz_ids list
+ * (ISO calendar), for world-clock views. Entry i belongs to the i-th non-blank id and
+ * has is_valid 0 if the id does not resolve. Returns the number of ids; at most
+ * out_len entries are written. Returns 0 with the last error set for a NULL,
+ * non-UTF-8 or too long list (see temporal_last_error_*).
+ */
+size_t temporal_world_clock(int64_t epoch_ns, const char *tz_ids, ZonedDateTimeComponents *out, size_t out_len);
+
+/**
+ * Milliseconds from epoch_ms until the local clock in tz_id next reaches a whole
+ * unit ("second", "minute", "hour" or "day"). Offset transitions count as
+ * boundaries; days follow the local start of day across DST.
+ * Returns -1 on error (see temporal_last_error_type/message).
+ */
+int64_t temporal_ms_until_next_boundary(int64_t epoch_ms, const char *tz_id, const char *unit);
+
+/**
+ * Start (epoch ms) of the bucket_minutes (1-1440) bucket containing epoch_ms on the
+ * local clock in tz_id, counted from local midnight, for chart aggregation. Returns
+ * TEMPORAL_BUCKET_ERROR on error (see temporal_last_error_type/message).
+ */
+#define TEMPORAL_BUCKET_ERROR INT64_MIN
+int64_t temporal_bucket_epoch_ms(int64_t epoch_ms, const char *tz_id, uint32_t bucket_minutes);
+
+/**
+ * Buckets len values at once; out may equal epoch_ms for in-place use. Returns 0,
+ * or -1 on error with the failing index in the last error message.
+ */
+int32_t temporal_bucket_epoch_ms_batch(
+    const int64_t *epoch_ms, size_t len, const char *tz_id, uint32_t bucket_minutes, int64_t *out
+);
 TemporalResult temporal_zoned_date_time_epoch_milliseconds(const char *s);
 TemporalResult temporal_zoned_date_time_epoch_nanoseconds(const char *s);
+void temporal_zoned_date_time_epoch_nanoseconds_parts(const char *s, EpochNanosecondsParts *out);
+/**
+ * Fixed-width (23 digit) key that sorts lexicographically in instant order, for
+ * ORDER BY on persisted values. Time zone and calendar are ignored.
+ */
+TemporalResult temporal_zoned_date_time_sort_key(const char *s);
 TemporalResult temporal_zoned_date_time_get_calendar(const char *s);
 TemporalResult temporal_zoned_date_time_get_time_zone(const char *s);
 TemporalResult temporal_zoned_date_time_get_offset(const char *s);
-TemporalResult temporal_zoned_date_time_add(const char *zdt_str, const char *duration_str);
+TemporalResult temporal_zoned_date_time_get_month_code(const char *s);
+/**
+ * Era and era year (decimal string); both are empty when the calendar has no eras.
+ */
+TemporalResult temporal_zoned_date_time_get_era(const char *s);
+TemporalResult temporal_zoned_date_time_get_era_year(const char *s);
+/**
+ * arithmetic is "calendar" (days keep the wall-clock time across DST) or "exact"
+ * (days are 24 elapsed hours; years, months and weeks are rejected). NULL = "calendar".
+ */
+TemporalResult temporal_zoned_date_time_add(const char *zdt_str, const char *duration_str, const char *arithmetic);
 TemporalResult temporal_zoned_date_time_subtract(const char *zdt_str, const char *duration_str);
 CompareResult temporal_zoned_date_time_compare(const char *a, const char *b);
 TemporalResult temporal_zoned_date_time_with(
@@ -526,8 +1604,22 @@ TemporalResult temporal_zoned_date_time_with(
     int64_t offset_ns,
     const char *calendar_id, const char *time_zone_id
 );
-TemporalResult temporal_zoned_date_time_until(const char *one_str, const char *two_str);
-TemporalResult temporal_zoned_date_time_since(const char *one_str, const char *two_str);
+TemporalResult temporal_zoned_date_time_until(
+    const char *one_str,
+    const char *two_str,
+    const char *largest_unit,
+    const char *smallest_unit,
+    int64_t rounding_increment,
+    const char *rounding_mode
+);
+TemporalResult temporal_zoned_date_time_since(
+    const char *one_str,
+    const char *two_str,
+    const char *largest_unit,
+    const char *smallest_unit,
+    int64_t rounding_increment,
+    const char *rounding_mode
+);
 TemporalResult temporal_zoned_date_time_round(
     const char *zdt_str,
     const char *smallest_unit,
@@ -539,6 +1631,32 @@ TemporalResult temporal_zoned_date_time_to_plain_date(const char *s);
 TemporalResult temporal_zoned_date_time_to_plain_time(const char *s);
 TemporalResult temporal_zoned_date_time_to_plain_date_time(const char *s);
 
+/**
+ * First instant / last nanosecond of the "day", "week", "month", or "year"
+ * containing the ZonedDateTime, using the zone's actual start of day (DST-aware).
+ */
+TemporalResult temporal_zoned_date_time_start_of(const char *s, const char *unit, uint8_t first_day_of_week);
+TemporalResult temporal_zoned_date_time_end_of(const char *s, const char *unit, uint8_t first_day_of_week);
+
+/**
+ * Returns the overlap of [start, end) with working hours as a Duration string.
+ * Working hours are work_start..work_end (PlainTime strings) in the start's time
+ * zone on every day whose ISO weekday bit is clear in weekend_mask
+ * (bit 0 = Monday ... bit 6 = Sunday; 0x60 = Saturday and Sunday).
+ * Ranges of 36525 days or more are a RangeError.
+ */
+TemporalResult temporal_working_time_between(const char *start, const char *end,
+                                             const char *work_start, const char *work_end,
+                                             uint8_t weekend_mask);
+
+/**
+ * Splits [start, end] at each local start of day in start's time zone.
+ * Returns newline-separated ZonedDateTimes: start, every start of day strictly
+ * between, and end in start's time zone. Consecutive lines bound one day's segment.
+ * Ranges of 36525 days or more are a RangeError.
+ */
+TemporalResult temporal_split_range_by_local_days(const char *start, const char *end);
+
 #ifdef __cplusplus
 
 }
@@ -0,0 +1,84 @@
+//! Builds the library once per feature set and prints the size of each release
+//! artifact, so the effect of a cargo feature on binary size can be measured
+//! instead of guessed. Extra arguments are passed to `cargo build`:
+//!
+//! ```sh
+//! cargo run --example size_report
+//! cargo run --example size_report -- --profile release-mobile --target aarch64-linux-android
+//! ```
+//!
+//! Sizes are of the unstripped artifacts; the app's own link step strips and
+//! dead-strips further, so compare rows rather than reading them as shipped sizes.
+
+use std::path::{Path, PathBuf};
+use std::process::{exit, Command};
+
+/// (label, cargo feature arguments)
+const CONFIGS: [(&str, &[&str]); 5] = [
+    ("default", &[]),
+    ("-jni", &["--no-default-features", "--features", "custom-calendars"]),
+    ("-custom-calendars", &["--no-default-features", "--features", "jni"]),
+    ("+locale-data", &["--features", "locale-data"]),
+    ("none", &["--no-default-features"]),
+];
+
+fn main() {
+    let extra: Vec<String> = std::env::args().skip(1).collect();
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let target_dir = manifest_dir.join("target").join("size-report");
+    let mut out_dir = target_dir.clone();
+    if let Some(index) = extra.iter().position(|arg| arg == "--target") {
+        match extra.get(index + 1) {
+            Some(triple) => out_dir.push(triple),
+            None => fail("--target needs a value"),
+        }
+    }
+    let profile = match extra.iter().position(|arg| arg == "--profile") {
+        Some(index) => extra.get(index + 1).cloned().unwrap_or_else(|| fail("--profile needs a value")),
+        None => "release".to_string(),
+    };
+    out_dir.push(&profile);
+
+    println!("{:<20} {:>20} {:>20}", "features", "cdylib", "staticlib");
+    let mut baseline = None;
+    for (label, features) in CONFIGS {
+        let status = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".into()))
+            .current_dir(manifest_dir)
+            .args(["build", "--lib", "--quiet", "--target-dir"])
+            .arg(&target_dir)
+            .args(if extra.iter().any(|arg| arg == "--profile") { None } else { Some("--release") })
+            .args(features)
+            .args(&extra)
+            .status()
+            .unwrap_or_else(|e| fail(&format!("failed to run cargo: {}", e)));
+        if !status.success() {
+            fail(&format!("build for '{}' failed", label));
+        }
+        let sizes = (artifact_size(&out_dir, &["libtemporal_rn.so", "libtemporal_rn.dylib"]), artifact_size(&out_dir, &["libtemporal_rn.a"]));
+        let base = *baseline.get_or_insert(sizes);
+        println!("{:<20} {:>20} {:>20}", label, describe(sizes.0, base.0), describe(sizes.1, base.1));
+    }
+}
+
+fn artifact_size(dir: &Path, names: &[&str]) -> Option<u64> {
+    names
+        .iter()
+        .map(|name| dir.join(name))
+        .find_map(|path: PathBuf| std::fs::metadata(path).ok())
+        .map(|metadata| metadata.len())
+}
+
+/// "12.3 MB" for the baseline row, "-1.2 MB" relative to it for the others.
+fn describe(size: Option<u64>, baseline: Option<u64>) -> String {
+    let mb = |bytes: f64| format!("{:.1} MB", bytes / 1_000_000.0);
+    match (size, baseline) {
+        (None, _) => "-".to_string(),
+        (Some(size), Some(base)) if size != base => format!("{} ({:+.1})", mb(size as f64), (size as f64 - base as f64) / 1_000_000.0),
+        (Some(size), _) => mb(size as f64),
+    }
+}
+
+fn fail(message: &str) -> ! {
+    eprintln!("size_report: {}", message);
+    exit(1);
+}
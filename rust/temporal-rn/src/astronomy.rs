@@ -0,0 +1,245 @@
+//! Helpers for astronomy and prayer-time features: Julian day numbers and local
+//! mean solar time.
+//!
+//! The Julian day number (JDN) of a date is the count of days since noon UT on
+//! 1 January 4713 BC in the proleptic Julian calendar; the Julian date (JD) adds the
+//! time of day as a fraction, so a day runs from JD n - 0.5 to n + 0.5. All
+//! Julian conversions treat wall-clock fields as UT, without a time zone.
+//!
+//! Local mean time (LMT) at a longitude is UT shifted by 4 minutes per degree,
+//! east positive, so mean solar noon falls at 12:00 LMT. It ignores the equation
+//! of time; apparent solar time differs from it by up to about 16 minutes.
+
+use std::ffi::c_char;
+use std::str::FromStr;
+
+use temporal_rs::options::Disambiguation;
+use temporal_rs::{Calendar, Duration, PlainDate, PlainDateTime, PlainTime, TemporalError, TimeZone, ZonedDateTime};
+
+use crate::display;
+use crate::{
+    parse_c_str, parse_plain_date, parse_plain_date_time, parse_time_zone, parse_zoned_date_time, TemporalResult,
+};
+
+/// Julian day number of 1970-01-01.
+const UNIX_EPOCH_JDN: i64 = 2_440_588;
+const NS_PER_DAY: i64 = 86_400_000_000_000;
+const MS_PER_DAY: f64 = 86_400_000.0;
+/// The mean sun crosses one degree of longitude every 4 minutes.
+const NS_PER_DEGREE: f64 = 240_000_000_000.0;
+
+/// Returns the Julian day number of a PlainDate as a decimal string, e.g. "2460325"
+/// for 2024-01-15. The date's calendar does not matter.
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_to_julian_day(date: *const c_char) -> TemporalResult {
+    let date = match parse_plain_date(date, "plain date") {
+        Ok(d) => d,
+        Err(e) => return e,
+    };
+    TemporalResult::success(julian_day_number(&date).to_string())
+}
+
+/// Creates a PlainDate from a Julian day number. A null calendar_id defaults to iso8601.
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_from_julian_day(jdn: i64, calendar_id: *const c_char) -> TemporalResult {
+    let calendar = match parse_calendar(calendar_id) {
+        Ok(c) => c,
+        Err(e) => return e,
+    };
+    match date_from_julian_day(jdn) {
+        Ok(date) => TemporalResult::success(date.with_calendar(calendar).to_ixdtf_string(display::calendar())),
+        Err(e) => TemporalResult::range_error(&format!("Invalid Julian day {}: {}", jdn, e)),
+    }
+}
+
+/// Returns the Julian date of a PlainDateTime as a decimal string, e.g. "2460325.25"
+/// for 2024-01-15T18:00. The value is an f64, so it resolves to tens of microseconds.
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_time_to_julian_date(dt: *const c_char) -> TemporalResult {
+    let dt = match parse_plain_date_time(dt, "plain date time") {
+        Ok(d) => d,
+        Err(e) => return e,
+    };
+    let ns_of_day = ((dt.hour() as i64 * 60 + dt.minute() as i64) * 60 + dt.second() as i64) * 1_000_000_000
+        + dt.millisecond() as i64 * 1_000_000
+        + dt.microsecond() as i64 * 1_000
+        + dt.nanosecond() as i64;
+    let jd = julian_day_number(&dt.to_plain_date()) as f64 - 0.5 + ns_of_day as f64 / NS_PER_DAY as f64;
+    TemporalResult::success(jd.to_string())
+}
+
+/// Creates a PlainDateTime from a Julian date, rounded to the nearest millisecond
+/// (the precision an f64 Julian date carries). A null calendar_id defaults to iso8601.
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_time_from_julian_date(jd: f64, calendar_id: *const c_char) -> TemporalResult {
+    let calendar = match parse_calendar(calendar_id) {
+        Ok(c) => c,
+        Err(e) => return e,
+    };
+    if !jd.is_finite() {
+        return TemporalResult::range_error(&format!("Invalid Julian date: {}", jd));
+    }
+
+    let days = jd + 0.5;
+    let mut jdn = days.floor();
+    let mut ms_of_day = ((days - jdn) * MS_PER_DAY).round();
+    if ms_of_day >= MS_PER_DAY {
+        jdn += 1.0;
+        ms_of_day = 0.0;
+    }
+    let ms_of_day = ms_of_day as i64;
+    let time = PlainTime::try_new(
+        (ms_of_day / 3_600_000) as u8,
+        (ms_of_day / 60_000 % 60) as u8,
+        (ms_of_day / 1_000 % 60) as u8,
+        (ms_of_day % 1_000) as u16,
+        0,
+        0,
+    );
+    let dt = time.and_then(|time| date_from_julian_day(jdn as i64)?.to_plain_date_time(Some(time)));
+    match dt {
+        Ok(dt) => match dt.with_calendar(calendar).to_ixdtf_string(display::rounding(), display::calendar()) {
+            Ok(s) => TemporalResult::success(s),
+            Err(e) => TemporalResult::range_error(&format!("Failed to format plain date time: {}", e)),
+        },
+        Err(e) => TemporalResult::range_error(&format!("Invalid Julian date {}: {}", jd, e)),
+    }
+}
+
+/// Returns the offset of local mean time from UT at a longitude (degrees, east
+/// positive, -180 to 180) as a Duration string, e.g. "PT1H" for 15 and
+/// "-PT4H56M1.44S" for -74.006.
+#[no_mangle]
+pub extern "C" fn temporal_solar_mean_time_offset(longitude: f64) -> TemporalResult {
+    match mean_time_offset(longitude) {
+        Ok(offset) => TemporalResult::success(offset.to_string()),
+        Err(e) => e,
+    }
+}
+
+/// Converts a ZonedDateTime to the local mean time at a longitude, returned as a
+/// PlainDateTime in the ZonedDateTime's calendar. The zone itself only fixes the instant.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_to_local_mean_time(zdt: *const c_char, longitude: f64) -> TemporalResult {
+    let zdt = match parse_zoned_date_time(zdt, "zoned date time") {
+        Ok(z) => z,
+        Err(e) => return e,
+    };
+    let offset = match mean_time_offset(longitude) {
+        Ok(o) => o,
+        Err(e) => return e,
+    };
+    let local = ZonedDateTime::try_new(zdt.epoch_nanoseconds().as_i128(), TimeZone::utc(), zdt.calendar().clone())
+        .and_then(|utc| utc.to_plain_date_time().add(&offset, None));
+    match local {
+        Ok(dt) => match dt.to_ixdtf_string(display::rounding(), display::calendar()) {
+            Ok(s) => TemporalResult::success(s),
+            Err(e) => TemporalResult::range_error(&format!("Failed to format plain date time: {}", e)),
+        },
+        Err(e) => TemporalResult::range_error(&format!("Failed to convert to local mean time: {}", e)),
+    }
+}
+
+/// Converts a PlainDateTime in local mean time at a longitude to a ZonedDateTime in
+/// the given time zone (the local standard time of the same instant).
+#[no_mangle]
+pub extern "C" fn temporal_local_mean_time_to_zoned_date_time(
+    dt: *const c_char,
+    longitude: f64,
+    tz_id: *const c_char,
+) -> TemporalResult {
+    let dt = match parse_plain_date_time(dt, "plain date time") {
+        Ok(d) => d,
+        Err(e) => return e,
+    };
+    let tz = match parse_time_zone(tz_id, "time zone") {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    mean_time_to_zoned(&dt, longitude, tz)
+}
+
+/// Returns the instant of mean solar noon (12:00 local mean time) at a longitude on
+/// a PlainDate, as a ZonedDateTime in the given time zone.
+#[no_mangle]
+pub extern "C" fn temporal_solar_mean_noon(date: *const c_char, longitude: f64, tz_id: *const c_char) -> TemporalResult {
+    let date = match parse_plain_date(date, "plain date") {
+        Ok(d) => d,
+        Err(e) => return e,
+    };
+    let tz = match parse_time_zone(tz_id, "time zone") {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    let noon = PlainTime::try_new(12, 0, 0, 0, 0, 0).and_then(|noon| date.to_plain_date_time(Some(noon)));
+    match noon {
+        Ok(noon) => mean_time_to_zoned(&noon, longitude, tz),
+        Err(e) => TemporalResult::range_error(&format!("Invalid date for solar noon: {}", e)),
+    }
+}
+
+/// The LMT - UT offset as a balanced time Duration, rounded to the nanosecond.
+fn mean_time_offset(longitude: f64) -> Result<Duration, TemporalResult> {
+    if !(-180.0..=180.0).contains(&longitude) {
+        return Err(TemporalResult::range_error(&format!(
+            "Invalid longitude: {} (must be between -180 and 180)",
+            longitude
+        )));
+    }
+    let total = (longitude * NS_PER_DEGREE).round() as i64;
+    let sign = total.signum();
+    let ns = total.abs();
+    let component = |unit: i64, modulus: i64| sign * (ns / unit % modulus);
+    Duration::new(
+        0,
+        0,
+        0,
+        0,
+        sign * (ns / 3_600_000_000_000),
+        component(60_000_000_000, 60),
+        component(1_000_000_000, 60),
+        component(1_000_000, 1_000),
+        component(1_000, 1_000) as i128,
+        component(1, 1_000) as i128,
+    )
+    .map_err(|e| TemporalResult::range_error(&format!("Invalid longitude {}: {}", longitude, e)))
+}
+
+fn mean_time_to_zoned(dt: &PlainDateTime, longitude: f64, tz: TimeZone) -> TemporalResult {
+    let offset = match mean_time_offset(longitude) {
+        Ok(o) => o,
+        Err(e) => return e,
+    };
+    let zdt = dt
+        .subtract(&offset, None)
+        .and_then(|ut| ut.to_zoned_date_time(TimeZone::utc(), Disambiguation::Compatible))
+        .and_then(|ut| ZonedDateTime::try_new(ut.epoch_nanoseconds().as_i128(), tz, dt.calendar().clone()));
+    match zdt {
+        Ok(zdt) => match zdt.to_ixdtf_string(display::offset(), display::time_zone(), display::calendar(), display::rounding()) {
+            Ok(s) => TemporalResult::success(s),
+            Err(e) => TemporalResult::range_error(&format!("Failed to format zoned date time: {}", e)),
+        },
+        Err(e) => TemporalResult::range_error(&format!("Failed to convert from local mean time: {}", e)),
+    }
+}
+
+fn julian_day_number(date: &PlainDate) -> i64 {
+    // epoch_ns_for_utc is taken at noon, so floor division gives the day
+    date.epoch_ns_for_utc().0.div_euclid(NS_PER_DAY as i128) as i64 + UNIX_EPOCH_JDN
+}
+
+fn date_from_julian_day(jdn: i64) -> Result<PlainDate, TemporalError> {
+    let days = jdn
+        .checked_sub(UNIX_EPOCH_JDN)
+        .ok_or_else(|| TemporalError::range().with_message("Julian day out of range"))?;
+    let offset = Duration::new(0, 0, 0, days, 0, 0, 0, 0, 0, 0)?;
+    PlainDate::try_new_iso(1970, 1, 1)?.add(&offset, None)
+}
+
+fn parse_calendar(calendar_id: *const c_char) -> Result<Calendar, TemporalResult> {
+    if calendar_id.is_null() {
+        return Ok(Calendar::default());
+    }
+    let id = parse_c_str(calendar_id, "calendar id")?;
+    Calendar::from_str(id).map_err(|e| TemporalResult::range_error(&format!("Invalid calendar: {}", e)))
+}
@@ -0,0 +1,198 @@
+//! Bounded cache from input string to normalized output, per Temporal type.
+//!
+//! Apps re-render the same few values over and over, so the `*_from_string` entry
+//! points and `temporal_batch_parse` look an input up here before parsing and
+//! formatting it. Only successful results are cached. Each type keeps its most recent
+//! `CAPACITY` inputs and drops the oldest first.
+//!
+//! A cache is emptied when the time zone generation moves, like every other cache
+//! derived from time zone state (see `temporal_notify_tz_changed`).
+//!
+//! Hit, miss and eviction counts are kept per type for
+//! `temporal_canonical_cache_stats`, to judge whether the cache pays for itself.
+
+use std::collections::{BTreeMap, VecDeque};
+use std::sync::{Mutex, MutexGuard};
+
+use crate::json::JsonValue;
+use crate::{temporal_tz_generation, TemporalResult};
+
+/// Inputs kept per type.
+pub(crate) const CAPACITY: usize = 64;
+
+/// Inputs longer than this are normalized without being cached; canonical Temporal
+/// strings are far shorter.
+const MAX_KEY_LENGTH: usize = 128;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum Kind {
+    Instant,
+    PlainDate,
+    PlainTime,
+    PlainDateTime,
+    PlainYearMonth,
+    PlainMonthDay,
+    ZonedDateTime,
+    Duration,
+}
+
+const KINDS: [Kind; 8] = [
+    Kind::Instant,
+    Kind::PlainDate,
+    Kind::PlainTime,
+    Kind::PlainDateTime,
+    Kind::PlainYearMonth,
+    Kind::PlainMonthDay,
+    Kind::ZonedDateTime,
+    Kind::Duration,
+];
+
+impl Kind {
+    /// The kind names of `temporal_batch_parse` and `temporal_validate`.
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            Kind::Instant => "Instant",
+            Kind::PlainDate => "PlainDate",
+            Kind::PlainTime => "PlainTime",
+            Kind::PlainDateTime => "PlainDateTime",
+            Kind::PlainYearMonth => "PlainYearMonth",
+            Kind::PlainMonthDay => "PlainMonthDay",
+            Kind::ZonedDateTime => "ZonedDateTime",
+            Kind::Duration => "Duration",
+        }
+    }
+
+    pub(crate) fn from_name(name: &str) -> Option<Kind> {
+        KINDS.into_iter().find(|kind| kind.name() == name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct Stats {
+    /// Every lookup, including inputs too long to be cached.
+    pub(crate) calls: u64,
+    pub(crate) hits: u64,
+    pub(crate) misses: u64,
+    pub(crate) evictions: u64,
+    /// Times the cache was emptied because the time zone generation moved.
+    pub(crate) resets: u64,
+    pub(crate) entries: usize,
+}
+
+pub(crate) struct Cache {
+    entries: BTreeMap<String, String>,
+    /// Keys in insertion order, oldest first.
+    order: VecDeque<String>,
+    /// The time zone generation the entries were formatted under.
+    built_for: Option<u64>,
+    stats: Stats,
+}
+
+impl Cache {
+    pub(crate) const fn new() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+            order: VecDeque::new(),
+            built_for: None,
+            stats: Stats { calls: 0, hits: 0, misses: 0, evictions: 0, resets: 0, entries: 0 },
+        }
+    }
+
+    /// Empties the cache if it was filled under another time zone generation.
+    pub(crate) fn revalidate(&mut self, current: u64) {
+        if self.built_for != Some(current) {
+            if self.built_for.is_some() {
+                self.stats.resets += 1;
+            }
+            self.entries.clear();
+            self.order.clear();
+            self.built_for = Some(current);
+        }
+    }
+
+    pub(crate) fn stats(&self) -> Stats {
+        Stats { entries: self.entries.len(), ..self.stats }
+    }
+
+    /// Looks `key` up, counting a hit or a miss.
+    pub(crate) fn get(&mut self, key: &str) -> Option<String> {
+        let hit = self.entries.get(key).cloned();
+        match hit {
+            Some(_) => self.stats.hits += 1,
+            None => self.stats.misses += 1,
+        }
+        hit
+    }
+
+    /// Adds `key`, first dropping the oldest entry if the cache is full.
+    pub(crate) fn insert(&mut self, key: &str, value: String) {
+        if self.entries.len() >= CAPACITY {
+            if let Some(oldest) = self.order.pop_front() {
+                self.entries.remove(&oldest);
+                self.stats.evictions += 1;
+            }
+        }
+        if self.entries.insert(key.to_string(), value).is_none() {
+            self.order.push_back(key.to_string());
+        }
+    }
+}
+
+static CACHES: [Mutex<Cache>; KINDS.len()] = [const { Mutex::new(Cache::new()) }; KINDS.len()];
+
+fn lock(kind: Kind) -> MutexGuard<'static, Cache> {
+    CACHES[kind as usize].lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Returns the normalized form of `input` as `kind`, from the cache or else from
+/// `normalize`, whose successful result is cached. The lock is not held while
+/// `normalize` runs.
+pub(crate) fn canonical<E>(kind: Kind, input: &str, normalize: impl FnOnce() -> Result<String, E>) -> Result<String, E> {
+    if input.len() > MAX_KEY_LENGTH {
+        lock(kind).stats.calls += 1;
+        return normalize();
+    }
+    let current = temporal_tz_generation();
+    {
+        let mut cache = lock(kind);
+        cache.stats.calls += 1;
+        cache.revalidate(current);
+        if let Some(hit) = cache.get(input) {
+            return Ok(hit);
+        }
+    }
+    let value = normalize()?;
+    let mut cache = lock(kind);
+    // The generation moved while normalizing: the value may already be stale
+    if cache.built_for == Some(current) {
+        cache.insert(input, value.clone());
+    }
+    Ok(value)
+}
+
+/// Counters and current size of each type's cache.
+pub(crate) fn stats() -> Vec<(Kind, Stats)> {
+    KINDS.into_iter().map(|kind| (kind, lock(kind).stats())).collect()
+}
+
+/// Returns the canonical value cache counters as JSON, one object per type:
+/// `{"Instant":{"hits":3,"misses":1,"evictions":0,"resets":0,"entries":1},...}`.
+/// Counters run from process start; `entries` is the current size, at most 64.
+#[no_mangle]
+pub extern "C" fn temporal_canonical_cache_stats() -> TemporalResult {
+    let number = |n: u64| JsonValue::Number(n.to_string());
+    let kinds = stats()
+        .into_iter()
+        .map(|(kind, stats)| {
+            let fields = vec![
+                ("hits".to_string(), number(stats.hits)),
+                ("misses".to_string(), number(stats.misses)),
+                ("evictions".to_string(), number(stats.evictions)),
+                ("resets".to_string(), number(stats.resets)),
+                ("entries".to_string(), number(stats.entries as u64)),
+            ];
+            (kind.name().to_string(), JsonValue::Object(fields))
+        })
+        .collect();
+    TemporalResult::success(JsonValue::Object(kinds).to_string())
+}
@@ -0,0 +1,139 @@
+//! Concurrency stress test for the FFI entry points.
+//!
+//! Every entry point may be called from any thread at once. Worker threads hammer
+//! parsing, formatting and zone conversion with the same inputs and compare against
+//! results computed on one thread first, so a race in shared state (the time zone
+//! provider, formatter handles, a future cache) shows up as a mismatch or a panic.
+//! Run it under ThreadSanitizer to catch races that happen not to change a result:
+//!
+//! ```sh
+//! RUSTFLAGS=-Zsanitizer=thread cargo +nightly test -Zbuild-std --target x86_64-unknown-linux-gnu concurrency_tests
+//! ```
+
+use std::ffi::{CStr, CString};
+use std::sync::{Arc, Barrier};
+use std::thread;
+
+use crate::formatter::{temporal_formatter_create, temporal_formatter_destroy, temporal_formatter_format};
+use crate::*;
+
+const THREADS: usize = 8;
+const ROUNDS: usize = 50;
+
+const ZONES: [&str; 6] = ["UTC", "Europe/Paris", "America/New_York", "Asia/Kolkata", "Australia/Lord_Howe", "+05:45"];
+const INSTANTS: [&str; 4] = [
+    "2024-03-10T07:30:00Z",
+    "2024-10-27T00:59:59.999999999Z",
+    "1969-12-31T23:59:59Z",
+    "2038-01-19T03:14:08Z",
+];
+
+fn take(mut result: TemporalResult) -> Result<String, String> {
+    let read = |s: *mut c_char| unsafe { CStr::from_ptr(s) }.to_string_lossy().into_owned();
+    let value = if result.error_type == TemporalErrorType::None as i32 {
+        Ok(read(result.value))
+    } else {
+        Err(read(result.error_message))
+    };
+    unsafe { temporal_free_result(&mut result) };
+    value
+}
+
+/// One pass over every input; the output must not depend on the calling thread.
+fn workload() -> Vec<String> {
+    let mut out = Vec::new();
+    let formatter = if cfg!(feature = "locale-data") {
+        let locale = CString::new("en").unwrap();
+        let options = CString::new(r#"{ "dateStyle": "medium", "timeStyle": "short" }"#).unwrap();
+        let handle = temporal_formatter_create(locale.as_ptr(), options.as_ptr());
+        assert_ne!(handle, 0);
+        Some(handle)
+    } else {
+        None
+    };
+    for instant in INSTANTS {
+        let instant = CString::new(instant).unwrap();
+        let epoch_ns = take(temporal_instant_epoch_nanoseconds(instant.as_ptr())).unwrap();
+        let epoch_ns = CString::new(epoch_ns).unwrap();
+        for zone in ZONES {
+            let zone = CString::new(zone).unwrap();
+            let zdt = take(temporal_instant_to_zoned_date_time(instant.as_ptr(), ptr::null(), zone.as_ptr())).unwrap();
+            let zdt_c = CString::new(zdt.clone()).unwrap();
+            out.push(take(temporal_zoned_date_time_from_string(zdt_c.as_ptr())).unwrap());
+            out.push(take(temporal_zoned_date_time_to_instant(zdt_c.as_ptr())).unwrap());
+            if let Some(formatter) = formatter {
+                out.push(take(temporal_formatter_format(formatter, zdt_c.as_ptr())).unwrap());
+            }
+
+            let mut components = ZonedDateTimeComponents::default();
+            temporal_convert_zone(epoch_ns.as_ptr(), zone.as_ptr(), ptr::null(), &mut components);
+            assert_eq!(components.is_valid, 1);
+            out.push(format!(
+                "{}-{}-{}T{}:{}:{} {}",
+                components.year,
+                components.month,
+                components.day,
+                components.hour,
+                components.minute,
+                components.second,
+                components.offset_nanoseconds
+            ));
+        }
+    }
+    if let Some(formatter) = formatter {
+        assert_eq!(temporal_formatter_destroy(formatter), 1);
+    }
+    out
+}
+
+#[test]
+fn entry_points_give_the_same_results_on_every_thread() {
+    let expected = Arc::new(workload());
+    let barrier = Arc::new(Barrier::new(THREADS));
+    let workers: Vec<_> = (0..THREADS)
+        .map(|_| {
+            let expected = Arc::clone(&expected);
+            let barrier = Arc::clone(&barrier);
+            thread::spawn(move || {
+                barrier.wait();
+                for _ in 0..ROUNDS {
+                    assert_eq!(workload(), *expected);
+                }
+            })
+        })
+        .collect();
+    for worker in workers {
+        worker.join().expect("worker panicked");
+    }
+}
+
+#[test]
+fn last_error_is_per_thread() {
+    let barrier = Arc::new(Barrier::new(THREADS));
+    let workers: Vec<_> = (0..THREADS)
+        .map(|index| {
+            let barrier = Arc::clone(&barrier);
+            thread::spawn(move || {
+                let bad_zone = CString::new(format!("Nowhere/Zone_{}", index)).unwrap();
+                let good_zone = CString::new("Europe/Paris").unwrap();
+                barrier.wait();
+                for round in 0..ROUNDS * 10 {
+                    if (round + index) % 2 == 0 {
+                        assert_eq!(temporal_bucket_epoch_ms(0, bad_zone.as_ptr(), 60), TEMPORAL_BUCKET_ERROR);
+                        let message = temporal_last_error_message();
+                        assert!(!message.is_null());
+                        let text = unsafe { CStr::from_ptr(message) }.to_string_lossy().into_owned();
+                        unsafe { temporal_free_string(message) };
+                        assert!(text.contains(&format!("Zone_{}", index)), "{}", text);
+                    } else {
+                        assert_eq!(temporal_bucket_epoch_ms(1_800_000, good_zone.as_ptr(), 60), 0);
+                        assert_eq!(temporal_last_error_type(), TemporalErrorType::None as i32);
+                    }
+                }
+            })
+        })
+        .collect();
+    for worker in workers {
+        worker.join().expect("worker panicked");
+    }
+}
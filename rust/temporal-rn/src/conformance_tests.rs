@@ -0,0 +1,151 @@
+//! Data-driven conformance tests against a vendored subset of test262.
+//!
+//! The cases live in `test262/subset.tsv` (see the header there for the format).
+//! Every case runs through the C functions, and the pass rate per category is
+//! printed so spec fidelity can be compared over time:
+//!
+//! ```sh
+//! cargo test test262_subset -- --nocapture
+//! ```
+
+use std::collections::BTreeMap;
+use std::ffi::{CStr, CString};
+use std::ptr;
+
+use crate::*;
+
+const SUBSET: &str = include_str!("../test262/subset.tsv");
+
+#[derive(Default)]
+struct Tally {
+    passed: usize,
+    failed: usize,
+}
+
+/// Runs one case and returns the output string, or the error name for a failed call.
+fn run(op: &str, kind: &str, args: &[&str]) -> Result<String, String> {
+    let c = |s: &str| CString::new(s).unwrap();
+    let args: Vec<CString> = args.iter().map(|arg| c(arg)).collect();
+    let arg = |i: usize| args.get(i).map_or(ptr::null(), |a| a.as_ptr());
+    let unit_code = |i: usize| match args.get(i).map(|a| a.to_str().unwrap()) {
+        Some("-") | None => TEMPORAL_OPTION_UNSET,
+        Some(name) => UNIT_CODES.iter().position(|u| unit_name(*u) == name).expect("unknown unit") as i32,
+    };
+    let unset = TEMPORAL_OPTION_UNSET;
+
+    let result = match (op, kind) {
+        ("from", "Instant") => temporal_instant_from_string(arg(0)),
+        ("from", "PlainDate") => temporal_plain_date_from_string(arg(0)),
+        ("from", "PlainTime") => temporal_plain_time_from_string(arg(0)),
+        ("from", "PlainDateTime") => temporal_plain_date_time_from_string(arg(0)),
+        ("from", "PlainYearMonth") => temporal_plain_year_month_from_string(arg(0)),
+        ("from", "PlainMonthDay") => temporal_plain_month_day_from_string(arg(0)),
+        ("from", "ZonedDateTime") => temporal_zoned_date_time_from_string(arg(0)),
+        ("from", "Duration") => temporal_duration_from_string(arg(0)),
+
+        ("add", "Instant") => temporal_instant_add(arg(0), arg(1)),
+        ("add", "PlainDate") => temporal_plain_date_add(arg(0), arg(1)),
+        ("add", "PlainTime") => temporal_plain_time_add(arg(0), arg(1)),
+        ("add", "PlainDateTime") => temporal_plain_date_time_add(arg(0), arg(1)),
+        ("add", "PlainYearMonth") => temporal_plain_year_month_add(arg(0), arg(1), ptr::null()),
+        ("add", "ZonedDateTime") => temporal_zoned_date_time_add(arg(0), arg(1), ptr::null()),
+        ("add", "Duration") => temporal_duration_add(arg(0), arg(1)),
+        ("subtract", "Instant") => temporal_instant_subtract(arg(0), arg(1)),
+        ("subtract", "PlainDate") => temporal_plain_date_subtract(arg(0), arg(1)),
+        ("subtract", "PlainTime") => temporal_plain_time_subtract(arg(0), arg(1)),
+        ("subtract", "PlainDateTime") => temporal_plain_date_time_subtract(arg(0), arg(1)),
+        ("subtract", "PlainYearMonth") => temporal_plain_year_month_subtract(arg(0), arg(1), ptr::null()),
+        ("subtract", "Duration") => temporal_duration_subtract(arg(0), arg(1)),
+
+        ("until", "Instant") => temporal_instant_until_coded(arg(0), arg(1), unit_code(2), unset, 1, unset),
+        ("until", "PlainDate") => temporal_plain_date_until_coded(arg(0), arg(1), unit_code(2), unset, 1, unset),
+        ("until", "PlainTime") => temporal_plain_time_until_coded(arg(0), arg(1), unit_code(2), unset, 1, unset),
+        ("until", "PlainDateTime") => temporal_plain_date_time_until_coded(arg(0), arg(1), unit_code(2), unset, 1, unset),
+        ("until", "PlainYearMonth") => temporal_plain_year_month_until_coded(arg(0), arg(1), unit_code(2), unset, 1, unset),
+        ("until", "ZonedDateTime") => temporal_zoned_date_time_until_coded(arg(0), arg(1), unit_code(2), unset, 1, unset),
+        ("since", "PlainDate") => temporal_plain_date_since_coded(arg(0), arg(1), unit_code(2), unset, 1, unset),
+        ("since", "PlainYearMonth") => temporal_plain_year_month_since_coded(arg(0), arg(1), unit_code(2), unset, 1, unset),
+
+        ("round", kind) => {
+            let increment: i64 = args[2].to_str().unwrap().parse().expect("invalid increment");
+            match kind {
+                "Instant" => temporal_instant_round(arg(0), arg(1), increment, arg(3)),
+                "PlainTime" => temporal_plain_time_round(arg(0), arg(1), increment, arg(3)),
+                "ZonedDateTime" => temporal_zoned_date_time_round(arg(0), arg(1), increment, arg(3)),
+                _ => panic!("unsupported case: {} {}", op, kind),
+            }
+        }
+        _ => panic!("unsupported case: {} {}", op, kind),
+    };
+    take(result)
+}
+
+fn take(mut result: TemporalResult) -> Result<String, String> {
+    let outcome = match result.error_type {
+        0 => Ok(unsafe { CStr::from_ptr(result.value) }.to_string_lossy().into_owned()),
+        1 => Err("RangeError".to_string()),
+        _ => Err("TypeError".to_string()),
+    };
+    unsafe { temporal_free_result(&mut result) };
+    outcome
+}
+
+#[test]
+fn test262_subset() {
+    let mut category = "uncategorized";
+    let mut tallies: BTreeMap<&str, Tally> = BTreeMap::new();
+    let mut unexpected = Vec::new();
+
+    for (index, line) in SUBSET.lines().enumerate() {
+        if let Some(name) = line.strip_prefix("## ") {
+            category = name;
+            continue;
+        }
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (xfail, line) = match line.strip_prefix("xfail\t") {
+            Some(rest) => (true, rest),
+            None => (false, line),
+        };
+        let columns: Vec<&str> = line.split('\t').collect();
+        let (op, kind, args, expected) = match columns.as_slice() {
+            [op, kind, args @ .., expected] if !args.is_empty() => (*op, *kind, args, *expected),
+            _ => panic!("malformed case on line {}: {}", index + 1, line),
+        };
+
+        let actual = run(op, kind, args).unwrap_or_else(|error| error);
+        let passed = actual == expected;
+        let tally = tallies.entry(category).or_default();
+        if passed {
+            tally.passed += 1;
+        } else {
+            tally.failed += 1;
+        }
+        if passed == xfail {
+            let status = if xfail { "passes but is marked xfail" } else { "fails" };
+            unexpected.push(format!(
+                "line {}: {} {} {:?} {}: expected {}, got {}",
+                index + 1,
+                op,
+                kind,
+                args,
+                status,
+                expected,
+                actual
+            ));
+        }
+    }
+
+    let (mut passed, mut total) = (0, 0);
+    println!("test262 subset:");
+    for (category, tally) in &tallies {
+        let count = tally.passed + tally.failed;
+        println!("  {:<12} {:>3}/{:<3} ({:.1}%)", category, tally.passed, count, 100.0 * tally.passed as f64 / count as f64);
+        passed += tally.passed;
+        total += count;
+    }
+    println!("  {:<12} {:>3}/{:<3} ({:.1}%)", "total", passed, total, 100.0 * passed as f64 / total as f64);
+
+    assert!(unexpected.is_empty(), "unexpected test262 results:\n{}", unexpected.join("\n"));
+}
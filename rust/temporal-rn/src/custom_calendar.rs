@@ -0,0 +1,430 @@
+//! Experimental: calendars implemented by the host through callbacks.
+//!
+//! Some apps need calendars that CLDR does not define, most often a retail fiscal
+//! calendar such as 4-4-5 (quarters of 4, 4 and 5 weeks). The host registers one with
+//! `temporal_register_custom_calendar`, passing a table of function pointers that map
+//! between ISO dates and the calendar's year/month/day fields.
+//!
+//! A date in a custom calendar is the ISO PlainDate string with the calendar's
+//! annotation, "2024-02-04[u-ca=fiscal-445]". `temporal_plain_date_from_string`,
+//! `_get_components`, `_get_calendar`, `_add` and `_subtract` accept such strings
+//! and read fields and do month and year arithmetic in the custom calendar. The
+//! `temporal_custom_calendar_*` functions below take plain ISO dates and the calendar
+//! id instead.
+//!
+//! Fields returned by the callbacks are checked against `months_in_year` and
+//! `days_in_month`; a date the calendar describes inconsistently is an error, never an
+//! out-of-range index.
+//!
+//! Callbacks may run on any thread, including several at once, and must not call
+//! back into this module. The context pointer is released through `release` (if
+//! set) once the calendar is unregistered or replaced and no call is still using it.
+
+use std::collections::BTreeMap;
+use std::ffi::{c_char, c_void};
+use std::str::FromStr;
+use std::sync::{Arc, Mutex};
+
+use temporal_rs::options::{DisplayCalendar, Overflow};
+use temporal_rs::{Calendar, Duration, PlainDate, TemporalError};
+
+use crate::display;
+use crate::{
+    clear_last_error, parse_c_str, parse_duration, parse_overflow, parse_plain_date, parse_with_last_error,
+    set_last_error, PlainDateComponents, TemporalErrorType, TemporalResult,
+};
+
+/// Callbacks implementing a custom calendar. All but `release` are required.
+///
+/// The conversion callbacks write the converted fields to the out pointers and
+/// return 1, or return 0 if the input is outside the calendar's supported range.
+/// `date_to_iso` is only called with a month and day that exist in that year, and
+/// the fields `date_from_iso` writes must exist too.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct TemporalCustomCalendarVTable {
+    pub context: *mut c_void,
+    pub date_from_iso: Option<
+        extern "C" fn(context: *mut c_void, iso_year: i32, iso_month: u8, iso_day: u8, year: *mut i32, month: *mut u8, day: *mut u8) -> i8,
+    >,
+    pub date_to_iso: Option<
+        extern "C" fn(context: *mut c_void, year: i32, month: u8, day: u8, iso_year: *mut i32, iso_month: *mut u8, iso_day: *mut u8) -> i8,
+    >,
+    pub months_in_year: Option<extern "C" fn(context: *mut c_void, year: i32) -> u8>,
+    pub days_in_month: Option<extern "C" fn(context: *mut c_void, year: i32, month: u8) -> u8>,
+    pub release: Option<extern "C" fn(context: *mut c_void)>,
+}
+
+struct CustomCalendar {
+    context: *mut c_void,
+    date_from_iso: extern "C" fn(*mut c_void, i32, u8, u8, *mut i32, *mut u8, *mut u8) -> i8,
+    date_to_iso: extern "C" fn(*mut c_void, i32, u8, u8, *mut i32, *mut u8, *mut u8) -> i8,
+    months_in_year: extern "C" fn(*mut c_void, i32) -> u8,
+    days_in_month: extern "C" fn(*mut c_void, i32, u8) -> u8,
+    release: Option<extern "C" fn(*mut c_void)>,
+}
+
+// The registration contract requires the callbacks and context to be usable from
+// any thread.
+unsafe impl Send for CustomCalendar {}
+unsafe impl Sync for CustomCalendar {}
+
+impl Drop for CustomCalendar {
+    fn drop(&mut self) {
+        if let Some(release) = self.release {
+            release(self.context);
+        }
+    }
+}
+
+impl CustomCalendar {
+    fn fields_of(&self, date: &PlainDate) -> Result<(i32, u8, u8), TemporalError> {
+        let (mut year, mut month, mut day) = (0, 0, 0);
+        let ok = (self.date_from_iso)(self.context, date.year(), date.month(), date.day(), &mut year, &mut month, &mut day);
+        if ok != 1 {
+            return Err(TemporalError::range().with_message("Date is outside the custom calendar's range"));
+        }
+        let months = (self.months_in_year)(self.context, year);
+        if !(1..=months).contains(&month) || !(1..=(self.days_in_month)(self.context, year, month)).contains(&day) {
+            return Err(TemporalError::range().with_message("Custom calendar returned a month or day outside its year"));
+        }
+        Ok((year, month, day))
+    }
+
+    fn to_iso(&self, year: i32, month: u8, day: u8) -> Result<PlainDate, TemporalError> {
+        let (mut iso_year, mut iso_month, mut iso_day) = (0, 0, 0);
+        let ok = (self.date_to_iso)(self.context, year, month, day, &mut iso_year, &mut iso_month, &mut iso_day);
+        if ok != 1 {
+            return Err(TemporalError::range().with_message("Date is outside the custom calendar's range"));
+        }
+        PlainDate::try_new_iso(iso_year, iso_month, iso_day)
+    }
+
+    /// Validates fields against the calendar, clamping them with Overflow::Constrain.
+    fn regulate(&self, year: i32, month: u8, day: u8, overflow: Overflow) -> Result<(u8, u8), TemporalError> {
+        let months = (self.months_in_year)(self.context, year);
+        if months == 0 {
+            return Err(TemporalError::range().with_message("Year is outside the custom calendar's range"));
+        }
+        let month = match (month, overflow) {
+            (1.., _) if month <= months => month,
+            (0, _) | (_, Overflow::Reject) => return Err(TemporalError::range().with_message("Month is not in the year")),
+            (_, Overflow::Constrain) => months,
+        };
+        let days = (self.days_in_month)(self.context, year, month);
+        if days == 0 {
+            return Err(TemporalError::range().with_message("Month is outside the custom calendar's range"));
+        }
+        let day = match (day, overflow) {
+            (1.., _) if day <= days => day,
+            (0, _) | (_, Overflow::Reject) => return Err(TemporalError::range().with_message("Day is not in the month")),
+            (_, Overflow::Constrain) => days,
+        };
+        Ok((month, day))
+    }
+
+    /// The custom calendar's fields, or None if `date` is outside its range.
+    fn components(&self, date: &PlainDate) -> Option<PlainDateComponents> {
+        let (year, month, day) = self.fields_of(date).ok()?;
+        let months_in_year = (self.months_in_year)(self.context, year);
+        let month_lengths: Vec<u16> =
+            (1..=months_in_year).map(|m| (self.days_in_month)(self.context, year, m) as u16).collect();
+        // fields_of checked that 1 <= month <= months_in_year
+        let days_before = |m: u8| month_lengths[..m as usize - 1].iter().sum::<u16>();
+        let mut out = PlainDateComponents {
+            year,
+            month,
+            day,
+            day_of_week: date.day_of_week(),
+            day_of_year: days_before(month) + day as u16,
+            days_in_week: 7,
+            days_in_month: month_lengths[month as usize - 1],
+            days_in_year: month_lengths.iter().sum(),
+            months_in_year: months_in_year as u16,
+            is_valid: 1,
+            ..Default::default()
+        };
+        if months_in_year == 12 {
+            let quarter = (month - 1) / 3 + 1;
+            // Left at 0 if the quarter is too long for the field
+            if let Ok(day_of_quarter) = u8::try_from(days_before(month) - days_before(quarter * 3 - 2) + day as u16) {
+                (out.quarter, out.day_of_quarter) = (quarter, day_of_quarter);
+            }
+        }
+        Some(out)
+    }
+}
+
+/// A PlainDate string annotated with a registered custom calendar: the ISO date and
+/// the calendar that reads it.
+pub(crate) struct CustomDate {
+    id: String,
+    calendar: Arc<CustomCalendar>,
+    date: PlainDate,
+}
+
+impl CustomDate {
+    pub(crate) fn calendar_id(&self) -> &str {
+        &self.id
+    }
+
+    pub(crate) fn to_ixdtf_string(&self) -> String {
+        annotate(&self.date, &self.id)
+    }
+
+    /// The custom calendar's fields, with `is_valid` 0 if the date is outside its range.
+    pub(crate) fn components(&self) -> PlainDateComponents {
+        self.calendar.components(&self.date).unwrap_or_default()
+    }
+
+    /// Adds `duration` as `temporal_custom_calendar_add` does; the result keeps the
+    /// calendar annotation.
+    pub(crate) fn add(&self, duration: &Duration, overflow: Overflow) -> Result<String, TemporalError> {
+        add_in_calendar(&self.calendar, &self.date, duration, overflow).map(|date| annotate(&date, &self.id))
+    }
+}
+
+fn annotate(date: &PlainDate, id: &str) -> String {
+    format!("{}[u-ca={}]", date.to_ixdtf_string(DisplayCalendar::Never), id)
+}
+
+/// Reads `s` as a custom calendar date if its last annotation names a registered
+/// custom calendar. Returns None for every other string, including a NULL or
+/// unregistered one, so the caller parses it as usual.
+pub(crate) fn parse_custom_date(s: *const c_char) -> Option<Result<CustomDate, TemporalResult>> {
+    let s = parse_c_str(s, "plain date").ok()?;
+    let (iso, annotation) = s.strip_suffix(']')?.rsplit_once('[')?;
+    let id = annotation.strip_prefix('!').unwrap_or(annotation).strip_prefix("u-ca=")?;
+    let calendar = lock_calendars().get(id).cloned()?;
+    let date = match PlainDate::from_str(iso) {
+        Ok(date) if date.calendar().is_iso() => date,
+        Ok(_) => return Some(Err(TemporalResult::range_error(&format!("Invalid plain date '{}': more than one calendar", s)))),
+        Err(e) => return Some(Err(TemporalResult::range_error(&format!("Invalid plain date '{}': {}", s, e)))),
+    };
+    Some(Ok(CustomDate { id: id.to_string(), calendar, date }))
+}
+
+static CUSTOM_CALENDARS: Mutex<BTreeMap<String, Arc<CustomCalendar>>> = Mutex::new(BTreeMap::new());
+
+/// Registers (or replaces) a custom calendar under `id`, which must not name a
+/// built-in calendar. The vtable is copied; its context must stay valid until
+/// `release` is called.
+///
+/// Returns 0 on success or -1 on error; details are available via
+/// `temporal_last_error_type` and `temporal_last_error_message`. On error `release`
+/// is not called.
+#[no_mangle]
+pub extern "C" fn temporal_register_custom_calendar(id: *const c_char, vtable: *const TemporalCustomCalendarVTable) -> i32 {
+    clear_last_error();
+    let id = match parse_with_last_error(id, "custom calendar", "id", &owned_str) {
+        Some(id) => id,
+        None => return -1,
+    };
+    if id.is_empty() || !id.bytes().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-') {
+        set_last_error(TemporalErrorType::RangeError, format_args!("Invalid custom calendar id '{}' (use a-z, 0-9 and '-')", id));
+        return -1;
+    }
+    if Calendar::from_str(&id).is_ok() {
+        set_last_error(TemporalErrorType::RangeError, format_args!("'{}' is a built-in calendar", id));
+        return -1;
+    }
+    if vtable.is_null() {
+        set_last_error(TemporalErrorType::TypeError, format_args!("Null custom calendar vtable"));
+        return -1;
+    }
+    let vtable = unsafe { *vtable };
+    let calendar = match (vtable.date_from_iso, vtable.date_to_iso, vtable.months_in_year, vtable.days_in_month) {
+        (Some(date_from_iso), Some(date_to_iso), Some(months_in_year), Some(days_in_month)) => CustomCalendar {
+            context: vtable.context,
+            date_from_iso,
+            date_to_iso,
+            months_in_year,
+            days_in_month,
+            release: vtable.release,
+        },
+        _ => {
+            set_last_error(TemporalErrorType::TypeError, format_args!("Custom calendar '{}' is missing a required callback", id));
+            return -1;
+        }
+    };
+    let previous = lock_calendars().insert(id, Arc::new(calendar));
+    // Dropped outside the lock, as release may be slow
+    drop(previous);
+    0
+}
+
+/// Removes a custom calendar. Returns 1 if it existed, 0 otherwise.
+#[no_mangle]
+pub extern "C" fn temporal_unregister_custom_calendar(id: *const c_char) -> i8 {
+    let id = match parse_with_last_error(id, "custom calendar", "id", &owned_str) {
+        Some(id) => id,
+        None => return 0,
+    };
+    let removed = lock_calendars().remove(&id);
+    removed.is_some() as i8
+}
+
+/// Gets the custom calendar's fields for a PlainDate. Calendar-independent fields
+/// (day of week) come from the date itself; week numbering and leap years are not
+/// defined for custom calendars, and quarters only for 12-month years. On error
+/// `is_valid` is 0.
+#[no_mangle]
+pub extern "C" fn temporal_custom_calendar_get_components(
+    id: *const c_char,
+    date: *const c_char,
+    out: *mut PlainDateComponents,
+) {
+    if out.is_null() {
+        return;
+    }
+    unsafe { *out = PlainDateComponents::default(); }
+
+    let calendar = match get_calendar(id) {
+        Ok(c) => c,
+        Err(_) => return,
+    };
+    let date = match parse_plain_date(date, "plain date") {
+        Ok(d) => d,
+        Err(_) => return,
+    };
+    if let Some(components) = calendar.components(&date) {
+        unsafe { *out = components };
+    }
+}
+
+/// Creates an ISO PlainDate from custom calendar fields. `overflow` is "constrain"
+/// (the default when NULL) or "reject".
+#[no_mangle]
+pub extern "C" fn temporal_custom_calendar_date_to_iso(
+    id: *const c_char,
+    year: i32,
+    month: i32,
+    day: i32,
+    overflow: *const c_char,
+) -> TemporalResult {
+    let calendar = match get_calendar(id) {
+        Ok(c) => c,
+        Err(e) => return e,
+    };
+    let overflow = match parse_overflow(overflow) {
+        Ok(o) => o,
+        Err(e) => return e,
+    };
+    let clamp = |value: i32| value.clamp(0, u8::MAX as i32) as u8;
+    let date = calendar
+        .regulate(year, clamp(month), clamp(day), overflow)
+        .and_then(|(month, day)| calendar.to_iso(year, month, day));
+    match date {
+        Ok(date) => TemporalResult::success(date.to_ixdtf_string(display::calendar())),
+        Err(e) => TemporalResult::range_error(&format!("Invalid custom calendar date: {}", e)),
+    }
+}
+
+/// Adds a duration to a PlainDate in a custom calendar: years and months move the
+/// custom fields (keeping the day, constrained or rejected per `overflow`), then
+/// weeks, days and time units are added as in `temporal_plain_date_add`. Returns an
+/// ISO PlainDate.
+#[no_mangle]
+pub extern "C" fn temporal_custom_calendar_add(
+    id: *const c_char,
+    date: *const c_char,
+    duration: *const c_char,
+    overflow: *const c_char,
+) -> TemporalResult {
+    let calendar = match get_calendar(id) {
+        Ok(c) => c,
+        Err(e) => return e,
+    };
+    let date = match parse_plain_date(date, "plain date") {
+        Ok(d) => d.with_calendar(Calendar::default()),
+        Err(e) => return e,
+    };
+    let duration = match parse_duration(duration, "duration") {
+        Ok(d) => d,
+        Err(e) => return e,
+    };
+    let overflow = match parse_overflow(overflow) {
+        Ok(o) => o,
+        Err(e) => return e,
+    };
+    match add_in_calendar(&calendar, &date, &duration, overflow) {
+        Ok(result) => TemporalResult::success(result.to_ixdtf_string(display::calendar())),
+        Err(e) => TemporalResult::range_error(&format!("Failed to add duration: {}", e)),
+    }
+}
+
+/// Years between the first and last ISO dates Temporal supports, -271821 to 275760.
+const MAX_YEAR_STEPS: u32 = 547_582;
+
+fn add_in_calendar(
+    calendar: &CustomCalendar,
+    date: &PlainDate,
+    duration: &Duration,
+    overflow: Overflow,
+) -> Result<PlainDate, TemporalError> {
+    let out_of_range = || TemporalError::range().with_message("Result is outside the custom calendar's range");
+    let (mut year, mut month, day) = calendar.fields_of(date)?;
+    year = i32::try_from(duration.years()).ok().and_then(|y| year.checked_add(y)).ok_or_else(out_of_range)?;
+
+    // Step through years so calendars with a varying number of months work. No
+    // PlainDate is more than MAX_YEAR_STEPS years from another, so stop there rather
+    // than looping through a huge month count.
+    let mut months = duration.months();
+    let mut steps = 0;
+    while months != 0 {
+        steps += 1;
+        if steps > MAX_YEAR_STEPS {
+            return Err(out_of_range());
+        }
+        let in_year = (calendar.months_in_year)(calendar.context, year) as i64;
+        if in_year == 0 {
+            return Err(out_of_range());
+        }
+        let target = month as i64 + months;
+        if (1..=in_year).contains(&target) {
+            month = target as u8;
+            months = 0;
+        } else if target > in_year {
+            months -= in_year - month as i64 + 1;
+            year = year.checked_add(1).ok_or_else(out_of_range)?;
+            month = 1;
+        } else {
+            months += month as i64;
+            year = year.checked_sub(1).ok_or_else(out_of_range)?;
+            month = (calendar.months_in_year)(calendar.context, year);
+        }
+    }
+    let (month, day) = calendar.regulate(year, month, day, overflow)?;
+    let moved = calendar.to_iso(year, month, day)?;
+
+    let rest = Duration::new(
+        0,
+        0,
+        duration.weeks(),
+        duration.days(),
+        duration.hours(),
+        duration.minutes(),
+        duration.seconds(),
+        duration.milliseconds(),
+        duration.microseconds(),
+        duration.nanoseconds(),
+    )?;
+    moved.add(&rest, Some(overflow))
+}
+
+fn owned_str(s: &str) -> Result<String, TemporalError> {
+    Ok(s.to_string())
+}
+
+fn lock_calendars() -> std::sync::MutexGuard<'static, BTreeMap<String, Arc<CustomCalendar>>> {
+    CUSTOM_CALENDARS.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Looks up a calendar, cloning the Arc so the lock is not held during callbacks.
+fn get_calendar(id: *const c_char) -> Result<Arc<CustomCalendar>, TemporalResult> {
+    let id = parse_c_str(id, "custom calendar id")?;
+    lock_calendars()
+        .get(id)
+        .cloned()
+        .ok_or_else(|| TemporalResult::range_error(&format!("Unknown custom calendar '{}'", id)))
+}
@@ -0,0 +1,87 @@
+//! Stand-ins for the custom calendar API when the crate is built without
+//! `custom-calendars`.
+//!
+//! The symbols stay so the C header and the platform modules link unchanged; every
+//! call fails with a RangeError naming the missing feature. Registration never
+//! takes ownership of the vtable, so `release` is not called.
+
+use std::ffi::{c_char, c_void};
+
+use temporal_rs::options::Overflow;
+use temporal_rs::{Duration, TemporalError};
+
+use crate::{clear_last_error, set_last_error, PlainDateComponents, TemporalErrorType, TemporalResult};
+
+const MESSAGE: &str = "Custom calendars need the custom-calendars feature";
+
+/// Always fails: returns -1 and sets the last error.
+#[no_mangle]
+pub extern "C" fn temporal_register_custom_calendar(_id: *const c_char, _vtable: *const c_void) -> i32 {
+    clear_last_error();
+    set_last_error(TemporalErrorType::RangeError, format_args!("{}", MESSAGE));
+    -1
+}
+
+#[no_mangle]
+pub extern "C" fn temporal_unregister_custom_calendar(_id: *const c_char) -> i8 {
+    0
+}
+
+/// Writes components with `is_valid` 0.
+#[no_mangle]
+pub extern "C" fn temporal_custom_calendar_get_components(
+    _id: *const c_char,
+    _date: *const c_char,
+    out: *mut PlainDateComponents,
+) {
+    if !out.is_null() {
+        unsafe { *out = PlainDateComponents::default() };
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn temporal_custom_calendar_date_to_iso(
+    _id: *const c_char,
+    _year: i32,
+    _month: i32,
+    _day: i32,
+    _overflow: *const c_char,
+) -> TemporalResult {
+    TemporalResult::range_error(MESSAGE)
+}
+
+#[no_mangle]
+pub extern "C" fn temporal_custom_calendar_add(
+    _id: *const c_char,
+    _date: *const c_char,
+    _duration: *const c_char,
+    _overflow: *const c_char,
+) -> TemporalResult {
+    TemporalResult::range_error(MESSAGE)
+}
+
+/// Never constructed: without the feature no custom calendar is registered.
+pub(crate) enum CustomDate {}
+
+impl CustomDate {
+    pub(crate) fn calendar_id(&self) -> &str {
+        match *self {}
+    }
+
+    pub(crate) fn to_ixdtf_string(&self) -> String {
+        match *self {}
+    }
+
+    pub(crate) fn components(&self) -> PlainDateComponents {
+        match *self {}
+    }
+
+    pub(crate) fn add(&self, _duration: &Duration, _overflow: Overflow) -> Result<String, TemporalError> {
+        match *self {}
+    }
+}
+
+/// Always None, so annotated strings are parsed (and rejected) as usual.
+pub(crate) fn parse_custom_date(_s: *const c_char) -> Option<Result<CustomDate, TemporalResult>> {
+    None
+}
@@ -0,0 +1,175 @@
+//! Display and rounding options for turning Temporal values into strings.
+//!
+//! Strings returned as values (parsing, arithmetic, conversions, the batch and JSON
+//! functions) are what the TS wrappers store and pass back in, so they are always
+//! written with the spec defaults from `calendar()`, `offset()`, `time_zone()` and
+//! `rounding()`. Anything else could drop the offset and time zone a ZonedDateTime
+//! needs to parse again, or truncate sub-second digits.
+//!
+//! The app's own policy, set once at startup with
+//! `temporal_set_default_display_options`, only fills in the options left NULL in the
+//! explicit `*_to_string` functions.
+
+use std::ffi::c_char;
+use std::str::FromStr;
+use std::sync::RwLock;
+
+use temporal_rs::options::{DisplayCalendar, DisplayOffset, DisplayTimeZone, ToStringRoundingOptions, Unit};
+use temporal_rs::parsers::Precision;
+use temporal_rs::TemporalError;
+
+use crate::{clear_last_error, parse_with_last_error, set_last_error, TemporalErrorType};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct DisplayDefaults {
+    pub(crate) calendar: DisplayCalendar,
+    pub(crate) offset: DisplayOffset,
+    pub(crate) time_zone: DisplayTimeZone,
+    pub(crate) precision: Precision,
+}
+
+impl DisplayDefaults {
+    /// The Temporal spec defaults: everything "auto".
+    pub(crate) const SPEC: Self = Self {
+        calendar: DisplayCalendar::Auto,
+        offset: DisplayOffset::Auto,
+        time_zone: DisplayTimeZone::Auto,
+        precision: Precision::Auto,
+    };
+}
+
+static DEFAULTS: RwLock<DisplayDefaults> = RwLock::new(DisplayDefaults::SPEC);
+
+/// The options set with `temporal_set_default_display_options`.
+pub(crate) fn current() -> DisplayDefaults {
+    *DEFAULTS.read().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+pub(crate) fn calendar() -> DisplayCalendar {
+    DisplayDefaults::SPEC.calendar
+}
+
+pub(crate) fn offset() -> DisplayOffset {
+    DisplayDefaults::SPEC.offset
+}
+
+pub(crate) fn time_zone() -> DisplayTimeZone {
+    DisplayDefaults::SPEC.time_zone
+}
+
+pub(crate) fn rounding() -> ToStringRoundingOptions {
+    ToStringRoundingOptions::default()
+}
+
+/// Rounding options for a per-call `precision` argument: "auto", a digit count "0"-"9"
+/// (fractionalSecondDigits), or a smallest unit from "minute" to "nanosecond". Output
+/// is truncated, as in the spec's toString. NULL falls back to the app's defaults.
+pub(crate) fn rounding_for_precision(precision: Option<&str>) -> Result<ToStringRoundingOptions, String> {
+    let precision = match precision {
+        Some(p) => p,
+        None => {
+            return Ok(ToStringRoundingOptions {
+                precision: current().precision,
+                ..Default::default()
+            })
+        }
+    };
+    let mut options = ToStringRoundingOptions::default();
+    match precision {
+        "auto" => {}
+        digits if digits.len() == 1 && digits.as_bytes()[0].is_ascii_digit() => {
+            options.precision = Precision::Digit(digits.as_bytes()[0] - b'0');
+        }
+        unit => match Unit::from_str(unit) {
+            Ok(u @ (Unit::Minute | Unit::Second | Unit::Millisecond | Unit::Microsecond | Unit::Nanosecond)) => {
+                options.smallest_unit = Some(u);
+            }
+            _ => {
+                return Err(format!(
+                    "Invalid precision: {} (expected auto, 0-9, or a unit from minute to nanosecond)",
+                    unit
+                ))
+            }
+        },
+    }
+    Ok(options)
+}
+
+/// Calendar display for a per-call `calendar_name` argument: "auto", "always", "never"
+/// or "critical", which writes "[!u-ca=...]" even for ISO 8601. NULL falls back to the
+/// app's defaults.
+pub(crate) fn calendar_for_name(calendar_name: Option<&str>) -> Result<DisplayCalendar, String> {
+    match calendar_name {
+        None => Ok(current().calendar),
+        Some(name) => DisplayCalendar::from_str(name)
+            .map_err(|_| format!("Invalid calendarName: {} (expected auto, always, never, or critical)", name)),
+    }
+}
+
+/// Sets the display options the explicit `*_to_string` functions use for arguments
+/// left NULL. Every other function keeps returning spec-formatted strings, so values
+/// still round-trip. NULL strings and a negative `fractional_second_digits` keep the
+/// spec default ("auto"), so passing all NULLs and -1 resets everything.
+///
+/// - `calendar_name`: "auto", "always", "never" or "critical"
+/// - `offset`: "auto" or "never"
+/// - `time_zone_name`: "auto", "never" or "critical"
+/// - `fractional_second_digits`: 0-9, or negative for "auto"
+///
+/// Returns 0 on success or -1 on error; details are available via
+/// `temporal_last_error_type` and `temporal_last_error_message`. On error the
+/// current options are left unchanged.
+#[no_mangle]
+pub extern "C" fn temporal_set_default_display_options(
+    calendar_name: *const c_char,
+    offset: *const c_char,
+    time_zone_name: *const c_char,
+    fractional_second_digits: i32,
+) -> i32 {
+    clear_last_error();
+    let calendar = match parse_option(calendar_name, "calendarName", DisplayDefaults::SPEC.calendar) {
+        Some(c) => c,
+        None => return -1,
+    };
+    let offset = match parse_option(offset, "offset", DisplayDefaults::SPEC.offset) {
+        Some(o) => o,
+        None => return -1,
+    };
+    let time_zone = match parse_option(time_zone_name, "timeZoneName", DisplayDefaults::SPEC.time_zone) {
+        Some(t) => t,
+        None => return -1,
+    };
+    let precision = match fractional_second_digits {
+        digits if digits < 0 => Precision::Auto,
+        digits @ 0..=9 => Precision::Digit(digits as u8),
+        digits => {
+            set_last_error(
+                TemporalErrorType::RangeError,
+                format_args!("Invalid fractionalSecondDigits: {} (must be 0-9)", digits),
+            );
+            return -1;
+        }
+    };
+
+    *DEFAULTS.write().unwrap_or_else(|poisoned| poisoned.into_inner()) = DisplayDefaults {
+        calendar,
+        offset,
+        time_zone,
+        precision,
+    };
+    0
+}
+
+fn parse_option<T: FromStr>(ptr: *const c_char, name: &str, default: T) -> Option<T> {
+    if ptr.is_null() {
+        return Some(default);
+    }
+    let value = parse_with_last_error(ptr, name, "option", &|s: &str| Ok::<_, TemporalError>(s.to_string()))?;
+    match T::from_str(&value) {
+        Ok(v) => Some(v),
+        Err(_) => {
+            set_last_error(TemporalErrorType::RangeError, format_args!("Invalid {} option: {}", name, value));
+            None
+        }
+    }
+}
@@ -0,0 +1,268 @@
+//! Locale-aware formatters created once and reused through a handle.
+//!
+//! Building an icu4x formatter loads locale data and resolves patterns, which is
+//! far more work than formatting a single value. List views that format hundreds
+//! of timestamps create one formatter up front and pass its handle to every call.
+//!
+//! Options are a JSON object, all fields optional:
+//!
+//! ```json
+//! { "dateStyle": "medium", "timeStyle": "short", "timeZone": "Europe/Paris" }
+//! ```
+//!
+//! `dateStyle` is "full", "long", "medium" or "short"; `timeStyle` is "medium" or
+//! "short" (the longer time styles include a zone name, which is not supported).
+//! Without either style the formatter uses `dateStyle: "short"`. `timeZone` (default
+//! "UTC") is where instants and epoch values are shown; ZonedDateTimes keep their own.
+//!
+//! Dates are shown in the locale's calendar (e.g. "th" uses the Buddhist calendar).
+//! Values in a calendar other than ISO 8601 are rejected with a RangeError rather than
+//! shown by their ISO fields; convert them to ISO 8601 first.
+//!
+//! Handles are process-wide and can be used from any thread, including concurrently.
+//! Destroying a handle while another thread is formatting with it is safe: that call
+//! finishes with the formatter it already looked up.
+
+use std::collections::BTreeMap;
+use std::ffi::c_char;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use icu_datetime::fieldsets::builder::{DateFields, FieldSetBuilder};
+use icu_datetime::fieldsets::enums::CompositeDateTimeFieldSet;
+use icu_datetime::options::{Length, TimePrecision};
+use icu_datetime::DateTimeFormatter;
+use temporal_rs::options::{Disambiguation, OffsetDisambiguation};
+use temporal_rs::{Calendar, Instant, PlainDateTime, PlainTime, TemporalError, TimeZone, ZonedDateTime};
+
+use crate::json::{self, JsonValue};
+use crate::{clear_last_error, parse_c_str, parse_with_last_error, set_last_error, TemporalErrorType, TemporalResult};
+
+struct Formatter {
+    inner: DateTimeFormatter<CompositeDateTimeFieldSet>,
+    has_date: bool,
+    has_time: bool,
+    time_zone: TimeZone,
+}
+
+/// ISO wall-clock fields of a value to format; the date or time may be missing.
+struct WallClock {
+    date: Option<(i32, u8, u8)>,
+    time: Option<(u8, u8, u8)>,
+}
+
+static FORMATTERS: Mutex<BTreeMap<u64, Arc<Formatter>>> = Mutex::new(BTreeMap::new());
+/// Handle 0 is never issued, so callers can use it as "no formatter".
+static NEXT_HANDLE: AtomicU64 = AtomicU64::new(1);
+
+/// Creates a formatter for `locale` (BCP 47, NULL = "en") with the given options JSON
+/// (NULL = defaults).
+///
+/// Returns a non-zero handle, or 0 on error; details are available via
+/// `temporal_last_error_type` and `temporal_last_error_message`. Release the handle
+/// with `temporal_formatter_destroy`.
+#[no_mangle]
+pub extern "C" fn temporal_formatter_create(locale: *const c_char, options_json: *const c_char) -> u64 {
+    clear_last_error();
+    let locale: icu_locale_core::Locale = if locale.is_null() {
+        icu_locale_core::locale!("en")
+    } else {
+        match parse_with_last_error(locale, "formatter", "locale", &owned_str) {
+            Some(tag) => match tag.parse() {
+                Ok(l) => l,
+                Err(e) => {
+                    set_last_error(TemporalErrorType::RangeError, format_args!("Invalid locale '{}': {}", tag, e));
+                    return 0;
+                }
+            },
+            None => return 0,
+        }
+    };
+    let options = if options_json.is_null() {
+        JsonValue::Object(Vec::new())
+    } else {
+        let text = match parse_with_last_error(options_json, "formatter", "options", &owned_str) {
+            Some(text) => text,
+            None => return 0,
+        };
+        match json::parse(&text) {
+            Ok(value @ JsonValue::Object(_)) => value,
+            Ok(_) => return range_error(format_args!("Invalid formatter options: expected a JSON object")),
+            Err(message) => return range_error(format_args!("Invalid formatter options: {}", message)),
+        }
+    };
+
+    // icu4x has one length for the date and time together, so the date style's wins;
+    // "full" is the long date with the weekday
+    let date_style = match options.get("dateStyle").map(|v| v.as_str()) {
+        None => None,
+        Some(Some("full")) => Some((DateFields::YMDE, Length::Long)),
+        Some(Some("long")) => Some((DateFields::YMD, Length::Long)),
+        Some(Some("medium")) => Some((DateFields::YMD, Length::Medium)),
+        Some(Some("short")) => Some((DateFields::YMD, Length::Short)),
+        Some(_) => return range_error(format_args!("Invalid dateStyle (expected full, long, medium or short)")),
+    };
+    let time_style = match options.get("timeStyle").map(|v| v.as_str()) {
+        None => None,
+        Some(Some("medium")) => Some((TimePrecision::Second, Length::Medium)),
+        Some(Some("short")) => Some((TimePrecision::Minute, Length::Short)),
+        Some(_) => return range_error(format_args!("Invalid timeStyle (expected medium or short)")),
+    };
+    let date_style = match (date_style, time_style) {
+        (None, None) => Some((DateFields::YMD, Length::Short)),
+        _ => date_style,
+    };
+    let mut builder = FieldSetBuilder::new();
+    builder.date_fields = date_style.map(|(fields, _)| fields);
+    builder.time_precision = time_style.map(|(precision, _)| precision);
+    builder.length = date_style.map(|(_, length)| length).or(time_style.map(|(_, length)| length));
+    let time_zone = match options.get("timeZone").map(|v| v.as_str()) {
+        None => TimeZone::utc(),
+        Some(Some(id)) => match TimeZone::try_from_str(id) {
+            Ok(tz) => tz,
+            Err(e) => return range_error(format_args!("Invalid timeZone '{}': {}", id, e)),
+        },
+        Some(None) => return range_error(format_args!("Invalid timeZone: expected a string")),
+    };
+
+    let field_set = match builder.build_composite_datetime() {
+        Ok(f) => f,
+        Err(e) => return range_error(format_args!("Failed to create formatter for '{}': {}", locale, e)),
+    };
+    let inner = match DateTimeFormatter::try_new((&locale).into(), field_set) {
+        Ok(f) => f,
+        Err(e) => return range_error(format_args!("Failed to create formatter for '{}': {}", locale, e)),
+    };
+    let formatter = Formatter {
+        inner,
+        has_date: date_style.is_some(),
+        has_time: time_style.is_some(),
+        time_zone,
+    };
+    let handle = NEXT_HANDLE.fetch_add(1, Ordering::Relaxed);
+    lock_formatters().insert(handle, Arc::new(formatter));
+    handle
+}
+
+/// Formats a Temporal string with a formatter: a PlainDate, PlainTime, PlainDateTime
+/// or ZonedDateTime as its wall-clock time, or an Instant in the formatter's time zone.
+/// Formatting a value without the fields the formatter shows (a PlainTime with a
+/// date style, say) is a TypeError.
+#[no_mangle]
+pub extern "C" fn temporal_formatter_format(handle: u64, value: *const c_char) -> TemporalResult {
+    let formatter = match get_formatter(handle) {
+        Ok(f) => f,
+        Err(e) => return e,
+    };
+    let value = match parse_c_str(value, "value") {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    match wall_clock(value, &formatter.time_zone) {
+        Ok(fields) => format_fields(&formatter, &fields),
+        Err(e) => TemporalResult::range_error(&format!("Invalid value '{}': {}", value, e)),
+    }
+}
+
+/// Formats epoch nanoseconds in the formatter's time zone.
+#[no_mangle]
+pub extern "C" fn temporal_formatter_format_epoch_ns(handle: u64, epoch_ns: i64) -> TemporalResult {
+    let formatter = match get_formatter(handle) {
+        Ok(f) => f,
+        Err(e) => return e,
+    };
+    let fields = Instant::try_new(epoch_ns as i128).and_then(|instant| instant_wall_clock(&instant, &formatter.time_zone));
+    match fields {
+        Ok(fields) => format_fields(&formatter, &fields),
+        Err(e) => TemporalResult::range_error(&format!("Invalid epoch nanoseconds {}: {}", epoch_ns, e)),
+    }
+}
+
+/// Releases a formatter. Returns 1 if the handle existed, 0 otherwise.
+#[no_mangle]
+pub extern "C" fn temporal_formatter_destroy(handle: u64) -> i8 {
+    lock_formatters().remove(&handle).is_some() as i8
+}
+
+fn owned_str(s: &str) -> Result<String, TemporalError> {
+    Ok(s.to_string())
+}
+
+fn range_error(message: std::fmt::Arguments) -> u64 {
+    set_last_error(TemporalErrorType::RangeError, message);
+    0
+}
+
+fn lock_formatters() -> std::sync::MutexGuard<'static, BTreeMap<u64, Arc<Formatter>>> {
+    FORMATTERS.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Looks up a formatter, cloning the Arc so the lock is not held while formatting.
+fn get_formatter(handle: u64) -> Result<Arc<Formatter>, TemporalResult> {
+    lock_formatters()
+        .get(&handle)
+        .cloned()
+        .ok_or_else(|| TemporalResult::type_error(&format!("Unknown formatter handle {}", handle)))
+}
+
+fn wall_clock(value: &str, time_zone: &TimeZone) -> Result<WallClock, TemporalError> {
+    // A bracketed annotation without '=' is a time zone ([u-ca=...] is a calendar).
+    if value.split('[').skip(1).any(|annotation| !annotation.contains('=')) {
+        let zdt = ZonedDateTime::from_utf8(value.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject)?;
+        return date_time_fields(&zdt.to_plain_date_time());
+    }
+    if let Ok(instant) = Instant::from_str(value) {
+        return instant_wall_clock(&instant, time_zone);
+    }
+    if let Ok(dt) = PlainDateTime::from_str(value) {
+        // A bare date also parses as a PlainDateTime at midnight
+        if !value.contains(['T', 't', ' ']) {
+            return Ok(WallClock { time: None, ..date_time_fields(&dt)? });
+        }
+        return date_time_fields(&dt);
+    }
+    let time = PlainTime::from_str(value)?;
+    Ok(WallClock {
+        date: None,
+        time: Some((time.hour(), time.minute(), time.second())),
+    })
+}
+
+fn instant_wall_clock(instant: &Instant, time_zone: &TimeZone) -> Result<WallClock, TemporalError> {
+    let zdt = ZonedDateTime::try_new(instant.epoch_nanoseconds().as_i128(), *time_zone, Calendar::default())?;
+    date_time_fields(&zdt.to_plain_date_time())
+}
+
+fn date_time_fields(dt: &PlainDateTime) -> Result<WallClock, TemporalError> {
+    if !dt.calendar().is_iso() {
+        return Err(TemporalError::range().with_message("only ISO 8601 values can be formatted; convert the value to iso8601 first"));
+    }
+    Ok(WallClock {
+        date: Some((dt.year(), dt.month(), dt.day())),
+        time: Some((dt.hour(), dt.minute(), dt.second())),
+    })
+}
+
+fn format_fields(formatter: &Formatter, fields: &WallClock) -> TemporalResult {
+    let (year, month, day) = match (formatter.has_date, fields.date) {
+        (true, None) => return TemporalResult::type_error("This formatter shows a date, but the value has none"),
+        (_, Some(date)) => date,
+        // Time-only formats ignore the date, so any valid one will do
+        (false, None) => (1970, 1, 1),
+    };
+    let (hour, minute, second) = match (formatter.has_time, fields.time) {
+        (true, None) => return TemporalResult::type_error("This formatter shows a time, but the value has none"),
+        (_, time) => time.unwrap_or((0, 0, 0)),
+    };
+    let date = match icu_calendar::Date::try_new_iso(year, month, day) {
+        Ok(date) => date,
+        Err(e) => return TemporalResult::range_error(&format!("Value out of range for formatting: {}", e)),
+    };
+    let time = match icu_time::Time::try_new(hour, minute, second, 0) {
+        Ok(time) => time,
+        Err(e) => return TemporalResult::range_error(&format!("Value out of range for formatting: {}", e)),
+    };
+    let input = icu_time::DateTime { date, time };
+    TemporalResult::success(formatter.inner.format(&input).to_string())
+}
@@ -0,0 +1,34 @@
+//! Stand-ins for the formatter API when the crate is built without `locale-data`.
+//!
+//! The symbols stay so the C header and the platform modules link unchanged; every
+//! call fails with a RangeError naming the missing feature.
+
+use std::ffi::c_char;
+
+use crate::{clear_last_error, set_last_error, TemporalErrorType, TemporalResult};
+
+const MESSAGE: &str = "Formatters need the locale-data feature";
+
+/// Always fails: returns 0 and sets the last error.
+#[no_mangle]
+pub extern "C" fn temporal_formatter_create(_locale: *const c_char, _options_json: *const c_char) -> u64 {
+    clear_last_error();
+    set_last_error(TemporalErrorType::RangeError, format_args!("{}", MESSAGE));
+    0
+}
+
+#[no_mangle]
+pub extern "C" fn temporal_formatter_format(_handle: u64, _value: *const c_char) -> TemporalResult {
+    TemporalResult::range_error(MESSAGE)
+}
+
+#[no_mangle]
+pub extern "C" fn temporal_formatter_format_epoch_ns(_handle: u64, _epoch_ns: i64) -> TemporalResult {
+    TemporalResult::range_error(MESSAGE)
+}
+
+/// No handle is ever issued, so there is nothing to release.
+#[no_mangle]
+pub extern "C" fn temporal_formatter_destroy(_handle: u64) -> i8 {
+    0
+}
@@ -0,0 +1,216 @@
+//! Named holiday sets registered by the app at runtime.
+//!
+//! A set is described by JSON, either a bare array of ISO dates or an object:
+//!
+//! ```json
+//! {
+//!   "dates": ["2024-03-29"],
+//!   "rules": [
+//!     { "month": 12, "day": 25 },
+//!     { "month": 11, "weekday": 4, "nth": 4 },
+//!     { "month": 5, "weekday": 1, "nth": -1 }
+//!   ]
+//! }
+//! ```
+//!
+//! `dates` are one-off ISO dates. A rule with `day` repeats every year; a rule with
+//! `weekday` (1 = Monday ... 7 = Sunday) and `nth` picks the nth such weekday of the
+//! month, counting from the end when `nth` is negative. All rules use the ISO calendar.
+
+use std::collections::BTreeMap;
+use std::ffi::c_char;
+use std::sync::Mutex;
+
+use temporal_rs::{Calendar, PlainDate, TemporalError};
+
+use crate::display;
+use crate::json::{self, JsonValue};
+use crate::{
+    clear_last_error, parse_c_str, parse_plain_date, parse_with_last_error, read_with_last_error, set_last_error,
+    TemporalErrorType, TemporalResult,
+};
+
+enum HolidayRule {
+    Date(PlainDate),
+    Annual { month: u8, day: u8 },
+    NthWeekday { month: u8, weekday: u16, nth: i8 },
+}
+
+/// Annual rules are searched this many years ahead, enough to find the next 02-29.
+/// One-off dates are found however far ahead they are.
+const MAX_YEARS_AHEAD: i32 = 8;
+
+static HOLIDAY_SETS: Mutex<BTreeMap<String, Vec<HolidayRule>>> = Mutex::new(BTreeMap::new());
+
+/// Registers (or replaces) a holiday set under `set_id`.
+///
+/// Returns the number of dates and rules in the set, or -1 on error; details are
+/// available via `temporal_last_error_type` and `temporal_last_error_message`.
+#[no_mangle]
+pub extern "C" fn temporal_holidays_register(set_id: *const c_char, json: *const c_char) -> i32 {
+    clear_last_error();
+    let set_id = match parse_with_last_error(set_id, "holiday", "set id", &owned_str) {
+        Some(id) => id,
+        None => return -1,
+    };
+    let json = match read_with_last_error(json, "holiday", "json", true) {
+        Some(json) => json,
+        None => return -1,
+    };
+
+    let rules = match json::parse(json).and_then(|value| parse_rules(&value)) {
+        Ok(rules) => rules,
+        Err(message) => {
+            set_last_error(TemporalErrorType::RangeError, format_args!("Invalid holiday set '{}': {}", set_id, message));
+            return -1;
+        }
+    };
+    let count = rules.len() as i32;
+    lock_sets().insert(set_id, rules);
+    count
+}
+
+/// Removes a holiday set. Returns 1 if it existed, 0 otherwise.
+#[no_mangle]
+pub extern "C" fn temporal_holidays_unregister(set_id: *const c_char) -> i8 {
+    clear_last_error();
+    match parse_with_last_error(set_id, "holiday", "set id", &owned_str) {
+        Some(id) => lock_sets().remove(&id).is_some() as i8,
+        None => 0,
+    }
+}
+
+/// Returns 1 if the PlainDate is a holiday in the set, 0 if not, or -1 on error
+/// (unknown set, invalid date).
+#[no_mangle]
+pub extern "C" fn temporal_is_holiday(date: *const c_char, set_id: *const c_char) -> i8 {
+    clear_last_error();
+    let date = match parse_with_last_error(date, "holiday", "plain date", &|s: &str| s.parse::<PlainDate>()) {
+        Some(d) => d.with_calendar(Calendar::default()),
+        None => return -1,
+    };
+    let set_id = match parse_with_last_error(set_id, "holiday", "set id", &owned_str) {
+        Some(id) => id,
+        None => return -1,
+    };
+
+    let sets = lock_sets();
+    let rules = match sets.get(&set_id) {
+        Some(rules) => rules,
+        None => {
+            set_last_error(TemporalErrorType::RangeError, format_args!("Unknown holiday set '{}'", set_id));
+            return -1;
+        }
+    };
+    let is_holiday = rules
+        .iter()
+        .any(|rule| occurrence_in_year(rule, date.year()).is_some_and(|d| same_day(&d, &date)));
+    is_holiday as i8
+}
+
+/// Returns the first holiday in the set strictly after the given PlainDate.
+#[no_mangle]
+pub extern "C" fn temporal_next_holiday(date: *const c_char, set_id: *const c_char) -> TemporalResult {
+    let date = match parse_plain_date(date, "plain date") {
+        Ok(d) => d.with_calendar(Calendar::default()),
+        Err(e) => return e,
+    };
+    let set_id = match parse_c_str(set_id, "set id") {
+        Ok(id) => id,
+        Err(e) => return e,
+    };
+
+    let sets = lock_sets();
+    let rules = match sets.get(set_id) {
+        Some(rules) => rules,
+        None => return TemporalResult::range_error(&format!("Unknown holiday set '{}'", set_id)),
+    };
+
+    let key = |d: &PlainDate| (d.year(), d.month(), d.day());
+    let next = rules
+        .iter()
+        .filter_map(|rule| match rule {
+            HolidayRule::Date(holiday) => (key(holiday) > key(&date)).then(|| holiday.clone()),
+            _ => (date.year()..=date.year() + MAX_YEARS_AHEAD)
+                .filter_map(|year| occurrence_in_year(rule, year))
+                .find(|candidate| key(candidate) > key(&date)),
+        })
+        .min_by_key(key);
+
+    match next {
+        Some(holiday) => TemporalResult::success(holiday.to_ixdtf_string(display::calendar())),
+        None => TemporalResult::range_error(&format!("No holiday after {} in set '{}'", date.to_ixdtf_string(display::calendar()), set_id)),
+    }
+}
+
+fn owned_str(s: &str) -> Result<String, TemporalError> {
+    Ok(s.to_string())
+}
+
+fn lock_sets() -> std::sync::MutexGuard<'static, BTreeMap<String, Vec<HolidayRule>>> {
+    HOLIDAY_SETS.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+fn same_day(a: &PlainDate, b: &PlainDate) -> bool {
+    (a.year(), a.month(), a.day()) == (b.year(), b.month(), b.day())
+}
+
+/// Returns the date a rule falls on in the given ISO year, if any.
+fn occurrence_in_year(rule: &HolidayRule, year: i32) -> Option<PlainDate> {
+    match rule {
+        HolidayRule::Date(date) => (date.year() == year).then(|| date.clone()),
+        HolidayRule::Annual { month, day } => PlainDate::try_new_iso(year, *month, *day).ok(),
+        HolidayRule::NthWeekday { month, weekday, nth } => {
+            let first = PlainDate::try_new_iso(year, *month, 1).ok()?;
+            let days_in_month = first.days_in_month() as i32;
+            let first_weekday = first.day_of_week() as i32;
+            let weekday = *weekday as i32;
+            let day = if *nth > 0 {
+                1 + (weekday - first_weekday).rem_euclid(7) + (*nth as i32 - 1) * 7
+            } else {
+                let last_weekday = (first_weekday - 1 + days_in_month - 1) % 7 + 1;
+                days_in_month - (last_weekday - weekday).rem_euclid(7) - (-(*nth as i32) - 1) * 7
+            };
+            if day < 1 || day > days_in_month {
+                return None;
+            }
+            PlainDate::try_new_iso(year, *month, day as u8).ok()
+        }
+    }
+}
+
+fn parse_rules(value: &JsonValue) -> Result<Vec<HolidayRule>, String> {
+    let (dates, rules) = match value {
+        JsonValue::Array(items) => (items.as_slice(), &[][..]),
+        JsonValue::Object(_) => (
+            value.get("dates").and_then(JsonValue::as_array).unwrap_or(&[]),
+            value.get("rules").and_then(JsonValue::as_array).unwrap_or(&[]),
+        ),
+        _ => return Err("expected an array of dates or an object".to_string()),
+    };
+
+    let mut parsed = Vec::with_capacity(dates.len() + rules.len());
+    for item in dates {
+        let text = item.as_str().ok_or("dates must be ISO date strings")?;
+        let date: PlainDate = text.parse().map_err(|e| format!("invalid date '{}': {}", text, e))?;
+        parsed.push(HolidayRule::Date(date.with_calendar(Calendar::default())));
+    }
+    for rule in rules {
+        let field = |name: &str| rule.get(name).and_then(JsonValue::as_i64);
+        let month = match field("month") {
+            Some(m @ 1..=12) => m as u8,
+            _ => return Err("each rule needs a month between 1 and 12".to_string()),
+        };
+        let parsed_rule = match (field("day"), field("weekday"), field("nth")) {
+            (Some(day @ 1..=31), None, None) => HolidayRule::Annual { month, day: day as u8 },
+            (None, Some(weekday @ 1..=7), Some(nth @ (-5..=-1 | 1..=5))) => HolidayRule::NthWeekday {
+                month,
+                weekday: weekday as u16,
+                nth: nth as i8,
+            },
+            _ => return Err("each rule needs either a day (1-31) or a weekday (1-7) with nth (1-5 or -5..-1)".to_string()),
+        };
+        parsed.push(parsed_rule);
+    }
+    Ok(parsed)
+}
@@ -1,13 +1,90 @@
+//! C and JNI bindings for temporal_rs.
+//!
+//! # Threading
+//!
+//! Every entry point can be called from any thread, including concurrently. The
+//! process-wide state they share (the time zone provider, display defaults, formatter
+//! handles, registered calendars and holiday sets) sits behind a lock or an atomic,
+//! and the last error is per thread, so it always belongs to the caller's own last
+//! call. A compile-time check below rejects shared state that is not `Send + Sync`;
+//! `concurrency_tests` exercises the entry points from many threads at once.
+//!
+//! # Panics
+//!
+//! Entry points are meant to report every failure through their return value, since
+//! the `release-mobile` profile builds with `panic = "abort"` and a panic would take
+//! the host app down. The lints below reject `unwrap`, `expect` and `panic!` outside
+//! tests; they do not catch out-of-bounds indexing or arithmetic overflow, so inputs
+//! that feed an index or a subtraction still need checking by hand.
+//!
+//! # Pointer arguments
+//!
+//! The C entry points take raw pointers from C callers, and the header states what
+//! each must point to. They check for NULL before reading or writing, but are not
+//! `unsafe fn`: that would change nothing for C and only burden Rust-side tests.
+
+#![cfg_attr(not(test), deny(clippy::unwrap_used, clippy::expect_used, clippy::panic))]
+#![allow(clippy::not_unsafe_ptr_arg_deref)]
+
 use std::ffi::{c_char, CString};
 use std::ptr;
 use std::str::FromStr;
-
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering as AtomicOrdering};
+
+mod astronomy;
+mod canonical_cache;
+#[cfg(test)]
+mod concurrency_tests;
+#[cfg(test)]
+mod conformance_tests;
+#[cfg(feature = "custom-calendars")]
+mod custom_calendar;
+#[cfg(not(feature = "custom-calendars"))]
+#[path = "custom_calendar_disabled.rs"]
+mod custom_calendar;
+mod display;
+#[cfg(feature = "locale-data")]
+mod formatter;
+#[cfg(not(feature = "locale-data"))]
+#[path = "formatter_disabled.rs"]
+mod formatter;
+mod holidays;
+mod json;
+mod meetings;
+mod metrics;
+#[cfg(test)]
+mod ownership_tests;
+#[cfg(test)]
+mod property_tests;
+
+use canonical_cache::Kind;
+use json::JsonValue;
+
+#[cfg(feature = "locale-data")]
+use icu_datetime::{fieldsets::{enums::ZoneFieldSet, zone}, NoCalendarFormatter};
+#[cfg(feature = "locale-data")]
+type TimeZoneFormatter = NoCalendarFormatter<ZoneFieldSet>;
 use temporal_rs::sys::Temporal;
 use temporal_rs::{
-    options::{DisplayCalendar, ToStringRoundingOptions, DisplayOffset, DisplayTimeZone, Disambiguation, OffsetDisambiguation, Overflow, RoundingOptions, RoundingMode, Unit, RoundingIncrement},
-    provider::COMPILED_TZ_PROVIDER,
+    options::{DisplayCalendar, Disambiguation, OffsetDisambiguation, Overflow, RoundingOptions, RoundingMode, Unit, RoundingIncrement, ToStringRoundingOptions},
+    partial::{PartialDate, PartialTime, PartialZonedDateTime},
+    provider::{TransitionDirection, COMPILED_TZ_PROVIDER},
     Calendar, Duration, Instant, PlainDate, PlainDateTime, PlainMonthDay, PlainTime,
-    PlainYearMonth, TimeZone, ZonedDateTime, TemporalError,
+    PlainYearMonth, TimeZone, UtcOffset, ZonedDateTime, TemporalError,
+};
+
+// Shared state must be safe to use from every thread at once (see the crate docs).
+// Add any new process-wide cache here so a type that isn't fails to compile.
+const _: () = {
+    const fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<timezone_provider::tzif::CompiledTzdbProvider>();
+    assert_send_sync::<TimeZone>();
+    assert_send_sync::<Calendar>();
+    assert_send_sync::<canonical_cache::Cache>();
+    #[cfg(feature = "locale-data")]
+    assert_send_sync::<icu_datetime::DateTimeFormatter<icu_datetime::fieldsets::enums::CompositeDateTimeFieldSet>>();
+    #[cfg(feature = "locale-data")]
+    assert_send_sync::<TimeZoneFormatter>();
 };
 
 // ============================================================================
@@ -24,6 +101,9 @@ pub enum TemporalErrorType {
     RangeError = 1,
     /// TypeError - wrong type or invalid argument
     TypeError = 2,
+    /// An input string is longer than the configured maximum (see
+    /// `temporal_set_max_input_length`). Hosts raise it as a RangeError.
+    InputTooLong = 3,
 }
 
 /// Result structure for FFI operations that can fail
@@ -39,20 +119,16 @@ pub struct TemporalResult {
 
 impl TemporalResult {
     fn success(value: String) -> Self {
-        match CString::new(value) {
-            Ok(c_str) => Self {
-                value: c_str.into_raw(),
-                error_type: TemporalErrorType::None as i32,
-                error_message: ptr::null_mut(),
-            },
-            Err(_) => Self::type_error("Failed to convert result to C string"),
+        Self {
+            value: into_c_string(value),
+            error_type: TemporalErrorType::None as i32,
+            error_message: ptr::null_mut(),
         }
     }
 
     fn range_error(message: &str) -> Self {
-        let error_msg = CString::new(message)
-            .map(|s| s.into_raw())
-            .unwrap_or(ptr::null_mut());
+        metrics::record_error(TemporalErrorType::RangeError);
+        let error_msg = into_c_string(message.to_string());
         Self {
             value: ptr::null_mut(),
             error_type: TemporalErrorType::RangeError as i32,
@@ -61,15 +137,34 @@ impl TemporalResult {
     }
 
     fn type_error(message: &str) -> Self {
-        let error_msg = CString::new(message)
-            .map(|s| s.into_raw())
-            .unwrap_or(ptr::null_mut());
+        metrics::record_error(TemporalErrorType::TypeError);
+        let error_msg = into_c_string(message.to_string());
         Self {
             value: ptr::null_mut(),
             error_type: TemporalErrorType::TypeError as i32,
             error_message: error_msg,
         }
     }
+
+    fn input_too_long(param_name: &str, len: usize, limit: usize) -> Self {
+        metrics::record_error(TemporalErrorType::InputTooLong);
+        Self {
+            value: ptr::null_mut(),
+            error_type: TemporalErrorType::InputTooLong as i32,
+            error_message: into_c_string(input_too_long_message(param_name, len, limit)),
+        }
+    }
+}
+
+/// Converts a string for C. Interior NUL bytes, which C strings cannot hold, are
+/// replaced with U+FFFD so a valid value or message is never dropped.
+fn into_c_string(value: String) -> *mut c_char {
+    CString::new(value)
+        .unwrap_or_else(|e| {
+            let sanitized = String::from_utf8_lossy(&e.into_vec()).replace('\0', "\u{FFFD}");
+            CString::new(sanitized).unwrap_or_default()
+        })
+        .into_raw()
 }
 
 /// Frees a TemporalResult's allocated strings.
@@ -92,6 +187,324 @@ pub unsafe extern "C" fn temporal_free_result(result: *mut TemporalResult) {
     }
 }
 
+/// Moves the value string out of a result, leaving NULL behind so a later
+/// `temporal_free_result` won't free it. The caller owns the returned string and
+/// must free it with `temporal_free_string`. Returns NULL if there is no value.
+///
+/// # Safety
+/// The result must be NULL or have been returned by a temporal function.
+#[no_mangle]
+pub unsafe extern "C" fn temporal_result_take_value(result: *mut TemporalResult) -> *mut c_char {
+    if result.is_null() {
+        return ptr::null_mut();
+    }
+    std::mem::replace(&mut (*result).value, ptr::null_mut())
+}
+
+/// Moves the error message out of a result, like `temporal_result_take_value`.
+///
+/// # Safety
+/// The result must be NULL or have been returned by a temporal function.
+#[no_mangle]
+pub unsafe extern "C" fn temporal_result_take_error_message(result: *mut TemporalResult) -> *mut c_char {
+    if result.is_null() {
+        return ptr::null_mut();
+    }
+    std::mem::replace(&mut (*result).error_message, ptr::null_mut())
+}
+
+// Per-thread error slot for functions that return plain numeric codes instead of
+// a result struct. The message buffer is reused, so error paths don't allocate
+// a new C string unless the caller actually asks for the message.
+thread_local! {
+    static LAST_ERROR: std::cell::RefCell<(i32, String)> = const {
+        std::cell::RefCell::new((TemporalErrorType::None as i32, String::new()))
+    };
+}
+
+fn set_last_error(error_type: TemporalErrorType, message: std::fmt::Arguments) {
+    use std::fmt::Write;
+    metrics::record_error(error_type);
+    LAST_ERROR.with(|slot| {
+        let mut slot = slot.borrow_mut();
+        slot.0 = error_type as i32;
+        slot.1.clear();
+        let _ = slot.1.write_fmt(message);
+    });
+}
+
+fn clear_last_error() {
+    LAST_ERROR.with(|slot| {
+        let mut slot = slot.borrow_mut();
+        slot.0 = TemporalErrorType::None as i32;
+        slot.1.clear();
+    });
+}
+
+/// Returns the error type set by the last code-returning call on this thread (0 = none).
+#[no_mangle]
+pub extern "C" fn temporal_last_error_type() -> i32 {
+    LAST_ERROR.with(|slot| slot.borrow().0)
+}
+
+/// Returns a copy of the last error message on this thread, or NULL if there is none.
+/// The caller must free the returned string with `temporal_free_string`.
+#[no_mangle]
+pub extern "C" fn temporal_last_error_message() -> *mut c_char {
+    match last_error_message() {
+        Some(message) => into_c_string(message),
+        None => ptr::null_mut(),
+    }
+}
+
+fn last_error_message() -> Option<String> {
+    LAST_ERROR.with(|slot| {
+        let slot = slot.borrow();
+        (slot.0 != TemporalErrorType::None as i32).then(|| slot.1.clone())
+    })
+}
+
+// Set while a JNI `OrNull` variant runs: the JNI throw helpers record errors as the
+// last error instead of throwing them.
+#[cfg(any(test, all(target_os = "android", feature = "jni")))]
+thread_local! {
+    static SUPPRESS_THROW: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
+
+/// Runs a throwing JNI function with errors recorded as the last error instead.
+/// The last error is cleared first, so it is `None` after a successful call.
+#[cfg(any(test, all(target_os = "android", feature = "jni")))]
+fn without_throwing<T>(f: impl FnOnce() -> T) -> T {
+    struct Restore(bool);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            SUPPRESS_THROW.with(|flag| flag.set(self.0));
+        }
+    }
+    clear_last_error();
+    let _restore = Restore(SUPPRESS_THROW.with(|flag| flag.replace(true)));
+    f()
+}
+
+/// Called by the JNI throw helpers before throwing. Under `without_throwing` it sets
+/// the last error and returns true, and the helper must not throw.
+#[cfg(any(test, all(target_os = "android", feature = "jni")))]
+fn record_instead_of_throwing(error_type: TemporalErrorType, message: &str) -> bool {
+    if !SUPPRESS_THROW.with(|flag| flag.get()) {
+        return false;
+    }
+    set_last_error(error_type, format_args!("{}", message));
+    true
+}
+
+/// Version of the FFI surface. Bump whenever a `#[repr(C)]` struct layout, a JNI
+/// array schema or an existing function signature changes, so prebuilt bindings
+/// can detect a mismatched native library.
+pub const TEMPORAL_FFI_API_VERSION: u32 = 8;
+
+/// Returns TEMPORAL_FFI_API_VERSION of the loaded library.
+#[no_mangle]
+pub extern "C" fn temporal_ffi_api_version() -> u32 {
+    TEMPORAL_FFI_API_VERSION
+}
+
+/// Sizes in bytes of the structs shared with C, so native modules can check them
+/// against their compiled `sizeof` values at startup.
+#[repr(C)]
+pub struct TemporalStructSizes {
+    pub temporal_result: u32,
+    pub compare_result: u32,
+    pub plain_time_components: u32,
+    pub plain_date_components: u32,
+    pub plain_date_time_components: u32,
+    pub plain_year_month_components: u32,
+    pub plain_month_day_components: u32,
+    pub duration_components: u32,
+    pub duration_components_f64: u32,
+    pub zoned_date_time_components: u32,
+    pub clock_fields: u32,
+    pub epoch_nanoseconds_parts: u32,
+    pub time_zone_abbreviation_info: u32,
+}
+
+/// Returns the sizes of the structs shared with C.
+#[no_mangle]
+pub extern "C" fn temporal_struct_sizes() -> TemporalStructSizes {
+    use std::mem::size_of;
+    TemporalStructSizes {
+        temporal_result: size_of::<TemporalResult>() as u32,
+        compare_result: size_of::<CompareResult>() as u32,
+        plain_time_components: size_of::<PlainTimeComponents>() as u32,
+        plain_date_components: size_of::<PlainDateComponents>() as u32,
+        plain_date_time_components: size_of::<PlainDateTimeComponents>() as u32,
+        plain_year_month_components: size_of::<PlainYearMonthComponents>() as u32,
+        plain_month_day_components: size_of::<PlainMonthDayComponents>() as u32,
+        duration_components: size_of::<DurationComponents>() as u32,
+        duration_components_f64: size_of::<DurationComponentsF64>() as u32,
+        zoned_date_time_components: size_of::<ZonedDateTimeComponents>() as u32,
+        clock_fields: size_of::<ClockFields>() as u32,
+        epoch_nanoseconds_parts: size_of::<EpochNanosecondsParts>() as u32,
+        time_zone_abbreviation_info: size_of::<TimeZoneAbbreviationInfo>() as u32,
+    }
+}
+
+/// Slot names of the `long[]` results of the JNI bindings, in index order. The JNI
+/// functions size their arrays from these, and `temporal_schema_json` publishes them
+/// so the Kotlin layer can check the indices it reads against the loaded library.
+const PLAIN_TIME_SLOTS: [&str; 6] = ["hour", "minute", "second", "millisecond", "microsecond", "nanosecond"];
+const PLAIN_DATE_SLOTS: [&str; 15] = [
+    "year", "month", "day", "dayOfWeek", "dayOfYear", "weekOfYear", "yearOfWeek", "daysInWeek", "daysInMonth",
+    "daysInYear", "monthsInYear", "inLeapYear", "quarter", "dayOfQuarter", "weekMask",
+];
+const PLAIN_DATE_TIME_SLOTS: [&str; 21] = [
+    "year", "month", "day", "dayOfWeek", "dayOfYear", "weekOfYear", "yearOfWeek", "daysInWeek", "daysInMonth",
+    "daysInYear", "monthsInYear", "inLeapYear", "hour", "minute", "second", "millisecond", "microsecond",
+    "nanosecond", "quarter", "dayOfQuarter", "weekMask",
+];
+/// "reserved" is always 0: a PlainYearMonth has no day.
+const PLAIN_YEAR_MONTH_SLOTS: [&str; 8] =
+    ["year", "month", "reserved", "daysInMonth", "daysInYear", "monthsInYear", "inLeapYear", "eraYear"];
+const PLAIN_MONTH_DAY_SLOTS: [&str; 2] = ["month", "day"];
+const DURATION_SLOTS: [&str; 17] = [
+    "years", "months", "weeks", "days", "hours", "minutes", "seconds", "milliseconds", "microseconds",
+    "nanoseconds", "sign", "blank", "microsecondsHigh", "microsecondsLow", "nanosecondsHigh", "nanosecondsLow",
+    "clamped",
+];
+const ZONED_DATE_TIME_SLOTS: [&str; 20] = [
+    "year", "month", "day", "dayOfWeek", "dayOfYear", "weekOfYear", "yearOfWeek", "daysInWeek", "daysInMonth",
+    "daysInYear", "monthsInYear", "inLeapYear", "hour", "minute", "second", "millisecond", "microsecond",
+    "nanosecond", "offsetNanoseconds", "weekMask",
+];
+/// Epoch nanoseconds as high * 2^64 + low, with low read as unsigned.
+const EPOCH_NANOSECONDS_PARTS_SLOTS: [&str; 2] = ["high", "low"];
+
+/// Bits of the weekMask slot. A clear bit means the calendar has no week numbering
+/// and the matching slot holds 0. Mirrors `TemporalNative.WEEK_OF_YEAR_VALID`/`YEAR_OF_WEEK_VALID`.
+const WEEK_OF_YEAR_VALID: i64 = 1 << 0;
+const YEAR_OF_WEEK_VALID: i64 = 1 << 1;
+
+/// Returns the weekOfYear and yearOfWeek slots plus their validity mask.
+#[cfg(any(test, all(target_os = "android", feature = "jni")))]
+fn week_info(week_of_year: Option<u8>, year_of_week: Option<i32>) -> (i64, i64, i64) {
+    let mut mask = 0;
+    if week_of_year.is_some() {
+        mask |= WEEK_OF_YEAR_VALID;
+    }
+    if year_of_week.is_some() {
+        mask |= YEAR_OF_WEEK_VALID;
+    }
+    (week_of_year.map_or(0, i64::from), year_of_week.map_or(0, i64::from), mask)
+}
+
+/// `{"version":..,"arrays":{"plainTime":["hour",...],...},"weekMask":{...}}`
+fn schema_json() -> String {
+    let arrays: [(&str, &[&str]); 8] = [
+        ("plainTime", &PLAIN_TIME_SLOTS),
+        ("plainDate", &PLAIN_DATE_SLOTS),
+        ("plainDateTime", &PLAIN_DATE_TIME_SLOTS),
+        ("plainYearMonth", &PLAIN_YEAR_MONTH_SLOTS),
+        ("plainMonthDay", &PLAIN_MONTH_DAY_SLOTS),
+        ("duration", &DURATION_SLOTS),
+        ("zonedDateTime", &ZONED_DATE_TIME_SLOTS),
+        ("epochNanosecondsParts", &EPOCH_NANOSECONDS_PARTS_SLOTS),
+    ];
+    let strings = |slots: &[&str]| JsonValue::Array(slots.iter().map(|slot| JsonValue::String(slot.to_string())).collect());
+    JsonValue::Object(vec![
+        ("version".to_string(), JsonValue::Number(TEMPORAL_FFI_API_VERSION.to_string())),
+        (
+            "arrays".to_string(),
+            JsonValue::Object(arrays.iter().map(|(name, slots)| (name.to_string(), strings(slots))).collect()),
+        ),
+        (
+            "weekMask".to_string(),
+            JsonValue::Object(vec![
+                ("weekOfYear".to_string(), JsonValue::Number(WEEK_OF_YEAR_VALID.to_string())),
+                ("yearOfWeek".to_string(), JsonValue::Number(YEAR_OF_WEEK_VALID.to_string())),
+            ]),
+        ),
+    ])
+    .to_string()
+}
+
+/// Returns the index layout of the JNI `long[]` results as JSON (see `schema_json`).
+#[no_mangle]
+pub extern "C" fn temporal_schema_json() -> TemporalResult {
+    TemporalResult::success(schema_json())
+}
+
+/// Fails the build if a shared struct's size, alignment or field offsets drift from
+/// the layout declared in temporal_rn.h.
+macro_rules! assert_layout {
+    ($ty:ty, size = $size:expr, align = $align:expr, { $($field:ident: $offset:expr),* $(,)? }) => {
+        const _: () = {
+            assert!(std::mem::size_of::<$ty>() == $size);
+            assert!(std::mem::align_of::<$ty>() == $align);
+            $(assert!(std::mem::offset_of!($ty, $field) == $offset);)*
+        };
+    };
+}
+
+const PTR: usize = std::mem::size_of::<*mut c_char>();
+
+assert_layout!(TemporalResult, size = 3 * PTR, align = PTR, { value: 0, error_type: PTR, error_message: 2 * PTR });
+assert_layout!(CompareResult, size = 8 + PTR, align = PTR, { value: 0, error_type: 4, error_message: 8 });
+assert_layout!(PlainTimeComponents, size = 12, align = 2, {
+    hour: 0, minute: 1, second: 2, millisecond: 4, microsecond: 6, nanosecond: 8, is_valid: 10,
+});
+assert_layout!(PlainDateComponents, size = 32, align = 4, {
+    year: 0, month: 4, day: 5, day_of_week: 6, day_of_year: 8, week_of_year: 10, year_of_week: 12,
+    days_in_week: 16, days_in_month: 18, days_in_year: 20, months_in_year: 22, in_leap_year: 24,
+    has_week_info: 25, quarter: 26, day_of_quarter: 27, is_valid: 28,
+});
+assert_layout!(PlainDateTimeComponents, size = 40, align = 4, {
+    year: 0, month: 4, day: 5, day_of_week: 6, day_of_year: 8, week_of_year: 10, year_of_week: 12,
+    days_in_week: 16, days_in_month: 18, days_in_year: 20, months_in_year: 22, in_leap_year: 24,
+    hour: 25, minute: 26, second: 27, millisecond: 28, microsecond: 30, nanosecond: 32,
+    has_week_info: 34, quarter: 35, day_of_quarter: 36, is_valid: 37,
+});
+assert_layout!(PlainYearMonthComponents, size = 24, align = 4, {
+    year: 0, month: 4, day: 5, days_in_month: 6, days_in_year: 8, months_in_year: 10, in_leap_year: 12,
+    era_year: 16, is_valid: 20,
+});
+assert_layout!(PlainMonthDayComponents, size = 3, align = 1, { month: 0, day: 1, is_valid: 2 });
+// i64 is only 4-byte aligned on 32-bit x86 (Android emulator), which shifts the
+// offsets below; the C compiler agrees there, so only the values differ.
+#[cfg(not(target_arch = "x86"))]
+assert_layout!(DurationComponents, size = 128, align = 8, {
+    years: 0, months: 8, weeks: 16, days: 24, hours: 32, minutes: 40, seconds: 48, milliseconds: 56,
+    microseconds: 64, nanoseconds: 72, sign: 80, is_valid: 81, microseconds_high: 88,
+    microseconds_low: 96, nanoseconds_high: 104, nanoseconds_low: 112, is_clamped: 120,
+});
+assert_layout!(DurationComponentsF64, size = 88, align = std::mem::align_of::<f64>(), {
+    years: 0, months: 8, weeks: 16, days: 24, hours: 32, minutes: 40, seconds: 48, milliseconds: 56,
+    microseconds: 64, nanoseconds: 72, sign: 80, is_valid: 81, is_exact: 82, inexact_fields: 84,
+});
+#[cfg(not(target_arch = "x86"))]
+assert_layout!(ZonedDateTimeComponents, size = 56, align = 8, {
+    year: 0, month: 4, day: 5, day_of_week: 6, day_of_year: 8, week_of_year: 10, year_of_week: 12,
+    days_in_week: 16, days_in_month: 18, days_in_year: 20, months_in_year: 22, in_leap_year: 24,
+    hour: 25, minute: 26, second: 27, millisecond: 28, microsecond: 30, nanosecond: 32,
+    offset_nanoseconds: 40, has_week_info: 48, is_valid: 49,
+});
+#[cfg(not(target_arch = "x86"))]
+assert_layout!(EpochNanosecondsParts, size = 24, align = 8, { high: 0, low: 8, is_valid: 16 });
+#[cfg(not(target_arch = "x86"))]
+assert_layout!(TimeZoneAbbreviationInfo, size = 16, align = 8, { offset_nanoseconds: 0, is_dst: 8, non_authoritative: 9 });
+assert_layout!(ZdtFromOptions, size = 24, align = std::mem::align_of::<i64>(), {
+    size: 0, disambiguation: 4, offset: 8, has_offset: 12, offset_nanoseconds: 16,
+});
+assert_layout!(DiffOptions, size = 24, align = std::mem::align_of::<i64>(), {
+    size: 0, largest_unit: 4, smallest_unit: 8, rounding_mode: 12, rounding_increment: 16,
+});
+#[cfg(not(target_arch = "x86"))]
+assert_layout!(RoundOptions, size = 24, align = 8, {
+    size: 0, smallest_unit: 4, rounding_mode: 8, rounding_increment: 16,
+});
+#[cfg(not(target_arch = "x86"))]
+assert_layout!(ClockFields, size = 16, align = 8, {
+    offset_nanoseconds: 0, hour: 8, minute: 9, second: 10, is_valid: 11,
+});
+
 /// Returns the current instant as an ISO 8601 string (e.g., "2024-01-15T10:30:45.123Z").
 /// The caller is responsible for freeing the returned string using `temporal_free_string`.
 ///
@@ -99,10 +512,7 @@ pub unsafe extern "C" fn temporal_free_result(result: *mut TemporalResult) {
 #[no_mangle]
 pub extern "C" fn temporal_instant_now() -> *mut c_char {
     match get_instant_now_string() {
-        Ok(s) => match CString::new(s) {
-            Ok(c_str) => c_str.into_raw(),
-            Err(_) => ptr::null_mut(),
-        },
+        Ok(s) => into_c_string(s),
         Err(_) => ptr::null_mut(),
     }
 }
@@ -122,7 +532,7 @@ fn get_instant_now_string() -> Result<String, Box<dyn std::error::Error>> {
     let now = Temporal::utc_now();
     let instant = now.instant()?;
     let provider = &*COMPILED_TZ_PROVIDER;
-    let iso_string = instant.to_ixdtf_string_with_provider(None, Default::default(), &provider)?;
+    let iso_string = instant.to_ixdtf_string_with_provider(None, display::rounding(), &provider)?;
     Ok(iso_string)
 }
 
@@ -137,18 +547,109 @@ pub extern "C" fn temporal_instant_from_string(s: *const c_char) -> TemporalResu
         Ok(s) => s,
         Err(e) => return e,
     };
-    match Instant::from_str(s_str) {
+    let normalized = canonical_cache::canonical(Kind::Instant, s_str, || match Instant::from_str(s_str) {
         Ok(instant) => {
             let provider = &*COMPILED_TZ_PROVIDER;
-            match instant.to_ixdtf_string_with_provider(None, Default::default(), &provider) {
-                Ok(s) => TemporalResult::success(s),
-                Err(e) => TemporalResult::range_error(&format!("Failed to format instant: {}", e)),
-            }
-        },
-        Err(e) => TemporalResult::range_error(&format!("Invalid instant '{}': {}", s_str, e)),
+            instant
+                .to_ixdtf_string_with_provider(None, display::rounding(), &provider)
+                .map_err(|e| TemporalResult::range_error(&format!("Failed to format instant: {}", e)))
+        }
+        Err(e) => Err(TemporalResult::range_error(&format!("Invalid instant '{}': {}", s_str, e))),
+    });
+    match normalized {
+        Ok(s) => TemporalResult::success(s),
+        Err(e) => e,
+    }
+}
+
+/// Formats an Instant with an explicit precision, for logs and APIs that need a stable
+/// width. `precision` is "auto", "0"-"9" fractional second digits, or a smallest unit
+/// ("minute", "second", "millisecond", "microsecond", "nanosecond"); the value is
+/// truncated. NULL uses the default display options.
+///
+/// `offset_style` is "Z" (the default for NULL) or "numeric", which writes "+00:00"
+/// for backends that require a numeric RFC 3339 offset.
+#[no_mangle]
+pub extern "C" fn temporal_instant_to_string(
+    s: *const c_char,
+    precision: *const c_char,
+    offset_style: *const c_char,
+) -> TemporalResult {
+    let instant = match parse_instant(s, "instant") {
+        Ok(i) => i,
+        Err(e) => return e,
+    };
+    let numeric_offset = if offset_style.is_null() {
+        false
+    } else {
+        match parse_c_str(offset_style, "offset style") {
+            Ok("Z") => false,
+            Ok("numeric") => true,
+            Ok(other) => return TemporalResult::range_error(&format!("Invalid offset style: {} (expected Z or numeric)", other)),
+            Err(e) => return e,
+        }
+    };
+    format_instant_with_precision(&instant, precision, numeric_offset)
+}
+
+/// Returns the current instant formatted with an explicit precision; see
+/// `temporal_instant_to_string`. Unlike `temporal_instant_now`, errors are reported.
+#[no_mangle]
+pub extern "C" fn temporal_instant_now_with_precision(precision: *const c_char) -> TemporalResult {
+    match Temporal::utc_now().instant() {
+        Ok(instant) => format_instant_with_precision(&instant, precision, false),
+        Err(e) => TemporalResult::range_error(&format!("Failed to get current instant: {}", e)),
+    }
+}
+
+/// Returns the current time as epoch milliseconds (floored), read straight from
+/// the system clock without building or formatting an Instant.
+#[no_mangle]
+pub extern "C" fn temporal_now_epoch_milliseconds() -> i64 {
+    now_epoch_nanoseconds().div_euclid(1_000_000) as i64
+}
+
+/// Returns the current time as epoch nanoseconds in a decimal string (an i64 only
+/// holds nanoseconds until 2262).
+#[no_mangle]
+pub extern "C" fn temporal_now_epoch_nanoseconds_string() -> TemporalResult {
+    TemporalResult::success(now_epoch_nanoseconds().to_string())
+}
+
+fn now_epoch_nanoseconds() -> i128 {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(since) => since.as_nanos() as i128,
+        Err(e) => -(e.duration().as_nanos() as i128),
+    }
+}
+
+/// Formats with "Z", or "+00:00" when `numeric_offset` is set.
+fn format_instant_with_precision(instant: &Instant, precision: *const c_char, numeric_offset: bool) -> TemporalResult {
+    let options = match parse_precision(precision) {
+        Ok(o) => o,
+        Err(e) => return e,
+    };
+    let provider = &*COMPILED_TZ_PROVIDER;
+    let time_zone = numeric_offset.then(TimeZone::utc);
+    match instant.to_ixdtf_string_with_provider(time_zone, options, &provider) {
+        Ok(s) => TemporalResult::success(s),
+        Err(e) => TemporalResult::range_error(&format!("Failed to format instant: {}", e)),
     }
 }
 
+/// Reads a per-call precision argument; NULL uses the default display options.
+fn parse_precision(precision: *const c_char) -> Result<ToStringRoundingOptions, TemporalResult> {
+    let precision = if precision.is_null() { None } else { Some(parse_c_str(precision, "precision")?) };
+    display::rounding_for_precision(precision).map_err(|message| TemporalResult::range_error(&message))
+}
+
+/// Reads a per-call calendarName argument; NULL uses the default display options.
+fn parse_calendar_name(calendar_name: *const c_char) -> Result<DisplayCalendar, TemporalResult> {
+    let name = if calendar_name.is_null() { None } else { Some(parse_c_str(calendar_name, "calendar name")?) };
+    display::calendar_for_name(name).map_err(|message| TemporalResult::range_error(&message))
+}
+
 /// Creates an Instant from epoch milliseconds.
 #[no_mangle]
 pub extern "C" fn temporal_instant_from_epoch_milliseconds(ms: i64) -> TemporalResult {
@@ -158,7 +659,7 @@ pub extern "C" fn temporal_instant_from_epoch_milliseconds(ms: i64) -> TemporalR
     match Instant::try_new(ns) {
         Ok(instant) => {
             let provider = &*COMPILED_TZ_PROVIDER;
-            match instant.to_ixdtf_string_with_provider(None, Default::default(), &provider) {
+            match instant.to_ixdtf_string_with_provider(None, display::rounding(), &provider) {
                 Ok(s) => TemporalResult::success(s),
                 Err(e) => TemporalResult::range_error(&format!("Failed to format instant: {}", e)),
             }
@@ -179,11 +680,20 @@ pub extern "C" fn temporal_instant_from_epoch_nanoseconds(ns_str: *const c_char)
         Ok(n) => n,
         Err(_) => return TemporalResult::range_error("Invalid nanoseconds string"),
     };
+    instant_from_epoch_ns(ns)
+}
+
+/// Creates an Instant from epoch nanoseconds given as `high * 2^64 + low`.
+#[no_mangle]
+pub extern "C" fn temporal_instant_from_epoch_nanoseconds_parts(high: i64, low: u64) -> TemporalResult {
+    instant_from_epoch_ns(join_i128(high, low))
+}
 
+fn instant_from_epoch_ns(ns: i128) -> TemporalResult {
     match Instant::try_new(ns) {
         Ok(instant) => {
             let provider = &*COMPILED_TZ_PROVIDER;
-            match instant.to_ixdtf_string_with_provider(None, Default::default(), &provider) {
+            match instant.to_ixdtf_string_with_provider(None, display::rounding(), &provider) {
                 Ok(s) => TemporalResult::success(s),
                 Err(e) => TemporalResult::range_error(&format!("Failed to format instant: {}", e)),
             }
@@ -221,6 +731,36 @@ pub extern "C" fn temporal_instant_epoch_nanoseconds(s: *const c_char) -> Tempor
     TemporalResult::success(ns.0.to_string())
 }
 
+/// Epoch nanoseconds split into 64-bit halves for callers without a 128-bit integer:
+/// the value is `high * 2^64 + low`.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct EpochNanosecondsParts {
+    pub high: i64,
+    pub low: u64,
+    pub is_valid: i8,
+}
+
+impl EpochNanosecondsParts {
+    fn new(ns: i128) -> Self {
+        let (high, low) = split_i128(ns);
+        Self { high, low, is_valid: 1 }
+    }
+}
+
+/// Fills `out` with the epoch nanoseconds of an Instant. On error `is_valid` is 0.
+#[no_mangle]
+pub extern "C" fn temporal_instant_epoch_nanoseconds_parts(s: *const c_char, out: *mut EpochNanosecondsParts) {
+    if out.is_null() {
+        return;
+    }
+    let parts = match parse_instant(s, "instant") {
+        Ok(instant) => EpochNanosecondsParts::new(instant.epoch_nanoseconds().0),
+        Err(_) => EpochNanosecondsParts::default(),
+    };
+    unsafe { *out = parts };
+}
+
 /// Adds a duration to an instant.
 #[no_mangle]
 pub extern "C" fn temporal_instant_add(instant_str: *const c_char, duration_str: *const c_char) -> TemporalResult {
@@ -232,11 +772,14 @@ pub extern "C" fn temporal_instant_add(instant_str: *const c_char, duration_str:
         Ok(d) => d,
         Err(e) => return e,
     };
-    
+    if let Err(message) = check_instant_duration(&duration, "add") {
+        return TemporalResult::range_error(&message);
+    }
+
     match instant.add(&duration) {
         Ok(result) => {
             let provider = &*COMPILED_TZ_PROVIDER;
-            match result.to_ixdtf_string_with_provider(None, Default::default(), &provider) {
+            match result.to_ixdtf_string_with_provider(None, display::rounding(), &provider) {
                 Ok(s) => TemporalResult::success(s),
                 Err(e) => TemporalResult::range_error(&format!("Failed to format instant: {}", e)),
             }
@@ -256,11 +799,14 @@ pub extern "C" fn temporal_instant_subtract(instant_str: *const c_char, duration
         Ok(d) => d,
         Err(e) => return e,
     };
-    
+    if let Err(message) = check_instant_duration(&duration, "subtract") {
+        return TemporalResult::range_error(&message);
+    }
+
     match instant.subtract(&duration) {
         Ok(result) => {
             let provider = &*COMPILED_TZ_PROVIDER;
-            match result.to_ixdtf_string_with_provider(None, Default::default(), &provider) {
+            match result.to_ixdtf_string_with_provider(None, display::rounding(), &provider) {
                 Ok(s) => TemporalResult::success(s),
                 Err(e) => TemporalResult::range_error(&format!("Failed to format instant: {}", e)),
             }
@@ -274,20 +820,86 @@ pub extern "C" fn temporal_instant_subtract(instant_str: *const c_char, duration
 pub extern "C" fn temporal_instant_compare(a: *const c_char, b: *const c_char) -> CompareResult {
     let instant_a = match parse_instant(a, "first instant") {
         Ok(i) => i,
-        Err(e) => return CompareResult::range_error(
-            &unsafe { std::ffi::CStr::from_ptr(e.error_message) }.to_string_lossy()
-        ),
+        Err(e) => return CompareResult::from_error(e),
     };
     let instant_b = match parse_instant(b, "second instant") {
         Ok(i) => i,
-        Err(e) => return CompareResult::range_error(
-            &unsafe { std::ffi::CStr::from_ptr(e.error_message) }.to_string_lossy()
-        ),
+        Err(e) => return CompareResult::from_error(e),
     };
     
     CompareResult::success(instant_a.cmp(&instant_b) as i32)
 }
 
+/// Returns the earliest instant from a newline-delimited list of instants.
+#[no_mangle]
+pub extern "C" fn temporal_instant_min(list: *const c_char) -> TemporalResult {
+    list_extreme(list, "instant", Instant::from_str, Instant::cmp, std::cmp::Ordering::Less, format_instant)
+}
+
+/// Returns the latest instant from a newline-delimited list of instants.
+#[no_mangle]
+pub extern "C" fn temporal_instant_max(list: *const c_char) -> TemporalResult {
+    list_extreme(list, "instant", Instant::from_str, Instant::cmp, std::cmp::Ordering::Greater, format_instant)
+}
+
+/// Clamps an instant into the inclusive range [min, max].
+#[no_mangle]
+pub extern "C" fn temporal_instant_clamp(
+    value: *const c_char,
+    min: *const c_char,
+    max: *const c_char,
+) -> TemporalResult {
+    let value = match parse_instant(value, "value") {
+        Ok(x) => x,
+        Err(e) => return e,
+    };
+    let min = match parse_instant(min, "min") {
+        Ok(x) => x,
+        Err(e) => return e,
+    };
+    let max = match parse_instant(max, "max") {
+        Ok(x) => x,
+        Err(e) => return e,
+    };
+    match clamp_by(value, min, max, Instant::cmp) {
+        Ok(instant) => match format_instant(&instant) {
+            Ok(s) => TemporalResult::success(s),
+            Err(e) => TemporalResult::range_error(&format!("Failed to format instant: {}", e)),
+        },
+        Err(e) => e,
+    }
+}
+
+/// Instants have no calendar, so per spec a duration with years, months, weeks or
+/// days cannot be added to one. Checked before temporal_rs so the message names the
+/// offending units.
+fn check_instant_duration(duration: &Duration, operation: &str) -> Result<(), String> {
+    let date_units: Vec<&str> = [
+        ("years", duration.years()),
+        ("months", duration.months()),
+        ("weeks", duration.weeks()),
+        ("days", duration.days()),
+    ]
+    .into_iter()
+    .filter(|&(_, value)| value != 0)
+    .map(|(name, _)| name)
+    .collect();
+    if date_units.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "Instants cannot {} {}; use hours or smaller units, or convert to a ZonedDateTime",
+            operation,
+            date_units.join("/")
+        ))
+    }
+}
+
+fn format_instant(instant: &Instant) -> Result<String, TemporalError> {
+    let provider = &*COMPILED_TZ_PROVIDER;
+    instant.to_ixdtf_string_with_provider(None, display::rounding(), &provider)
+}
+
 /// Computes the difference between two Instants (until).
 #[no_mangle]
 pub extern "C" fn temporal_instant_until(
@@ -346,15 +958,9 @@ pub extern "C" fn temporal_instant_until(
         None
     };
 
-    let increment = if rounding_increment > 0 {
-        rounding_increment as u32
-    } else {
-        1
-    };
-    
-    let increment_opt = match RoundingIncrement::try_new(increment) {
+    let increment_opt = match rounding_increment_from_i64(rounding_increment, smallest, IncrementContext::Difference) {
         Ok(i) => Some(i),
-        Err(e) => return TemporalResult::range_error(&format!("Invalid rounding increment: {}", e)),
+        Err(e) => return e,
     };
 
     let mut options = temporal_rs::options::DifferenceSettings::default();
@@ -427,15 +1033,9 @@ pub extern "C" fn temporal_instant_since(
         None
     };
 
-    let increment = if rounding_increment > 0 {
-        rounding_increment as u32
-    } else {
-        1
-    };
-    
-    let increment_opt = match RoundingIncrement::try_new(increment) {
+    let increment_opt = match rounding_increment_from_i64(rounding_increment, smallest, IncrementContext::Difference) {
         Ok(i) => Some(i),
-        Err(e) => return TemporalResult::range_error(&format!("Invalid rounding increment: {}", e)),
+        Err(e) => return e,
     };
 
     let mut options = temporal_rs::options::DifferenceSettings::default();
@@ -489,15 +1089,9 @@ pub extern "C" fn temporal_instant_round(
         RoundingMode::HalfExpand
     };
 
-    let increment = if rounding_increment > 0 {
-        rounding_increment as u32
-    } else {
-        1
-    };
-    
-    let increment_opt = match RoundingIncrement::try_new(increment) {
+    let increment_opt = match rounding_increment_from_i64(rounding_increment, Some(unit), IncrementContext::InstantRound) {
         Ok(i) => i,
-        Err(e) => return TemporalResult::range_error(&format!("Invalid rounding increment: {}", e)),
+        Err(e) => return e,
     };
 
     let mut options = RoundingOptions::default();
@@ -508,7 +1102,7 @@ pub extern "C" fn temporal_instant_round(
     match instant.round(options) {
         Ok(result) => {
             let provider = &*COMPILED_TZ_PROVIDER;
-            match result.to_ixdtf_string_with_provider(None, Default::default(), &provider) {
+            match result.to_ixdtf_string_with_provider(None, display::rounding(), &provider) {
                 Ok(s) => TemporalResult::success(s),
                 Err(e) => TemporalResult::range_error(&format!("Failed to format instant: {}", e)),
             }
@@ -556,7 +1150,7 @@ pub extern "C" fn temporal_instant_to_zoned_date_time(
     };
     
     match ZonedDateTime::try_new(instant.epoch_nanoseconds().0, tz, calendar) {
-        Ok(zdt) => match zdt.to_ixdtf_string(DisplayOffset::Auto, DisplayTimeZone::Auto, DisplayCalendar::Auto, ToStringRoundingOptions::default()) {
+        Ok(zdt) => match zdt.to_ixdtf_string(display::offset(), display::time_zone(), display::calendar(), display::rounding()) {
             Ok(s) => TemporalResult::success(s),
             Err(e) => TemporalResult::range_error(&format!("Failed to format zoned date time: {}", e)),
         },
@@ -625,7 +1219,7 @@ fn get_now_zoned_date_time_string(tz_id: &str) -> Result<String, Box<dyn std::er
     let instant = now.instant()?;
     let time_zone = TimeZone::try_from_str(tz_id)?;
     let zdt = instant.to_zoned_date_time_iso(time_zone)?;
-    Ok(zdt.to_ixdtf_string(DisplayOffset::Auto, DisplayTimeZone::Auto, DisplayCalendar::Auto, ToStringRoundingOptions::default())?)
+    Ok(zdt.to_ixdtf_string(display::offset(), display::time_zone(), display::calendar(), display::rounding())?)
 }
 
 fn get_now_plain_date_time_string(tz_id: &str) -> Result<String, Box<dyn std::error::Error>> {
@@ -635,7 +1229,7 @@ fn get_now_plain_date_time_string(tz_id: &str) -> Result<String, Box<dyn std::er
     let zdt = instant.to_zoned_date_time_iso(time_zone)?;
     Ok(zdt
         .to_plain_date_time()
-        .to_ixdtf_string(ToStringRoundingOptions::default(), DisplayCalendar::Auto)?)
+        .to_ixdtf_string(display::rounding(), display::calendar())?)
 }
 
 fn get_now_plain_date_string(tz_id: &str) -> Result<String, Box<dyn std::error::Error>> {
@@ -643,7 +1237,7 @@ fn get_now_plain_date_string(tz_id: &str) -> Result<String, Box<dyn std::error::
     let instant = now.instant()?;
     let time_zone = TimeZone::try_from_str(tz_id)?;
     let zdt = instant.to_zoned_date_time_iso(time_zone)?;
-    Ok(zdt.to_plain_date().to_ixdtf_string(DisplayCalendar::Auto))
+    Ok(zdt.to_plain_date().to_ixdtf_string(display::calendar()))
 }
 
 fn get_now_plain_time_string(tz_id: &str) -> Result<String, Box<dyn std::error::Error>> {
@@ -653,7 +1247,86 @@ fn get_now_plain_time_string(tz_id: &str) -> Result<String, Box<dyn std::error::
     let zdt = instant.to_zoned_date_time_iso(time_zone)?;
     Ok(zdt
         .to_plain_time()
-        .to_ixdtf_string(ToStringRoundingOptions::default())?)
+        .to_ixdtf_string(display::rounding())?)
+}
+
+// ============================================================================
+// Time zone change tracking
+// ============================================================================
+
+// Bumped whenever the host reports a device time zone change. Anything that caches
+// values derived from the device zone must remember the generation it was built
+// at and rebuild once `temporal_tz_generation` no longer matches.
+static TZ_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Returns the current time zone generation, starting at 0.
+#[no_mangle]
+pub extern "C" fn temporal_tz_generation() -> u64 {
+    TZ_GENERATION.load(AtomicOrdering::Acquire)
+}
+
+/// Records a device time zone change (e.g. from NSSystemTimeZoneDidChangeNotification
+/// or ACTION_TIMEZONE_CHANGED) and returns the new generation.
+#[no_mangle]
+pub extern "C" fn temporal_notify_tz_changed() -> u64 {
+    TZ_GENERATION.fetch_add(1, AtomicOrdering::AcqRel) + 1
+}
+
+// ============================================================================
+// Warm-up
+// ============================================================================
+
+/// Builds the time zone database and loads the zones in `tz_ids_joined` (a
+/// newline-delimited list; NULL for none) plus the device zone, so the first real use
+/// of each zone doesn't pay for parsing its rules. Call it from a background thread
+/// during app start; other functions can run concurrently and simply find the zones
+/// already loaded.
+///
+/// Returns the number of listed zones loaded. The device zone is skipped if the host
+/// can't report one. Returns -1 on the first id that does not resolve; details are
+/// available via `temporal_last_error_type` and `temporal_last_error_message`.
+#[no_mangle]
+pub extern "C" fn temporal_warmup(tz_ids_joined: *const c_char) -> i32 {
+    clear_last_error();
+    let ids = if tz_ids_joined.is_null() {
+        ""
+    } else {
+        match read_with_last_error(tz_ids_joined, "warmup", "time zone ids", true) {
+            Some(s) => s,
+            None => return -1,
+        }
+    };
+    match warmup(ids) {
+        Ok(count) => count,
+        Err((kind, message)) => {
+            set_last_error(kind, format_args!("{}", message));
+            -1
+        }
+    }
+}
+
+/// The list as a whole is checked against the bulk input limit by the callers; each
+/// id is checked against the per-string one here.
+fn warmup(ids: &str) -> Result<i32, (TemporalErrorType, String)> {
+    let provider = &*COMPILED_TZ_PROVIDER;
+    let now_ns = now_epoch_nanoseconds();
+    // Resolving an offset is what loads a zone's transition data into the provider
+    let load = |tz: TimeZone| ZonedDateTime::try_new(now_ns, tz, Calendar::default()).map(|zdt| zdt.offset_nanoseconds());
+    if let Ok(tz) = Temporal::local_now().time_zone_with_provider(provider) {
+        let _ = load(tz);
+    }
+    let mut count = 0;
+    for (index, id) in split_list(ids).enumerate() {
+        if id.len() > max_input_length() {
+            let name = format!("time zone id at index {}", index);
+            return Err((TemporalErrorType::InputTooLong, input_too_long_message(&name, id.len(), max_input_length())));
+        }
+        if let Err(e) = TimeZone::try_from_str(id).and_then(load) {
+            return Err((TemporalErrorType::RangeError, format!("Invalid time zone at index {} '{}': {}", index, id, e)));
+        }
+        count += 1;
+    }
+    Ok(count)
 }
 
 // ============================================================================
@@ -694,12 +1367,15 @@ pub extern "C" fn temporal_plain_time_from_string(s: *const c_char) -> TemporalR
         Ok(s) => s,
         Err(e) => return e,
     };
-    match PlainTime::from_str(s_str) {
-        Ok(time) => match time.to_ixdtf_string(ToStringRoundingOptions::default()) {
-            Ok(s) => TemporalResult::success(s),
-            Err(e) => TemporalResult::range_error(&format!("Failed to format plain time: {}", e)),
-        },
-        Err(e) => TemporalResult::range_error(&format!("Invalid plain time '{}': {}", s_str, e)),
+    let normalized = canonical_cache::canonical(Kind::PlainTime, s_str, || match PlainTime::from_str(s_str) {
+        Ok(time) => time
+            .to_ixdtf_string(display::rounding())
+            .map_err(|e| TemporalResult::range_error(&format!("Failed to format plain time: {}", e))),
+        Err(e) => Err(TemporalResult::range_error(&format!("Invalid plain time '{}': {}", s_str, e))),
+    });
+    match normalized {
+        Ok(s) => TemporalResult::success(s),
+        Err(e) => e,
     }
 }
 
@@ -736,7 +1412,7 @@ pub extern "C" fn temporal_plain_time_from_components(
     }
 
     match PlainTime::new(hour, minute, second, millisecond, microsecond, nanosecond) {
-        Ok(time) => match time.to_ixdtf_string(ToStringRoundingOptions::default()) {
+        Ok(time) => match time.to_ixdtf_string(display::rounding()) {
             Ok(s) => TemporalResult::success(s),
             Err(e) => TemporalResult::range_error(&format!("Failed to format plain time: {}", e)),
         },
@@ -789,7 +1465,7 @@ pub extern "C" fn temporal_plain_time_add(time_str: *const c_char, duration_str:
     };
 
     match time.add(&duration) {
-        Ok(result) => match result.to_ixdtf_string(ToStringRoundingOptions::default()) {
+        Ok(result) => match result.to_ixdtf_string(display::rounding()) {
             Ok(s) => TemporalResult::success(s),
             Err(e) => TemporalResult::range_error(&format!("Failed to format plain time: {}", e)),
         },
@@ -810,7 +1486,7 @@ pub extern "C" fn temporal_plain_time_subtract(time_str: *const c_char, duration
     };
 
     match time.subtract(&duration) {
-        Ok(result) => match result.to_ixdtf_string(ToStringRoundingOptions::default()) {
+        Ok(result) => match result.to_ixdtf_string(display::rounding()) {
             Ok(s) => TemporalResult::success(s),
             Err(e) => TemporalResult::range_error(&format!("Failed to format plain time: {}", e)),
         },
@@ -818,20 +1494,17 @@ pub extern "C" fn temporal_plain_time_subtract(time_str: *const c_char, duration
     }
 }
 
-/// Compares two PlainTime objects.
+/// Compares two PlainTime objects field by field down to the nanosecond, so times
+/// that differ only in their last fractional digit never compare equal.
 #[no_mangle]
 pub extern "C" fn temporal_plain_time_compare(a: *const c_char, b: *const c_char) -> CompareResult {
     let time_a = match parse_plain_time(a, "first plain time") {
         Ok(t) => t,
-        Err(e) => return CompareResult::range_error(
-            &unsafe { std::ffi::CStr::from_ptr(e.error_message) }.to_string_lossy()
-        ),
+        Err(e) => return CompareResult::from_error(e),
     };
     let time_b = match parse_plain_time(b, "second plain time") {
         Ok(t) => t,
-        Err(e) => return CompareResult::range_error(
-            &unsafe { std::ffi::CStr::from_ptr(e.error_message) }.to_string_lossy()
-        ),
+        Err(e) => return CompareResult::from_error(e),
     };
 
     CompareResult::success(time_a.cmp(&time_b) as i32)
@@ -895,15 +1568,9 @@ pub extern "C" fn temporal_plain_time_until(
         None
     };
 
-    let increment = if rounding_increment > 0 {
-        rounding_increment as u32
-    } else {
-        1
-    };
-    
-    let increment_opt = match RoundingIncrement::try_new(increment) {
+    let increment_opt = match rounding_increment_from_i64(rounding_increment, smallest, IncrementContext::Difference) {
         Ok(i) => Some(i),
-        Err(e) => return TemporalResult::range_error(&format!("Invalid rounding increment: {}", e)),
+        Err(e) => return e,
     };
 
     let mut options = temporal_rs::options::DifferenceSettings::default();
@@ -976,15 +1643,9 @@ pub extern "C" fn temporal_plain_time_since(
         None
     };
 
-    let increment = if rounding_increment > 0 {
-        rounding_increment as u32
-    } else {
-        1
-    };
-    
-    let increment_opt = match RoundingIncrement::try_new(increment) {
+    let increment_opt = match rounding_increment_from_i64(rounding_increment, smallest, IncrementContext::Difference) {
         Ok(i) => Some(i),
-        Err(e) => return TemporalResult::range_error(&format!("Invalid rounding increment: {}", e)),
+        Err(e) => return e,
     };
 
     let mut options = temporal_rs::options::DifferenceSettings::default();
@@ -1038,15 +1699,9 @@ pub extern "C" fn temporal_plain_time_round(
         RoundingMode::HalfExpand
     };
 
-    let increment = if rounding_increment > 0 {
-        rounding_increment as u32
-    } else {
-        1
-    };
-    
-    let increment_opt = match RoundingIncrement::try_new(increment) {
+    let increment_opt = match rounding_increment_from_i64(rounding_increment, Some(unit), IncrementContext::Round) {
         Ok(i) => i,
-        Err(e) => return TemporalResult::range_error(&format!("Invalid rounding increment: {}", e)),
+        Err(e) => return e,
     };
 
     let mut options = RoundingOptions::default();
@@ -1055,7 +1710,7 @@ pub extern "C" fn temporal_plain_time_round(
     options.increment = Some(increment_opt);
 
     match time.round(options) {
-        Ok(t) => match t.to_ixdtf_string(ToStringRoundingOptions::default()) {
+        Ok(t) => match t.to_ixdtf_string(display::rounding()) {
             Ok(s) => TemporalResult::success(s),
             Err(e) => TemporalResult::range_error(&format!("Failed to format plain time: {}", e)),
         },
@@ -1082,6 +1737,11 @@ pub struct PlainDateComponents {
     pub days_in_year: u16,
     pub months_in_year: u16,
     pub in_leap_year: i8,
+    /// 1 when the calendar defines week numbering, 0 otherwise.
+    pub has_week_info: i8,
+    /// 1-4, or 0 for calendars without 12 months.
+    pub quarter: u8,
+    pub day_of_quarter: u8,
     pub is_valid: i8,
 }
 
@@ -1100,51 +1760,256 @@ impl Default for PlainDateComponents {
             days_in_year: 0,
             months_in_year: 0,
             in_leap_year: 0,
+            has_week_info: 0,
+            quarter: 0,
+            day_of_quarter: 0,
             is_valid: 0,
         }
     }
 }
 
 /// Parses an ISO 8601 string into a PlainDate and returns the normalized string.
+/// A registered custom calendar's annotation is kept (see `custom_calendar`).
 #[no_mangle]
 pub extern "C" fn temporal_plain_date_from_string(s: *const c_char) -> TemporalResult {
+    match custom_calendar::parse_custom_date(s) {
+        Some(Ok(date)) => return TemporalResult::success(date.to_ixdtf_string()),
+        Some(Err(e)) => return e,
+        None => {}
+    }
     let s_str = match parse_c_str(s, "plain date string") {
         Ok(s) => s,
         Err(e) => return e,
     };
-    match PlainDate::from_str(s_str) {
-        Ok(date) => TemporalResult::success(date.to_ixdtf_string(DisplayCalendar::Auto)),
-        Err(e) => TemporalResult::range_error(&format!("Invalid plain date '{}': {}", s_str, e)),
+    let normalized = canonical_cache::canonical(Kind::PlainDate, s_str, || match PlainDate::from_str(s_str) {
+        Ok(date) => Ok(date.to_ixdtf_string(display::calendar())),
+        Err(e) => Err(TemporalResult::range_error(&format!("Invalid plain date '{}': {}", s_str, e))),
+    });
+    match normalized {
+        Ok(s) => TemporalResult::success(s),
+        Err(e) => e,
     }
 }
 
-/// Creates a PlainDate from components.
+/// Formats a PlainDate with an explicit `calendar_name` ("auto", "always", "never" or
+/// "critical"), as `toString({ calendarName })`. NULL uses the default display options.
 #[no_mangle]
-pub extern "C" fn temporal_plain_date_from_components(
-    year: i32,
-    month: u8,
-    day: u8,
-    calendar_id: *const c_char,
-) -> TemporalResult {
-    let calendar = if !calendar_id.is_null() {
-        match parse_c_str(calendar_id, "calendar id") {
-            Ok(s) => match Calendar::from_str(s) {
-                Ok(c) => c,
-                Err(e) => return TemporalResult::range_error(&format!("Invalid calendar: {}", e)),
-            },
-            Err(e) => return e,
-        }
+pub extern "C" fn temporal_plain_date_to_string(s: *const c_char, calendar_name: *const c_char) -> TemporalResult {
+    let date = match parse_plain_date(s, "plain date") {
+        Ok(d) => d,
+        Err(e) => return e,
+    };
+    match parse_calendar_name(calendar_name) {
+        Ok(calendar) => TemporalResult::success(date.to_ixdtf_string(calendar)),
+        Err(e) => e,
+    }
+}
+
+/// Parses a PlainDate, additionally accepting a two-digit year ("24-01-15") when
+/// `pivot_year` is non-negative. Two-digit years resolve into the 100-year window
+/// ending at `pivot_year`: with 2049, "49" is 2049 and "50" is 1950. With a negative
+/// `pivot_year`, a two-digit year is a RangeError. Other input is parsed strictly.
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_from_string_lenient(s: *const c_char, pivot_year: i32) -> TemporalResult {
+    let s_str = match parse_c_str(s, "plain date string") {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    let date = match split_two_digit_year(s_str) {
+        Some(_) if pivot_year < 0 => {
+            return TemporalResult::range_error(&format!(
+                "Invalid plain date '{}': two-digit years require a pivot year",
+                s_str
+            ))
+        }
+        Some((yy, month, day)) => {
+            let year = pivot_year - (pivot_year - yy).rem_euclid(100);
+            PlainDate::try_new_iso(year, month, day)
+        }
+        None => PlainDate::from_str(s_str),
+    };
+    match date {
+        Ok(date) => TemporalResult::success(date.to_ixdtf_string(display::calendar())),
+        Err(e) => TemporalResult::range_error(&format!("Invalid plain date '{}': {}", s_str, e)),
+    }
+}
+
+/// Splits "YY-MM-DD" into its numeric fields; None for any other shape.
+fn split_two_digit_year(s: &str) -> Option<(i32, u8, u8)> {
+    let bytes = s.as_bytes();
+    let shape_ok = bytes.len() == 8
+        && bytes[2] == b'-'
+        && bytes[5] == b'-'
+        && [0, 1, 3, 4, 6, 7].iter().all(|&i| bytes[i].is_ascii_digit());
+    if !shape_ok {
+        return None;
+    }
+    Some((s[0..2].parse().ok()?, s[3..5].parse().ok()?, s[6..8].parse().ok()?))
+}
+
+/// Creates a PlainDate from components.
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_from_components(
+    year: i32,
+    month: u8,
+    day: u8,
+    calendar_id: *const c_char,
+) -> TemporalResult {
+    let calendar = if !calendar_id.is_null() {
+        match parse_c_str(calendar_id, "calendar id") {
+            Ok(s) => match Calendar::from_str(s) {
+                Ok(c) => c,
+                Err(e) => return TemporalResult::range_error(&format!("Invalid calendar: {}", e)),
+            },
+            Err(e) => return e,
+        }
     } else {
         Calendar::default()
     };
 
     match PlainDate::new(year, month, day, calendar) {
-        Ok(date) => TemporalResult::success(date.to_ixdtf_string(DisplayCalendar::Auto)),
+        Ok(date) => TemporalResult::success(date.to_ixdtf_string(display::calendar())),
         Err(e) => TemporalResult::range_error(&format!("Invalid plain date components: {}", e)),
     }
 }
 
-/// Gets all integer component values from a PlainDate string.
+/// Returned by `temporal_plain_date_pack` on error.
+pub const TEMPORAL_PACKED_DATE_ERROR: i64 = i64::MIN;
+
+/// Packs a PlainDate's year, month and day into one integer, `year << 16 | month << 8
+/// | day`, so long lists can carry dates as numbers and read the fields with shifts
+/// instead of a getter call each. The fields are those of the date's calendar (month
+/// is ordinal), and the year keeps its sign, so packed dates of one calendar sort like
+/// the dates. Every packed date fits in 2^53, so JavaScript numbers hold it exactly.
+///
+/// Returns `TEMPORAL_PACKED_DATE_ERROR` on error; details are available via
+/// `temporal_last_error_type` and `temporal_last_error_message`.
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_pack(s: *const c_char) -> i64 {
+    clear_last_error();
+    match parse_with_last_error(s, "pack", "plain date", &PlainDate::from_str) {
+        Some(date) => pack_date(&date),
+        None => TEMPORAL_PACKED_DATE_ERROR,
+    }
+}
+
+/// Rebuilds the PlainDate string from a `temporal_plain_date_pack` value, reading the
+/// fields in `calendar_id` (NULL = iso8601), which must be the calendar it was packed in.
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_unpack(packed: i64, calendar_id: *const c_char) -> TemporalResult {
+    let calendar = if !calendar_id.is_null() {
+        match parse_c_str(calendar_id, "calendar id") {
+            Ok(s) => match Calendar::from_str(s) {
+                Ok(c) => c,
+                Err(e) => return TemporalResult::range_error(&format!("Invalid calendar: {}", e)),
+            },
+            Err(e) => return e,
+        }
+    } else {
+        Calendar::default()
+    };
+    match unpack_date(packed, calendar) {
+        Ok(date) => TemporalResult::success(date.to_ixdtf_string(display::calendar())),
+        Err(e) => TemporalResult::range_error(&format!("Invalid packed date {}: {}", packed, e)),
+    }
+}
+
+fn pack_date(date: &PlainDate) -> i64 {
+    (date.year() as i64) << 16 | (date.month() as i64) << 8 | date.day() as i64
+}
+
+fn unpack_date(packed: i64, calendar: Calendar) -> Result<PlainDate, TemporalError> {
+    let year = i32::try_from(packed >> 16).map_err(|_| TemporalError::range().with_message("year out of range"))?;
+    let partial = PartialDate::new()
+        .with_calendar(calendar)
+        .with_year(Some(year))
+        .with_month(Some((packed >> 8 & 0xff) as u8))
+        .with_day(Some((packed & 0xff) as u8));
+    PlainDate::from_partial(partial, Some(Overflow::Reject))
+}
+
+/// Creates a PlainDate from a year and a 1-based day of that year in `calendar_id`
+/// (NULL = iso8601), the inverse of the dayOfYear getter. A day beyond the end of
+/// the year is a RangeError.
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_from_ordinal(
+    year: i32,
+    day_of_year: u16,
+    calendar_id: *const c_char,
+) -> TemporalResult {
+    let calendar = if !calendar_id.is_null() {
+        match parse_c_str(calendar_id, "calendar id") {
+            Ok(s) => match Calendar::from_str(s) {
+                Ok(c) => c,
+                Err(e) => return TemporalResult::range_error(&format!("Invalid calendar: {}", e)),
+            },
+            Err(e) => return e,
+        }
+    } else {
+        Calendar::default()
+    };
+    match date_from_ordinal(&calendar, year, day_of_year) {
+        Ok(date) => TemporalResult::success(date.to_ixdtf_string(display::calendar())),
+        Err(e) => TemporalResult::range_error(&format!("Invalid ordinal date {}-{:03}: {}", year, day_of_year, e)),
+    }
+}
+
+/// Parses an ISO 8601 ordinal date, "YYYY-DDD" or basic "YYYYDDD" (with a signed
+/// six-digit year outside 0000-9999), into an ISO PlainDate.
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_from_ordinal_string(s: *const c_char) -> TemporalResult {
+    let s_str = match parse_c_str(s, "ordinal date string") {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    let date = match split_ordinal(s_str) {
+        Some((year, day_of_year)) => date_from_ordinal(&Calendar::default(), year, day_of_year),
+        None => Err(TemporalError::range().with_message("expected YYYY-DDD")),
+    };
+    match date {
+        Ok(date) => TemporalResult::success(date.to_ixdtf_string(display::calendar())),
+        Err(e) => TemporalResult::range_error(&format!("Invalid ordinal date '{}': {}", s_str, e)),
+    }
+}
+
+/// Formats a PlainDate as an ISO 8601 ordinal date ("2024-075"). ISO 8601 ordinal
+/// dates are ISO years, so the date's calendar is ignored.
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_to_ordinal_string(s: *const c_char) -> TemporalResult {
+    let date = match parse_plain_date(s, "plain date") {
+        Ok(d) => d.with_calendar(Calendar::default()),
+        Err(e) => return e,
+    };
+    let year = match date.year() {
+        year @ 0..=9999 => format!("{:04}", year),
+        year => format!("{:+07}", year),
+    };
+    TemporalResult::success(format!("{}-{:03}", year, date.day_of_year()))
+}
+
+fn date_from_ordinal(calendar: &Calendar, year: i32, day_of_year: u16) -> Result<PlainDate, TemporalError> {
+    let start = month_start(calendar, year, 1)?;
+    if day_of_year == 0 || day_of_year > start.days_in_year() {
+        return Err(TemporalError::range().with_message("day of year is outside the year"));
+    }
+    start.add(&Duration::new(0, 0, 0, day_of_year as i64 - 1, 0, 0, 0, 0, 0, 0)?, None)
+}
+
+/// Splits "YYYY-DDD", "YYYYDDD" or "±YYYYYY-DDD" into year and day of year.
+fn split_ordinal(s: &str) -> Option<(i32, u16)> {
+    let year_len = if s.starts_with(['+', '-']) { 7 } else { 4 };
+    let (year, rest) = (s.get(..year_len)?, s.get(year_len..)?);
+    let day = rest.strip_prefix('-').unwrap_or(rest);
+    let digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+    if day.len() != 3 || !digits(day) || !digits(year.trim_start_matches(['+', '-'])) || year == "-000000" {
+        return None;
+    }
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+/// Gets all integer component values from a PlainDate string. For a custom calendar
+/// date these are the custom calendar's fields, as in
+/// `temporal_custom_calendar_get_components`.
 #[no_mangle]
 pub extern "C" fn temporal_plain_date_get_components(
     s: *const c_char,
@@ -1159,6 +2024,14 @@ pub extern "C" fn temporal_plain_date_get_components(
     if s.is_null() {
         return;
     }
+    match custom_calendar::parse_custom_date(s) {
+        Some(Ok(date)) => {
+            unsafe { *out = date.components() };
+            return;
+        }
+        Some(Err(_)) => return,
+        None => {}
+    }
 
     let date = match parse_plain_date(s, "plain date") {
         Ok(d) => d,
@@ -1178,6 +2051,8 @@ pub extern "C" fn temporal_plain_date_get_components(
         (*out).days_in_year = date.days_in_year();
         (*out).months_in_year = date.months_in_year();
         (*out).in_leap_year = if date.in_leap_year() { 1 } else { 0 };
+        (*out).has_week_info = if date.week_of_year().is_some() { 1 } else { 0 };
+        ((*out).quarter, (*out).day_of_quarter) = quarter_info(&date);
         (*out).is_valid = 1;
     }
 }
@@ -1195,6 +2070,11 @@ pub extern "C" fn temporal_plain_date_get_month_code(s: *const c_char) -> Tempor
 /// Gets the calendar ID of a PlainDate.
 #[no_mangle]
 pub extern "C" fn temporal_plain_date_get_calendar(s: *const c_char) -> TemporalResult {
+    match custom_calendar::parse_custom_date(s) {
+        Some(Ok(date)) => return TemporalResult::success(date.calendar_id().to_string()),
+        Some(Err(e)) => return e,
+        None => {}
+    }
     let date = match parse_plain_date(s, "plain date") {
         Ok(d) => d,
         Err(e) => return e,
@@ -1202,70 +2082,338 @@ pub extern "C" fn temporal_plain_date_get_calendar(s: *const c_char) -> Temporal
     TemporalResult::success(date.calendar().identifier().to_string())
 }
 
-/// Adds a duration to a PlainDate.
+/// Adds a duration to a PlainDate. Custom calendar dates add years and months in
+/// their calendar, constraining the day.
 #[no_mangle]
 pub extern "C" fn temporal_plain_date_add(date_str: *const c_char, duration_str: *const c_char) -> TemporalResult {
-    let date = match parse_plain_date(date_str, "plain date") {
+    let custom = custom_calendar::parse_custom_date(date_str);
+    if let Some(Err(e)) = custom {
+        return e;
+    }
+    let duration = match parse_duration(duration_str, "duration") {
         Ok(d) => d,
         Err(e) => return e,
     };
-    let duration = match parse_duration(duration_str, "duration") {
+    if let Some(Ok(date)) = custom {
+        return match date.add(&duration, Overflow::Constrain) {
+            Ok(s) => TemporalResult::success(s),
+            Err(e) => TemporalResult::range_error(&format!("Failed to add duration: {}", e)),
+        };
+    }
+    let date = match parse_plain_date(date_str, "plain date") {
         Ok(d) => d,
         Err(e) => return e,
     };
 
     match date.add(&duration, None) {
-        Ok(result) => TemporalResult::success(result.to_ixdtf_string(DisplayCalendar::Auto)),
+        Ok(result) => TemporalResult::success(result.to_ixdtf_string(display::calendar())),
         Err(e) => TemporalResult::range_error(&format!("Failed to add duration: {}", e)),
     }
 }
 
-/// Subtracts a duration from a PlainDate.
+/// Subtracts a duration from a PlainDate; see `temporal_plain_date_add`.
 #[no_mangle]
 pub extern "C" fn temporal_plain_date_subtract(date_str: *const c_char, duration_str: *const c_char) -> TemporalResult {
-    let date = match parse_plain_date(date_str, "plain date") {
+    let custom = custom_calendar::parse_custom_date(date_str);
+    if let Some(Err(e)) = custom {
+        return e;
+    }
+    let duration = match parse_duration(duration_str, "duration") {
         Ok(d) => d,
         Err(e) => return e,
     };
-    let duration = match parse_duration(duration_str, "duration") {
+    if let Some(Ok(date)) = custom {
+        return match date.add(&duration.negated(), Overflow::Constrain) {
+            Ok(s) => TemporalResult::success(s),
+            Err(e) => TemporalResult::range_error(&format!("Failed to subtract duration: {}", e)),
+        };
+    }
+    let date = match parse_plain_date(date_str, "plain date") {
         Ok(d) => d,
         Err(e) => return e,
     };
 
     match date.subtract(&duration, None) {
-        Ok(result) => TemporalResult::success(result.to_ixdtf_string(DisplayCalendar::Auto)),
+        Ok(result) => TemporalResult::success(result.to_ixdtf_string(display::calendar())),
         Err(e) => TemporalResult::range_error(&format!("Failed to subtract duration: {}", e)),
     }
 }
 
+/// Adds whole months, clamping the day to the end of a shorter month: Jan 31 plus one
+/// month is Feb 28 or 29, and in lunisolar calendars a missing leap month moves to
+/// its regular counterpart. Negative `months` go back. The same as adding P<n>M with
+/// overflow "constrain", without building the duration string.
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_add_months_constrained(date_str: *const c_char, months: i64) -> TemporalResult {
+    let date = match parse_plain_date(date_str, "plain date") {
+        Ok(d) => d,
+        Err(e) => return e,
+    };
+    match add_months_constrained(&date, months) {
+        Ok(result) => TemporalResult::success(result.to_ixdtf_string(display::calendar())),
+        Err(e) => TemporalResult::range_error(&format!("Failed to add months: {}", e)),
+    }
+}
+
+fn add_months_constrained(date: &PlainDate, months: i64) -> Result<PlainDate, TemporalError> {
+    let duration = Duration::new(0, months, 0, 0, 0, 0, 0, 0, 0, 0)?;
+    date.add(&duration, Some(Overflow::Constrain))
+}
+
 /// Compares two PlainDates.
 #[no_mangle]
 pub extern "C" fn temporal_plain_date_compare(a: *const c_char, b: *const c_char) -> CompareResult {
     let date_a = match parse_plain_date(a, "first plain date") {
         Ok(d) => d,
-        Err(e) => return CompareResult::range_error(
-            &unsafe { std::ffi::CStr::from_ptr(e.error_message) }.to_string_lossy()
-        ),
+        Err(e) => return CompareResult::from_error(e),
     };
     let date_b = match parse_plain_date(b, "second plain date") {
         Ok(d) => d,
-        Err(e) => return CompareResult::range_error(
-            &unsafe { std::ffi::CStr::from_ptr(e.error_message) }.to_string_lossy()
-        ),
+        Err(e) => return CompareResult::from_error(e),
+    };
+
+    CompareResult::success(compare_plain_dates(&date_a, &date_b) as i32)
+}
+
+/// Compares two PlainDates by their ISO fields only, ignoring the calendar, so
+/// 2024-01-01[u-ca=hebrew] and 2024-01-01 compare as equal (same as compare).
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_compare_iso(a: *const c_char, b: *const c_char) -> CompareResult {
+    temporal_plain_date_compare(a, b)
+}
+
+/// Returns 1 if both PlainDates have the same ISO fields and the same calendar,
+/// 0 if not, or -1 on error. Unlike compare, 2024-01-01[u-ca=hebrew] does not
+/// equal 2024-01-01.
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_equals(a: *const c_char, b: *const c_char) -> i8 {
+    clear_last_error();
+    let date_a = match parse_with_last_error(a, "first", "plain date", &PlainDate::from_str) {
+        Some(d) => d,
+        None => return -1,
+    };
+    let date_b = match parse_with_last_error(b, "second", "plain date", &PlainDate::from_str) {
+        Some(d) => d,
+        None => return -1,
+    };
+    (date_a == date_b) as i8
+}
+
+/// Returns the earliest date from a newline-delimited list of PlainDates.
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_min(list: *const c_char) -> TemporalResult {
+    list_extreme(list, "plain date", PlainDate::from_str, compare_plain_dates, std::cmp::Ordering::Less, |d| {
+        Ok(d.to_ixdtf_string(display::calendar()))
+    })
+}
+
+/// Returns the latest date from a newline-delimited list of PlainDates.
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_max(list: *const c_char) -> TemporalResult {
+    list_extreme(list, "plain date", PlainDate::from_str, compare_plain_dates, std::cmp::Ordering::Greater, |d| {
+        Ok(d.to_ixdtf_string(display::calendar()))
+    })
+}
+
+/// Clamps a PlainDate into the inclusive range [min, max].
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_clamp(
+    value: *const c_char,
+    min: *const c_char,
+    max: *const c_char,
+) -> TemporalResult {
+    let value = match parse_plain_date(value, "value") {
+        Ok(x) => x,
+        Err(e) => return e,
+    };
+    let min = match parse_plain_date(min, "min") {
+        Ok(x) => x,
+        Err(e) => return e,
+    };
+    let max = match parse_plain_date(max, "max") {
+        Ok(x) => x,
+        Err(e) => return e,
+    };
+    match clamp_by(value, min, max, compare_plain_dates) {
+        Ok(date) => TemporalResult::success(date.to_ixdtf_string(display::calendar())),
+        Err(e) => e,
+    }
+}
+
+/// Returns the first day of the quarter containing the given PlainDate.
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_start_of_quarter(s: *const c_char) -> TemporalResult {
+    let date = match parse_plain_date(s, "plain date") {
+        Ok(d) => d,
+        Err(e) => return e,
+    };
+    match quarter_bounds(&date) {
+        Ok((start, _)) => TemporalResult::success(start.to_ixdtf_string(display::calendar())),
+        Err(message) => TemporalResult::range_error(&message),
+    }
+}
+
+/// Returns the last day of the quarter containing the given PlainDate.
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_end_of_quarter(s: *const c_char) -> TemporalResult {
+    let date = match parse_plain_date(s, "plain date") {
+        Ok(d) => d,
+        Err(e) => return e,
+    };
+    match quarter_bounds(&date) {
+        Ok((_, end)) => TemporalResult::success(end.to_ixdtf_string(display::calendar())),
+        Err(message) => TemporalResult::range_error(&message),
+    }
+}
+
+/// Returns the first day of the day, week, month, or year containing the given date.
+/// Weeks start on `first_day_of_week` (1 = Monday ... 7 = Sunday).
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_start_of(
+    s: *const c_char,
+    unit: *const c_char,
+    first_day_of_week: u8,
+) -> TemporalResult {
+    let date = match parse_plain_date(s, "plain date") {
+        Ok(d) => d,
+        Err(e) => return e,
+    };
+    let unit = match parse_calendar_unit(unit) {
+        Ok(u) => u,
+        Err(e) => return e,
+    };
+    match start_of_unit(&date, unit, first_day_of_week) {
+        Ok(start) => TemporalResult::success(start.to_ixdtf_string(display::calendar())),
+        Err(message) => TemporalResult::range_error(&message),
+    }
+}
+
+/// Returns the last day of the day, week, month, or year containing the given date.
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_end_of(
+    s: *const c_char,
+    unit: *const c_char,
+    first_day_of_week: u8,
+) -> TemporalResult {
+    let date = match parse_plain_date(s, "plain date") {
+        Ok(d) => d,
+        Err(e) => return e,
     };
+    let unit = match parse_calendar_unit(unit) {
+        Ok(u) => u,
+        Err(e) => return e,
+    };
+    let end = start_of_unit(&date, unit, first_day_of_week).and_then(|start| {
+        let one_day = Duration::new(0, 0, 0, 1, 0, 0, 0, 0, 0, 0).map_err(|e| e.to_string())?;
+        start
+            .add(&unit_duration(unit), None)
+            .and_then(|next| next.subtract(&one_day, None))
+            .map_err(|e| e.to_string())
+    });
+    match end {
+        Ok(end) => TemporalResult::success(end.to_ixdtf_string(display::calendar())),
+        Err(message) => TemporalResult::range_error(&message),
+    }
+}
 
-    // Fallback to string comparison since direct comparison is not exposed/working
-    // Use DisplayCalendar::Never to compare pure ISO dates without calendar annotations
-    let s_a = date_a.to_ixdtf_string(DisplayCalendar::Never);
-    let s_b = date_b.to_ixdtf_string(DisplayCalendar::Never);
+/// Returned by `temporal_plain_date_weeks_between` on error.
+pub const TEMPORAL_WEEKS_BETWEEN_ERROR: i64 = i64::MIN;
+
+/// Returns the number of calendar-week boundaries between two dates (positive when
+/// `b` is later), where weeks start on `first_day_of_week` (1 = Monday ... 7 = Sunday).
+/// Unlike dividing a day count by 7, Sunday and the following Monday are one week
+/// apart when weeks start on Monday.
+///
+/// Returns `TEMPORAL_WEEKS_BETWEEN_ERROR` on error; details are available via
+/// `temporal_last_error_type` and `temporal_last_error_message`.
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_weeks_between(
+    a: *const c_char,
+    b: *const c_char,
+    first_day_of_week: u8,
+) -> i64 {
+    clear_last_error();
+    if !(1..=7).contains(&first_day_of_week) {
+        set_last_error(TemporalErrorType::RangeError, format_args!("firstDayOfWeek must be between 1 and 7, got {}", first_day_of_week));
+        return TEMPORAL_WEEKS_BETWEEN_ERROR;
+    }
+    let date_a = match parse_with_last_error(a, "first", "plain date", &PlainDate::from_str) {
+        Some(d) => d,
+        None => return TEMPORAL_WEEKS_BETWEEN_ERROR,
+    };
+    let date_b = match parse_with_last_error(b, "second", "plain date", &PlainDate::from_str) {
+        Some(d) => d,
+        None => return TEMPORAL_WEEKS_BETWEEN_ERROR,
+    };
 
-    let val = match s_a.cmp(&s_b) {
-        std::cmp::Ordering::Less => -1,
-        std::cmp::Ordering::Equal => 0,
-        std::cmp::Ordering::Greater => 1,
+    let mut options = temporal_rs::options::DifferenceSettings::default();
+    options.largest_unit = Some(Unit::Day);
+    let days = match date_a.until(&date_b, options) {
+        Ok(d) => d.days(),
+        Err(e) => {
+            set_last_error(TemporalErrorType::RangeError, format_args!("Failed to compute difference: {}", e));
+            return TEMPORAL_WEEKS_BETWEEN_ERROR;
+        }
     };
 
-    CompareResult::success(val)
+    // Distance between the starts of the two weeks is always a multiple of 7.
+    let offset_a = (date_a.day_of_week() as i64 - first_day_of_week as i64).rem_euclid(7);
+    let offset_b = (date_b.day_of_week() as i64 - first_day_of_week as i64).rem_euclid(7);
+    (days + offset_a - offset_b) / 7
+}
+
+/// Returned by `temporal_fiscal_year_of` and `temporal_fiscal_quarter_of` on error.
+pub const TEMPORAL_FISCAL_ERROR: i32 = i32::MIN;
+
+/// Returns the fiscal year containing a PlainDate, for fiscal years starting on the
+/// first of `fiscal_start_month` (1-12). A fiscal year is named after the calendar
+/// year it ends in: with a start month of 10, 2024-10-01 is in fiscal year 2025. A
+/// start month of 1 gives the calendar year.
+///
+/// Returns `TEMPORAL_FISCAL_ERROR` on error (including lunisolar and 13-month calendars);
+/// details are available via `temporal_last_error_type` and `temporal_last_error_message`.
+#[no_mangle]
+pub extern "C" fn temporal_fiscal_year_of(date: *const c_char, fiscal_start_month: u8) -> i32 {
+    match fiscal_position(date, fiscal_start_month) {
+        Some((year, _)) => year,
+        None => TEMPORAL_FISCAL_ERROR,
+    }
+}
+
+/// Returns the fiscal quarter (1-4) containing a PlainDate, for fiscal years starting
+/// on the first of `fiscal_start_month` (1-12).
+///
+/// Returns `TEMPORAL_FISCAL_ERROR` on error; details are available via
+/// `temporal_last_error_type` and `temporal_last_error_message`.
+#[no_mangle]
+pub extern "C" fn temporal_fiscal_quarter_of(date: *const c_char, fiscal_start_month: u8) -> i32 {
+    match fiscal_position(date, fiscal_start_month) {
+        Some((_, month_index)) => month_index as i32 / 3 + 1,
+        None => TEMPORAL_FISCAL_ERROR,
+    }
+}
+
+/// Returns (fiscal year, 0-based month within the fiscal year), setting the last
+/// error on failure.
+fn fiscal_position(date: *const c_char, fiscal_start_month: u8) -> Option<(i32, u8)> {
+    clear_last_error();
+    if !(1..=12).contains(&fiscal_start_month) {
+        set_last_error(TemporalErrorType::RangeError, format_args!("Fiscal start month must be between 1 and 12, got {}", fiscal_start_month));
+        return None;
+    }
+    let date = parse_with_last_error(date, "fiscal", "plain date", &PlainDate::from_str)?;
+    // Decided by calendar, not by the date's year: Hebrew common years have 12 months
+    // but leap years 13, and a fiscal year must not depend on which one a date is in
+    if matches!(date.calendar().identifier(), "hebrew" | "chinese" | "dangi" | "coptic" | "ethiopic" | "ethioaa") {
+        set_last_error(
+            TemporalErrorType::RangeError,
+            format_args!("Fiscal years are not defined for calendar '{}'", date.calendar().identifier()),
+        );
+        return None;
+    }
+    let month_index = (date.month() + 12 - fiscal_start_month) % 12;
+    let year = if fiscal_start_month > 1 && date.month() >= fiscal_start_month { date.year() + 1 } else { date.year() };
+    Some((year, month_index))
 }
 
 /// Returns a new PlainDate with updated fields.
@@ -1299,12 +2447,13 @@ pub extern "C" fn temporal_plain_date_with(
     };
 
     match PlainDate::new(new_year, new_month, new_day, new_calendar) {
-         Ok(new_date) => TemporalResult::success(new_date.to_ixdtf_string(DisplayCalendar::Auto)),
+         Ok(new_date) => TemporalResult::success(new_date.to_ixdtf_string(display::calendar())),
         Err(e) => TemporalResult::range_error(&format!("Invalid date components: {}", e)),
     }
 }
 
-/// Computes the difference between two PlainDates (until).
+/// Computes the difference between two PlainDates (until) with the spec default
+/// options (largestUnit "day").
 #[no_mangle]
 pub extern "C" fn temporal_plain_date_until(
     one_str: *const c_char,
@@ -1325,7 +2474,8 @@ pub extern "C" fn temporal_plain_date_until(
     }
 }
 
-/// Computes the difference between two PlainDates (since).
+/// Computes the difference between two PlainDates (since) with the spec default
+/// options (largestUnit "day").
 #[no_mangle]
 pub extern "C" fn temporal_plain_date_since(
     one_str: *const c_char,
@@ -1347,6 +2497,101 @@ pub extern "C" fn temporal_plain_date_since(
 }
 
 // Helper functions for PlainDate
+fn month_start(calendar: &Calendar, year: i32, month: u8) -> Result<PlainDate, TemporalError> {
+    let partial = PartialDate::new()
+        .with_calendar(calendar.clone())
+        .with_year(Some(year))
+        .with_month(Some(month))
+        .with_day(Some(1));
+    PlainDate::from_partial(partial, Some(Overflow::Reject))
+}
+
+/// Returns the first and last day of the quarter containing `date`. Quarters are
+/// only defined for calendars with 12 months in the year.
+fn quarter_bounds(date: &PlainDate) -> Result<(PlainDate, PlainDate), String> {
+    if date.months_in_year() != 12 {
+        return Err(format!("Quarters are not defined for calendar '{}'", date.calendar().identifier()));
+    }
+    let first_month = (date.month() - 1) / 3 * 3 + 1;
+    let start = month_start(date.calendar(), date.year(), first_month).map_err(|e| e.to_string())?;
+    let three_months = Duration::new(0, 3, 0, 0, 0, 0, 0, 0, 0, 0).map_err(|e| e.to_string())?;
+    let one_day = Duration::new(0, 0, 0, 1, 0, 0, 0, 0, 0, 0).map_err(|e| e.to_string())?;
+    let end = start
+        .add(&three_months, None)
+        .and_then(|next| next.subtract(&one_day, None))
+        .map_err(|e| e.to_string())?;
+    Ok((start, end))
+}
+
+/// Returns (quarter, day of quarter), or (0, 0) when quarters are not defined.
+///
+/// This runs for every components call, so it adds up month lengths instead of
+/// building the quarter's first day. Only calendars whose month lengths are not
+/// the ISO ones (Hebrew, Chinese, Hijri...) fall back to building it.
+fn quarter_info(date: &PlainDate) -> (u8, u8) {
+    if date.months_in_year() != 12 {
+        return (0, 0);
+    }
+    let month = date.month();
+    let quarter = (month - 1) / 3 + 1;
+    let first_month = quarter * 3 - 2;
+    let days_before = if month == first_month {
+        0
+    } else if matches!(date.calendar().identifier(), "iso8601" | "gregory" | "buddhist" | "japanese" | "roc") {
+        let february = if date.in_leap_year() { 29 } else { 28 };
+        let lengths: [u16; 12] = [31, february, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+        lengths[usize::from(first_month - 1)..usize::from(month - 1)].iter().sum()
+    } else {
+        match month_start(date.calendar(), date.year(), first_month) {
+            Ok(start) => date.day_of_year() - start.day_of_year() - u16::from(date.day()) + 1,
+            Err(_) => return (0, 0),
+        }
+    };
+    (quarter, (days_before + u16::from(date.day())) as u8)
+}
+
+/// Parses a "day", "week", "month", or "year" unit for the start/end-of helpers.
+fn parse_calendar_unit(unit: *const c_char) -> Result<Unit, TemporalResult> {
+    let unit_str = parse_c_str(unit, "unit")?;
+    match Unit::from_str(unit_str) {
+        Ok(u @ (Unit::Day | Unit::Week | Unit::Month | Unit::Year)) => Ok(u),
+        _ => Err(TemporalResult::range_error(&format!("Invalid unit: {} (expected day, week, month, or year)", unit_str))),
+    }
+}
+
+/// Returns the first day of the `unit` (day, week, month, or year) containing `date`.
+fn start_of_unit(date: &PlainDate, unit: Unit, first_day_of_week: u8) -> Result<PlainDate, String> {
+    match unit {
+        Unit::Week => {
+            if !(1..=7).contains(&first_day_of_week) {
+                return Err(format!("firstDayOfWeek must be between 1 and 7, got {}", first_day_of_week));
+            }
+            let offset = (date.day_of_week() as i64 - first_day_of_week as i64).rem_euclid(7);
+            let back = Duration::new(0, 0, 0, offset, 0, 0, 0, 0, 0, 0).map_err(|e| e.to_string())?;
+            date.subtract(&back, None).map_err(|e| e.to_string())
+        }
+        Unit::Month => month_start(date.calendar(), date.year(), date.month()).map_err(|e| e.to_string()),
+        Unit::Year => month_start(date.calendar(), date.year(), 1).map_err(|e| e.to_string()),
+        _ => Ok(date.clone()),
+    }
+}
+
+/// Returns a duration of exactly one `unit`.
+fn unit_duration(unit: Unit) -> Duration {
+    let (years, months, weeks, days) = match unit {
+        Unit::Year => (1, 0, 0, 0),
+        Unit::Month => (0, 1, 0, 0),
+        Unit::Week => (0, 0, 1, 0),
+        _ => (0, 0, 0, 1),
+    };
+    Duration::new(years, months, weeks, days, 0, 0, 0, 0, 0, 0).unwrap_or_default()
+}
+
+fn compare_plain_dates(a: &PlainDate, b: &PlainDate) -> std::cmp::Ordering {
+    // Compare the ISO fields; per spec the calendar does not take part in ordering
+    a.compare_iso(b)
+}
+
 fn parse_plain_date(s: *const c_char, param_name: &str) -> Result<PlainDate, TemporalResult> {
     let str_val = parse_c_str(s, param_name)?;
     PlainDate::from_str(str_val)
@@ -1378,6 +2623,11 @@ pub struct PlainDateTimeComponents {
     pub millisecond: u16,
     pub microsecond: u16,
     pub nanosecond: u16,
+    /// 1 when the calendar defines week numbering, 0 otherwise.
+    pub has_week_info: i8,
+    /// 1-4, or 0 for calendars without 12 months.
+    pub quarter: u8,
+    pub day_of_quarter: u8,
     pub is_valid: i8,
 }
 
@@ -1402,6 +2652,9 @@ impl Default for PlainDateTimeComponents {
             millisecond: 0,
             microsecond: 0,
             nanosecond: 0,
+            has_week_info: 0,
+            quarter: 0,
+            day_of_quarter: 0,
             is_valid: 0,
         }
     }
@@ -1414,16 +2667,42 @@ pub extern "C" fn temporal_plain_date_time_from_string(s: *const c_char) -> Temp
         Ok(s) => s,
         Err(e) => return e,
     };
-    match PlainDateTime::from_str(s_str) {
-        Ok(dt) => match dt.to_ixdtf_string(ToStringRoundingOptions::default(), DisplayCalendar::Auto) {
-            Ok(s) => TemporalResult::success(s),
-            Err(e) => TemporalResult::range_error(&format!("Failed to format plain date time: {}", e)),
-        },
-        Err(e) => TemporalResult::range_error(&format!("Invalid plain date time '{}': {}", s_str, e)),
+    let normalized = canonical_cache::canonical(Kind::PlainDateTime, s_str, || match PlainDateTime::from_str(s_str) {
+        Ok(dt) => dt
+            .to_ixdtf_string(display::rounding(), display::calendar())
+            .map_err(|e| TemporalResult::range_error(&format!("Failed to format plain date time: {}", e))),
+        Err(e) => Err(TemporalResult::range_error(&format!("Invalid plain date time '{}': {}", s_str, e))),
+    });
+    match normalized {
+        Ok(s) => TemporalResult::success(s),
+        Err(e) => e,
     }
 }
 
-/// Creates a PlainDateTime from components.
+/// Formats a PlainDateTime with an explicit `precision` (see
+/// `temporal_instant_to_string`) and `calendar_name` (see
+/// `temporal_plain_date_to_string`). NULL arguments use the default display options.
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_time_to_string(
+    s: *const c_char,
+    precision: *const c_char,
+    calendar_name: *const c_char,
+) -> TemporalResult {
+    let dt = match parse_plain_date_time(s, "plain date time") {
+        Ok(dt) => dt,
+        Err(e) => return e,
+    };
+    let (rounding, calendar) = match parse_precision(precision).and_then(|r| Ok((r, parse_calendar_name(calendar_name)?))) {
+        Ok(options) => options,
+        Err(e) => return e,
+    };
+    match dt.to_ixdtf_string(rounding, calendar) {
+        Ok(s) => TemporalResult::success(s),
+        Err(e) => TemporalResult::range_error(&format!("Failed to format plain date time: {}", e)),
+    }
+}
+
+/// Creates a PlainDateTime from components.
 #[no_mangle]
 pub extern "C" fn temporal_plain_date_time_from_components(
     year: i32,
@@ -1450,7 +2729,7 @@ pub extern "C" fn temporal_plain_date_time_from_components(
     };
 
     match PlainDateTime::new(year, month, day, hour, minute, second, millisecond, microsecond, nanosecond, calendar) {
-        Ok(dt) => match dt.to_ixdtf_string(ToStringRoundingOptions::default(), DisplayCalendar::Auto) {
+        Ok(dt) => match dt.to_ixdtf_string(display::rounding(), display::calendar()) {
             Ok(s) => TemporalResult::success(s),
             Err(e) => TemporalResult::range_error(&format!("Failed to format plain date time: {}", e)),
         },
@@ -1492,6 +2771,8 @@ pub extern "C" fn temporal_plain_date_time_get_components(
         (*out).days_in_year = dt.days_in_year();
         (*out).months_in_year = dt.months_in_year();
         (*out).in_leap_year = if dt.in_leap_year() { 1 } else { 0 };
+        (*out).has_week_info = if dt.week_of_year().is_some() { 1 } else { 0 };
+        ((*out).quarter, (*out).day_of_quarter) = quarter_info(&dt.to_plain_date());
 
         (*out).hour = dt.hour();
         (*out).minute = dt.minute();
@@ -1537,7 +2818,7 @@ pub extern "C" fn temporal_plain_date_time_add(dt_str: *const c_char, duration_s
     };
 
     match dt.add(&duration, None) {
-        Ok(result) => match result.to_ixdtf_string(ToStringRoundingOptions::default(), DisplayCalendar::Auto) {
+        Ok(result) => match result.to_ixdtf_string(display::rounding(), display::calendar()) {
             Ok(s) => TemporalResult::success(s),
             Err(e) => TemporalResult::range_error(&format!("Failed to format plain date time: {}", e)),
         },
@@ -1558,7 +2839,7 @@ pub extern "C" fn temporal_plain_date_time_subtract(dt_str: *const c_char, durat
     };
 
     match dt.subtract(&duration, None) {
-        Ok(result) => match result.to_ixdtf_string(ToStringRoundingOptions::default(), DisplayCalendar::Auto) {
+        Ok(result) => match result.to_ixdtf_string(display::rounding(), display::calendar()) {
             Ok(s) => TemporalResult::success(s),
             Err(e) => TemporalResult::range_error(&format!("Failed to format plain date time: {}", e)),
         },
@@ -1566,20 +2847,17 @@ pub extern "C" fn temporal_plain_date_time_subtract(dt_str: *const c_char, durat
     }
 }
 
-/// Compares two PlainDateTimes.
+/// Compares two PlainDateTimes by their ISO fields down to the nanosecond; the
+/// calendar is ignored, as in Temporal.PlainDateTime.compare.
 #[no_mangle]
 pub extern "C" fn temporal_plain_date_time_compare(a: *const c_char, b: *const c_char) -> CompareResult {
     let dt_a: PlainDateTime = match parse_plain_date_time(a, "first plain date time") {
         Ok(d) => d,
-        Err(e) => return CompareResult::range_error(
-            &unsafe { std::ffi::CStr::from_ptr(e.error_message) }.to_string_lossy()
-        ),
+        Err(e) => return CompareResult::from_error(e),
     };
     let dt_b: PlainDateTime = match parse_plain_date_time(b, "second plain date time") {
         Ok(d) => d,
-        Err(e) => return CompareResult::range_error(
-            &unsafe { std::ffi::CStr::from_ptr(e.error_message) }.to_string_lossy()
-        ),
+        Err(e) => return CompareResult::from_error(e),
     };
 
     CompareResult::success(dt_a.compare_iso(&dt_b) as i32)
@@ -1629,7 +2907,7 @@ pub extern "C" fn temporal_plain_date_time_with(
     };
 
     match PlainDateTime::new(new_year, new_month, new_day, new_hour, new_minute, new_second, new_millisecond, new_microsecond, new_nanosecond, new_calendar) {
-         Ok(new_dt) => match new_dt.to_ixdtf_string(ToStringRoundingOptions::default(), DisplayCalendar::Auto) {
+         Ok(new_dt) => match new_dt.to_ixdtf_string(display::rounding(), display::calendar()) {
              Ok(s) => TemporalResult::success(s),
              Err(e) => TemporalResult::range_error(&format!("Failed to format plain date time: {}", e)),
          },
@@ -1637,7 +2915,8 @@ pub extern "C" fn temporal_plain_date_time_with(
     }
 }
 
-/// Computes the difference between two PlainDateTimes (until).
+/// Computes the difference between two PlainDateTimes (until) with the spec default
+/// options (largestUnit "day").
 #[no_mangle]
 pub extern "C" fn temporal_plain_date_time_until(
     one_str: *const c_char,
@@ -1658,7 +2937,8 @@ pub extern "C" fn temporal_plain_date_time_until(
     }
 }
 
-/// Computes the difference between two PlainDateTimes (since).
+/// Computes the difference between two PlainDateTimes (since) with the spec default
+/// options (largestUnit "day").
 #[no_mangle]
 pub extern "C" fn temporal_plain_date_time_since(
     one_str: *const c_char,
@@ -1679,7 +2959,56 @@ pub extern "C" fn temporal_plain_date_time_since(
     }
 }
 
+/// Splits a PlainDateTime into its PlainDate and PlainTime in one call. Returns the
+/// date and the time separated by a newline, e.g. "2024-01-15\n10:30:00".
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_time_split(s: *const c_char) -> TemporalResult {
+    let dt = match parse_plain_date_time(s, "plain date time") {
+        Ok(d) => d,
+        Err(e) => return e,
+    };
+    let date = dt.to_plain_date().to_ixdtf_string(display::calendar());
+    match dt.to_plain_time().to_ixdtf_string(display::rounding()) {
+        Ok(time) => TemporalResult::success(format!("{}\n{}", date, time)),
+        Err(e) => TemporalResult::range_error(&format!("Failed to format plain time: {}", e)),
+    }
+}
+
+/// Combines a PlainDate and a PlainTime into a PlainDateTime in the date's calendar.
+/// A NULL time means midnight.
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_time_combine(date: *const c_char, time: *const c_char) -> TemporalResult {
+    let date = match parse_plain_date(date, "plain date") {
+        Ok(d) => d,
+        Err(e) => return e,
+    };
+    let time = if time.is_null() {
+        None
+    } else {
+        match parse_plain_time(time, "plain time") {
+            Ok(t) => Some(t),
+            Err(e) => return e,
+        }
+    };
+    match date.to_plain_date_time(time) {
+        Ok(dt) => match dt.to_ixdtf_string(display::rounding(), display::calendar()) {
+            Ok(s) => TemporalResult::success(s),
+            Err(e) => TemporalResult::range_error(&format!("Failed to format plain date time: {}", e)),
+        },
+        Err(e) => TemporalResult::range_error(&format!("Failed to combine date and time: {}", e)),
+    }
+}
+
 // Helper functions for PlainDateTime
+/// Parses an optional "constrain" / "reject" option; NULL means "constrain".
+fn parse_overflow(overflow: *const c_char) -> Result<Overflow, TemporalResult> {
+    if overflow.is_null() {
+        return Ok(Overflow::Constrain);
+    }
+    let s = parse_c_str(overflow, "overflow")?;
+    Overflow::from_str(s).map_err(|_| TemporalResult::range_error(&format!("Invalid overflow option: {}", s)))
+}
+
 fn parse_plain_date_time(s: *const c_char, param_name: &str) -> Result<PlainDateTime, TemporalResult> {
     let str_val = parse_c_str(s, param_name)?;
     PlainDateTime::from_str(str_val)
@@ -1727,9 +3056,27 @@ pub extern "C" fn temporal_plain_year_month_from_string(s: *const c_char) -> Tem
         Ok(s) => s,
         Err(e) => return e,
     };
-    match PlainYearMonth::from_str(s_str) {
-        Ok(ym) => TemporalResult::success(ym.to_ixdtf_string(DisplayCalendar::Auto)),
-        Err(e) => TemporalResult::range_error(&format!("Invalid plain year month '{}': {}", s_str, e)),
+    let normalized = canonical_cache::canonical(Kind::PlainYearMonth, s_str, || match PlainYearMonth::from_str(s_str) {
+        Ok(ym) => Ok(ym.to_ixdtf_string(display::calendar())),
+        Err(e) => Err(TemporalResult::range_error(&format!("Invalid plain year month '{}': {}", s_str, e))),
+    });
+    match normalized {
+        Ok(s) => TemporalResult::success(s),
+        Err(e) => e,
+    }
+}
+
+/// Formats a PlainYearMonth with an explicit `calendar_name`; see
+/// `temporal_plain_date_to_string`.
+#[no_mangle]
+pub extern "C" fn temporal_plain_year_month_to_string(s: *const c_char, calendar_name: *const c_char) -> TemporalResult {
+    let ym = match parse_plain_year_month(s, "plain year month") {
+        Ok(ym) => ym,
+        Err(e) => return e,
+    };
+    match parse_calendar_name(calendar_name) {
+        Ok(calendar) => TemporalResult::success(ym.to_ixdtf_string(calendar)),
+        Err(e) => e,
     }
 }
 
@@ -1759,7 +3106,7 @@ pub extern "C" fn temporal_plain_year_month_from_components(
     // but here we primarily use year/month.
     
     match PlainYearMonth::new(year, month, None, calendar) {
-        Ok(ym) => TemporalResult::success(ym.to_ixdtf_string(DisplayCalendar::Auto)),
+        Ok(ym) => TemporalResult::success(ym.to_ixdtf_string(display::calendar())),
         Err(e) => TemporalResult::range_error(&format!("Invalid plain year month components: {}", e)),
     }
 }
@@ -1812,11 +3159,19 @@ pub extern "C" fn temporal_plain_year_month_get_calendar(s: *const c_char) -> Te
     TemporalResult::success(ym.calendar().identifier().to_string())
 }
 
-/// Adds a duration to a PlainYearMonth.
+/// Adds a duration to a PlainYearMonth. `overflow` is "constrain" or "reject";
+/// NULL means "constrain", the spec default. It matters when the result month does
+/// not exist in the target year, e.g. a leap month in the hebrew calendar.
+///
+/// As in the current spec the month is entered on its first day whatever the sign,
+/// and only years and months may be added (weeks, days and time units are a
+/// RangeError). `temporal_plain_year_month_add_with_reference_day` takes weeks and
+/// days from a chosen day of the month.
 #[no_mangle]
 pub extern "C" fn temporal_plain_year_month_add(
     ym_str: *const c_char,
     duration_str: *const c_char,
+    overflow: *const c_char,
 ) -> TemporalResult {
     let ym = match parse_plain_year_month(ym_str, "plain year month") {
         Ok(y) => y,
@@ -1827,17 +3182,25 @@ pub extern "C" fn temporal_plain_year_month_add(
         Err(e) => return e,
     };
 
-    match ym.add(&duration, temporal_rs::options::Overflow::Reject) {
-        Ok(result) => TemporalResult::success(result.to_ixdtf_string(DisplayCalendar::Auto)),
+    let overflow = match parse_overflow(overflow) {
+        Ok(o) => o,
+        Err(e) => return e,
+    };
+
+    match ym.add(&duration, overflow) {
+        Ok(result) => TemporalResult::success(result.to_ixdtf_string(display::calendar())),
         Err(e) => TemporalResult::range_error(&format!("Failed to add duration: {}", e)),
     }
 }
 
-/// Subtracts a duration from a PlainYearMonth.
+/// Subtracts a duration from a PlainYearMonth. `overflow` is "constrain" or "reject";
+/// NULL means "constrain", the spec default. It matters when the result month does
+/// not exist in the target year, e.g. a leap month in the hebrew calendar.
 #[no_mangle]
 pub extern "C" fn temporal_plain_year_month_subtract(
     ym_str: *const c_char,
     duration_str: *const c_char,
+    overflow: *const c_char,
 ) -> TemporalResult {
     let ym = match parse_plain_year_month(ym_str, "plain year month") {
         Ok(y) => y,
@@ -1848,44 +3211,117 @@ pub extern "C" fn temporal_plain_year_month_subtract(
         Err(e) => return e,
     };
 
-    match ym.subtract(&duration, temporal_rs::options::Overflow::Reject) {
-        Ok(result) => TemporalResult::success(result.to_ixdtf_string(DisplayCalendar::Auto)),
+    let overflow = match parse_overflow(overflow) {
+        Ok(o) => o,
+        Err(e) => return e,
+    };
+
+    match ym.subtract(&duration, overflow) {
+        Ok(result) => TemporalResult::success(result.to_ixdtf_string(display::calendar())),
         Err(e) => TemporalResult::range_error(&format!("Failed to subtract duration: {}", e)),
     }
 }
 
+/// Adds a duration to a PlainYearMonth from a day of its month, so weeks and days
+/// can be added too. `reference_day` 0 starts on the first day for a non-negative
+/// duration and the last day for a negative one, as earlier spec drafts did; 1-31
+/// starts on that day, constrained to the month's length (31 is always the last
+/// day). `overflow` is as in `temporal_plain_year_month_add` and also applies to the
+/// day the addition lands on: with "reject", 2024-01 + P1M from day 31 fails because
+/// February has no 31st.
+/// Time units are a RangeError.
+#[no_mangle]
+pub extern "C" fn temporal_plain_year_month_add_with_reference_day(
+    ym_str: *const c_char,
+    duration_str: *const c_char,
+    overflow: *const c_char,
+    reference_day: i32,
+) -> TemporalResult {
+    year_month_add_with_reference_day(ym_str, duration_str, overflow, reference_day, false)
+}
+
+/// Subtracts a duration from a PlainYearMonth from a day of its month; see
+/// `temporal_plain_year_month_add_with_reference_day`. The sign used for
+/// `reference_day` 0 is that of the negated duration, so subtracting P1D starts on
+/// the last day and stays in the same month.
+#[no_mangle]
+pub extern "C" fn temporal_plain_year_month_subtract_with_reference_day(
+    ym_str: *const c_char,
+    duration_str: *const c_char,
+    overflow: *const c_char,
+    reference_day: i32,
+) -> TemporalResult {
+    year_month_add_with_reference_day(ym_str, duration_str, overflow, reference_day, true)
+}
+
+fn year_month_add_with_reference_day(
+    ym_str: *const c_char,
+    duration_str: *const c_char,
+    overflow: *const c_char,
+    reference_day: i32,
+    subtract: bool,
+) -> TemporalResult {
+    let ym = match parse_plain_year_month(ym_str, "plain year month") {
+        Ok(y) => y,
+        Err(e) => return e,
+    };
+    let duration = match parse_duration(duration_str, "duration") {
+        Ok(d) => d,
+        Err(e) => return e,
+    };
+    let overflow = match parse_overflow(overflow) {
+        Ok(o) => o,
+        Err(e) => return e,
+    };
+
+    let duration = if subtract { duration.negated() } else { duration };
+    match add_to_year_month_from_day(&ym, &duration, overflow, reference_day) {
+        Ok(result) => TemporalResult::success(result.to_ixdtf_string(display::calendar())),
+        Err(e) if subtract => TemporalResult::range_error(&format!("Failed to subtract duration: {}", e)),
+        Err(e) => TemporalResult::range_error(&format!("Failed to add duration: {}", e)),
+    }
+}
+
+/// Adds `duration` to the date `reference_day` of `ym`'s month (0: first or last day
+/// by the duration's sign) and returns the month it lands in.
+fn add_to_year_month_from_day(
+    ym: &PlainYearMonth,
+    duration: &Duration,
+    overflow: Overflow,
+    reference_day: i32,
+) -> Result<PlainYearMonth, TemporalError> {
+    if duration.hours() != 0
+        || duration.minutes() != 0
+        || duration.seconds() != 0
+        || duration.milliseconds() != 0
+        || duration.microseconds() != 0
+        || duration.nanoseconds() != 0
+    {
+        return Err(TemporalError::range().with_message("Can only add years, months, weeks or days to PlainYearMonth."));
+    }
+    let day = match reference_day {
+        0 if duration.sign() == temporal_rs::Sign::Negative => ym.days_in_month() as u8,
+        0 => 1,
+        1..=31 => reference_day as u8,
+        _ => return Err(TemporalError::range().with_message("referenceDay must be between 0 and 31")),
+    };
+    let start = ym.to_plain_date(Some(temporal_rs::fields::CalendarFields::new().with_day(day)))?;
+    start.add(duration, Some(overflow))?.to_plain_year_month()
+}
+
 /// Compares two PlainYearMonths.
 #[no_mangle]
 pub extern "C" fn temporal_plain_year_month_compare(a: *const c_char, b: *const c_char) -> CompareResult {
     let ym_a = match parse_plain_year_month(a, "first plain year month") {
         Ok(y) => y,
-        Err(e) => return CompareResult::range_error(
-            &unsafe { std::ffi::CStr::from_ptr(e.error_message) }.to_string_lossy()
-        ),
+        Err(e) => return CompareResult::from_error(e),
     };
     let ym_b = match parse_plain_year_month(b, "second plain year month") {
         Ok(y) => y,
-        Err(e) => return CompareResult::range_error(
-            &unsafe { std::ffi::CStr::from_ptr(e.error_message) }.to_string_lossy()
-        ),
+        Err(e) => return CompareResult::from_error(e),
     };
 
-    // PlainYearMonth doesn't have a direct compare method in temporal_rs that is public/exposed easily
-    // But we can compare ISO representations if calendars are the same, or compare fields.
-    // However, the spec says to compare ISO dates.
-    // Let's use to_plain_date with day=1 comparison as proxy or ISO string compare.
-    // For now, let's use string comparison of ISO format (normalized).
-    
-    let s_a = ym_a.to_ixdtf_string(DisplayCalendar::Never);
-    let s_b = ym_b.to_ixdtf_string(DisplayCalendar::Never);
-    
-    let val = match s_a.cmp(&s_b) {
-        std::cmp::Ordering::Less => -1,
-        std::cmp::Ordering::Equal => 0,
-        std::cmp::Ordering::Greater => 1,
-    };
-    
-    CompareResult::success(val)
+    CompareResult::success(compare_plain_year_months(&ym_a, &ym_b) as i32)
 }
 
 /// Returns a new PlainYearMonth with updated fields.
@@ -1917,12 +3353,13 @@ pub extern "C" fn temporal_plain_year_month_with(
     };
 
     match PlainYearMonth::new(new_year, new_month, None, new_calendar) {
-        Ok(new_ym) => TemporalResult::success(new_ym.to_ixdtf_string(DisplayCalendar::Auto)),
+        Ok(new_ym) => TemporalResult::success(new_ym.to_ixdtf_string(display::calendar())),
         Err(e) => TemporalResult::range_error(&format!("Invalid components: {}", e)),
     }
 }
 
-/// Computes difference (until).
+/// Computes the difference between two PlainYearMonths (until) with the spec default
+/// options (largestUnit "year").
 #[no_mangle]
 pub extern "C" fn temporal_plain_year_month_until(
     one_str: *const c_char,
@@ -1943,7 +3380,8 @@ pub extern "C" fn temporal_plain_year_month_until(
     }
 }
 
-/// Computes difference (since).
+/// Computes the difference between two PlainYearMonths (since) with the spec default
+/// options (largestUnit "year").
 #[no_mangle]
 pub extern "C" fn temporal_plain_year_month_since(
     one_str: *const c_char,
@@ -1981,12 +3419,18 @@ pub extern "C" fn temporal_plain_year_month_to_plain_date(
     
     // Construct manually:
     match PlainDate::new(ym.year(), ym.month(), day as u8, ym.calendar().clone()) {
-        Ok(d) => TemporalResult::success(d.to_ixdtf_string(DisplayCalendar::Auto)),
+        Ok(d) => TemporalResult::success(d.to_ixdtf_string(display::calendar())),
         Err(e) => TemporalResult::range_error(&format!("Failed to convert to plain date: {}", e)),
     }
 }
 
 // Helper
+fn compare_plain_year_months(a: &PlainYearMonth, b: &PlainYearMonth) -> std::cmp::Ordering {
+    // Compare the ISO fields, reference day included; strings would misorder negative
+    // and expanded years
+    a.compare_iso(b)
+}
+
 fn parse_plain_year_month(s: *const c_char, param_name: &str) -> Result<PlainYearMonth, TemporalResult> {
     let str_val = parse_c_str(s, param_name)?;
     PlainYearMonth::from_str(str_val)
@@ -2022,19 +3466,46 @@ pub extern "C" fn temporal_plain_month_day_from_string(s: *const c_char) -> Temp
         Ok(s) => s,
         Err(e) => return e,
     };
-    match PlainMonthDay::from_str(s_str) {
-        Ok(md) => TemporalResult::success(md.to_ixdtf_string(DisplayCalendar::Auto)),
-        Err(e) => TemporalResult::range_error(&format!("Invalid plain month day '{}': {}", s_str, e)),
+    let normalized = canonical_cache::canonical(Kind::PlainMonthDay, s_str, || match PlainMonthDay::from_str(s_str) {
+        Ok(md) => Ok(md.to_ixdtf_string(display::calendar())),
+        Err(e) => Err(TemporalResult::range_error(&format!("Invalid plain month day '{}': {}", s_str, e))),
+    });
+    match normalized {
+        Ok(s) => TemporalResult::success(s),
+        Err(e) => e,
+    }
+}
+
+/// Formats a PlainMonthDay with an explicit `calendar_name`; see
+/// `temporal_plain_date_to_string`.
+#[no_mangle]
+pub extern "C" fn temporal_plain_month_day_to_string(s: *const c_char, calendar_name: *const c_char) -> TemporalResult {
+    let md = match parse_plain_month_day(s, "plain month day") {
+        Ok(md) => md,
+        Err(e) => return e,
+    };
+    match parse_calendar_name(calendar_name) {
+        Ok(calendar) => TemporalResult::success(md.to_ixdtf_string(calendar)),
+        Err(e) => e,
     }
 }
 
+/// Passed as `reference_year` to `temporal_plain_month_day_from_components` when there
+/// is none. Out of the Temporal year range, so every real year, 0 included, can be passed.
+pub const TEMPORAL_NO_REFERENCE_YEAR: i32 = i32::MIN;
+
 /// Creates a PlainMonthDay from components.
+///
+/// `reference_year` (TEMPORAL_NO_REFERENCE_YEAR = none) is only used to check the
+/// month-day, so e.g. 02-29 is rejected (or constrained to 02-28) for a non-leap year. `overflow` is "constrain"
+/// or "reject"; NULL means "constrain".
 #[no_mangle]
 pub extern "C" fn temporal_plain_month_day_from_components(
     month: u8,
     day: u8,
     calendar_id: *const c_char,
-    _reference_year: i32,
+    reference_year: i32,
+    overflow: *const c_char,
 ) -> TemporalResult {
     let calendar = if !calendar_id.is_null() {
         match parse_c_str(calendar_id, "calendar id") {
@@ -2048,17 +3519,13 @@ pub extern "C" fn temporal_plain_month_day_from_components(
         Calendar::default()
     };
 
-    // temporal_rs PlainMonthDay::new takes (month, day, calendar).
-    // Reference year is implicit or handled by logic if needed, but basic constructor doesn't take it?
-    // Wait, PlainMonthDay usually needs a reference year for leap years (Feb 29).
-    // Let's check constructor.
-    
-    // Assuming new(month, day, calendar) works and uses iso8601 reference year if needed.
-    // If reference_year is provided, we might need a different constructor or logic.
-    // For now, let's try standard new.
-    
-    match PlainMonthDay::new_with_overflow(month, day, calendar, temporal_rs::options::Overflow::Reject, None) {
-        Ok(md) => TemporalResult::success(md.to_ixdtf_string(DisplayCalendar::Auto)),
+    let overflow = match parse_overflow(overflow) {
+        Ok(o) => o,
+        Err(e) => return e,
+    };
+
+    match plain_month_day_from_fields(month, day, calendar, overflow, reference_year) {
+        Ok(md) => TemporalResult::success(md.to_ixdtf_string(display::calendar())),
         Err(e) => TemporalResult::range_error(&format!("Invalid plain month day components: {}", e)),
     }
 }
@@ -2125,11 +3592,115 @@ pub extern "C" fn temporal_plain_month_day_to_plain_date(
     };
 
     match PlainDate::new(year, month, md.day(), md.calendar().clone()) {
-        Ok(d) => TemporalResult::success(d.to_ixdtf_string(DisplayCalendar::Auto)),
+        Ok(d) => TemporalResult::success(d.to_ixdtf_string(display::calendar())),
         Err(e) => TemporalResult::range_error(&format!("Failed to convert to plain date: {}", e)),
     }
 }
 
+/// Returns the first date strictly after `after_date` that falls on the month-day, for
+/// birthdays and anniversaries. See `month_day_occurrence` for the arguments.
+#[no_mangle]
+pub extern "C" fn temporal_next_occurrence_of_month_day(
+    md_str: *const c_char,
+    after_date: *const c_char,
+    calendar_id: *const c_char,
+    policy: *const c_char,
+) -> TemporalResult {
+    month_day_occurrence(md_str, after_date, calendar_id, policy, 1)
+}
+
+/// Returns the last date strictly before `before_date` that falls on the month-day.
+/// See `month_day_occurrence` for the arguments.
+#[no_mangle]
+pub extern "C" fn temporal_previous_occurrence_of_month_day(
+    md_str: *const c_char,
+    before_date: *const c_char,
+    calendar_id: *const c_char,
+    policy: *const c_char,
+) -> TemporalResult {
+    month_day_occurrence(md_str, before_date, calendar_id, policy, -1)
+}
+
+/// Years searched for a month-day before giving up: a full Gregorian cycle, far more
+/// than any Feb 29 or leap-month gap.
+const MONTH_DAY_SEARCH_YEARS: i32 = 400;
+
+/// Shared body of the occurrence functions. The month code and day of `md_str` are
+/// looked up in `calendar_id` (NULL = the month-day's own calendar), so "--12-25"
+/// with "hebrew" means 25 Elul. The result is a PlainDate in that calendar.
+///
+/// `policy` decides years where the day does not exist: "previous-day" (default, as
+/// `toPlainDate` constrains) uses the last day of the month, "next-day" the day after
+/// it, and "strict" skips the year. A leap month missing from a year (Hebrew Adar I)
+/// resolves as "constrain" does for both non-strict policies.
+fn month_day_occurrence(
+    md_str: *const c_char,
+    date_str: *const c_char,
+    calendar_id: *const c_char,
+    policy: *const c_char,
+    direction: i32,
+) -> TemporalResult {
+    let md = match parse_plain_month_day(md_str, "plain month day") {
+        Ok(m) => m,
+        Err(e) => return e,
+    };
+    let date = match parse_plain_date(date_str, "plain date") {
+        Ok(d) => d,
+        Err(e) => return e,
+    };
+    let calendar = if calendar_id.is_null() {
+        md.calendar().clone()
+    } else {
+        match parse_c_str(calendar_id, "calendar id") {
+            Ok(s) => match Calendar::from_str(s) {
+                Ok(c) => c,
+                Err(e) => return TemporalResult::range_error(&format!("Invalid calendar: {}", e)),
+            },
+            Err(e) => return e,
+        }
+    };
+    let policy = if policy.is_null() {
+        "previous-day"
+    } else {
+        match parse_c_str(policy, "policy") {
+            Ok(p @ ("strict" | "previous-day" | "next-day")) => p,
+            Ok(p) => return TemporalResult::range_error(&format!("Invalid policy: {} (expected strict, previous-day or next-day)", p)),
+            Err(e) => return e,
+        }
+    };
+
+    let in_year = |year: i32| -> Result<Option<PlainDate>, TemporalError> {
+        let partial = PartialDate::new()
+            .with_calendar(calendar.clone())
+            .with_year(Some(year))
+            .with_month_code(Some(md.month_code()))
+            .with_day(Some(md.day()));
+        if let Ok(exact) = PlainDate::from_partial(partial.clone(), Some(Overflow::Reject)) {
+            return Ok(Some(exact));
+        }
+        let constrained = PlainDate::from_partial(partial, Some(Overflow::Constrain))?;
+        match policy {
+            "strict" => Ok(None),
+            "next-day" if constrained.day() < md.day() => constrained.add(&unit_duration(Unit::Day), None).map(Some),
+            _ => Ok(Some(constrained)),
+        }
+    };
+
+    let start = date.with_calendar(calendar.clone()).year();
+    for offset in 0..=MONTH_DAY_SEARCH_YEARS {
+        let candidate = match in_year(start + offset * direction) {
+            Ok(c) => c,
+            Err(e) => return TemporalResult::range_error(&format!("Failed to find occurrence: {}", e)),
+        };
+        if let Some(candidate) = candidate {
+            if compare_plain_dates(&candidate, &date) as i32 == direction {
+                return TemporalResult::success(candidate.to_ixdtf_string(display::calendar()));
+            }
+        }
+    }
+    TemporalResult::range_error(&format!("No occurrence within {} years", MONTH_DAY_SEARCH_YEARS))
+}
+
 // Helper
 fn parse_plain_month_day(s: *const c_char, param_name: &str) -> Result<PlainMonthDay, TemporalResult> {
     let str_val = parse_c_str(s, param_name)?;
@@ -2137,18 +3708,51 @@ fn parse_plain_month_day(s: *const c_char, param_name: &str) -> Result<PlainMont
         .map_err(|e| TemporalResult::range_error(&format!("Invalid plain month day '{}': {}", str_val, e)))
 }
 
+/// Builds a PlainMonthDay, validating it against `reference_year`
+/// (TEMPORAL_NO_REFERENCE_YEAR = the default 1972) first. ISO month-days are then re-anchored to 1972 like the polyfill does.
+fn plain_month_day_from_fields(
+    month: u8,
+    day: u8,
+    calendar: Calendar,
+    overflow: Overflow,
+    reference_year: i32,
+) -> Result<PlainMonthDay, TemporalError> {
+    let reference_year = (reference_year != TEMPORAL_NO_REFERENCE_YEAR).then_some(reference_year);
+    let md = PlainMonthDay::new_with_overflow(month, day, calendar.clone(), overflow, reference_year)?;
+    if calendar.is_iso() && md.reference_year() != 1972 {
+        return PlainMonthDay::new_with_overflow(
+            md.month_code().to_month_integer(),
+            md.day(),
+            calendar,
+            Overflow::Reject,
+            None,
+        );
+    }
+    Ok(md)
+}
+
 // ============================================================================
 // Calendar API
 // ============================================================================
 
 /// Gets a Calendar from a string identifier.
+///
+/// With `lenient` non-zero, surrounding whitespace is trimmed and the identifier
+/// is lowercased before lookup, so " Gregory\n" resolves to "gregory".
 #[no_mangle]
-pub extern "C" fn temporal_calendar_from(id: *const c_char) -> TemporalResult {
+pub extern "C" fn temporal_calendar_from(id: *const c_char, lenient: i8) -> TemporalResult {
     let id_str = match parse_c_str(id, "calendar identifier") {
         Ok(s) => s,
         Err(e) => return e,
     };
-    
+    let normalized;
+    let id_str = if lenient != 0 {
+        normalized = lenient_identifier(id_str);
+        normalized.as_str()
+    } else {
+        id_str
+    };
+
     match Calendar::from_str(id_str) {
         Ok(calendar) => TemporalResult::success(calendar.identifier().to_string()),
         Err(e) => TemporalResult::range_error(&format!("Invalid calendar identifier '{}': {}", id_str, e)),
@@ -2171,111 +3775,552 @@ pub extern "C" fn temporal_calendar_id(id: *const c_char) -> TemporalResult {
     }
 }
 
-// ============================================================================
-// Duration API
-
-// ============================================================================
-/// Note: microseconds and nanoseconds are clamped to i64 range for FFI safety.
-#[repr(C)]
-pub struct DurationComponents {
-    pub years: i64,
-    pub months: i64,
-    pub weeks: i64,
-    pub days: i64,
-    pub hours: i64,
-    pub minutes: i64,
-    pub seconds: i64,
-    pub milliseconds: i64,
-    pub microseconds: i64,
-    pub nanoseconds: i64,
-    /// Sign of the duration: -1, 0, or 1
-    pub sign: i8,
-    /// 1 if the components are valid, 0 if parsing failed
-    pub is_valid: i8,
+/// Returns the number of days in the given month (1-based ordinal month) of a year
+/// in the given calendar, without going through a date string. A NULL calendar
+/// means ISO 8601.
+///
+/// Returns 0 on error; details are available via `temporal_last_error_type` and
+/// `temporal_last_error_message`.
+#[no_mangle]
+pub extern "C" fn temporal_calendar_days_in_month(
+    calendar_id: *const c_char,
+    year: i32,
+    month: u8,
+) -> u16 {
+    clear_last_error();
+    match calendar_month_start(calendar_id, year, month) {
+        Some(date) => date.days_in_month(),
+        None => 0,
+    }
 }
 
-impl Default for DurationComponents {
-    fn default() -> Self {
-        Self {
-            years: 0,
-            months: 0,
-            weeks: 0,
-            days: 0,
-            hours: 0,
-            minutes: 0,
-            seconds: 0,
-            milliseconds: 0,
-            microseconds: 0,
-            nanoseconds: 0,
-            sign: 0,
-            is_valid: 0,
-        }
+/// Returns the number of days in the given year of a calendar (NULL = ISO 8601).
+///
+/// Returns 0 on error; details are available via the last-error functions.
+#[no_mangle]
+pub extern "C" fn temporal_calendar_days_in_year(calendar_id: *const c_char, year: i32) -> u16 {
+    clear_last_error();
+    match calendar_month_start(calendar_id, year, 1) {
+        Some(date) => date.days_in_year(),
+        None => 0,
     }
 }
 
-/// Parses an ISO 8601 duration string and returns a TemporalResult.
+/// Returns 1 if the given year is a leap year in the calendar (NULL = ISO 8601), 0 if
+/// not, or -1 on error. For lunisolar calendars a leap year is one with a leap month.
 #[no_mangle]
-pub extern "C" fn temporal_duration_from_string(s: *const c_char) -> TemporalResult {
-    if s.is_null() {
-        return TemporalResult::type_error("Duration string cannot be null");
+pub extern "C" fn temporal_calendar_in_leap_year(calendar_id: *const c_char, year: i32) -> i8 {
+    clear_last_error();
+    match calendar_month_start(calendar_id, year, 1) {
+        Some(date) => if date.in_leap_year() { 1 } else { 0 },
+        None => -1,
     }
+}
 
-    let c_str = match unsafe { std::ffi::CStr::from_ptr(s) }.to_str() {
-        Ok(s) => s,
-        Err(_) => return TemporalResult::type_error("Invalid UTF-8 in duration string"),
-    };
-
-    match Duration::from_str(c_str) {
-        Ok(duration) => TemporalResult::success(duration.to_string()),
-        Err(e) => TemporalResult::range_error(&format!("Invalid duration '{}': {}", c_str, e)),
+/// Returns the number of months in the given year of a calendar (NULL = ISO 8601),
+/// e.g. 13 for Hebrew leap years. Returns 0 on error.
+#[no_mangle]
+pub extern "C" fn temporal_calendar_months_in_year(calendar_id: *const c_char, year: i32) -> u16 {
+    clear_last_error();
+    match calendar_month_start(calendar_id, year, 1) {
+        Some(date) => date.months_in_year(),
+        None => 0,
     }
 }
 
-/// Gets all component values from a duration string in a single call.
-/// Sets out->is_valid to 1 on success, 0 on error.
+/// Returns the number of valid days (1..=n) for a month-day picker in the given
+/// month of a calendar year (NULL calendar = ISO 8601), or 0 on error.
 #[no_mangle]
-pub extern "C" fn temporal_duration_get_components(
-    s: *const c_char,
-    out: *mut DurationComponents,
-) {
-    if out.is_null() {
-        return;
-    }
+pub extern "C" fn temporal_calendar_valid_days_in_month(
+    calendar_id: *const c_char,
+    year: i32,
+    month: u8,
+) -> u8 {
+    temporal_calendar_days_in_month(calendar_id, year, month) as u8
+}
 
-    // Initialize to invalid state
-    unsafe {
-        *out = DurationComponents::default();
+/// Writes the valid day count of every month of a calendar year into `out`
+/// (index 0 = month 1), so a picker can be built in one call.
+///
+/// Returns the number of months in the year, or -1 on error. If `out_len` is smaller
+/// than the month count, nothing is written and the required length is still returned.
+#[no_mangle]
+pub extern "C" fn temporal_calendar_valid_days_in_year(
+    calendar_id: *const c_char,
+    year: i32,
+    out: *mut u8,
+    out_len: usize,
+) -> i32 {
+    clear_last_error();
+    let calendar = match calendar_with_last_error(calendar_id) {
+        Some(c) => c,
+        None => return -1,
+    };
+    let months_in_year = match calendar_month_start_in(&calendar, year, 1) {
+        Some(date) => date.months_in_year(),
+        None => return -1,
+    };
+    if out.is_null() || out_len < months_in_year as usize {
+        return months_in_year as i32;
     }
 
-    if s.is_null() {
-        return;
+    let out = unsafe { std::slice::from_raw_parts_mut(out, months_in_year as usize) };
+    for (index, slot) in out.iter_mut().enumerate() {
+        match calendar_month_start_in(&calendar, year, index as u8 + 1) {
+            Some(date) => *slot = date.days_in_month() as u8,
+            None => return -1,
+        }
     }
+    months_in_year as i32
+}
 
-    let c_str = unsafe { std::ffi::CStr::from_ptr(s) };
-    let duration_str = match c_str.to_str() {
-        Ok(s) => s,
-        Err(_) => return,
+/// The calendar's own dateUntil: the difference from `one` to `two` counted in the
+/// calendar's years, months, weeks and days, for recurrence logic that needs real
+/// month and year differences in non-ISO calendars. Both dates are read in
+/// `calendar_id` (NULL = ISO 8601) whatever their own calendar. `largest_unit` is
+/// "year", "month", "week" or "day" (the default for NULL).
+#[no_mangle]
+pub extern "C" fn temporal_calendar_date_until(
+    calendar_id: *const c_char,
+    one: *const c_char,
+    two: *const c_char,
+    largest_unit: *const c_char,
+) -> TemporalResult {
+    let calendar = if calendar_id.is_null() {
+        Calendar::default()
+    } else {
+        let id_str = match parse_c_str(calendar_id, "calendar identifier") {
+            Ok(s) => s,
+            Err(e) => return e,
+        };
+        match Calendar::from_str(id_str) {
+            Ok(c) => c,
+            Err(e) => return TemporalResult::range_error(&format!("Invalid calendar identifier '{}': {}", id_str, e)),
+        }
+    };
+    let one = match parse_plain_date(one, "first plain date") {
+        Ok(d) => d.with_calendar(calendar.clone()),
+        Err(e) => return e,
+    };
+    let two = match parse_plain_date(two, "second plain date") {
+        Ok(d) => d.with_calendar(calendar),
+        Err(e) => return e,
+    };
+    let largest_unit = if largest_unit.is_null() {
+        Unit::Day
+    } else {
+        match parse_calendar_unit(largest_unit) {
+            Ok(u) => u,
+            Err(e) => return e,
+        }
+    };
+
+    let mut options = temporal_rs::options::DifferenceSettings::default();
+    options.largest_unit = Some(largest_unit);
+    match one.until(&two, options) {
+        Ok(d) => TemporalResult::success(d.to_string()),
+        Err(e) => TemporalResult::range_error(&format!("Failed to compute difference: {}", e)),
+    }
+}
+
+/// Lists a calendar's eras in chronological order, one line per era, so a picker can
+/// offer era-aware year entry: `code,start,end,direction`. start and end are the ISO
+/// dates of the era's first and last day, empty where the era runs past the range
+/// Temporal supports; direction is -1 for eras whose years count backwards (bce,
+/// broc, bh) and 1 otherwise. A calendar without eras (iso8601, chinese, dangi)
+/// gives an empty string. A NULL calendar_id means ISO 8601.
+///
+/// The boundaries come from the calendar data itself, so they follow whatever
+/// temporal_rs knows (e.g. reiwa from 2019-05-01).
+#[no_mangle]
+pub extern "C" fn temporal_calendar_eras(calendar_id: *const c_char) -> TemporalResult {
+    let calendar = if calendar_id.is_null() {
+        Calendar::default()
+    } else {
+        let id_str = match parse_c_str(calendar_id, "calendar identifier") {
+            Ok(s) => s,
+            Err(e) => return e,
+        };
+        match Calendar::from_str(id_str) {
+            Ok(c) => c,
+            Err(e) => return TemporalResult::range_error(&format!("Invalid calendar identifier '{}': {}", id_str, e)),
+        }
+    };
+    let eras = match calendar_eras(&calendar) {
+        Ok(eras) => eras,
+        Err(e) => return TemporalResult::range_error(&format!("Failed to list eras: {}", e)),
+    };
+    let (first, last) = PLAIN_DATE_EPOCH_DAYS;
+    let date = |day: i64, unbounded: i64| -> Result<String, TemporalError> {
+        if day == unbounded {
+            Ok(String::new())
+        } else {
+            Ok(iso_date_on(day)?.to_ixdtf_string(DisplayCalendar::Never))
+        }
+    };
+    let mut lines = Vec::with_capacity(eras.len());
+    for era in eras {
+        match date(era.first_day, first).and_then(|start| Ok((start, date(era.last_day, last)?))) {
+            Ok((start, end)) => lines.push(format!("{},{},{},{}", era.code, start, end, if era.inverse { -1 } else { 1 })),
+            Err(e) => return TemporalResult::range_error(&format!("Failed to format era boundary: {}", e)),
+        }
+    }
+    TemporalResult::success(lines.join("\n"))
+}
+
+/// Epoch days of the first and last PlainDate, -271821-04-19 and +275760-09-13.
+const PLAIN_DATE_EPOCH_DAYS: (i64, i64) = (-100_000_001, 100_000_000);
+
+struct CalendarEra {
+    code: String,
+    first_day: i64,
+    last_day: i64,
+    /// Era years count backwards, as for bce.
+    inverse: bool,
+}
+
+fn iso_date_on(epoch_day: i64) -> Result<PlainDate, TemporalError> {
+    PlainDate::try_new_iso(1970, 1, 1)?.add(&Duration::new(0, 0, 0, epoch_day, 0, 0, 0, 0, 0, 0)?, None)
+}
+
+/// The era code and era year of an epoch day in `calendar`.
+fn era_on(calendar: &Calendar, epoch_day: i64) -> Result<Option<(String, i32)>, TemporalError> {
+    let date = iso_date_on(epoch_day)?.with_calendar(calendar.clone());
+    Ok(date.era().map(|era| era.to_string()).zip(date.era_year()))
+}
+
+/// Finds every era of a calendar across the PlainDate range. Each era covers one
+/// contiguous span, so bisecting wherever the era differs between two days finds
+/// every first day with a few dozen lookups per boundary.
+fn calendar_eras(calendar: &Calendar) -> Result<Vec<CalendarEra>, TemporalError> {
+    let code_on = |day: i64| era_on(calendar, day).map(|era| era.map(|(code, _)| code));
+    if code_on(0)?.is_none() {
+        return Ok(Vec::new());
+    }
+    let (first, last) = PLAIN_DATE_EPOCH_DAYS;
+    let mut starts = vec![first];
+    era_starts(&code_on, (first, code_on(first)?), (last, code_on(last)?), &mut starts)?;
+
+    let mut eras = Vec::with_capacity(starts.len());
+    for (index, &first_day) in starts.iter().enumerate() {
+        let last_day = starts.get(index + 1).map_or(last, |next| next - 1);
+        let Some((code, year)) = era_on(calendar, first_day)? else {
+            continue;
+        };
+        let inverse =
+            last_day - first_day >= 366 && era_on(calendar, first_day + 366)?.is_some_and(|(_, later)| later < year);
+        eras.push(CalendarEra { code, first_day, last_day, inverse });
+    }
+    Ok(eras)
+}
+
+/// Pushes the first day of every era that starts in (lo, hi].
+fn era_starts(
+    code_on: &impl Fn(i64) -> Result<Option<String>, TemporalError>,
+    (lo, lo_code): (i64, Option<String>),
+    (hi, hi_code): (i64, Option<String>),
+    starts: &mut Vec<i64>,
+) -> Result<(), TemporalError> {
+    if lo_code == hi_code {
+        return Ok(());
+    }
+    if hi - lo == 1 {
+        starts.push(hi);
+        return Ok(());
+    }
+    let mid = lo + (hi - lo) / 2;
+    let mid_code = code_on(mid)?;
+    era_starts(code_on, (lo, lo_code), (mid, mid_code.clone()), starts)?;
+    era_starts(code_on, (mid, mid_code), (hi, hi_code), starts)
+}
+
+/// Resolves an optional calendar identifier, reporting failures via the last-error slot.
+fn calendar_with_last_error(calendar_id: *const c_char) -> Option<Calendar> {
+    if calendar_id.is_null() {
+        return Some(Calendar::default());
+    }
+    let id_str = read_with_last_error(calendar_id, "calendar", "id", false)?;
+    match Calendar::from_str(id_str) {
+        Ok(calendar) => Some(calendar),
+        Err(e) => {
+            set_last_error(TemporalErrorType::RangeError, format_args!("Invalid calendar '{}': {}", id_str, e));
+            None
+        }
+    }
+}
+
+/// Builds the first day of a calendar month from calendar (not ISO) year/month fields.
+fn calendar_month_start(calendar_id: *const c_char, year: i32, month: u8) -> Option<PlainDate> {
+    let calendar = calendar_with_last_error(calendar_id)?;
+    calendar_month_start_in(&calendar, year, month)
+}
+
+fn calendar_month_start_in(calendar: &Calendar, year: i32, month: u8) -> Option<PlainDate> {
+    match month_start(calendar, year, month) {
+        Ok(date) => Some(date),
+        Err(e) => {
+            set_last_error(TemporalErrorType::RangeError, format_args!("Invalid month {} of year {}: {}", month, year, e));
+            None
+        }
+    }
+}
+
+// ============================================================================
+// Duration API
+
+// ============================================================================
+/// Note: `microseconds` and `nanoseconds` are clamped to i64 range for FFI safety;
+/// the full i128 values are available from the `_high`/`_low` split fields.
+#[repr(C)]
+pub struct DurationComponents {
+    pub years: i64,
+    pub months: i64,
+    pub weeks: i64,
+    pub days: i64,
+    pub hours: i64,
+    pub minutes: i64,
+    pub seconds: i64,
+    pub milliseconds: i64,
+    pub microseconds: i64,
+    pub nanoseconds: i64,
+    /// Sign of the duration: -1, 0, or 1
+    pub sign: i8,
+    /// 1 if the components are valid, 0 if parsing failed
+    pub is_valid: i8,
+    /// Full microseconds value is `microseconds_high * 2^64 + microseconds_low`
+    pub microseconds_high: i64,
+    pub microseconds_low: u64,
+    /// Full nanoseconds value is `nanoseconds_high * 2^64 + nanoseconds_low`
+    pub nanoseconds_high: i64,
+    pub nanoseconds_low: u64,
+    /// 1 if `microseconds` or `nanoseconds` did not fit in i64 and were clamped
+    pub is_clamped: i8,
+}
+
+impl Default for DurationComponents {
+    fn default() -> Self {
+        Self {
+            years: 0,
+            months: 0,
+            weeks: 0,
+            days: 0,
+            hours: 0,
+            minutes: 0,
+            seconds: 0,
+            milliseconds: 0,
+            microseconds: 0,
+            nanoseconds: 0,
+            sign: 0,
+            is_valid: 0,
+            microseconds_high: 0,
+            microseconds_low: 0,
+            nanoseconds_high: 0,
+            nanoseconds_low: 0,
+            is_clamped: 0,
+        }
+    }
+}
+
+/// Parses an ISO 8601 duration string and returns a TemporalResult.
+#[no_mangle]
+pub extern "C" fn temporal_duration_from_string(s: *const c_char) -> TemporalResult {
+    let c_str = match parse_c_str(s, "Duration string") {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+
+    let normalized = canonical_cache::canonical(Kind::Duration, c_str, || match duration_from_str(c_str) {
+        Ok(duration) => Ok(duration.to_string()),
+        Err(e) => Err(TemporalResult::range_error(&format!("Invalid duration '{}': {}", c_str, e))),
+    });
+    match normalized {
+        Ok(s) => TemporalResult::success(s),
+        Err(e) => e,
+    }
+}
+
+/// Gets all component values from a duration string in a single call.
+/// Sets out->is_valid to 1 on success, 0 on error.
+#[no_mangle]
+pub extern "C" fn temporal_duration_get_components(
+    s: *const c_char,
+    out: *mut DurationComponents,
+) {
+    if out.is_null() {
+        return;
+    }
+
+    // Initialize to invalid state
+    unsafe {
+        *out = DurationComponents::default();
+    }
+
+    let duration_str = match read_c_str(s, false) {
+        Ok(s) => s,
+        Err(_) => return,
     };
 
-    let duration = match Duration::from_str(duration_str) {
+    let duration = match duration_from_str(duration_str) {
         Ok(d) => d,
         Err(_) => return,
     };
 
     unsafe {
-        (*out).years = duration.years();
-        (*out).months = duration.months();
-        (*out).weeks = duration.weeks();
-        (*out).days = duration.days();
-        (*out).hours = duration.hours();
-        (*out).minutes = duration.minutes();
-        (*out).seconds = duration.seconds();
-        (*out).milliseconds = duration.milliseconds();
+        *out = duration_components(&duration);
+    }
+}
+
+fn duration_components(duration: &Duration) -> DurationComponents {
+    let (microseconds_high, microseconds_low) = split_i128(duration.microseconds());
+    let (nanoseconds_high, nanoseconds_low) = split_i128(duration.nanoseconds());
+    DurationComponents {
+        years: duration.years(),
+        months: duration.months(),
+        weeks: duration.weeks(),
+        days: duration.days(),
+        hours: duration.hours(),
+        minutes: duration.minutes(),
+        seconds: duration.seconds(),
+        milliseconds: duration.milliseconds(),
         // Clamp i128 values to i64 range for FFI safety
-        (*out).microseconds = duration.microseconds().clamp(i64::MIN as i128, i64::MAX as i128) as i64;
-        (*out).nanoseconds = duration.nanoseconds().clamp(i64::MIN as i128, i64::MAX as i128) as i64;
-        (*out).sign = duration.sign() as i8;
-        (*out).is_valid = 1;
+        microseconds: clamp_i128(duration.microseconds()),
+        nanoseconds: clamp_i128(duration.nanoseconds()),
+        sign: duration.sign() as i8,
+        is_valid: 1,
+        microseconds_high,
+        microseconds_low,
+        nanoseconds_high,
+        nanoseconds_low,
+        is_clamped: duration_is_clamped(duration) as i8,
+    }
+}
+
+/// A duration's components as doubles, the type JS Numbers use.
+#[repr(C)]
+#[derive(Debug, Default)]
+pub struct DurationComponentsF64 {
+    pub years: f64,
+    pub months: f64,
+    pub weeks: f64,
+    pub days: f64,
+    pub hours: f64,
+    pub minutes: f64,
+    pub seconds: f64,
+    pub milliseconds: f64,
+    pub microseconds: f64,
+    pub nanoseconds: f64,
+    /// Sign of the duration: -1, 0, or 1
+    pub sign: i8,
+    /// 1 if the components are valid, 0 if parsing failed
+    pub is_valid: i8,
+    /// 1 if every component is exactly representable as a double
+    pub is_exact: i8,
+    /// Bit i (0 = years ... 9 = nanoseconds) is set when that component was rounded
+    pub inexact_fields: u16,
+}
+
+/// Gets all component values from a duration string as doubles. A component that is
+/// not exactly representable would be rounded to the nearest double and flagged in
+/// `inexact_fields`, with `is_exact` 0. Temporal caps durations below 2^53 seconds, so
+/// valid durations currently always convert exactly; the flags let callers check
+/// rather than assume it. Sets out->is_valid to 0 on error.
+#[no_mangle]
+pub extern "C" fn temporal_duration_get_components_f64(s: *const c_char, out: *mut DurationComponentsF64) {
+    if out.is_null() {
+        return;
+    }
+    unsafe { *out = DurationComponentsF64::default() };
+
+    let duration = match read_c_str(s, false).map(duration_from_str) {
+        Ok(Ok(d)) => d,
+        _ => return,
+    };
+    let values = [
+        duration.years() as i128,
+        duration.months() as i128,
+        duration.weeks() as i128,
+        duration.days() as i128,
+        duration.hours() as i128,
+        duration.minutes() as i128,
+        duration.seconds() as i128,
+        duration.milliseconds() as i128,
+        duration.microseconds(),
+        duration.nanoseconds(),
+    ];
+    let doubles = values.map(|v| v as f64);
+    let inexact_fields = values
+        .iter()
+        .zip(doubles)
+        .enumerate()
+        .filter(|(_, (value, double))| *double as i128 != **value)
+        .fold(0u16, |mask, (i, _)| mask | 1 << i);
+    let [years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds] = doubles;
+    unsafe {
+        *out = DurationComponentsF64 {
+            years,
+            months,
+            weeks,
+            days,
+            hours,
+            minutes,
+            seconds,
+            milliseconds,
+            microseconds,
+            nanoseconds,
+            sign: duration.sign() as i8,
+            is_valid: 1,
+            is_exact: (inexact_fields == 0) as i8,
+            inexact_fields,
+        };
+    }
+}
+
+/// Converts a time-only duration to milliseconds for animation and timer APIs,
+/// including any sub-millisecond fraction. As in `Duration.total` without relativeTo,
+/// days count as 24 hours and years, months or weeks are a RangeError.
+///
+/// Returns NaN on error; details are available via `temporal_last_error_type` and
+/// `temporal_last_error_message`.
+#[no_mangle]
+pub extern "C" fn temporal_duration_to_millis_f64(s: *const c_char) -> f64 {
+    clear_last_error();
+    let duration = match parse_with_last_error(s, "millis", "duration", &duration_from_str) {
+        Some(d) => d,
+        None => return f64::NAN,
+    };
+    if duration.years() != 0 || duration.months() != 0 || duration.weeks() != 0 {
+        set_last_error(
+            TemporalErrorType::RangeError,
+            format_args!("Cannot convert {} to milliseconds: it has years, months or weeks", duration),
+        );
+        return f64::NAN;
+    }
+    let whole_seconds = ((duration.days() * 24 + duration.hours()) * 60 + duration.minutes()) * 60 + duration.seconds();
+    let nanoseconds = whole_seconds as i128 * 1_000_000_000
+        + duration.milliseconds() as i128 * 1_000_000
+        + duration.microseconds() * 1_000
+        + duration.nanoseconds();
+    nanoseconds as f64 / 1_000_000.0
+}
+
+/// Creates a time-only Duration from milliseconds, such as an Animated or timer
+/// value. The fraction is kept to the nearest nanosecond; the result is in seconds
+/// (1500 -> "PT1.5S"), as `Temporal.Duration.from({ milliseconds })` prints it.
+#[no_mangle]
+pub extern "C" fn temporal_duration_from_millis(ms: f64) -> TemporalResult {
+    if !ms.is_finite() {
+        return TemporalResult::range_error(&format!("Invalid milliseconds: {}", ms));
+    }
+    // Saturates far outside the Duration range, which Duration::new then rejects
+    let nanoseconds = (ms * 1_000_000.0).round() as i128;
+    let milliseconds = match i64::try_from(nanoseconds / 1_000_000) {
+        Ok(m) => m,
+        Err(_) => return TemporalResult::range_error(&format!("Milliseconds out of range: {}", ms)),
+    };
+    let remainder = nanoseconds % 1_000_000;
+    match Duration::new(0, 0, 0, 0, 0, 0, 0, milliseconds, remainder / 1_000, remainder % 1_000) {
+        Ok(d) => TemporalResult::success(d.to_string()),
+        Err(e) => TemporalResult::range_error(&format!("Milliseconds out of range: {}", e)),
     }
 }
 
@@ -2370,9 +4415,8 @@ impl CompareResult {
     }
 
     fn range_error(message: &str) -> Self {
-        let error_msg = CString::new(message)
-            .map(|s| s.into_raw())
-            .unwrap_or(ptr::null_mut());
+        metrics::record_error(TemporalErrorType::RangeError);
+        let error_msg = into_c_string(message.to_string());
         Self {
             value: 0,
             error_type: TemporalErrorType::RangeError as i32,
@@ -2381,15 +4425,25 @@ impl CompareResult {
     }
 
     fn type_error(message: &str) -> Self {
-        let error_msg = CString::new(message)
-            .map(|s| s.into_raw())
-            .unwrap_or(ptr::null_mut());
+        metrics::record_error(TemporalErrorType::TypeError);
+        let error_msg = into_c_string(message.to_string());
         Self {
             value: 0,
             error_type: TemporalErrorType::TypeError as i32,
             error_message: error_msg,
         }
     }
+
+    /// Converts a failed parse result, keeping its error type and taking ownership
+    /// of its message.
+    fn from_error(error: TemporalResult) -> Self {
+        debug_assert!(error.value.is_null());
+        Self {
+            value: 0,
+            error_type: error.error_type,
+            error_message: error.error_message,
+        }
+    }
 }
 
 /// Frees a CompareResult's allocated strings.
@@ -2409,45 +4463,31 @@ pub unsafe extern "C" fn temporal_free_compare_result(result: *mut CompareResult
 pub extern "C" fn temporal_duration_compare(a: *const c_char, b: *const c_char) -> CompareResult {
     let duration_a = match parse_duration(a, "first duration") {
         Ok(d) => d,
-        Err(e) => return CompareResult::range_error(
-            &unsafe { std::ffi::CStr::from_ptr(e.error_message) }.to_string_lossy()
-        ),
+        Err(e) => return CompareResult::from_error(e),
     };
     let duration_b = match parse_duration(b, "second duration") {
         Ok(d) => d,
-        Err(e) => return CompareResult::range_error(
-            &unsafe { std::ffi::CStr::from_ptr(e.error_message) }.to_string_lossy()
-        ),
+        Err(e) => return CompareResult::from_error(e),
     };
 
+    match compare_durations(&duration_a, &duration_b) {
+        Ok(ordering) => CompareResult::success(ordering as i32),
+        Err(message) => CompareResult::range_error(message),
+    }
+}
+
+fn compare_durations(a: &Duration, b: &Duration) -> Result<std::cmp::Ordering, &'static str> {
     // Check if durations have calendar units (years, months, weeks)
-    let has_calendar_a = duration_a.years() != 0 || duration_a.months() != 0 || duration_a.weeks() != 0;
-    let has_calendar_b = duration_b.years() != 0 || duration_b.months() != 0 || duration_b.weeks() != 0;
+    let has_calendar_a = a.years() != 0 || a.months() != 0 || a.weeks() != 0;
+    let has_calendar_b = b.years() != 0 || b.months() != 0 || b.weeks() != 0;
 
     if has_calendar_a || has_calendar_b {
-        return CompareResult::range_error(
-            "Comparing durations with years, months, or weeks requires a relativeTo option (not yet supported)"
-        );
+        return Err("Comparing durations with years, months, or weeks requires a relativeTo option (not yet supported)");
     }
 
-    // For time-only durations, we can compare by converting to total nanoseconds
-    let total_a = duration_a.days() as i128 * 86_400_000_000_000
-        + duration_a.hours() as i128 * 3_600_000_000_000
-        + duration_a.minutes() as i128 * 60_000_000_000
-        + duration_a.seconds() as i128 * 1_000_000_000
-        + duration_a.milliseconds() as i128 * 1_000_000
-        + duration_a.microseconds() * 1_000
-        + duration_a.nanoseconds();
-
-    let total_b = duration_b.days() as i128 * 86_400_000_000_000
-        + duration_b.hours() as i128 * 3_600_000_000_000
-        + duration_b.minutes() as i128 * 60_000_000_000
-        + duration_b.seconds() as i128 * 1_000_000_000
-        + duration_b.milliseconds() as i128 * 1_000_000
-        + duration_b.microseconds() * 1_000
-        + duration_b.nanoseconds();
-
-    CompareResult::success(total_a.cmp(&total_b) as i32)
+    // Days are treated as 24 hours and compared as normalized time durations by temporal_rs
+    a.compare(b, None)
+        .map_err(|_| "Durations are out of range for comparison")
 }
 
 /// Sentinel value for "unchanged" component in durationWith.
@@ -2524,50 +4564,447 @@ pub extern "C" fn temporal_duration_with(
     }
 }
 
-// Helper functions
+/// Adds up a newline-delimited list of durations in a single call.
+///
+/// With a NULL `relative_to`, durations containing years, months, or weeks are
+/// rejected. With a PlainDate or PlainDateTime `relative_to`, the durations are
+/// applied in order starting from that point and the total is balanced up to the
+/// largest unit present in the inputs.
+#[no_mangle]
+pub extern "C" fn temporal_duration_sum(
+    durations: *const c_char,
+    relative_to: *const c_char,
+) -> TemporalResult {
+    let list = match parse_c_str(durations, "durations") {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
 
-fn parse_c_str(s: *const c_char, param_name: &str) -> Result<&str, TemporalResult> {
-    if s.is_null() {
-        return Err(TemporalResult::type_error(&format!("{} cannot be null", param_name)));
+    let mut parsed = Vec::new();
+    for item in split_list(list) {
+        match duration_from_str(item) {
+            Ok(d) => parsed.push(d),
+            Err(e) => return TemporalResult::range_error(&format!("Invalid duration '{}': {}", item, e)),
+        }
     }
-    unsafe { std::ffi::CStr::from_ptr(s) }
-        .to_str()
-        .map_err(|_| TemporalResult::type_error(&format!("Invalid UTF-8 in {}", param_name)))
-}
 
-fn parse_duration(s: *const c_char, param_name: &str) -> Result<Duration, TemporalResult> {
-    let str_val = parse_c_str(s, param_name)?;
-    Duration::from_str(str_val)
-        .map_err(|e| TemporalResult::range_error(&format!("Invalid duration '{}': {}", str_val, e)))
-}
+    if relative_to.is_null() {
+        let mut total = Duration::default();
+        for duration in &parsed {
+            total = match total.add(duration) {
+                Ok(d) => d,
+                Err(e) => return TemporalResult::range_error(&format!("Failed to sum durations: {}", e)),
+            };
+        }
+        return TemporalResult::success(total.to_string());
+    }
 
-fn parse_instant(s: *const c_char, param_name: &str) -> Result<Instant, TemporalResult> {
-    let str_val = parse_c_str(s, param_name)?;
-    Instant::from_str(str_val)
-        .map_err(|e| TemporalResult::range_error(&format!("Invalid instant '{}': {}", str_val, e)))
-}
+    let start = match parse_plain_date_time(relative_to, "relativeTo") {
+        Ok(dt) => dt,
+        Err(e) => return e,
+    };
+    let mut end = start.clone();
+    let mut largest_unit = Unit::Nanosecond;
+    for duration in &parsed {
+        largest_unit = largest_unit.max(largest_duration_unit(duration));
+        end = match end.add(duration, Some(Overflow::Constrain)) {
+            Ok(dt) => dt,
+            Err(e) => return TemporalResult::range_error(&format!("Failed to sum durations: {}", e)),
+        };
+    }
 
-fn parse_plain_time(s: *const c_char, param_name: &str) -> Result<PlainTime, TemporalResult> {
-    let str_val = parse_c_str(s, param_name)?;
-    PlainTime::from_str(str_val)
-        .map_err(|e| TemporalResult::range_error(&format!("Invalid plain time '{}': {}", str_val, e)))
+    let mut options = temporal_rs::options::DifferenceSettings::default();
+    options.largest_unit = Some(largest_unit);
+    match start.until(&end, options) {
+        Ok(total) => TemporalResult::success(total.to_string()),
+        Err(e) => TemporalResult::range_error(&format!("Failed to sum durations: {}", e)),
+    }
 }
 
-fn duration_binary_op<F>(
-    a: *const c_char,
-    b: *const c_char,
-    op_name: &str,
-    op: F,
-) -> TemporalResult
-where
-    F: FnOnce(Duration, Duration) -> Result<Duration, temporal_rs::TemporalError>,
-{
-    let duration_a = match parse_duration(a, "first duration") {
-        Ok(d) => d,
+/// Splits a duration into a whole number of `unit`s and the duration left over, so
+/// a schedule can page through "full weeks + leftover days" in one call instead of
+/// a round and a subtract. Writes the whole count to `quotient` (which may be NULL)
+/// and returns the remainder.
+///
+/// The count truncates toward zero, so the remainder has the sign of the duration.
+/// The remainder is balanced from the next smaller unit down, skipping weeks: P40D
+/// by "month" relative to 2024-01-01 is 1 and "P9D". `relative_to` is a PlainDate or
+/// PlainDateTime and is required when the duration or `unit` has years, months or
+/// weeks; without it a day is 24 hours.
+#[no_mangle]
+pub extern "C" fn temporal_duration_divmod(
+    duration: *const c_char,
+    unit: *const c_char,
+    relative_to: *const c_char,
+    quotient: *mut i64,
+) -> TemporalResult {
+    let duration = match parse_c_str(duration, "duration") {
+        Ok(s) => match duration_from_str(s) {
+            Ok(d) => d,
+            Err(e) => return TemporalResult::range_error(&format!("Invalid duration '{}': {}", s, e)),
+        },
         Err(e) => return e,
     };
-    let duration_b = match parse_duration(b, "second duration") {
-        Ok(d) => d,
+    let unit = match parse_c_str(unit, "unit") {
+        Ok(s) => match Unit::from_str(s) {
+            Ok(Unit::Auto) | Err(_) => return TemporalResult::range_error(&format!("Invalid unit: {}", s)),
+            Ok(u) => u,
+        },
+        Err(e) => return e,
+    };
+    let remainder_unit = match unit {
+        Unit::Year => Unit::Month,
+        Unit::Month | Unit::Week => Unit::Day,
+        Unit::Day => Unit::Hour,
+        Unit::Hour => Unit::Minute,
+        Unit::Minute => Unit::Second,
+        Unit::Second => Unit::Millisecond,
+        Unit::Millisecond => Unit::Microsecond,
+        _ => Unit::Nanosecond,
+    };
+
+    let result = if relative_to.is_null() {
+        duration_divmod_exact(&duration, unit)
+    } else {
+        let start = match parse_plain_date_time(relative_to, "relativeTo") {
+            Ok(dt) => dt,
+            Err(e) => return e,
+        };
+        duration_divmod_relative(&start, &duration, unit, remainder_unit)
+    };
+    match result {
+        Ok((whole, remainder)) => {
+            if !quotient.is_null() {
+                unsafe { *quotient = whole };
+            }
+            TemporalResult::success(remainder.to_string())
+        }
+        Err(e) => TemporalResult::range_error(&format!("Failed to divide duration: {}", e)),
+    }
+}
+
+/// Divides in exact time, where a day is 24 hours. The remainder is shorter than
+/// `unit`, so balancing it up to hours leaves the larger fields zero.
+fn duration_divmod_exact(duration: &Duration, unit: Unit) -> Result<(i64, Duration), String> {
+    if matches!(unit, Unit::Year | Unit::Month | Unit::Week) || largest_duration_unit(duration) > Unit::Day {
+        return Err("relativeTo is required for years, months, or weeks".to_string());
+    }
+    let total = duration.days() as i128 * 86_400_000_000_000
+        + duration.hours() as i128 * 3_600_000_000_000
+        + duration.minutes() as i128 * 60_000_000_000
+        + duration.seconds() as i128 * 1_000_000_000
+        + duration.milliseconds() as i128 * 1_000_000
+        + duration.microseconds() * 1_000
+        + duration.nanoseconds();
+    let unit_ns: i128 = match unit {
+        Unit::Day => 86_400_000_000_000,
+        Unit::Hour => 3_600_000_000_000,
+        Unit::Minute => 60_000_000_000,
+        Unit::Second => 1_000_000_000,
+        Unit::Millisecond => 1_000_000,
+        Unit::Microsecond => 1_000,
+        _ => 1,
+    };
+    let whole = i64::try_from(total / unit_ns).map_err(|_| "quotient out of range".to_string())?;
+    let rest = total % unit_ns;
+    let remainder = Duration::new(
+        0,
+        0,
+        0,
+        0,
+        (rest / 3_600_000_000_000) as i64,
+        (rest / 60_000_000_000 % 60) as i64,
+        (rest / 1_000_000_000 % 60) as i64,
+        (rest / 1_000_000 % 1_000) as i64,
+        rest / 1_000 % 1_000,
+        rest % 1_000,
+    )
+    .map_err(|e| e.to_string())?;
+    Ok((whole, remainder))
+}
+
+/// Divides on the calendar from `start`: the count is the difference to `start +
+/// duration` truncated to `unit`, and the remainder what is left after adding it.
+fn duration_divmod_relative(
+    start: &PlainDateTime,
+    duration: &Duration,
+    unit: Unit,
+    remainder_unit: Unit,
+) -> Result<(i64, Duration), String> {
+    let end = start.add(duration, Some(Overflow::Constrain)).map_err(|e| e.to_string())?;
+    let mut options = temporal_rs::options::DifferenceSettings::default();
+    options.largest_unit = Some(unit);
+    options.smallest_unit = Some(unit);
+    options.rounding_mode = Some(RoundingMode::Trunc);
+    let whole = start.until(&end, options).map_err(|e| e.to_string())?;
+    let middle = start.add(&whole, Some(Overflow::Constrain)).map_err(|e| e.to_string())?;
+    let mut options = temporal_rs::options::DifferenceSettings::default();
+    options.largest_unit = Some(remainder_unit);
+    let remainder = middle.until(&end, options).map_err(|e| e.to_string())?;
+    let count = match unit {
+        Unit::Year => whole.years(),
+        Unit::Month => whole.months(),
+        Unit::Week => whole.weeks(),
+        Unit::Day => whole.days(),
+        Unit::Hour => whole.hours(),
+        Unit::Minute => whole.minutes(),
+        Unit::Second => whole.seconds(),
+        Unit::Millisecond => whole.milliseconds(),
+        Unit::Microsecond => i64::try_from(whole.microseconds()).map_err(|_| "quotient out of range".to_string())?,
+        _ => i64::try_from(whole.nanoseconds()).map_err(|_| "quotient out of range".to_string())?,
+    };
+    Ok((count, remainder))
+}
+
+/// Returns the unit of the largest non-zero field (nanoseconds for a zero duration).
+fn largest_duration_unit(d: &Duration) -> Unit {
+    if d.years() != 0 {
+        Unit::Year
+    } else if d.months() != 0 {
+        Unit::Month
+    } else if d.weeks() != 0 {
+        Unit::Week
+    } else if d.days() != 0 {
+        Unit::Day
+    } else if d.hours() != 0 {
+        Unit::Hour
+    } else if d.minutes() != 0 {
+        Unit::Minute
+    } else if d.seconds() != 0 {
+        Unit::Second
+    } else if d.milliseconds() != 0 {
+        Unit::Millisecond
+    } else if d.microseconds() != 0 {
+        Unit::Microsecond
+    } else {
+        Unit::Nanosecond
+    }
+}
+
+// Helper functions
+
+fn clamp_i128(value: i128) -> i64 {
+    value.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
+
+/// Splits an i128 into its high (signed) and low (unsigned) 64-bit halves.
+fn split_i128(value: i128) -> (i64, u64) {
+    ((value >> 64) as i64, value as u64)
+}
+
+/// Inverse of `split_i128`.
+fn join_i128(high: i64, low: u64) -> i128 {
+    ((high as i128) << 64) | low as i128
+}
+
+/// Returns true if the duration's microseconds or nanoseconds exceed i64.
+fn duration_is_clamped(duration: &Duration) -> bool {
+    i64::try_from(duration.microseconds()).is_err() || i64::try_from(duration.nanoseconds()).is_err()
+}
+
+/// Longest string argument, in bytes, accepted by default. Temporal strings are
+/// well under 100 bytes, so this only stops pathological payloads.
+pub const TEMPORAL_DEFAULT_MAX_INPUT_LENGTH: usize = 4096;
+
+/// Longest bulk argument (newline-delimited list, batch input or JSON document), in
+/// bytes, accepted by default.
+pub const TEMPORAL_DEFAULT_MAX_BULK_INPUT_LENGTH: usize = 1 << 20;
+
+static MAX_INPUT_LENGTH: AtomicUsize = AtomicUsize::new(TEMPORAL_DEFAULT_MAX_INPUT_LENGTH);
+static MAX_BULK_INPUT_LENGTH: AtomicUsize = AtomicUsize::new(TEMPORAL_DEFAULT_MAX_BULK_INPUT_LENGTH);
+
+/// Sets the longest string argument, in bytes, that functions accept before failing
+/// with `TemporalErrorType::InputTooLong`; 0 restores the default. Bulk arguments
+/// (newline-delimited lists, batch input and JSON documents) have their own limit,
+/// see `temporal_set_max_bulk_input_length`, though `temporal_batch_parse` applies
+/// this one to each item. Returns the previous limit.
+#[no_mangle]
+pub extern "C" fn temporal_set_max_input_length(max_bytes: usize) -> usize {
+    let max_bytes = if max_bytes == 0 { TEMPORAL_DEFAULT_MAX_INPUT_LENGTH } else { max_bytes };
+    MAX_INPUT_LENGTH.swap(max_bytes, AtomicOrdering::Relaxed)
+}
+
+/// Sets the longest bulk argument, in bytes, that functions accept before failing
+/// with `TemporalErrorType::InputTooLong`; 0 restores the default. Returns the
+/// previous limit.
+#[no_mangle]
+pub extern "C" fn temporal_set_max_bulk_input_length(max_bytes: usize) -> usize {
+    let max_bytes = if max_bytes == 0 { TEMPORAL_DEFAULT_MAX_BULK_INPUT_LENGTH } else { max_bytes };
+    MAX_BULK_INPUT_LENGTH.swap(max_bytes, AtomicOrdering::Relaxed)
+}
+
+fn max_input_length() -> usize {
+    MAX_INPUT_LENGTH.load(AtomicOrdering::Relaxed)
+}
+
+fn max_bulk_input_length() -> usize {
+    MAX_BULK_INPUT_LENGTH.load(AtomicOrdering::Relaxed)
+}
+
+fn input_too_long_message(param_name: &str, len: usize, limit: usize) -> String {
+    format!("{} is too long ({} bytes, the limit is {})", param_name, len, limit)
+}
+
+enum CStrError {
+    Null,
+    InvalidUtf8,
+    /// The length and the limit it exceeds.
+    TooLong(usize, usize),
+}
+
+/// Reads a C string argument, enforcing the max input length, or the max bulk input
+/// length if `bulk`.
+fn read_c_str<'a>(s: *const c_char, bulk: bool) -> Result<&'a str, CStrError> {
+    if s.is_null() {
+        return Err(CStrError::Null);
+    }
+    let bytes = unsafe { std::ffi::CStr::from_ptr(s) }.to_bytes();
+    let limit = if bulk { max_bulk_input_length() } else { max_input_length() };
+    if bytes.len() > limit {
+        return Err(CStrError::TooLong(bytes.len(), limit));
+    }
+    std::str::from_utf8(bytes).map_err(|_| CStrError::InvalidUtf8)
+}
+
+fn parse_c_str(s: *const c_char, param_name: &str) -> Result<&str, TemporalResult> {
+    c_str_result(read_c_str(s, false), param_name)
+}
+
+/// Like `parse_c_str` for bulk arguments, which have the larger max bulk input length.
+fn parse_bulk_c_str(s: *const c_char, param_name: &str) -> Result<&str, TemporalResult> {
+    c_str_result(read_c_str(s, true), param_name)
+}
+
+fn c_str_result<'a>(read: Result<&'a str, CStrError>, param_name: &str) -> Result<&'a str, TemporalResult> {
+    read.map_err(|e| match e {
+        CStrError::Null => TemporalResult::type_error(&format!("{} cannot be null", param_name)),
+        CStrError::InvalidUtf8 => TemporalResult::type_error(&format!("Invalid UTF-8 in {}", param_name)),
+        CStrError::TooLong(len, limit) => TemporalResult::input_too_long(param_name, len, limit),
+    })
+}
+
+/// Splits a newline-delimited list argument, skipping blank lines. Newlines are used
+/// rather than commas because ISO 8601 allows a comma as the decimal separator.
+fn split_list(s: &str) -> impl Iterator<Item = &str> {
+    s.split('\n').map(str::trim).filter(|item| !item.is_empty())
+}
+
+/// Parses a newline-delimited list and returns the extreme item, i.e. the first one
+/// that no other item compares `wanted` against. Empty lists are a RangeError.
+fn list_extreme<T, P, C, F>(
+    list: *const c_char,
+    kind: &str,
+    parse: P,
+    compare: C,
+    wanted: std::cmp::Ordering,
+    format: F,
+) -> TemporalResult
+where
+    P: Fn(&str) -> Result<T, TemporalError>,
+    C: Fn(&T, &T) -> std::cmp::Ordering,
+    F: FnOnce(&T) -> Result<String, TemporalError>,
+{
+    let list_str = match parse_bulk_c_str(list, "list") {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+
+    let mut extreme: Option<T> = None;
+    for item in split_list(list_str) {
+        let value = match parse(item) {
+            Ok(v) => v,
+            Err(e) => return TemporalResult::range_error(&format!("Invalid {} '{}': {}", kind, item, e)),
+        };
+        extreme = match extreme {
+            Some(current) if compare(&value, &current) != wanted => Some(current),
+            _ => Some(value),
+        };
+    }
+
+    match extreme {
+        Some(value) => match format(&value) {
+            Ok(s) => TemporalResult::success(s),
+            Err(e) => TemporalResult::range_error(&format!("Failed to format {}: {}", kind, e)),
+        },
+        None => TemporalResult::range_error(&format!("{} list cannot be empty", kind)),
+    }
+}
+
+/// Clamps `value` into [min, max]; a range with min after max is a RangeError.
+fn clamp_by<T, C>(value: T, min: T, max: T, compare: C) -> Result<T, TemporalResult>
+where
+    C: Fn(&T, &T) -> std::cmp::Ordering,
+{
+    if compare(&min, &max) == std::cmp::Ordering::Greater {
+        return Err(TemporalResult::range_error("min must not be after max"));
+    }
+    if compare(&value, &min) == std::cmp::Ordering::Less {
+        Ok(min)
+    } else if compare(&value, &max) == std::cmp::Ordering::Greater {
+        Ok(max)
+    } else {
+        Ok(value)
+    }
+}
+
+/// `Duration::from_str` with a clear error for a fraction on any but the last unit
+/// ("PT0.5H30M"). ISO 8601 and Temporal only allow a fraction on the smallest unit
+/// written, and the parser would otherwise report an unexpected character.
+fn duration_from_str(s: &str) -> Result<Duration, TemporalError> {
+    Duration::from_str(s).map_err(|e| {
+        if fraction_before_last_unit(s) {
+            TemporalError::range().with_message("only the last time unit of a duration may have a fraction")
+        } else {
+            e
+        }
+    })
+}
+
+/// True if the time part has a fraction followed by another unit, as in "PT0.5H30M".
+fn fraction_before_last_unit(s: &str) -> bool {
+    let Some(time) = s.find(['T', 't']).map(|t| &s[t + 1..]) else {
+        return false;
+    };
+    let Some(fraction) = time.find(['.', ',']).map(|dot| &time[dot + 1..]) else {
+        return false;
+    };
+    let digits = fraction.find(|c: char| !c.is_ascii_digit()).unwrap_or(fraction.len());
+    digits > 0 && fraction.len() > digits + 1
+}
+
+fn parse_duration(s: *const c_char, param_name: &str) -> Result<Duration, TemporalResult> {
+    let str_val = parse_c_str(s, param_name)?;
+    duration_from_str(str_val)
+        .map_err(|e| TemporalResult::range_error(&format!("Invalid duration '{}': {}", str_val, e)))
+}
+
+fn parse_instant(s: *const c_char, param_name: &str) -> Result<Instant, TemporalResult> {
+    let str_val = parse_c_str(s, param_name)?;
+    Instant::from_str(str_val)
+        .map_err(|e| TemporalResult::range_error(&format!("Invalid instant '{}': {}", str_val, e)))
+}
+
+fn parse_plain_time(s: *const c_char, param_name: &str) -> Result<PlainTime, TemporalResult> {
+    let str_val = parse_c_str(s, param_name)?;
+    PlainTime::from_str(str_val)
+        .map_err(|e| TemporalResult::range_error(&format!("Invalid plain time '{}': {}", str_val, e)))
+}
+
+fn duration_binary_op<F>(
+    a: *const c_char,
+    b: *const c_char,
+    op_name: &str,
+    op: F,
+) -> TemporalResult
+where
+    F: FnOnce(Duration, Duration) -> Result<Duration, temporal_rs::TemporalError>,
+{
+    let duration_a = match parse_duration(a, "first duration") {
+        Ok(d) => d,
+        Err(e) => return e,
+    };
+    let duration_b = match parse_duration(b, "second duration") {
+        Ok(d) => d,
         Err(e) => return e,
     };
 
@@ -2597,106 +5034,143 @@ where
 }
 
 // ============================================================================
-// Android JNI bindings
-// ============================================================================
-
-
-// ============================================================================
-// TimeZone API
+// Formatting API
 // ============================================================================
 
-/// Gets a TimeZone from a string identifier.
+/// Formats a duration for display with English labels, like `Intl.DurationFormat`
+/// in an English locale. There is no locale-aware variant: the locale data shipped
+/// with the library has no duration or unit patterns.
+///
+/// `style` is "long" ("1 hour, 30 minutes"), "short" ("1 hr, 30 min"), "narrow"
+/// ("1h 30m") or "digital" ("1:30:05"); NULL means "short".
 #[no_mangle]
-pub extern "C" fn temporal_time_zone_from_string(s: *const c_char) -> TemporalResult {
-    let s_str = match parse_c_str(s, "timezone string") {
-        Ok(s) => s,
+pub extern "C" fn temporal_format_duration_en(duration: *const c_char, style: *const c_char) -> TemporalResult {
+    let duration = match parse_duration(duration, "duration") {
+        Ok(d) => d,
         Err(e) => return e,
     };
-    match TimeZone::try_from_str(s_str) {
-        Ok(tz) => match tz.identifier() {
-            Ok(id) => TemporalResult::success(id),
-            Err(e) => TemporalResult::range_error(&format!("Failed to get timezone id: {}", e)),
-        },
-        Err(e) => TemporalResult::range_error(&format!("Invalid timezone '{}': {}", s_str, e)),
-    }
-}
+    let style = if !style.is_null() {
+        match parse_c_str(style, "style") {
+            Ok(s) => s,
+            Err(e) => return e,
+        }
+    } else {
+        "short"
+    };
 
-/// Gets the identifier of a TimeZone.
-#[no_mangle]
-pub extern "C" fn temporal_time_zone_get_id(s: *const c_char) -> TemporalResult {
-    let s_str = match parse_c_str(s, "timezone string") {
-        Ok(s) => s,
-        Err(e) => return e,
+    let formatted = match style {
+        "digital" => format_duration_digital(&duration),
+        "long" => format_duration_units(&duration, 0, ", "),
+        "short" => format_duration_units(&duration, 1, ", "),
+        "narrow" => format_duration_units(&duration, 2, " "),
+        _ => return TemporalResult::range_error(&format!("Invalid duration format style: {}", style)),
     };
-    match TimeZone::try_from_str(s_str) {
-        Ok(tz) => match tz.identifier() {
-            Ok(id) => TemporalResult::success(id),
-            Err(e) => TemporalResult::range_error(&format!("Failed to get timezone id: {}", e)),
-        },
-        Err(e) => TemporalResult::range_error(&format!("Invalid timezone '{}': {}", s_str, e)),
-    }
+    TemporalResult::success(formatted)
 }
 
-/// Gets the offset nanoseconds for an instant in a timezone.
-#[no_mangle]
-pub extern "C" fn temporal_time_zone_get_offset_nanoseconds_for(
-    tz_id: *const c_char,
-    instant_str: *const c_char,
-) -> TemporalResult {
-    let tz = match parse_time_zone(tz_id, "timezone") {
-        Ok(t) => t,
-        Err(e) => return e,
-    };
-    let instant = match parse_instant(instant_str, "instant") {
-        Ok(i) => i,
-        Err(e) => return e,
-    };
+/// (singular long, plural long, singular short, plural short, narrow) labels per unit.
+const DURATION_UNIT_LABELS: [(&str, &str, &str, &str, &str); 10] = [
+    ("year", "years", "yr", "yrs", "y"),
+    ("month", "months", "mth", "mths", "mo"),
+    ("week", "weeks", "wk", "wks", "w"),
+    ("day", "days", "day", "days", "d"),
+    ("hour", "hours", "hr", "hr", "h"),
+    ("minute", "minutes", "min", "min", "m"),
+    ("second", "seconds", "sec", "sec", "s"),
+    ("millisecond", "milliseconds", "ms", "ms", "ms"),
+    ("microsecond", "microseconds", "\u{3bc}s", "\u{3bc}s", "\u{3bc}s"),
+    ("nanosecond", "nanoseconds", "ns", "ns", "ns"),
+];
+
+/// Absolute field values in DURATION_UNIT_LABELS order.
+fn duration_field_values(d: &Duration) -> [u128; 10] {
+    [
+        d.years().unsigned_abs() as u128,
+        d.months().unsigned_abs() as u128,
+        d.weeks().unsigned_abs() as u128,
+        d.days().unsigned_abs() as u128,
+        d.hours().unsigned_abs() as u128,
+        d.minutes().unsigned_abs() as u128,
+        d.seconds().unsigned_abs() as u128,
+        d.milliseconds().unsigned_abs() as u128,
+        d.microseconds().unsigned_abs(),
+        d.nanoseconds().unsigned_abs(),
+    ]
+}
 
-    let provider = &*COMPILED_TZ_PROVIDER;
-    match ZonedDateTime::try_new(instant.epoch_nanoseconds().0, tz, Calendar::default()) {
-        Ok(zdt) => TemporalResult::success(zdt.offset_nanoseconds().to_string()),
-        Err(e) => TemporalResult::range_error(&format!("Failed to get offset: {}", e)),
+/// Formats non-zero fields with labels; `width` is 0 = long, 1 = short, 2 = narrow.
+fn format_duration_units(d: &Duration, width: u8, separator: &str) -> String {
+    let values = duration_field_values(d);
+    let mut parts = Vec::new();
+    for (value, labels) in values.iter().zip(DURATION_UNIT_LABELS.iter()) {
+        if *value == 0 {
+            continue;
+        }
+        let part = match width {
+            0 => format!("{} {}", value, if *value == 1 { labels.0 } else { labels.1 }),
+            1 => format!("{} {}", value, if *value == 1 { labels.2 } else { labels.3 }),
+            _ => format!("{}{}", value, labels.4),
+        };
+        parts.push(part);
     }
+    if parts.is_empty() {
+        parts.push(match width {
+            0 => "0 seconds".to_string(),
+            1 => "0 sec".to_string(),
+            _ => "0s".to_string(),
+        });
+    }
+    let sign = if d.sign() == temporal_rs::Sign::Negative { "-" } else { "" };
+    format!("{}{}", sign, parts.join(separator))
 }
 
-/// Gets the offset string for an instant in a timezone.
-#[no_mangle]
-pub extern "C" fn temporal_time_zone_get_offset_string_for(
-    tz_id: *const c_char,
-    instant_str: *const c_char,
-) -> TemporalResult {
-    let tz = match parse_time_zone(tz_id, "timezone") {
-        Ok(t) => t,
-        Err(e) => return e,
-    };
-    let instant = match parse_instant(instant_str, "instant") {
-        Ok(i) => i,
-        Err(e) => return e,
-    };
-
-    let provider = &*COMPILED_TZ_PROVIDER;
-    match ZonedDateTime::try_new(instant.epoch_nanoseconds().0, tz, Calendar::default()) {
-        Ok(zdt) => TemporalResult::success(zdt.offset().to_string()),
-        Err(e) => TemporalResult::range_error(&format!("Failed to get offset string: {}", e)),
+/// Formats hours, minutes and seconds as "h:mm:ss[.fff]", with any date fields
+/// prepended in narrow style (e.g. "2d 1:30:05").
+fn format_duration_digital(d: &Duration) -> String {
+    let values = duration_field_values(d);
+    let mut out = String::new();
+    if d.sign() == temporal_rs::Sign::Negative {
+        out.push('-');
     }
+    for (value, labels) in values[..4].iter().zip(DURATION_UNIT_LABELS.iter()) {
+        if *value != 0 {
+            out.push_str(&format!("{}{} ", value, labels.4));
+        }
+    }
+    // Fields are shown as given (PT90M is "0:90:00"), except that sub-second
+    // fields add up into the seconds and their fraction.
+    let subsecond_ns = values[7] * 1_000_000 + values[8] * 1_000 + values[9];
+    let seconds = values[6] + subsecond_ns / 1_000_000_000;
+    let fraction_ns = subsecond_ns % 1_000_000_000;
+    out.push_str(&format!("{}:{:02}:{:02}", values[4], values[5], seconds));
+    if fraction_ns > 0 {
+        let fraction = format!("{:09}", fraction_ns);
+        out.push('.');
+        out.push_str(fraction.trim_end_matches('0'));
+    }
+    out
 }
 
-/// Gets the PlainDateTime for an instant in a timezone.
+/// Parses user input such as "31/12/2024" into a PlainDate using a fixed pattern.
+///
+/// Supported tokens: `yyyy` (4-digit year), `yy` (2-digit year, 2000-2099), `y`
+/// (1-6 digit year), `MM`/`dd` (2 digits), `M`/`d` (1-2 digits). Other ASCII letters
+/// are rejected; any other character and text in single quotes must match literally.
+/// Fields are interpreted in `calendar_id` (NULL = ISO 8601) and must be valid.
 #[no_mangle]
-pub extern "C" fn temporal_time_zone_get_plain_date_time_for(
-    tz_id: *const c_char,
-    instant_str: *const c_char,
+pub extern "C" fn temporal_parse_with_pattern(
+    input: *const c_char,
+    pattern: *const c_char,
     calendar_id: *const c_char,
 ) -> TemporalResult {
-    let tz = match parse_time_zone(tz_id, "timezone") {
-        Ok(t) => t,
+    let input = match parse_c_str(input, "input") {
+        Ok(s) => s,
         Err(e) => return e,
     };
-    let instant = match parse_instant(instant_str, "instant") {
-        Ok(i) => i,
+    let pattern = match parse_c_str(pattern, "pattern") {
+        Ok(s) => s,
         Err(e) => return e,
     };
-    
     let calendar = if !calendar_id.is_null() {
         match parse_c_str(calendar_id, "calendar id") {
             Ok(s) => match Calendar::from_str(s) {
@@ -2708,2020 +5182,7436 @@ pub extern "C" fn temporal_time_zone_get_plain_date_time_for(
     } else {
         Calendar::default()
     };
+    let tokens = match tokenize_pattern(pattern) {
+        Ok(t) => t,
+        Err(message) => return TemporalResult::range_error(&message),
+    };
 
-    match ZonedDateTime::try_new(instant.epoch_nanoseconds().0, tz, calendar) {
-        Ok(zdt) => match zdt.to_plain_date_time().to_ixdtf_string(ToStringRoundingOptions::default(), DisplayCalendar::Auto) {
-            Ok(s) => TemporalResult::success(s),
-            Err(e) => TemporalResult::range_error(&format!("Failed to format plain date time: {}", e)),
-        },
-        Err(e) => TemporalResult::range_error(&format!("Failed to get plain date time: {}", e)),
+    let mut rest = input;
+    let (mut year, mut month, mut day) = (None, None, None);
+    for token in &tokens {
+        match token {
+            PatternToken::Literal(text) => match rest.strip_prefix(text.as_str()) {
+                Some(r) => rest = r,
+                None => return TemporalResult::range_error(&format!("Input '{}' does not match pattern '{}'", input, pattern)),
+            },
+            PatternToken::Field(letter, count) => {
+                let (min_digits, max_digits) = match (letter, count) {
+                    ('y', 2) | ('M', 2) | ('d', 2) => (2, 2),
+                    ('y', 4) => (4, 4),
+                    ('y', _) => (1, 6),
+                    ('M', 1) | ('d', 1) => (1, 2),
+                    _ => return TemporalResult::range_error(&format!("Unsupported pattern token: {}", letter.to_string().repeat(*count))),
+                };
+                let digits = rest.bytes().take(max_digits).take_while(u8::is_ascii_digit).count();
+                if digits < min_digits {
+                    return TemporalResult::range_error(&format!("Input '{}' does not match pattern '{}'", input, pattern));
+                }
+                let value: i32 = rest[..digits].parse().unwrap_or(0);
+                rest = &rest[digits..];
+                match letter {
+                    'y' if *count == 2 => year = Some(2000 + value),
+                    'y' => year = Some(value),
+                    'M' => month = Some(value),
+                    _ => day = Some(value),
+                }
+            }
+        }
+    }
+    if !rest.is_empty() {
+        return TemporalResult::range_error(&format!("Unexpected trailing input '{}'", rest));
+    }
+
+    let (year, month, day) = match (year, month, day) {
+        (Some(y), Some(m), Some(d)) => (y, m, d),
+        _ => return TemporalResult::range_error("Pattern must contain year, month, and day fields"),
+    };
+    let partial = PartialDate::new()
+        .with_calendar(calendar)
+        .with_year(Some(year))
+        .with_month(Some(month as u8))
+        .with_day(Some(day as u8));
+    match PlainDate::from_partial(partial, Some(Overflow::Reject)) {
+        Ok(date) => TemporalResult::success(date.to_ixdtf_string(display::calendar())),
+        Err(e) => TemporalResult::range_error(&format!("Invalid date '{}': {}", input, e)),
     }
 }
 
-/// Gets the Instant for a PlainDateTime in a timezone.
+/// Formats a value with a fixed, locale-independent pattern (for logs, filenames...).
+///
+/// `value_kind` is "PlainDate", "PlainTime", "PlainDateTime", "ZonedDateTime" or
+/// "Instant" (formatted in UTC). Supported tokens: `yyyy`, `yy`, `MM`, `M`, `dd`, `d`,
+/// `HH`, `H`, `mm`, `ss`, `SSS` (milliseconds) and `ZZZZ` ("GMT+01:00"). Text in
+/// single quotes and non-letter characters are copied as-is; other letters and
+/// tokens the value doesn't have (e.g. `HH` for a PlainDate) are a RangeError.
 #[no_mangle]
-pub extern "C" fn temporal_time_zone_get_instant_for(
-    tz_id: *const c_char,
-    dt_str: *const c_char,
-    disambiguation: *const c_char,
+pub extern "C" fn temporal_format_pattern(
+    value_kind: *const c_char,
+    value: *const c_char,
+    pattern: *const c_char,
 ) -> TemporalResult {
-    let tz = match parse_time_zone(tz_id, "timezone") {
-        Ok(t) => t,
+    let kind = match parse_c_str(value_kind, "value kind") {
+        Ok(s) => s,
         Err(e) => return e,
     };
-    let dt = match parse_plain_date_time(dt_str, "plain date time") {
-        Ok(d) => d,
+    let pattern = match parse_c_str(pattern, "pattern") {
+        Ok(s) => s,
         Err(e) => return e,
     };
-
-    let disambig_enum = if !disambiguation.is_null() {
-        match parse_c_str(disambiguation, "disambiguation") {
-            Ok(s) => match s {
-                "compatible" => Disambiguation::Compatible,
-                "earlier" => Disambiguation::Earlier,
-                "later" => Disambiguation::Later,
-                "reject" => Disambiguation::Reject,
-                _ => Disambiguation::Compatible,
-            },
-            Err(e) => return e,
-        }
-    } else {
-        Disambiguation::Compatible
+    let fields = match kind {
+        "PlainDate" => parse_plain_date(value, "value").map(|d| PatternFields {
+            date: Some((d.year(), d.month(), d.day())),
+            ..Default::default()
+        }),
+        "PlainTime" => parse_plain_time(value, "value").map(|t| PatternFields {
+            time: Some((t.hour(), t.minute(), t.second(), t.millisecond())),
+            ..Default::default()
+        }),
+        "PlainDateTime" => parse_plain_date_time(value, "value").map(|dt| PatternFields {
+            date: Some((dt.year(), dt.month(), dt.day())),
+            time: Some((dt.hour(), dt.minute(), dt.second(), dt.millisecond())),
+            ..Default::default()
+        }),
+        "ZonedDateTime" => parse_zoned_date_time(value, "value").map(|zdt| PatternFields {
+            date: Some((zdt.year(), zdt.month(), zdt.day())),
+            time: Some((zdt.hour(), zdt.minute(), zdt.second(), zdt.millisecond())),
+            offset_ns: Some(zdt.offset_nanoseconds()),
+        }),
+        "Instant" => parse_instant(value, "value").and_then(|instant| {
+            instant
+                .to_zoned_date_time_iso(TimeZone::utc())
+                .map(|zdt| PatternFields {
+                    date: Some((zdt.year(), zdt.month(), zdt.day())),
+                    time: Some((zdt.hour(), zdt.minute(), zdt.second(), zdt.millisecond())),
+                    offset_ns: Some(0),
+                })
+                .map_err(|e| TemporalResult::range_error(&format!("Failed to convert instant: {}", e)))
+        }),
+        _ => return TemporalResult::range_error(&format!("Invalid value kind: {}", kind)),
     };
-
-    match dt.to_zoned_date_time(tz, disambig_enum) {
-        Ok(zdt) => {
-             let instant = zdt.to_instant();
-             let provider = &*COMPILED_TZ_PROVIDER;
-             match instant.to_ixdtf_string_with_provider(None, Default::default(), &provider) {
-                Ok(s) => TemporalResult::success(s),
-                Err(e) => TemporalResult::range_error(&format!("Failed to format instant: {}", e)),
-             }
-        },
-        Err(e) => TemporalResult::range_error(&format!("Failed to get instant: {}", e)),
-    }
-}
-
-/// Gets the next transition instant.
-#[no_mangle]
-pub extern "C" fn temporal_time_zone_get_next_transition(
-    tz_id: *const c_char,
-    instant_str: *const c_char,
-) -> TemporalResult {
-    let tz = match parse_time_zone(tz_id, "timezone") {
-        Ok(t) => t,
+    let fields = match fields {
+        Ok(f) => f,
         Err(e) => return e,
     };
-    let instant = match parse_instant(instant_str, "instant") {
-        Ok(i) => i,
-        Err(e) => return e,
+    let tokens = match tokenize_pattern(pattern) {
+        Ok(t) => t,
+        Err(message) => return TemporalResult::range_error(&message),
     };
 
-    // TODO: Implement using provider directly when API is clear
-    match Ok::<Option<Instant>, TemporalError>(None) { // Stub
-        Ok(Some(i)) => {
-            let provider = &*COMPILED_TZ_PROVIDER;
-            match i.to_ixdtf_string_with_provider(None, Default::default(), &provider) {
-                Ok(s) => TemporalResult::success(s),
-                Err(e) => TemporalResult::range_error(&format!("Failed to format instant: {}", e)),
+    let mut out = String::new();
+    for token in &tokens {
+        let (letter, count) = match token {
+            PatternToken::Literal(text) => {
+                out.push_str(text);
+                continue;
             }
-        },
-        Ok(None) => TemporalResult::success(String::new()),
-        Err(e) => TemporalResult::range_error(&format!("Failed to get next transition: {}", e)),
+            PatternToken::Field(letter, count) => (*letter, *count),
+        };
+        let formatted = match (letter, count, fields.date, fields.time, fields.offset_ns) {
+            ('y', 4, Some((y, _, _)), _, _) if y < 0 => format!("-{:04}", -(y as i64)),
+            ('y', 4, Some((y, _, _)), _, _) => format!("{:04}", y),
+            ('y', 2, Some((y, _, _)), _, _) => format!("{:02}", y.rem_euclid(100)),
+            ('M', 1 | 2, Some((_, m, _)), _, _) => format!("{:0width$}", m, width = count),
+            ('d', 1 | 2, Some((_, _, d)), _, _) => format!("{:0width$}", d, width = count),
+            ('H', 1 | 2, _, Some((h, _, _, _)), _) => format!("{:0width$}", h, width = count),
+            ('m', 2, _, Some((_, m, _, _)), _) => format!("{:02}", m),
+            ('s', 2, _, Some((_, _, s, _)), _) => format!("{:02}", s),
+            ('S', 3, _, Some((_, _, _, ms)), _) => format!("{:03}", ms),
+            ('Z', 4, _, _, Some(offset_ns)) => format_gmt_offset(offset_ns),
+            _ => {
+                return TemporalResult::range_error(&format!(
+                    "Unsupported pattern token '{}' for {}",
+                    letter.to_string().repeat(count),
+                    kind
+                ))
+            }
+        };
+        out.push_str(&formatted);
     }
+    TemporalResult::success(out)
 }
 
-/// Gets the previous transition instant.
-#[no_mangle]
-pub extern "C" fn temporal_time_zone_get_previous_transition(
-    tz_id: *const c_char,
-    instant_str: *const c_char,
-) -> TemporalResult {
-    let tz = match parse_time_zone(tz_id, "timezone") {
-        Ok(t) => t,
-        Err(e) => return e,
-    };
-    let instant = match parse_instant(instant_str, "instant") {
-        Ok(i) => i,
-        Err(e) => return e,
-    };
+/// Field values available to `temporal_format_pattern` for a given value kind.
+#[derive(Default)]
+struct PatternFields {
+    date: Option<(i32, u8, u8)>,
+    time: Option<(u8, u8, u8, u16)>,
+    offset_ns: Option<i64>,
+}
 
-    // TODO: Implement using provider directly
-    match Ok::<Option<Instant>, TemporalError>(None) {
-        Ok(Some(i)) => {
-            let provider = &*COMPILED_TZ_PROVIDER;
-            match i.to_ixdtf_string_with_provider(None, Default::default(), &provider) {
-                Ok(s) => TemporalResult::success(s),
-                Err(e) => TemporalResult::range_error(&format!("Failed to format instant: {}", e)),
-            }
-        },
-        Ok(None) => TemporalResult::success(String::new()),
-        Err(e) => TemporalResult::range_error(&format!("Failed to get previous transition: {}", e)),
+/// Formats an offset as a localized GMT format: "GMT", "GMT+01:00", "GMT-05:30", and
+/// with seconds for historical offsets that have them ("GMT+00:09:21").
+fn format_gmt_offset(offset_ns: i64) -> String {
+    if offset_ns == 0 {
+        return "GMT".to_string();
+    }
+    let sign = if offset_ns < 0 { '-' } else { '+' };
+    let total_seconds = offset_ns.unsigned_abs() / 1_000_000_000;
+    let (hours, minutes, seconds) = (total_seconds / 3600, total_seconds / 60 % 60, total_seconds % 60);
+    match seconds {
+        0 => format!("GMT{}{:02}:{:02}", sign, hours, minutes),
+        _ => format!("GMT{}{:02}:{:02}:{:02}", sign, hours, minutes, seconds),
     }
 }
 
-// ============================================================================
-// ZonedDateTime API
-// ============================================================================
-
-/// Represents a ZonedDateTime's component values for FFI.
-#[repr(C)]
-pub struct ZonedDateTimeComponents {
-    pub year: i32,
-    pub month: u8,
-    pub day: u8,
-    pub day_of_week: u16,
-    pub day_of_year: u16,
-    pub week_of_year: u16,
-    pub year_of_week: i32,
-    pub days_in_week: u16,
-    pub days_in_month: u16,
-    pub days_in_year: u16,
-    pub months_in_year: u16,
-    pub in_leap_year: i8,
-    pub hour: u8,
-    pub minute: u8,
-    pub second: u8,
-    pub millisecond: u16,
-    pub microsecond: u16,
-    pub nanosecond: u16,
-    pub offset_nanoseconds: i64,
-    pub is_valid: i8,
+/// A piece of a date pattern: a run of one ASCII letter, or literal text.
+enum PatternToken {
+    Field(char, usize),
+    Literal(String),
 }
 
-impl Default for ZonedDateTimeComponents {
-    fn default() -> Self {
-        Self {
-            year: 0,
-            month: 0,
-            day: 0,
-            day_of_week: 0,
-            day_of_year: 0,
-            week_of_year: 0,
-            year_of_week: 0,
-            days_in_week: 0,
-            days_in_month: 0,
-            days_in_year: 0,
-            months_in_year: 0,
-            in_leap_year: 0,
-            hour: 0,
-            minute: 0,
-            second: 0,
-            millisecond: 0,
-            microsecond: 0,
-            nanosecond: 0,
-            offset_nanoseconds: 0,
-            is_valid: 0,
+/// Splits a pattern into letter runs and literals. Text inside single quotes is
+/// literal and `''` is a single quote.
+fn tokenize_pattern(pattern: &str) -> Result<Vec<PatternToken>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\'' {
+            let mut literal = String::new();
+            if chars.peek() == Some(&'\'') {
+                chars.next();
+                literal.push('\'');
+            } else {
+                loop {
+                    match chars.next() {
+                        Some('\'') if chars.peek() == Some(&'\'') => {
+                            chars.next();
+                            literal.push('\'');
+                        }
+                        Some('\'') => break,
+                        Some(ch) => literal.push(ch),
+                        None => return Err(format!("Unterminated quote in pattern '{}'", pattern)),
+                    }
+                }
+            }
+            tokens.push(PatternToken::Literal(literal));
+        } else if c.is_ascii_alphabetic() {
+            let mut count = 1;
+            while chars.peek() == Some(&c) {
+                chars.next();
+                count += 1;
+            }
+            tokens.push(PatternToken::Field(c, count));
+        } else {
+            tokens.push(PatternToken::Literal(c.to_string()));
         }
     }
+    Ok(tokens)
 }
 
-/// Parses an ISO 8601 string into a ZonedDateTime.
+// ============================================================================
+// Binary encoding API
+// ============================================================================
+
+// Layout (all integers big-endian):
+//   u8   version (ENCODING_VERSION)
+//   u8   kind (ENCODING_KIND_*)
+//   i128 epoch nanoseconds
+//   ZonedDateTime only: u8 length + UTF-8 time zone id, u8 length + UTF-8 calendar id
+// Readers reject versions newer than they understand, so old app builds fail loudly
+// on data written by newer ones instead of misreading it.
+const ENCODING_VERSION: u8 = 1;
+const ENCODING_KIND_INSTANT: u8 = 1;
+const ENCODING_KIND_ZONED_DATE_TIME: u8 = 2;
+
+/// Encodes an Instant into `out`. Like snprintf, returns the encoded length and
+/// writes only if it fits in `out_len` (call with NULL/0 to size the buffer).
+/// Returns 0 on error; details are available via `temporal_last_error_type` and
+/// `temporal_last_error_message`.
 #[no_mangle]
-pub extern "C" fn temporal_zoned_date_time_from_string(
-    s: *const c_char,
-) -> TemporalResult {
-    let s_str = match parse_c_str(s, "zoned date time string") {
-        Ok(s) => s,
-        Err(e) => return e,
-    };
-    
-    // Using default provider (TZDB)
-    match ZonedDateTime::from_utf8(s_str.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
-        Ok(zdt) => match zdt.to_ixdtf_string(DisplayOffset::Auto, DisplayTimeZone::Auto, DisplayCalendar::Auto, ToStringRoundingOptions::default()) {
-            Ok(s) => TemporalResult::success(s),
-            Err(e) => TemporalResult::range_error(&format!("Failed to format zoned date time: {}", e)),
+pub extern "C" fn temporal_instant_to_bytes(s: *const c_char, out: *mut u8, out_len: usize) -> usize {
+    clear_last_error();
+    match parse_with_last_error(s, "source", "instant", &Instant::from_str) {
+        Some(instant) => match encode_epoch(ENCODING_KIND_INSTANT, instant.as_i128(), &[]) {
+            Ok(bytes) => write_encoded(&bytes, out, out_len),
+            Err(message) => {
+                set_last_error(TemporalErrorType::RangeError, format_args!("{}", message));
+                0
+            }
         },
-        Err(e) => TemporalResult::range_error(&format!("Invalid zoned date time '{}': {}", s_str, e)),
+        None => 0,
     }
 }
 
-/// Creates a ZonedDateTime from components.
+/// Decodes bytes produced by `temporal_instant_to_bytes` into an Instant string.
 #[no_mangle]
-pub extern "C" fn temporal_zoned_date_time_from_components(
-    year: i32,
-    month: u8,
-    day: u8,
-    hour: u8,
-    minute: u8,
-    second: u8,
-    millisecond: u16,
-    microsecond: u16,
-    nanosecond: u16,
-    calendar_id: *const c_char,
-    time_zone_id: *const c_char,
-    offset_nanoseconds: i64, // Optional offset for conflict resolution, 0 if ignored? 
-    // Spec: needs disambiguation options if offset is ignored/provided
-) -> TemporalResult {
-    // Constructing ZDT from components usually requires creating a PlainDateTime first, 
-    // then converting to ZDT with timezone and disambiguation.
-    
-    let calendar = if !calendar_id.is_null() {
-        match parse_c_str(calendar_id, "calendar id") {
-            Ok(s) => match Calendar::from_str(s) {
-                Ok(c) => c,
-                Err(e) => return TemporalResult::range_error(&format!("Invalid calendar: {}", e)),
-            },
-            Err(e) => return e,
-        }
-    } else {
-        Calendar::default()
+pub extern "C" fn temporal_instant_from_bytes(data: *const u8, len: usize) -> TemporalResult {
+    let (ns, _) = match decode_epoch(data, len, ENCODING_KIND_INSTANT, 0) {
+        Ok(decoded) => decoded,
+        Err(e) => return e,
     };
+    match Instant::try_new(ns).map_err(|e| e.to_string()).and_then(|i| format_instant(&i).map_err(|e| e.to_string())) {
+        Ok(s) => TemporalResult::success(s),
+        Err(message) => TemporalResult::range_error(&format!("Invalid encoded instant: {}", message)),
+    }
+}
 
-    let pdt = match PlainDateTime::new(
-        year, month, day, 
-        hour, minute, second, 
-        millisecond, microsecond, nanosecond, 
-        calendar
-    ) {
-        Ok(d) => d,
-        Err(e) => return TemporalResult::range_error(&format!("Invalid components: {}", e)),
+/// Encodes a ZonedDateTime (epoch nanoseconds, time zone id, calendar id) into
+/// `out`, with the same return convention as `temporal_instant_to_bytes`.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_to_bytes(s: *const c_char, out: *mut u8, out_len: usize) -> usize {
+    clear_last_error();
+    let zdt = match parse_with_last_error(s, "source", "zoned date time", &|s: &str| {
+        ZonedDateTime::from_utf8(s.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject)
+    }) {
+        Some(z) => z,
+        None => return 0,
     };
-
-    let tz_str = if !time_zone_id.is_null() {
-        match parse_c_str(time_zone_id, "timezone id") {
-            Ok(s) => s,
-            Err(e) => return e,
+    let time_zone = match zdt.time_zone().identifier() {
+        Ok(id) => id,
+        Err(e) => {
+            set_last_error(TemporalErrorType::RangeError, format_args!("Failed to get time zone id: {}", e));
+            return 0;
         }
-    } else {
-        return TemporalResult::type_error("Timezone ID is required");
     };
+    match encode_epoch(ENCODING_KIND_ZONED_DATE_TIME, zdt.epoch_nanoseconds().as_i128(), &[&time_zone, zdt.calendar().identifier()]) {
+        Ok(bytes) => write_encoded(&bytes, out, out_len),
+        Err(message) => {
+            set_last_error(TemporalErrorType::RangeError, format_args!("{}", message));
+            0
+        }
+    }
+}
 
-    let tz = match TimeZone::try_from_str(tz_str) {
-        Ok(t) => t,
-        Err(e) => return TemporalResult::range_error(&format!("Invalid timezone: {}", e)),
+/// Decodes bytes produced by `temporal_zoned_date_time_to_bytes` into a
+/// ZonedDateTime string.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_from_bytes(data: *const u8, len: usize) -> TemporalResult {
+    let (ns, ids) = match decode_epoch(data, len, ENCODING_KIND_ZONED_DATE_TIME, 2) {
+        Ok(decoded) => decoded,
+        Err(e) => return e,
     };
-
-    // We create ZDT from PDT + TZ. 
-    // TC39 `from` usually takes an object with components and options.
-    // Here we assume standard construction (compatible disambiguation).
-    
-    match pdt.to_zoned_date_time(tz, Disambiguation::Compatible) { // None = compatible/default
-        Ok(zdt) => match zdt.to_ixdtf_string(DisplayOffset::Auto, DisplayTimeZone::Auto, DisplayCalendar::Auto, ToStringRoundingOptions::default()) {
+    let zdt = TimeZone::try_from_str(&ids[0]).and_then(|tz| {
+        let calendar = Calendar::from_str(&ids[1])?;
+        ZonedDateTime::try_new(ns, tz, calendar)
+    });
+    match zdt {
+        Ok(z) => match z.to_ixdtf_string(display::offset(), display::time_zone(), display::calendar(), display::rounding()) {
             Ok(s) => TemporalResult::success(s),
             Err(e) => TemporalResult::range_error(&format!("Failed to format zoned date time: {}", e)),
         },
-        Err(e) => TemporalResult::range_error(&format!("Failed to create zoned date time: {}", e)),
+        Err(e) => TemporalResult::range_error(&format!("Invalid encoded zoned date time: {}", e)),
     }
 }
 
-/// Gets components from a ZonedDateTime string.
-#[no_mangle]
-pub extern "C" fn temporal_zoned_date_time_get_components(
-    s: *const c_char,
-    out: *mut ZonedDateTimeComponents,
-) {
-    if out.is_null() { return; }
-    unsafe { *out = ZonedDateTimeComponents::default(); }
-    if s.is_null() { return; }
+/// Fails if an id is too long for its one-byte length prefix.
+fn encode_epoch(kind: u8, epoch_ns: i128, ids: &[&str]) -> Result<Vec<u8>, String> {
+    let mut bytes = Vec::with_capacity(18 + ids.iter().map(|id| id.len() + 1).sum::<usize>());
+    bytes.push(ENCODING_VERSION);
+    bytes.push(kind);
+    bytes.extend_from_slice(&epoch_ns.to_be_bytes());
+    for id in ids {
+        match u8::try_from(id.len()) {
+            Ok(len) => bytes.push(len),
+            Err(_) => return Err(format!("Identifier too long to encode: {} bytes (at most 255)", id.len())),
+        }
+        bytes.extend_from_slice(id.as_bytes());
+    }
+    Ok(bytes)
+}
 
-    let zdt = match parse_zoned_date_time(s, "zoned date time") {
-        Ok(z) => z,
-        Err(_) => return,
+fn write_encoded(bytes: &[u8], out: *mut u8, out_len: usize) -> usize {
+    if !out.is_null() && bytes.len() <= out_len {
+        unsafe { ptr::copy_nonoverlapping(bytes.as_ptr(), out, bytes.len()) };
+    }
+    bytes.len()
+}
+
+/// Reads the header, epoch nanoseconds and `id_count` length-prefixed ids.
+fn decode_epoch(data: *const u8, len: usize, kind: u8, id_count: usize) -> Result<(i128, Vec<String>), TemporalResult> {
+    if data.is_null() {
+        return Err(TemporalResult::type_error("encoded bytes cannot be null"));
+    }
+    let bytes = unsafe { std::slice::from_raw_parts(data, len) };
+    match bytes.first() {
+        None => return Err(TemporalResult::range_error("Encoded value is empty")),
+        Some(&version) if version > ENCODING_VERSION => {
+            return Err(TemporalResult::range_error(&format!(
+                "Unsupported encoding version {} (this build reads up to {})",
+                version, ENCODING_VERSION
+            )))
+        }
+        Some(0) => return Err(TemporalResult::range_error("Invalid encoding version 0")),
+        Some(_) => {}
+    }
+    if bytes.get(1) != Some(&kind) {
+        return Err(TemporalResult::range_error("Encoded value is not of the expected type"));
+    }
+    let epoch_ns = match bytes.get(2..18) {
+        Some(raw) => i128::from_be_bytes(raw.try_into().unwrap_or_default()),
+        None => return Err(TemporalResult::range_error("Encoded value is truncated")),
     };
 
-    unsafe {
-        (*out).year = zdt.year();
-        (*out).month = zdt.month();
-        (*out).day = zdt.day();
-        (*out).day_of_week = zdt.day_of_week();
-        (*out).day_of_year = zdt.day_of_year();
-        (*out).week_of_year = zdt.week_of_year().unwrap_or(0) as u16;
-        (*out).year_of_week = zdt.year_of_week().unwrap_or(0);
-        (*out).days_in_week = zdt.days_in_week();
-        (*out).days_in_month = zdt.days_in_month();
-        (*out).days_in_year = zdt.days_in_year();
-        (*out).months_in_year = zdt.months_in_year();
-        (*out).in_leap_year = if zdt.in_leap_year() { 1 } else { 0 };
-        
-        (*out).hour = zdt.hour();
-        (*out).minute = zdt.minute();
-        (*out).second = zdt.second();
-        (*out).millisecond = zdt.millisecond();
-        (*out).microsecond = zdt.microsecond();
-        (*out).nanosecond = zdt.nanosecond();
-        
-        (*out).offset_nanoseconds = zdt.offset_nanoseconds() as i64;
-        
-        (*out).is_valid = 1;
+    let mut ids = Vec::with_capacity(id_count);
+    let mut pos = 18;
+    for _ in 0..id_count {
+        let id = bytes.get(pos).and_then(|&n| bytes.get(pos + 1..pos + 1 + n as usize));
+        match id.map(std::str::from_utf8) {
+            Some(Ok(id)) => {
+                pos += 1 + id.len();
+                ids.push(id.to_string());
+            }
+            Some(Err(_)) => return Err(TemporalResult::range_error("Encoded identifier is not valid UTF-8")),
+            None => return Err(TemporalResult::range_error("Encoded value is truncated")),
+        }
+    }
+    if pos != bytes.len() {
+        return Err(TemporalResult::range_error("Encoded value has trailing bytes"));
     }
+    Ok((epoch_ns, ids))
 }
 
-/// Gets the epoch values.
+// ============================================================================
+// Batch API
+// ============================================================================
+
+/// Parses every `delimiter`-separated item of `joined_input` as `kind` ("Instant",
+/// "PlainDate", "PlainTime", "PlainDateTime", "PlainYearMonth", "PlainMonthDay",
+/// "ZonedDateTime" or "Duration") in one call.
+///
+/// The result holds the normalized values joined by the same delimiter, with an
+/// empty slot for each item that failed. If `error_codes` is not NULL, the error
+/// type of each item (0 = ok, 1 = RangeError, 3 = InputTooLong) is written to it, up to
+/// `error_codes_len` entries. An error result means the call itself was invalid
+/// (unknown kind, empty delimiter), not that an item failed.
 #[no_mangle]
-pub extern "C" fn temporal_zoned_date_time_epoch_milliseconds(s: *const c_char) -> TemporalResult {
-    let zdt = match parse_zoned_date_time(s, "zoned date time") {
-        Ok(z) => z,
+pub extern "C" fn temporal_batch_parse(
+    kind: *const c_char,
+    joined_input: *const c_char,
+    delimiter: *const c_char,
+    error_codes: *mut i32,
+    error_codes_len: usize,
+) -> TemporalResult {
+    let kind = match parse_c_str(kind, "kind") {
+        Ok(s) => s,
         Err(e) => return e,
     };
-    TemporalResult::success(zdt.epoch_milliseconds().to_string())
-}
-
-#[no_mangle]
-pub extern "C" fn temporal_zoned_date_time_epoch_nanoseconds(s: *const c_char) -> TemporalResult {
-    let zdt = match parse_zoned_date_time(s, "zoned date time") {
-        Ok(z) => z,
+    let input = match parse_bulk_c_str(joined_input, "joined input") {
+        Ok(s) => s,
         Err(e) => return e,
     };
-    TemporalResult::success(zdt.epoch_nanoseconds().0.to_string())
-}
-
-/// Gets the calendar ID.
-#[no_mangle]
-pub extern "C" fn temporal_zoned_date_time_get_calendar(s: *const c_char) -> TemporalResult {
-    let zdt = match parse_zoned_date_time(s, "zoned date time") {
-        Ok(z) => z,
+    let delimiter = match parse_c_str(delimiter, "delimiter") {
+        Ok("") => return TemporalResult::range_error("delimiter cannot be empty"),
+        Ok(s) => s,
         Err(e) => return e,
     };
-    TemporalResult::success(zdt.calendar().identifier().to_string())
-}
 
-/// Gets the TimeZone ID.
-#[no_mangle]
-pub extern "C" fn temporal_zoned_date_time_get_time_zone(s: *const c_char) -> TemporalResult {
-    let zdt = match parse_zoned_date_time(s, "zoned date time") {
-        Ok(z) => z,
-        Err(e) => return e,
+    let codes = if error_codes.is_null() {
+        &mut [][..]
+    } else {
+        unsafe { std::slice::from_raw_parts_mut(error_codes, error_codes_len) }
     };
-    match zdt.time_zone().identifier() {
-        Ok(id) => TemporalResult::success(id),
-        Err(e) => TemporalResult::range_error(&format!("Failed to get timezone id: {}", e)),
+    let kind = match Kind::from_name(kind) {
+        Some(k) => k,
+        None => return TemporalResult::range_error(&format!("Invalid kind: {}", kind)),
+    };
+    let mut values = Vec::new();
+    for (index, item) in input.split(delimiter).enumerate() {
+        let normalized = if item.len() > max_input_length() { None } else { Some(normalize_temporal_string(kind, item)) };
+        let (value, code) = match normalized {
+            Some(Ok(value)) => (value, TemporalErrorType::None),
+            Some(Err(_)) => (String::new(), TemporalErrorType::RangeError),
+            None => (String::new(), TemporalErrorType::InputTooLong),
+        };
+        if let Some(slot) = codes.get_mut(index) {
+            *slot = code as i32;
+        }
+        values.push(value);
     }
+    TemporalResult::success(values.join(delimiter))
 }
 
-/// Gets the offset string.
+/// Checks whether `s` parses as `kind` (same kinds as `temporal_batch_parse`) without
+/// returning the value, for isValid checks on every keystroke. It only parses: the
+/// value is neither formatted nor put in the canonical cache, so partial inputs
+/// typed on the way to a valid one don't evict cached values.
+///
+/// Returns 0 when valid, otherwise the TemporalErrorType code: 1 (RangeError) for
+/// invalid input or an unknown kind, 2 (TypeError) for a NULL or non-UTF-8 argument,
+/// 3 (InputTooLong) for input over the max input length.
+/// The message is available via `temporal_last_error_message`.
 #[no_mangle]
-pub extern "C" fn temporal_zoned_date_time_get_offset(s: *const c_char) -> TemporalResult {
-    let zdt = match parse_zoned_date_time(s, "zoned date time") {
-        Ok(z) => z,
-        Err(e) => return e,
-    };
-    TemporalResult::success(zdt.offset().to_string())
+pub extern "C" fn temporal_validate(kind: *const c_char, s: *const c_char) -> i32 {
+    clear_last_error();
+    let kind = parse_with_last_error(kind, "validation", "kind", &|name: &str| {
+        Kind::from_name(name).ok_or_else(|| TemporalError::range().with_message("unknown kind"))
+    });
+    let validated = kind.and_then(|kind| parse_with_last_error(s, "input", kind.name(), &|s: &str| parse_temporal_string(kind, s)));
+    match validated {
+        Some(_) => TemporalErrorType::None as i32,
+        None => temporal_last_error_type(),
+    }
 }
 
-/// Adds a duration.
+/// Rewrites the string values at the given JSON pointers (RFC 6901, newline-delimited,
+/// `*` matches every array element or object member) to canonical IXDTF, so large
+/// payloads are normalized in one call. Values with a time zone annotation become
+/// ZonedDateTimes, values with an offset or Z become Instants, and the rest
+/// PlainDateTimes or PlainDates. Missing paths and non-string values are left alone;
+/// an unparseable timestamp fails the whole call. Returns the rewritten compact JSON.
 #[no_mangle]
-pub extern "C" fn temporal_zoned_date_time_add(
-    zdt_str: *const c_char,
-    duration_str: *const c_char,
+pub extern "C" fn temporal_normalize_json_timestamps(
+    json: *const c_char,
+    pointer_paths: *const c_char,
 ) -> TemporalResult {
-    let zdt = match parse_zoned_date_time(zdt_str, "zoned date time") {
-        Ok(z) => z,
+    let json_str = match parse_bulk_c_str(json, "json") {
+        Ok(s) => s,
         Err(e) => return e,
     };
-    let duration = match parse_duration(duration_str, "duration") {
-        Ok(d) => d,
+    let paths = match parse_bulk_c_str(pointer_paths, "pointer paths") {
+        Ok(s) => s,
         Err(e) => return e,
     };
+    let mut document = match json::parse(json_str) {
+        Ok(d) => d,
+        Err(message) => return TemporalResult::range_error(&format!("Invalid JSON: {}", message)),
+    };
 
-    match zdt.add(&duration, Some(Overflow::Reject)) {
-        Ok(result) => match result.to_ixdtf_string(DisplayOffset::Auto, DisplayTimeZone::Auto, DisplayCalendar::Auto, ToStringRoundingOptions::default()) {
-            Ok(s) => TemporalResult::success(s),
-            Err(e) => TemporalResult::range_error(&format!("Failed to format: {}", e)),
-        },
-        Err(e) => TemporalResult::range_error(&format!("Failed to add duration: {}", e)),
+    for pointer in split_list(paths) {
+        if !pointer.starts_with('/') {
+            return TemporalResult::range_error(&format!("Invalid JSON pointer: {}", pointer));
+        }
+        let result = document.for_each_pointer_mut(pointer, &mut |path, value| {
+            if let JsonValue::String(s) = value {
+                *s = normalize_timestamp(s).map_err(|e| format!("Invalid timestamp at {}: {}", path, e))?;
+            }
+            Ok::<(), String>(())
+        });
+        if let Err(message) = result {
+            return TemporalResult::range_error(&message);
+        }
     }
+    TemporalResult::success(document.to_string())
 }
 
-/// Subtracts a duration.
-#[no_mangle]
-pub extern "C" fn temporal_zoned_date_time_subtract(
-    zdt_str: *const c_char,
-    duration_str: *const c_char,
-) -> TemporalResult {
-    let zdt = match parse_zoned_date_time(zdt_str, "zoned date time") {
-        Ok(z) => z,
-        Err(e) => return e,
-    };
-    let duration = match parse_duration(duration_str, "duration") {
-        Ok(d) => d,
-        Err(e) => return e,
-    };
+/// Normalizes a timestamp of unknown kind: ZonedDateTime if it names a time zone,
+/// else Instant if it has an offset, else PlainDateTime (if it has a time) or PlainDate.
+fn normalize_timestamp(s: &str) -> Result<String, TemporalError> {
+    // A bracketed annotation without '=' is a time zone ([u-ca=...] is a calendar).
+    let has_time_zone = s.split('[').skip(1).any(|annotation| !annotation.contains('='));
+    if has_time_zone {
+        return ZonedDateTime::from_utf8(s.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject)
+            .and_then(|zdt| zdt.to_ixdtf_string(display::offset(), display::time_zone(), display::calendar(), display::rounding()));
+    }
+    if let Ok(instant) = Instant::from_str(s) {
+        return format_instant(&instant);
+    }
+    if s.contains(['T', 't', ' ']) {
+        PlainDateTime::from_str(s).and_then(|dt| dt.to_ixdtf_string(display::rounding(), display::calendar()))
+    } else {
+        PlainDate::from_str(s).map(|d| d.to_ixdtf_string(display::calendar()))
+    }
+}
 
-    match zdt.subtract(&duration, Some(Overflow::Reject)) {
-        Ok(result) => match result.to_ixdtf_string(DisplayOffset::Auto, DisplayTimeZone::Auto, DisplayCalendar::Auto, ToStringRoundingOptions::default()) {
-            Ok(s) => TemporalResult::success(s),
-            Err(e) => TemporalResult::range_error(&format!("Failed to format: {}", e)),
-        },
-        Err(e) => TemporalResult::range_error(&format!("Failed to subtract duration: {}", e)),
+/// Parses `s` as the given kind without formatting or caching it.
+fn parse_temporal_string(kind: Kind, s: &str) -> Result<(), TemporalError> {
+    match kind {
+        Kind::Instant => Instant::from_str(s).map(drop),
+        Kind::PlainDate => PlainDate::from_str(s).map(drop),
+        Kind::PlainTime => PlainTime::from_str(s).map(drop),
+        Kind::PlainDateTime => PlainDateTime::from_str(s).map(drop),
+        Kind::PlainYearMonth => PlainYearMonth::from_str(s).map(drop),
+        Kind::PlainMonthDay => PlainMonthDay::from_str(s).map(drop),
+        Kind::ZonedDateTime => {
+            ZonedDateTime::from_utf8(s.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject).map(drop)
+        }
+        Kind::Duration => duration_from_str(s).map(drop),
     }
 }
 
-/// Compares two ZonedDateTimes.
+/// Parses `s` as the given kind and returns its canonical string.
+fn normalize_temporal_string(kind: Kind, s: &str) -> Result<String, TemporalError> {
+    canonical_cache::canonical(kind, s, || {
+        let rounding = display::rounding();
+        match kind {
+            Kind::Instant => Instant::from_str(s).and_then(|i| format_instant(&i)),
+            Kind::PlainDate => PlainDate::from_str(s).map(|d| d.to_ixdtf_string(display::calendar())),
+            Kind::PlainTime => PlainTime::from_str(s).and_then(|t| t.to_ixdtf_string(rounding)),
+            Kind::PlainDateTime => PlainDateTime::from_str(s).and_then(|dt| dt.to_ixdtf_string(rounding, display::calendar())),
+            Kind::PlainYearMonth => PlainYearMonth::from_str(s).map(|ym| ym.to_ixdtf_string(display::calendar())),
+            Kind::PlainMonthDay => PlainMonthDay::from_str(s).map(|md| md.to_ixdtf_string(display::calendar())),
+            Kind::ZonedDateTime => ZonedDateTime::from_utf8(s.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject)
+                .and_then(|zdt| zdt.to_ixdtf_string(display::offset(), display::time_zone(), display::calendar(), rounding)),
+            Kind::Duration => duration_from_str(s).map(|d| d.to_string()),
+        }
+    })
+}
+
+// ============================================================================
+// Compare codes (allocation-free error reporting)
+// ============================================================================
+
+/// Returned by the `*_compare_code` functions when comparison failed.
+/// Details are available via `temporal_last_error_type`/`temporal_last_error_message`.
+pub const TEMPORAL_COMPARE_ERROR: i32 = i32::MIN;
+
+/// Compares two Instants. Returns -1, 0, 1 or TEMPORAL_COMPARE_ERROR.
 #[no_mangle]
-pub extern "C" fn temporal_zoned_date_time_compare(
-    a: *const c_char,
-    b: *const c_char,
-) -> CompareResult {
-    let zdt_a = match parse_zoned_date_time(a, "first zoned date time") {
-        Ok(z) => z,
-        Err(e) => return CompareResult::range_error(
-            &unsafe { std::ffi::CStr::from_ptr(e.error_message) }.to_string_lossy()
-        ),
-    };
-    let zdt_b = match parse_zoned_date_time(b, "second zoned date time") {
-        Ok(z) => z,
-        Err(e) => return CompareResult::range_error(
-            &unsafe { std::ffi::CStr::from_ptr(e.error_message) }.to_string_lossy()
-        ),
-    };
+pub extern "C" fn temporal_instant_compare_code(a: *const c_char, b: *const c_char) -> i32 {
+    compare_code(a, b, "instant", Instant::from_str, |x, y| Ok(x.cmp(y)))
+}
 
-    CompareResult::success(zdt_a.epoch_nanoseconds().0.cmp(&zdt_b.epoch_nanoseconds().0) as i32)
+/// Compares two PlainTimes with nanosecond resolution. Returns -1, 0, 1 or
+/// TEMPORAL_COMPARE_ERROR.
+#[no_mangle]
+pub extern "C" fn temporal_plain_time_compare_code(a: *const c_char, b: *const c_char) -> i32 {
+    compare_code(a, b, "plain time", PlainTime::from_str, |x, y| Ok(x.cmp(y)))
 }
 
-/// Returns a new ZonedDateTime with updated fields.
+/// Compares two PlainDates. Returns -1, 0, 1 or TEMPORAL_COMPARE_ERROR.
 #[no_mangle]
-pub extern "C" fn temporal_zoned_date_time_with(
-    zdt_str: *const c_char,
-    year: i32,
-    month: i32,
-    day: i32,
-    hour: i32,
-    minute: i32,
-    second: i32,
-    millisecond: i32,
-    microsecond: i32,
-    nanosecond: i32,
-    offset_ns: i64, // Used for disambiguation if provided
-    calendar_id: *const c_char,
-    time_zone_id: *const c_char,
-) -> TemporalResult {
-    let zdt = match parse_zoned_date_time(zdt_str, "zoned date time") {
-        Ok(z) => z,
-        Err(e) => return e,
+pub extern "C" fn temporal_plain_date_compare_code(a: *const c_char, b: *const c_char) -> i32 {
+    compare_code(a, b, "plain date", PlainDate::from_str, |x, y| Ok(compare_plain_dates(x, y)))
+}
+
+/// Compares two PlainDateTimes with nanosecond resolution, ignoring the calendar.
+/// Returns -1, 0, 1 or TEMPORAL_COMPARE_ERROR.
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_time_compare_code(a: *const c_char, b: *const c_char) -> i32 {
+    compare_code(a, b, "plain date time", PlainDateTime::from_str, |x, y| Ok(x.compare_iso(y)))
+}
+
+/// Compares two PlainYearMonths. Returns -1, 0, 1 or TEMPORAL_COMPARE_ERROR.
+#[no_mangle]
+pub extern "C" fn temporal_plain_year_month_compare_code(a: *const c_char, b: *const c_char) -> i32 {
+    compare_code(a, b, "plain year month", PlainYearMonth::from_str, |x, y| Ok(compare_plain_year_months(x, y)))
+}
+
+/// Compares two ZonedDateTimes by their exact time. Returns -1, 0, 1 or TEMPORAL_COMPARE_ERROR.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_compare_code(a: *const c_char, b: *const c_char) -> i32 {
+    compare_code(
+        a,
+        b,
+        "zoned date time",
+        |s| ZonedDateTime::from_utf8(s.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject),
+        |x, y| Ok(x.epoch_nanoseconds().0.cmp(&y.epoch_nanoseconds().0)),
+    )
+}
+
+/// Compares two Durations. Returns -1, 0, 1 or TEMPORAL_COMPARE_ERROR.
+#[no_mangle]
+pub extern "C" fn temporal_duration_compare_code(a: *const c_char, b: *const c_char) -> i32 {
+    compare_code(a, b, "duration", duration_from_str, compare_durations)
+}
+
+fn compare_code<T, P, C>(a: *const c_char, b: *const c_char, kind: &str, parse: P, compare: C) -> i32
+where
+    P: Fn(&str) -> Result<T, TemporalError>,
+    C: FnOnce(&T, &T) -> Result<std::cmp::Ordering, &'static str>,
+{
+    clear_last_error();
+    let value_a = match parse_with_last_error(a, "first", kind, &parse) {
+        Some(v) => v,
+        None => return TEMPORAL_COMPARE_ERROR,
+    };
+    let value_b = match parse_with_last_error(b, "second", kind, &parse) {
+        Some(v) => v,
+        None => return TEMPORAL_COMPARE_ERROR,
     };
-    
-    // This is complex. `with` works on PlainDateTime components then resolves.
-    // We need to implement partial update logic similar to PlainDateTime but then re-resolve.
-    // For simplicity, we can extract current components, overlay new ones, create new ZDT.
-    
-    let current_pdt = zdt.to_plain_date_time();
-    
-    let new_year = if year == i32::MIN { current_pdt.year() } else { year };
-    let new_month = if month == i32::MIN { current_pdt.month() } else { month as u8 };
-    let new_day = if day == i32::MIN { current_pdt.day() } else { day as u8 };
-    
-    let new_hour = if hour == i32::MIN { current_pdt.hour() } else { hour as u8 };
-    let new_minute = if minute == i32::MIN { current_pdt.minute() } else { minute as u8 };
-    let new_second = if second == i32::MIN { current_pdt.second() } else { second as u8 };
-    let new_millisecond = if millisecond == i32::MIN { current_pdt.millisecond() } else { millisecond as u16 };
-    let new_microsecond = if microsecond == i32::MIN { current_pdt.microsecond() } else { microsecond as u16 };
-    let new_nanosecond = if nanosecond == i32::MIN { current_pdt.nanosecond() } else { nanosecond as u16 };
 
-    let new_calendar = if !calendar_id.is_null() {
-        match parse_c_str(calendar_id, "calendar id") {
-            Ok(s) => match Calendar::from_str(s) {
-                Ok(c) => c,
-                Err(e) => return TemporalResult::range_error(&format!("Invalid calendar: {}", e)),
-            },
-            Err(e) => return e,
+    match compare(&value_a, &value_b) {
+        Ok(ordering) => ordering as i32,
+        Err(message) => {
+            set_last_error(TemporalErrorType::RangeError, format_args!("{}", message));
+            TEMPORAL_COMPARE_ERROR
+        }
+    }
+}
+
+/// Reads a C string argument, setting the last error on failure.
+fn read_with_last_error<'a>(s: *const c_char, position: &str, kind: &str, bulk: bool) -> Option<&'a str> {
+    match read_c_str(s, bulk) {
+        Ok(v) => Some(v),
+        Err(CStrError::Null) => {
+            set_last_error(TemporalErrorType::TypeError, format_args!("{} {} cannot be null", position, kind));
+            None
+        }
+        Err(CStrError::InvalidUtf8) => {
+            set_last_error(TemporalErrorType::TypeError, format_args!("Invalid UTF-8 in {} {}", position, kind));
+            None
+        }
+        Err(CStrError::TooLong(len, limit)) => {
+            let message = input_too_long_message(&format!("{} {}", position, kind), len, limit);
+            set_last_error(TemporalErrorType::InputTooLong, format_args!("{}", message));
+            None
+        }
+    }
+}
+
+fn parse_with_last_error<T, P>(s: *const c_char, position: &str, kind: &str, parse: &P) -> Option<T>
+where
+    P: Fn(&str) -> Result<T, TemporalError>,
+{
+    let str_val = read_with_last_error(s, position, kind, false)?;
+    match parse(str_val) {
+        Ok(v) => Some(v),
+        Err(e) => {
+            set_last_error(TemporalErrorType::RangeError, format_args!("Invalid {} '{}': {}", kind, str_val, e));
+            None
         }
+    }
+}
+
+// ============================================================================
+// Integer-coded option variants
+// ============================================================================
+
+/// Passed for an optional coded option that should use its default.
+pub const TEMPORAL_OPTION_UNSET: i32 = -1;
+
+/// Unit codes: 0 auto, 1 nanosecond, 2 microsecond, 3 millisecond, 4 second,
+/// 5 minute, 6 hour, 7 day, 8 week, 9 month, 10 year.
+const UNIT_CODES: [Unit; 11] = [
+    Unit::Auto,
+    Unit::Nanosecond,
+    Unit::Microsecond,
+    Unit::Millisecond,
+    Unit::Second,
+    Unit::Minute,
+    Unit::Hour,
+    Unit::Day,
+    Unit::Week,
+    Unit::Month,
+    Unit::Year,
+];
+
+/// Rounding mode codes: 0 ceil, 1 floor, 2 expand, 3 trunc, 4 halfCeil,
+/// 5 halfFloor, 6 halfExpand, 7 halfTrunc, 8 halfEven.
+const ROUNDING_MODE_CODES: [RoundingMode; 9] = [
+    RoundingMode::Ceil,
+    RoundingMode::Floor,
+    RoundingMode::Expand,
+    RoundingMode::Trunc,
+    RoundingMode::HalfCeil,
+    RoundingMode::HalfFloor,
+    RoundingMode::HalfExpand,
+    RoundingMode::HalfTrunc,
+    RoundingMode::HalfEven,
+];
+
+/// Disambiguation codes: 0 compatible, 1 earlier, 2 later, 3 reject.
+const DISAMBIGUATION_CODES: [Disambiguation; 4] = [
+    Disambiguation::Compatible,
+    Disambiguation::Earlier,
+    Disambiguation::Later,
+    Disambiguation::Reject,
+];
+
+/// Offset option codes: 0 use, 1 prefer, 2 ignore, 3 reject.
+const OFFSET_DISAMBIGUATION_CODES: [OffsetDisambiguation; 4] = [
+    OffsetDisambiguation::Use,
+    OffsetDisambiguation::Prefer,
+    OffsetDisambiguation::Ignore,
+    OffsetDisambiguation::Reject,
+];
+
+/// Returns the rounding mode names accepted by the string-option functions,
+/// newline-separated, in the order of the coded variants.
+#[no_mangle]
+pub extern "C" fn temporal_supported_rounding_modes() -> TemporalResult {
+    let names: Vec<String> = ROUNDING_MODE_CODES.iter().map(RoundingMode::to_string).collect();
+    TemporalResult::success(names.join("\n"))
+}
+
+/// Returns the unit names (singular, largest first) accepted for a type's
+/// until/since/round options, newline-separated.
+///
+/// `context_kind` is a type name such as "PlainTime" or "ZonedDateTime"; NULL
+/// returns every unit. "auto" is not listed since it is only valid as a largest unit.
+#[no_mangle]
+pub extern "C" fn temporal_supported_units(context_kind: *const c_char) -> TemporalResult {
+    let accepts: fn(&Unit) -> bool = if context_kind.is_null() {
+        |_| true
     } else {
-        zdt.calendar().clone()
-    };
-    
-    let new_timezone = if !time_zone_id.is_null() {
-        match parse_c_str(time_zone_id, "timezone id") {
-            Ok(s) => match TimeZone::try_from_str(s) {
-                Ok(t) => t,
-                Err(e) => return TemporalResult::range_error(&format!("Invalid timezone: {}", e)),
-            },
+        let kind = match parse_c_str(context_kind, "context kind") {
+            Ok(k) => k,
             Err(e) => return e,
+        };
+        match kind {
+            "Instant" | "PlainTime" => Unit::is_time_unit,
+            "PlainDate" => Unit::is_date_unit,
+            "PlainYearMonth" => |u| matches!(u, Unit::Year | Unit::Month),
+            "PlainDateTime" | "ZonedDateTime" | "Duration" => |_| true,
+            _ => return TemporalResult::range_error(&format!("Invalid context kind: {}", kind)),
         }
-    } else {
-        zdt.time_zone().clone()
     };
+    let names: Vec<&str> = UNIT_CODES
+        .iter()
+        .rev()
+        .filter(|u| **u != Unit::Auto && accepts(u))
+        .map(|u| unit_name(*u))
+        .collect();
+    TemporalResult::success(names.join("\n"))
+}
 
-    let pdt = match PlainDateTime::new(
-        new_year, new_month, new_day, 
-        new_hour, new_minute, new_second, 
-        new_millisecond, new_microsecond, new_nanosecond, 
-        new_calendar
-    ) {
-        Ok(d) => d,
-        Err(e) => return TemporalResult::range_error(&format!("Invalid components: {}", e)),
-    };
-    
-    match pdt.to_zoned_date_time(new_timezone, Disambiguation::Compatible) {
-        Ok(new_zdt) => match new_zdt.to_ixdtf_string(DisplayOffset::Auto, DisplayTimeZone::Auto, DisplayCalendar::Auto, ToStringRoundingOptions::default()) {
-            Ok(s) => TemporalResult::success(s),
-            Err(e) => TemporalResult::range_error(&format!("Failed to format: {}", e)),
-        },
-        Err(e) => TemporalResult::range_error(&format!("Failed to create zoned date time: {}", e)),
+/// Singular option name of a unit. Unit's Display output is not used since it
+/// misspells "millisecond".
+fn unit_name(unit: Unit) -> &'static str {
+    match unit {
+        Unit::Auto => "auto",
+        Unit::Year => "year",
+        Unit::Month => "month",
+        Unit::Week => "week",
+        Unit::Day => "day",
+        Unit::Hour => "hour",
+        Unit::Minute => "minute",
+        Unit::Second => "second",
+        Unit::Millisecond => "millisecond",
+        Unit::Microsecond => "microsecond",
+        Unit::Nanosecond => "nanosecond",
     }
 }
 
-/// Computes difference (until).
+/// Computes the difference between two Instants (until) using coded options.
 #[no_mangle]
-pub extern "C" fn temporal_zoned_date_time_until(
+pub extern "C" fn temporal_instant_until_coded(
     one_str: *const c_char,
     two_str: *const c_char,
+    largest_unit: i32,
+    smallest_unit: i32,
+    rounding_increment: i64,
+    rounding_mode: i32,
 ) -> TemporalResult {
-    let one = match parse_zoned_date_time(one_str, "first zoned date time") {
-        Ok(z) => z,
+    let one = match parse_instant(one_str, "first instant") {
+        Ok(i) => i,
         Err(e) => return e,
     };
-    let two = match parse_zoned_date_time(two_str, "second zoned date time") {
-        Ok(z) => z,
+    let two = match parse_instant(two_str, "second instant") {
+        Ok(i) => i,
+        Err(e) => return e,
+    };
+    let options = match difference_settings_from_codes(largest_unit, smallest_unit, rounding_increment, rounding_mode) {
+        Ok(o) => o,
         Err(e) => return e,
     };
 
-    match one.until(&two, Default::default()) {
+    match one.until(&two, options) {
         Ok(d) => TemporalResult::success(d.to_string()),
         Err(e) => TemporalResult::range_error(&format!("Failed to compute difference: {}", e)),
     }
 }
 
-/// Computes difference (since).
+/// Computes the difference between two Instants (since) using coded options.
 #[no_mangle]
-pub extern "C" fn temporal_zoned_date_time_since(
+pub extern "C" fn temporal_instant_since_coded(
     one_str: *const c_char,
     two_str: *const c_char,
+    largest_unit: i32,
+    smallest_unit: i32,
+    rounding_increment: i64,
+    rounding_mode: i32,
 ) -> TemporalResult {
-    let one = match parse_zoned_date_time(one_str, "first zoned date time") {
-        Ok(z) => z,
+    let one = match parse_instant(one_str, "first instant") {
+        Ok(i) => i,
         Err(e) => return e,
     };
-    let two = match parse_zoned_date_time(two_str, "second zoned date time") {
-        Ok(z) => z,
+    let two = match parse_instant(two_str, "second instant") {
+        Ok(i) => i,
+        Err(e) => return e,
+    };
+    let options = match difference_settings_from_codes(largest_unit, smallest_unit, rounding_increment, rounding_mode) {
+        Ok(o) => o,
         Err(e) => return e,
     };
 
-    match one.since(&two, Default::default()) {
+    match one.since(&two, options) {
         Ok(d) => TemporalResult::success(d.to_string()),
         Err(e) => TemporalResult::range_error(&format!("Failed to compute difference: {}", e)),
     }
 }
 
-/// Rounds the ZonedDateTime.
+/// Rounds the Instant using coded options. smallest_unit is required.
 #[no_mangle]
-pub extern "C" fn temporal_zoned_date_time_round(
-    zdt_str: *const c_char,
-    smallest_unit: *const c_char,
+pub extern "C" fn temporal_instant_round_coded(
+    instant_str: *const c_char,
+    smallest_unit: i32,
     rounding_increment: i64,
-    rounding_mode: *const c_char,
+    rounding_mode: i32,
 ) -> TemporalResult {
-    let zdt = match parse_zoned_date_time(zdt_str, "zoned date time") {
-        Ok(z) => z,
+    let instant = match parse_instant(instant_str, "instant") {
+        Ok(i) => i,
         Err(e) => return e,
     };
-
-    let unit = if !smallest_unit.is_null() {
-        let s = match parse_c_str(smallest_unit, "smallest unit") {
-            Ok(s) => s,
-            Err(e) => return e,
-        };
-        match Unit::from_str(s) {
-            Ok(u) => u,
-            Err(_) => return TemporalResult::range_error(&format!("Invalid smallest unit: {}", s)),
-        }
-    } else {
-        return TemporalResult::type_error("smallestUnit is required");
+    let options = match rounding_options_from_codes(smallest_unit, rounding_increment, rounding_mode, IncrementContext::InstantRound) {
+        Ok(o) => o,
+        Err(e) => return e,
     };
 
-    let mode = if !rounding_mode.is_null() {
-        let s = match parse_c_str(rounding_mode, "rounding mode") {
-            Ok(s) => s,
-            Err(e) => return e,
-        };
-        match RoundingMode::from_str(s) {
-            Ok(m) => m,
-            Err(_) => return TemporalResult::range_error(&format!("Invalid rounding mode: {}", s)),
-        }
-    } else {
-        RoundingMode::HalfExpand
-    };
+    match instant.round(options) {
+        Ok(result) => {
+            let provider = &*COMPILED_TZ_PROVIDER;
+            match result.to_ixdtf_string_with_provider(None, display::rounding(), &provider) {
+                Ok(s) => TemporalResult::success(s),
+                Err(e) => TemporalResult::range_error(&format!("Failed to format instant: {}", e)),
+            }
+        },
+        Err(e) => TemporalResult::range_error(&format!("Failed to round: {}", e)),
+    }
+}
 
-    let increment = if rounding_increment > 0 {
-        rounding_increment as u32
-    } else {
-        1
+/// Computes the difference between two PlainTimes (until) using coded options.
+#[no_mangle]
+pub extern "C" fn temporal_plain_time_until_coded(
+    one_str: *const c_char,
+    two_str: *const c_char,
+    largest_unit: i32,
+    smallest_unit: i32,
+    rounding_increment: i64,
+    rounding_mode: i32,
+) -> TemporalResult {
+    let one = match parse_plain_time(one_str, "first plain time") {
+        Ok(t) => t,
+        Err(e) => return e,
     };
-    
-    let increment_opt = match RoundingIncrement::try_new(increment) {
-        Ok(i) => i,
-        Err(e) => return TemporalResult::range_error(&format!("Invalid rounding increment: {}", e)),
+    let two = match parse_plain_time(two_str, "second plain time") {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    let options = match difference_settings_from_codes(largest_unit, smallest_unit, rounding_increment, rounding_mode) {
+        Ok(o) => o,
+        Err(e) => return e,
     };
 
-    let mut options = RoundingOptions::default();
-    options.smallest_unit = Some(unit);
-    options.rounding_mode = Some(mode);
-    options.increment = Some(increment_opt);
-
-    match zdt.round(options) {
-        Ok(result) => match result.to_ixdtf_string(DisplayOffset::Auto, DisplayTimeZone::Auto, DisplayCalendar::Auto, ToStringRoundingOptions::default()) {
-            Ok(s) => TemporalResult::success(s),
-            Err(e) => TemporalResult::range_error(&format!("Failed to format: {}", e)),
-        },
-        Err(e) => TemporalResult::range_error(&format!("Failed to round: {}", e)),
+    match one.until(&two, options) {
+        Ok(d) => TemporalResult::success(d.to_string()),
+        Err(e) => TemporalResult::range_error(&format!("Failed to compute difference: {}", e)),
     }
 }
 
-/// Converts to Instant.
+/// Computes the difference between two PlainTimes (since) using coded options.
 #[no_mangle]
-pub extern "C" fn temporal_zoned_date_time_to_instant(s: *const c_char) -> TemporalResult {
-    let zdt = match parse_zoned_date_time(s, "zoned date time") {
-        Ok(z) => z,
+pub extern "C" fn temporal_plain_time_since_coded(
+    one_str: *const c_char,
+    two_str: *const c_char,
+    largest_unit: i32,
+    smallest_unit: i32,
+    rounding_increment: i64,
+    rounding_mode: i32,
+) -> TemporalResult {
+    let one = match parse_plain_time(one_str, "first plain time") {
+        Ok(t) => t,
         Err(e) => return e,
     };
-    let provider = &*COMPILED_TZ_PROVIDER;
-    match zdt.to_instant().to_ixdtf_string_with_provider(None, Default::default(), &provider) {
-        Ok(s) => TemporalResult::success(s),
-        Err(e) => TemporalResult::range_error(&format!("Failed to convert to instant: {}", e)),
+    let two = match parse_plain_time(two_str, "second plain time") {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    let options = match difference_settings_from_codes(largest_unit, smallest_unit, rounding_increment, rounding_mode) {
+        Ok(o) => o,
+        Err(e) => return e,
+    };
+
+    match one.since(&two, options) {
+        Ok(d) => TemporalResult::success(d.to_string()),
+        Err(e) => TemporalResult::range_error(&format!("Failed to compute difference: {}", e)),
     }
 }
 
-/// Converts to PlainDate.
+/// Computes the difference between two PlainDates (until) using coded options.
 #[no_mangle]
-pub extern "C" fn temporal_zoned_date_time_to_plain_date(s: *const c_char) -> TemporalResult {
-    let zdt = match parse_zoned_date_time(s, "zoned date time") {
-        Ok(z) => z,
+pub extern "C" fn temporal_plain_date_until_coded(
+    one_str: *const c_char,
+    two_str: *const c_char,
+    largest_unit: i32,
+    smallest_unit: i32,
+    rounding_increment: i64,
+    rounding_mode: i32,
+) -> TemporalResult {
+    let codes = (largest_unit, smallest_unit, rounding_increment, rounding_mode);
+    difference_coded(one_str, two_str, parse_plain_date, "plain date", codes, |a, b, options| a.until(b, options))
+}
+
+/// Computes the difference between two PlainDates (since) using coded options.
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_since_coded(
+    one_str: *const c_char,
+    two_str: *const c_char,
+    largest_unit: i32,
+    smallest_unit: i32,
+    rounding_increment: i64,
+    rounding_mode: i32,
+) -> TemporalResult {
+    let codes = (largest_unit, smallest_unit, rounding_increment, rounding_mode);
+    difference_coded(one_str, two_str, parse_plain_date, "plain date", codes, |a, b, options| a.since(b, options))
+}
+
+/// Computes the difference between two PlainDateTimes (until) using coded options.
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_time_until_coded(
+    one_str: *const c_char,
+    two_str: *const c_char,
+    largest_unit: i32,
+    smallest_unit: i32,
+    rounding_increment: i64,
+    rounding_mode: i32,
+) -> TemporalResult {
+    let codes = (largest_unit, smallest_unit, rounding_increment, rounding_mode);
+    difference_coded(one_str, two_str, parse_plain_date_time, "plain date time", codes, |a, b, options| a.until(b, options))
+}
+
+/// Computes the difference between two PlainDateTimes (since) using coded options.
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_time_since_coded(
+    one_str: *const c_char,
+    two_str: *const c_char,
+    largest_unit: i32,
+    smallest_unit: i32,
+    rounding_increment: i64,
+    rounding_mode: i32,
+) -> TemporalResult {
+    let codes = (largest_unit, smallest_unit, rounding_increment, rounding_mode);
+    difference_coded(one_str, two_str, parse_plain_date_time, "plain date time", codes, |a, b, options| a.since(b, options))
+}
+
+/// Computes the difference between two PlainYearMonths (until) using coded options.
+#[no_mangle]
+pub extern "C" fn temporal_plain_year_month_until_coded(
+    one_str: *const c_char,
+    two_str: *const c_char,
+    largest_unit: i32,
+    smallest_unit: i32,
+    rounding_increment: i64,
+    rounding_mode: i32,
+) -> TemporalResult {
+    let codes = (largest_unit, smallest_unit, rounding_increment, rounding_mode);
+    difference_coded(one_str, two_str, parse_plain_year_month, "plain year month", codes, |a, b, options| a.until(b, options))
+}
+
+/// Computes the difference between two PlainYearMonths (since) using coded options.
+#[no_mangle]
+pub extern "C" fn temporal_plain_year_month_since_coded(
+    one_str: *const c_char,
+    two_str: *const c_char,
+    largest_unit: i32,
+    smallest_unit: i32,
+    rounding_increment: i64,
+    rounding_mode: i32,
+) -> TemporalResult {
+    let codes = (largest_unit, smallest_unit, rounding_increment, rounding_mode);
+    difference_coded(one_str, two_str, parse_plain_year_month, "plain year month", codes, |a, b, options| a.since(b, options))
+}
+
+/// Computes the difference between two ZonedDateTimes (until) using coded options.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_until_coded(
+    one_str: *const c_char,
+    two_str: *const c_char,
+    largest_unit: i32,
+    smallest_unit: i32,
+    rounding_increment: i64,
+    rounding_mode: i32,
+) -> TemporalResult {
+    let codes = (largest_unit, smallest_unit, rounding_increment, rounding_mode);
+    difference_coded(one_str, two_str, parse_zoned_date_time, "zoned date time", codes, |a, b, options| a.until(b, options))
+}
+
+/// Computes the difference between two ZonedDateTimes (since) using coded options.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_since_coded(
+    one_str: *const c_char,
+    two_str: *const c_char,
+    largest_unit: i32,
+    smallest_unit: i32,
+    rounding_increment: i64,
+    rounding_mode: i32,
+) -> TemporalResult {
+    let codes = (largest_unit, smallest_unit, rounding_increment, rounding_mode);
+    difference_coded(one_str, two_str, parse_zoned_date_time, "zoned date time", codes, |a, b, options| a.since(b, options))
+}
+
+/// Shared body of the coded until/since functions: parses both operands, decodes the
+/// options and formats the resulting Duration. Unset options take the spec defaults.
+fn difference_coded<T>(
+    one_str: *const c_char,
+    two_str: *const c_char,
+    parse: fn(*const c_char, &str) -> Result<T, TemporalResult>,
+    kind: &str,
+    (largest_unit, smallest_unit, rounding_increment, rounding_mode): (i32, i32, i64, i32),
+    op: impl FnOnce(&T, &T, temporal_rs::options::DifferenceSettings) -> Result<Duration, TemporalError>,
+) -> TemporalResult {
+    let one = match parse(one_str, &format!("first {}", kind)) {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    let two = match parse(two_str, &format!("second {}", kind)) {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    let options = match difference_settings_from_codes(largest_unit, smallest_unit, rounding_increment, rounding_mode) {
+        Ok(o) => o,
         Err(e) => return e,
     };
-    TemporalResult::success(zdt.to_plain_date().to_ixdtf_string(DisplayCalendar::Auto))
+
+    match op(&one, &two, options) {
+        Ok(d) => TemporalResult::success(d.to_string()),
+        Err(e) => TemporalResult::range_error(&format!("Failed to compute difference: {}", e)),
+    }
 }
 
-/// Converts to PlainTime.
+/// Rounds the PlainTime using coded options. smallest_unit is required.
 #[no_mangle]
-pub extern "C" fn temporal_zoned_date_time_to_plain_time(s: *const c_char) -> TemporalResult {
-    let zdt = match parse_zoned_date_time(s, "zoned date time") {
-        Ok(z) => z,
+pub extern "C" fn temporal_plain_time_round_coded(
+    time_str: *const c_char,
+    smallest_unit: i32,
+    rounding_increment: i64,
+    rounding_mode: i32,
+) -> TemporalResult {
+    let time = match parse_plain_time(time_str, "plain time") {
+        Ok(t) => t,
         Err(e) => return e,
     };
-    match zdt.to_plain_time().to_ixdtf_string(ToStringRoundingOptions::default()) {
-        Ok(s) => TemporalResult::success(s),
-        Err(e) => TemporalResult::range_error(&format!("Failed to convert to plain time: {}", e)),
+    let options = match rounding_options_from_codes(smallest_unit, rounding_increment, rounding_mode, IncrementContext::Round) {
+        Ok(o) => o,
+        Err(e) => return e,
+    };
+
+    match time.round(options) {
+        Ok(t) => match t.to_ixdtf_string(display::rounding()) {
+            Ok(s) => TemporalResult::success(s),
+            Err(e) => TemporalResult::range_error(&format!("Failed to format plain time: {}", e)),
+        },
+        Err(e) => TemporalResult::range_error(&format!("Failed to round: {}", e)),
     }
 }
 
-/// Converts to PlainDateTime.
+/// Rounds the ZonedDateTime using coded options. smallest_unit is required.
 #[no_mangle]
-pub extern "C" fn temporal_zoned_date_time_to_plain_date_time(s: *const c_char) -> TemporalResult {
-    let zdt = match parse_zoned_date_time(s, "zoned date time") {
+pub extern "C" fn temporal_zoned_date_time_round_coded(
+    zdt_str: *const c_char,
+    smallest_unit: i32,
+    rounding_increment: i64,
+    rounding_mode: i32,
+) -> TemporalResult {
+    let zdt = match parse_zoned_date_time(zdt_str, "zoned date time") {
         Ok(z) => z,
         Err(e) => return e,
     };
-    match zdt.to_plain_date_time().to_ixdtf_string(ToStringRoundingOptions::default(), DisplayCalendar::Auto) {
-        Ok(s) => TemporalResult::success(s),
-        Err(e) => TemporalResult::range_error(&format!("Failed to convert to plain date time: {}", e)),
+    let options = match rounding_options_from_codes(smallest_unit, rounding_increment, rounding_mode, IncrementContext::Round) {
+        Ok(o) => o,
+        Err(e) => return e,
+    };
+
+    match zdt.round(options) {
+        Ok(result) => match result.to_ixdtf_string(display::offset(), display::time_zone(), display::calendar(), display::rounding()) {
+            Ok(s) => TemporalResult::success(s),
+            Err(e) => TemporalResult::range_error(&format!("Failed to format: {}", e)),
+        },
+        Err(e) => TemporalResult::range_error(&format!("Failed to round: {}", e)),
     }
 }
 
-// Helper functions for ZonedDateTime/TimeZone
-fn parse_time_zone(s: *const c_char, param_name: &str) -> Result<TimeZone, TemporalResult> {
-    let str_val = parse_c_str(s, param_name)?;
-    TimeZone::try_from_str(str_val)
-        .map_err(|e| TemporalResult::range_error(&format!("Invalid timezone '{}': {}", str_val, e)))
-}
+/// Gets the Instant for a PlainDateTime in a timezone using a coded disambiguation.
+/// TEMPORAL_OPTION_UNSET selects "compatible".
+#[no_mangle]
+pub extern "C" fn temporal_time_zone_get_instant_for_coded(
+    tz_id: *const c_char,
+    dt_str: *const c_char,
+    disambiguation: i32,
+) -> TemporalResult {
+    let tz = match parse_time_zone(tz_id, "timezone") {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    let dt = match parse_plain_date_time(dt_str, "plain date time") {
+        Ok(d) => d,
+        Err(e) => return e,
+    };
+    let disambig_enum = match option_from_code(disambiguation, &DISAMBIGUATION_CODES, "disambiguation") {
+        Ok(d) => d.unwrap_or(Disambiguation::Compatible),
+        Err(e) => return e,
+    };
 
-fn parse_zoned_date_time(s: *const c_char, param_name: &str) -> Result<ZonedDateTime, TemporalResult> {
-    let str_val = parse_c_str(s, param_name)?;
-    ZonedDateTime::from_utf8(str_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject)
-        .map_err(|e| TemporalResult::range_error(&format!("Invalid zoned date time '{}': {}", str_val, e)))
+    match dt.to_zoned_date_time(tz, disambig_enum) {
+        Ok(zdt) => {
+            let provider = &*COMPILED_TZ_PROVIDER;
+            match zdt.to_instant().to_ixdtf_string_with_provider(None, display::rounding(), &provider) {
+                Ok(s) => TemporalResult::success(s),
+                Err(e) => TemporalResult::range_error(&format!("Failed to format instant: {}", e)),
+            }
+        },
+        Err(e) => TemporalResult::range_error(&format!("Failed to get instant: {}", e)),
+    }
 }
 
-#[cfg(target_os = "android")]
+fn option_from_code<T: Copy>(code: i32, table: &[T], param_name: &str) -> Result<Option<T>, TemporalResult> {
+    if code == TEMPORAL_OPTION_UNSET {
+        return Ok(None);
+    }
+    usize::try_from(code)
+        .ok()
+        .and_then(|index| table.get(index))
+        .map(|value| Some(*value))
+        .ok_or_else(|| TemporalResult::range_error(&format!("Invalid {} code: {}", param_name, code)))
+}
 
-mod android {
-    use jni::objects::{JClass, JString};
-    use jni::sys::{jint, jlong, jlongArray, jstring};
-    use jni::JNIEnv;
+/// Which operation a rounding increment is for; the spec bounds it differently for each.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum IncrementContext {
+    /// Instant.round: the increment must divide a whole day of the unit.
+    InstantRound,
+    /// PlainTime, PlainDateTime and ZonedDateTime round: it must divide the next larger
+    /// unit, and days allow only 1.
+    Round,
+    /// until/since: as Round for time units; days and calendar units are unbounded.
+    Difference,
+}
 
-    use super::{
-        get_instant_now_string, get_now_plain_date_string, get_now_plain_date_time_string,
-        get_now_plain_time_string, get_now_zoned_date_time_string,
+/// Checks a rounding increment the way the spec does for `smallest_unit`, so 7 minutes
+/// or 5 hours are a RangeError instead of reaching the rounding code. 0 stands for the
+/// default of 1, since the C and JNI arguments have no "undefined"; a negative increment
+/// is an error rather than silently becoming 1.
+pub(crate) fn validate_rounding_increment(
+    value: i64,
+    smallest_unit: Option<Unit>,
+    context: IncrementContext,
+) -> Result<RoundingIncrement, String> {
+    if value < 0 {
+        return Err(format!("roundingIncrement must be positive, got {}", value));
+    }
+    let increment = u32::try_from(value.max(1))
+        .ok()
+        .and_then(|i| RoundingIncrement::try_new(i).ok())
+        .ok_or_else(|| format!("roundingIncrement {} is out of range (1 to 1000000000)", value))?;
+    let Some(unit) = smallest_unit else {
+        return Ok(increment);
     };
-    use temporal_rs::{
-        options::{DisplayCalendar, ToStringRoundingOptions, Overflow, DisplayOffset, DisplayTimeZone, Disambiguation, OffsetDisambiguation, Unit, RoundingMode, RoundingIncrement, RoundingOptions},
-        provider::{TransitionDirection, TimeZoneProvider, COMPILED_TZ_PROVIDER},
-        Calendar, Duration, Instant, PlainDate, PlainDateTime, PlainMonthDay, PlainTime,
-        PlainYearMonth, TimeZone, ZonedDateTime, TemporalError,
+    let (maximum, inclusive): (u64, bool) = match (context, unit) {
+        (IncrementContext::InstantRound, _) => match unit.as_nanoseconds() {
+            Some(ns) => ((86_400_000_000_000 / ns.get()) as u64, true),
+            None => return Ok(increment),
+        },
+        (_, Unit::Hour) => (24, false),
+        (_, Unit::Minute | Unit::Second) => (60, false),
+        (_, Unit::Millisecond | Unit::Microsecond | Unit::Nanosecond) => (1000, false),
+        (IncrementContext::Round, Unit::Day) => (1, true),
+        _ => return Ok(increment),
     };
-    use std::str::FromStr;
-    use std::ptr;
-
-
-    
-    const RANGE_ERROR_CLASS: &str = "java/lang/RuntimeException";
-    const TYPE_ERROR_CLASS: &str = "java/lang/RuntimeException";
-
-    /// Throws a RangeError exception
-    fn throw_range_error(env: &mut JNIEnv, message: &str) {
-        let _ = env.throw_new(RANGE_ERROR_CLASS, &format!("[RangeError] {}", message));
+    let n = u64::from(increment.get());
+    if n > maximum || (n == maximum && !inclusive) {
+        let largest = if inclusive { maximum } else { maximum - 1 };
+        return Err(format!("roundingIncrement {} is too large for {}; the maximum is {}", n, unit_name(unit), largest));
     }
-
-    /// Throws a TypeError exception
-    fn throw_type_error(env: &mut JNIEnv, message: &str) {
-        let _ = env.throw_new(TYPE_ERROR_CLASS, &format!("[TypeError] {}", message));
+    if maximum % n != 0 {
+        return Err(format!("roundingIncrement {} does not divide {} evenly for {}", n, maximum, unit_name(unit)));
     }
+    Ok(increment)
+}
 
-    /// Parses a JNI string, throwing TypeError if null or invalid
-    fn parse_jstring(env: &mut JNIEnv, s: &JString, name: &str) -> Option<String> {
-        if s.is_null() {
-            throw_type_error(env, &format!("{} cannot be null", name));
-            return None;
-        }
-        match env.get_string(s) {
-            Ok(js) => Some(js.to_string_lossy().into_owned()),
-            Err(_) => {
-                throw_type_error(env, &format!("Invalid UTF-8 in {}", name));
-                None
-            }
+fn rounding_increment_from_i64(
+    rounding_increment: i64,
+    smallest_unit: Option<Unit>,
+    context: IncrementContext,
+) -> Result<RoundingIncrement, TemporalResult> {
+    validate_rounding_increment(rounding_increment, smallest_unit, context).map_err(|e| TemporalResult::range_error(&e))
+}
+
+fn difference_settings_from_codes(
+    largest_unit: i32,
+    smallest_unit: i32,
+    rounding_increment: i64,
+    rounding_mode: i32,
+) -> Result<temporal_rs::options::DifferenceSettings, TemporalResult> {
+    let mut options = temporal_rs::options::DifferenceSettings::default();
+    options.largest_unit = option_from_code(largest_unit, &UNIT_CODES, "largest unit")?;
+    options.smallest_unit = option_from_code(smallest_unit, &UNIT_CODES, "smallest unit")?;
+    options.rounding_mode = option_from_code(rounding_mode, &ROUNDING_MODE_CODES, "rounding mode")?;
+    options.increment = Some(rounding_increment_from_i64(rounding_increment, options.smallest_unit, IncrementContext::Difference)?);
+    Ok(options)
+}
+
+/// Builds difference settings from the string-option form; NULL leaves an option unset.
+fn difference_settings_from_strings(
+    largest_unit: *const c_char,
+    smallest_unit: *const c_char,
+    rounding_increment: i64,
+    rounding_mode: *const c_char,
+) -> Result<temporal_rs::options::DifferenceSettings, TemporalResult> {
+    let parse_unit = |ptr: *const c_char, name: &str| -> Result<Option<Unit>, TemporalResult> {
+        if ptr.is_null() {
+            return Ok(None);
         }
+        let s = parse_c_str(ptr, name)?;
+        Unit::from_str(s)
+            .map(Some)
+            .map_err(|_| TemporalResult::range_error(&format!("Invalid {}: {}", name, s)))
+    };
+    let mut options = temporal_rs::options::DifferenceSettings::default();
+    options.largest_unit = parse_unit(largest_unit, "largest unit")?;
+    options.smallest_unit = parse_unit(smallest_unit, "smallest unit")?;
+    if !rounding_mode.is_null() {
+        let s = parse_c_str(rounding_mode, "rounding mode")?;
+        let mode = RoundingMode::from_str(s)
+            .map_err(|_| TemporalResult::range_error(&format!("Invalid rounding mode: {}", s)))?;
+        options.rounding_mode = Some(mode);
     }
+    options.increment = Some(rounding_increment_from_i64(rounding_increment, options.smallest_unit, IncrementContext::Difference)?);
+    Ok(options)
+}
 
-    /// Parses a duration string, throwing RangeError if invalid
-    fn parse_duration(env: &mut JNIEnv, s: &JString, name: &str) -> Option<Duration> {
-        let s_str = parse_jstring(env, s, name)?;
-        match Duration::from_str(&s_str) {
-            Ok(d) => Some(d),
-            Err(e) => {
-                throw_range_error(env, &format!("Invalid duration '{}': {}", s_str, e));
-                None
-            }
+fn rounding_options_from_codes(
+    smallest_unit: i32,
+    rounding_increment: i64,
+    rounding_mode: i32,
+    context: IncrementContext,
+) -> Result<RoundingOptions, TemporalResult> {
+    let unit = match option_from_code(smallest_unit, &UNIT_CODES, "smallest unit")? {
+        Some(u) => u,
+        None => return Err(TemporalResult::type_error("smallestUnit is required")),
+    };
+    let mode = option_from_code(rounding_mode, &ROUNDING_MODE_CODES, "rounding mode")?
+        .unwrap_or(RoundingMode::HalfExpand);
+
+    let mut options = RoundingOptions::default();
+    options.smallest_unit = Some(unit);
+    options.rounding_mode = Some(mode);
+    options.increment = Some(rounding_increment_from_i64(rounding_increment, Some(unit), context)?);
+    Ok(options)
+}
+
+// ============================================================================
+// Option structs
+// ============================================================================
+//
+// Per-call options passed by pointer instead of as trailing scalar arguments. Every
+// struct starts with `size`, which callers set to the `sizeof` they were compiled
+// against: fields beyond it keep their defaults, so new options can be appended
+// without changing any signature. A NULL pointer uses all defaults.
+
+/// Options for `temporal_zoned_date_time_from_components_with_options`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct ZdtFromOptions {
+    pub size: u32,
+    /// Disambiguation code; TEMPORAL_OPTION_UNSET is "compatible".
+    pub disambiguation: i32,
+    /// Offset option code; TEMPORAL_OPTION_UNSET is "reject".
+    pub offset: i32,
+    /// Non-zero if `offset_nanoseconds` is the expected UTC offset.
+    pub has_offset: i8,
+    pub offset_nanoseconds: i64,
+}
+
+impl Default for ZdtFromOptions {
+    fn default() -> Self {
+        Self {
+            size: std::mem::size_of::<Self>() as u32,
+            disambiguation: TEMPORAL_OPTION_UNSET,
+            offset: TEMPORAL_OPTION_UNSET,
+            has_offset: 0,
+            offset_nanoseconds: 0,
         }
     }
+}
 
-    /// Parses an instant string, throwing RangeError if invalid
-    fn parse_instant(env: &mut JNIEnv, s: &JString, name: &str) -> Option<Instant> {
-        let s_str = parse_jstring(env, s, name)?;
-        match Instant::from_str(&s_str) {
-            Ok(i) => Some(i),
-            Err(e) => {
-                throw_range_error(env, &format!("Invalid instant '{}': {}", s_str, e));
-                None
-            }
+/// Options for the until/since `_with_options` functions, as in the coded variants.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct DiffOptions {
+    pub size: u32,
+    pub largest_unit: i32,
+    pub smallest_unit: i32,
+    pub rounding_mode: i32,
+    /// 0 is 1; negative is a RangeError.
+    pub rounding_increment: i64,
+}
+
+impl Default for DiffOptions {
+    fn default() -> Self {
+        Self {
+            size: std::mem::size_of::<Self>() as u32,
+            largest_unit: TEMPORAL_OPTION_UNSET,
+            smallest_unit: TEMPORAL_OPTION_UNSET,
+            rounding_mode: TEMPORAL_OPTION_UNSET,
+            rounding_increment: 0,
         }
     }
+}
 
-    /// JNI function for `com.temporal.TemporalNative.instantNow()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_instantNow(
-        mut env: JNIEnv,
-        _class: JClass,
-    ) -> jstring {
-        match get_instant_now_string() {
-            Ok(s) => env
-                .new_string(s)
-                .map(|js| js.into_raw())
-                .unwrap_or_else(|_| {
-                    throw_range_error(&mut env, "Failed to create string");
-                    ptr::null_mut()
-                }),
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to get current instant: {}", e));
-                ptr::null_mut()
-            }
+/// Options for the round `_with_options` functions. smallest_unit is required.
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct RoundOptions {
+    pub size: u32,
+    pub smallest_unit: i32,
+    pub rounding_mode: i32,
+    /// 0 is 1; negative is a RangeError.
+    pub rounding_increment: i64,
+}
+
+impl Default for RoundOptions {
+    fn default() -> Self {
+        Self {
+            size: std::mem::size_of::<Self>() as u32,
+            smallest_unit: TEMPORAL_OPTION_UNSET,
+            rounding_mode: TEMPORAL_OPTION_UNSET,
+            rounding_increment: 0,
         }
     }
+}
 
-    /// JNI function for `com.temporal.TemporalNative.instantFromString()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_instantFromString(
-        mut env: JNIEnv,
-        _class: JClass,
-        s: JString,
-    ) -> jstring {
-        let instant = match parse_instant(&mut env, &s, "instant string") {
-            Some(i) => i,
-            None => return ptr::null_mut(),
-        };
-        let provider = &*COMPILED_TZ_PROVIDER;
-        match instant.to_ixdtf_string_with_provider(None, Default::default(), &provider) {
-            Ok(s) => env
-                .new_string(s)
-                .map(|js| js.into_raw())
-                .unwrap_or(ptr::null_mut()),
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to format instant: {}", e));
-                ptr::null_mut()
-            }
-        }
+/// An option struct passed by pointer, whose leading `size` says which version of it
+/// the caller was built against.
+trait OptionStruct: Copy + Default {
+    /// The struct's size in each released version, oldest first. Versions only append
+    /// fields, so any of these sizes ends on a field boundary.
+    const VERSION_SIZES: &'static [usize];
+}
+
+impl OptionStruct for ZdtFromOptions {
+    const VERSION_SIZES: &'static [usize] = &[std::mem::size_of::<Self>()];
+}
+
+impl OptionStruct for DiffOptions {
+    const VERSION_SIZES: &'static [usize] = &[std::mem::size_of::<Self>()];
+}
+
+impl OptionStruct for RoundOptions {
+    const VERSION_SIZES: &'static [usize] = &[std::mem::size_of::<Self>()];
+}
+
+/// Copies the first `size` bytes of a caller's option struct over the defaults.
+/// A size larger than this library's struct comes from newer bindings and is rejected
+/// rather than silently ignoring options it cannot honour; any other size that is not
+/// one of the released versions would cut a field in half and is a TypeError.
+fn read_options<T: OptionStruct>(options: *const T, name: &str) -> Result<T, TemporalResult> {
+    let mut value = T::default();
+    if options.is_null() {
+        return Ok(value);
     }
+    // SAFETY: every option struct starts with its u32 size, and the caller guarantees
+    // the pointer is valid for that many bytes.
+    let size = unsafe { options.cast::<u32>().read_unaligned() } as usize;
+    if size > std::mem::size_of::<T>() {
+        return Err(TemporalResult::range_error(&format!(
+            "Invalid {} size {} (this library supports up to {})",
+            name,
+            size,
+            std::mem::size_of::<T>()
+        )));
+    }
+    if !T::VERSION_SIZES.contains(&size) {
+        return Err(TemporalResult::type_error(&format!(
+            "Invalid {} size {} (expected one of {:?})",
+            name,
+            size,
+            T::VERSION_SIZES
+        )));
+    }
+    // SAFETY: size is within both the caller's struct and `value`.
+    unsafe { ptr::copy_nonoverlapping(options.cast::<u8>(), (&mut value as *mut T).cast::<u8>(), size) };
+    Ok(value)
+}
 
-    /// JNI function for `com.temporal.TemporalNative.instantFromEpochMilliseconds()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_instantFromEpochMilliseconds(
-        mut env: JNIEnv,
-        _class: JClass,
-        ms: jlong,
-    ) -> jstring {
-        let ns = (ms as i128).saturating_mul(1_000_000);
-        match Instant::try_new(ns) {
-            Ok(instant) => {
-                let provider = &*COMPILED_TZ_PROVIDER;
-                match instant.to_ixdtf_string_with_provider(None, Default::default(), &provider) {
-                    Ok(s) => env
-                        .new_string(s)
-                        .map(|js| js.into_raw())
-                        .unwrap_or(ptr::null_mut()),
-                    Err(e) => {
-                        throw_range_error(&mut env, &format!("Failed to format instant: {}", e));
-                        ptr::null_mut()
-                    }
-                }
+/// Creates a ZonedDateTime from ISO components, like
+/// `temporal_zoned_date_time_from_components`, resolving ambiguous or conflicting
+/// local times with the given options.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_from_components_with_options(
+    year: i32,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    millisecond: u16,
+    microsecond: u16,
+    nanosecond: u16,
+    calendar_id: *const c_char,
+    time_zone_id: *const c_char,
+    options: *const ZdtFromOptions,
+) -> TemporalResult {
+    let options = match read_options(options, "ZdtFromOptions") {
+        Ok(o) => o,
+        Err(e) => return e,
+    };
+    let disambiguation = match option_from_code(options.disambiguation, &DISAMBIGUATION_CODES, "disambiguation") {
+        Ok(d) => d,
+        Err(e) => return e,
+    };
+    let offset_option = match option_from_code(options.offset, &OFFSET_DISAMBIGUATION_CODES, "offset") {
+        Ok(o) => o,
+        Err(e) => return e,
+    };
+    let calendar = if calendar_id.is_null() {
+        Calendar::default()
+    } else {
+        match parse_c_str(calendar_id, "calendar id") {
+            Ok(s) => match Calendar::from_str(s) {
+                Ok(c) => c,
+                Err(e) => return TemporalResult::range_error(&format!("Invalid calendar: {}", e)),
             },
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid epoch milliseconds: {}", e));
-                ptr::null_mut()
-            }
+            Err(e) => return e,
         }
+    };
+    if time_zone_id.is_null() {
+        return TemporalResult::type_error("Timezone ID is required");
     }
+    let tz = match parse_time_zone(time_zone_id, "timezone id") {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
 
-    /// JNI function for `com.temporal.TemporalNative.instantFromEpochNanoseconds()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_instantFromEpochNanoseconds(
-        mut env: JNIEnv,
-        _class: JClass,
-        ns_str: JString,
-    ) -> jstring {
-        let s_str = parse_jstring(&mut env, &ns_str, "nanoseconds string");
-        let s_val = match s_str {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        
-        let ns = match i128::from_str(&s_val) {
-            Ok(n) => n,
-            Err(_) => {
-                throw_range_error(&mut env, "Invalid nanoseconds string");
-                return ptr::null_mut();
-            }
-        };
-
-        match Instant::try_new(ns) {
-            Ok(instant) => {
-                let provider = &*COMPILED_TZ_PROVIDER;
-                match instant.to_ixdtf_string_with_provider(None, Default::default(), &provider) {
-                    Ok(s) => env
-                        .new_string(s)
-                        .map(|js| js.into_raw())
-                        .unwrap_or(ptr::null_mut()),
-                    Err(e) => {
-                        throw_range_error(&mut env, &format!("Failed to format instant: {}", e));
-                        ptr::null_mut()
-                    }
-                }
-            },
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid epoch nanoseconds: {}", e));
-                ptr::null_mut()
-            }
+    let mut partial = PartialZonedDateTime::new()
+        .with_calendar_fields(
+            temporal_rs::fields::CalendarFields::new()
+                .with_year(year)
+                .with_month(month)
+                .with_day(day),
+        )
+        .with_time(
+            PartialTime::new()
+                .with_hour(Some(hour))
+                .with_minute(Some(minute))
+                .with_second(Some(second))
+                .with_millisecond(Some(millisecond))
+                .with_microsecond(Some(microsecond))
+                .with_nanosecond(Some(nanosecond)),
+        )
+        .with_timezone(Some(tz));
+    if options.has_offset != 0 {
+        match utc_offset_from_nanoseconds(options.offset_nanoseconds) {
+            Ok(offset) => partial = partial.with_offset(offset),
+            Err(e) => return TemporalResult::range_error(&format!("Invalid offset: {}", e)),
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.instantEpochMilliseconds()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_instantEpochMilliseconds(
-        mut env: JNIEnv,
-        _class: JClass,
-        s: JString,
-    ) -> jstring {
-        let instant = match parse_instant(&mut env, &s, "instant") {
-            Some(i) => i,
-            None => return ptr::null_mut(),
-        };
-        let ms = instant.epoch_milliseconds();
-        env.new_string(ms.to_string())
-            .map(|js| js.into_raw())
-            .unwrap_or(ptr::null_mut())
+    let zdt = ZonedDateTime::from_partial(partial, Some(Overflow::Reject), disambiguation, offset_option)
+        .map(|zdt| zdt.with_calendar(calendar));
+    match zdt {
+        Ok(zdt) => match zdt.to_ixdtf_string(display::offset(), display::time_zone(), display::calendar(), display::rounding()) {
+            Ok(s) => TemporalResult::success(s),
+            Err(e) => TemporalResult::range_error(&format!("Failed to format zoned date time: {}", e)),
+        },
+        Err(e) => TemporalResult::range_error(&format!("Failed to create zoned date time: {}", e)),
     }
+}
 
-    /// JNI function for `com.temporal.TemporalNative.instantEpochNanoseconds()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_instantEpochNanoseconds(
-        mut env: JNIEnv,
-        _class: JClass,
-        s: JString,
-    ) -> jstring {
-        let instant = match parse_instant(&mut env, &s, "instant") {
-            Some(i) => i,
-            None => return ptr::null_mut(),
-        };
-        let ns = instant.epoch_nanoseconds();
-        env.new_string(ns.0.to_string())
-            .map(|js| js.into_raw())
-            .unwrap_or(ptr::null_mut())
+/// UtcOffset has no public nanosecond constructor, so go through its string form.
+fn utc_offset_from_nanoseconds(offset_ns: i64) -> Result<UtcOffset, TemporalError> {
+    let sign = if offset_ns < 0 { '-' } else { '+' };
+    let abs = offset_ns.unsigned_abs();
+    let seconds = abs / 1_000_000_000;
+    let text = format!(
+        "{}{:02}:{:02}:{:02}.{:09}",
+        sign,
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60,
+        abs % 1_000_000_000
+    );
+    UtcOffset::from_utf8(text.as_bytes())
+}
+
+/// Computes the difference between two Instants (until) using an options struct.
+#[no_mangle]
+pub extern "C" fn temporal_instant_until_with_options(
+    one_str: *const c_char,
+    two_str: *const c_char,
+    options: *const DiffOptions,
+) -> TemporalResult {
+    match read_options(options, "DiffOptions") {
+        Ok(o) => temporal_instant_until_coded(one_str, two_str, o.largest_unit, o.smallest_unit, o.rounding_increment, o.rounding_mode),
+        Err(e) => e,
     }
+}
 
-    /// JNI function for `com.temporal.TemporalNative.instantAdd()`
+/// Computes the difference between two Instants (since) using an options struct.
+#[no_mangle]
+pub extern "C" fn temporal_instant_since_with_options(
+    one_str: *const c_char,
+    two_str: *const c_char,
+    options: *const DiffOptions,
+) -> TemporalResult {
+    match read_options(options, "DiffOptions") {
+        Ok(o) => temporal_instant_since_coded(one_str, two_str, o.largest_unit, o.smallest_unit, o.rounding_increment, o.rounding_mode),
+        Err(e) => e,
+    }
+}
+
+/// Computes the difference between two PlainTimes (until) using an options struct.
+#[no_mangle]
+pub extern "C" fn temporal_plain_time_until_with_options(
+    one_str: *const c_char,
+    two_str: *const c_char,
+    options: *const DiffOptions,
+) -> TemporalResult {
+    match read_options(options, "DiffOptions") {
+        Ok(o) => temporal_plain_time_until_coded(one_str, two_str, o.largest_unit, o.smallest_unit, o.rounding_increment, o.rounding_mode),
+        Err(e) => e,
+    }
+}
+
+/// Computes the difference between two PlainTimes (since) using an options struct.
+#[no_mangle]
+pub extern "C" fn temporal_plain_time_since_with_options(
+    one_str: *const c_char,
+    two_str: *const c_char,
+    options: *const DiffOptions,
+) -> TemporalResult {
+    match read_options(options, "DiffOptions") {
+        Ok(o) => temporal_plain_time_since_coded(one_str, two_str, o.largest_unit, o.smallest_unit, o.rounding_increment, o.rounding_mode),
+        Err(e) => e,
+    }
+}
+
+/// Computes the difference between two PlainDates (until) using an options struct.
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_until_with_options(
+    one_str: *const c_char,
+    two_str: *const c_char,
+    options: *const DiffOptions,
+) -> TemporalResult {
+    match read_options(options, "DiffOptions") {
+        Ok(o) => temporal_plain_date_until_coded(one_str, two_str, o.largest_unit, o.smallest_unit, o.rounding_increment, o.rounding_mode),
+        Err(e) => e,
+    }
+}
+
+/// Computes the difference between two PlainDates (since) using an options struct.
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_since_with_options(
+    one_str: *const c_char,
+    two_str: *const c_char,
+    options: *const DiffOptions,
+) -> TemporalResult {
+    match read_options(options, "DiffOptions") {
+        Ok(o) => temporal_plain_date_since_coded(one_str, two_str, o.largest_unit, o.smallest_unit, o.rounding_increment, o.rounding_mode),
+        Err(e) => e,
+    }
+}
+
+/// Computes the difference between two PlainDateTimes (until) using an options struct.
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_time_until_with_options(
+    one_str: *const c_char,
+    two_str: *const c_char,
+    options: *const DiffOptions,
+) -> TemporalResult {
+    match read_options(options, "DiffOptions") {
+        Ok(o) => temporal_plain_date_time_until_coded(one_str, two_str, o.largest_unit, o.smallest_unit, o.rounding_increment, o.rounding_mode),
+        Err(e) => e,
+    }
+}
+
+/// Computes the difference between two PlainDateTimes (since) using an options struct.
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_time_since_with_options(
+    one_str: *const c_char,
+    two_str: *const c_char,
+    options: *const DiffOptions,
+) -> TemporalResult {
+    match read_options(options, "DiffOptions") {
+        Ok(o) => temporal_plain_date_time_since_coded(one_str, two_str, o.largest_unit, o.smallest_unit, o.rounding_increment, o.rounding_mode),
+        Err(e) => e,
+    }
+}
+
+/// Computes the difference between two PlainYearMonths (until) using an options struct.
+#[no_mangle]
+pub extern "C" fn temporal_plain_year_month_until_with_options(
+    one_str: *const c_char,
+    two_str: *const c_char,
+    options: *const DiffOptions,
+) -> TemporalResult {
+    match read_options(options, "DiffOptions") {
+        Ok(o) => temporal_plain_year_month_until_coded(one_str, two_str, o.largest_unit, o.smallest_unit, o.rounding_increment, o.rounding_mode),
+        Err(e) => e,
+    }
+}
+
+/// Computes the difference between two PlainYearMonths (since) using an options struct.
+#[no_mangle]
+pub extern "C" fn temporal_plain_year_month_since_with_options(
+    one_str: *const c_char,
+    two_str: *const c_char,
+    options: *const DiffOptions,
+) -> TemporalResult {
+    match read_options(options, "DiffOptions") {
+        Ok(o) => temporal_plain_year_month_since_coded(one_str, two_str, o.largest_unit, o.smallest_unit, o.rounding_increment, o.rounding_mode),
+        Err(e) => e,
+    }
+}
+
+/// Computes the difference between two ZonedDateTimes (until) using an options struct.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_until_with_options(
+    one_str: *const c_char,
+    two_str: *const c_char,
+    options: *const DiffOptions,
+) -> TemporalResult {
+    match read_options(options, "DiffOptions") {
+        Ok(o) => temporal_zoned_date_time_until_coded(one_str, two_str, o.largest_unit, o.smallest_unit, o.rounding_increment, o.rounding_mode),
+        Err(e) => e,
+    }
+}
+
+/// Computes the difference between two ZonedDateTimes (since) using an options struct.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_since_with_options(
+    one_str: *const c_char,
+    two_str: *const c_char,
+    options: *const DiffOptions,
+) -> TemporalResult {
+    match read_options(options, "DiffOptions") {
+        Ok(o) => temporal_zoned_date_time_since_coded(one_str, two_str, o.largest_unit, o.smallest_unit, o.rounding_increment, o.rounding_mode),
+        Err(e) => e,
+    }
+}
+
+/// Rounds the Instant using an options struct.
+#[no_mangle]
+pub extern "C" fn temporal_instant_round_with_options(instant_str: *const c_char, options: *const RoundOptions) -> TemporalResult {
+    match read_options(options, "RoundOptions") {
+        Ok(o) => temporal_instant_round_coded(instant_str, o.smallest_unit, o.rounding_increment, o.rounding_mode),
+        Err(e) => e,
+    }
+}
+
+/// Rounds the PlainTime using an options struct.
+#[no_mangle]
+pub extern "C" fn temporal_plain_time_round_with_options(time_str: *const c_char, options: *const RoundOptions) -> TemporalResult {
+    match read_options(options, "RoundOptions") {
+        Ok(o) => temporal_plain_time_round_coded(time_str, o.smallest_unit, o.rounding_increment, o.rounding_mode),
+        Err(e) => e,
+    }
+}
+
+/// Rounds the ZonedDateTime using an options struct.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_round_with_options(zdt_str: *const c_char, options: *const RoundOptions) -> TemporalResult {
+    match read_options(options, "RoundOptions") {
+        Ok(o) => temporal_zoned_date_time_round_coded(zdt_str, o.smallest_unit, o.rounding_increment, o.rounding_mode),
+        Err(e) => e,
+    }
+}
+
+// ============================================================================
+// Android JNI bindings
+// ============================================================================
+
+
+// ============================================================================
+// TimeZone API
+// ============================================================================
+
+/// Gets a TimeZone from a string identifier.
+///
+/// With `lenient` non-zero, surrounding whitespace is trimmed and the identifier
+/// is case-folded before lookup; the result is always the case-normalized id.
+#[no_mangle]
+pub extern "C" fn temporal_time_zone_from_string(s: *const c_char, lenient: i8) -> TemporalResult {
+    let s_str = match parse_c_str(s, "timezone string") {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    let normalized;
+    let s_str = if lenient != 0 {
+        normalized = lenient_identifier(s_str);
+        normalized.as_str()
+    } else {
+        s_str
+    };
+    match TimeZone::try_from_str(s_str) {
+        Ok(tz) => match tz.identifier() {
+            Ok(id) => TemporalResult::success(id),
+            Err(e) => TemporalResult::range_error(&format!("Failed to get timezone id: {}", e)),
+        },
+        Err(e) => TemporalResult::range_error(&format!("Invalid timezone '{}': {}", s_str, e)),
+    }
+}
+
+/// Gets the identifier of a TimeZone.
+#[no_mangle]
+pub extern "C" fn temporal_time_zone_get_id(s: *const c_char) -> TemporalResult {
+    let s_str = match parse_c_str(s, "timezone string") {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    match TimeZone::try_from_str(s_str) {
+        Ok(tz) => match tz.identifier() {
+            Ok(id) => TemporalResult::success(id),
+            Err(e) => TemporalResult::range_error(&format!("Failed to get timezone id: {}", e)),
+        },
+        Err(e) => TemporalResult::range_error(&format!("Invalid timezone '{}': {}", s_str, e)),
+    }
+}
+
+/// Resolves a time zone identifier to its primary identifier, following IANA links
+/// (e.g. "Asia/Calcutta" -> "Asia/Kolkata", "US/Pacific" -> "America/Los_Angeles").
+///
+/// When `preserve_original` is non-zero the identifier is returned as given, only
+/// case-normalized, which is what Temporal keeps for display; links are still
+/// validated against the time zone database.
+#[no_mangle]
+pub extern "C" fn temporal_time_zone_canonicalize(id: *const c_char, preserve_original: i8) -> TemporalResult {
+    let tz = match parse_time_zone(id, "timezone id") {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    let resolved = if preserve_original != 0 { Ok(tz) } else { tz.primary_identifier() };
+    match resolved.and_then(|tz| tz.identifier()) {
+        Ok(id) => TemporalResult::success(id),
+        Err(e) => TemporalResult::range_error(&format!("Failed to canonicalize timezone: {}", e)),
+    }
+}
+
+/// Returns 1 if two time zones are equal per the spec's TimeZoneEquals, 0 if not, or
+/// -1 on error. Named zones are equal when their primary identifiers match, so "UTC",
+/// "Etc/UTC" and "GMT" are all equal; offset zones compare by offset. A named zone
+/// never equals an offset zone, even "UTC" and "+00:00".
+#[no_mangle]
+pub extern "C" fn temporal_time_zone_equals(a: *const c_char, b: *const c_char) -> i8 {
+    clear_last_error();
+    let tz_a = match parse_with_last_error(a, "first", "timezone", &TimeZone::try_from_str) {
+        Some(t) => t,
+        None => return -1,
+    };
+    let tz_b = match parse_with_last_error(b, "second", "timezone", &TimeZone::try_from_str) {
+        Some(t) => t,
+        None => return -1,
+    };
+    let equal = match (tz_a, tz_b) {
+        (TimeZone::UtcOffset(one), TimeZone::UtcOffset(two)) => Ok(one == two),
+        (TimeZone::IanaIdentifier(_), TimeZone::IanaIdentifier(_)) => {
+            let primary = |tz: TimeZone| tz.primary_identifier().and_then(|p| p.identifier());
+            primary(tz_a).and_then(|one| Ok(one == primary(tz_b)?))
+        }
+        _ => Ok(false),
+    };
+    match equal {
+        Ok(equal) => equal as i8,
+        Err(e) => {
+            set_last_error(TemporalErrorType::RangeError, format_args!("Failed to compare time zones: {}", e));
+            -1
+        }
+    }
+}
+
+/// Gets the offset nanoseconds for an instant in a timezone.
+#[no_mangle]
+pub extern "C" fn temporal_time_zone_get_offset_nanoseconds_for(
+    tz_id: *const c_char,
+    instant_str: *const c_char,
+) -> TemporalResult {
+    let tz = match parse_time_zone(tz_id, "timezone") {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    let instant = match parse_instant(instant_str, "instant") {
+        Ok(i) => i,
+        Err(e) => return e,
+    };
+
+    let provider = &*COMPILED_TZ_PROVIDER;
+    match ZonedDateTime::try_new(instant.epoch_nanoseconds().0, tz, Calendar::default()) {
+        Ok(zdt) => TemporalResult::success(zdt.offset_nanoseconds().to_string()),
+        Err(e) => TemporalResult::range_error(&format!("Failed to get offset: {}", e)),
+    }
+}
+
+/// Gets the offset string for an instant in a timezone.
+#[no_mangle]
+pub extern "C" fn temporal_time_zone_get_offset_string_for(
+    tz_id: *const c_char,
+    instant_str: *const c_char,
+) -> TemporalResult {
+    let tz = match parse_time_zone(tz_id, "timezone") {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    let instant = match parse_instant(instant_str, "instant") {
+        Ok(i) => i,
+        Err(e) => return e,
+    };
+
+    let provider = &*COMPILED_TZ_PROVIDER;
+    match ZonedDateTime::try_new(instant.epoch_nanoseconds().0, tz, Calendar::default()) {
+        Ok(zdt) => TemporalResult::success(zdt.offset().to_string()),
+        Err(e) => TemporalResult::range_error(&format!("Failed to get offset string: {}", e)),
+    }
+}
+
+/// Gets the PlainDateTime for an instant in a timezone.
+#[no_mangle]
+pub extern "C" fn temporal_time_zone_get_plain_date_time_for(
+    tz_id: *const c_char,
+    instant_str: *const c_char,
+    calendar_id: *const c_char,
+) -> TemporalResult {
+    let tz = match parse_time_zone(tz_id, "timezone") {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    let instant = match parse_instant(instant_str, "instant") {
+        Ok(i) => i,
+        Err(e) => return e,
+    };
+    
+    let calendar = if !calendar_id.is_null() {
+        match parse_c_str(calendar_id, "calendar id") {
+            Ok(s) => match Calendar::from_str(s) {
+                Ok(c) => c,
+                Err(e) => return TemporalResult::range_error(&format!("Invalid calendar: {}", e)),
+            },
+            Err(e) => return e,
+        }
+    } else {
+        Calendar::default()
+    };
+
+    match ZonedDateTime::try_new(instant.epoch_nanoseconds().0, tz, calendar) {
+        Ok(zdt) => match zdt.to_plain_date_time().to_ixdtf_string(display::rounding(), display::calendar()) {
+            Ok(s) => TemporalResult::success(s),
+            Err(e) => TemporalResult::range_error(&format!("Failed to format plain date time: {}", e)),
+        },
+        Err(e) => TemporalResult::range_error(&format!("Failed to get plain date time: {}", e)),
+    }
+}
+
+/// Gets the Instant for a PlainDateTime in a timezone.
+#[no_mangle]
+pub extern "C" fn temporal_time_zone_get_instant_for(
+    tz_id: *const c_char,
+    dt_str: *const c_char,
+    disambiguation: *const c_char,
+) -> TemporalResult {
+    let tz = match parse_time_zone(tz_id, "timezone") {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    let dt = match parse_plain_date_time(dt_str, "plain date time") {
+        Ok(d) => d,
+        Err(e) => return e,
+    };
+
+    let disambig_enum = if !disambiguation.is_null() {
+        match parse_c_str(disambiguation, "disambiguation") {
+            Ok(s) => match s {
+                "compatible" => Disambiguation::Compatible,
+                "earlier" => Disambiguation::Earlier,
+                "later" => Disambiguation::Later,
+                "reject" => Disambiguation::Reject,
+                _ => Disambiguation::Compatible,
+            },
+            Err(e) => return e,
+        }
+    } else {
+        Disambiguation::Compatible
+    };
+
+    match dt.to_zoned_date_time(tz, disambig_enum) {
+        Ok(zdt) => {
+             let instant = zdt.to_instant();
+             let provider = &*COMPILED_TZ_PROVIDER;
+             match instant.to_ixdtf_string_with_provider(None, display::rounding(), &provider) {
+                Ok(s) => TemporalResult::success(s),
+                Err(e) => TemporalResult::range_error(&format!("Failed to format instant: {}", e)),
+             }
+        },
+        Err(e) => TemporalResult::range_error(&format!("Failed to get instant: {}", e)),
+    }
+}
+
+/// Interprets a PlainDateTime in a time zone and returns the epoch nanoseconds
+/// (as string) in one call. A NULL disambiguation means "compatible".
+#[no_mangle]
+pub extern "C" fn temporal_plain_date_time_to_epoch_ns(
+    dt_str: *const c_char,
+    tz_id: *const c_char,
+    disambiguation: *const c_char,
+) -> TemporalResult {
+    let dt = match parse_plain_date_time(dt_str, "plain date time") {
+        Ok(d) => d,
+        Err(e) => return e,
+    };
+    let tz = match parse_time_zone(tz_id, "timezone") {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    let disambiguation = match parse_disambiguation(disambiguation) {
+        Ok(d) => d,
+        Err(e) => return e,
+    };
+
+    match dt.to_zoned_date_time(tz, disambiguation) {
+        Ok(zdt) => TemporalResult::success(zdt.epoch_nanoseconds().0.to_string()),
+        Err(e) => TemporalResult::range_error(&format!("Failed to get instant: {}", e)),
+    }
+}
+
+/// Compares a PlainDateTime interpreted in a time zone with an instant, e.g. to check
+/// that a picked local time is not in the past. Returns -1, 0 or 1 as the local time
+/// is before, at or after the instant. A NULL disambiguation means "compatible".
+#[no_mangle]
+pub extern "C" fn temporal_compare_pdt_in_zone_to_instant(
+    dt_str: *const c_char,
+    tz_id: *const c_char,
+    disambiguation: *const c_char,
+    instant_str: *const c_char,
+) -> CompareResult {
+    let dt = match parse_plain_date_time(dt_str, "plain date time") {
+        Ok(d) => d,
+        Err(e) => return CompareResult::from_error(e),
+    };
+    let tz = match parse_time_zone(tz_id, "timezone") {
+        Ok(t) => t,
+        Err(e) => return CompareResult::from_error(e),
+    };
+    let disambiguation = match parse_disambiguation(disambiguation) {
+        Ok(d) => d,
+        Err(e) => return CompareResult::from_error(e),
+    };
+    let instant = match parse_instant(instant_str, "instant") {
+        Ok(i) => i,
+        Err(e) => return CompareResult::from_error(e),
+    };
+
+    match dt.to_zoned_date_time(tz, disambiguation) {
+        Ok(zdt) => CompareResult::success(zdt.to_instant().cmp(&instant) as i32),
+        Err(e) => CompareResult::range_error(&format!("Failed to get instant: {}", e)),
+    }
+}
+
+/// NULL means "compatible"; anything else must be a Disambiguation value.
+fn parse_disambiguation(s: *const c_char) -> Result<Disambiguation, TemporalResult> {
+    if s.is_null() {
+        return Ok(Disambiguation::Compatible);
+    }
+    let s = parse_c_str(s, "disambiguation")?;
+    Disambiguation::from_str(s).map_err(|_| TemporalResult::range_error(&format!("Invalid disambiguation: {}", s)))
+}
+
+/// Gets the next transition instant.
+#[no_mangle]
+pub extern "C" fn temporal_time_zone_get_next_transition(
+    tz_id: *const c_char,
+    instant_str: *const c_char,
+) -> TemporalResult {
+    let tz = match parse_time_zone(tz_id, "timezone") {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    let instant = match parse_instant(instant_str, "instant") {
+        Ok(i) => i,
+        Err(e) => return e,
+    };
+
+    // TODO: Implement using provider directly when API is clear
+    match Ok::<Option<Instant>, TemporalError>(None) { // Stub
+        Ok(Some(i)) => {
+            let provider = &*COMPILED_TZ_PROVIDER;
+            match i.to_ixdtf_string_with_provider(None, display::rounding(), &provider) {
+                Ok(s) => TemporalResult::success(s),
+                Err(e) => TemporalResult::range_error(&format!("Failed to format instant: {}", e)),
+            }
+        },
+        Ok(None) => TemporalResult::success(String::new()),
+        Err(e) => TemporalResult::range_error(&format!("Failed to get next transition: {}", e)),
+    }
+}
+
+/// Gets the previous transition instant.
+#[no_mangle]
+pub extern "C" fn temporal_time_zone_get_previous_transition(
+    tz_id: *const c_char,
+    instant_str: *const c_char,
+) -> TemporalResult {
+    let tz = match parse_time_zone(tz_id, "timezone") {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    let instant = match parse_instant(instant_str, "instant") {
+        Ok(i) => i,
+        Err(e) => return e,
+    };
+
+    // TODO: Implement using provider directly
+    match Ok::<Option<Instant>, TemporalError>(None) {
+        Ok(Some(i)) => {
+            let provider = &*COMPILED_TZ_PROVIDER;
+            match i.to_ixdtf_string_with_provider(None, display::rounding(), &provider) {
+                Ok(s) => TemporalResult::success(s),
+                Err(e) => TemporalResult::range_error(&format!("Failed to format instant: {}", e)),
+            }
+        },
+        Ok(None) => TemporalResult::success(String::new()),
+        Err(e) => TemporalResult::range_error(&format!("Failed to get previous transition: {}", e)),
+    }
+}
+
+/// Returned by `temporal_time_zone_seconds_until_next_transition` on error.
+pub const TEMPORAL_SECONDS_UNTIL_TRANSITION_ERROR: i64 = i64::MIN;
+
+/// Returns the whole seconds from `instant_str` until the next UTC offset change in
+/// `tz_id`, or -1 if the zone has no later transition (fixed offsets, UTC, or zones
+/// that stopped observing DST).
+///
+/// Returns `TEMPORAL_SECONDS_UNTIL_TRANSITION_ERROR` on error; details are available
+/// via `temporal_last_error_type` and `temporal_last_error_message`.
+#[no_mangle]
+pub extern "C" fn temporal_time_zone_seconds_until_next_transition(
+    tz_id: *const c_char,
+    instant_str: *const c_char,
+) -> i64 {
+    clear_last_error();
+    let tz = match parse_with_last_error(tz_id, "transition", "time zone", &TimeZone::try_from_str) {
+        Some(t) => t,
+        None => return TEMPORAL_SECONDS_UNTIL_TRANSITION_ERROR,
+    };
+    let instant = match parse_with_last_error(instant_str, "transition", "instant", &Instant::from_str) {
+        Some(i) => i,
+        None => return TEMPORAL_SECONDS_UNTIL_TRANSITION_ERROR,
+    };
+
+    let start_ns = instant.epoch_nanoseconds().as_i128();
+    let next = ZonedDateTime::try_new(start_ns, tz, Calendar::default())
+        .and_then(|zdt| zdt.get_time_zone_transition(TransitionDirection::Next));
+    match next {
+        Ok(Some(transition)) => {
+            ((transition.epoch_nanoseconds().as_i128() - start_ns) / 1_000_000_000) as i64
+        }
+        Ok(None) => -1,
+        Err(e) => {
+            set_last_error(TemporalErrorType::RangeError, format_args!("Failed to get next transition: {}", e));
+            TEMPORAL_SECONDS_UNTIL_TRANSITION_ERROR
+        }
+    }
+}
+
+/// Time zone name formatters with their names loaded, by locale and style. Loading
+/// the names is most of the cost of a display name, and an app only asks for a few
+/// combinations; the map is emptied if it ever reaches the cap.
+#[cfg(feature = "locale-data")]
+static TIME_ZONE_FORMATTERS: std::sync::Mutex<std::collections::BTreeMap<(String, String), std::sync::Arc<TimeZoneFormatter>>> =
+    std::sync::Mutex::new(std::collections::BTreeMap::new());
+
+#[cfg(feature = "locale-data")]
+const MAX_TIME_ZONE_FORMATTERS: usize = 32;
+
+/// Returns the cached formatter for `locale` and `style`, loading it on first use.
+#[cfg(feature = "locale-data")]
+fn time_zone_formatter(locale: &icu_locale_core::Locale, style: &str) -> Result<std::sync::Arc<TimeZoneFormatter>, TemporalResult> {
+    let key = (locale.to_string(), style.to_string());
+    if let Some(formatter) = TIME_ZONE_FORMATTERS.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).get(&key) {
+        return Ok(formatter.clone());
+    }
+    let field_set = match style {
+        "long" => ZoneFieldSet::SpecificLong(zone::SpecificLong),
+        "short" => ZoneFieldSet::SpecificShort(zone::SpecificShort),
+        "longGeneric" => ZoneFieldSet::GenericLong(zone::GenericLong),
+        "shortGeneric" => ZoneFieldSet::GenericShort(zone::GenericShort),
+        "longOffset" => ZoneFieldSet::LocalizedOffsetLong(zone::LocalizedOffsetLong),
+        "shortOffset" => ZoneFieldSet::LocalizedOffsetShort(zone::LocalizedOffsetShort),
+        _ => return Err(TemporalResult::range_error(&format!(
+            "Invalid style: {} (expected long, short, longGeneric, shortGeneric, longOffset, or shortOffset)",
+            style
+        ))),
+    };
+    let formatter = match TimeZoneFormatter::try_new(locale.into(), field_set) {
+        Ok(f) => std::sync::Arc::new(f),
+        Err(e) => return Err(TemporalResult::range_error(&format!("Failed to load time zone names: {}", e))),
+    };
+    let mut formatters = TIME_ZONE_FORMATTERS.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+    if formatters.len() >= MAX_TIME_ZONE_FORMATTERS {
+        formatters.clear();
+    }
+    formatters.insert(key, formatter.clone());
+    Ok(formatter)
+}
+
+/// Returns a localized display name for a time zone, e.g. "Pacific Standard Time",
+/// "PT" or "GMT+2". `style` matches Intl.DateTimeFormat's timeZoneName option: "long",
+/// "short", "longGeneric", "shortGeneric", "longOffset" or "shortOffset".
+///
+/// `locale` is a BCP 47 tag (NULL = "en") and `instant` picks standard or daylight
+/// time (NULL = now). Names missing from the locale data fall back to the GMT format.
+#[cfg(feature = "locale-data")]
+#[no_mangle]
+pub extern "C" fn temporal_time_zone_display_name(
+    tz_id: *const c_char,
+    locale: *const c_char,
+    style: *const c_char,
+    instant: *const c_char,
+) -> TemporalResult {
+    let tz = match parse_time_zone(tz_id, "timezone") {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    let locale: icu_locale_core::Locale = if !locale.is_null() {
+        match parse_c_str(locale, "locale") {
+            Ok(s) => match s.parse() {
+                Ok(l) => l,
+                Err(e) => return TemporalResult::range_error(&format!("Invalid locale '{}': {}", s, e)),
+            },
+            Err(e) => return e,
+        }
+    } else {
+        icu_locale_core::locale!("en")
+    };
+    let style = match parse_c_str(style, "style") {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    let instant = if !instant.is_null() {
+        match parse_instant(instant, "instant") {
+            Ok(i) => i,
+            Err(e) => return e,
+        }
+    } else {
+        match Temporal::utc_now().instant() {
+            Ok(i) => i,
+            Err(e) => return TemporalResult::range_error(&format!("Failed to get current instant: {}", e)),
+        }
+    };
+
+    // icu4x only knows the CLDR spelling of each zone, so resolve aliases such as
+    // Asia/Calcutta and case differences to the primary identifier first
+    let canonical_id = match tz.primary_identifier().and_then(|primary| primary.identifier()) {
+        Ok(id) => id,
+        Err(e) => return TemporalResult::range_error(&format!("Failed to resolve time zone: {}", e)),
+    };
+    let zdt = match ZonedDateTime::try_new(instant.epoch_nanoseconds().0, tz, Calendar::default()) {
+        Ok(z) => z,
+        Err(e) => return TemporalResult::range_error(&format!("Failed to resolve time zone: {}", e)),
+    };
+    let formatter = match time_zone_formatter(&locale, style) {
+        Ok(f) => f,
+        Err(e) => return e,
+    };
+
+    // icu4x picks the metazone and standard or daylight name from the offset and
+    // the wall-clock time
+    let offset_seconds = (zdt.offset_nanoseconds() / 1_000_000_000) as i32;
+    let local = zdt.to_plain_date_time();
+    let date = match icu_calendar::Date::try_new_iso(local.year(), local.month(), local.day()) {
+        Ok(date) => date,
+        Err(e) => return TemporalResult::range_error(&format!("Failed to resolve time zone: {}", e)),
+    };
+    let time = match icu_time::Time::try_new(local.hour(), local.minute(), local.second(), 0) {
+        Ok(time) => time,
+        Err(e) => return TemporalResult::range_error(&format!("Failed to resolve time zone: {}", e)),
+    };
+    let icu_tz = icu_time::TimeZone::from_iana_id(&canonical_id)
+        .with_offset(icu_time::zone::UtcOffset::try_from_seconds(offset_seconds).ok())
+        .at_date_time(icu_time::DateTime { date, time });
+    TemporalResult::success(formatter.format(&icu_tz).to_string())
+}
+
+#[cfg(not(feature = "locale-data"))]
+#[no_mangle]
+pub extern "C" fn temporal_time_zone_display_name(
+    _tz_id: *const c_char,
+    _locale: *const c_char,
+    _style: *const c_char,
+    _instant: *const c_char,
+) -> TemporalResult {
+    TemporalResult::range_error("Time zone display names need the locale-data feature")
+}
+
+/// Lists the offsets a time zone uses in an ISO year and when they change, for
+/// "observes DST between X and Y" hints. The first line holds the distinct offsets,
+/// comma-separated in order of use (e.g. "+01:00,+02:00"); each following line is
+/// a transition within the year as a ZonedDateTime carrying the new offset, e.g.
+/// "2024-03-31T03:00:00+02:00[Europe/Warsaw]". Zones without DST return one line.
+#[no_mangle]
+pub extern "C" fn temporal_time_zone_offsets_for_year(tz_id: *const c_char, year: i32) -> TemporalResult {
+    let tz = match parse_time_zone(tz_id, "timezone") {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    let year_start = |year: i32| PlainDate::try_new_iso(year, 1, 1).and_then(|d| d.to_zoned_date_time(tz, None));
+    let (start, end) = match year_start(year).and_then(|start| Ok((start, year_start(year + 1)?))) {
+        Ok(bounds) => bounds,
+        Err(e) => return TemporalResult::range_error(&format!("Invalid year {}: {}", year, e)),
+    };
+
+    let mut offsets = vec![start.offset()];
+    let mut transitions = Vec::new();
+    let mut current = start;
+    loop {
+        let next = match current.get_time_zone_transition(TransitionDirection::Next) {
+            Ok(Some(next)) if next.epoch_nanoseconds().as_i128() < end.epoch_nanoseconds().as_i128() => next,
+            Ok(_) => break,
+            Err(e) => return TemporalResult::range_error(&format!("Failed to get next transition: {}", e)),
+        };
+        let offset = next.offset();
+        if !offsets.contains(&offset) {
+            offsets.push(offset);
+        }
+        match next.to_ixdtf_string(display::offset(), display::time_zone(), display::calendar(), display::rounding()) {
+            Ok(s) => transitions.push(s),
+            Err(e) => return TemporalResult::range_error(&format!("Failed to format transition: {}", e)),
+        }
+        current = next;
+    }
+
+    let mut lines = vec![offsets.join(",")];
+    lines.extend(transitions);
+    TemporalResult::success(lines.join("\n"))
+}
+
+/// Details returned alongside `temporal_time_zone_abbreviation`.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TimeZoneAbbreviationInfo {
+    /// The UTC offset in effect, which unlike the abbreviation is exact.
+    pub offset_nanoseconds: i64,
+    /// 1 if the zone is on daylight saving time.
+    pub is_dst: i8,
+    /// 1 if the time zone database has no abbreviation in use for the zone and the
+    /// designation only restates the offset ("+0545", "-03").
+    pub non_authoritative: i8,
+}
+
+/// Returns the time zone database's abbreviation for a zone at an instant, e.g.
+/// "PST", "CEST" or "IST", for logs and compact UIs. `instant` NULL means now, and
+/// `info` (which may be NULL) receives the exact offset and flags.
+///
+/// Abbreviations are informal and ambiguous: "IST" is India, Ireland and Israel,
+/// "CST" both US Central and China. Show them, but never parse them back or use
+/// them to pick a zone. Where the database lists none the designation is the
+/// offset itself and `non_authoritative` is set; fixed-offset zones get the same
+/// form.
+#[no_mangle]
+pub extern "C" fn temporal_time_zone_abbreviation(
+    tz_id: *const c_char,
+    instant: *const c_char,
+    info: *mut TimeZoneAbbreviationInfo,
+) -> TemporalResult {
+    let tz = match parse_time_zone(tz_id, "timezone") {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    let instant = if !instant.is_null() {
+        match parse_instant(instant, "instant") {
+            Ok(i) => i,
+            Err(e) => return e,
+        }
+    } else {
+        match Temporal::utc_now().instant() {
+            Ok(i) => i,
+            Err(e) => return TemporalResult::range_error(&format!("Failed to get current instant: {}", e)),
+        }
+    };
+    let epoch_ns = instant.epoch_nanoseconds().as_i128();
+    let offset_nanoseconds = match ZonedDateTime::try_new(epoch_ns, tz, Calendar::default()) {
+        Ok(zdt) => zdt.offset_nanoseconds(),
+        Err(e) => return TemporalResult::range_error(&format!("Failed to resolve time zone: {}", e)),
+    };
+    let offset_seconds = offset_nanoseconds / 1_000_000_000;
+    let designation = match tz {
+        TimeZone::IanaIdentifier(_) => match tz.identifier() {
+            Ok(id) => tzif_designation(&id, epoch_ns.div_euclid(1_000_000_000) as i64, offset_seconds),
+            Err(e) => Err(e.to_string()),
+        },
+        TimeZone::UtcOffset(_) => Ok((String::new(), false)),
+    };
+    let (mut abbreviation, is_dst) = match designation {
+        Ok(found) => found,
+        Err(e) => return TemporalResult::range_error(&format!("Failed to read time zone abbreviation: {}", e)),
+    };
+    let numeric = !abbreviation.chars().any(|c| c.is_ascii_alphabetic());
+    if abbreviation.is_empty() {
+        abbreviation = numeric_designation(offset_seconds);
+    }
+    if !info.is_null() {
+        unsafe {
+            *info = TimeZoneAbbreviationInfo {
+                offset_nanoseconds,
+                is_dst: is_dst as i8,
+                non_authoritative: numeric as i8,
+            };
+        }
+    }
+    TemporalResult::success(abbreviation)
+}
+
+/// Looks up the designation and DST flag in effect at `epoch_seconds` in the zone's
+/// TZif data: the transition table, then the POSIX rule in the footer for times
+/// after it.
+fn tzif_designation(id: &str, epoch_seconds: i64, offset_seconds: i64) -> Result<(String, bool), String> {
+    let (_, bytes) = jiff_tzdb::get(id).ok_or_else(|| format!("No TZif data for {}", id))?;
+    let tzif = timezone_provider::tzif::Tzif::from_bytes(bytes).map_err(|e| format!("{:?}", e))?;
+    let block = tzif.get_data_block2().map_err(|e| format!("{:?}", e))?;
+    let after = block.transition_times.partition_point(|t| t.0 <= epoch_seconds);
+    let record_index = match after {
+        // Before the first transition local time is type 0 (RFC 8536 section 3.2)
+        0 => Some(0),
+        n if n < block.transition_times.len() || tzif.footer.is_none() => block.transition_types.get(n - 1).copied(),
+        _ => None,
+    };
+    if let Some(index) = record_index {
+        let record = block.local_time_type_records.get(index).ok_or("Invalid TZif time type")?;
+        let designation = block.time_zone_designation(record.idx).ok_or("Invalid TZif designation")?;
+        return Ok((designation.to_string(), record.is_dst));
+    }
+    let rule = tzif.footer.as_ref().ok_or("Missing TZif footer")?;
+    // POSIX offsets count west of UTC, the opposite of ours
+    match &rule.dst_info {
+        Some(dst) if -dst.variant_info.offset.0 == offset_seconds => Ok((dst.variant_info.name.clone(), true)),
+        _ => Ok((rule.std_info.name.clone(), false)),
+    }
+}
+
+/// The tzdb style for zones without an abbreviation: "+05", "-0330", "+054500".
+fn numeric_designation(offset_seconds: i64) -> String {
+    let sign = if offset_seconds < 0 { '-' } else { '+' };
+    let total = offset_seconds.unsigned_abs();
+    let (hours, minutes, seconds) = (total / 3600, total / 60 % 60, total % 60);
+    match (minutes, seconds) {
+        (0, 0) => format!("{}{:02}", sign, hours),
+        (_, 0) => format!("{}{:02}{:02}", sign, hours, minutes),
+        _ => format!("{}{:02}{:02}{:02}", sign, hours, minutes, seconds),
+    }
+}
+
+// ============================================================================
+// ZonedDateTime API
+// ============================================================================
+
+/// Represents a ZonedDateTime's component values for FFI.
+#[repr(C)]
+pub struct ZonedDateTimeComponents {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+    pub day_of_week: u16,
+    pub day_of_year: u16,
+    pub week_of_year: u16,
+    pub year_of_week: i32,
+    pub days_in_week: u16,
+    pub days_in_month: u16,
+    pub days_in_year: u16,
+    pub months_in_year: u16,
+    pub in_leap_year: i8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    pub millisecond: u16,
+    pub microsecond: u16,
+    pub nanosecond: u16,
+    pub offset_nanoseconds: i64,
+    /// 1 when the calendar defines week numbering, 0 otherwise.
+    pub has_week_info: i8,
+    pub is_valid: i8,
+}
+
+impl Default for ZonedDateTimeComponents {
+    fn default() -> Self {
+        Self {
+            year: 0,
+            month: 0,
+            day: 0,
+            day_of_week: 0,
+            day_of_year: 0,
+            week_of_year: 0,
+            year_of_week: 0,
+            days_in_week: 0,
+            days_in_month: 0,
+            days_in_year: 0,
+            months_in_year: 0,
+            in_leap_year: 0,
+            hour: 0,
+            minute: 0,
+            second: 0,
+            millisecond: 0,
+            microsecond: 0,
+            nanosecond: 0,
+            offset_nanoseconds: 0,
+            has_week_info: 0,
+            is_valid: 0,
+        }
+    }
+}
+
+/// Parses an ISO 8601 string into a ZonedDateTime.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_from_string(
+    s: *const c_char,
+) -> TemporalResult {
+    let s_str = match parse_c_str(s, "zoned date time string") {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    
+    // Using default provider (TZDB)
+    let normalized = canonical_cache::canonical(Kind::ZonedDateTime, s_str, || {
+        match ZonedDateTime::from_utf8(s_str.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
+            Ok(zdt) => zdt
+                .to_ixdtf_string(display::offset(), display::time_zone(), display::calendar(), display::rounding())
+                .map_err(|e| TemporalResult::range_error(&format!("Failed to format zoned date time: {}", e))),
+            Err(e) => Err(TemporalResult::range_error(&format!("Invalid zoned date time '{}': {}", s_str, e))),
+        }
+    });
+    match normalized {
+        Ok(s) => TemporalResult::success(s),
+        Err(e) => e,
+    }
+}
+
+/// Formats a ZonedDateTime with an explicit `precision` (see
+/// `temporal_instant_to_string`) and `calendar_name` (see
+/// `temporal_plain_date_to_string`); the offset and time zone follow the default
+/// display options. NULL arguments use the defaults too.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_to_string(
+    s: *const c_char,
+    precision: *const c_char,
+    calendar_name: *const c_char,
+) -> TemporalResult {
+    let zdt = match parse_zoned_date_time(s, "zoned date time") {
+        Ok(z) => z,
+        Err(e) => return e,
+    };
+    let (rounding, calendar) = match parse_precision(precision).and_then(|r| Ok((r, parse_calendar_name(calendar_name)?))) {
+        Ok(options) => options,
+        Err(e) => return e,
+    };
+    let defaults = display::current();
+    match zdt.to_ixdtf_string(defaults.offset, defaults.time_zone, calendar, rounding) {
+        Ok(s) => TemporalResult::success(s),
+        Err(e) => TemporalResult::range_error(&format!("Failed to format zoned date time: {}", e)),
+    }
+}
+
+/// Creates a ZonedDateTime from components.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_from_components(
+    year: i32,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    millisecond: u16,
+    microsecond: u16,
+    nanosecond: u16,
+    calendar_id: *const c_char,
+    time_zone_id: *const c_char,
+    offset_nanoseconds: i64, // Optional offset for conflict resolution, 0 if ignored? 
+    // Spec: needs disambiguation options if offset is ignored/provided
+) -> TemporalResult {
+    // Constructing ZDT from components usually requires creating a PlainDateTime first, 
+    // then converting to ZDT with timezone and disambiguation.
+    
+    let calendar = if !calendar_id.is_null() {
+        match parse_c_str(calendar_id, "calendar id") {
+            Ok(s) => match Calendar::from_str(s) {
+                Ok(c) => c,
+                Err(e) => return TemporalResult::range_error(&format!("Invalid calendar: {}", e)),
+            },
+            Err(e) => return e,
+        }
+    } else {
+        Calendar::default()
+    };
+
+    let pdt = match PlainDateTime::new(
+        year, month, day, 
+        hour, minute, second, 
+        millisecond, microsecond, nanosecond, 
+        calendar
+    ) {
+        Ok(d) => d,
+        Err(e) => return TemporalResult::range_error(&format!("Invalid components: {}", e)),
+    };
+
+    let tz_str = if !time_zone_id.is_null() {
+        match parse_c_str(time_zone_id, "timezone id") {
+            Ok(s) => s,
+            Err(e) => return e,
+        }
+    } else {
+        return TemporalResult::type_error("Timezone ID is required");
+    };
+
+    let tz = match TimeZone::try_from_str(tz_str) {
+        Ok(t) => t,
+        Err(e) => return TemporalResult::range_error(&format!("Invalid timezone: {}", e)),
+    };
+
+    // We create ZDT from PDT + TZ. 
+    // TC39 `from` usually takes an object with components and options.
+    // Here we assume standard construction (compatible disambiguation).
+    
+    match pdt.to_zoned_date_time(tz, Disambiguation::Compatible) { // None = compatible/default
+        Ok(zdt) => match zdt.to_ixdtf_string(display::offset(), display::time_zone(), display::calendar(), display::rounding()) {
+            Ok(s) => TemporalResult::success(s),
+            Err(e) => TemporalResult::range_error(&format!("Failed to format zoned date time: {}", e)),
+        },
+        Err(e) => TemporalResult::range_error(&format!("Failed to create zoned date time: {}", e)),
+    }
+}
+
+/// Creates a ZonedDateTime from epoch milliseconds in the given timezone.
+/// A null calendar_id defaults to iso8601.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_from_epoch_ms(
+    ms: i64,
+    time_zone_id: *const c_char,
+    calendar_id: *const c_char,
+) -> TemporalResult {
+    let ns = (ms as i128).saturating_mul(1_000_000);
+    zoned_date_time_from_epoch_ns(ns, time_zone_id, calendar_id)
+}
+
+/// Creates a ZonedDateTime from epoch nanoseconds (string input for i128 precision)
+/// in the given timezone. A null calendar_id defaults to iso8601.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_from_epoch_ns(
+    ns_str: *const c_char,
+    time_zone_id: *const c_char,
+    calendar_id: *const c_char,
+) -> TemporalResult {
+    let s_str = match parse_c_str(ns_str, "nanoseconds string") {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+
+    let ns = match i128::from_str(s_str) {
+        Ok(n) => n,
+        Err(_) => return TemporalResult::range_error("Invalid nanoseconds string"),
+    };
+
+    zoned_date_time_from_epoch_ns(ns, time_zone_id, calendar_id)
+}
+
+/// Creates a ZonedDateTime from epoch nanoseconds given as `high * 2^64 + low`.
+/// A null calendar_id defaults to iso8601.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_from_epoch_ns_parts(
+    high: i64,
+    low: u64,
+    time_zone_id: *const c_char,
+    calendar_id: *const c_char,
+) -> TemporalResult {
+    zoned_date_time_from_epoch_ns(join_i128(high, low), time_zone_id, calendar_id)
+}
+
+/// Wall-clock time and offset of a ZonedDateTime, for clocks that refresh every second.
+#[repr(C)]
+#[derive(Debug, Default)]
+pub struct ClockFields {
+    pub offset_nanoseconds: i64,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+    pub is_valid: i8,
+}
+
+/// Fills `out` with the hour, minute, second and offset only, skipping the calendar
+/// and week computations of `temporal_zoned_date_time_get_components`.
+///
+/// With a NULL `tz_id`, `s` is a ZonedDateTime string. Otherwise `s` is epoch
+/// nanoseconds (decimal string) interpreted in `tz_id`. On error `is_valid` is 0.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_clock_fields(s: *const c_char, tz_id: *const c_char, out: *mut ClockFields) {
+    if out.is_null() {
+        return;
+    }
+    unsafe { *out = ClockFields::default() };
+
+    let (epoch_ns, offset_ns) = if tz_id.is_null() {
+        match parse_zoned_date_time(s, "zoned date time") {
+            Ok(zdt) => (zdt.epoch_nanoseconds().0, zdt.offset_nanoseconds()),
+            Err(_) => return,
+        }
+    } else {
+        let ns = match parse_c_str(s, "epoch nanoseconds").map(i128::from_str) {
+            Ok(Ok(ns)) => ns,
+            _ => return,
+        };
+        let tz = match parse_time_zone(tz_id, "timezone") {
+            Ok(t) => t,
+            Err(_) => return,
+        };
+        match ZonedDateTime::try_new(ns, tz, Calendar::default()) {
+            Ok(zdt) => (ns, zdt.offset_nanoseconds()),
+            Err(_) => return,
+        }
+    };
+
+    let second_of_day = (epoch_ns + offset_ns as i128).div_euclid(1_000_000_000).rem_euclid(86_400) as u32;
+    unsafe {
+        *out = ClockFields {
+            offset_nanoseconds: offset_ns,
+            hour: (second_of_day / 3600) as u8,
+            minute: (second_of_day / 60 % 60) as u8,
+            second: (second_of_day % 60) as u8,
+            is_valid: 1,
+        };
+    }
+}
+
+/// Fills `out` with the components of epoch nanoseconds (decimal string) seen in
+/// `to_tz`, in `calendar_id` (NULL = iso8601). This is `withTimeZone` for callers that
+/// already store the epoch: the source zone does not affect the result, so no source
+/// ZonedDateTime string has to be built or parsed. On error `is_valid` is 0.
+#[no_mangle]
+pub extern "C" fn temporal_convert_zone(
+    epoch_ns_str: *const c_char,
+    to_tz: *const c_char,
+    calendar_id: *const c_char,
+    out: *mut ZonedDateTimeComponents,
+) {
+    if out.is_null() {
+        return;
+    }
+    unsafe { *out = ZonedDateTimeComponents::default() };
+
+    let ns = match parse_c_str(epoch_ns_str, "epoch nanoseconds").map(i128::from_str) {
+        Ok(Ok(ns)) => ns,
+        _ => return,
+    };
+    let tz = match parse_time_zone(to_tz, "timezone") {
+        Ok(t) => t,
+        Err(_) => return,
+    };
+    let calendar = if calendar_id.is_null() {
+        Calendar::default()
+    } else {
+        match parse_c_str(calendar_id, "calendar id").map(Calendar::from_str) {
+            Ok(Ok(c)) => c,
+            _ => return,
+        }
+    };
+    if let Ok(zdt) = ZonedDateTime::try_new(ns, tz, calendar) {
+        unsafe { *out = zoned_components(&zdt) };
+    }
+}
+
+/// Fills `out` with the local components of one instant in several time zones, for
+/// world-clock views that would otherwise convert once per zone. `tz_ids` is a
+/// newline-delimited list; entry i of `out` belongs to the i-th non-blank id, in the
+/// ISO calendar, and has `is_valid` 0 for an id that does not resolve.
+///
+/// Returns the number of ids in the list. At most `out_len` entries are written, so
+/// a caller can size `out` from a first call with `out_len` 0. Returns 0 on error (a
+/// NULL, non-UTF-8 or too long list); details are available via
+/// `temporal_last_error_type` and `temporal_last_error_message`.
+#[no_mangle]
+pub extern "C" fn temporal_world_clock(
+    epoch_ns: i64,
+    tz_ids: *const c_char,
+    out: *mut ZonedDateTimeComponents,
+    out_len: usize,
+) -> usize {
+    clear_last_error();
+    let ids = match read_with_last_error(tz_ids, "world clock", "time zone ids", true) {
+        Some(s) => s,
+        None => return 0,
+    };
+    let entries = if out.is_null() {
+        &mut [][..]
+    } else {
+        unsafe { std::slice::from_raw_parts_mut(out, out_len) }
+    };
+    let mut count = 0;
+    for (index, id) in split_list(ids).enumerate() {
+        if let Some(entry) = entries.get_mut(index) {
+            let zdt = TimeZone::try_from_str(id).and_then(|tz| ZonedDateTime::try_new(epoch_ns as i128, tz, Calendar::default()));
+            *entry = match zdt {
+                Ok(zdt) => zoned_components(&zdt),
+                Err(_) => ZonedDateTimeComponents::default(),
+            };
+        }
+        count += 1;
+    }
+    count
+}
+
+/// Returns the milliseconds from `epoch_ms` until the local clock in `tz_id` next
+/// reaches a whole `unit` ("second", "minute", "hour" or "day"), so a ticking view can
+/// schedule its next render exactly. The boundary is always strictly in the future.
+///
+/// Epoch milliseconds are leap-second-free, so a second is always 1000 ms. Offset
+/// transitions count as boundaries for sub-day units, and a "day" ends at the next
+/// local start of day even when DST makes it 23 or 25 hours long.
+///
+/// Returns -1 on error; details are available via `temporal_last_error_type` and
+/// `temporal_last_error_message`.
+#[no_mangle]
+pub extern "C" fn temporal_ms_until_next_boundary(epoch_ms: i64, tz_id: *const c_char, unit: *const c_char) -> i64 {
+    clear_last_error();
+    let tz = match parse_with_last_error(tz_id, "boundary", "timezone", &TimeZone::try_from_str) {
+        Some(t) => t,
+        None => return -1,
+    };
+    let unit_str = match parse_with_last_error(unit, "boundary", "unit", &|s: &str| Ok::<_, TemporalError>(s.to_string())) {
+        Some(s) => s,
+        None => return -1,
+    };
+    let unit = match Unit::from_str(&unit_str) {
+        Ok(u @ (Unit::Second | Unit::Minute | Unit::Hour | Unit::Day)) => u,
+        _ => {
+            set_last_error(
+                TemporalErrorType::RangeError,
+                format_args!("Invalid unit: {} (expected second, minute, hour, or day)", unit_str),
+            );
+            return -1;
+        }
+    };
+    match next_boundary_epoch_ms(epoch_ms, tz, unit) {
+        Ok(next) => next - epoch_ms,
+        Err(e) => {
+            set_last_error(TemporalErrorType::RangeError, format_args!("Failed to find next boundary: {}", e));
+            -1
+        }
+    }
+}
+
+fn next_boundary_epoch_ms(epoch_ms: i64, tz: TimeZone, unit: Unit) -> Result<i64, TemporalError> {
+    let zdt = ZonedDateTime::try_new(epoch_ms as i128 * 1_000_000, tz, Calendar::default())?;
+    let unit_ms: i64 = match unit {
+        Unit::Day => {
+            let tomorrow = zdt.to_plain_date().add(&unit_duration(Unit::Day), None)?;
+            return Ok(tomorrow.to_zoned_date_time(tz, None)?.epoch_milliseconds());
+        }
+        Unit::Hour => 3_600_000,
+        Unit::Minute => 60_000,
+        _ => 1_000,
+    };
+    let local_ms = epoch_ms + zdt.offset_nanoseconds().div_euclid(1_000_000);
+    let next = epoch_ms + (unit_ms - local_ms.rem_euclid(unit_ms));
+    // An offset change moves the local clock, so the following boundary is relative to the new offset
+    match zdt.get_time_zone_transition(TransitionDirection::Next)? {
+        Some(transition) => Ok(next.min(transition.epoch_milliseconds())),
+        None => Ok(next),
+    }
+}
+
+/// Returned by `temporal_bucket_epoch_ms` on error.
+pub const TEMPORAL_BUCKET_ERROR: i64 = i64::MIN;
+
+/// Floors epoch milliseconds to the start of its `bucket_minutes` bucket (1-1440) on
+/// the local clock in `tz_id`, for chart aggregation: 15-minute buckets start at :00,
+/// :15, :30 and :45 local time, counted from each local midnight. An instant keeps
+/// its own offset, so buckets in a repeated DST hour stay apart.
+///
+/// Returns `TEMPORAL_BUCKET_ERROR` on error; details are available via
+/// `temporal_last_error_type` and `temporal_last_error_message`.
+#[no_mangle]
+pub extern "C" fn temporal_bucket_epoch_ms(epoch_ms: i64, tz_id: *const c_char, bucket_minutes: u32) -> i64 {
+    clear_last_error();
+    let tz = match parse_with_last_error(tz_id, "bucket", "timezone", &TimeZone::try_from_str) {
+        Some(t) => t,
+        None => return TEMPORAL_BUCKET_ERROR,
+    };
+    match bucket_start_ms(epoch_ms, tz, bucket_minutes) {
+        Ok(start) => start,
+        Err(e) => {
+            set_last_error(TemporalErrorType::RangeError, format_args!("Failed to bucket {}: {}", epoch_ms, e));
+            TEMPORAL_BUCKET_ERROR
+        }
+    }
+}
+
+/// Batch form of `temporal_bucket_epoch_ms`: writes the bucket start of each of the
+/// `len` values in `epoch_ms` to `out`, which may be the same array for in-place use.
+///
+/// Returns 0 on success or -1 on error, leaving the remaining entries unwritten; the
+/// last error message names the failing index.
+#[no_mangle]
+pub extern "C" fn temporal_bucket_epoch_ms_batch(
+    epoch_ms: *const i64,
+    len: usize,
+    tz_id: *const c_char,
+    bucket_minutes: u32,
+    out: *mut i64,
+) -> i32 {
+    clear_last_error();
+    if len > 0 && (epoch_ms.is_null() || out.is_null()) {
+        set_last_error(TemporalErrorType::TypeError, format_args!("Bucket arrays must not be null"));
+        return -1;
+    }
+    let tz = match parse_with_last_error(tz_id, "bucket", "timezone", &TimeZone::try_from_str) {
+        Some(t) => t,
+        None => return -1,
+    };
+    for index in 0..len {
+        // Element-wise reads and writes so that `out` may alias `epoch_ms`
+        let value = unsafe { epoch_ms.add(index).read() };
+        match bucket_start_ms(value, tz, bucket_minutes) {
+            Ok(start) => unsafe { out.add(index).write(start) },
+            Err(e) => {
+                set_last_error(TemporalErrorType::RangeError, format_args!("Failed to bucket item {}: {}", index, e));
+                return -1;
+            }
+        }
+    }
+    0
+}
+
+fn bucket_start_ms(epoch_ms: i64, tz: TimeZone, bucket_minutes: u32) -> Result<i64, TemporalError> {
+    if !(1..=1440).contains(&bucket_minutes) {
+        return Err(TemporalError::range().with_message("bucket_minutes must be between 1 and 1440"));
+    }
+    let zdt = ZonedDateTime::try_new(epoch_ms as i128 * 1_000_000, tz, Calendar::default())?;
+    let local_ms = epoch_ms + zdt.offset_nanoseconds().div_euclid(1_000_000);
+    let into_bucket = local_ms.rem_euclid(86_400_000) % (bucket_minutes as i64 * 60_000);
+    Ok(epoch_ms - into_bucket)
+}
+
+/// Gets components from a ZonedDateTime string.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_get_components(
+    s: *const c_char,
+    out: *mut ZonedDateTimeComponents,
+) {
+    if out.is_null() { return; }
+    unsafe { *out = ZonedDateTimeComponents::default(); }
+    if s.is_null() { return; }
+
+    let zdt = match parse_zoned_date_time(s, "zoned date time") {
+        Ok(z) => z,
+        Err(_) => return,
+    };
+    unsafe { *out = zoned_components(&zdt) };
+}
+
+fn zoned_components(zdt: &ZonedDateTime) -> ZonedDateTimeComponents {
+    ZonedDateTimeComponents {
+        year: zdt.year(),
+        month: zdt.month(),
+        day: zdt.day(),
+        day_of_week: zdt.day_of_week(),
+        day_of_year: zdt.day_of_year(),
+        week_of_year: zdt.week_of_year().unwrap_or(0) as u16,
+        year_of_week: zdt.year_of_week().unwrap_or(0),
+        days_in_week: zdt.days_in_week(),
+        days_in_month: zdt.days_in_month(),
+        days_in_year: zdt.days_in_year(),
+        months_in_year: zdt.months_in_year(),
+        in_leap_year: if zdt.in_leap_year() { 1 } else { 0 },
+        hour: zdt.hour(),
+        minute: zdt.minute(),
+        second: zdt.second(),
+        millisecond: zdt.millisecond(),
+        microsecond: zdt.microsecond(),
+        nanosecond: zdt.nanosecond(),
+        offset_nanoseconds: zdt.offset_nanoseconds() as i64,
+        has_week_info: if zdt.week_of_year().is_some() { 1 } else { 0 },
+        is_valid: 1,
+    }
+}
+
+/// Gets the epoch values.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_epoch_milliseconds(s: *const c_char) -> TemporalResult {
+    let zdt = match parse_zoned_date_time(s, "zoned date time") {
+        Ok(z) => z,
+        Err(e) => return e,
+    };
+    TemporalResult::success(zdt.epoch_milliseconds().to_string())
+}
+
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_epoch_nanoseconds(s: *const c_char) -> TemporalResult {
+    let zdt = match parse_zoned_date_time(s, "zoned date time") {
+        Ok(z) => z,
+        Err(e) => return e,
+    };
+    TemporalResult::success(zdt.epoch_nanoseconds().0.to_string())
+}
+
+/// Fills `out` with the epoch nanoseconds of a ZonedDateTime. On error `is_valid` is 0.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_epoch_nanoseconds_parts(s: *const c_char, out: *mut EpochNanosecondsParts) {
+    if out.is_null() {
+        return;
+    }
+    let parts = match parse_zoned_date_time(s, "zoned date time") {
+        Ok(zdt) => EpochNanosecondsParts::new(zdt.epoch_nanoseconds().0),
+        Err(_) => EpochNanosecondsParts::default(),
+    };
+    unsafe { *out = parts };
+}
+
+/// Returns a fixed-width key that sorts lexicographically in the same order as the
+/// instants, for ORDER BY on persisted values: the epoch nanoseconds shifted by the
+/// Temporal minimum (-8.64e21) so they are never negative, zero-padded to 23 digits.
+/// Time zone and calendar do not affect the key.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_sort_key(s: *const c_char) -> TemporalResult {
+    let zdt = match parse_zoned_date_time(s, "zoned date time") {
+        Ok(z) => z,
+        Err(e) => return e,
+    };
+    const EPOCH_NS_BIAS: i128 = 8_640_000_000_000_000_000_000;
+    TemporalResult::success(format!("{:023}", zdt.epoch_nanoseconds().as_i128() + EPOCH_NS_BIAS))
+}
+
+/// Gets the calendar ID.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_get_calendar(s: *const c_char) -> TemporalResult {
+    let zdt = match parse_zoned_date_time(s, "zoned date time") {
+        Ok(z) => z,
+        Err(e) => return e,
+    };
+    TemporalResult::success(zdt.calendar().identifier().to_string())
+}
+
+/// Gets the TimeZone ID.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_get_time_zone(s: *const c_char) -> TemporalResult {
+    let zdt = match parse_zoned_date_time(s, "zoned date time") {
+        Ok(z) => z,
+        Err(e) => return e,
+    };
+    match zdt.time_zone().identifier() {
+        Ok(id) => TemporalResult::success(id),
+        Err(e) => TemporalResult::range_error(&format!("Failed to get timezone id: {}", e)),
+    }
+}
+
+/// Gets the month code (e.g. "M01", "M05L").
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_get_month_code(s: *const c_char) -> TemporalResult {
+    let zdt = match parse_zoned_date_time(s, "zoned date time") {
+        Ok(z) => z,
+        Err(e) => return e,
+    };
+    TemporalResult::success(zdt.month_code().as_str().to_string())
+}
+
+/// Gets the era (e.g. "reiwa" in the japanese calendar), or an empty string when the
+/// calendar has no eras.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_get_era(s: *const c_char) -> TemporalResult {
+    let zdt = match parse_zoned_date_time(s, "zoned date time") {
+        Ok(z) => z,
+        Err(e) => return e,
+    };
+    TemporalResult::success(zdt.era().map(|era| era.to_string()).unwrap_or_default())
+}
+
+/// Gets the year within the era as a decimal string, or an empty string when the
+/// calendar has no eras.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_get_era_year(s: *const c_char) -> TemporalResult {
+    let zdt = match parse_zoned_date_time(s, "zoned date time") {
+        Ok(z) => z,
+        Err(e) => return e,
+    };
+    TemporalResult::success(zdt.era_year().map(|year| year.to_string()).unwrap_or_default())
+}
+
+/// Gets the offset string.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_get_offset(s: *const c_char) -> TemporalResult {
+    let zdt = match parse_zoned_date_time(s, "zoned date time") {
+        Ok(z) => z,
+        Err(e) => return e,
+    };
+    TemporalResult::success(zdt.offset().to_string())
+}
+
+/// Adds a duration.
+///
+/// `arithmetic` selects how days are added (NULL = "calendar"):
+/// - "calendar": days move the wall-clock date and keep the local time, so P1D
+///   across a DST change is 23 or 25 hours (Temporal's default).
+/// - "exact": days are exactly 24 hours of elapsed time, so P1D equals PT24H and
+///   the local time shifts across a DST change. Years, months and weeks are rejected.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_add(
+    zdt_str: *const c_char,
+    duration_str: *const c_char,
+    arithmetic: *const c_char,
+) -> TemporalResult {
+    let zdt = match parse_zoned_date_time(zdt_str, "zoned date time") {
+        Ok(z) => z,
+        Err(e) => return e,
+    };
+    let duration = match parse_duration(duration_str, "duration") {
+        Ok(d) => d,
+        Err(e) => return e,
+    };
+    let duration = if !arithmetic.is_null() {
+        match parse_c_str(arithmetic, "arithmetic") {
+            Ok("calendar") => duration,
+            Ok("exact") => match exact_time_duration(&duration) {
+                Ok(d) => d,
+                Err(message) => return TemporalResult::range_error(&message),
+            },
+            Ok(s) => return TemporalResult::range_error(&format!("Invalid arithmetic option: {} (expected calendar or exact)", s)),
+            Err(e) => return e,
+        }
+    } else {
+        duration
+    };
+
+    match zdt.add(&duration, Some(Overflow::Reject)) {
+        Ok(result) => match result.to_ixdtf_string(display::offset(), display::time_zone(), display::calendar(), display::rounding()) {
+            Ok(s) => TemporalResult::success(s),
+            Err(e) => TemporalResult::range_error(&format!("Failed to format: {}", e)),
+        },
+        Err(e) => TemporalResult::range_error(&format!("Failed to add duration: {}", e)),
+    }
+}
+
+/// Subtracts a duration.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_subtract(
+    zdt_str: *const c_char,
+    duration_str: *const c_char,
+) -> TemporalResult {
+    let zdt = match parse_zoned_date_time(zdt_str, "zoned date time") {
+        Ok(z) => z,
+        Err(e) => return e,
+    };
+    let duration = match parse_duration(duration_str, "duration") {
+        Ok(d) => d,
+        Err(e) => return e,
+    };
+
+    match zdt.subtract(&duration, Some(Overflow::Reject)) {
+        Ok(result) => match result.to_ixdtf_string(display::offset(), display::time_zone(), display::calendar(), display::rounding()) {
+            Ok(s) => TemporalResult::success(s),
+            Err(e) => TemporalResult::range_error(&format!("Failed to format: {}", e)),
+        },
+        Err(e) => TemporalResult::range_error(&format!("Failed to subtract duration: {}", e)),
+    }
+}
+
+/// Compares two ZonedDateTimes.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_compare(
+    a: *const c_char,
+    b: *const c_char,
+) -> CompareResult {
+    let zdt_a = match parse_zoned_date_time(a, "first zoned date time") {
+        Ok(z) => z,
+        Err(e) => return CompareResult::from_error(e),
+    };
+    let zdt_b = match parse_zoned_date_time(b, "second zoned date time") {
+        Ok(z) => z,
+        Err(e) => return CompareResult::from_error(e),
+    };
+
+    CompareResult::success(zdt_a.epoch_nanoseconds().0.cmp(&zdt_b.epoch_nanoseconds().0) as i32)
+}
+
+/// Returns a new ZonedDateTime with updated fields.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_with(
+    zdt_str: *const c_char,
+    year: i32,
+    month: i32,
+    day: i32,
+    hour: i32,
+    minute: i32,
+    second: i32,
+    millisecond: i32,
+    microsecond: i32,
+    nanosecond: i32,
+    offset_ns: i64, // Used for disambiguation if provided
+    calendar_id: *const c_char,
+    time_zone_id: *const c_char,
+) -> TemporalResult {
+    let zdt = match parse_zoned_date_time(zdt_str, "zoned date time") {
+        Ok(z) => z,
+        Err(e) => return e,
+    };
+    
+    // This is complex. `with` works on PlainDateTime components then resolves.
+    // We need to implement partial update logic similar to PlainDateTime but then re-resolve.
+    // For simplicity, we can extract current components, overlay new ones, create new ZDT.
+    
+    let current_pdt = zdt.to_plain_date_time();
+    
+    let new_year = if year == i32::MIN { current_pdt.year() } else { year };
+    let new_month = if month == i32::MIN { current_pdt.month() } else { month as u8 };
+    let new_day = if day == i32::MIN { current_pdt.day() } else { day as u8 };
+    
+    let new_hour = if hour == i32::MIN { current_pdt.hour() } else { hour as u8 };
+    let new_minute = if minute == i32::MIN { current_pdt.minute() } else { minute as u8 };
+    let new_second = if second == i32::MIN { current_pdt.second() } else { second as u8 };
+    let new_millisecond = if millisecond == i32::MIN { current_pdt.millisecond() } else { millisecond as u16 };
+    let new_microsecond = if microsecond == i32::MIN { current_pdt.microsecond() } else { microsecond as u16 };
+    let new_nanosecond = if nanosecond == i32::MIN { current_pdt.nanosecond() } else { nanosecond as u16 };
+
+    let new_calendar = if !calendar_id.is_null() {
+        match parse_c_str(calendar_id, "calendar id") {
+            Ok(s) => match Calendar::from_str(s) {
+                Ok(c) => c,
+                Err(e) => return TemporalResult::range_error(&format!("Invalid calendar: {}", e)),
+            },
+            Err(e) => return e,
+        }
+    } else {
+        zdt.calendar().clone()
+    };
+    
+    let new_timezone = if !time_zone_id.is_null() {
+        match parse_c_str(time_zone_id, "timezone id") {
+            Ok(s) => match TimeZone::try_from_str(s) {
+                Ok(t) => t,
+                Err(e) => return TemporalResult::range_error(&format!("Invalid timezone: {}", e)),
+            },
+            Err(e) => return e,
+        }
+    } else {
+        zdt.time_zone().clone()
+    };
+
+    let pdt = match PlainDateTime::new(
+        new_year, new_month, new_day, 
+        new_hour, new_minute, new_second, 
+        new_millisecond, new_microsecond, new_nanosecond, 
+        new_calendar
+    ) {
+        Ok(d) => d,
+        Err(e) => return TemporalResult::range_error(&format!("Invalid components: {}", e)),
+    };
+    
+    match pdt.to_zoned_date_time(new_timezone, Disambiguation::Compatible) {
+        Ok(new_zdt) => match new_zdt.to_ixdtf_string(display::offset(), display::time_zone(), display::calendar(), display::rounding()) {
+            Ok(s) => TemporalResult::success(s),
+            Err(e) => TemporalResult::range_error(&format!("Failed to format: {}", e)),
+        },
+        Err(e) => TemporalResult::range_error(&format!("Failed to create zoned date time: {}", e)),
+    }
+}
+
+/// Computes the difference between two ZonedDateTimes (until). NULL options and a
+/// zero increment take the spec defaults (largestUnit "hour").
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_until(
+    one_str: *const c_char,
+    two_str: *const c_char,
+    largest_unit: *const c_char,
+    smallest_unit: *const c_char,
+    rounding_increment: i64,
+    rounding_mode: *const c_char,
+) -> TemporalResult {
+    let one = match parse_zoned_date_time(one_str, "first zoned date time") {
+        Ok(z) => z,
+        Err(e) => return e,
+    };
+    let two = match parse_zoned_date_time(two_str, "second zoned date time") {
+        Ok(z) => z,
+        Err(e) => return e,
+    };
+    let options = match difference_settings_from_strings(largest_unit, smallest_unit, rounding_increment, rounding_mode) {
+        Ok(o) => o,
+        Err(e) => return e,
+    };
+
+    match one.until(&two, options) {
+        Ok(d) => TemporalResult::success(d.to_string()),
+        Err(e) => TemporalResult::range_error(&format!("Failed to compute difference: {}", e)),
+    }
+}
+
+/// Computes the difference between two ZonedDateTimes (since). NULL options and a
+/// zero increment take the spec defaults (largestUnit "hour").
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_since(
+    one_str: *const c_char,
+    two_str: *const c_char,
+    largest_unit: *const c_char,
+    smallest_unit: *const c_char,
+    rounding_increment: i64,
+    rounding_mode: *const c_char,
+) -> TemporalResult {
+    let one = match parse_zoned_date_time(one_str, "first zoned date time") {
+        Ok(z) => z,
+        Err(e) => return e,
+    };
+    let two = match parse_zoned_date_time(two_str, "second zoned date time") {
+        Ok(z) => z,
+        Err(e) => return e,
+    };
+    let options = match difference_settings_from_strings(largest_unit, smallest_unit, rounding_increment, rounding_mode) {
+        Ok(o) => o,
+        Err(e) => return e,
+    };
+
+    match one.since(&two, options) {
+        Ok(d) => TemporalResult::success(d.to_string()),
+        Err(e) => TemporalResult::range_error(&format!("Failed to compute difference: {}", e)),
+    }
+}
+
+/// Rounds the ZonedDateTime.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_round(
+    zdt_str: *const c_char,
+    smallest_unit: *const c_char,
+    rounding_increment: i64,
+    rounding_mode: *const c_char,
+) -> TemporalResult {
+    let zdt = match parse_zoned_date_time(zdt_str, "zoned date time") {
+        Ok(z) => z,
+        Err(e) => return e,
+    };
+
+    let unit = if !smallest_unit.is_null() {
+        let s = match parse_c_str(smallest_unit, "smallest unit") {
+            Ok(s) => s,
+            Err(e) => return e,
+        };
+        match Unit::from_str(s) {
+            Ok(u) => u,
+            Err(_) => return TemporalResult::range_error(&format!("Invalid smallest unit: {}", s)),
+        }
+    } else {
+        return TemporalResult::type_error("smallestUnit is required");
+    };
+
+    let mode = if !rounding_mode.is_null() {
+        let s = match parse_c_str(rounding_mode, "rounding mode") {
+            Ok(s) => s,
+            Err(e) => return e,
+        };
+        match RoundingMode::from_str(s) {
+            Ok(m) => m,
+            Err(_) => return TemporalResult::range_error(&format!("Invalid rounding mode: {}", s)),
+        }
+    } else {
+        RoundingMode::HalfExpand
+    };
+
+    let increment_opt = match rounding_increment_from_i64(rounding_increment, Some(unit), IncrementContext::Round) {
+        Ok(i) => i,
+        Err(e) => return e,
+    };
+
+    let mut options = RoundingOptions::default();
+    options.smallest_unit = Some(unit);
+    options.rounding_mode = Some(mode);
+    options.increment = Some(increment_opt);
+
+    match zdt.round(options) {
+        Ok(result) => match result.to_ixdtf_string(display::offset(), display::time_zone(), display::calendar(), display::rounding()) {
+            Ok(s) => TemporalResult::success(s),
+            Err(e) => TemporalResult::range_error(&format!("Failed to format: {}", e)),
+        },
+        Err(e) => TemporalResult::range_error(&format!("Failed to round: {}", e)),
+    }
+}
+
+/// Converts to Instant.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_to_instant(s: *const c_char) -> TemporalResult {
+    let zdt = match parse_zoned_date_time(s, "zoned date time") {
+        Ok(z) => z,
+        Err(e) => return e,
+    };
+    let provider = &*COMPILED_TZ_PROVIDER;
+    match zdt.to_instant().to_ixdtf_string_with_provider(None, display::rounding(), &provider) {
+        Ok(s) => TemporalResult::success(s),
+        Err(e) => TemporalResult::range_error(&format!("Failed to convert to instant: {}", e)),
+    }
+}
+
+/// Converts to PlainDate.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_to_plain_date(s: *const c_char) -> TemporalResult {
+    let zdt = match parse_zoned_date_time(s, "zoned date time") {
+        Ok(z) => z,
+        Err(e) => return e,
+    };
+    TemporalResult::success(zdt.to_plain_date().to_ixdtf_string(display::calendar()))
+}
+
+/// Converts to PlainTime.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_to_plain_time(s: *const c_char) -> TemporalResult {
+    let zdt = match parse_zoned_date_time(s, "zoned date time") {
+        Ok(z) => z,
+        Err(e) => return e,
+    };
+    match zdt.to_plain_time().to_ixdtf_string(display::rounding()) {
+        Ok(s) => TemporalResult::success(s),
+        Err(e) => TemporalResult::range_error(&format!("Failed to convert to plain time: {}", e)),
+    }
+}
+
+/// Converts to PlainDateTime.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_to_plain_date_time(s: *const c_char) -> TemporalResult {
+    let zdt = match parse_zoned_date_time(s, "zoned date time") {
+        Ok(z) => z,
+        Err(e) => return e,
+    };
+    match zdt.to_plain_date_time().to_ixdtf_string(display::rounding(), display::calendar()) {
+        Ok(s) => TemporalResult::success(s),
+        Err(e) => TemporalResult::range_error(&format!("Failed to convert to plain date time: {}", e)),
+    }
+}
+
+/// Returns the first instant of the day, week, month, or year containing the given
+/// ZonedDateTime. Uses the time zone's start of day, so days starting at 01:00 on a
+/// DST change are handled.
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_start_of(
+    s: *const c_char,
+    unit: *const c_char,
+    first_day_of_week: u8,
+) -> TemporalResult {
+    zoned_date_time_unit_bound(s, unit, first_day_of_week, false)
+}
+
+/// Returns the last nanosecond of the day, week, month, or year containing the given
+/// ZonedDateTime (one nanosecond before the start of the next one).
+#[no_mangle]
+pub extern "C" fn temporal_zoned_date_time_end_of(
+    s: *const c_char,
+    unit: *const c_char,
+    first_day_of_week: u8,
+) -> TemporalResult {
+    zoned_date_time_unit_bound(s, unit, first_day_of_week, true)
+}
+
+/// Local days the day-by-day range functions walk, about a century. Without a bound a
+/// range across the whole Temporal instant range would loop for 2e8 days.
+const MAX_RANGE_LOCAL_DAYS: i128 = 36_525;
+
+/// Checks that [start_ns, end_ns] covers at most MAX_RANGE_LOCAL_DAYS days.
+fn check_range_local_days(start_ns: i128, end_ns: i128) -> Result<(), TemporalResult> {
+    if (end_ns - start_ns) / 86_400_000_000_000 >= MAX_RANGE_LOCAL_DAYS {
+        return Err(TemporalResult::range_error(&format!(
+            "range must span fewer than {} days",
+            MAX_RANGE_LOCAL_DAYS
+        )));
+    }
+    Ok(())
+}
+
+/// Returns the part of [start, end) that falls within working hours, as a Duration
+/// balanced up to hours. Working hours are `work_start`..`work_end` wall-clock time
+/// in the start's time zone on every day whose ISO weekday (1 = Monday ... 7 = Sunday)
+/// is not set in `weekend_mask` (bit 0 = Monday ... bit 6 = Sunday; 0x60 = Sat/Sun).
+/// Windows are resolved through the time zone, so DST days count their real length.
+/// Ranges of MAX_RANGE_LOCAL_DAYS or more are a RangeError.
+#[no_mangle]
+pub extern "C" fn temporal_working_time_between(
+    start: *const c_char,
+    end: *const c_char,
+    work_start: *const c_char,
+    work_end: *const c_char,
+    weekend_mask: u8,
+) -> TemporalResult {
+    let start = match parse_zoned_date_time(start, "start") {
+        Ok(z) => z,
+        Err(e) => return e,
+    };
+    let end = match parse_zoned_date_time(end, "end") {
+        Ok(z) => z,
+        Err(e) => return e,
+    };
+    let work_start = match parse_plain_time(work_start, "work start") {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    let work_end = match parse_plain_time(work_end, "work end") {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+    if work_end <= work_start {
+        return TemporalResult::range_error("work end must be later than work start");
+    }
+
+    let start_ns = start.epoch_nanoseconds().as_i128();
+    let end_ns = end.epoch_nanoseconds().as_i128();
+    if end_ns < start_ns {
+        return TemporalResult::range_error("end must not be earlier than start");
+    }
+    if let Err(e) = check_range_local_days(start_ns, end_ns) {
+        return e;
+    }
+
+    let tz = *start.time_zone();
+    let last_day = match ZonedDateTime::try_new(end_ns, tz, Calendar::default()) {
+        Ok(z) => z.to_plain_date().with_calendar(Calendar::default()),
+        Err(e) => return TemporalResult::range_error(&format!("Failed to convert end to start time zone: {}", e)),
+    };
+    let mut day = start.to_plain_date().with_calendar(Calendar::default());
+    let one_day = unit_duration(Unit::Day);
+    let window_ns = |date: &PlainDate, time: PlainTime| -> Result<i128, TemporalError> {
+        let zdt = date.to_plain_date_time(Some(time))?.to_zoned_date_time(tz, Disambiguation::Compatible)?;
+        Ok(zdt.epoch_nanoseconds().as_i128())
+    };
+
+    let mut total_ns: i128 = 0;
+    while compare_plain_dates(&day, &last_day) != std::cmp::Ordering::Greater {
+        if weekend_mask & (1 << (day.day_of_week() - 1)) == 0 {
+            let window = window_ns(&day, work_start).and_then(|from| Ok((from, window_ns(&day, work_end)?)));
+            match window {
+                Ok((from, to)) => total_ns += (to.min(end_ns) - from.max(start_ns)).max(0),
+                Err(e) => return TemporalResult::range_error(&format!("Failed to resolve working hours: {}", e)),
+            }
+        }
+        day = match day.add(&one_day, None) {
+            Ok(d) => d,
+            Err(e) => return TemporalResult::range_error(&format!("Failed to advance day: {}", e)),
+        };
+    }
+
+    let seconds = total_ns / 1_000_000_000;
+    let duration = Duration::new(
+        0,
+        0,
+        0,
+        0,
+        (seconds / 3600) as i64,
+        (seconds / 60 % 60) as i64,
+        (seconds % 60) as i64,
+        0,
+        0,
+        total_ns % 1_000_000_000,
+    );
+    match duration {
+        Ok(d) => TemporalResult::success(d.to_string()),
+        Err(e) => TemporalResult::range_error(&format!("Failed to create duration: {}", e)),
+    }
+}
+
+/// Splits [start, end] at each local start of day in the start's time zone, for
+/// drawing a multi-day event as one segment per day. Returns newline-separated
+/// ZonedDateTimes: the start, every start of day strictly between the two, and the
+/// end converted to the start's time zone, so consecutive lines bound one segment.
+/// Days are resolved through the time zone, so 23- and 25-hour DST days and days
+/// that start at 01:00 get their real boundaries. Ranges of MAX_RANGE_LOCAL_DAYS or
+/// more are a RangeError.
+#[no_mangle]
+pub extern "C" fn temporal_split_range_by_local_days(start: *const c_char, end: *const c_char) -> TemporalResult {
+    let start = match parse_zoned_date_time(start, "start") {
+        Ok(z) => z,
+        Err(e) => return e,
+    };
+    let end = match parse_zoned_date_time(end, "end") {
+        Ok(z) => z,
+        Err(e) => return e,
+    };
+    let start_ns = start.epoch_nanoseconds().as_i128();
+    let end_ns = end.epoch_nanoseconds().as_i128();
+    if end_ns < start_ns {
+        return TemporalResult::range_error("end must not be earlier than start");
+    }
+    if let Err(e) = check_range_local_days(start_ns, end_ns) {
+        return e;
+    }
+
+    let tz = *start.time_zone();
+    let in_zone = |ns: i128| ZonedDateTime::try_new(ns, tz, start.calendar().clone());
+    let mut boundaries = vec![start.clone()];
+    let mut day = start.to_plain_date().with_calendar(Calendar::default());
+    let one_day = unit_duration(Unit::Day);
+    loop {
+        let next = day
+            .add(&one_day, None)
+            .and_then(|d| Ok((d.to_zoned_date_time(tz, None)?, d)));
+        let (start_of_day, next_day) = match next {
+            Ok(next) => next,
+            Err(e) => return TemporalResult::range_error(&format!("Failed to find start of day: {}", e)),
+        };
+        let ns = start_of_day.epoch_nanoseconds().as_i128();
+        if ns >= end_ns {
+            break;
+        }
+        match in_zone(ns) {
+            Ok(z) => boundaries.push(z),
+            Err(e) => return TemporalResult::range_error(&format!("Failed to create day boundary: {}", e)),
+        }
+        day = next_day;
+    }
+    match in_zone(end_ns) {
+        Ok(z) => boundaries.push(z),
+        Err(e) => return TemporalResult::range_error(&format!("Failed to convert end to start time zone: {}", e)),
+    }
+
+    let mut lines = Vec::with_capacity(boundaries.len());
+    for zdt in &boundaries {
+        match zdt.to_ixdtf_string(display::offset(), display::time_zone(), display::calendar(), display::rounding()) {
+            Ok(s) => lines.push(s),
+            Err(e) => return TemporalResult::range_error(&format!("Failed to format day boundary: {}", e)),
+        }
+    }
+    TemporalResult::success(lines.join("\n"))
+}
+
+// Helper functions for ZonedDateTime/TimeZone
+fn zoned_date_time_unit_bound(
+    s: *const c_char,
+    unit: *const c_char,
+    first_day_of_week: u8,
+    end: bool,
+) -> TemporalResult {
+    let zdt = match parse_zoned_date_time(s, "zoned date time") {
+        Ok(z) => z,
+        Err(e) => return e,
+    };
+    let unit = match parse_calendar_unit(unit) {
+        Ok(u) => u,
+        Err(e) => return e,
+    };
+
+    let mut date = match start_of_unit(&zdt.to_plain_date(), unit, first_day_of_week) {
+        Ok(d) => d,
+        Err(message) => return TemporalResult::range_error(&message),
+    };
+    if end {
+        date = match date.add(&unit_duration(unit), None) {
+            Ok(d) => d,
+            Err(e) => return TemporalResult::range_error(&format!("Failed to compute end of {}: {}", unit, e)),
+        };
+    }
+
+    let bound = date
+        .to_zoned_date_time(*zdt.time_zone(), None)
+        .and_then(|start| {
+            if end {
+                let ns = start.epoch_nanoseconds().as_i128() - 1;
+                ZonedDateTime::try_new(ns, *zdt.time_zone(), zdt.calendar().clone())
+            } else {
+                Ok(start)
+            }
+        });
+    match bound {
+        Ok(result) => match result.to_ixdtf_string(display::offset(), display::time_zone(), display::calendar(), display::rounding()) {
+            Ok(s) => TemporalResult::success(s),
+            Err(e) => TemporalResult::range_error(&format!("Failed to format zoned date time: {}", e)),
+        },
+        Err(e) => TemporalResult::range_error(&format!("Failed to compute {} bound: {}", unit, e)),
+    }
+}
+
+/// Converts days to 24-hour blocks so that adding the duration to a ZonedDateTime
+/// is pure exact-time arithmetic.
+fn exact_time_duration(duration: &Duration) -> Result<Duration, String> {
+    if duration.years() != 0 || duration.months() != 0 || duration.weeks() != 0 {
+        return Err("Exact-time arithmetic does not support years, months, or weeks".to_string());
+    }
+    Duration::new(
+        0,
+        0,
+        0,
+        0,
+        duration.hours() + duration.days() * 24,
+        duration.minutes(),
+        duration.seconds(),
+        duration.milliseconds(),
+        duration.microseconds(),
+        duration.nanoseconds(),
+    )
+    .map_err(|e| e.to_string())
+}
+
+/// Trims and lowercases a user-provided zone or calendar id. Both lookups are
+/// ASCII case-insensitive, so this only changes whether stray input is accepted.
+fn lenient_identifier(id: &str) -> String {
+    id.trim().to_ascii_lowercase()
+}
+
+fn parse_time_zone(s: *const c_char, param_name: &str) -> Result<TimeZone, TemporalResult> {
+    let str_val = parse_c_str(s, param_name)?;
+    TimeZone::try_from_str(str_val)
+        .map_err(|e| TemporalResult::range_error(&format!("Invalid timezone '{}': {}", str_val, e)))
+}
+
+fn parse_zoned_date_time(s: *const c_char, param_name: &str) -> Result<ZonedDateTime, TemporalResult> {
+    let str_val = parse_c_str(s, param_name)?;
+    ZonedDateTime::from_utf8(str_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject)
+        .map_err(|e| TemporalResult::range_error(&format!("Invalid zoned date time '{}': {}", str_val, e)))
+}
+
+fn zoned_date_time_from_epoch_ns(
+    ns: i128,
+    time_zone_id: *const c_char,
+    calendar_id: *const c_char,
+) -> TemporalResult {
+    let tz = match parse_time_zone(time_zone_id, "timezone id") {
+        Ok(t) => t,
+        Err(e) => return e,
+    };
+
+    let calendar = if !calendar_id.is_null() {
+        match parse_c_str(calendar_id, "calendar id") {
+            Ok(s) => match Calendar::from_str(s) {
+                Ok(c) => c,
+                Err(e) => return TemporalResult::range_error(&format!("Invalid calendar: {}", e)),
+            },
+            Err(e) => return e,
+        }
+    } else {
+        Calendar::default()
+    };
+
+    match ZonedDateTime::try_new(ns, tz, calendar) {
+        Ok(zdt) => match zdt.to_ixdtf_string(display::offset(), display::time_zone(), display::calendar(), display::rounding()) {
+            Ok(s) => TemporalResult::success(s),
+            Err(e) => TemporalResult::range_error(&format!("Failed to format zoned date time: {}", e)),
+        },
+        Err(e) => TemporalResult::range_error(&format!("Invalid epoch nanoseconds: {}", e)),
+    }
+}
+
+#[cfg(all(target_os = "android", feature = "jni"))]
+mod android {
+    use jni::objects::{JClass, JObject, JString};
+    use jni::sys::{jint, jlong, jlongArray, jobjectArray, jstring};
+    use jni::JNIEnv;
+
+    use super::{
+        add_months_constrained, add_to_year_month_from_day, canonical_cache, check_instant_duration, clamp_i128, compare_durations, display, duration_from_str, duration_is_clamped, exact_time_duration, get_instant_now_string,
+        get_now_plain_date_string, get_now_plain_date_time_string, get_now_plain_time_string,
+        get_now_zoned_date_time_string, input_too_long_message, last_error_message, max_bulk_input_length, max_input_length, now_epoch_nanoseconds,
+        join_i128, metrics, record_instead_of_throwing, without_throwing, pack_date, plain_month_day_from_fields, quarter_info, schema_json, split_i128, temporal_ffi_api_version, temporal_notify_tz_changed,
+        temporal_last_error_type, temporal_now_epoch_milliseconds,
+        temporal_tz_generation, unpack_date, validate_rounding_increment, warmup, IncrementContext, Kind, TemporalErrorType, DURATION_SLOTS,
+        EPOCH_NANOSECONDS_PARTS_SLOTS, PLAIN_DATE_SLOTS, PLAIN_DATE_TIME_SLOTS, PLAIN_MONTH_DAY_SLOTS,
+        PLAIN_TIME_SLOTS, PLAIN_YEAR_MONTH_SLOTS, week_info, ZONED_DATE_TIME_SLOTS,
+    };
+    use temporal_rs::{
+        options::{DisplayCalendar, Overflow, Disambiguation, OffsetDisambiguation, Unit, RoundingMode, RoundingOptions},
+        provider::{TransitionDirection, TimeZoneProvider, COMPILED_TZ_PROVIDER},
+        Calendar, Duration, Instant, PlainDate, PlainDateTime, PlainMonthDay, PlainTime,
+        PlainYearMonth, TimeZone, ZonedDateTime, TemporalError,
+    };
+    use std::str::FromStr;
+    use std::ptr;
+
+
+    
+    const RANGE_ERROR_CLASS: &str = "java/lang/RuntimeException";
+    const TYPE_ERROR_CLASS: &str = "java/lang/RuntimeException";
+
+    /// Throws a RangeError exception
+    fn throw_range_error(env: &mut JNIEnv, message: &str) {
+        if record_instead_of_throwing(TemporalErrorType::RangeError, message) {
+            return;
+        }
+        metrics::record_error(TemporalErrorType::RangeError);
+        let _ = env.throw_new(RANGE_ERROR_CLASS, &format!("[RangeError] {}", message));
+    }
+
+    /// Throws a TypeError exception
+    fn throw_type_error(env: &mut JNIEnv, message: &str) {
+        if record_instead_of_throwing(TemporalErrorType::TypeError, message) {
+            return;
+        }
+        metrics::record_error(TemporalErrorType::TypeError);
+        let _ = env.throw_new(TYPE_ERROR_CLASS, &format!("[TypeError] {}", message));
+    }
+
+    /// Parses a JNI string, throwing TypeError if null or invalid
+    fn parse_jstring(env: &mut JNIEnv, s: &JString, name: &str) -> Option<String> {
+        read_jstring(env, s, name, max_input_length())
+    }
+
+    /// Like `parse_jstring`, for newline-delimited lists checked against the bulk limit.
+    fn parse_bulk_jstring(env: &mut JNIEnv, s: &JString, name: &str) -> Option<String> {
+        read_jstring(env, s, name, max_bulk_input_length())
+    }
+
+    fn read_jstring(env: &mut JNIEnv, s: &JString, name: &str, limit: usize) -> Option<String> {
+        if s.is_null() {
+            throw_type_error(env, &format!("{} cannot be null", name));
+            return None;
+        }
+        match env.get_string(s) {
+            Ok(js) => {
+                let value = js.to_string_lossy().into_owned();
+                if value.len() > limit {
+                    throw_range_error(env, &input_too_long_message(name, value.len(), limit));
+                    return None;
+                }
+                Some(value)
+            }
+            Err(_) => {
+                throw_type_error(env, &format!("Invalid UTF-8 in {}", name));
+                None
+            }
+        }
+    }
+
+    /// Parses a duration string, throwing RangeError if invalid
+    fn parse_duration(env: &mut JNIEnv, s: &JString, name: &str) -> Option<Duration> {
+        let s_str = parse_jstring(env, s, name)?;
+        match duration_from_str(&s_str) {
+            Ok(d) => Some(d),
+            Err(e) => {
+                throw_range_error(env, &format!("Invalid duration '{}': {}", s_str, e));
+                None
+            }
+        }
+    }
+
+    /// Returns the canonical string of `s` as `kind`, through the canonical value cache.
+    /// `normalize` runs on a miss; its error message is thrown as a RangeError.
+    fn canonical_jstring(
+        env: &mut JNIEnv,
+        s: &JString,
+        name: &str,
+        kind: Kind,
+        normalize: impl FnOnce(&str) -> Result<String, String>,
+    ) -> jstring {
+        let input = match parse_jstring(env, s, name) {
+            Some(s) => s,
+            None => return ptr::null_mut(),
+        };
+        match canonical_cache::canonical(kind, &input, || normalize(&input)) {
+            Ok(value) => env.new_string(value).map(|js| js.into_raw()).unwrap_or_else(|_| {
+                throw_range_error(env, "Failed to create result string");
+                ptr::null_mut()
+            }),
+            Err(message) => {
+                throw_range_error(env, &message);
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// Parses an instant string, throwing RangeError if invalid
+    fn parse_instant(env: &mut JNIEnv, s: &JString, name: &str) -> Option<Instant> {
+        let s_str = parse_jstring(env, s, name)?;
+        match Instant::from_str(&s_str) {
+            Ok(i) => Some(i),
+            Err(e) => {
+                throw_range_error(env, &format!("Invalid instant '{}': {}", s_str, e));
+                None
+            }
+        }
+    }
+
+    /// Parses an optional "constrain" / "reject" option; null means "constrain".
+    fn parse_overflow(env: &mut JNIEnv, overflow: &JString) -> Option<Overflow> {
+        if overflow.is_null() {
+            return Some(Overflow::Constrain);
+        }
+        let s = parse_jstring(env, overflow, "overflow")?;
+        match Overflow::from_str(&s) {
+            Ok(o) => Some(o),
+            Err(_) => {
+                throw_range_error(env, &format!("Invalid overflow option: {}", s));
+                None
+            }
+        }
+    }
+
+    /// Builds difference settings from nullable string options, throwing RangeError
+    /// on an unknown unit or mode.
+    fn parse_difference_settings(
+        env: &mut JNIEnv,
+        largest_unit: &JString,
+        smallest_unit: &JString,
+        rounding_increment: jlong,
+        rounding_mode: &JString,
+    ) -> Option<temporal_rs::options::DifferenceSettings> {
+        let mut options = temporal_rs::options::DifferenceSettings::default();
+        for (target, value, name) in [
+            (&mut options.largest_unit, largest_unit, "largest unit"),
+            (&mut options.smallest_unit, smallest_unit, "smallest unit"),
+        ] {
+            if value.is_null() {
+                continue;
+            }
+            let s = parse_jstring(env, value, name)?;
+            match Unit::from_str(&s) {
+                Ok(u) => *target = Some(u),
+                Err(_) => {
+                    throw_range_error(env, &format!("Invalid {}: {}", name, s));
+                    return None;
+                }
+            }
+        }
+        if !rounding_mode.is_null() {
+            let s = parse_jstring(env, rounding_mode, "rounding mode")?;
+            match RoundingMode::from_str(&s) {
+                Ok(m) => options.rounding_mode = Some(m),
+                Err(_) => {
+                    throw_range_error(env, &format!("Invalid rounding mode: {}", s));
+                    return None;
+                }
+            }
+        }
+        match validate_rounding_increment(rounding_increment, options.smallest_unit, IncrementContext::Difference) {
+            Ok(i) => options.increment = Some(i),
+            Err(message) => {
+                throw_range_error(env, &message);
+                return None;
+            }
+        }
+        Some(options)
+    }
+
+    /// Narrows a date component (month, day) to u8, throwing RangeError instead of
+    /// letting out-of-range values wrap into valid ones. Calendar-specific limits
+    /// are left to temporal_rs.
+    fn narrow_date_component(env: &mut JNIEnv, name: &str, value: jint) -> Option<u8> {
+        match u8::try_from(value) {
+            Ok(v) => Some(v),
+            Err(_) => {
+                throw_range_error(env, &format!("Invalid {}: {} (out of range)", name, value));
+                None
+            }
+        }
+    }
+
+    /// Validates time components with the same ranges as the C API's
+    /// `temporal_plain_time_from_components`, throwing RangeError on failure.
+    fn check_time_components(
+        env: &mut JNIEnv,
+        hour: jint,
+        minute: jint,
+        second: jint,
+        millisecond: jint,
+        microsecond: jint,
+        nanosecond: jint,
+    ) -> bool {
+        let checks = [
+            ("hour", hour, 23),
+            ("minute", minute, 59),
+            ("second", second, 59),
+            ("millisecond", millisecond, 999),
+            ("microsecond", microsecond, 999),
+            ("nanosecond", nanosecond, 999),
+        ];
+        for (name, value, max) in checks {
+            if !(0..=max).contains(&value) {
+                throw_range_error(env, &format!("Invalid {}: {} (must be 0-{})", name, value, max));
+                return false;
+            }
+        }
+        true
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.ffiApiVersion()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_ffiApiVersion(
+        _env: JNIEnv,
+        _class: JClass,
+    ) -> jint {
+        temporal_ffi_api_version() as jint
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.schemaJson()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_schemaJson(
+        mut env: JNIEnv,
+        _class: JClass,
+    ) -> jstring {
+        env.new_string(schema_json())
+            .map(|js| js.into_raw())
+            .unwrap_or_else(|_| {
+                throw_range_error(&mut env, "Failed to create result string");
+                ptr::null_mut()
+            })
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.metricsSnapshot()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_metricsSnapshot(
+        mut env: JNIEnv,
+        _class: JClass,
+    ) -> jstring {
+        env.new_string(metrics::snapshot_json())
+            .map(|js| js.into_raw())
+            .unwrap_or_else(|_| {
+                throw_range_error(&mut env, "Failed to create result string");
+                ptr::null_mut()
+            })
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.timeZoneGeneration()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_timeZoneGeneration(
+        _env: JNIEnv,
+        _class: JClass,
+    ) -> jlong {
+        temporal_tz_generation() as jlong
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.notifyTimeZoneChanged()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_notifyTimeZoneChanged(
+        _env: JNIEnv,
+        _class: JClass,
+    ) -> jlong {
+        temporal_notify_tz_changed() as jlong
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.warmup()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_warmup(
+        mut env: JNIEnv,
+        _class: JClass,
+        tz_ids: JString,
+    ) -> jint {
+        let ids = if tz_ids.is_null() {
+            String::new()
+        } else {
+            match parse_bulk_jstring(&mut env, &tz_ids, "time zone ids") {
+                Some(s) => s,
+                None => return -1,
+            }
+        };
+        match warmup(&ids) {
+            Ok(count) => count,
+            Err((_, message)) => {
+                throw_range_error(&mut env, &message);
+                -1
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.instantNow()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_instantNow(
+        mut env: JNIEnv,
+        _class: JClass,
+    ) -> jstring {
+        match get_instant_now_string() {
+            Ok(s) => env
+                .new_string(s)
+                .map(|js| js.into_raw())
+                .unwrap_or_else(|_| {
+                    throw_range_error(&mut env, "Failed to create string");
+                    ptr::null_mut()
+                }),
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Failed to get current instant: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.nowEpochMilliseconds()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_nowEpochMilliseconds(
+        _env: JNIEnv,
+        _class: JClass,
+    ) -> jlong {
+        temporal_now_epoch_milliseconds()
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.nowEpochNanosecondsString()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_nowEpochNanosecondsString(
+        mut env: JNIEnv,
+        _class: JClass,
+    ) -> jstring {
+        env.new_string(now_epoch_nanoseconds().to_string())
+            .map(|js| js.into_raw())
+            .unwrap_or_else(|_| {
+                throw_range_error(&mut env, "Failed to create string");
+                ptr::null_mut()
+            })
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.instantFromString()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_instantFromString(
+        mut env: JNIEnv,
+        _class: JClass,
+        s: JString,
+    ) -> jstring {
+        canonical_jstring(&mut env, &s, "instant string", Kind::Instant, |input| match Instant::from_str(input) {
+            Ok(instant) => {
+                let provider = &*COMPILED_TZ_PROVIDER;
+                instant
+                    .to_ixdtf_string_with_provider(None, display::rounding(), &provider)
+                    .map_err(|e| format!("Failed to format instant: {}", e))
+            }
+            Err(e) => Err(format!("Invalid instant '{}': {}", input, e)),
+        })
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.instantFromEpochMilliseconds()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_instantFromEpochMilliseconds(
+        mut env: JNIEnv,
+        _class: JClass,
+        ms: jlong,
+    ) -> jstring {
+        let ns = (ms as i128).saturating_mul(1_000_000);
+        match Instant::try_new(ns) {
+            Ok(instant) => {
+                let provider = &*COMPILED_TZ_PROVIDER;
+                match instant.to_ixdtf_string_with_provider(None, display::rounding(), &provider) {
+                    Ok(s) => env
+                        .new_string(s)
+                        .map(|js| js.into_raw())
+                        .unwrap_or(ptr::null_mut()),
+                    Err(e) => {
+                        throw_range_error(&mut env, &format!("Failed to format instant: {}", e));
+                        ptr::null_mut()
+                    }
+                }
+            },
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid epoch milliseconds: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.instantFromEpochNanoseconds()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_instantFromEpochNanoseconds(
+        mut env: JNIEnv,
+        _class: JClass,
+        ns_str: JString,
+    ) -> jstring {
+        let s_str = parse_jstring(&mut env, &ns_str, "nanoseconds string");
+        let s_val = match s_str {
+            Some(s) => s,
+            None => return ptr::null_mut(),
+        };
+        
+        let ns = match i128::from_str(&s_val) {
+            Ok(n) => n,
+            Err(_) => {
+                throw_range_error(&mut env, "Invalid nanoseconds string");
+                return ptr::null_mut();
+            }
+        };
+
+        match Instant::try_new(ns) {
+            Ok(instant) => {
+                let provider = &*COMPILED_TZ_PROVIDER;
+                match instant.to_ixdtf_string_with_provider(None, display::rounding(), &provider) {
+                    Ok(s) => env
+                        .new_string(s)
+                        .map(|js| js.into_raw())
+                        .unwrap_or(ptr::null_mut()),
+                    Err(e) => {
+                        throw_range_error(&mut env, &format!("Failed to format instant: {}", e));
+                        ptr::null_mut()
+                    }
+                }
+            },
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid epoch nanoseconds: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.instantEpochMilliseconds()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_instantEpochMilliseconds(
+        mut env: JNIEnv,
+        _class: JClass,
+        s: JString,
+    ) -> jstring {
+        let instant = match parse_instant(&mut env, &s, "instant") {
+            Some(i) => i,
+            None => return ptr::null_mut(),
+        };
+        let ms = instant.epoch_milliseconds();
+        env.new_string(ms.to_string())
+            .map(|js| js.into_raw())
+            .unwrap_or(ptr::null_mut())
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.instantEpochNanoseconds()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_instantEpochNanoseconds(
+        mut env: JNIEnv,
+        _class: JClass,
+        s: JString,
+    ) -> jstring {
+        let instant = match parse_instant(&mut env, &s, "instant") {
+            Some(i) => i,
+            None => return ptr::null_mut(),
+        };
+        let ns = instant.epoch_nanoseconds();
+        env.new_string(ns.0.to_string())
+            .map(|js| js.into_raw())
+            .unwrap_or(ptr::null_mut())
+    }
+
+    /// Returns epoch nanoseconds as [high, low], the value being high * 2^64 + low
+    /// with low read as unsigned.
+    fn epoch_ns_parts_array(env: &mut JNIEnv, ns: i128) -> jlongArray {
+        let (high, low) = split_i128(ns);
+        match env.new_long_array(EPOCH_NANOSECONDS_PARTS_SLOTS.len() as i32) {
+            Ok(arr) => {
+                if env.set_long_array_region(&arr, 0, &[high, low as i64]).is_err() {
+                    throw_range_error(env, "Failed to set array elements");
+                    return ptr::null_mut();
+                }
+                arr.into_raw()
+            }
+            Err(_) => {
+                throw_range_error(env, "Failed to create result array");
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.instantEpochNanosecondsParts()`
+    /// Returns: [high, low]
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_instantEpochNanosecondsParts(
+        mut env: JNIEnv,
+        _class: JClass,
+        s: JString,
+    ) -> jlongArray {
+        let instant = match parse_instant(&mut env, &s, "instant") {
+            Some(i) => i,
+            None => return ptr::null_mut(),
+        };
+        epoch_ns_parts_array(&mut env, instant.epoch_nanoseconds().0)
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.instantFromEpochNanosecondsParts()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_instantFromEpochNanosecondsParts(
+        mut env: JNIEnv,
+        _class: JClass,
+        high: jlong,
+        low: jlong,
+    ) -> jstring {
+        let formatted = Instant::try_new(join_i128(high, low as u64)).and_then(|instant| {
+            instant.to_ixdtf_string_with_provider(None, display::rounding(), &*COMPILED_TZ_PROVIDER)
+        });
+        match formatted {
+            Ok(s) => env.new_string(s).map(|js| js.into_raw()).unwrap_or(ptr::null_mut()),
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid epoch nanoseconds: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.instantAdd()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_instantAdd(
+        mut env: JNIEnv,
+        _class: JClass,
+        instant_str: JString,
+        duration_str: JString,
+    ) -> jstring {
+        let instant = match parse_instant(&mut env, &instant_str, "instant") {
+            Some(i) => i,
+            None => return ptr::null_mut(),
+        };
+        let duration = match parse_duration(&mut env, &duration_str, "duration") {
+            Some(d) => d,
+            None => return ptr::null_mut(),
+        };
+        if let Err(message) = check_instant_duration(&duration, "add") {
+            throw_range_error(&mut env, &message);
+            return ptr::null_mut();
+        }
+
+        match instant.add(&duration) {
+            Ok(result) => {
+                let provider = &*COMPILED_TZ_PROVIDER;
+                match result.to_ixdtf_string_with_provider(None, display::rounding(), &provider) {
+                    Ok(s) => env
+                        .new_string(s)
+                        .map(|js| js.into_raw())
+                        .unwrap_or(ptr::null_mut()),
+                    Err(e) => {
+                        throw_range_error(&mut env, &format!("Failed to format instant: {}", e));
+                        ptr::null_mut()
+                    }
+                }
+            },
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Failed to add duration: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.instantSubtract()`
+
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_instantSubtract(
+        mut env: JNIEnv,
+        _class: JClass,
+        instant_str: JString,
+        duration_str: JString,
+    ) -> jstring {
+        let instant = match parse_instant(&mut env, &instant_str, "instant") {
+            Some(i) => i,
+            None => return ptr::null_mut(),
+        };
+        let duration = match parse_duration(&mut env, &duration_str, "duration") {
+            Some(d) => d,
+            None => return ptr::null_mut(),
+        };
+        if let Err(message) = check_instant_duration(&duration, "subtract") {
+            throw_range_error(&mut env, &message);
+            return ptr::null_mut();
+        }
+
+        match instant.subtract(&duration) {
+            Ok(result) => {
+                let provider = &*COMPILED_TZ_PROVIDER;
+                match result.to_ixdtf_string_with_provider(None, display::rounding(), &provider) {
+                    Ok(s) => env
+                        .new_string(s)
+                        .map(|js| js.into_raw())
+                        .unwrap_or(ptr::null_mut()),
+                    Err(e) => {
+                        throw_range_error(&mut env, &format!("Failed to format instant: {}", e));
+                        ptr::null_mut()
+                    }
+                }
+            },
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Failed to subtract duration: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.instantCompare()`
+
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_instantCompare(
+        mut env: JNIEnv,
+        _class: JClass,
+        a: JString,
+        b: JString,
+    ) -> jint {
+        let instant_a = match parse_instant(&mut env, &a, "first instant") {
+            Some(i) => i,
+            None => return 0,
+        };
+        let instant_b = match parse_instant(&mut env, &b, "second instant") {
+            Some(i) => i,
+            None => return 0,
+        };
+        
+        instant_a.cmp(&instant_b) as jint
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.instantUntil()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_instantUntil(
+        mut env: JNIEnv,
+        _class: JClass,
+        one: JString,
+        two: JString,
+        largest_unit: JString,
+        smallest_unit: JString,
+        rounding_increment: jlong,
+        rounding_mode: JString,
+    ) -> jstring {
+        let one_inst = match parse_instant(&mut env, &one, "first instant") {
+            Some(i) => i,
+            None => return ptr::null_mut(),
+        };
+        let two_inst = match parse_instant(&mut env, &two, "second instant") {
+            Some(i) => i,
+            None => return ptr::null_mut(),
+        };
+
+        let largest = if !largest_unit.is_null() {
+            let s = parse_jstring(&mut env, &largest_unit, "largest unit");
+            match s {
+                Some(s) => match Unit::from_str(&s) {
+                    Ok(u) => Some(u),
+                    Err(_) => {
+                        throw_range_error(&mut env, &format!("Invalid largest unit: {}", s));
+                        return ptr::null_mut();
+                    }
+                },
+                None => None, // null passed
+            }
+        } else {
+            None
+        };
+
+        let smallest = if !smallest_unit.is_null() {
+            let s = parse_jstring(&mut env, &smallest_unit, "smallest unit");
+            match s {
+                Some(s) => match Unit::from_str(&s) {
+                    Ok(u) => Some(u),
+                    Err(_) => {
+                        throw_range_error(&mut env, &format!("Invalid smallest unit: {}", s));
+                        return ptr::null_mut();
+                    }
+                },
+                None => None,
+            }
+        } else {
+            None
+        };
+
+        let mode = if !rounding_mode.is_null() {
+            let s = parse_jstring(&mut env, &rounding_mode, "rounding mode");
+            match s {
+                Some(s) => match RoundingMode::from_str(&s) {
+                    Ok(m) => Some(m),
+                    Err(_) => {
+                        throw_range_error(&mut env, &format!("Invalid rounding mode: {}", s));
+                        return ptr::null_mut();
+                    }
+                },
+                None => None,
+            }
+        } else {
+            None
+        };
+
+        let increment_opt = match validate_rounding_increment(rounding_increment, smallest, IncrementContext::Difference) {
+            Ok(i) => Some(i),
+            Err(message) => {
+                throw_range_error(&mut env, &message);
+                return ptr::null_mut();
+            }
+        };
+
+        let mut options = temporal_rs::options::DifferenceSettings::default();
+        options.largest_unit = largest;
+        options.smallest_unit = smallest;
+        options.rounding_mode = mode;
+        options.increment = increment_opt;
+
+        match one_inst.until(&two_inst, options) {
+            Ok(d) => env
+                .new_string(d.to_string())
+                .map(|js| js.into_raw())
+                .unwrap_or(ptr::null_mut()),
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Failed to compute difference: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.instantSince()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_instantSince(
+        mut env: JNIEnv,
+        _class: JClass,
+        one: JString,
+        two: JString,
+        largest_unit: JString,
+        smallest_unit: JString,
+        rounding_increment: jlong,
+        rounding_mode: JString,
+    ) -> jstring {
+        let one_inst = match parse_instant(&mut env, &one, "first instant") {
+            Some(i) => i,
+            None => return ptr::null_mut(),
+        };
+        let two_inst = match parse_instant(&mut env, &two, "second instant") {
+            Some(i) => i,
+            None => return ptr::null_mut(),
+        };
+
+        let largest = if !largest_unit.is_null() {
+            let s = parse_jstring(&mut env, &largest_unit, "largest unit");
+            match s {
+                Some(s) => match Unit::from_str(&s) {
+                    Ok(u) => Some(u),
+                    Err(_) => {
+                        throw_range_error(&mut env, &format!("Invalid largest unit: {}", s));
+                        return ptr::null_mut();
+                    }
+                },
+                None => None,
+            }
+        } else {
+            None
+        };
+
+        let smallest = if !smallest_unit.is_null() {
+            let s = parse_jstring(&mut env, &smallest_unit, "smallest unit");
+            match s {
+                Some(s) => match Unit::from_str(&s) {
+                    Ok(u) => Some(u),
+                    Err(_) => {
+                        throw_range_error(&mut env, &format!("Invalid smallest unit: {}", s));
+                        return ptr::null_mut();
+                    }
+                },
+                None => None,
+            }
+        } else {
+            None
+        };
+
+        let mode = if !rounding_mode.is_null() {
+            let s = parse_jstring(&mut env, &rounding_mode, "rounding mode");
+            match s {
+                Some(s) => match RoundingMode::from_str(&s) {
+                    Ok(m) => Some(m),
+                    Err(_) => {
+                        throw_range_error(&mut env, &format!("Invalid rounding mode: {}", s));
+                        return ptr::null_mut();
+                    }
+                },
+                None => None,
+            }
+        } else {
+            None
+        };
+
+        let increment_opt = match validate_rounding_increment(rounding_increment, smallest, IncrementContext::Difference) {
+            Ok(i) => Some(i),
+            Err(message) => {
+                throw_range_error(&mut env, &message);
+                return ptr::null_mut();
+            }
+        };
+
+        let mut options = temporal_rs::options::DifferenceSettings::default();
+        options.largest_unit = largest;
+        options.smallest_unit = smallest;
+        options.rounding_mode = mode;
+        options.increment = increment_opt;
+
+        match one_inst.since(&two_inst, options) {
+            Ok(d) => env
+                .new_string(d.to_string())
+                .map(|js| js.into_raw())
+                .unwrap_or(ptr::null_mut()),
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Failed to compute difference: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.instantRound()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_instantRound(
+        mut env: JNIEnv,
+        _class: JClass,
+        instant_str: JString,
+        smallest_unit: JString,
+        rounding_increment: jlong,
+        rounding_mode: JString,
+    ) -> jstring {
+        let instant = match parse_instant(&mut env, &instant_str, "instant") {
+            Some(i) => i,
+            None => return ptr::null_mut(),
+        };
+
+        let unit = if !smallest_unit.is_null() {
+            let s = parse_jstring(&mut env, &smallest_unit, "smallest unit");
+            match s {
+                Some(s) => match Unit::from_str(&s) {
+                    Ok(u) => u,
+                    Err(_) => {
+                        throw_range_error(&mut env, &format!("Invalid smallest unit: {}", s));
+                        return ptr::null_mut();
+                    }
+                },
+                None => {
+                    throw_type_error(&mut env, "smallestUnit is required");
+                    return ptr::null_mut();
+                }
+            }
+        } else {
+            throw_type_error(&mut env, "smallestUnit is required");
+            return ptr::null_mut();
+        };
+
+        let mode = if !rounding_mode.is_null() {
+            let s = parse_jstring(&mut env, &rounding_mode, "rounding mode");
+            match s {
+                Some(s) => match RoundingMode::from_str(&s) {
+                    Ok(m) => m,
+                    Err(_) => {
+                        throw_range_error(&mut env, &format!("Invalid rounding mode: {}", s));
+                        return ptr::null_mut();
+                    }
+                },
+                None => RoundingMode::HalfExpand,
+            }
+        } else {
+            RoundingMode::HalfExpand
+        };
+
+        let increment_opt = match validate_rounding_increment(rounding_increment, Some(unit), IncrementContext::InstantRound) {
+            Ok(i) => i,
+            Err(message) => {
+                throw_range_error(&mut env, &message);
+                return ptr::null_mut();
+            }
+        };
+
+        let mut options = RoundingOptions::default();
+        options.smallest_unit = Some(unit);
+        options.rounding_mode = Some(mode);
+        options.increment = Some(increment_opt);
+
+        match instant.round(options) {
+            Ok(result) => {
+                let provider = &*COMPILED_TZ_PROVIDER;
+                match result.to_ixdtf_string_with_provider(None, display::rounding(), &provider) {
+                    Ok(s) => env
+                        .new_string(s)
+                        .map(|js| js.into_raw())
+                        .unwrap_or(ptr::null_mut()),
+                    Err(e) => {
+                        throw_range_error(&mut env, &format!("Failed to format instant: {}", e));
+                        ptr::null_mut()
+                    }
+                }
+            },
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Failed to round: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.instantToZonedDateTime()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_instantToZonedDateTime(
+        mut env: JNIEnv,
+        _class: JClass,
+        instant_str: JString,
+        calendar_id: JString,
+        time_zone_id: JString,
+    ) -> jstring {
+        let instant = match parse_instant(&mut env, &instant_str, "instant") {
+            Some(i) => i,
+            None => return ptr::null_mut(),
+        };
+
+        let calendar = if !calendar_id.is_null() {
+            let s = parse_jstring(&mut env, &calendar_id, "calendar id");
+            match s {
+                Some(s) => match Calendar::from_str(&s) {
+                    Ok(c) => c,
+                    Err(e) => {
+                        throw_range_error(&mut env, &format!("Invalid calendar: {}", e));
+                        return ptr::null_mut();
+                    }
+                },
+                None => Calendar::default(),
+            }
+        } else {
+            Calendar::default()
+        };
+
+        let tz_str = if !time_zone_id.is_null() {
+            parse_jstring(&mut env, &time_zone_id, "timezone id")
+        } else {
+            throw_type_error(&mut env, "Timezone ID is required");
+            return ptr::null_mut();
+        };
+
+        let tz = match tz_str {
+            Some(s) => match TimeZone::try_from_str(&s) {
+                Ok(t) => t,
+                Err(e) => {
+                    throw_range_error(&mut env, &format!("Invalid timezone: {}", e));
+                    return ptr::null_mut();
+                }
+            },
+            None => {
+                throw_type_error(&mut env, "Timezone ID is required");
+                return ptr::null_mut();
+            }
+        };
+        
+        match ZonedDateTime::try_new(instant.epoch_nanoseconds().0, tz, calendar) {
+            Ok(zdt) => match zdt.to_ixdtf_string(display::offset(), display::time_zone(), display::calendar(), display::rounding()) {
+                Ok(s) => env
+                    .new_string(s)
+                    .map(|js| js.into_raw())
+                    .unwrap_or(ptr::null_mut()),
+                Err(e) => {
+                    throw_range_error(&mut env, &format!("Failed to format zoned date time: {}", e));
+                    ptr::null_mut()
+                }
+            },
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Failed to convert to zoned date time: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.nowPlainDateTimeISO()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_nowPlainDateTimeISO(
+        mut env: JNIEnv,
+        _class: JClass,
+        tz_id: JString,
+    ) -> jstring {
+        let tz_str = parse_jstring(&mut env, &tz_id, "timezone id");
+        let tz_val = match tz_str {
+            Some(s) => s,
+            None => return ptr::null_mut(),
+        };
+        
+        match get_now_plain_date_time_string(&tz_val) {
+            Ok(s) => env
+                .new_string(s)
+                .map(|js| js.into_raw())
+                .unwrap_or(ptr::null_mut()),
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Failed to get plain date time: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.nowPlainDateISO()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_nowPlainDateISO(
+        mut env: JNIEnv,
+        _class: JClass,
+        tz_id: JString,
+    ) -> jstring {
+        let tz_str = parse_jstring(&mut env, &tz_id, "timezone id");
+        let tz_val = match tz_str {
+            Some(s) => s,
+            None => return ptr::null_mut(),
+        };
+        
+        match get_now_plain_date_string(&tz_val) {
+            Ok(s) => env
+                .new_string(s)
+                .map(|js| js.into_raw())
+                .unwrap_or(ptr::null_mut()),
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Failed to get plain date: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.nowPlainTimeISO()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_nowPlainTimeISO(
+        mut env: JNIEnv,
+        _class: JClass,
+        tz_id: JString,
+    ) -> jstring {
+        let tz_str = parse_jstring(&mut env, &tz_id, "timezone id");
+        let tz_val = match tz_str {
+            Some(s) => s,
+            None => return ptr::null_mut(),
+        };
+        
+        match get_now_plain_time_string(&tz_val) {
+            Ok(s) => env
+                .new_string(s)
+                .map(|js| js.into_raw())
+                .unwrap_or(ptr::null_mut()),
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Failed to get plain time: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.nowZonedDateTimeISO()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_nowZonedDateTimeISO(
+        mut env: JNIEnv,
+        _class: JClass,
+        tz_id: JString,
+    ) -> jstring {
+        let tz_str = parse_jstring(&mut env, &tz_id, "timezone id");
+        let tz_val = match tz_str {
+            Some(s) => s,
+            None => return ptr::null_mut(),
+        };
+        
+        match get_now_zoned_date_time_string(&tz_val) {
+            Ok(s) => env
+                .new_string(s)
+                .map(|js| js.into_raw())
+                .unwrap_or(ptr::null_mut()),
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Failed to get zoned date time: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// Parses a PlainTime string, throwing RangeError if invalid
+    fn parse_plain_time(env: &mut JNIEnv, s: &JString, name: &str) -> Option<PlainTime> {
+        let s_str = parse_jstring(env, s, name)?;
+        match PlainTime::from_str(&s_str) {
+            Ok(t) => Some(t),
+            Err(e) => {
+                throw_range_error(env, &format!("Invalid plain time '{}': {}", s_str, e));
+                None
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainTimeFromString()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainTimeFromString(
+        mut env: JNIEnv,
+        _class: JClass,
+        s: JString,
+    ) -> jstring {
+        canonical_jstring(&mut env, &s, "plain time string", Kind::PlainTime, |input| match PlainTime::from_str(input) {
+            Ok(time) => time.to_ixdtf_string(display::rounding()).map_err(|e| format!("Failed to format plain time: {}", e)),
+            Err(e) => Err(format!("Invalid plain time '{}': {}", input, e)),
+        })
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainTimeFromComponents()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainTimeFromComponents(
+        mut env: JNIEnv,
+        _class: JClass,
+        hour: jint,
+        minute: jint,
+        second: jint,
+        millisecond: jint,
+        microsecond: jint,
+        nanosecond: jint,
+    ) -> jstring {
+        // Validate ranges before casting to narrower types
+        if !check_time_components(&mut env, hour, minute, second, millisecond, microsecond, nanosecond) {
+            return ptr::null_mut();
+        }
+
+        match PlainTime::new(
+            hour as u8,
+            minute as u8,
+            second as u8,
+            millisecond as u16,
+            microsecond as u16,
+            nanosecond as u16
+        ) {
+            Ok(time) => match time.to_ixdtf_string(display::rounding()) {
+                Ok(s) => env
+                    .new_string(s)
+                    .map(|js| js.into_raw())
+                    .unwrap_or_else(|_| {
+                        throw_range_error(&mut env, "Failed to create result string");
+                        ptr::null_mut()
+                    }),
+                Err(e) => {
+                    throw_range_error(&mut env, &format!("Failed to format plain time: {}", e));
+                    ptr::null_mut()
+                }
+            },
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid plain time components: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainTimeGetAllComponents()`
+    /// Returns: [hour, minute, second, millisecond, microsecond, nanosecond]
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainTimeGetAllComponents(
+        mut env: JNIEnv,
+        _class: JClass,
+        s: JString,
+    ) -> jlongArray {
+        let time = match parse_plain_time(&mut env, &s, "plain time string") {
+            Some(t) => t,
+            None => return ptr::null_mut(),
+        };
+
+        let components: [i64; PLAIN_TIME_SLOTS.len()] = [
+            time.hour() as i64,
+            time.minute() as i64,
+            time.second() as i64,
+            time.millisecond() as i64,
+            time.microsecond() as i64,
+            time.nanosecond() as i64,
+        ];
+
+        match env.new_long_array(components.len() as i32) {
+            Ok(arr) => {
+                if env.set_long_array_region(&arr, 0, &components).is_err() {
+                    throw_range_error(&mut env, "Failed to set array elements");
+                    return ptr::null_mut();
+                }
+                arr.into_raw()
+            }
+            Err(_) => {
+                throw_range_error(&mut env, "Failed to create result array");
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainTimeAdd()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainTimeAdd(
+        mut env: JNIEnv,
+        _class: JClass,
+        time_str: JString,
+        duration_str: JString,
+    ) -> jstring {
+        let time = match parse_plain_time(&mut env, &time_str, "plain time") {
+            Some(t) => t,
+            None => return ptr::null_mut(),
+        };
+        let duration = match parse_duration(&mut env, &duration_str, "duration") {
+            Some(d) => d,
+            None => return ptr::null_mut(),
+        };
+
+        match time.add(&duration) {
+            Ok(result) => match result.to_ixdtf_string(display::rounding()) {
+                Ok(s) => env
+                    .new_string(s)
+                    .map(|js| js.into_raw())
+                    .unwrap_or(ptr::null_mut()),
+                Err(e) => {
+                    throw_range_error(&mut env, &format!("Failed to format plain time: {}", e));
+                    ptr::null_mut()
+                }
+            },
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Failed to add duration: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainTimeSubtract()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainTimeSubtract(
+        mut env: JNIEnv,
+        _class: JClass,
+        time_str: JString,
+        duration_str: JString,
+    ) -> jstring {
+        let time = match parse_plain_time(&mut env, &time_str, "plain time") {
+            Some(t) => t,
+            None => return ptr::null_mut(),
+        };
+        let duration = match parse_duration(&mut env, &duration_str, "duration") {
+            Some(d) => d,
+            None => return ptr::null_mut(),
+        };
+
+        match time.subtract(&duration) {
+            Ok(result) => match result.to_ixdtf_string(display::rounding()) {
+                Ok(s) => env
+                    .new_string(s)
+                    .map(|js| js.into_raw())
+                    .unwrap_or(ptr::null_mut()),
+                Err(e) => {
+                    throw_range_error(&mut env, &format!("Failed to format plain time: {}", e));
+                    ptr::null_mut()
+                }
+            },
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Failed to subtract duration: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainTimeCompare()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainTimeCompare(
+        mut env: JNIEnv,
+        _class: JClass,
+        a: JString,
+        b: JString,
+    ) -> jint {
+        let time_a = match parse_plain_time(&mut env, &a, "first plain time") {
+            Some(t) => t,
+            None => return 0,
+        };
+        let time_b = match parse_plain_time(&mut env, &b, "second plain time") {
+            Some(t) => t,
+            None => return 0,
+        };
+
+        time_a.cmp(&time_b) as jint
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainTimeUntil()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainTimeUntil(
+        mut env: JNIEnv,
+        _class: JClass,
+        one: JString,
+        two: JString,
+        largest_unit: JString,
+        smallest_unit: JString,
+        rounding_increment: jlong,
+        rounding_mode: JString,
+    ) -> jstring {
+        let one_time = match parse_plain_time(&mut env, &one, "first plain time") {
+            Some(t) => t,
+            None => return ptr::null_mut(),
+        };
+        let two_time = match parse_plain_time(&mut env, &two, "second plain time") {
+            Some(t) => t,
+            None => return ptr::null_mut(),
+        };
+
+        let largest = if !largest_unit.is_null() {
+            let s = parse_jstring(&mut env, &largest_unit, "largest unit");
+            match s {
+                Some(s) => match Unit::from_str(&s) {
+                    Ok(u) => Some(u),
+                    Err(_) => {
+                        throw_range_error(&mut env, &format!("Invalid largest unit: {}", s));
+                        return ptr::null_mut();
+                    }
+                },
+                None => None,
+            }
+        } else {
+            None
+        };
+
+        let smallest = if !smallest_unit.is_null() {
+            let s = parse_jstring(&mut env, &smallest_unit, "smallest unit");
+            match s {
+                Some(s) => match Unit::from_str(&s) {
+                    Ok(u) => Some(u),
+                    Err(_) => {
+                        throw_range_error(&mut env, &format!("Invalid smallest unit: {}", s));
+                        return ptr::null_mut();
+                    }
+                },
+                None => None,
+            }
+        } else {
+            None
+        };
+
+        let mode = if !rounding_mode.is_null() {
+            let s = parse_jstring(&mut env, &rounding_mode, "rounding mode");
+            match s {
+                Some(s) => match RoundingMode::from_str(&s) {
+                    Ok(m) => Some(m),
+                    Err(_) => {
+                        throw_range_error(&mut env, &format!("Invalid rounding mode: {}", s));
+                        return ptr::null_mut();
+                    }
+                },
+                None => None,
+            }
+        } else {
+            None
+        };
+
+        let increment_opt = match validate_rounding_increment(rounding_increment, smallest, IncrementContext::Difference) {
+            Ok(i) => Some(i),
+            Err(message) => {
+                throw_range_error(&mut env, &message);
+                return ptr::null_mut();
+            }
+        };
+
+        let mut options = temporal_rs::options::DifferenceSettings::default();
+        options.largest_unit = largest;
+        options.smallest_unit = smallest;
+        options.rounding_mode = mode;
+        options.increment = increment_opt;
+
+        match one_time.until(&two_time, options) {
+            Ok(d) => env
+                .new_string(d.to_string())
+                .map(|js| js.into_raw())
+                .unwrap_or(ptr::null_mut()),
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Failed to compute difference: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainTimeSince()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainTimeSince(
+        mut env: JNIEnv,
+        _class: JClass,
+        one: JString,
+        two: JString,
+        largest_unit: JString,
+        smallest_unit: JString,
+        rounding_increment: jlong,
+        rounding_mode: JString,
+    ) -> jstring {
+        let one_time = match parse_plain_time(&mut env, &one, "first plain time") {
+            Some(t) => t,
+            None => return ptr::null_mut(),
+        };
+        let two_time = match parse_plain_time(&mut env, &two, "second plain time") {
+            Some(t) => t,
+            None => return ptr::null_mut(),
+        };
+
+        let largest = if !largest_unit.is_null() {
+            let s = parse_jstring(&mut env, &largest_unit, "largest unit");
+            match s {
+                Some(s) => match Unit::from_str(&s) {
+                    Ok(u) => Some(u),
+                    Err(_) => {
+                        throw_range_error(&mut env, &format!("Invalid largest unit: {}", s));
+                        return ptr::null_mut();
+                    }
+                },
+                None => None,
+            }
+        } else {
+            None
+        };
+
+        let smallest = if !smallest_unit.is_null() {
+            let s = parse_jstring(&mut env, &smallest_unit, "smallest unit");
+            match s {
+                Some(s) => match Unit::from_str(&s) {
+                    Ok(u) => Some(u),
+                    Err(_) => {
+                        throw_range_error(&mut env, &format!("Invalid smallest unit: {}", s));
+                        return ptr::null_mut();
+                    }
+                },
+                None => None,
+            }
+        } else {
+            None
+        };
+
+        let mode = if !rounding_mode.is_null() {
+            let s = parse_jstring(&mut env, &rounding_mode, "rounding mode");
+            match s {
+                Some(s) => match RoundingMode::from_str(&s) {
+                    Ok(m) => Some(m),
+                    Err(_) => {
+                        throw_range_error(&mut env, &format!("Invalid rounding mode: {}", s));
+                        return ptr::null_mut();
+                    }
+                },
+                None => None,
+            }
+        } else {
+            None
+        };
+
+        let increment_opt = match validate_rounding_increment(rounding_increment, smallest, IncrementContext::Difference) {
+            Ok(i) => Some(i),
+            Err(message) => {
+                throw_range_error(&mut env, &message);
+                return ptr::null_mut();
+            }
+        };
+
+        let mut options = temporal_rs::options::DifferenceSettings::default();
+        options.largest_unit = largest;
+        options.smallest_unit = smallest;
+        options.rounding_mode = mode;
+        options.increment = increment_opt;
+
+        match one_time.since(&two_time, options) {
+            Ok(d) => env
+                .new_string(d.to_string())
+                .map(|js| js.into_raw())
+                .unwrap_or(ptr::null_mut()),
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Failed to compute difference: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainTimeRound()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainTimeRound(
+        mut env: JNIEnv,
+        _class: JClass,
+        time_str: JString,
+        smallest_unit: JString,
+        rounding_increment: jlong,
+        rounding_mode: JString,
+    ) -> jstring {
+        let time = match parse_plain_time(&mut env, &time_str, "plain time") {
+            Some(t) => t,
+            None => return ptr::null_mut(),
+        };
+
+        let unit = if !smallest_unit.is_null() {
+            let s = parse_jstring(&mut env, &smallest_unit, "smallest unit");
+            match s {
+                Some(s) => match Unit::from_str(&s) {
+                    Ok(u) => u,
+                    Err(_) => {
+                        throw_range_error(&mut env, &format!("Invalid smallest unit: {}", s));
+                        return ptr::null_mut();
+                    }
+                },
+                None => {
+                    throw_type_error(&mut env, "smallestUnit is required");
+                    return ptr::null_mut();
+                }
+            }
+        } else {
+            throw_type_error(&mut env, "smallestUnit is required");
+            return ptr::null_mut();
+        };
+
+        let mode = if !rounding_mode.is_null() {
+            let s = parse_jstring(&mut env, &rounding_mode, "rounding mode");
+            match s {
+                Some(s) => match RoundingMode::from_str(&s) {
+                    Ok(m) => m,
+                    Err(_) => {
+                        throw_range_error(&mut env, &format!("Invalid rounding mode: {}", s));
+                        return ptr::null_mut();
+                    }
+                },
+                None => RoundingMode::HalfExpand,
+            }
+        } else {
+            RoundingMode::HalfExpand
+        };
+
+        let increment_opt = match validate_rounding_increment(rounding_increment, Some(unit), IncrementContext::Round) {
+            Ok(i) => i,
+            Err(message) => {
+                throw_range_error(&mut env, &message);
+                return ptr::null_mut();
+            }
+        };
+
+        let mut options = RoundingOptions::default();
+        options.smallest_unit = Some(unit);
+        options.rounding_mode = Some(mode);
+        options.increment = Some(increment_opt);
+
+        match time.round(options) {
+            Ok(t) => match t.to_ixdtf_string(display::rounding()) {
+                Ok(s) => env
+                    .new_string(s)
+                    .map(|js| js.into_raw())
+                    .unwrap_or(ptr::null_mut()),
+                Err(e) => {
+                    throw_range_error(&mut env, &format!("Failed to format plain time: {}", e));
+                    ptr::null_mut()
+                }
+            },
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Failed to round: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// Parses a PlainDate string, throwing RangeError if invalid
+    fn parse_plain_date(env: &mut JNIEnv, s: &JString, name: &str) -> Option<PlainDate> {
+        let s_str = parse_jstring(env, s, name)?;
+        match PlainDate::from_str(&s_str) {
+            Ok(d) => Some(d),
+            Err(e) => {
+                throw_range_error(env, &format!("Invalid plain date '{}': {}", s_str, e));
+                None
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainDateFromString()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateFromString(
+        mut env: JNIEnv,
+        _class: JClass,
+        s: JString,
+    ) -> jstring {
+        canonical_jstring(&mut env, &s, "plain date string", Kind::PlainDate, |input| match PlainDate::from_str(input) {
+            Ok(date) => Ok(date.to_ixdtf_string(display::calendar())),
+            Err(e) => Err(format!("Invalid plain date '{}': {}", input, e)),
+        })
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainDateFromComponents()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateFromComponents(
+        mut env: JNIEnv,
+        _class: JClass,
+        year: jint,
+        month: jint,
+        day: jint,
+        calendar_id: JString,
+    ) -> jstring {
+        let month = match narrow_date_component(&mut env, "month", month) {
+            Some(v) => v,
+            None => return ptr::null_mut(),
+        };
+        let day = match narrow_date_component(&mut env, "day", day) {
+            Some(v) => v,
+            None => return ptr::null_mut(),
+        };
+
+        let calendar = if !calendar_id.is_null() {
+            let id_str = parse_jstring(&mut env, &calendar_id, "calendar id");
+            match id_str {
+                Some(s) => match Calendar::from_str(&s) {
+                    Ok(c) => c,
+                    Err(e) => {
+                        throw_range_error(&mut env, &format!("Invalid calendar: {}", e));
+                        return ptr::null_mut();
+                    }
+                },
+                None => return ptr::null_mut(),
+            }
+        } else {
+            Calendar::default()
+        };
+
+        match PlainDate::new(year, month, day, calendar) {
+            Ok(date) => env
+                .new_string(date.to_ixdtf_string(display::calendar()))
+                .map(|js| js.into_raw())
+                .unwrap_or_else(|_| {
+                    throw_range_error(&mut env, "Failed to create result string");
+                    ptr::null_mut()
+                }),
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid plain date components: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainDatePack()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainDatePack(
+        mut env: JNIEnv,
+        _class: JClass,
+        s: JString,
+    ) -> jlong {
+        match parse_plain_date(&mut env, &s, "plain date") {
+            Some(date) => pack_date(&date),
+            None => 0,
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainDateUnpack()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateUnpack(
+        mut env: JNIEnv,
+        _class: JClass,
+        packed: jlong,
+        calendar_id: JString,
+    ) -> jstring {
+        let calendar = if !calendar_id.is_null() {
+            match parse_jstring(&mut env, &calendar_id, "calendar id").map(|s| Calendar::from_str(&s)) {
+                Some(Ok(c)) => c,
+                Some(Err(e)) => {
+                    throw_range_error(&mut env, &format!("Invalid calendar: {}", e));
+                    return ptr::null_mut();
+                }
+                None => return ptr::null_mut(),
+            }
+        } else {
+            Calendar::default()
+        };
+
+        match unpack_date(packed, calendar) {
+            Ok(date) => env
+                .new_string(date.to_ixdtf_string(display::calendar()))
+                .map(|js| js.into_raw())
+                .unwrap_or_else(|_| {
+                    throw_range_error(&mut env, "Failed to create result string");
+                    ptr::null_mut()
+                }),
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid packed date {}: {}", packed, e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainDateGetAllComponents()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateGetAllComponents(
+        mut env: JNIEnv,
+        _class: JClass,
+        s: JString,
+    ) -> jlongArray {
+        let date = match parse_plain_date(&mut env, &s, "plain date string") {
+            Some(d) => d,
+            None => return ptr::null_mut(),
+        };
+
+        let (quarter, day_of_quarter) = quarter_info(&date);
+        let (week_of_year, year_of_week, week_mask) = week_info(date.week_of_year(), date.year_of_week());
+        let components: [i64; PLAIN_DATE_SLOTS.len()] = [
+            date.year() as i64,
+            date.month() as i64,
+            date.day() as i64,
+            date.day_of_week() as i64,
+            date.day_of_year() as i64,
+            week_of_year,
+            year_of_week,
+            date.days_in_week() as i64,
+            date.days_in_month() as i64,
+            date.days_in_year() as i64,
+            date.months_in_year() as i64,
+            if date.in_leap_year() { 1 } else { 0 },
+            quarter as i64,
+            day_of_quarter as i64,
+            week_mask,
+        ];
+
+        match env.new_long_array(components.len() as i32) {
+            Ok(arr) => {
+                if env.set_long_array_region(&arr, 0, &components).is_err() {
+                    throw_range_error(&mut env, "Failed to set array elements");
+                    return ptr::null_mut();
+                }
+                arr.into_raw()
+            }
+            Err(_) => {
+                throw_range_error(&mut env, "Failed to create result array");
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainDateGetMonthCode()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateGetMonthCode(
+        mut env: JNIEnv,
+        _class: JClass,
+        s: JString,
+    ) -> jstring {
+        let date = match parse_plain_date(&mut env, &s, "plain date string") {
+            Some(d) => d,
+            None => return ptr::null_mut(),
+        };
+        env.new_string(date.month_code().as_str())
+            .map(|js| js.into_raw())
+            .unwrap_or_else(|_| {
+                throw_range_error(&mut env, "Failed to create result string");
+                ptr::null_mut()
+            })
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainDateGetCalendar()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateGetCalendar(
+        mut env: JNIEnv,
+        _class: JClass,
+        s: JString,
+    ) -> jstring {
+        let date = match parse_plain_date(&mut env, &s, "plain date string") {
+            Some(d) => d,
+            None => return ptr::null_mut(),
+        };
+        env.new_string(date.calendar().identifier())
+            .map(|js| js.into_raw())
+            .unwrap_or_else(|_| {
+                throw_range_error(&mut env, "Failed to create result string");
+                ptr::null_mut()
+            })
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainDateAdd()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateAdd(
+        mut env: JNIEnv,
+        _class: JClass,
+        date_str: JString,
+        duration_str: JString,
+    ) -> jstring {
+        let date = match parse_plain_date(&mut env, &date_str, "plain date") {
+            Some(d) => d,
+            None => return ptr::null_mut(),
+        };
+        let duration = match parse_duration(&mut env, &duration_str, "duration") {
+            Some(d) => d,
+            None => return ptr::null_mut(),
+        };
+
+        match date.add(&duration, None) {
+            Ok(result) => env
+                .new_string(result.to_ixdtf_string(display::calendar()))
+                .map(|js| js.into_raw())
+                .unwrap_or_else(|_| {
+                    throw_range_error(&mut env, "Failed to create result string");
+                    ptr::null_mut()
+                }),
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Failed to add duration: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainDateSubtract()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateSubtract(
+        mut env: JNIEnv,
+        _class: JClass,
+        date_str: JString,
+        duration_str: JString,
+    ) -> jstring {
+        let date = match parse_plain_date(&mut env, &date_str, "plain date") {
+            Some(d) => d,
+            None => return ptr::null_mut(),
+        };
+        let duration = match parse_duration(&mut env, &duration_str, "duration") {
+            Some(d) => d,
+            None => return ptr::null_mut(),
+        };
+
+        match date.subtract(&duration, None) {
+            Ok(result) => env
+                .new_string(result.to_ixdtf_string(display::calendar()))
+                .map(|js| js.into_raw())
+                .unwrap_or_else(|_| {
+                    throw_range_error(&mut env, "Failed to create result string");
+                    ptr::null_mut()
+                }),
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Failed to subtract duration: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainDateAddMonthsConstrained()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateAddMonthsConstrained(
+        mut env: JNIEnv,
+        _class: JClass,
+        date_str: JString,
+        months: jlong,
+    ) -> jstring {
+        let date = match parse_plain_date(&mut env, &date_str, "plain date") {
+            Some(d) => d,
+            None => return ptr::null_mut(),
+        };
+
+        match add_months_constrained(&date, months) {
+            Ok(result) => env
+                .new_string(result.to_ixdtf_string(display::calendar()))
+                .map(|js| js.into_raw())
+                .unwrap_or_else(|_| {
+                    throw_range_error(&mut env, "Failed to create result string");
+                    ptr::null_mut()
+                }),
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Failed to add months: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainDateCompare()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateCompare(
+        mut env: JNIEnv,
+        _class: JClass,
+        a: JString,
+        b: JString,
+    ) -> jint {
+        let date_a = match parse_plain_date(&mut env, &a, "first plain date") {
+            Some(d) => d,
+            None => return 0,
+        };
+        let date_b = match parse_plain_date(&mut env, &b, "second plain date") {
+            Some(d) => d,
+            None => return 0,
+        };
+
+        // Fallback to string comparison for now
+        let s_a = date_a.to_ixdtf_string(DisplayCalendar::Never);
+        let s_b = date_b.to_ixdtf_string(DisplayCalendar::Never);
+
+        s_a.cmp(&s_b) as jint
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainDateWith()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateWith(
+        mut env: JNIEnv,
+        _class: JClass,
+        date_str: JString,
+        year: jint,
+        month: jint,
+        day: jint,
+        calendar_id: JString,
+    ) -> jstring {
+        let date = match parse_plain_date(&mut env, &date_str, "plain date") {
+            Some(d) => d,
+            None => return ptr::null_mut(),
+        };
+
+        let new_year = if year == i32::MIN { date.year() } else { year };
+        let new_month = if month == i32::MIN { date.month() } else {
+            match narrow_date_component(&mut env, "month", month) {
+                Some(v) => v,
+                None => return ptr::null_mut(),
+            }
+        };
+        let new_day = if day == i32::MIN { date.day() } else {
+            match narrow_date_component(&mut env, "day", day) {
+                Some(v) => v,
+                None => return ptr::null_mut(),
+            }
+        };
+
+        let new_calendar = if !calendar_id.is_null() {
+            let id_str = parse_jstring(&mut env, &calendar_id, "calendar id");
+            match id_str {
+                Some(s) => match Calendar::from_str(&s) {
+                    Ok(c) => c,
+                    Err(e) => {
+                        throw_range_error(&mut env, &format!("Invalid calendar: {}", e));
+                        return ptr::null_mut();
+                    }
+                },
+                None => return ptr::null_mut(),
+            }
+        } else {
+            date.calendar().clone()
+        };
+
+        match PlainDate::new(new_year, new_month, new_day, new_calendar) {
+            Ok(new_date) => env
+                .new_string(new_date.to_ixdtf_string(display::calendar()))
+                .map(|js| js.into_raw())
+                .unwrap_or_else(|_| {
+                    throw_range_error(&mut env, "Failed to create result string");
+                    ptr::null_mut()
+                }),
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid date components: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainDateUntil()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateUntil(
+        mut env: JNIEnv,
+        _class: JClass,
+        one: JString,
+        two: JString,
+    ) -> jstring {
+        let d1 = match parse_plain_date(&mut env, &one, "first plain date") {
+            Some(d) => d,
+            None => return ptr::null_mut(),
+        };
+        let d2 = match parse_plain_date(&mut env, &two, "second plain date") {
+            Some(d) => d,
+            None => return ptr::null_mut(),
+        };
+
+        match d1.until(&d2, Default::default()) {
+            Ok(d) => env
+                .new_string(d.to_string())
+                .map(|js| js.into_raw())
+                .unwrap_or_else(|_| {
+                    throw_range_error(&mut env, "Failed to create result string");
+                    ptr::null_mut()
+                }),
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Failed to compute until: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainDateSince()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateSince(
+        mut env: JNIEnv,
+        _class: JClass,
+        one: JString,
+        two: JString,
+    ) -> jstring {
+        let d1 = match parse_plain_date(&mut env, &one, "first plain date") {
+            Some(d) => d,
+            None => return ptr::null_mut(),
+        };
+        let d2 = match parse_plain_date(&mut env, &two, "second plain date") {
+            Some(d) => d,
+            None => return ptr::null_mut(),
+        };
+
+        match d1.since(&d2, Default::default()) {
+            Ok(d) => env
+                .new_string(d.to_string())
+                .map(|js| js.into_raw())
+                .unwrap_or_else(|_| {
+                    throw_range_error(&mut env, "Failed to create result string");
+                    ptr::null_mut()
+                }),
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Failed to compute since: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainDateTimeFromString()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateTimeFromString(
+        mut env: JNIEnv,
+        _class: JClass,
+        s: JString,
+    ) -> jstring {
+        canonical_jstring(&mut env, &s, "plain date time string", Kind::PlainDateTime, |input| match PlainDateTime::from_str(input) {
+            Ok(dt) => dt
+                .to_ixdtf_string(display::rounding(), display::calendar())
+                .map_err(|e| format!("Failed to format plain date time: {}", e)),
+            Err(e) => Err(format!("Invalid plain date time '{}': {}", input, e)),
+        })
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainDateTimeFromComponents()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateTimeFromComponents(
+        mut env: JNIEnv,
+        _class: JClass,
+        year: jint,
+        month: jint,
+        day: jint,
+        hour: jint,
+        minute: jint,
+        second: jint,
+        millisecond: jint,
+        microsecond: jint,
+        nanosecond: jint,
+        calendar_id: JString,
+    ) -> jstring {
+        let month = match narrow_date_component(&mut env, "month", month) {
+            Some(v) => v,
+            None => return ptr::null_mut(),
+        };
+        let day = match narrow_date_component(&mut env, "day", day) {
+            Some(v) => v,
+            None => return ptr::null_mut(),
+        };
+        if !check_time_components(&mut env, hour, minute, second, millisecond, microsecond, nanosecond) {
+            return ptr::null_mut();
+        }
+
+        let calendar = if !calendar_id.is_null() {
+            let id_str = parse_jstring(&mut env, &calendar_id, "calendar id");
+            match id_str {
+                Some(s) => match Calendar::from_str(&s) {
+                    Ok(c) => c,
+                    Err(e) => {
+                        throw_range_error(&mut env, &format!("Invalid calendar: {}", e));
+                        return ptr::null_mut();
+                    }
+                },
+                None => return ptr::null_mut(),
+            }
+        } else {
+            Calendar::default()
+        };
+
+        match PlainDateTime::new(
+            year,
+            month,
+            day,
+            hour as u8,
+            minute as u8,
+            second as u8,
+            millisecond as u16,
+            microsecond as u16,
+            nanosecond as u16,
+            calendar
+        ) {
+            Ok(dt) => match dt.to_ixdtf_string(display::rounding(), display::calendar()) {
+                Ok(s) => env
+                    .new_string(s)
+                    .map(|js| js.into_raw())
+                    .unwrap_or(ptr::null_mut()),
+                Err(e) => {
+                    throw_range_error(&mut env, &format!("Failed to format plain date time: {}", e));
+                    ptr::null_mut()
+                }
+            },
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid plain date time components: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainDateTimeGetAllComponents()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateTimeGetAllComponents(
+        mut env: JNIEnv,
+        _class: JClass,
+        s: JString,
+    ) -> jlongArray {
+        let s_str = parse_jstring(&mut env, &s, "plain date time string");
+        let s_val = match s_str {
+            Some(s) => s,
+            None => return ptr::null_mut(),
+        };
+        
+        let dt = match PlainDateTime::from_str(&s_val) {
+            Ok(d) => d,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid plain date time: {}", e));
+                return ptr::null_mut();
+            }
+        };
+
+        let (quarter, day_of_quarter) = quarter_info(&dt.to_plain_date());
+        let (week_of_year, year_of_week, week_mask) = week_info(dt.week_of_year(), dt.year_of_week());
+        let components: [i64; PLAIN_DATE_TIME_SLOTS.len()] = [
+            dt.year() as i64,
+            dt.month() as i64,
+            dt.day() as i64,
+            dt.day_of_week() as i64,
+            dt.day_of_year() as i64,
+            week_of_year,
+            year_of_week,
+            dt.days_in_week() as i64,
+            dt.days_in_month() as i64,
+            dt.days_in_year() as i64,
+            dt.months_in_year() as i64,
+            if dt.in_leap_year() { 1 } else { 0 },
+            dt.hour() as i64,
+            dt.minute() as i64,
+            dt.second() as i64,
+            dt.millisecond() as i64,
+            dt.microsecond() as i64,
+            dt.nanosecond() as i64,
+            quarter as i64,
+            day_of_quarter as i64,
+            week_mask,
+        ];
+
+        match env.new_long_array(components.len() as i32) {
+            Ok(arr) => {
+                if env.set_long_array_region(&arr, 0, &components).is_err() {
+                    throw_range_error(&mut env, "Failed to set array elements");
+                    return ptr::null_mut();
+                }
+                arr.into_raw()
+            }
+            Err(_) => {
+                throw_range_error(&mut env, "Failed to create result array");
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainDateTimeGetMonthCode()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateTimeGetMonthCode(
+        mut env: JNIEnv,
+        _class: JClass,
+        s: JString,
+    ) -> jstring {
+        let s_str = parse_jstring(&mut env, &s, "plain date time string");
+        let s_val = match s_str {
+            Some(s) => s,
+            None => return ptr::null_mut(),
+        };
+        match PlainDateTime::from_str(&s_val) {
+            Ok(dt) => env.new_string(dt.month_code().as_str())
+                .map(|js| js.into_raw())
+                .unwrap_or(ptr::null_mut()),
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid plain date time: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainDateTimeGetCalendar()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateTimeGetCalendar(
+        mut env: JNIEnv,
+        _class: JClass,
+        s: JString,
+    ) -> jstring {
+        let s_str = parse_jstring(&mut env, &s, "plain date time string");
+        let s_val = match s_str {
+            Some(s) => s,
+            None => return ptr::null_mut(),
+        };
+        match PlainDateTime::from_str(&s_val) {
+            Ok(dt) => env.new_string(dt.calendar().identifier())
+                .map(|js| js.into_raw())
+                .unwrap_or(ptr::null_mut()),
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid plain date time: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainDateTimeAdd()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateTimeAdd(
+        mut env: JNIEnv,
+        _class: JClass,
+        dt_str: JString,
+        duration_str: JString,
+    ) -> jstring {
+        let dt_s = parse_jstring(&mut env, &dt_str, "plain date time");
+        let dt_val = match dt_s {
+            Some(s) => s,
+            None => return ptr::null_mut(),
+        };
+        let dt = match PlainDateTime::from_str(&dt_val) {
+            Ok(d) => d,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid plain date time: {}", e));
+                return ptr::null_mut();
+            }
+        };
+
+        let dur_s = parse_jstring(&mut env, &duration_str, "duration");
+        let dur_val = match dur_s {
+            Some(s) => s,
+            None => return ptr::null_mut(),
+        };
+        let duration = match duration_from_str(&dur_val) {
+            Ok(d) => d,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid duration: {}", e));
+                return ptr::null_mut();
+            }
+        };
+
+        match dt.add(&duration, None) {
+            Ok(result) => match result.to_ixdtf_string(display::rounding(), display::calendar()) {
+                Ok(s) => env
+                    .new_string(s)
+                    .map(|js| js.into_raw())
+                    .unwrap_or(ptr::null_mut()),
+                Err(e) => {
+                    throw_range_error(&mut env, &format!("Failed to format result: {}", e));
+                    ptr::null_mut()
+                }
+            },
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Failed to add duration: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainDateTimeSubtract()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateTimeSubtract(
+        mut env: JNIEnv,
+        _class: JClass,
+        dt_str: JString,
+        duration_str: JString,
+    ) -> jstring {
+        let dt_s = parse_jstring(&mut env, &dt_str, "plain date time");
+        let dt_val = match dt_s {
+            Some(s) => s,
+            None => return ptr::null_mut(),
+        };
+        let dt = match PlainDateTime::from_str(&dt_val) {
+            Ok(d) => d,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid plain date time: {}", e));
+                return ptr::null_mut();
+            }
+        };
+
+        let dur_s = parse_jstring(&mut env, &duration_str, "duration");
+        let dur_val = match dur_s {
+            Some(s) => s,
+            None => return ptr::null_mut(),
+        };
+        let duration = match duration_from_str(&dur_val) {
+            Ok(d) => d,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid duration: {}", e));
+                return ptr::null_mut();
+            }
+        };
+
+        match dt.subtract(&duration, None) {
+            Ok(result) => match result.to_ixdtf_string(display::rounding(), display::calendar()) {
+                Ok(s) => env
+                    .new_string(s)
+                    .map(|js| js.into_raw())
+                    .unwrap_or(ptr::null_mut()),
+                Err(e) => {
+                    throw_range_error(&mut env, &format!("Failed to format result: {}", e));
+                    ptr::null_mut()
+                }
+            },
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Failed to subtract duration: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainDateTimeCompare()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateTimeCompare(
+        mut env: JNIEnv,
+        _class: JClass,
+        a: JString,
+        b: JString,
+    ) -> jint {
+        let a_str = parse_jstring(&mut env, &a, "first plain date time");
+        let a_val = match a_str {
+            Some(s) => s,
+            None => return 0,
+        };
+        let dt_a = match PlainDateTime::from_str(&a_val) {
+            Ok(d) => d,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid plain date time '{}': {}", a_val, e));
+                return 0;
+            }
+        };
+
+        let b_str = parse_jstring(&mut env, &b, "second plain date time");
+        let b_val = match b_str {
+            Some(s) => s,
+            None => return 0,
+        };
+        let dt_b = match PlainDateTime::from_str(&b_val) {
+            Ok(d) => d,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid plain date time '{}': {}", b_val, e));
+                return 0;
+            }
+        };
+
+        dt_a.compare_iso(&dt_b) as jint
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainDateTimeWith()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateTimeWith(
+        mut env: JNIEnv,
+        _class: JClass,
+        dt_str: JString,
+        year: jint,
+        month: jint,
+        day: jint,
+        hour: jint,
+        minute: jint,
+        second: jint,
+        millisecond: jint,
+        microsecond: jint,
+        nanosecond: jint,
+        calendar_id: JString,
+    ) -> jstring {
+        let s_str = parse_jstring(&mut env, &dt_str, "plain date time");
+        let s_val = match s_str {
+            Some(s) => s,
+            None => return ptr::null_mut(),
+        };
+        let dt = match PlainDateTime::from_str(&s_val) {
+            Ok(d) => d,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid plain date time: {}", e));
+                return ptr::null_mut();
+            }
+        };
+
+        let new_year = if year == i32::MIN { dt.year() } else { year };
+        let new_month = if month == i32::MIN { dt.month() } else {
+            match narrow_date_component(&mut env, "month", month) {
+                Some(v) => v,
+                None => return ptr::null_mut(),
+            }
+        };
+        let new_day = if day == i32::MIN { dt.day() } else {
+            match narrow_date_component(&mut env, "day", day) {
+                Some(v) => v,
+                None => return ptr::null_mut(),
+            }
+        };
+
+        // Fill in the unchanged fields first so the range check sees the final values
+        let keep = |value: jint, current: u16| if value == i32::MIN { current as jint } else { value };
+        let hour = keep(hour, dt.hour() as u16);
+        let minute = keep(minute, dt.minute() as u16);
+        let second = keep(second, dt.second() as u16);
+        let millisecond = keep(millisecond, dt.millisecond());
+        let microsecond = keep(microsecond, dt.microsecond());
+        let nanosecond = keep(nanosecond, dt.nanosecond());
+        if !check_time_components(&mut env, hour, minute, second, millisecond, microsecond, nanosecond) {
+            return ptr::null_mut();
+        }
+        let (new_hour, new_minute, new_second) = (hour as u8, minute as u8, second as u8);
+        let (new_millisecond, new_microsecond, new_nanosecond) = (millisecond as u16, microsecond as u16, nanosecond as u16);
+
+        let new_calendar = if !calendar_id.is_null() {
+            let id_str = parse_jstring(&mut env, &calendar_id, "calendar id");
+            match id_str {
+                Some(s) => match Calendar::from_str(&s) {
+                    Ok(c) => c,
+                    Err(e) => {
+                        throw_range_error(&mut env, &format!("Invalid calendar: {}", e));
+                        return ptr::null_mut();
+                    }
+                },
+                None => return ptr::null_mut(),
+            }
+        } else {
+            dt.calendar().clone()
+        };
+
+        match PlainDateTime::new(
+            new_year, new_month, new_day,
+            new_hour, new_minute, new_second,
+            new_millisecond, new_microsecond, new_nanosecond,
+            new_calendar
+        ) {
+             Ok(new_dt) => match new_dt.to_ixdtf_string(display::rounding(), display::calendar()) {
+                 Ok(s) => env
+                    .new_string(s)
+                    .map(|js| js.into_raw())
+                    .unwrap_or(ptr::null_mut()),
+                 Err(e) => {
+                     throw_range_error(&mut env, &format!("Failed to format plain date time: {}", e));
+                     ptr::null_mut()
+                 }
+             },
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid date components: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainDateTimeUntil()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateTimeUntil(
+        mut env: JNIEnv,
+        _class: JClass,
+        one: JString,
+        two: JString,
+    ) -> jstring {
+        let one_str = parse_jstring(&mut env, &one, "first plain date time");
+        let one_val = match one_str {
+            Some(s) => s,
+            None => return ptr::null_mut(),
+        };
+        let dt1 = match PlainDateTime::from_str(&one_val) {
+            Ok(d) => d,
+            Err(_) => return ptr::null_mut(),
+        };
+
+        let two_str = parse_jstring(&mut env, &two, "second plain date time");
+        let two_val = match two_str {
+            Some(s) => s,
+            None => return ptr::null_mut(),
+        };
+        let dt2 = match PlainDateTime::from_str(&two_val) {
+            Ok(d) => d,
+            Err(_) => return ptr::null_mut(),
+        };
+
+        match dt1.until(&dt2, Default::default()) {
+            Ok(d) => env
+                .new_string(d.to_string())
+                .map(|js| js.into_raw())
+                .unwrap_or(ptr::null_mut()),
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Failed to compute until: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainDateTimeSince()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateTimeSince(
+        mut env: JNIEnv,
+        _class: JClass,
+        one: JString,
+        two: JString,
+    ) -> jstring {
+        let one_str = parse_jstring(&mut env, &one, "first plain date time");
+        let one_val = match one_str {
+            Some(s) => s,
+            None => return ptr::null_mut(),
+        };
+        let dt1 = match PlainDateTime::from_str(&one_val) {
+            Ok(d) => d,
+            Err(_) => return ptr::null_mut(),
+        };
+
+        let two_str = parse_jstring(&mut env, &two, "second plain date time");
+        let two_val = match two_str {
+            Some(s) => s,
+            None => return ptr::null_mut(),
+        };
+        let dt2 = match PlainDateTime::from_str(&two_val) {
+            Ok(d) => d,
+            Err(_) => return ptr::null_mut(),
+        };
+
+        match dt1.since(&dt2, Default::default()) {
+            Ok(d) => env
+                .new_string(d.to_string())
+                .map(|js| js.into_raw())
+                .unwrap_or(ptr::null_mut()),
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Failed to compute since: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainYearMonthFromString()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainYearMonthFromString(
+        mut env: JNIEnv,
+        _class: JClass,
+        s: JString,
+    ) -> jstring {
+        canonical_jstring(&mut env, &s, "plain year month string", Kind::PlainYearMonth, |input| match PlainYearMonth::from_str(input) {
+            Ok(ym) => Ok(ym.to_ixdtf_string(display::calendar())),
+            Err(e) => Err(format!("Invalid plain year month '{}': {}", input, e)),
+        })
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainYearMonthFromComponents()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainYearMonthFromComponents(
+        mut env: JNIEnv,
+        _class: JClass,
+        year: jint,
+        month: jint,
+        calendar_id: JString,
+        _reference_day: jint,
+    ) -> jstring {
+        let month = match narrow_date_component(&mut env, "month", month) {
+            Some(v) => v,
+            None => return ptr::null_mut(),
+        };
+
+        let calendar = if !calendar_id.is_null() {
+            let id_str = parse_jstring(&mut env, &calendar_id, "calendar id");
+            match id_str {
+                Some(s) => match Calendar::from_str(&s) {
+                    Ok(c) => c,
+                    Err(e) => {
+                        throw_range_error(&mut env, &format!("Invalid calendar: {}", e));
+                        return ptr::null_mut();
+                    }
+                },
+                None => return ptr::null_mut(),
+            }
+        } else {
+            Calendar::default()
+        };
+
+        match PlainYearMonth::new(year, month, None, calendar) {
+            Ok(ym) => env.new_string(ym.to_ixdtf_string(display::calendar()))
+                .map(|js| js.into_raw())
+                .unwrap_or(ptr::null_mut()),
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid plain year month components: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainYearMonthGetAllComponents()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainYearMonthGetAllComponents(
+        mut env: JNIEnv,
+        _class: JClass,
+        s: JString,
+    ) -> jlongArray {
+        let s_str = parse_jstring(&mut env, &s, "plain year month string");
+        let s_val = match s_str {
+            Some(s) => s,
+            None => return ptr::null_mut(),
+        };
+        
+        let ym: PlainYearMonth = match PlainYearMonth::from_str(&s_val) {
+            Ok(y) => y,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid plain year month: {}", e));
+                return ptr::null_mut();
+            }
+        };
+
+        let components: [i64; PLAIN_YEAR_MONTH_SLOTS.len()] = [
+            ym.year() as i64,
+            ym.month() as i64,
+            0, // PlainYearMonth does not have a day
+            ym.days_in_month() as i64,
+            ym.days_in_year() as i64,
+            ym.months_in_year() as i64,
+            if ym.in_leap_year() { 1 } else { 0 },
+            ym.era_year().unwrap_or(0) as i64,
+        ];
+
+        match env.new_long_array(components.len() as i32) {
+            Ok(arr) => {
+                if env.set_long_array_region(&arr, 0, &components).is_err() {
+                    throw_range_error(&mut env, "Failed to set array elements");
+                    return ptr::null_mut();
+                }
+                arr.into_raw()
+            }
+            Err(_) => {
+                throw_range_error(&mut env, "Failed to create result array");
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainYearMonthGetMonthCode()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainYearMonthGetMonthCode(
+        mut env: JNIEnv,
+        _class: JClass,
+        s: JString,
+    ) -> jstring {
+        let s_str = parse_jstring(&mut env, &s, "plain year month string");
+        let s_val = match s_str {
+            Some(s) => s,
+            None => return ptr::null_mut(),
+        };
+        match PlainYearMonth::from_str(&s_val) {
+            Ok(ym) => env.new_string(ym.month_code().as_str())
+                .map(|js| js.into_raw())
+                .unwrap_or(ptr::null_mut()),
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid plain year month: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainYearMonthGetCalendar()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_instantAdd(
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainYearMonthGetCalendar(
         mut env: JNIEnv,
         _class: JClass,
-        instant_str: JString,
+        s: JString,
+    ) -> jstring {
+        let s_str = parse_jstring(&mut env, &s, "plain year month string");
+        let s_val = match s_str {
+            Some(s) => s,
+            None => return ptr::null_mut(),
+        };
+        match PlainYearMonth::from_str(&s_val) {
+            Ok(ym) => env.new_string(ym.calendar().identifier())
+                .map(|js| js.into_raw())
+                .unwrap_or(ptr::null_mut()),
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid plain year month: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainYearMonthAdd()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainYearMonthAdd(
+        mut env: JNIEnv,
+        _class: JClass,
+        ym_str: JString,
         duration_str: JString,
+        overflow: JString,
     ) -> jstring {
-        let instant = match parse_instant(&mut env, &instant_str, "instant") {
-            Some(i) => i,
+        let ym_s = parse_jstring(&mut env, &ym_str, "plain year month");
+        let ym_val = match ym_s {
+            Some(s) => s,
             None => return ptr::null_mut(),
         };
-        let duration = match parse_duration(&mut env, &duration_str, "duration") {
+        let ym = match PlainYearMonth::from_str(&ym_val) {
+            Ok(y) => y,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid plain year month: {}", e));
+                return ptr::null_mut();
+            }
+        };
+
+        let dur_s = parse_jstring(&mut env, &duration_str, "duration");
+        let dur_val = match dur_s {
+            Some(s) => s,
+            None => return ptr::null_mut(),
+        };
+        let duration = match duration_from_str(&dur_val) {
+            Ok(d) => d,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid duration: {}", e));
+                return ptr::null_mut();
+            }
+        };
+
+        let overflow = match parse_overflow(&mut env, &overflow) {
+            Some(o) => o,
+            None => return ptr::null_mut(),
+        };
+
+        match ym.add(&duration, overflow) {
+            Ok(result) => env.new_string(result.to_ixdtf_string(display::calendar()))
+                .map(|js| js.into_raw())
+                .unwrap_or(ptr::null_mut()),
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Failed to add duration: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainYearMonthSubtract()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainYearMonthSubtract(
+        mut env: JNIEnv,
+        _class: JClass,
+        ym_str: JString,
+        duration_str: JString,
+        overflow: JString,
+    ) -> jstring {
+        let ym_s = parse_jstring(&mut env, &ym_str, "plain year month");
+        let ym_val = match ym_s {
+            Some(s) => s,
+            None => return ptr::null_mut(),
+        };
+        let ym = match PlainYearMonth::from_str(&ym_val) {
+            Ok(y) => y,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid plain year month: {}", e));
+                return ptr::null_mut();
+            }
+        };
+
+        let dur_s = parse_jstring(&mut env, &duration_str, "duration");
+        let dur_val = match dur_s {
+            Some(s) => s,
+            None => return ptr::null_mut(),
+        };
+        let duration = match duration_from_str(&dur_val) {
+            Ok(d) => d,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid duration: {}", e));
+                return ptr::null_mut();
+            }
+        };
+
+        let overflow = match parse_overflow(&mut env, &overflow) {
+            Some(o) => o,
+            None => return ptr::null_mut(),
+        };
+
+        match ym.subtract(&duration, overflow) {
+            Ok(result) => env.new_string(result.to_ixdtf_string(display::calendar()))
+                .map(|js| js.into_raw())
+                .unwrap_or(ptr::null_mut()),
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Failed to subtract duration: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainYearMonthAddWithReferenceDay()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainYearMonthAddWithReferenceDay(
+        mut env: JNIEnv,
+        _class: JClass,
+        ym_str: JString,
+        duration_str: JString,
+        overflow: JString,
+        reference_day: jint,
+    ) -> jstring {
+        year_month_add_with_reference_day(&mut env, &ym_str, &duration_str, &overflow, reference_day, false)
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainYearMonthSubtractWithReferenceDay()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainYearMonthSubtractWithReferenceDay(
+        mut env: JNIEnv,
+        _class: JClass,
+        ym_str: JString,
+        duration_str: JString,
+        overflow: JString,
+        reference_day: jint,
+    ) -> jstring {
+        year_month_add_with_reference_day(&mut env, &ym_str, &duration_str, &overflow, reference_day, true)
+    }
+
+    fn year_month_add_with_reference_day(
+        env: &mut JNIEnv,
+        ym_str: &JString,
+        duration_str: &JString,
+        overflow: &JString,
+        reference_day: jint,
+        subtract: bool,
+    ) -> jstring {
+        let ym_val = match parse_jstring(env, ym_str, "plain year month") {
+            Some(s) => s,
+            None => return ptr::null_mut(),
+        };
+        let ym = match PlainYearMonth::from_str(&ym_val) {
+            Ok(y) => y,
+            Err(e) => {
+                throw_range_error(env, &format!("Invalid plain year month: {}", e));
+                return ptr::null_mut();
+            }
+        };
+        let duration = match parse_duration(env, duration_str, "duration") {
             Some(d) => d,
             None => return ptr::null_mut(),
         };
-        
-        match instant.add(&duration) {
-            Ok(result) => {
-                let provider = &*COMPILED_TZ_PROVIDER;
-                match result.to_ixdtf_string_with_provider(None, Default::default(), &provider) {
-                    Ok(s) => env
-                        .new_string(s)
-                        .map(|js| js.into_raw())
-                        .unwrap_or(ptr::null_mut()),
+        let overflow = match parse_overflow(env, overflow) {
+            Some(o) => o,
+            None => return ptr::null_mut(),
+        };
+
+        let duration = if subtract { duration.negated() } else { duration };
+        match add_to_year_month_from_day(&ym, &duration, overflow, reference_day) {
+            Ok(result) => env.new_string(result.to_ixdtf_string(display::calendar()))
+                .map(|js| js.into_raw())
+                .unwrap_or_else(|_| {
+                    throw_range_error(env, "Failed to create result string");
+                    ptr::null_mut()
+                }),
+            Err(e) => {
+                let operation = if subtract { "subtract" } else { "add" };
+                throw_range_error(env, &format!("Failed to {} duration: {}", operation, e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainYearMonthCompare()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainYearMonthCompare(
+        mut env: JNIEnv,
+        _class: JClass,
+        a: JString,
+        b: JString,
+    ) -> jint {
+        let a_str = parse_jstring(&mut env, &a, "first plain year month");
+        let a_val = match a_str {
+            Some(s) => s,
+            None => return 0,
+        };
+        let ym_a: PlainYearMonth = match PlainYearMonth::from_str(&a_val) {
+            Ok(y) => y,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid plain year month '{}': {}", a_val, e));
+                return 0;
+            }
+        };
+
+        let b_str = parse_jstring(&mut env, &b, "second plain year month");
+        let b_val = match b_str {
+            Some(s) => s,
+            None => return 0,
+        };
+        let ym_b: PlainYearMonth = match PlainYearMonth::from_str(&b_val) {
+            Ok(y) => y,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid plain year month '{}': {}", b_val, e));
+                return 0;
+            }
+        };
+
+        // Fallback to string comparison for now
+        let s_a = ym_a.to_ixdtf_string(DisplayCalendar::Never);
+        let s_b = ym_b.to_ixdtf_string(DisplayCalendar::Never);
+
+        s_a.cmp(&s_b) as jint
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainYearMonthWith()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainYearMonthWith(
+        mut env: JNIEnv,
+        _class: JClass,
+        ym_str: JString,
+        year: jint,
+        month: jint,
+        calendar_id: JString,
+    ) -> jstring {
+        let ym_s = parse_jstring(&mut env, &ym_str, "plain year month");
+        let ym_val = match ym_s {
+            Some(s) => s,
+            None => return ptr::null_mut(),
+        };
+        let ym: PlainYearMonth = match PlainYearMonth::from_str(&ym_val) {
+            Ok(y) => y,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid plain year month: {}", e));
+                return ptr::null_mut();
+            }
+        };
+
+        let new_year = if year == i32::MIN { ym.year() } else { year };
+        let new_month = if month == i32::MIN { ym.month() } else {
+            match narrow_date_component(&mut env, "month", month) {
+                Some(v) => v,
+                None => return ptr::null_mut(),
+            }
+        };
+
+        let new_calendar = if !calendar_id.is_null() {
+            let id_str = parse_jstring(&mut env, &calendar_id, "calendar id");
+            match id_str {
+                Some(s) => match Calendar::from_str(&s) {
+                    Ok(c) => c,
                     Err(e) => {
-                        throw_range_error(&mut env, &format!("Failed to format instant: {}", e));
-                        ptr::null_mut()
+                        throw_range_error(&mut env, &format!("Invalid calendar: {}", e));
+                        return ptr::null_mut();
                     }
-                }
-            },
+                },
+                None => return ptr::null_mut(),
+            }
+        } else {
+            ym.calendar().clone()
+        };
+
+        match PlainYearMonth::new(new_year, new_month, None, new_calendar) {
+            Ok(new_ym) => env.new_string(new_ym.to_ixdtf_string(display::calendar()))
+                .map(|js| js.into_raw())
+                .unwrap_or(ptr::null_mut()),
             Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to add duration: {}", e));
+                throw_range_error(&mut env, &format!("Invalid components: {}", e));
                 ptr::null_mut()
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.instantSubtract()`
-
+    /// JNI function for `com.temporal.TemporalNative.plainYearMonthUntil()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_instantSubtract(
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainYearMonthUntil(
         mut env: JNIEnv,
         _class: JClass,
-        instant_str: JString,
-        duration_str: JString,
+        one: JString,
+        two: JString,
     ) -> jstring {
-        let instant = match parse_instant(&mut env, &instant_str, "instant") {
-            Some(i) => i,
+        let one_str = parse_jstring(&mut env, &one, "first plain year month");
+        let one_val = match one_str {
+            Some(s) => s,
             None => return ptr::null_mut(),
         };
-        let duration = match parse_duration(&mut env, &duration_str, "duration") {
-            Some(d) => d,
+        let ym1: PlainYearMonth = match PlainYearMonth::from_str(&one_val) {
+            Ok(y) => y,
+            Err(_) => return ptr::null_mut(),
+        };
+
+        let two_str = parse_jstring(&mut env, &two, "second plain year month");
+        let two_val = match two_str {
+            Some(s) => s,
             None => return ptr::null_mut(),
         };
-        
-        match instant.subtract(&duration) {
-            Ok(result) => {
-                let provider = &*COMPILED_TZ_PROVIDER;
-                match result.to_ixdtf_string_with_provider(None, Default::default(), &provider) {
-                    Ok(s) => env
-                        .new_string(s)
-                        .map(|js| js.into_raw())
-                        .unwrap_or(ptr::null_mut()),
-                    Err(e) => {
-                        throw_range_error(&mut env, &format!("Failed to format instant: {}", e));
-                        ptr::null_mut()
-                    }
-                }
-            },
+        let ym2: PlainYearMonth = match PlainYearMonth::from_str(&two_val) {
+            Ok(y) => y,
+            Err(_) => return ptr::null_mut(),
+        };
+
+        match ym1.until(&ym2, Default::default()) {
+            Ok(d) => env.new_string(d.to_string())
+                .map(|js| js.into_raw())
+                .unwrap_or(ptr::null_mut()),
             Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to subtract duration: {}", e));
+                throw_range_error(&mut env, &format!("Failed to compute until: {}", e));
                 ptr::null_mut()
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.instantCompare()`
-
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_instantCompare(
-        mut env: JNIEnv,
-        _class: JClass,
-        a: JString,
-        b: JString,
-    ) -> jint {
-        let instant_a = match parse_instant(&mut env, &a, "first instant") {
-            Some(i) => i,
-            None => return 0,
-        };
-        let instant_b = match parse_instant(&mut env, &b, "second instant") {
-            Some(i) => i,
-            None => return 0,
-        };
-        
-        instant_a.cmp(&instant_b) as jint
-    }
-
-    /// JNI function for `com.temporal.TemporalNative.instantUntil()`
+    /// JNI function for `com.temporal.TemporalNative.plainYearMonthSince()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_instantUntil(
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainYearMonthSince(
         mut env: JNIEnv,
         _class: JClass,
         one: JString,
         two: JString,
-        largest_unit: JString,
-        smallest_unit: JString,
-        rounding_increment: jlong,
-        rounding_mode: JString,
     ) -> jstring {
-        let one_inst = match parse_instant(&mut env, &one, "first instant") {
-            Some(i) => i,
+        let one_str = parse_jstring(&mut env, &one, "first plain year month");
+        let one_val = match one_str {
+            Some(s) => s,
             None => return ptr::null_mut(),
         };
-        let two_inst = match parse_instant(&mut env, &two, "second instant") {
-            Some(i) => i,
-            None => return ptr::null_mut(),
+        let ym1: PlainYearMonth = match PlainYearMonth::from_str(&one_val) {
+            Ok(y) => y,
+            Err(_) => return ptr::null_mut(),
         };
 
-        let largest = if !largest_unit.is_null() {
-            let s = parse_jstring(&mut env, &largest_unit, "largest unit");
-            match s {
-                Some(s) => match Unit::from_str(&s) {
-                    Ok(u) => Some(u),
-                    Err(_) => {
-                        throw_range_error(&mut env, &format!("Invalid largest unit: {}", s));
-                        return ptr::null_mut();
-                    }
-                },
-                None => None, // null passed
-            }
-        } else {
-            None
+        let two_str = parse_jstring(&mut env, &two, "second plain year month");
+        let two_val = match two_str {
+            Some(s) => s,
+            None => return ptr::null_mut(),
         };
-
-        let smallest = if !smallest_unit.is_null() {
-            let s = parse_jstring(&mut env, &smallest_unit, "smallest unit");
-            match s {
-                Some(s) => match Unit::from_str(&s) {
-                    Ok(u) => Some(u),
-                    Err(_) => {
-                        throw_range_error(&mut env, &format!("Invalid smallest unit: {}", s));
-                        return ptr::null_mut();
-                    }
-                },
-                None => None,
-            }
-        } else {
-            None
+        let ym2: PlainYearMonth = match PlainYearMonth::from_str(&two_val) {
+            Ok(y) => y,
+            Err(_) => return ptr::null_mut(),
         };
 
-        let mode = if !rounding_mode.is_null() {
-            let s = parse_jstring(&mut env, &rounding_mode, "rounding mode");
-            match s {
-                Some(s) => match RoundingMode::from_str(&s) {
-                    Ok(m) => Some(m),
-                    Err(_) => {
-                        throw_range_error(&mut env, &format!("Invalid rounding mode: {}", s));
-                        return ptr::null_mut();
-                    }
-                },
-                None => None,
+        match ym1.since(&ym2, Default::default()) {
+            Ok(d) => env.new_string(d.to_string())
+                .map(|js| js.into_raw())
+                .unwrap_or(ptr::null_mut()),
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Failed to compute since: {}", e));
+                ptr::null_mut()
             }
-        } else {
-            None
-        };
+        }
+    }
 
-        let increment = if rounding_increment > 0 {
-            rounding_increment as u32
-        } else {
-            1
+    /// JNI function for `com.temporal.TemporalNative.plainYearMonthToPlainDate()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainYearMonthToPlainDate(
+        mut env: JNIEnv,
+        _class: JClass,
+        ym_str: JString,
+        day: jint,
+    ) -> jstring {
+        let ym_s = parse_jstring(&mut env, &ym_str, "plain year month");
+        let ym_val = match ym_s {
+            Some(s) => s,
+            None => return ptr::null_mut(),
         };
-        
-        let increment_opt = match RoundingIncrement::try_new(increment) {
-            Ok(i) => Some(i),
+        let ym: PlainYearMonth = match PlainYearMonth::from_str(&ym_val) {
+            Ok(y) => y,
             Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid rounding increment: {}", e));
+                throw_range_error(&mut env, &format!("Invalid plain year month: {}", e));
                 return ptr::null_mut();
             }
         };
 
-        let mut options = temporal_rs::options::DifferenceSettings::default();
-        options.largest_unit = largest;
-        options.smallest_unit = smallest;
-        options.rounding_mode = mode;
-        options.increment = increment_opt;
+        let day = match narrow_date_component(&mut env, "day", day) {
+            Some(v) => v,
+            None => return ptr::null_mut(),
+        };
 
-        match one_inst.until(&two_inst, options) {
-            Ok(d) => env
-                .new_string(d.to_string())
+        match PlainDate::new(ym.year(), ym.month(), day, ym.calendar().clone()) {
+            Ok(d) => env.new_string(d.to_ixdtf_string(display::calendar()))
                 .map(|js| js.into_raw())
                 .unwrap_or(ptr::null_mut()),
             Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to compute difference: {}", e));
+                throw_range_error(&mut env, &format!("Failed to convert to plain date: {}", e));
                 ptr::null_mut()
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.instantSince()`
+    /// JNI function for `com.temporal.TemporalNative.plainMonthDayFromString()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_instantSince(
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainMonthDayFromString(
         mut env: JNIEnv,
         _class: JClass,
-        one: JString,
-        two: JString,
-        largest_unit: JString,
-        smallest_unit: JString,
-        rounding_increment: jlong,
-        rounding_mode: JString,
+        s: JString,
     ) -> jstring {
-        let one_inst = match parse_instant(&mut env, &one, "first instant") {
-            Some(i) => i,
+        canonical_jstring(&mut env, &s, "plain month day string", Kind::PlainMonthDay, |input| match PlainMonthDay::from_str(input) {
+            Ok(md) => Ok(md.to_ixdtf_string(display::calendar())),
+            Err(e) => Err(format!("Invalid plain month day '{}': {}", input, e)),
+        })
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.plainMonthDayFromComponents()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainMonthDayFromComponents(
+        mut env: JNIEnv,
+        _class: JClass,
+        month: jint,
+        day: jint,
+        calendar_id: JString,
+        reference_year: jint,
+        overflow: JString,
+    ) -> jstring {
+        let month = match narrow_date_component(&mut env, "month", month) {
+            Some(v) => v,
             None => return ptr::null_mut(),
         };
-        let two_inst = match parse_instant(&mut env, &two, "second instant") {
-            Some(i) => i,
+        let day = match narrow_date_component(&mut env, "day", day) {
+            Some(v) => v,
             None => return ptr::null_mut(),
         };
 
-        let largest = if !largest_unit.is_null() {
-            let s = parse_jstring(&mut env, &largest_unit, "largest unit");
-            match s {
-                Some(s) => match Unit::from_str(&s) {
-                    Ok(u) => Some(u),
-                    Err(_) => {
-                        throw_range_error(&mut env, &format!("Invalid largest unit: {}", s));
-                        return ptr::null_mut();
-                    }
-                },
-                None => None,
-            }
-        } else {
-            None
-        };
-
-        let smallest = if !smallest_unit.is_null() {
-            let s = parse_jstring(&mut env, &smallest_unit, "smallest unit");
-            match s {
-                Some(s) => match Unit::from_str(&s) {
-                    Ok(u) => Some(u),
-                    Err(_) => {
-                        throw_range_error(&mut env, &format!("Invalid smallest unit: {}", s));
-                        return ptr::null_mut();
-                    }
-                },
-                None => None,
-            }
-        } else {
-            None
-        };
-
-        let mode = if !rounding_mode.is_null() {
-            let s = parse_jstring(&mut env, &rounding_mode, "rounding mode");
-            match s {
-                Some(s) => match RoundingMode::from_str(&s) {
-                    Ok(m) => Some(m),
-                    Err(_) => {
-                        throw_range_error(&mut env, &format!("Invalid rounding mode: {}", s));
+        let calendar = if !calendar_id.is_null() {
+            let id_str = parse_jstring(&mut env, &calendar_id, "calendar id");
+            match id_str {
+                Some(s) => match Calendar::from_str(&s) {
+                    Ok(c) => c,
+                    Err(e) => {
+                        throw_range_error(&mut env, &format!("Invalid calendar: {}", e));
                         return ptr::null_mut();
                     }
                 },
-                None => None,
+                None => return ptr::null_mut(),
             }
         } else {
-            None
+            Calendar::default()
         };
 
-        let increment = if rounding_increment > 0 {
-            rounding_increment as u32
-        } else {
-            1
-        };
-        
-        let increment_opt = match RoundingIncrement::try_new(increment) {
-            Ok(i) => Some(i),
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid rounding increment: {}", e));
-                return ptr::null_mut();
-            }
+        let overflow = match parse_overflow(&mut env, &overflow) {
+            Some(o) => o,
+            None => return ptr::null_mut(),
         };
 
-        let mut options = temporal_rs::options::DifferenceSettings::default();
-        options.largest_unit = largest;
-        options.smallest_unit = smallest;
-        options.rounding_mode = mode;
-        options.increment = increment_opt;
-
-        match one_inst.since(&two_inst, options) {
-            Ok(d) => env
-                .new_string(d.to_string())
+        match plain_month_day_from_fields(month, day, calendar, overflow, reference_year) {
+            Ok(md) => env.new_string(md.to_ixdtf_string(display::calendar()))
                 .map(|js| js.into_raw())
                 .unwrap_or(ptr::null_mut()),
             Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to compute difference: {}", e));
+                throw_range_error(&mut env, &format!("Invalid plain month day components: {}", e));
                 ptr::null_mut()
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.instantRound()`
+    /// JNI function for `com.temporal.TemporalNative.plainMonthDayGetAllComponents()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_instantRound(
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainMonthDayGetAllComponents(
         mut env: JNIEnv,
         _class: JClass,
-        instant_str: JString,
-        smallest_unit: JString,
-        rounding_increment: jlong,
-        rounding_mode: JString,
-    ) -> jstring {
-        let instant = match parse_instant(&mut env, &instant_str, "instant") {
-            Some(i) => i,
+        s: JString,
+    ) -> jlongArray {
+        let s_str = parse_jstring(&mut env, &s, "plain month day string");
+        let s_val = match s_str {
+            Some(s) => s,
             None => return ptr::null_mut(),
         };
+        
+        let md = match PlainMonthDay::from_str(&s_val) {
+            Ok(m) => m,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid plain month day: {}", e));
+                return ptr::null_mut();
+            }
+        };
 
-        let unit = if !smallest_unit.is_null() {
-            let s = parse_jstring(&mut env, &smallest_unit, "smallest unit");
-            match s {
-                Some(s) => match Unit::from_str(&s) {
-                    Ok(u) => u,
-                    Err(_) => {
-                        throw_range_error(&mut env, &format!("Invalid smallest unit: {}", s));
-                        return ptr::null_mut();
-                    }
-                },
-                None => {
-                    throw_type_error(&mut env, "smallestUnit is required");
+        let components: [i64; PLAIN_MONTH_DAY_SLOTS.len()] = [
+            md.calendar().month(&md.iso) as i64,
+            md.day() as i64,
+        ];
+
+        match env.new_long_array(components.len() as i32) {
+            Ok(arr) => {
+                if env.set_long_array_region(&arr, 0, &components).is_err() {
+                    throw_range_error(&mut env, "Failed to set array elements");
                     return ptr::null_mut();
                 }
+                arr.into_raw()
             }
-        } else {
-            throw_type_error(&mut env, "smallestUnit is required");
-            return ptr::null_mut();
-        };
-
-        let mode = if !rounding_mode.is_null() {
-            let s = parse_jstring(&mut env, &rounding_mode, "rounding mode");
-            match s {
-                Some(s) => match RoundingMode::from_str(&s) {
-                    Ok(m) => m,
-                    Err(_) => {
-                        throw_range_error(&mut env, &format!("Invalid rounding mode: {}", s));
-                        return ptr::null_mut();
-                    }
-                },
-                None => RoundingMode::HalfExpand,
+            Err(_) => {
+                throw_range_error(&mut env, "Failed to create result array");
+                ptr::null_mut()
             }
-        } else {
-            RoundingMode::HalfExpand
-        };
+        }
+    }
 
-        let increment = if rounding_increment > 0 {
-            rounding_increment as u32
-        } else {
-            1
+    /// JNI function for `com.temporal.TemporalNative.plainMonthDayGetMonthCode()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainMonthDayGetMonthCode(
+        mut env: JNIEnv,
+        _class: JClass,
+        s: JString,
+    ) -> jstring {
+        let s_str = parse_jstring(&mut env, &s, "plain month day string");
+        let s_val = match s_str {
+            Some(s) => s,
+            None => return ptr::null_mut(),
         };
-        
-        let increment_opt = match RoundingIncrement::try_new(increment) {
-            Ok(i) => i,
+        match PlainMonthDay::from_str(&s_val) {
+            Ok(md) => env.new_string(md.month_code().as_str())
+                .map(|js| js.into_raw())
+                .unwrap_or(ptr::null_mut()),
             Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid rounding increment: {}", e));
-                return ptr::null_mut();
+                throw_range_error(&mut env, &format!("Invalid plain month day: {}", e));
+                ptr::null_mut()
             }
-        };
-
-        let mut options = RoundingOptions::default();
-        options.smallest_unit = Some(unit);
-        options.rounding_mode = Some(mode);
-        options.increment = Some(increment_opt);
+        }
+    }
 
-        match instant.round(options) {
-            Ok(result) => {
-                let provider = &*COMPILED_TZ_PROVIDER;
-                match result.to_ixdtf_string_with_provider(None, Default::default(), &provider) {
-                    Ok(s) => env
-                        .new_string(s)
-                        .map(|js| js.into_raw())
-                        .unwrap_or(ptr::null_mut()),
-                    Err(e) => {
-                        throw_range_error(&mut env, &format!("Failed to format instant: {}", e));
-                        ptr::null_mut()
-                    }
-                }
-            },
+    /// JNI function for `com.temporal.TemporalNative.plainMonthDayGetCalendar()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainMonthDayGetCalendar(
+        mut env: JNIEnv,
+        _class: JClass,
+        s: JString,
+    ) -> jstring {
+        let s_str = parse_jstring(&mut env, &s, "plain month day string");
+        let s_val = match s_str {
+            Some(s) => s,
+            None => return ptr::null_mut(),
+        };
+        match PlainMonthDay::from_str(&s_val) {
+            Ok(md) => env.new_string(md.calendar().identifier())
+                .map(|js| js.into_raw())
+                .unwrap_or(ptr::null_mut()),
             Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to round: {}", e));
+                throw_range_error(&mut env, &format!("Invalid plain month day: {}", e));
                 ptr::null_mut()
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.instantToZonedDateTime()`
+    /// JNI function for `com.temporal.TemporalNative.plainMonthDayToPlainDate()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_instantToZonedDateTime(
+    pub extern "system" fn Java_com_temporal_TemporalNative_plainMonthDayToPlainDate(
         mut env: JNIEnv,
         _class: JClass,
-        instant_str: JString,
-        calendar_id: JString,
-        time_zone_id: JString,
+        md_str: JString,
+        year: jint,
     ) -> jstring {
-        let instant = match parse_instant(&mut env, &instant_str, "instant") {
-            Some(i) => i,
+        let md_s = parse_jstring(&mut env, &md_str, "plain month day");
+        let md_val = match md_s {
+            Some(s) => s,
             None => return ptr::null_mut(),
         };
-
-        let calendar = if !calendar_id.is_null() {
-            let s = parse_jstring(&mut env, &calendar_id, "calendar id");
-            match s {
-                Some(s) => match Calendar::from_str(&s) {
-                    Ok(c) => c,
-                    Err(e) => {
-                        throw_range_error(&mut env, &format!("Invalid calendar: {}", e));
-                        return ptr::null_mut();
-                    }
-                },
-                None => Calendar::default(),
+        let md = match PlainMonthDay::from_str(&md_val) {
+            Ok(m) => m,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid plain month day: {}", e));
+                return ptr::null_mut();
             }
-        } else {
-            Calendar::default()
-        };
-
-        let tz_str = if !time_zone_id.is_null() {
-            parse_jstring(&mut env, &time_zone_id, "timezone id")
-        } else {
-            throw_type_error(&mut env, "Timezone ID is required");
-            return ptr::null_mut();
         };
 
-        let tz = match tz_str {
-            Some(s) => match TimeZone::try_from_str(&s) {
-                Ok(t) => t,
-                Err(e) => {
-                    throw_range_error(&mut env, &format!("Invalid timezone: {}", e));
-                    return ptr::null_mut();
-                }
-            },
-            None => {
-                throw_type_error(&mut env, "Timezone ID is required");
-                return ptr::null_mut();
+        match PlainDate::new(year, md.calendar().month(&md.iso), md.day(), md.calendar().clone()) {
+            Ok(d) => env.new_string(d.to_ixdtf_string(display::calendar()))
+                .map(|js| js.into_raw())
+                .unwrap_or(ptr::null_mut()),
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Failed to convert to plain date: {}", e));
+                ptr::null_mut()
             }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.calendarFrom()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_calendarFrom(
+        mut env: JNIEnv,
+        _class: JClass,
+        id: JString,
+    ) -> jstring {
+        let id_str = parse_jstring(&mut env, &id, "calendar identifier");
+        let id_val = match id_str {
+            Some(s) => s,
+            None => return ptr::null_mut(),
         };
         
-        match ZonedDateTime::try_new(instant.epoch_nanoseconds().0, tz, calendar) {
-            Ok(zdt) => match zdt.to_ixdtf_string(DisplayOffset::Auto, DisplayTimeZone::Auto, DisplayCalendar::Auto, ToStringRoundingOptions::default()) {
-                Ok(s) => env
-                    .new_string(s)
-                    .map(|js| js.into_raw())
-                    .unwrap_or(ptr::null_mut()),
-                Err(e) => {
-                    throw_range_error(&mut env, &format!("Failed to format zoned date time: {}", e));
-                    ptr::null_mut()
-                }
-            },
+        match Calendar::from_str(&id_val) {
+            Ok(calendar) => env
+                .new_string(calendar.identifier().to_string())
+                .map(|js| js.into_raw())
+                .unwrap_or(ptr::null_mut()),
             Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to convert to zoned date time: {}", e));
+                throw_range_error(&mut env, &format!("Invalid calendar identifier '{}': {}", id_val, e));
                 ptr::null_mut()
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.nowPlainDateTimeISO()`
+    /// JNI function for `com.temporal.TemporalNative.calendarId()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_calendarId(
+        env: JNIEnv,
+        _class: JClass,
+        id: JString,
+    ) -> jstring {
+        // Just reusing calendarFrom logic since ID access is basically normalization
+        Java_com_temporal_TemporalNative_calendarFrom(env, _class, id)
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.durationFromString()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_nowPlainDateTimeISO(
+    pub extern "system" fn Java_com_temporal_TemporalNative_durationFromString(
         mut env: JNIEnv,
         _class: JClass,
-        tz_id: JString,
+        input: JString,
     ) -> jstring {
-        let tz_str = parse_jstring(&mut env, &tz_id, "timezone id");
-        let tz_val = match tz_str {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        
-        match get_now_plain_date_time_string(&tz_val) {
-            Ok(s) => env
-                .new_string(s)
+        canonical_jstring(&mut env, &input, "duration string", Kind::Duration, |input| match duration_from_str(input) {
+            Ok(duration) => Ok(duration.to_string()),
+            Err(e) => Err(format!("Invalid duration '{}': {}", input, e)),
+        })
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.durationFromComponents()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_durationFromComponents(
+        mut env: JNIEnv,
+        _class: JClass,
+        years: jlong,
+        months: jlong,
+        weeks: jlong,
+        days: jlong,
+        hours: jlong,
+        minutes: jlong,
+        seconds: jlong,
+        milliseconds: jlong,
+        microseconds: jlong,
+        nanoseconds: jlong,
+    ) -> jstring {
+        // Check for mixed signs
+        let values = [years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds];
+        let non_zero: Vec<i64> = values.iter().copied().filter(|&v| v != 0).collect();
+
+        if !non_zero.is_empty() {
+            let first_sign = non_zero[0].signum();
+            if !non_zero.iter().all(|&v| v.signum() == first_sign) {
+                throw_range_error(&mut env, "All non-zero duration values must have the same sign");
+                return ptr::null_mut();
+            }
+        }
+
+        match Duration::new(
+            years,
+            months,
+            weeks,
+            days,
+            hours,
+            minutes,
+            seconds,
+            milliseconds,
+            microseconds as i128,
+            nanoseconds as i128,
+        ) {
+            Ok(duration) => env
+                .new_string(duration.to_string())
                 .map(|js| js.into_raw())
-                .unwrap_or(ptr::null_mut()),
+                .unwrap_or_else(|_| {
+                    throw_range_error(&mut env, "Failed to create result string");
+                    ptr::null_mut()
+                }),
             Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to get plain date time: {}", e));
+                throw_range_error(&mut env, &format!("Invalid duration components: {}", e));
                 ptr::null_mut()
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.nowPlainDateISO()`
+    /// JNI function for `com.temporal.TemporalNative.durationGetAllComponents()`
+    /// Returns a long array: [years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds, sign, blank]
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_nowPlainDateISO(
+    pub extern "system" fn Java_com_temporal_TemporalNative_durationGetAllComponents(
         mut env: JNIEnv,
         _class: JClass,
-        tz_id: JString,
-    ) -> jstring {
-        let tz_str = parse_jstring(&mut env, &tz_id, "timezone id");
-        let tz_val = match tz_str {
-            Some(s) => s,
+        duration_str: JString,
+    ) -> jlongArray {
+        let duration = match parse_duration(&mut env, &duration_str, "duration string") {
+            Some(d) => d,
             None => return ptr::null_mut(),
         };
-        
-        match get_now_plain_date_string(&tz_val) {
-            Ok(s) => env
-                .new_string(s)
-                .map(|js| js.into_raw())
-                .unwrap_or(ptr::null_mut()),
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to get plain date: {}", e));
+
+        let (microseconds_high, microseconds_low) = split_i128(duration.microseconds());
+        let (nanoseconds_high, nanoseconds_low) = split_i128(duration.nanoseconds());
+        let components: [i64; DURATION_SLOTS.len()] = [
+            duration.years(),
+            duration.months(),
+            duration.weeks(),
+            duration.days(),
+            duration.hours(),
+            duration.minutes(),
+            duration.seconds(),
+            duration.milliseconds(),
+            clamp_i128(duration.microseconds()),
+            clamp_i128(duration.nanoseconds()),
+            duration.sign() as i64,
+            if duration.is_zero() { 1 } else { 0 },
+            microseconds_high,
+            microseconds_low as i64,
+            nanoseconds_high,
+            nanoseconds_low as i64,
+            duration_is_clamped(&duration) as i64,
+        ];
+
+        match env.new_long_array(components.len() as i32) {
+            Ok(arr) => {
+                if env.set_long_array_region(&arr, 0, &components).is_err() {
+                    throw_range_error(&mut env, "Failed to set array elements");
+                    return ptr::null_mut();
+                }
+                arr.into_raw()
+            }
+            Err(_) => {
+                throw_range_error(&mut env, "Failed to create result array");
                 ptr::null_mut()
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.nowPlainTimeISO()`
+    /// JNI function for `com.temporal.TemporalNative.durationAdd()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_nowPlainTimeISO(
+    pub extern "system" fn Java_com_temporal_TemporalNative_durationAdd(
         mut env: JNIEnv,
         _class: JClass,
-        tz_id: JString,
+        a: JString,
+        b: JString,
     ) -> jstring {
-        let tz_str = parse_jstring(&mut env, &tz_id, "timezone id");
-        let tz_val = match tz_str {
-            Some(s) => s,
+        let duration_a = match parse_duration(&mut env, &a, "first duration") {
+            Some(d) => d,
             None => return ptr::null_mut(),
         };
-        
-        match get_now_plain_time_string(&tz_val) {
-            Ok(s) => env
-                .new_string(s)
+        let duration_b = match parse_duration(&mut env, &b, "second duration") {
+            Some(d) => d,
+            None => return ptr::null_mut(),
+        };
+
+        match duration_a.add(&duration_b) {
+            Ok(result) => env
+                .new_string(result.to_string())
                 .map(|js| js.into_raw())
-                .unwrap_or(ptr::null_mut()),
+                .unwrap_or_else(|_| {
+                    throw_range_error(&mut env, "Failed to create result string");
+                    ptr::null_mut()
+                }),
             Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to get plain time: {}", e));
+                throw_range_error(&mut env, &format!("Failed to add durations: {}", e));
                 ptr::null_mut()
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.nowZonedDateTimeISO()`
+    /// JNI function for `com.temporal.TemporalNative.durationSubtract()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_nowZonedDateTimeISO(
+    pub extern "system" fn Java_com_temporal_TemporalNative_durationSubtract(
         mut env: JNIEnv,
         _class: JClass,
-        tz_id: JString,
+        a: JString,
+        b: JString,
     ) -> jstring {
-        let tz_str = parse_jstring(&mut env, &tz_id, "timezone id");
-        let tz_val = match tz_str {
-            Some(s) => s,
+        let duration_a = match parse_duration(&mut env, &a, "first duration") {
+            Some(d) => d,
             None => return ptr::null_mut(),
         };
-        
-        match get_now_zoned_date_time_string(&tz_val) {
-            Ok(s) => env
-                .new_string(s)
+        let duration_b = match parse_duration(&mut env, &b, "second duration") {
+            Some(d) => d,
+            None => return ptr::null_mut(),
+        };
+
+        match duration_a.subtract(&duration_b) {
+            Ok(result) => env
+                .new_string(result.to_string())
                 .map(|js| js.into_raw())
-                .unwrap_or(ptr::null_mut()),
+                .unwrap_or_else(|_| {
+                    throw_range_error(&mut env, "Failed to create result string");
+                    ptr::null_mut()
+                }),
             Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to get zoned date time: {}", e));
+                throw_range_error(&mut env, &format!("Failed to subtract durations: {}", e));
                 ptr::null_mut()
             }
         }
     }
 
-    /// Parses a PlainTime string, throwing RangeError if invalid
-    fn parse_plain_time(env: &mut JNIEnv, s: &JString, name: &str) -> Option<PlainTime> {
-        let s_str = parse_jstring(env, s, name)?;
-        match PlainTime::from_str(&s_str) {
-            Ok(t) => Some(t),
-            Err(e) => {
-                throw_range_error(env, &format!("Invalid plain time '{}': {}", s_str, e));
-                None
-            }
-        }
-    }
-
-    /// JNI function for `com.temporal.TemporalNative.plainTimeFromString()`
+    /// JNI function for `com.temporal.TemporalNative.durationNegated()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainTimeFromString(
+    pub extern "system" fn Java_com_temporal_TemporalNative_durationNegated(
         mut env: JNIEnv,
         _class: JClass,
         s: JString,
     ) -> jstring {
-        let time = match parse_plain_time(&mut env, &s, "plain time string") {
-            Some(t) => t,
+        let duration = match parse_duration(&mut env, &s, "duration") {
+            Some(d) => d,
             None => return ptr::null_mut(),
         };
-        match time.to_ixdtf_string(ToStringRoundingOptions::default()) {
-            Ok(s) => env.new_string(s)
-                .map(|js| js.into_raw())
-                .unwrap_or(ptr::null_mut()),
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to format plain time: {}", e));
+
+        env.new_string(duration.negated().to_string())
+            .map(|js| js.into_raw())
+            .unwrap_or_else(|_| {
+                throw_range_error(&mut env, "Failed to create result string");
                 ptr::null_mut()
-            }
-        }
+            })
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainTimeFromComponents()`
+    /// JNI function for `com.temporal.TemporalNative.durationAbs()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainTimeFromComponents(
+    pub extern "system" fn Java_com_temporal_TemporalNative_durationAbs(
         mut env: JNIEnv,
         _class: JClass,
-        hour: jint,
-        minute: jint,
-        second: jint,
-        millisecond: jint,
-        microsecond: jint,
-        nanosecond: jint,
+        s: JString,
     ) -> jstring {
-        // Validate ranges before casting to narrower types
-        if hour < 0 || hour > 23 {
-            throw_range_error(&mut env, &format!("Invalid hour: {} (must be 0-23)", hour));
-            return ptr::null_mut();
-        }
-        if minute < 0 || minute > 59 {
-            throw_range_error(&mut env, &format!("Invalid minute: {} (must be 0-59)", minute));
-            return ptr::null_mut();
-        }
-        if second < 0 || second > 59 {
-            throw_range_error(&mut env, &format!("Invalid second: {} (must be 0-59)", second));
-            return ptr::null_mut();
-        }
-        if millisecond < 0 || millisecond > 999 {
-            throw_range_error(&mut env, &format!("Invalid millisecond: {} (must be 0-999)", millisecond));
-            return ptr::null_mut();
-        }
-        if microsecond < 0 || microsecond > 999 {
-            throw_range_error(&mut env, &format!("Invalid microsecond: {} (must be 0-999)", microsecond));
-            return ptr::null_mut();
-        }
-        if nanosecond < 0 || nanosecond > 999 {
-            throw_range_error(&mut env, &format!("Invalid nanosecond: {} (must be 0-999)", nanosecond));
-            return ptr::null_mut();
-        }
+        let duration = match parse_duration(&mut env, &s, "duration") {
+            Some(d) => d,
+            None => return ptr::null_mut(),
+        };
 
-        match PlainTime::new(
-            hour as u8,
-            minute as u8,
-            second as u8,
-            millisecond as u16,
-            microsecond as u16,
-            nanosecond as u16
-        ) {
-            Ok(time) => match time.to_ixdtf_string(ToStringRoundingOptions::default()) {
-                Ok(s) => env
-                    .new_string(s)
-                    .map(|js| js.into_raw())
-                    .unwrap_or_else(|_| {
-                        throw_range_error(&mut env, "Failed to create result string");
-                        ptr::null_mut()
-                    }),
-                Err(e) => {
-                    throw_range_error(&mut env, &format!("Failed to format plain time: {}", e));
-                    ptr::null_mut()
-                }
-            },
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid plain time components: {}", e));
+        env.new_string(duration.abs().to_string())
+            .map(|js| js.into_raw())
+            .unwrap_or_else(|_| {
+                throw_range_error(&mut env, "Failed to create result string");
                 ptr::null_mut()
-            }
-        }
+            })
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainTimeGetAllComponents()`
-    /// Returns: [hour, minute, second, millisecond, microsecond, nanosecond]
+    /// JNI function for `com.temporal.TemporalNative.durationCompare()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainTimeGetAllComponents(
+    pub extern "system" fn Java_com_temporal_TemporalNative_durationCompare(
         mut env: JNIEnv,
         _class: JClass,
-        s: JString,
-    ) -> jlongArray {
-        let time = match parse_plain_time(&mut env, &s, "plain time string") {
-            Some(t) => t,
-            None => return ptr::null_mut(),
+        a: JString,
+        b: JString,
+    ) -> jint {
+        let duration_a = match parse_duration(&mut env, &a, "first duration") {
+            Some(d) => d,
+            None => return 0,
+        };
+        let duration_b = match parse_duration(&mut env, &b, "second duration") {
+            Some(d) => d,
+            None => return 0,
         };
 
-        let components: [i64; 6] = [
-            time.hour() as i64,
-            time.minute() as i64,
-            time.second() as i64,
-            time.millisecond() as i64,
-            time.microsecond() as i64,
-            time.nanosecond() as i64,
-        ];
-
-        match env.new_long_array(6) {
-            Ok(arr) => {
-                if env.set_long_array_region(&arr, 0, &components).is_err() {
-                    throw_range_error(&mut env, "Failed to set array elements");
-                    return ptr::null_mut();
-                }
-                arr.into_raw()
-            }
-            Err(_) => {
-                throw_range_error(&mut env, "Failed to create result array");
-                ptr::null_mut()
+        match compare_durations(&duration_a, &duration_b) {
+            Ok(ordering) => ordering as jint,
+            Err(message) => {
+                throw_range_error(&mut env, message);
+                0
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainTimeAdd()`
+    /// Sentinel value for "unchanged" component in durationWith.
+    /// Matches JavaScript's Number.MIN_SAFE_INTEGER (-(2^53 - 1)).
+    const UNCHANGED_SENTINEL: i64 = -9007199254740991;
+
+    /// JNI function for `com.temporal.TemporalNative.durationWith()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainTimeAdd(
+    pub extern "system" fn Java_com_temporal_TemporalNative_durationWith(
         mut env: JNIEnv,
         _class: JClass,
-        time_str: JString,
-        duration_str: JString,
+        original: JString,
+        years: jlong,
+        months: jlong,
+        weeks: jlong,
+        days: jlong,
+        hours: jlong,
+        minutes: jlong,
+        seconds: jlong,
+        milliseconds: jlong,
+        microseconds: jlong,
+        nanoseconds: jlong,
     ) -> jstring {
-        let time = match parse_plain_time(&mut env, &time_str, "plain time") {
-            Some(t) => t,
-            None => return ptr::null_mut(),
-        };
-        let duration = match parse_duration(&mut env, &duration_str, "duration") {
+        let duration = match parse_duration(&mut env, &original, "duration") {
             Some(d) => d,
             None => return ptr::null_mut(),
         };
 
-        match time.add(&duration) {
-            Ok(result) => match result.to_ixdtf_string(ToStringRoundingOptions::default()) {
-                Ok(s) => env
-                    .new_string(s)
-                    .map(|js| js.into_raw())
-                    .unwrap_or(ptr::null_mut()),
-                Err(e) => {
-                    throw_range_error(&mut env, &format!("Failed to format plain time: {}", e));
+        // Use original values for any component set to UNCHANGED_SENTINEL (sentinel)
+        let new_years = if years == UNCHANGED_SENTINEL { duration.years() } else { years };
+        let new_months = if months == UNCHANGED_SENTINEL { duration.months() } else { months };
+        let new_weeks = if weeks == UNCHANGED_SENTINEL { duration.weeks() } else { weeks };
+        let new_days = if days == UNCHANGED_SENTINEL { duration.days() } else { days };
+        let new_hours = if hours == UNCHANGED_SENTINEL { duration.hours() } else { hours };
+        let new_minutes = if minutes == UNCHANGED_SENTINEL { duration.minutes() } else { minutes };
+        let new_seconds = if seconds == UNCHANGED_SENTINEL { duration.seconds() } else { seconds };
+        let new_milliseconds = if milliseconds == UNCHANGED_SENTINEL { duration.milliseconds() } else { milliseconds };
+        let new_microseconds = if microseconds == UNCHANGED_SENTINEL {
+            duration.microseconds().clamp(i64::MIN as i128, i64::MAX as i128) as i64
+        } else {
+            microseconds
+        };
+        let new_nanoseconds = if nanoseconds == UNCHANGED_SENTINEL {
+            duration.nanoseconds().clamp(i64::MIN as i128, i64::MAX as i128) as i64
+        } else {
+            nanoseconds
+        };
+
+        // Check for mixed signs
+        let values = [new_years, new_months, new_weeks, new_days, new_hours, new_minutes,
+                      new_seconds, new_milliseconds, new_microseconds, new_nanoseconds];
+        let non_zero: Vec<i64> = values.iter().copied().filter(|&v| v != 0).collect();
+
+        if !non_zero.is_empty() {
+            let first_sign = non_zero[0].signum();
+            if !non_zero.iter().all(|&v| v.signum() == first_sign) {
+                throw_range_error(&mut env, "All non-zero duration values must have the same sign");
+                return ptr::null_mut();
+            }
+        }
+
+        match Duration::new(
+            new_years,
+            new_months,
+            new_weeks,
+            new_days,
+            new_hours,
+            new_minutes,
+            new_seconds,
+            new_milliseconds,
+            new_microseconds as i128,
+            new_nanoseconds as i128,
+        ) {
+            Ok(result) => env
+                .new_string(result.to_string())
+                .map(|js| js.into_raw())
+                .unwrap_or_else(|_| {
+                    throw_range_error(&mut env, "Failed to create result string");
                     ptr::null_mut()
-                }
-            },
+                }),
             Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to add duration: {}", e));
+                throw_range_error(&mut env, &format!("Invalid duration: {}", e));
                 ptr::null_mut()
             }
         }
+
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainTimeSubtract()`
+    /// JNI function for `com.temporal.TemporalNative.timeZoneFromString()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainTimeSubtract(
+    pub extern "system" fn Java_com_temporal_TemporalNative_timeZoneFromString(
         mut env: JNIEnv,
         _class: JClass,
-        time_str: JString,
-        duration_str: JString,
+        s: JString,
     ) -> jstring {
-        let time = match parse_plain_time(&mut env, &time_str, "plain time") {
-            Some(t) => t,
-            None => return ptr::null_mut(),
-        };
-        let duration = match parse_duration(&mut env, &duration_str, "duration") {
-            Some(d) => d,
+        let s_str = parse_jstring(&mut env, &s, "timezone string");
+        let s_val = match s_str {
+            Some(s) => s,
             None => return ptr::null_mut(),
         };
-
-        match time.subtract(&duration) {
-            Ok(result) => match result.to_ixdtf_string(ToStringRoundingOptions::default()) {
-                Ok(s) => env
-                    .new_string(s)
+        match TimeZone::try_from_str(&s_val) {
+            Ok(tz) => match tz.identifier() {
+                Ok(id) => env.new_string(id)
                     .map(|js| js.into_raw())
                     .unwrap_or(ptr::null_mut()),
                 Err(e) => {
-                    throw_range_error(&mut env, &format!("Failed to format plain time: {}", e));
+                    throw_range_error(&mut env, &format!("Failed to get timezone id: {}", e));
                     ptr::null_mut()
                 }
             },
             Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to subtract duration: {}", e));
+                throw_range_error(&mut env, &format!("Invalid timezone '{}': {}", s_val, e));
                 ptr::null_mut()
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainTimeCompare()`
+    /// JNI function for `com.temporal.TemporalNative.timeZoneGetId()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainTimeCompare(
-        mut env: JNIEnv,
+    pub extern "system" fn Java_com_temporal_TemporalNative_timeZoneGetId(
+        env: JNIEnv,
         _class: JClass,
-        a: JString,
-        b: JString,
-    ) -> jint {
-        let time_a = match parse_plain_time(&mut env, &a, "first plain time") {
-            Some(t) => t,
-            None => return 0,
-        };
-        let time_b = match parse_plain_time(&mut env, &b, "second plain time") {
-            Some(t) => t,
-            None => return 0,
-        };
-
-        time_a.cmp(&time_b) as jint
+        s: JString,
+    ) -> jstring {
+        Java_com_temporal_TemporalNative_timeZoneFromString(env, _class, s)
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainTimeUntil()`
+    /// JNI function for `com.temporal.TemporalNative.timeZoneGetOffsetNanosecondsFor()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainTimeUntil(
+    pub extern "system" fn Java_com_temporal_TemporalNative_timeZoneGetOffsetNanosecondsFor(
         mut env: JNIEnv,
         _class: JClass,
-        one: JString,
-        two: JString,
-        largest_unit: JString,
-        smallest_unit: JString,
-        rounding_increment: jlong,
-        rounding_mode: JString,
-    ) -> jstring {
-        let one_time = match parse_plain_time(&mut env, &one, "first plain time") {
-            Some(t) => t,
-            None => return ptr::null_mut(),
-        };
-        let two_time = match parse_plain_time(&mut env, &two, "second plain time") {
-            Some(t) => t,
-            None => return ptr::null_mut(),
-        };
-
-        let largest = if !largest_unit.is_null() {
-            let s = parse_jstring(&mut env, &largest_unit, "largest unit");
-            match s {
-                Some(s) => match Unit::from_str(&s) {
-                    Ok(u) => Some(u),
-                    Err(_) => {
-                        throw_range_error(&mut env, &format!("Invalid largest unit: {}", s));
-                        return ptr::null_mut();
-                    }
-                },
-                None => None,
-            }
-        } else {
-            None
-        };
-
-        let smallest = if !smallest_unit.is_null() {
-            let s = parse_jstring(&mut env, &smallest_unit, "smallest unit");
-            match s {
-                Some(s) => match Unit::from_str(&s) {
-                    Ok(u) => Some(u),
-                    Err(_) => {
-                        throw_range_error(&mut env, &format!("Invalid smallest unit: {}", s));
-                        return ptr::null_mut();
-                    }
-                },
-                None => None,
-            }
-        } else {
-            None
+        tz_id: JString,
+        instant_str: JString,
+    ) -> jlong {
+        let tz_s = parse_jstring(&mut env, &tz_id, "timezone");
+        let tz_val = match tz_s {
+            Some(s) => s,
+            None => return 0,
         };
-
-        let mode = if !rounding_mode.is_null() {
-            let s = parse_jstring(&mut env, &rounding_mode, "rounding mode");
-            match s {
-                Some(s) => match RoundingMode::from_str(&s) {
-                    Ok(m) => Some(m),
-                    Err(_) => {
-                        throw_range_error(&mut env, &format!("Invalid rounding mode: {}", s));
-                        return ptr::null_mut();
-                    }
-                },
-                None => None,
+        let tz = match TimeZone::try_from_str(&tz_val) {
+            Ok(t) => t,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid timezone: {}", e));
+                return 0;
             }
-        } else {
-            None
         };
 
-        let increment = if rounding_increment > 0 {
-            rounding_increment as u32
-        } else {
-            1
+        let inst_s = parse_jstring(&mut env, &instant_str, "instant");
+        let inst_val = match inst_s {
+            Some(s) => s,
+            None => return 0,
         };
-        
-        let increment_opt = match RoundingIncrement::try_new(increment) {
-            Ok(i) => Some(i),
+        let instant = match Instant::from_str(&inst_val) {
+            Ok(i) => i,
             Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid rounding increment: {}", e));
-                return ptr::null_mut();
+                throw_range_error(&mut env, &format!("Invalid instant: {}", e));
+                return 0;
             }
         };
 
-        let mut options = temporal_rs::options::DifferenceSettings::default();
-        options.largest_unit = largest;
-        options.smallest_unit = smallest;
-        options.rounding_mode = mode;
-        options.increment = increment_opt;
-
-        match one_time.until(&two_time, options) {
-            Ok(d) => env
-                .new_string(d.to_string())
-                .map(|js| js.into_raw())
-                .unwrap_or(ptr::null_mut()),
+        match ZonedDateTime::try_new(instant.epoch_nanoseconds().0, tz, Calendar::default()) {
+            Ok(zdt) => zdt.offset_nanoseconds() as jlong,
             Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to compute difference: {}", e));
-                ptr::null_mut()
+                throw_range_error(&mut env, &format!("Failed to get offset: {}", e));
+                0
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainTimeSince()`
+    /// JNI function for `com.temporal.TemporalNative.timeZoneGetOffsetStringFor()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainTimeSince(
+    pub extern "system" fn Java_com_temporal_TemporalNative_timeZoneGetOffsetStringFor(
         mut env: JNIEnv,
         _class: JClass,
-        one: JString,
-        two: JString,
-        largest_unit: JString,
-        smallest_unit: JString,
-        rounding_increment: jlong,
-        rounding_mode: JString,
+        tz_id: JString,
+        instant_str: JString,
     ) -> jstring {
-        let one_time = match parse_plain_time(&mut env, &one, "first plain time") {
-            Some(t) => t,
-            None => return ptr::null_mut(),
-        };
-        let two_time = match parse_plain_time(&mut env, &two, "second plain time") {
-            Some(t) => t,
+        let tz_s = parse_jstring(&mut env, &tz_id, "timezone");
+        let tz_val = match tz_s {
+            Some(s) => s,
             None => return ptr::null_mut(),
         };
-
-        let largest = if !largest_unit.is_null() {
-            let s = parse_jstring(&mut env, &largest_unit, "largest unit");
-            match s {
-                Some(s) => match Unit::from_str(&s) {
-                    Ok(u) => Some(u),
-                    Err(_) => {
-                        throw_range_error(&mut env, &format!("Invalid largest unit: {}", s));
-                        return ptr::null_mut();
-                    }
-                },
-                None => None,
-            }
-        } else {
-            None
-        };
-
-        let smallest = if !smallest_unit.is_null() {
-            let s = parse_jstring(&mut env, &smallest_unit, "smallest unit");
-            match s {
-                Some(s) => match Unit::from_str(&s) {
-                    Ok(u) => Some(u),
-                    Err(_) => {
-                        throw_range_error(&mut env, &format!("Invalid smallest unit: {}", s));
-                        return ptr::null_mut();
-                    }
-                },
-                None => None,
-            }
-        } else {
-            None
-        };
-
-        let mode = if !rounding_mode.is_null() {
-            let s = parse_jstring(&mut env, &rounding_mode, "rounding mode");
-            match s {
-                Some(s) => match RoundingMode::from_str(&s) {
-                    Ok(m) => Some(m),
-                    Err(_) => {
-                        throw_range_error(&mut env, &format!("Invalid rounding mode: {}", s));
-                        return ptr::null_mut();
-                    }
-                },
-                None => None,
+        let tz = match TimeZone::try_from_str(&tz_val) {
+            Ok(t) => t,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid timezone: {}", e));
+                return ptr::null_mut();
             }
-        } else {
-            None
         };
 
-        let increment = if rounding_increment > 0 {
-            rounding_increment as u32
-        } else {
-            1
+        let inst_s = parse_jstring(&mut env, &instant_str, "instant");
+        let inst_val = match inst_s {
+            Some(s) => s,
+            None => return ptr::null_mut(),
         };
-        
-        let increment_opt = match RoundingIncrement::try_new(increment) {
-            Ok(i) => Some(i),
+        let instant = match Instant::from_str(&inst_val) {
+            Ok(i) => i,
             Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid rounding increment: {}", e));
+                throw_range_error(&mut env, &format!("Invalid instant: {}", e));
                 return ptr::null_mut();
             }
         };
 
-        let mut options = temporal_rs::options::DifferenceSettings::default();
-        options.largest_unit = largest;
-        options.smallest_unit = smallest;
-        options.rounding_mode = mode;
-        options.increment = increment_opt;
-
-        match one_time.since(&two_time, options) {
-            Ok(d) => env
-                .new_string(d.to_string())
+        match ZonedDateTime::try_new(instant.epoch_nanoseconds().0, tz, Calendar::default()) {
+            Ok(zdt) => env.new_string(zdt.offset().to_string())
                 .map(|js| js.into_raw())
                 .unwrap_or(ptr::null_mut()),
             Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to compute difference: {}", e));
+                throw_range_error(&mut env, &format!("Failed to get offset string: {}", e));
                 ptr::null_mut()
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainTimeRound()`
+    /// JNI function for `com.temporal.TemporalNative.timeZoneGetPlainDateTimeFor()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainTimeRound(
+    pub extern "system" fn Java_com_temporal_TemporalNative_timeZoneGetPlainDateTimeFor(
         mut env: JNIEnv,
         _class: JClass,
-        time_str: JString,
-        smallest_unit: JString,
-        rounding_increment: jlong,
-        rounding_mode: JString,
+        tz_id: JString,
+        instant_str: JString,
+        calendar_id: JString,
     ) -> jstring {
-        let time = match parse_plain_time(&mut env, &time_str, "plain time") {
-            Some(t) => t,
+        let tz_s = parse_jstring(&mut env, &tz_id, "timezone");
+        let tz_val = match tz_s {
+            Some(s) => s,
             None => return ptr::null_mut(),
         };
-
-        let unit = if !smallest_unit.is_null() {
-            let s = parse_jstring(&mut env, &smallest_unit, "smallest unit");
-            match s {
-                Some(s) => match Unit::from_str(&s) {
-                    Ok(u) => u,
-                    Err(_) => {
-                        throw_range_error(&mut env, &format!("Invalid smallest unit: {}", s));
-                        return ptr::null_mut();
-                    }
-                },
-                None => {
-                    throw_type_error(&mut env, "smallestUnit is required");
-                    return ptr::null_mut();
-                }
-            }
-        } else {
-            throw_type_error(&mut env, "smallestUnit is required");
-            return ptr::null_mut();
-        };
-
-        let mode = if !rounding_mode.is_null() {
-            let s = parse_jstring(&mut env, &rounding_mode, "rounding mode");
-            match s {
-                Some(s) => match RoundingMode::from_str(&s) {
-                    Ok(m) => m,
-                    Err(_) => {
-                        throw_range_error(&mut env, &format!("Invalid rounding mode: {}", s));
-                        return ptr::null_mut();
-                    }
-                },
-                None => RoundingMode::HalfExpand,
+        let tz = match TimeZone::try_from_str(&tz_val) {
+            Ok(t) => t,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid timezone: {}", e));
+                return ptr::null_mut();
             }
-        } else {
-            RoundingMode::HalfExpand
         };
 
-        let increment = if rounding_increment > 0 {
-            rounding_increment as u32
-        } else {
-            1
+        let inst_s = parse_jstring(&mut env, &instant_str, "instant");
+        let inst_val = match inst_s {
+            Some(s) => s,
+            None => return ptr::null_mut(),
         };
-        
-        let increment_opt = match RoundingIncrement::try_new(increment) {
+        let instant = match Instant::from_str(&inst_val) {
             Ok(i) => i,
             Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid rounding increment: {}", e));
+                throw_range_error(&mut env, &format!("Invalid instant: {}", e));
                 return ptr::null_mut();
             }
-        };
-
-        let mut options = RoundingOptions::default();
-        options.smallest_unit = Some(unit);
-        options.rounding_mode = Some(mode);
-        options.increment = Some(increment_opt);
-
-        match time.round(options) {
-            Ok(t) => match t.to_ixdtf_string(ToStringRoundingOptions::default()) {
-                Ok(s) => env
-                    .new_string(s)
-                    .map(|js| js.into_raw())
-                    .unwrap_or(ptr::null_mut()),
-                Err(e) => {
-                    throw_range_error(&mut env, &format!("Failed to format plain time: {}", e));
-                    ptr::null_mut()
-                }
-            },
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to round: {}", e));
-                ptr::null_mut()
-            }
-        }
-    }
-
-    /// Parses a PlainDate string, throwing RangeError if invalid
-    fn parse_plain_date(env: &mut JNIEnv, s: &JString, name: &str) -> Option<PlainDate> {
-        let s_str = parse_jstring(env, s, name)?;
-        match PlainDate::from_str(&s_str) {
-            Ok(d) => Some(d),
-            Err(e) => {
-                throw_range_error(env, &format!("Invalid plain date '{}': {}", s_str, e));
-                None
-            }
-        }
-    }
-
-    /// JNI function for `com.temporal.TemporalNative.plainDateFromString()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateFromString(
-        mut env: JNIEnv,
-        _class: JClass,
-        s: JString,
-    ) -> jstring {
-        let date = match parse_plain_date(&mut env, &s, "plain date string") {
-            Some(d) => d,
-            None => return ptr::null_mut(),
-        };
-        env.new_string(date.to_ixdtf_string(DisplayCalendar::Auto))
-            .map(|js| js.into_raw())
-            .unwrap_or_else(|_| {
-                throw_range_error(&mut env, "Failed to create result string");
-                ptr::null_mut()
-            })
-    }
-
-    /// JNI function for `com.temporal.TemporalNative.plainDateFromComponents()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateFromComponents(
-        mut env: JNIEnv,
-        _class: JClass,
-        year: jint,
-        month: jint,
-        day: jint,
-        calendar_id: JString,
-    ) -> jstring {
+        };
+
         let calendar = if !calendar_id.is_null() {
             let id_str = parse_jstring(&mut env, &calendar_id, "calendar id");
             match id_str {
@@ -4738,337 +12628,319 @@ mod android {
             Calendar::default()
         };
 
-        match PlainDate::new(year, month as u8, day as u8, calendar) {
-            Ok(date) => env
-                .new_string(date.to_ixdtf_string(DisplayCalendar::Auto))
-                .map(|js| js.into_raw())
-                .unwrap_or_else(|_| {
-                    throw_range_error(&mut env, "Failed to create result string");
-                    ptr::null_mut()
-                }),
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid plain date components: {}", e));
-                ptr::null_mut()
-            }
-        }
-    }
-
-    /// JNI function for `com.temporal.TemporalNative.plainDateGetAllComponents()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateGetAllComponents(
-        mut env: JNIEnv,
-        _class: JClass,
-        s: JString,
-    ) -> jlongArray {
-        let date = match parse_plain_date(&mut env, &s, "plain date string") {
-            Some(d) => d,
-            None => return ptr::null_mut(),
-        };
-
-        let components: [i64; 12] = [
-            date.year() as i64,
-            date.month() as i64,
-            date.day() as i64,
-            date.day_of_week() as i64,
-            date.day_of_year() as i64,
-            date.week_of_year().unwrap_or(0) as i64,
-            date.year_of_week().unwrap_or(0) as i64,
-            date.days_in_week() as i64,
-            date.days_in_month() as i64,
-            date.days_in_year() as i64,
-            date.months_in_year() as i64,
-            if date.in_leap_year() { 1 } else { 0 },
-        ];
-
-        match env.new_long_array(12) {
-            Ok(arr) => {
-                if env.set_long_array_region(&arr, 0, &components).is_err() {
-                    throw_range_error(&mut env, "Failed to set array elements");
-                    return ptr::null_mut();
+        match ZonedDateTime::try_new(instant.epoch_nanoseconds().0, tz, calendar) {
+            Ok(zdt) => {
+                let dt = zdt.to_plain_date_time();
+                match dt.to_ixdtf_string(display::rounding(), display::calendar()) {
+                    Ok(s) => env.new_string(s)
+                        .map(|js| js.into_raw())
+                        .unwrap_or(ptr::null_mut()),
+                    Err(e) => {
+                        throw_range_error(&mut env, &format!("Failed to format plain date time: {}", e));
+                        ptr::null_mut()
+                    }
                 }
-                arr.into_raw()
-            }
-            Err(_) => {
-                throw_range_error(&mut env, "Failed to create result array");
+            },
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Failed to get plain date time: {}", e));
                 ptr::null_mut()
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainDateGetMonthCode()`
+    /// JNI function for `com.temporal.TemporalNative.timeZoneGetInstantFor()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateGetMonthCode(
+    pub extern "system" fn Java_com_temporal_TemporalNative_timeZoneGetInstantFor(
         mut env: JNIEnv,
         _class: JClass,
-        s: JString,
+        tz_id: JString,
+        dt_str: JString,
+        disambiguation: JString,
     ) -> jstring {
-        let date = match parse_plain_date(&mut env, &s, "plain date string") {
-            Some(d) => d,
+        let tz_s = parse_jstring(&mut env, &tz_id, "timezone");
+        let tz_val = match tz_s {
+            Some(s) => s,
             None => return ptr::null_mut(),
         };
-        env.new_string(date.month_code().as_str())
-            .map(|js| js.into_raw())
-            .unwrap_or_else(|_| {
-                throw_range_error(&mut env, "Failed to create result string");
-                ptr::null_mut()
-            })
-    }
-
-    /// JNI function for `com.temporal.TemporalNative.plainDateGetCalendar()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateGetCalendar(
-        mut env: JNIEnv,
-        _class: JClass,
-        s: JString,
-    ) -> jstring {
-        let date = match parse_plain_date(&mut env, &s, "plain date string") {
-            Some(d) => d,
-            None => return ptr::null_mut(),
+        let tz = match TimeZone::try_from_str(&tz_val) {
+            Ok(t) => t,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid timezone: {}", e));
+                return ptr::null_mut();
+            }
         };
-        env.new_string(date.calendar().identifier())
-            .map(|js| js.into_raw())
-            .unwrap_or_else(|_| {
-                throw_range_error(&mut env, "Failed to create result string");
-                ptr::null_mut()
-            })
-    }
 
-    /// JNI function for `com.temporal.TemporalNative.plainDateAdd()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateAdd(
-        mut env: JNIEnv,
-        _class: JClass,
-        date_str: JString,
-        duration_str: JString,
-    ) -> jstring {
-        let date = match parse_plain_date(&mut env, &date_str, "plain date") {
-            Some(d) => d,
-            None => return ptr::null_mut(),
-        };
-        let duration = match parse_duration(&mut env, &duration_str, "duration") {
-            Some(d) => d,
+        let dt_s = parse_jstring(&mut env, &dt_str, "plain date time");
+        let dt_val = match dt_s {
+            Some(s) => s,
             None => return ptr::null_mut(),
         };
-
-        match date.add(&duration, None) {
-            Ok(result) => env
-                .new_string(result.to_ixdtf_string(DisplayCalendar::Auto))
-                .map(|js| js.into_raw())
-                .unwrap_or_else(|_| {
-                    throw_range_error(&mut env, "Failed to create result string");
-                    ptr::null_mut()
-                }),
+        let dt = match PlainDateTime::from_str(&dt_val) {
+            Ok(d) => d,
             Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to add duration: {}", e));
-                ptr::null_mut()
+                throw_range_error(&mut env, &format!("Invalid plain date time: {}", e));
+                return ptr::null_mut();
             }
-        }
-    }
-
-    /// JNI function for `com.temporal.TemporalNative.plainDateSubtract()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateSubtract(
-        mut env: JNIEnv,
-        _class: JClass,
-        date_str: JString,
-        duration_str: JString,
-    ) -> jstring {
-        let date = match parse_plain_date(&mut env, &date_str, "plain date") {
-            Some(d) => d,
-            None => return ptr::null_mut(),
         };
-        let duration = match parse_duration(&mut env, &duration_str, "duration") {
-            Some(d) => d,
-            None => return ptr::null_mut(),
+
+        // Disambiguation handling... assumes Compatible default or parse string
+        let disambig_enum = if !disambiguation.is_null() {
+            match parse_jstring(&mut env, &disambiguation, "disambiguation") {
+                Some(s) => match s.as_str() {
+                    "compatible" => Disambiguation::Compatible,
+                    "earlier" => Disambiguation::Earlier,
+                    "later" => Disambiguation::Later,
+                    "reject" => Disambiguation::Reject,
+                    _ => Disambiguation::Compatible,
+                },
+                None => return ptr::null_mut(),
+            }
+        } else {
+            Disambiguation::Compatible
         };
 
-        match date.subtract(&duration, None) {
-            Ok(result) => env
-                .new_string(result.to_ixdtf_string(DisplayCalendar::Auto))
-                .map(|js| js.into_raw())
-                .unwrap_or_else(|_| {
-                    throw_range_error(&mut env, "Failed to create result string");
-                    ptr::null_mut()
-                }),
+        match dt.to_zoned_date_time(tz, disambig_enum) {
+            Ok(zdt) => {
+                let instant = zdt.to_instant();
+                let provider = &*COMPILED_TZ_PROVIDER;
+                match instant.to_ixdtf_string_with_provider(None, display::rounding(), &provider) {
+                    Ok(s) => env.new_string(s)
+                        .map(|js| js.into_raw())
+                        .unwrap_or(ptr::null_mut()),
+                    Err(e) => {
+                        throw_range_error(&mut env, &format!("Failed to format instant: {}", e));
+                        ptr::null_mut()
+                    }
+                }
+            },
             Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to subtract duration: {}", e));
+                throw_range_error(&mut env, &format!("Failed to get instant: {}", e));
                 ptr::null_mut()
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainDateCompare()`
+    /// JNI function for `com.temporal.TemporalNative.compareDateTimeInZoneToInstant()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateCompare(
+    pub extern "system" fn Java_com_temporal_TemporalNative_compareDateTimeInZoneToInstant(
         mut env: JNIEnv,
         _class: JClass,
-        a: JString,
-        b: JString,
+        dt_str: JString,
+        tz_id: JString,
+        disambiguation: JString,
+        instant_str: JString,
     ) -> jint {
-        let date_a = match parse_plain_date(&mut env, &a, "first plain date") {
-            Some(d) => d,
+        let dt_val = match parse_jstring(&mut env, &dt_str, "plain date time") {
+            Some(s) => s,
             None => return 0,
         };
-        let date_b = match parse_plain_date(&mut env, &b, "second plain date") {
-            Some(d) => d,
+        let dt = match PlainDateTime::from_str(&dt_val) {
+            Ok(d) => d,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid plain date time: {}", e));
+                return 0;
+            }
+        };
+        let tz_val = match parse_jstring(&mut env, &tz_id, "timezone") {
+            Some(s) => s,
             None => return 0,
         };
-
-        // Fallback to string comparison for now
-        let s_a = date_a.to_ixdtf_string(DisplayCalendar::Never);
-        let s_b = date_b.to_ixdtf_string(DisplayCalendar::Never);
-
-        s_a.cmp(&s_b) as jint
-    }
-
-    /// JNI function for `com.temporal.TemporalNative.plainDateWith()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateWith(
-        mut env: JNIEnv,
-        _class: JClass,
-        date_str: JString,
-        year: jint,
-        month: jint,
-        day: jint,
-        calendar_id: JString,
-    ) -> jstring {
-        let date = match parse_plain_date(&mut env, &date_str, "plain date") {
-            Some(d) => d,
-            None => return ptr::null_mut(),
+        let tz = match TimeZone::try_from_str(&tz_val) {
+            Ok(t) => t,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid timezone: {}", e));
+                return 0;
+            }
         };
-
-        let new_year = if year == i32::MIN { date.year() } else { year };
-        let new_month = if month == i32::MIN { date.month() } else { month as u8 };
-        let new_day = if day == i32::MIN { date.day() } else { day as u8 };
-
-        let new_calendar = if !calendar_id.is_null() {
-            let id_str = parse_jstring(&mut env, &calendar_id, "calendar id");
-            match id_str {
-                Some(s) => match Calendar::from_str(&s) {
-                    Ok(c) => c,
-                    Err(e) => {
-                        throw_range_error(&mut env, &format!("Invalid calendar: {}", e));
-                        return ptr::null_mut();
-                    }
-                },
-                None => return ptr::null_mut(),
+        let disambiguation = if disambiguation.is_null() {
+            Disambiguation::Compatible
+        } else {
+            let s = match parse_jstring(&mut env, &disambiguation, "disambiguation") {
+                Some(s) => s,
+                None => return 0,
+            };
+            match Disambiguation::from_str(&s) {
+                Ok(d) => d,
+                Err(_) => {
+                    throw_range_error(&mut env, &format!("Invalid disambiguation: {}", s));
+                    return 0;
+                }
             }
-        } else {
-            date.calendar().clone()
+        };
+        let instant = match parse_instant(&mut env, &instant_str, "instant") {
+            Some(i) => i,
+            None => return 0,
         };
 
-        match PlainDate::new(new_year, new_month, new_day, new_calendar) {
-            Ok(new_date) => env
-                .new_string(new_date.to_ixdtf_string(DisplayCalendar::Auto))
-                .map(|js| js.into_raw())
-                .unwrap_or_else(|_| {
-                    throw_range_error(&mut env, "Failed to create result string");
-                    ptr::null_mut()
-                }),
+        match dt.to_zoned_date_time(tz, disambiguation) {
+            Ok(zdt) => zdt.to_instant().cmp(&instant) as jint,
             Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid date components: {}", e));
-                ptr::null_mut()
+                throw_range_error(&mut env, &format!("Failed to get instant: {}", e));
+                0
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainDateUntil()`
+    /// JNI function for `com.temporal.TemporalNative.timeZoneGetNextTransition()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateUntil(
+    pub extern "system" fn Java_com_temporal_TemporalNative_timeZoneGetNextTransition(
         mut env: JNIEnv,
         _class: JClass,
-        one: JString,
-        two: JString,
+        tz_id: JString,
+        instant_str: JString,
     ) -> jstring {
-        let d1 = match parse_plain_date(&mut env, &one, "first plain date") {
-            Some(d) => d,
+        let tz_s = parse_jstring(&mut env, &tz_id, "timezone");
+        let tz_val = match tz_s {
+            Some(s) => s,
             None => return ptr::null_mut(),
         };
-        let d2 = match parse_plain_date(&mut env, &two, "second plain date") {
-            Some(d) => d,
+        let tz = match TimeZone::try_from_str(&tz_val) {
+            Ok(t) => t,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid timezone: {}", e));
+                return ptr::null_mut();
+            }
+        };
+
+        let inst_s = parse_jstring(&mut env, &instant_str, "instant");
+        let inst_val = match inst_s {
+            Some(s) => s,
             None => return ptr::null_mut(),
         };
+        let instant = match Instant::from_str(&inst_val) {
+            Ok(i) => i,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid instant: {}", e));
+                return ptr::null_mut();
+            }
+        };
 
-        match d1.until(&d2, Default::default()) {
-            Ok(d) => env
-                .new_string(d.to_string())
-                .map(|js| js.into_raw())
-                .unwrap_or_else(|_| {
-                    throw_range_error(&mut env, "Failed to create result string");
-                    ptr::null_mut()
-                }),
+        let provider = &*COMPILED_TZ_PROVIDER;
+        
+        let result = match tz {
+            TimeZone::IanaIdentifier(id) => {
+                provider.get_time_zone_transition(id, instant.as_i128(), TransitionDirection::Next)
+            }
+            TimeZone::UtcOffset(_) => Ok(None),
+        };
+
+        match result {
+            Ok(Some(ns)) => {
+                let instant_next = match Instant::try_new(ns.0) {
+                    Ok(i) => i,
+                    Err(e) => {
+                        throw_range_error(&mut env, &format!("Invalid instant: {}", e));
+                        return ptr::null_mut();
+                    }
+                };
+                match instant_next.to_ixdtf_string_with_provider(None, display::rounding(), &provider) {
+                    Ok(s) => env.new_string(s)
+                        .map(|js| js.into_raw())
+                        .unwrap_or(ptr::null_mut()),
+                    Err(e) => {
+                        throw_range_error(&mut env, &format!("Failed to format instant: {}", e));
+                        ptr::null_mut()
+                    }
+                }
+            },
+            Ok(None) => ptr::null_mut(),
             Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to compute until: {}", e));
+                throw_range_error(&mut env, &format!("Failed to get next transition: {:?}", e));
                 ptr::null_mut()
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainDateSince()`
+    /// JNI function for `com.temporal.TemporalNative.timeZoneGetPreviousTransition()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateSince(
+    pub extern "system" fn Java_com_temporal_TemporalNative_timeZoneGetPreviousTransition(
         mut env: JNIEnv,
         _class: JClass,
-        one: JString,
-        two: JString,
+        tz_id: JString,
+        instant_str: JString,
     ) -> jstring {
-        let d1 = match parse_plain_date(&mut env, &one, "first plain date") {
-            Some(d) => d,
+        let tz_s = parse_jstring(&mut env, &tz_id, "timezone");
+        let tz_val = match tz_s {
+            Some(s) => s,
             None => return ptr::null_mut(),
         };
-        let d2 = match parse_plain_date(&mut env, &two, "second plain date") {
-            Some(d) => d,
+        let tz = match TimeZone::try_from_str(&tz_val) {
+            Ok(t) => t,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid timezone: {}", e));
+                return ptr::null_mut();
+            }
+        };
+
+        let inst_s = parse_jstring(&mut env, &instant_str, "instant");
+        let inst_val = match inst_s {
+            Some(s) => s,
             None => return ptr::null_mut(),
         };
+        let instant = match Instant::from_str(&inst_val) {
+            Ok(i) => i,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid instant: {}", e));
+                return ptr::null_mut();
+            }
+        };
 
-        match d1.since(&d2, Default::default()) {
-            Ok(d) => env
-                .new_string(d.to_string())
-                .map(|js| js.into_raw())
-                .unwrap_or_else(|_| {
-                    throw_range_error(&mut env, "Failed to create result string");
-                    ptr::null_mut()
-                }),
+        let provider = &*COMPILED_TZ_PROVIDER;
+        
+        let result = match tz {
+            TimeZone::IanaIdentifier(id) => {
+                provider.get_time_zone_transition(id, instant.as_i128(), TransitionDirection::Previous)
+            }
+            TimeZone::UtcOffset(_) => Ok(None),
+        };
+
+        match result {
+            Ok(Some(ns)) => {
+                let instant_prev = match Instant::try_new(ns.0) {
+                    Ok(i) => i,
+                    Err(e) => {
+                        throw_range_error(&mut env, &format!("Invalid instant: {}", e));
+                        return ptr::null_mut();
+                    }
+                };
+                match instant_prev.to_ixdtf_string_with_provider(None, display::rounding(), &provider) {
+                    Ok(s) => env.new_string(s)
+                        .map(|js| js.into_raw())
+                        .unwrap_or(ptr::null_mut()),
+                    Err(e) => {
+                        throw_range_error(&mut env, &format!("Failed to format instant: {}", e));
+                        ptr::null_mut()
+                    }
+                }
+            },
+            Ok(None) => ptr::null_mut(),
             Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to compute since: {}", e));
+                throw_range_error(&mut env, &format!("Failed to get previous transition: {:?}", e));
                 ptr::null_mut()
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainDateTimeFromString()`
+    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeFromString()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateTimeFromString(
+    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeFromString(
         mut env: JNIEnv,
         _class: JClass,
         s: JString,
     ) -> jstring {
-        let s_str = parse_jstring(&mut env, &s, "plain date time string");
-        let s_val = match s_str {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        match PlainDateTime::from_str(&s_val) {
-            Ok(dt) => match dt.to_ixdtf_string(ToStringRoundingOptions::default(), DisplayCalendar::Auto) {
-                Ok(s) => env
-                    .new_string(s)
-                    .map(|js| js.into_raw())
-                    .unwrap_or(ptr::null_mut()),
-                Err(e) => {
-                    throw_range_error(&mut env, &format!("Failed to format plain date time: {}", e));
-                    ptr::null_mut()
-                }
-            },
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid plain date time '{}': {}", s_val, e));
-                ptr::null_mut()
+        canonical_jstring(&mut env, &s, "zoned date time string", Kind::ZonedDateTime, |input| {
+            match ZonedDateTime::from_utf8(input.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
+                Ok(zdt) => zdt
+                    .to_ixdtf_string(display::offset(), display::time_zone(), display::calendar(), display::rounding())
+                    .map_err(|e| format!("Failed to format zoned date time: {}", e)),
+                Err(e) => Err(format!("Invalid zoned date time '{}': {}", input, e)),
             }
-        }
+        })
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainDateTimeFromComponents()`
+    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeFromComponents()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateTimeFromComponents(
+    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeFromComponents(
         mut env: JNIEnv,
         _class: JClass,
         year: jint,
@@ -5081,7 +12953,21 @@ mod android {
         microsecond: jint,
         nanosecond: jint,
         calendar_id: JString,
+        time_zone_id: JString,
+        offset_nanoseconds: jlong,
     ) -> jstring {
+        let month = match narrow_date_component(&mut env, "month", month) {
+            Some(v) => v,
+            None => return ptr::null_mut(),
+        };
+        let day = match narrow_date_component(&mut env, "day", day) {
+            Some(v) => v,
+            None => return ptr::null_mut(),
+        };
+        if !check_time_components(&mut env, hour, minute, second, millisecond, microsecond, nanosecond) {
+            return ptr::null_mut();
+        }
+
         let calendar = if !calendar_id.is_null() {
             let id_str = parse_jstring(&mut env, &calendar_id, "calendar id");
             match id_str {
@@ -5098,78 +12984,106 @@ mod android {
             Calendar::default()
         };
 
-        match PlainDateTime::new(
-            year,
-            month as u8,
-            day as u8,
-            hour as u8,
-            minute as u8,
-            second as u8,
-            millisecond as u16,
-            microsecond as u16,
-            nanosecond as u16,
+        let pdt = match PlainDateTime::new(
+            year, month, day,
+            hour as u8, minute as u8, second as u8, 
+            millisecond as u16, microsecond as u16, nanosecond as u16, 
             calendar
         ) {
-            Ok(dt) => match dt.to_ixdtf_string(ToStringRoundingOptions::default(), DisplayCalendar::Auto) {
-                Ok(s) => env
-                    .new_string(s)
+            Ok(d) => d,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid components: {}", e));
+                return ptr::null_mut();
+            }
+        };
+
+        let tz_s = parse_jstring(&mut env, &time_zone_id, "timezone id");
+        let tz_val = match tz_s {
+            Some(s) => s,
+            None => {
+                throw_type_error(&mut env, "Timezone ID is required");
+                return ptr::null_mut();
+            }
+        };
+
+        let tz = match TimeZone::try_from_str(&tz_val) {
+            Ok(t) => t,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid timezone: {}", e));
+                return ptr::null_mut();
+            }
+        };
+
+        match pdt.to_zoned_date_time(tz, Disambiguation::Compatible) {
+            Ok(zdt) => match zdt.to_ixdtf_string(display::offset(), display::time_zone(), display::calendar(), display::rounding()) {
+                Ok(s) => env.new_string(s)
                     .map(|js| js.into_raw())
                     .unwrap_or(ptr::null_mut()),
                 Err(e) => {
-                    throw_range_error(&mut env, &format!("Failed to format plain date time: {}", e));
+                    throw_range_error(&mut env, &format!("Failed to format zoned date time: {}", e));
                     ptr::null_mut()
                 }
             },
             Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid plain date time components: {}", e));
+                throw_range_error(&mut env, &format!("Failed to create zoned date time: {}", e));
                 ptr::null_mut()
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainDateTimeGetAllComponents()`
+    /// Component slots of `zonedDateTimeGetAllComponents`, also the first element of
+    /// `zonedDateTimeGetFullComponents`.
+    fn zoned_components_array(zdt: &ZonedDateTime) -> [i64; ZONED_DATE_TIME_SLOTS.len()] {
+        let (week_of_year, year_of_week, week_mask) = week_info(zdt.week_of_year(), zdt.year_of_week());
+        [
+            zdt.year() as i64,
+            zdt.month() as i64,
+            zdt.day() as i64,
+            zdt.day_of_week() as i64,
+            zdt.day_of_year() as i64,
+            week_of_year,
+            year_of_week,
+            zdt.days_in_week() as i64,
+            zdt.days_in_month() as i64,
+            zdt.days_in_year() as i64,
+            zdt.months_in_year() as i64,
+            if zdt.in_leap_year() { 1 } else { 0 },
+            zdt.hour() as i64,
+            zdt.minute() as i64,
+            zdt.second() as i64,
+            zdt.millisecond() as i64,
+            zdt.microsecond() as i64,
+            zdt.nanosecond() as i64,
+            zdt.offset_nanoseconds(),
+            week_mask,
+        ]
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeGetAllComponents()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateTimeGetAllComponents(
+    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeGetAllComponents(
         mut env: JNIEnv,
         _class: JClass,
         s: JString,
     ) -> jlongArray {
-        let s_str = parse_jstring(&mut env, &s, "plain date time string");
+        let s_str = parse_jstring(&mut env, &s, "zoned date time string");
         let s_val = match s_str {
             Some(s) => s,
             None => return ptr::null_mut(),
         };
         
-        let dt = match PlainDateTime::from_str(&s_val) {
-            Ok(d) => d,
+        // Use default provider
+        let zdt = match ZonedDateTime::from_utf8(s_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
+            Ok(z) => z,
             Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid plain date time: {}", e));
+                throw_range_error(&mut env, &format!("Invalid zoned date time: {}", e));
                 return ptr::null_mut();
             }
         };
 
-        let components: [i64; 18] = [
-            dt.year() as i64,
-            dt.month() as i64,
-            dt.day() as i64,
-            dt.day_of_week() as i64,
-            dt.day_of_year() as i64,
-            dt.week_of_year().unwrap_or(0) as i64,
-            dt.year_of_week().unwrap_or(0) as i64,
-            dt.days_in_week() as i64,
-            dt.days_in_month() as i64,
-            dt.days_in_year() as i64,
-            dt.months_in_year() as i64,
-            if dt.in_leap_year() { 1 } else { 0 },
-            dt.hour() as i64,
-            dt.minute() as i64,
-            dt.second() as i64,
-            dt.millisecond() as i64,
-            dt.microsecond() as i64,
-            dt.nanosecond() as i64,
-        ];
+        let components = zoned_components_array(&zdt);
 
-        match env.new_long_array(18) {
+        match env.new_long_array(components.len() as i32) {
             Ok(arr) => {
                 if env.set_long_array_region(&arr, 0, &components).is_err() {
                     throw_range_error(&mut env, "Failed to set array elements");
@@ -5184,517 +13098,385 @@ mod android {
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainDateTimeGetMonthCode()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateTimeGetMonthCode(
-        mut env: JNIEnv,
-        _class: JClass,
-        s: JString,
-    ) -> jstring {
-        let s_str = parse_jstring(&mut env, &s, "plain date time string");
-        let s_val = match s_str {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        match PlainDateTime::from_str(&s_val) {
-            Ok(dt) => env.new_string(dt.month_code().as_str())
-                .map(|js| js.into_raw())
-                .unwrap_or(ptr::null_mut()),
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid plain date time: {}", e));
-                ptr::null_mut()
-            }
-        }
-    }
-
-    /// JNI function for `com.temporal.TemporalNative.plainDateTimeGetCalendar()`
+    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeGetFullComponents()`
+    ///
+    /// Returns `[components, timeZoneId, calendarId]`: the `zonedDateTimeGetAllComponents`
+    /// array followed by the two identifiers, in a single call.
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateTimeGetCalendar(
+    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeGetFullComponents(
         mut env: JNIEnv,
         _class: JClass,
         s: JString,
-    ) -> jstring {
-        let s_str = parse_jstring(&mut env, &s, "plain date time string");
-        let s_val = match s_str {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        match PlainDateTime::from_str(&s_val) {
-            Ok(dt) => env.new_string(dt.calendar().identifier())
-                .map(|js| js.into_raw())
-                .unwrap_or(ptr::null_mut()),
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid plain date time: {}", e));
-                ptr::null_mut()
-            }
-        }
-    }
-
-    /// JNI function for `com.temporal.TemporalNative.plainDateTimeAdd()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateTimeAdd(
-        mut env: JNIEnv,
-        _class: JClass,
-        dt_str: JString,
-        duration_str: JString,
-    ) -> jstring {
-        let dt_s = parse_jstring(&mut env, &dt_str, "plain date time");
-        let dt_val = match dt_s {
+    ) -> jobjectArray {
+        let s_val = match parse_jstring(&mut env, &s, "zoned date time string") {
             Some(s) => s,
             None => return ptr::null_mut(),
         };
-        let dt = match PlainDateTime::from_str(&dt_val) {
-            Ok(d) => d,
+        let zdt = match ZonedDateTime::from_utf8(s_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
+            Ok(z) => z,
             Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid plain date time: {}", e));
+                throw_range_error(&mut env, &format!("Invalid zoned date time: {}", e));
                 return ptr::null_mut();
             }
         };
-
-        let dur_s = parse_jstring(&mut env, &duration_str, "duration");
-        let dur_val = match dur_s {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        let duration = match Duration::from_str(&dur_val) {
-            Ok(d) => d,
+        let time_zone_id = match zdt.time_zone().identifier() {
+            Ok(id) => id,
             Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid duration: {}", e));
+                throw_range_error(&mut env, &format!("Failed to get identifier: {}", e));
                 return ptr::null_mut();
             }
         };
 
-        match dt.add(&duration, None) {
-            Ok(result) => match result.to_ixdtf_string(ToStringRoundingOptions::default(), DisplayCalendar::Auto) {
-                Ok(s) => env
-                    .new_string(s)
-                    .map(|js| js.into_raw())
-                    .unwrap_or(ptr::null_mut()),
-                Err(e) => {
-                    throw_range_error(&mut env, &format!("Failed to format result: {}", e));
-                    ptr::null_mut()
-                }
-            },
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to add duration: {}", e));
+        let components = zoned_components_array(&zdt);
+        let result = (|| -> jni::errors::Result<jobjectArray> {
+            let array = env.new_object_array(3, "java/lang/Object", JObject::null())?;
+            let longs = env.new_long_array(components.len() as i32)?;
+            env.set_long_array_region(&longs, 0, &components)?;
+            env.set_object_array_element(&array, 0, longs)?;
+            let time_zone_id = env.new_string(time_zone_id)?;
+            env.set_object_array_element(&array, 1, time_zone_id)?;
+            let calendar_id = env.new_string(zdt.calendar().identifier())?;
+            env.set_object_array_element(&array, 2, calendar_id)?;
+            Ok(array.into_raw())
+        })();
+        match result {
+            Ok(array) => array,
+            Err(_) => {
+                throw_range_error(&mut env, "Failed to create result array");
                 ptr::null_mut()
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainDateTimeSubtract()`
+    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeToEpochParts()`
+    ///
+    /// Returns `[[high, low], timeZoneId, calendarId]`, everything
+    /// `zonedDateTimeFromEpochNanosecondsParts` needs to rebuild the value.
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateTimeSubtract(
+    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeToEpochParts(
         mut env: JNIEnv,
         _class: JClass,
-        dt_str: JString,
-        duration_str: JString,
-    ) -> jstring {
-        let dt_s = parse_jstring(&mut env, &dt_str, "plain date time");
-        let dt_val = match dt_s {
+        s: JString,
+    ) -> jobjectArray {
+        let s_val = match parse_jstring(&mut env, &s, "zoned date time string") {
             Some(s) => s,
             None => return ptr::null_mut(),
         };
-        let dt = match PlainDateTime::from_str(&dt_val) {
-            Ok(d) => d,
+        let zdt = match ZonedDateTime::from_utf8(s_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
+            Ok(z) => z,
             Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid plain date time: {}", e));
+                throw_range_error(&mut env, &format!("Invalid zoned date time: {}", e));
                 return ptr::null_mut();
             }
         };
-
-        let dur_s = parse_jstring(&mut env, &duration_str, "duration");
-        let dur_val = match dur_s {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        let duration = match Duration::from_str(&dur_val) {
-            Ok(d) => d,
+        let time_zone_id = match zdt.time_zone().identifier() {
+            Ok(id) => id,
             Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid duration: {}", e));
+                throw_range_error(&mut env, &format!("Failed to get identifier: {}", e));
                 return ptr::null_mut();
             }
         };
 
-        match dt.subtract(&duration, None) {
-            Ok(result) => match result.to_ixdtf_string(ToStringRoundingOptions::default(), DisplayCalendar::Auto) {
-                Ok(s) => env
-                    .new_string(s)
-                    .map(|js| js.into_raw())
-                    .unwrap_or(ptr::null_mut()),
-                Err(e) => {
-                    throw_range_error(&mut env, &format!("Failed to format result: {}", e));
-                    ptr::null_mut()
-                }
-            },
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to subtract duration: {}", e));
+        let (high, low) = split_i128(zdt.epoch_nanoseconds().as_i128());
+        let result = (|| -> jni::errors::Result<jobjectArray> {
+            let array = env.new_object_array(3, "java/lang/Object", JObject::null())?;
+            let parts = env.new_long_array(EPOCH_NANOSECONDS_PARTS_SLOTS.len() as i32)?;
+            env.set_long_array_region(&parts, 0, &[high, low as i64])?;
+            env.set_object_array_element(&array, 0, parts)?;
+            let time_zone_id = env.new_string(time_zone_id)?;
+            env.set_object_array_element(&array, 1, time_zone_id)?;
+            let calendar_id = env.new_string(zdt.calendar().identifier())?;
+            env.set_object_array_element(&array, 2, calendar_id)?;
+            Ok(array.into_raw())
+        })();
+        match result {
+            Ok(array) => array,
+            Err(_) => {
+                throw_range_error(&mut env, "Failed to create result array");
                 ptr::null_mut()
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainDateTimeCompare()`
+    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeEpochMilliseconds()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateTimeCompare(
+    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeEpochMilliseconds(
         mut env: JNIEnv,
         _class: JClass,
-        a: JString,
-        b: JString,
-    ) -> jint {
-        let a_str = parse_jstring(&mut env, &a, "first plain date time");
-        let a_val = match a_str {
-            Some(s) => s,
-            None => return 0,
-        };
-        let dt_a = match PlainDateTime::from_str(&a_val) {
-            Ok(d) => d,
-            Err(_) => return 0,
-        };
-
-        let b_str = parse_jstring(&mut env, &b, "second plain date time");
-        let b_val = match b_str {
+        s: JString,
+    ) -> jstring {
+        let s_str = parse_jstring(&mut env, &s, "zoned date time string");
+        let s_val = match s_str {
             Some(s) => s,
-            None => return 0,
+            None => return ptr::null_mut(),
         };
-        let dt_b = match PlainDateTime::from_str(&b_val) {
-            Ok(d) => d,
-            Err(_) => return 0,
+        let zdt = match ZonedDateTime::from_utf8(s_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
+            Ok(z) => z,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid zoned date time: {}", e));
+                return ptr::null_mut();
+            }
         };
-
-        dt_a.compare_iso(&dt_b) as jint
+        env.new_string(zdt.epoch_milliseconds().to_string())
+            .map(|js| js.into_raw())
+            .unwrap_or(ptr::null_mut())
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainDateTimeWith()`
+    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeEpochNanoseconds()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateTimeWith(
+    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeEpochNanoseconds(
         mut env: JNIEnv,
         _class: JClass,
-        dt_str: JString,
-        year: jint,
-        month: jint,
-        day: jint,
-        hour: jint,
-        minute: jint,
-        second: jint,
-        millisecond: jint,
-        microsecond: jint,
-        nanosecond: jint,
-        calendar_id: JString,
+        s: JString,
     ) -> jstring {
-        let s_str = parse_jstring(&mut env, &dt_str, "plain date time");
+        let s_str = parse_jstring(&mut env, &s, "zoned date time string");
         let s_val = match s_str {
             Some(s) => s,
             None => return ptr::null_mut(),
         };
-        let dt = match PlainDateTime::from_str(&s_val) {
-            Ok(d) => d,
+        let zdt = match ZonedDateTime::from_utf8(s_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
+            Ok(z) => z,
             Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid plain date time: {}", e));
+                throw_range_error(&mut env, &format!("Invalid zoned date time: {}", e));
                 return ptr::null_mut();
             }
         };
+        env.new_string(zdt.epoch_nanoseconds().0.to_string())
+            .map(|js| js.into_raw())
+            .unwrap_or(ptr::null_mut())
+    }
 
-        let new_year = if year == i32::MIN { dt.year() } else { year };
-        let new_month = if month == i32::MIN { dt.month() } else { month as u8 };
-        let new_day = if day == i32::MIN { dt.day() } else { day as u8 };
-        
-        let new_hour = if hour == i32::MIN { dt.hour() } else { hour as u8 };
-        let new_minute = if minute == i32::MIN { dt.minute() } else { minute as u8 };
-        let new_second = if second == i32::MIN { dt.second() } else { second as u8 };
-        let new_millisecond = if millisecond == i32::MIN { dt.millisecond() } else { millisecond as u16 };
-        let new_microsecond = if microsecond == i32::MIN { dt.microsecond() } else { microsecond as u16 };
-        let new_nanosecond = if nanosecond == i32::MIN { dt.nanosecond() } else { nanosecond as u16 };
-
-        let new_calendar = if !calendar_id.is_null() {
-            let id_str = parse_jstring(&mut env, &calendar_id, "calendar id");
-            match id_str {
-                Some(s) => match Calendar::from_str(&s) {
-                    Ok(c) => c,
-                    Err(e) => {
-                        throw_range_error(&mut env, &format!("Invalid calendar: {}", e));
-                        return ptr::null_mut();
-                    }
-                },
-                None => return ptr::null_mut(),
-            }
-        } else {
-            dt.calendar().clone()
+    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeEpochNanosecondsParts()`
+    /// Returns: [high, low]
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeEpochNanosecondsParts(
+        mut env: JNIEnv,
+        _class: JClass,
+        s: JString,
+    ) -> jlongArray {
+        let s_val = match parse_jstring(&mut env, &s, "zoned date time string") {
+            Some(s) => s,
+            None => return ptr::null_mut(),
         };
-
-        match PlainDateTime::new(
-            new_year, new_month, new_day,
-            new_hour, new_minute, new_second,
-            new_millisecond, new_microsecond, new_nanosecond,
-            new_calendar
-        ) {
-             Ok(new_dt) => match new_dt.to_ixdtf_string(ToStringRoundingOptions::default(), DisplayCalendar::Auto) {
-                 Ok(s) => env
-                    .new_string(s)
-                    .map(|js| js.into_raw())
-                    .unwrap_or(ptr::null_mut()),
-                 Err(e) => {
-                     throw_range_error(&mut env, &format!("Failed to format plain date time: {}", e));
-                     ptr::null_mut()
-                 }
-             },
+        match ZonedDateTime::from_utf8(s_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
+            Ok(zdt) => epoch_ns_parts_array(&mut env, zdt.epoch_nanoseconds().0),
             Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid date components: {}", e));
+                throw_range_error(&mut env, &format!("Invalid zoned date time: {}", e));
                 ptr::null_mut()
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainDateTimeUntil()`
+    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeFromEpochNanosecondsParts()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateTimeUntil(
+    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeFromEpochNanosecondsParts(
         mut env: JNIEnv,
         _class: JClass,
-        one: JString,
-        two: JString,
+        high: jlong,
+        low: jlong,
+        time_zone_id: JString,
+        calendar_id: JString,
     ) -> jstring {
-        let one_str = parse_jstring(&mut env, &one, "first plain date time");
-        let one_val = match one_str {
+        let tz_val = match parse_jstring(&mut env, &time_zone_id, "timezone id") {
             Some(s) => s,
             None => return ptr::null_mut(),
         };
-        let dt1 = match PlainDateTime::from_str(&one_val) {
-            Ok(d) => d,
-            Err(_) => return ptr::null_mut(),
-        };
-
-        let two_str = parse_jstring(&mut env, &two, "second plain date time");
-        let two_val = match two_str {
-            Some(s) => s,
-            None => return ptr::null_mut(),
+        let tz = match TimeZone::try_from_str(&tz_val) {
+            Ok(t) => t,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid timezone: {}", e));
+                return ptr::null_mut();
+            }
         };
-        let dt2 = match PlainDateTime::from_str(&two_val) {
-            Ok(d) => d,
-            Err(_) => return ptr::null_mut(),
+        let calendar = if !calendar_id.is_null() {
+            match parse_jstring(&mut env, &calendar_id, "calendar id") {
+                Some(s) => match Calendar::from_str(&s) {
+                    Ok(c) => c,
+                    Err(e) => {
+                        throw_range_error(&mut env, &format!("Invalid calendar: {}", e));
+                        return ptr::null_mut();
+                    }
+                },
+                None => return ptr::null_mut(),
+            }
+        } else {
+            Calendar::default()
         };
 
-        match dt1.until(&dt2, Default::default()) {
-            Ok(d) => env
-                .new_string(d.to_string())
-                .map(|js| js.into_raw())
-                .unwrap_or(ptr::null_mut()),
+        let formatted = ZonedDateTime::try_new(join_i128(high, low as u64), tz, calendar).and_then(|zdt| {
+            zdt.to_ixdtf_string(display::offset(), display::time_zone(), display::calendar(), display::rounding())
+        });
+        match formatted {
+            Ok(s) => env.new_string(s).map(|js| js.into_raw()).unwrap_or(ptr::null_mut()),
             Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to compute until: {}", e));
+                throw_range_error(&mut env, &format!("Invalid epoch nanoseconds: {}", e));
                 ptr::null_mut()
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainDateTimeSince()`
+    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeGetCalendar()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainDateTimeSince(
+    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeGetCalendar(
         mut env: JNIEnv,
         _class: JClass,
-        one: JString,
-        two: JString,
+        s: JString,
     ) -> jstring {
-        let one_str = parse_jstring(&mut env, &one, "first plain date time");
-        let one_val = match one_str {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        let dt1 = match PlainDateTime::from_str(&one_val) {
-            Ok(d) => d,
-            Err(_) => return ptr::null_mut(),
-        };
-
-        let two_str = parse_jstring(&mut env, &two, "second plain date time");
-        let two_val = match two_str {
+        let s_str = parse_jstring(&mut env, &s, "zoned date time string");
+        let s_val = match s_str {
             Some(s) => s,
             None => return ptr::null_mut(),
         };
-        let dt2 = match PlainDateTime::from_str(&two_val) {
-            Ok(d) => d,
-            Err(_) => return ptr::null_mut(),
-        };
-
-        match dt1.since(&dt2, Default::default()) {
-            Ok(d) => env
-                .new_string(d.to_string())
+        match ZonedDateTime::from_utf8(s_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
+            Ok(z) => env.new_string(z.calendar().identifier())
                 .map(|js| js.into_raw())
                 .unwrap_or(ptr::null_mut()),
             Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to compute since: {}", e));
+                throw_range_error(&mut env, &format!("Invalid zoned date time: {}", e));
                 ptr::null_mut()
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainYearMonthFromString()`
+    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeGetTimeZone()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainYearMonthFromString(
+    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeGetTimeZone(
         mut env: JNIEnv,
         _class: JClass,
         s: JString,
     ) -> jstring {
-        let s_str = parse_jstring(&mut env, &s, "plain year month string");
+        let s_str = parse_jstring(&mut env, &s, "zoned date time string");
         let s_val = match s_str {
             Some(s) => s,
             None => return ptr::null_mut(),
         };
-        match PlainYearMonth::from_str(&s_val) {
-            Ok(ym) => env.new_string(ym.to_ixdtf_string(DisplayCalendar::Auto))
-                .map(|js| js.into_raw())
-                .unwrap_or(ptr::null_mut()),
+        match ZonedDateTime::from_utf8(s_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
+            Ok(z) => match z.time_zone().identifier() {
+                Ok(id) => env.new_string(id)
+                    .map(|js| js.into_raw())
+                    .unwrap_or(ptr::null_mut()),
+                Err(e) => {
+                    throw_range_error(&mut env, &format!("Failed to get identifier: {}", e));
+                    ptr::null_mut()
+                }
+            },
             Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid plain year month '{}': {}", s_val, e));
+                throw_range_error(&mut env, &format!("Invalid zoned date time: {}", e));
                 ptr::null_mut()
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainYearMonthFromComponents()`
+    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeGetOffset()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainYearMonthFromComponents(
+    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeGetOffset(
         mut env: JNIEnv,
         _class: JClass,
-        year: jint,
-        month: jint,
-        calendar_id: JString,
-        _reference_day: jint,
+        s: JString,
     ) -> jstring {
-        let calendar = if !calendar_id.is_null() {
-            let id_str = parse_jstring(&mut env, &calendar_id, "calendar id");
-            match id_str {
-                Some(s) => match Calendar::from_str(&s) {
-                    Ok(c) => c,
-                    Err(e) => {
-                        throw_range_error(&mut env, &format!("Invalid calendar: {}", e));
-                        return ptr::null_mut();
-                    }
-                },
-                None => return ptr::null_mut(),
-            }
-        } else {
-            Calendar::default()
+        let s_str = parse_jstring(&mut env, &s, "zoned date time string");
+        let s_val = match s_str {
+            Some(s) => s,
+            None => return ptr::null_mut(),
         };
-
-        match PlainYearMonth::new(year, month as u8, None, calendar) {
-            Ok(ym) => env.new_string(ym.to_ixdtf_string(DisplayCalendar::Auto))
+        match ZonedDateTime::from_utf8(s_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
+            Ok(z) => env.new_string(z.offset().to_string())
                 .map(|js| js.into_raw())
                 .unwrap_or(ptr::null_mut()),
             Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid plain year month components: {}", e));
+                throw_range_error(&mut env, &format!("Invalid zoned date time: {}", e));
                 ptr::null_mut()
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainYearMonthGetAllComponents()`
+    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeGetMonthCode()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainYearMonthGetAllComponents(
+    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeGetMonthCode(
         mut env: JNIEnv,
         _class: JClass,
         s: JString,
-    ) -> jlongArray {
-        let s_str = parse_jstring(&mut env, &s, "plain year month string");
+    ) -> jstring {
+        let s_str = parse_jstring(&mut env, &s, "zoned date time string");
         let s_val = match s_str {
             Some(s) => s,
             None => return ptr::null_mut(),
         };
-        
-        let ym: PlainYearMonth = match PlainYearMonth::from_str(&s_val) {
-            Ok(y) => y,
+        match ZonedDateTime::from_utf8(s_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
+            Ok(z) => env.new_string(z.month_code().as_str().to_string())
+                .map(|js| js.into_raw())
+                .unwrap_or(ptr::null_mut()),
             Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid plain year month: {}", e));
-                return ptr::null_mut();
-            }
-        };
-
-        let components: [i64; 8] = [
-            ym.year() as i64,
-            ym.month() as i64,
-            0, // PlainYearMonth does not have a day
-            ym.days_in_month() as i64,
-            ym.days_in_year() as i64,
-            ym.months_in_year() as i64,
-            if ym.in_leap_year() { 1 } else { 0 },
-            ym.era_year().unwrap_or(0) as i64,
-        ];
-
-        match env.new_long_array(8) {
-            Ok(arr) => {
-                if env.set_long_array_region(&arr, 0, &components).is_err() {
-                    throw_range_error(&mut env, "Failed to set array elements");
-                    return ptr::null_mut();
-                }
-                arr.into_raw()
-            }
-            Err(_) => {
-                throw_range_error(&mut env, "Failed to create result array");
+                throw_range_error(&mut env, &format!("Invalid zoned date time: {}", e));
                 ptr::null_mut()
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainYearMonthGetMonthCode()`
+    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeGetEra()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainYearMonthGetMonthCode(
+    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeGetEra(
         mut env: JNIEnv,
         _class: JClass,
         s: JString,
     ) -> jstring {
-        let s_str = parse_jstring(&mut env, &s, "plain year month string");
+        let s_str = parse_jstring(&mut env, &s, "zoned date time string");
         let s_val = match s_str {
             Some(s) => s,
             None => return ptr::null_mut(),
         };
-        match PlainYearMonth::from_str(&s_val) {
-            Ok(ym) => env.new_string(ym.month_code().as_str())
+        match ZonedDateTime::from_utf8(s_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
+            Ok(z) => env.new_string(z.era().map(|era| era.to_string()).unwrap_or_default())
                 .map(|js| js.into_raw())
                 .unwrap_or(ptr::null_mut()),
             Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid plain year month: {}", e));
+                throw_range_error(&mut env, &format!("Invalid zoned date time: {}", e));
                 ptr::null_mut()
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainYearMonthGetCalendar()`
+    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeGetEraYear()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainYearMonthGetCalendar(
+    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeGetEraYear(
         mut env: JNIEnv,
         _class: JClass,
         s: JString,
     ) -> jstring {
-        let s_str = parse_jstring(&mut env, &s, "plain year month string");
+        let s_str = parse_jstring(&mut env, &s, "zoned date time string");
         let s_val = match s_str {
             Some(s) => s,
             None => return ptr::null_mut(),
         };
-        match PlainYearMonth::from_str(&s_val) {
-            Ok(ym) => env.new_string(ym.calendar().identifier())
+        match ZonedDateTime::from_utf8(s_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
+            Ok(z) => env.new_string(z.era_year().map(|year| year.to_string()).unwrap_or_default())
                 .map(|js| js.into_raw())
                 .unwrap_or(ptr::null_mut()),
             Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid plain year month: {}", e));
+                throw_range_error(&mut env, &format!("Invalid zoned date time: {}", e));
                 ptr::null_mut()
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainYearMonthAdd()`
+    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeAdd()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainYearMonthAdd(
+    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeAdd(
         mut env: JNIEnv,
         _class: JClass,
-        ym_str: JString,
+        zdt_str: JString,
         duration_str: JString,
+        arithmetic: JString,
     ) -> jstring {
-        let ym_s = parse_jstring(&mut env, &ym_str, "plain year month");
-        let ym_val = match ym_s {
+        let zdt_s = parse_jstring(&mut env, &zdt_str, "zoned date time");
+        let zdt_val = match zdt_s {
             Some(s) => s,
             None => return ptr::null_mut(),
         };
-        let ym = match PlainYearMonth::from_str(&ym_val) {
-            Ok(y) => y,
+        let zdt = match ZonedDateTime::from_utf8(zdt_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
+            Ok(z) => z,
             Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid plain year month: {}", e));
+                throw_range_error(&mut env, &format!("Invalid zoned date time: {}", e));
                 return ptr::null_mut();
             }
         };
@@ -5704,7 +13486,7 @@ mod android {
             Some(s) => s,
             None => return ptr::null_mut(),
         };
-        let duration = match Duration::from_str(&dur_val) {
+        let duration = match duration_from_str(&dur_val) {
             Ok(d) => d,
             Err(e) => {
                 throw_range_error(&mut env, &format!("Invalid duration: {}", e));
@@ -5712,10 +13494,37 @@ mod android {
             }
         };
 
-        match ym.add(&duration, Overflow::Reject) {
-            Ok(result) => env.new_string(result.to_ixdtf_string(DisplayCalendar::Auto))
-                .map(|js| js.into_raw())
-                .unwrap_or(ptr::null_mut()),
+        let duration = if !arithmetic.is_null() {
+            let arithmetic_str = parse_jstring(&mut env, &arithmetic, "arithmetic");
+            match arithmetic_str.as_deref() {
+                Some("calendar") => duration,
+                Some("exact") => match exact_time_duration(&duration) {
+                    Ok(d) => d,
+                    Err(message) => {
+                        throw_range_error(&mut env, &message);
+                        return ptr::null_mut();
+                    }
+                },
+                Some(s) => {
+                    throw_range_error(&mut env, &format!("Invalid arithmetic option: {} (expected calendar or exact)", s));
+                    return ptr::null_mut();
+                }
+                None => return ptr::null_mut(),
+            }
+        } else {
+            duration
+        };
+
+        match zdt.add(&duration, Some(Overflow::Reject)) {
+            Ok(result) => match result.to_ixdtf_string(display::offset(), display::time_zone(), display::calendar(), display::rounding()) {
+                Ok(s) => env.new_string(s)
+                    .map(|js| js.into_raw())
+                    .unwrap_or(ptr::null_mut()),
+                Err(e) => {
+                    throw_range_error(&mut env, &format!("Failed to format result: {}", e));
+                    ptr::null_mut()
+                }
+            },
             Err(e) => {
                 throw_range_error(&mut env, &format!("Failed to add duration: {}", e));
                 ptr::null_mut()
@@ -5723,23 +13532,23 @@ mod android {
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainYearMonthSubtract()`
+    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeSubtract()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainYearMonthSubtract(
+    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeSubtract(
         mut env: JNIEnv,
         _class: JClass,
-        ym_str: JString,
+        zdt_str: JString,
         duration_str: JString,
     ) -> jstring {
-        let ym_s = parse_jstring(&mut env, &ym_str, "plain year month");
-        let ym_val = match ym_s {
+        let zdt_s = parse_jstring(&mut env, &zdt_str, "zoned date time");
+        let zdt_val = match zdt_s {
             Some(s) => s,
             None => return ptr::null_mut(),
         };
-        let ym = match PlainYearMonth::from_str(&ym_val) {
-            Ok(y) => y,
+        let zdt = match ZonedDateTime::from_utf8(zdt_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
+            Ok(z) => z,
             Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid plain year month: {}", e));
+                throw_range_error(&mut env, &format!("Invalid zoned date time: {}", e));
                 return ptr::null_mut();
             }
         };
@@ -5749,7 +13558,7 @@ mod android {
             Some(s) => s,
             None => return ptr::null_mut(),
         };
-        let duration = match Duration::from_str(&dur_val) {
+        let duration = match duration_from_str(&dur_val) {
             Ok(d) => d,
             Err(e) => {
                 throw_range_error(&mut env, &format!("Invalid duration: {}", e));
@@ -5757,10 +13566,16 @@ mod android {
             }
         };
 
-        match ym.subtract(&duration, Overflow::Reject) {
-            Ok(result) => env.new_string(result.to_ixdtf_string(DisplayCalendar::Auto))
-                .map(|js| js.into_raw())
-                .unwrap_or(ptr::null_mut()),
+        match zdt.subtract(&duration, Some(Overflow::Reject)) {
+            Ok(result) => match result.to_ixdtf_string(display::offset(), display::time_zone(), display::calendar(), display::rounding()) {
+                Ok(s) => env.new_string(s)
+                    .map(|js| js.into_raw())
+                    .unwrap_or(ptr::null_mut()),
+                Err(e) => {
+                    throw_range_error(&mut env, &format!("Failed to format result: {}", e));
+                    ptr::null_mut()
+                }
+            },
             Err(e) => {
                 throw_range_error(&mut env, &format!("Failed to subtract duration: {}", e));
                 ptr::null_mut()
@@ -5768,66 +13583,104 @@ mod android {
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainYearMonthCompare()`
+    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeCompare()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainYearMonthCompare(
+    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeCompare(
         mut env: JNIEnv,
         _class: JClass,
         a: JString,
         b: JString,
     ) -> jint {
-        let a_str = parse_jstring(&mut env, &a, "first plain year month");
+        let a_str = parse_jstring(&mut env, &a, "first zoned date time");
         let a_val = match a_str {
             Some(s) => s,
             None => return 0,
         };
-        let ym_a: PlainYearMonth = match PlainYearMonth::from_str(&a_val) {
-            Ok(y) => y,
-            Err(_) => return 0,
+        let zdt_a = match ZonedDateTime::from_utf8(a_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
+            Ok(z) => z,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid zoned date time '{}': {}", a_val, e));
+                return 0;
+            }
         };
 
-        let b_str = parse_jstring(&mut env, &b, "second plain year month");
+        let b_str = parse_jstring(&mut env, &b, "second zoned date time");
         let b_val = match b_str {
             Some(s) => s,
             None => return 0,
         };
-        let ym_b: PlainYearMonth = match PlainYearMonth::from_str(&b_val) {
-            Ok(y) => y,
-            Err(_) => return 0,
+        let zdt_b = match ZonedDateTime::from_utf8(b_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
+            Ok(z) => z,
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid zoned date time '{}': {}", b_val, e));
+                return 0;
+            }
         };
 
-        // Fallback to string comparison for now
-        let s_a = ym_a.to_ixdtf_string(DisplayCalendar::Never);
-        let s_b = ym_b.to_ixdtf_string(DisplayCalendar::Never);
-
-        s_a.cmp(&s_b) as jint
+        zdt_a.epoch_nanoseconds().0.cmp(&zdt_b.epoch_nanoseconds().0) as jint
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainYearMonthWith()`
+    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeWith()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainYearMonthWith(
+    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeWith(
         mut env: JNIEnv,
         _class: JClass,
-        ym_str: JString,
+        zdt_str: JString,
         year: jint,
         month: jint,
+        day: jint,
+        hour: jint,
+        minute: jint,
+        second: jint,
+        millisecond: jint,
+        microsecond: jint,
+        nanosecond: jint,
+        _offset_ns: jlong,
         calendar_id: JString,
+        time_zone_id: JString,
     ) -> jstring {
-        let ym_s = parse_jstring(&mut env, &ym_str, "plain year month");
-        let ym_val = match ym_s {
+        let zdt_s = parse_jstring(&mut env, &zdt_str, "zoned date time");
+        let zdt_val = match zdt_s {
             Some(s) => s,
             None => return ptr::null_mut(),
         };
-        let ym: PlainYearMonth = match PlainYearMonth::from_str(&ym_val) {
-            Ok(y) => y,
+        let zdt = match ZonedDateTime::from_utf8(zdt_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
+            Ok(z) => z,
             Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid plain year month: {}", e));
+                throw_range_error(&mut env, &format!("Invalid zoned date time: {}", e));
                 return ptr::null_mut();
             }
         };
+        
+        let current_pdt = zdt.to_plain_date_time();
+    
+        let new_year = if year == i32::MIN { current_pdt.year() } else { year };
+        let new_month = if month == i32::MIN { current_pdt.month() } else {
+            match narrow_date_component(&mut env, "month", month) {
+                Some(v) => v,
+                None => return ptr::null_mut(),
+            }
+        };
+        let new_day = if day == i32::MIN { current_pdt.day() } else {
+            match narrow_date_component(&mut env, "day", day) {
+                Some(v) => v,
+                None => return ptr::null_mut(),
+            }
+        };
 
-        let new_year = if year == i32::MIN { ym.year() } else { year };
-        let new_month = if month == i32::MIN { ym.month() } else { month as u8 };
+        // Fill in the unchanged fields first so the range check sees the final values
+        let keep = |value: jint, current: u16| if value == i32::MIN { current as jint } else { value };
+        let hour = keep(hour, current_pdt.hour() as u16);
+        let minute = keep(minute, current_pdt.minute() as u16);
+        let second = keep(second, current_pdt.second() as u16);
+        let millisecond = keep(millisecond, current_pdt.millisecond());
+        let microsecond = keep(microsecond, current_pdt.microsecond());
+        let nanosecond = keep(nanosecond, current_pdt.nanosecond());
+        if !check_time_components(&mut env, hour, minute, second, millisecond, microsecond, nanosecond) {
+            return ptr::null_mut();
+        }
+        let (new_hour, new_minute, new_second) = (hour as u8, minute as u8, second as u8);
+        let (new_millisecond, new_microsecond, new_nanosecond) = (millisecond as u16, microsecond as u16, nanosecond as u16);
 
         let new_calendar = if !calendar_id.is_null() {
             let id_str = parse_jstring(&mut env, &calendar_id, "calendar id");
@@ -5842,49 +13695,93 @@ mod android {
                 None => return ptr::null_mut(),
             }
         } else {
-            ym.calendar().clone()
+            zdt.calendar().clone()
+        };
+        
+        let new_timezone = if !time_zone_id.is_null() {
+            let id_str = parse_jstring(&mut env, &time_zone_id, "timezone id");
+            match id_str {
+                Some(s) => match TimeZone::try_from_str(&s) {
+                    Ok(t) => t,
+                    Err(e) => {
+                        throw_range_error(&mut env, &format!("Invalid timezone: {}", e));
+                        return ptr::null_mut();
+                    }
+                },
+                None => return ptr::null_mut(),
+            }
+        } else {
+            zdt.time_zone().clone()
         };
 
-        match PlainYearMonth::new(new_year, new_month, None, new_calendar) {
-            Ok(new_ym) => env.new_string(new_ym.to_ixdtf_string(DisplayCalendar::Auto))
-                .map(|js| js.into_raw())
-                .unwrap_or(ptr::null_mut()),
+        let pdt = match PlainDateTime::new(
+            new_year, new_month, new_day, 
+            new_hour, new_minute, new_second, 
+            new_millisecond, new_microsecond, new_nanosecond, 
+            new_calendar
+        ) {
+            Ok(d) => d,
             Err(e) => {
                 throw_range_error(&mut env, &format!("Invalid components: {}", e));
+                return ptr::null_mut();
+            }
+        };
+        
+        match pdt.to_zoned_date_time(new_timezone, Disambiguation::Compatible) {
+            Ok(new_zdt) => match new_zdt.to_ixdtf_string(display::offset(), display::time_zone(), display::calendar(), display::rounding()) {
+                Ok(s) => env.new_string(s)
+                    .map(|js| js.into_raw())
+                    .unwrap_or(ptr::null_mut()),
+                Err(e) => {
+                    throw_range_error(&mut env, &format!("Failed to format: {}", e));
+                    ptr::null_mut()
+                }
+            },
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Failed to create zoned date time: {}", e));
                 ptr::null_mut()
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainYearMonthUntil()`
+    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeUntil()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainYearMonthUntil(
+    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeUntil(
         mut env: JNIEnv,
         _class: JClass,
         one: JString,
         two: JString,
+        largest_unit: JString,
+        smallest_unit: JString,
+        rounding_increment: jlong,
+        rounding_mode: JString,
     ) -> jstring {
-        let one_str = parse_jstring(&mut env, &one, "first plain year month");
+        let one_str = parse_jstring(&mut env, &one, "first zoned date time");
         let one_val = match one_str {
             Some(s) => s,
             None => return ptr::null_mut(),
         };
-        let ym1: PlainYearMonth = match PlainYearMonth::from_str(&one_val) {
-            Ok(y) => y,
+        let zdt1 = match ZonedDateTime::from_utf8(one_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
+            Ok(z) => z,
             Err(_) => return ptr::null_mut(),
         };
 
-        let two_str = parse_jstring(&mut env, &two, "second plain year month");
+        let two_str = parse_jstring(&mut env, &two, "second zoned date time");
         let two_val = match two_str {
             Some(s) => s,
             None => return ptr::null_mut(),
         };
-        let ym2: PlainYearMonth = match PlainYearMonth::from_str(&two_val) {
-            Ok(y) => y,
+        let zdt2 = match ZonedDateTime::from_utf8(two_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
+            Ok(z) => z,
             Err(_) => return ptr::null_mut(),
         };
 
-        match ym1.until(&ym2, Default::default()) {
+        let options = match parse_difference_settings(&mut env, &largest_unit, &smallest_unit, rounding_increment, &rounding_mode) {
+            Some(o) => o,
+            None => return ptr::null_mut(),
+        };
+
+        match zdt1.until(&zdt2, options) {
             Ok(d) => env.new_string(d.to_string())
                 .map(|js| js.into_raw())
                 .unwrap_or(ptr::null_mut()),
@@ -5895,35 +13792,44 @@ mod android {
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainYearMonthSince()`
+    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeSince()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainYearMonthSince(
+    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeSince(
         mut env: JNIEnv,
         _class: JClass,
         one: JString,
         two: JString,
+        largest_unit: JString,
+        smallest_unit: JString,
+        rounding_increment: jlong,
+        rounding_mode: JString,
     ) -> jstring {
-        let one_str = parse_jstring(&mut env, &one, "first plain year month");
+        let one_str = parse_jstring(&mut env, &one, "first zoned date time");
         let one_val = match one_str {
             Some(s) => s,
             None => return ptr::null_mut(),
         };
-        let ym1: PlainYearMonth = match PlainYearMonth::from_str(&one_val) {
-            Ok(y) => y,
+        let zdt1 = match ZonedDateTime::from_utf8(one_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
+            Ok(z) => z,
             Err(_) => return ptr::null_mut(),
         };
 
-        let two_str = parse_jstring(&mut env, &two, "second plain year month");
+        let two_str = parse_jstring(&mut env, &two, "second zoned date time");
         let two_val = match two_str {
             Some(s) => s,
             None => return ptr::null_mut(),
         };
-        let ym2: PlainYearMonth = match PlainYearMonth::from_str(&two_val) {
-            Ok(y) => y,
+        let zdt2 = match ZonedDateTime::from_utf8(two_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
+            Ok(z) => z,
             Err(_) => return ptr::null_mut(),
         };
 
-        match ym1.since(&ym2, Default::default()) {
+        let options = match parse_difference_settings(&mut env, &largest_unit, &smallest_unit, rounding_increment, &rounding_mode) {
+            Some(o) => o,
+            None => return ptr::null_mut(),
+        };
+
+        match zdt1.since(&zdt2, options) {
             Ok(d) => env.new_string(d.to_string())
                 .map(|js| js.into_raw())
                 .unwrap_or(ptr::null_mut()),
@@ -5934,2019 +13840,3477 @@ mod android {
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainYearMonthToPlainDate()`
+    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeRound()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainYearMonthToPlainDate(
+    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeRound(
         mut env: JNIEnv,
         _class: JClass,
-        ym_str: JString,
-        day: jint,
+        zdt_str: JString,
+        smallest_unit: JString,
+        rounding_increment: jlong,
+        rounding_mode: JString,
     ) -> jstring {
-        let ym_s = parse_jstring(&mut env, &ym_str, "plain year month");
-        let ym_val = match ym_s {
+        let s_str = parse_jstring(&mut env, &zdt_str, "zoned date time");
+        let s_val = match s_str {
             Some(s) => s,
             None => return ptr::null_mut(),
         };
-        let ym: PlainYearMonth = match PlainYearMonth::from_str(&ym_val) {
-            Ok(y) => y,
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid plain year month: {}", e));
+        let zdt = match ZonedDateTime::from_utf8(s_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
+            Ok(z) => z,
+            Err(_) => return ptr::null_mut(),
+        };
+
+        let unit = if !smallest_unit.is_null() {
+            let s = parse_jstring(&mut env, &smallest_unit, "smallest unit");
+            match s {
+                Some(s) => match Unit::from_str(&s) {
+                    Ok(u) => u,
+                    Err(_) => {
+                        throw_range_error(&mut env, &format!("Invalid smallest unit: {}", s));
+                        return ptr::null_mut();
+                    }
+                },
+                None => {
+                    throw_type_error(&mut env, "smallestUnit is required");
+                    return ptr::null_mut();
+                }
+            }
+        } else {
+            throw_type_error(&mut env, "smallestUnit is required");
+            return ptr::null_mut();
+        };
+
+        let mode = if !rounding_mode.is_null() {
+            let s = parse_jstring(&mut env, &rounding_mode, "rounding mode");
+            match s {
+                Some(s) => match RoundingMode::from_str(&s) {
+                    Ok(m) => m,
+                    Err(_) => {
+                        throw_range_error(&mut env, &format!("Invalid rounding mode: {}", s));
+                        return ptr::null_mut();
+                    }
+                },
+                None => RoundingMode::HalfExpand,
+            }
+        } else {
+            RoundingMode::HalfExpand
+        };
+
+        let increment_opt = match validate_rounding_increment(rounding_increment, Some(unit), IncrementContext::Round) {
+            Ok(i) => i,
+            Err(message) => {
+                throw_range_error(&mut env, &message);
                 return ptr::null_mut();
             }
         };
 
-        match PlainDate::new(ym.year(), ym.month(), day as u8, ym.calendar().clone()) {
-            Ok(d) => env.new_string(d.to_ixdtf_string(DisplayCalendar::Auto))
-                .map(|js| js.into_raw())
-                .unwrap_or(ptr::null_mut()),
+        let mut options = RoundingOptions::default();
+        options.smallest_unit = Some(unit);
+        options.rounding_mode = Some(mode);
+        options.increment = Some(increment_opt);
+
+        match zdt.round(options) {
+            Ok(result) => match result.to_ixdtf_string(display::offset(), display::time_zone(), display::calendar(), display::rounding()) {
+                Ok(s) => env
+                    .new_string(s)
+                    .map(|js| js.into_raw())
+                    .unwrap_or(ptr::null_mut()),
+                Err(e) => {
+                    throw_range_error(&mut env, &format!("Failed to format zoned date time: {}", e));
+                    ptr::null_mut()
+                }
+            },
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Failed to round: {}", e));
+                ptr::null_mut()
+            }
+        }
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeToInstant()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeToInstant(
+        mut env: JNIEnv,
+        _class: JClass,
+        s: JString,
+    ) -> jstring {
+        let s_str = parse_jstring(&mut env, &s, "zoned date time string");
+        let s_val = match s_str {
+            Some(s) => s,
+            None => return ptr::null_mut(),
+        };
+        match ZonedDateTime::from_utf8(s_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
+            Ok(zdt) => {
+                let provider = &*COMPILED_TZ_PROVIDER;
+                match zdt.to_instant().to_ixdtf_string_with_provider(None, display::rounding(), &provider) {
+                    Ok(s) => env.new_string(s)
+                        .map(|js| js.into_raw())
+                        .unwrap_or(ptr::null_mut()),
+                    Err(e) => {
+                        throw_range_error(&mut env, &format!("Failed to format instant: {}", e));
+                        ptr::null_mut()
+                    }
+                }
+            },
             Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to convert to plain date: {}", e));
+                throw_range_error(&mut env, &format!("Invalid zoned date time: {}", e));
                 ptr::null_mut()
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainMonthDayFromString()`
+    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeToPlainDate()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainMonthDayFromString(
+    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeToPlainDate(
         mut env: JNIEnv,
         _class: JClass,
         s: JString,
     ) -> jstring {
-        let s_str = parse_jstring(&mut env, &s, "plain month day string");
+        let s_str = parse_jstring(&mut env, &s, "zoned date time string");
         let s_val = match s_str {
             Some(s) => s,
             None => return ptr::null_mut(),
         };
-        match PlainMonthDay::from_str(&s_val) {
-            Ok(md) => env.new_string(md.to_ixdtf_string(DisplayCalendar::Auto))
+        match ZonedDateTime::from_utf8(s_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
+            Ok(zdt) => env.new_string(zdt.to_plain_date().to_ixdtf_string(display::calendar()))
                 .map(|js| js.into_raw())
                 .unwrap_or(ptr::null_mut()),
             Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid plain month day '{}': {}", s_val, e));
+                throw_range_error(&mut env, &format!("Invalid zoned date time: {}", e));
                 ptr::null_mut()
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainMonthDayFromComponents()`
+    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeToPlainTime()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainMonthDayFromComponents(
+    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeToPlainTime(
         mut env: JNIEnv,
         _class: JClass,
-        month: jint,
-        day: jint,
-        calendar_id: JString,
-        _reference_year: jint,
+        s: JString,
     ) -> jstring {
-        let calendar = if !calendar_id.is_null() {
-            let id_str = parse_jstring(&mut env, &calendar_id, "calendar id");
-            match id_str {
-                Some(s) => match Calendar::from_str(&s) {
-                    Ok(c) => c,
-                    Err(e) => {
-                        throw_range_error(&mut env, &format!("Invalid calendar: {}", e));
-                        return ptr::null_mut();
-                    }
-                },
-                None => return ptr::null_mut(),
-            }
-        } else {
-            Calendar::default()
+        let s_str = parse_jstring(&mut env, &s, "zoned date time string");
+        let s_val = match s_str {
+            Some(s) => s,
+            None => return ptr::null_mut(),
         };
-
-        match PlainMonthDay::new_with_overflow(month as u8, day as u8, calendar, Overflow::Reject, None) {
-            Ok(md) => env.new_string(md.to_ixdtf_string(DisplayCalendar::Auto))
-                .map(|js| js.into_raw())
-                .unwrap_or(ptr::null_mut()),
+        match ZonedDateTime::from_utf8(s_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
+            Ok(zdt) => match zdt.to_plain_time().to_ixdtf_string(display::rounding()) {
+                Ok(s) => env.new_string(s)
+                    .map(|js| js.into_raw())
+                    .unwrap_or(ptr::null_mut()),
+                Err(e) => {
+                    throw_range_error(&mut env, &format!("Failed to format plain time: {}", e));
+                    ptr::null_mut()
+                }
+            },
             Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid plain month day components: {}", e));
+                throw_range_error(&mut env, &format!("Invalid zoned date time: {}", e));
                 ptr::null_mut()
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainMonthDayGetAllComponents()`
+    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeToPlainDateTime()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainMonthDayGetAllComponents(
+    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeToPlainDateTime(
         mut env: JNIEnv,
         _class: JClass,
         s: JString,
-    ) -> jlongArray {
-        let s_str = parse_jstring(&mut env, &s, "plain month day string");
+    ) -> jstring {
+        let s_str = parse_jstring(&mut env, &s, "zoned date time string");
         let s_val = match s_str {
             Some(s) => s,
             None => return ptr::null_mut(),
         };
-        
-        let md = match PlainMonthDay::from_str(&s_val) {
-            Ok(m) => m,
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid plain month day: {}", e));
-                return ptr::null_mut();
-            }
-        };
-
-        let components: [i64; 2] = [
-            md.calendar().month(&md.iso) as i64,
-            md.day() as i64,
-        ];
-
-        match env.new_long_array(2) {
-            Ok(arr) => {
-                if env.set_long_array_region(&arr, 0, &components).is_err() {
-                    throw_range_error(&mut env, "Failed to set array elements");
-                    return ptr::null_mut();
+        match ZonedDateTime::from_utf8(s_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
+            Ok(zdt) => match zdt.to_plain_date_time().to_ixdtf_string(display::rounding(), display::calendar()) {
+                Ok(s) => env.new_string(s)
+                    .map(|js| js.into_raw())
+                    .unwrap_or(ptr::null_mut()),
+                Err(e) => {
+                    throw_range_error(&mut env, &format!("Failed to format plain date time: {}", e));
+                    ptr::null_mut()
                 }
-                arr.into_raw()
-            }
-            Err(_) => {
-                throw_range_error(&mut env, "Failed to create result array");
+            },
+            Err(e) => {
+                throw_range_error(&mut env, &format!("Invalid zoned date time: {}", e));
                 ptr::null_mut()
             }
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainMonthDayGetMonthCode()`
+    /// JNI function for `com.temporal.TemporalNative.lastErrorType()`
     #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainMonthDayGetMonthCode(
-        mut env: JNIEnv,
-        _class: JClass,
-        s: JString,
-    ) -> jstring {
-        let s_str = parse_jstring(&mut env, &s, "plain month day string");
-        let s_val = match s_str {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        match PlainMonthDay::from_str(&s_val) {
-            Ok(md) => env.new_string(md.month_code().as_str())
-                .map(|js| js.into_raw())
-                .unwrap_or(ptr::null_mut()),
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid plain month day: {}", e));
-                ptr::null_mut()
+    pub extern "system" fn Java_com_temporal_TemporalNative_lastErrorType(_env: JNIEnv, _class: JClass) -> jint {
+        temporal_last_error_type()
+    }
+
+    /// JNI function for `com.temporal.TemporalNative.lastErrorMessage()`
+    #[no_mangle]
+    pub extern "system" fn Java_com_temporal_TemporalNative_lastErrorMessage(env: JNIEnv, _class: JClass) -> jstring {
+        match last_error_message() {
+            Some(message) => env.new_string(message).map(|js| js.into_raw()).unwrap_or(ptr::null_mut()),
+            None => ptr::null_mut(),
+        }
+    }
+
+    /// Defines the `OrNull` variant of a throwing getter: same arguments and result,
+    /// but errors return null (0 for numbers) and set the last error.
+    macro_rules! or_null {
+        ($($or_null:ident => $throwing:ident($($arg:ident: $ty:ty),*) -> $ret:ty;)*) => {$(
+            #[doc = concat!("Non-throwing variant of `", stringify!($throwing), "`.")]
+            #[no_mangle]
+            pub extern "system" fn $or_null(env: JNIEnv, class: JClass, $($arg: $ty),*) -> $ret {
+                without_throwing(|| $throwing(env, class, $($arg),*))
             }
+        )*};
+    }
+
+    or_null! {
+        Java_com_temporal_TemporalNative_instantEpochMillisecondsOrNull =>
+            Java_com_temporal_TemporalNative_instantEpochMilliseconds(s: JString) -> jstring;
+        Java_com_temporal_TemporalNative_instantEpochNanosecondsOrNull =>
+            Java_com_temporal_TemporalNative_instantEpochNanoseconds(s: JString) -> jstring;
+        Java_com_temporal_TemporalNative_plainTimeGetAllComponentsOrNull =>
+            Java_com_temporal_TemporalNative_plainTimeGetAllComponents(s: JString) -> jlongArray;
+        Java_com_temporal_TemporalNative_plainDateGetAllComponentsOrNull =>
+            Java_com_temporal_TemporalNative_plainDateGetAllComponents(s: JString) -> jlongArray;
+        Java_com_temporal_TemporalNative_plainDateTimeGetAllComponentsOrNull =>
+            Java_com_temporal_TemporalNative_plainDateTimeGetAllComponents(s: JString) -> jlongArray;
+        Java_com_temporal_TemporalNative_plainYearMonthGetAllComponentsOrNull =>
+            Java_com_temporal_TemporalNative_plainYearMonthGetAllComponents(s: JString) -> jlongArray;
+        Java_com_temporal_TemporalNative_plainMonthDayGetAllComponentsOrNull =>
+            Java_com_temporal_TemporalNative_plainMonthDayGetAllComponents(s: JString) -> jlongArray;
+        Java_com_temporal_TemporalNative_durationGetAllComponentsOrNull =>
+            Java_com_temporal_TemporalNative_durationGetAllComponents(duration_str: JString) -> jlongArray;
+        Java_com_temporal_TemporalNative_zonedDateTimeGetAllComponentsOrNull =>
+            Java_com_temporal_TemporalNative_zonedDateTimeGetAllComponents(s: JString) -> jlongArray;
+        Java_com_temporal_TemporalNative_zonedDateTimeGetFullComponentsOrNull =>
+            Java_com_temporal_TemporalNative_zonedDateTimeGetFullComponents(s: JString) -> jobjectArray;
+        Java_com_temporal_TemporalNative_zonedDateTimeEpochMillisecondsOrNull =>
+            Java_com_temporal_TemporalNative_zonedDateTimeEpochMilliseconds(s: JString) -> jstring;
+        Java_com_temporal_TemporalNative_zonedDateTimeEpochNanosecondsOrNull =>
+            Java_com_temporal_TemporalNative_zonedDateTimeEpochNanoseconds(s: JString) -> jstring;
+        Java_com_temporal_TemporalNative_timeZoneGetOffsetNanosecondsForOrNull =>
+            Java_com_temporal_TemporalNative_timeZoneGetOffsetNanosecondsFor(tz_id: JString, instant_str: JString) -> jlong;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CString;
+
+    // Helper to extract value from TemporalResult or panic with error message
+    fn extract_result(mut result: TemporalResult) -> String {
+        if result.error_type != TemporalErrorType::None as i32 {
+            let error_msg = if !result.error_message.is_null() {
+                unsafe { std::ffi::CStr::from_ptr(result.error_message) }
+                    .to_string_lossy()
+                    .to_string()
+            } else {
+                "Unknown error".to_string()
+            };
+            unsafe { temporal_free_result(&mut result) };
+            panic!("TemporalResult error: {}", error_msg);
         }
+
+        let value = if !result.value.is_null() {
+            unsafe { std::ffi::CStr::from_ptr(result.value) }
+                .to_string_lossy()
+                .to_string()
+        } else {
+            String::new()
+        };
+        
+        unsafe { temporal_free_result(&mut result) };
+        value
+    }
+
+    /// Held by tests that set the app's default display options or call a
+    /// `*_to_string` function with NULL options, since those defaults are process-wide.
+    fn lock_display_defaults() -> std::sync::MutexGuard<'static, ()> {
+        static LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+        LOCK.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    #[test]
+    fn test_instant_now() {
+        let result = get_instant_now_string().unwrap();
+        // Should be in ISO 8601 format like "2024-01-15T10:30:45.123456789Z"
+        assert!(result.ends_with('Z'), "Expected UTC timestamp: {}", result);
+        assert!(result.contains('T'), "Expected ISO format: {}", result);
+        println!("Current instant: {}", result);
+    }
+
+    #[test]
+    fn test_duration_from_string_valid() {
+        let input = CString::new("P1Y2M3DT4H5M6S").unwrap();
+        let result = temporal_duration_from_string(input.as_ptr());
+        let result_string = extract_result(result);
+        
+        // Should parse and normalize the duration
+        assert!(result_string.starts_with('P'), "Should start with P: {}", result_string);
+    }
+
+    #[test]
+    fn test_duration_from_string_invalid() {
+        let input = CString::new("invalid").unwrap();
+        let result = temporal_duration_from_string(input.as_ptr());
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32, "Invalid duration should return RangeError");
+        assert!(!result.error_message.is_null(), "Should have error message");
+        unsafe { temporal_free_result(&mut { result }) };
+    }
+
+    #[test]
+    fn test_duration_from_string_null() {
+        let result = temporal_duration_from_string(ptr::null());
+        assert_eq!(result.error_type, TemporalErrorType::TypeError as i32, "Null input should return TypeError");
+        unsafe { temporal_free_result(&mut { result }) };
+    }
+
+    #[test]
+    fn test_duration_get_components() {
+        let input = CString::new("P1Y2M3W4DT5H6M7S").unwrap();
+        let mut components = DurationComponents::default();
+        
+        temporal_duration_get_components(input.as_ptr(), &mut components);
+        
+        assert_eq!(components.is_valid, 1, "Should be valid");
+        assert_eq!(components.years, 1);
+        assert_eq!(components.months, 2);
+        assert_eq!(components.weeks, 3);
+        assert_eq!(components.days, 4);
+        assert_eq!(components.hours, 5);
+        assert_eq!(components.minutes, 6);
+        assert_eq!(components.seconds, 7);
+        assert_eq!(components.sign, 1, "Positive duration should have sign 1");
+    }
+
+    #[test]
+    fn test_duration_get_components_negative() {
+        let input = CString::new("-P1Y2M").unwrap();
+        let mut components = DurationComponents::default();
+        
+        temporal_duration_get_components(input.as_ptr(), &mut components);
+        
+        assert_eq!(components.is_valid, 1);
+        assert_eq!(components.years, -1);
+        assert_eq!(components.months, -2);
+        assert_eq!(components.sign, -1, "Negative duration should have sign -1");
+    }
+
+    #[test]
+    fn test_duration_get_components_zero() {
+        let input = CString::new("PT0S").unwrap();
+        let mut components = DurationComponents::default();
+        
+        temporal_duration_get_components(input.as_ptr(), &mut components);
+        
+        assert_eq!(components.is_valid, 1);
+        assert_eq!(components.sign, 0, "Zero duration should have sign 0");
+    }
+
+    #[test]
+    fn test_duration_get_components_invalid() {
+        let input = CString::new("invalid").unwrap();
+        let mut components = DurationComponents::default();
+        
+        temporal_duration_get_components(input.as_ptr(), &mut components);
+        
+        assert_eq!(components.is_valid, 0, "Invalid duration should set is_valid to 0");
+    }
+
+    #[test]
+    fn test_duration_add() {
+        // Use time-only durations which don't require relative context
+        let a = CString::new("PT1H30M").unwrap();
+        let b = CString::new("PT2H15M").unwrap();
+        
+        let result = temporal_duration_add(a.as_ptr(), b.as_ptr());
+        let result_string = extract_result(result);
+        
+        // PT1H30M + PT2H15M = PT3H45M
+        assert!(result_string.contains("3H"), "1H30M + 2H15M should contain 3H: {}", result_string);
+        assert!(result_string.contains("45M"), "1H30M + 2H15M should contain 45M: {}", result_string);
+    }
+
+    #[test]
+    fn test_duration_subtract() {
+        // Use time-only durations which don't require relative context
+        let a = CString::new("PT3H45M").unwrap();
+        let b = CString::new("PT1H15M").unwrap();
+        
+        let result = temporal_duration_subtract(a.as_ptr(), b.as_ptr());
+        let result_string = extract_result(result);
+        
+        // PT3H45M - PT1H15M = PT2H30M
+        assert!(result_string.contains("2H"), "3H45M - 1H15M should contain 2H: {}", result_string);
+        assert!(result_string.contains("30M"), "3H45M - 1H15M should contain 30M: {}", result_string);
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainMonthDayGetCalendar()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainMonthDayGetCalendar(
-        mut env: JNIEnv,
-        _class: JClass,
-        s: JString,
-    ) -> jstring {
-        let s_str = parse_jstring(&mut env, &s, "plain month day string");
-        let s_val = match s_str {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        match PlainMonthDay::from_str(&s_val) {
-            Ok(md) => env.new_string(md.calendar().identifier())
-                .map(|js| js.into_raw())
-                .unwrap_or(ptr::null_mut()),
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid plain month day: {}", e));
-                ptr::null_mut()
-            }
-        }
+    #[test]
+    fn test_duration_negated() {
+        let input = CString::new("P1Y2M").unwrap();
+        
+        let result = temporal_duration_negated(input.as_ptr());
+        let result_string = extract_result(result);
+        
+        // Negation should produce negative duration
+        assert!(result_string.starts_with("-P"), "Negated should start with -P: {}", result_string);
     }
 
-    /// JNI function for `com.temporal.TemporalNative.plainMonthDayToPlainDate()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_plainMonthDayToPlainDate(
-        mut env: JNIEnv,
-        _class: JClass,
-        md_str: JString,
-        year: jint,
-    ) -> jstring {
-        let md_s = parse_jstring(&mut env, &md_str, "plain month day");
-        let md_val = match md_s {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        let md = match PlainMonthDay::from_str(&md_val) {
-            Ok(m) => m,
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid plain month day: {}", e));
-                return ptr::null_mut();
-            }
-        };
-
-        match PlainDate::new(year, md.calendar().month(&md.iso), md.day(), md.calendar().clone()) {
-            Ok(d) => env.new_string(d.to_ixdtf_string(DisplayCalendar::Auto))
-                .map(|js| js.into_raw())
-                .unwrap_or(ptr::null_mut()),
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to convert to plain date: {}", e));
-                ptr::null_mut()
-            }
-        }
+    #[test]
+    fn test_duration_abs() {
+        let input = CString::new("-P1Y2M").unwrap();
+        
+        let result = temporal_duration_abs(input.as_ptr());
+        let result_string = extract_result(result);
+        
+        // Absolute value should be positive
+        assert!(result_string.starts_with('P') && !result_string.starts_with("-P"), 
+                "Abs should be positive: {}", result_string);
     }
 
-    /// JNI function for `com.temporal.TemporalNative.calendarFrom()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_calendarFrom(
-        mut env: JNIEnv,
-        _class: JClass,
-        id: JString,
-    ) -> jstring {
-        let id_str = parse_jstring(&mut env, &id, "calendar identifier");
-        let id_val = match id_str {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
+    #[test]
+    fn test_error_types() {
+        // Test TypeError for null input
+        let result = temporal_duration_from_string(ptr::null());
+        assert_eq!(result.error_type, TemporalErrorType::TypeError as i32);
+        unsafe { temporal_free_result(&mut { result }) };
         
-        match Calendar::from_str(&id_val) {
-            Ok(calendar) => env
-                .new_string(calendar.identifier().to_string())
-                .map(|js| js.into_raw())
-                .unwrap_or(ptr::null_mut()),
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid calendar identifier '{}': {}", id_val, e));
-                ptr::null_mut()
-            }
-        }
+        // Test RangeError for invalid format
+        let invalid = CString::new("not-a-duration").unwrap();
+        let result = temporal_duration_from_string(invalid.as_ptr());
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        
+        // Check error message contains useful info
+        let error_msg = unsafe { std::ffi::CStr::from_ptr(result.error_message) }
+            .to_string_lossy()
+            .to_string();
+        assert!(error_msg.contains("not-a-duration"), "Error message should include input: {}", error_msg);
+        unsafe { temporal_free_result(&mut { result }) };
     }
 
-    /// JNI function for `com.temporal.TemporalNative.calendarId()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_calendarId(
-        env: JNIEnv,
-        _class: JClass,
-        id: JString,
-    ) -> jstring {
-        // Just reusing calendarFrom logic since ID access is basically normalization
-        Java_com_temporal_TemporalNative_calendarFrom(env, _class, id)
+    #[test]
+    fn test_zoned_date_time_from_epoch_ms() {
+        let tz = CString::new("Europe/Warsaw").unwrap();
+        let result = temporal_zoned_date_time_from_epoch_ms(1_704_067_200_000, tz.as_ptr(), ptr::null());
+        let result_string = extract_result(result);
+
+        assert_eq!(result_string, "2024-01-01T01:00:00+01:00[Europe/Warsaw]");
     }
 
-    /// JNI function for `com.temporal.TemporalNative.durationFromString()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_durationFromString(
-        mut env: JNIEnv,
-        _class: JClass,
-        input: JString,
-    ) -> jstring {
-        let duration = match parse_duration(&mut env, &input, "duration string") {
-            Some(d) => d,
-            None => return ptr::null_mut(),
-        };
+    #[test]
+    fn test_zoned_date_time_from_epoch_ns() {
+        let ns = CString::new("1704067200000000001").unwrap();
+        let tz = CString::new("UTC").unwrap();
+        let calendar = CString::new("gregory").unwrap();
+        let result = temporal_zoned_date_time_from_epoch_ns(ns.as_ptr(), tz.as_ptr(), calendar.as_ptr());
+        let result_string = extract_result(result);
 
-        env.new_string(duration.to_string())
-            .map(|js| js.into_raw())
-            .unwrap_or_else(|_| {
-                throw_range_error(&mut env, "Failed to create result string");
-                ptr::null_mut()
-            })
+        assert_eq!(result_string, "2024-01-01T00:00:00.000000001+00:00[UTC][u-ca=gregory]");
+
+        let invalid = CString::new("not-a-number").unwrap();
+        let result = temporal_zoned_date_time_from_epoch_ns(invalid.as_ptr(), tz.as_ptr(), ptr::null());
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut { result }) };
     }
 
-    /// JNI function for `com.temporal.TemporalNative.durationFromComponents()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_durationFromComponents(
-        mut env: JNIEnv,
-        _class: JClass,
-        years: jlong,
-        months: jlong,
-        weeks: jlong,
-        days: jlong,
-        hours: jlong,
-        minutes: jlong,
-        seconds: jlong,
-        milliseconds: jlong,
-        microseconds: jlong,
-        nanoseconds: jlong,
-    ) -> jstring {
-        // Check for mixed signs
-        let values = [years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds];
-        let non_zero: Vec<i64> = values.iter().copied().filter(|&v| v != 0).collect();
+    #[test]
+    fn test_compare_code() {
+        let a = CString::new("2024-01-01T00:00:00Z").unwrap();
+        let b = CString::new("2024-01-02T00:00:00Z").unwrap();
+        assert_eq!(temporal_instant_compare_code(a.as_ptr(), b.as_ptr()), -1);
+        assert_eq!(temporal_instant_compare_code(b.as_ptr(), a.as_ptr()), 1);
+        assert_eq!(temporal_instant_compare_code(a.as_ptr(), a.as_ptr()), 0);
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::None as i32);
+        assert!(temporal_last_error_message().is_null());
+
+        let d1 = CString::new("PT1H").unwrap();
+        let d2 = CString::new("PT59M").unwrap();
+        assert_eq!(temporal_duration_compare_code(d1.as_ptr(), d2.as_ptr()), 1);
+    }
 
-        if !non_zero.is_empty() {
-            let first_sign = non_zero[0].signum();
-            if !non_zero.iter().all(|&v| v.signum() == first_sign) {
-                throw_range_error(&mut env, "All non-zero duration values must have the same sign");
-                return ptr::null_mut();
-            }
-        }
+    #[test]
+    fn test_compare_code_sets_last_error() {
+        let valid = CString::new("2024-01-01").unwrap();
+        let invalid = CString::new("not-a-date").unwrap();
+        assert_eq!(temporal_plain_date_compare_code(valid.as_ptr(), invalid.as_ptr()), TEMPORAL_COMPARE_ERROR);
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::RangeError as i32);
+
+        let message = temporal_last_error_message();
+        assert!(!message.is_null());
+        let message_str = unsafe { std::ffi::CStr::from_ptr(message) }.to_string_lossy().to_string();
+        assert!(message_str.contains("not-a-date"), "Error message should include input: {}", message_str);
+        unsafe { temporal_free_string(message) };
+
+        assert_eq!(temporal_plain_date_compare_code(ptr::null(), valid.as_ptr()), TEMPORAL_COMPARE_ERROR);
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::TypeError as i32);
+
+        // A successful call clears the previous error
+        assert_eq!(temporal_plain_date_compare_code(valid.as_ptr(), valid.as_ptr()), 0);
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::None as i32);
+    }
 
-        match Duration::new(
-            years,
-            months,
-            weeks,
-            days,
-            hours,
-            minutes,
-            seconds,
-            milliseconds,
-            microseconds as i128,
-            nanoseconds as i128,
-        ) {
-            Ok(duration) => env
-                .new_string(duration.to_string())
-                .map(|js| js.into_raw())
-                .unwrap_or_else(|_| {
-                    throw_range_error(&mut env, "Failed to create result string");
-                    ptr::null_mut()
-                }),
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid duration components: {}", e));
-                ptr::null_mut()
-            }
-        }
+    #[test]
+    fn test_coded_options() {
+        let one = CString::new("10:00").unwrap();
+        let two = CString::new("12:30:45").unwrap();
+        let result = temporal_plain_time_until_coded(one.as_ptr(), two.as_ptr(), 5, 5, 1, 3);
+        assert_eq!(extract_result(result), "PT150M");
+
+        let time = CString::new("10:29:31").unwrap();
+        let result = temporal_plain_time_round_coded(time.as_ptr(), 5, 1, TEMPORAL_OPTION_UNSET);
+        assert_eq!(extract_result(result), "10:30:00");
     }
 
-    /// JNI function for `com.temporal.TemporalNative.durationGetAllComponents()`
-    /// Returns a long array: [years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds, sign, blank]
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_durationGetAllComponents(
-        mut env: JNIEnv,
-        _class: JClass,
-        duration_str: JString,
-    ) -> jlongArray {
-        let duration = match parse_duration(&mut env, &duration_str, "duration string") {
-            Some(d) => d,
-            None => return ptr::null_mut(),
-        };
+    #[test]
+    fn test_coded_options_reject_unknown_codes() {
+        let one = CString::new("2024-01-01T00:00:00Z").unwrap();
+        let two = CString::new("2024-01-02T00:00:00Z").unwrap();
 
-        let components: [i64; 12] = [
-            duration.years(),
-            duration.months(),
-            duration.weeks(),
-            duration.days(),
-            duration.hours(),
-            duration.minutes(),
-            duration.seconds(),
-            duration.milliseconds(),
-            duration.microseconds().clamp(i64::MIN as i128, i64::MAX as i128) as i64,
-            duration.nanoseconds().clamp(i64::MIN as i128, i64::MAX as i128) as i64,
-            duration.sign() as i64,
-            if duration.is_zero() { 1 } else { 0 },
-        ];
+        let result = temporal_instant_until_coded(one.as_ptr(), two.as_ptr(), 42, TEMPORAL_OPTION_UNSET, 1, TEMPORAL_OPTION_UNSET);
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut { result }) };
 
-        match env.new_long_array(12) {
-            Ok(arr) => {
-                if env.set_long_array_region(&arr, 0, &components).is_err() {
-                    throw_range_error(&mut env, "Failed to set array elements");
-                    return ptr::null_mut();
-                }
-                arr.into_raw()
-            }
-            Err(_) => {
-                throw_range_error(&mut env, "Failed to create result array");
-                ptr::null_mut()
-            }
-        }
+        let result = temporal_instant_round_coded(one.as_ptr(), 6, 1, 9);
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut { result }) };
+
+        let result = temporal_instant_round_coded(one.as_ptr(), TEMPORAL_OPTION_UNSET, 1, 6);
+        assert_eq!(result.error_type, TemporalErrorType::TypeError as i32);
+        unsafe { temporal_free_result(&mut { result }) };
+
+        let tz = CString::new("UTC").unwrap();
+        let dt = CString::new("2024-01-01T12:00").unwrap();
+        let result = temporal_time_zone_get_instant_for_coded(tz.as_ptr(), dt.as_ptr(), 4);
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut { result }) };
+    }
+
+    #[test]
+    fn test_plain_date_week_info() {
+        let iso = CString::new("2024-01-01").unwrap();
+        let mut components = PlainDateComponents::default();
+        temporal_plain_date_get_components(iso.as_ptr(), &mut components);
+        assert_eq!(components.is_valid, 1);
+        assert_eq!(components.has_week_info, 1);
+        assert_eq!(components.week_of_year, 1);
+        assert_eq!(components.year_of_week, 2024);
+
+        let gregory = CString::new("2024-01-01[u-ca=gregory]").unwrap();
+        let mut components = PlainDateComponents::default();
+        temporal_plain_date_get_components(gregory.as_ptr(), &mut components);
+        assert_eq!(components.is_valid, 1);
+        assert_eq!(components.has_week_info, 0);
+    }
+
+    #[test]
+    fn test_jni_week_info() {
+        let both = WEEK_OF_YEAR_VALID | YEAR_OF_WEEK_VALID;
+        assert_eq!(week_info(Some(1), Some(2025)), (1, 2025, both));
+        assert_eq!(week_info(None, None), (0, 0, 0));
+        assert_eq!(week_info(Some(52), None), (52, 0, WEEK_OF_YEAR_VALID));
+        assert_eq!(week_info(None, Some(2024)), (0, 2024, YEAR_OF_WEEK_VALID));
+
+        let iso = PlainDate::from_str("2024-12-30").unwrap();
+        assert_eq!(week_info(iso.week_of_year(), iso.year_of_week()), (1, 2025, both));
+        let gregory = PlainDate::from_str("2024-12-30[u-ca=gregory]").unwrap();
+        assert_eq!(week_info(gregory.week_of_year(), gregory.year_of_week()), (0, 0, 0));
     }
 
-    /// JNI function for `com.temporal.TemporalNative.durationAdd()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_durationAdd(
-        mut env: JNIEnv,
-        _class: JClass,
-        a: JString,
-        b: JString,
-    ) -> jstring {
-        let duration_a = match parse_duration(&mut env, &a, "first duration") {
-            Some(d) => d,
-            None => return ptr::null_mut(),
-        };
-        let duration_b = match parse_duration(&mut env, &b, "second duration") {
-            Some(d) => d,
-            None => return ptr::null_mut(),
-        };
+    #[test]
+    fn test_jni_or_null_records_instead_of_throwing() {
+        let range_error = TemporalErrorType::RangeError as i32;
+
+        // Outside an OrNull variant the helpers throw and leave the last error alone
+        clear_last_error();
+        assert!(!record_instead_of_throwing(TemporalErrorType::RangeError, "thrown"));
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::None as i32);
+
+        // Inside one the error becomes the last error, which is cleared on entry
+        set_last_error(TemporalErrorType::TypeError, format_args!("stale"));
+        let recorded = without_throwing(|| {
+            assert_eq!(temporal_last_error_type(), TemporalErrorType::None as i32);
+            record_instead_of_throwing(TemporalErrorType::RangeError, "Invalid instant 'x'")
+        });
+        assert!(recorded);
+        assert_eq!(temporal_last_error_type(), range_error);
+        assert_eq!(last_error_message().as_deref(), Some("Invalid instant 'x'"));
+        without_throwing(|| ());
+        assert_eq!(last_error_message(), None);
+
+        // Nested variants and panics restore throwing afterwards
+        without_throwing(|| without_throwing(|| ()));
+        assert!(!record_instead_of_throwing(TemporalErrorType::RangeError, "thrown"));
+        assert!(std::panic::catch_unwind(|| without_throwing(|| panic!("getter panicked"))).is_err());
+        assert!(!record_instead_of_throwing(TemporalErrorType::RangeError, "thrown"));
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::None as i32);
+    }
 
-        match duration_a.add(&duration_b) {
-            Ok(result) => env
-                .new_string(result.to_string())
-                .map(|js| js.into_raw())
-                .unwrap_or_else(|_| {
-                    throw_range_error(&mut env, "Failed to create result string");
-                    ptr::null_mut()
-                }),
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to add durations: {}", e));
-                ptr::null_mut()
-            }
+    #[test]
+    fn test_jni_component_array_lengths() {
+        // The lengths and indices TemporalModule.kt, TemporalParcels and Duration.ts read
+        let lengths = [
+            (&PLAIN_TIME_SLOTS[..], 6),
+            (&PLAIN_DATE_SLOTS[..], 15),
+            (&PLAIN_DATE_TIME_SLOTS[..], 21),
+            (&PLAIN_YEAR_MONTH_SLOTS[..], 8),
+            (&PLAIN_MONTH_DAY_SLOTS[..], 2),
+            (&DURATION_SLOTS[..], 17),
+            (&ZONED_DATE_TIME_SLOTS[..], 20),
+            (&EPOCH_NANOSECONDS_PARTS_SLOTS[..], 2),
+        ];
+        for (slots, length) in lengths {
+            assert_eq!(slots.len(), length, "{:?}", slots);
         }
+        for slots in [&PLAIN_DATE_SLOTS[..], &PLAIN_DATE_TIME_SLOTS[..], &ZONED_DATE_TIME_SLOTS[..]] {
+            assert_eq!((slots[5], slots[6], slots[slots.len() - 1]), ("weekOfYear", "yearOfWeek", "weekMask"));
+        }
+        assert_eq!(&DURATION_SLOTS[12..], ["microsecondsHigh", "microsecondsLow", "nanosecondsHigh", "nanosecondsLow", "clamped"]);
     }
 
-    /// JNI function for `com.temporal.TemporalNative.durationSubtract()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_durationSubtract(
-        mut env: JNIEnv,
-        _class: JClass,
-        a: JString,
-        b: JString,
-    ) -> jstring {
-        let duration_a = match parse_duration(&mut env, &a, "first duration") {
-            Some(d) => d,
-            None => return ptr::null_mut(),
-        };
-        let duration_b = match parse_duration(&mut env, &b, "second duration") {
-            Some(d) => d,
-            None => return ptr::null_mut(),
-        };
-
-        match duration_a.subtract(&duration_b) {
-            Ok(result) => env
-                .new_string(result.to_string())
-                .map(|js| js.into_raw())
-                .unwrap_or_else(|_| {
-                    throw_range_error(&mut env, "Failed to create result string");
-                    ptr::null_mut()
-                }),
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to subtract durations: {}", e));
-                ptr::null_mut()
-            }
-        }
+    #[test]
+    fn test_calendar_days_in_month_and_year() {
+        let gregory = CString::new("gregory").unwrap();
+        assert_eq!(temporal_calendar_days_in_month(gregory.as_ptr(), 2024, 2), 29);
+        assert_eq!(temporal_calendar_days_in_month(ptr::null(), 2023, 2), 28);
+        assert_eq!(temporal_calendar_days_in_year(ptr::null(), 2023), 365);
+        assert_eq!(temporal_calendar_days_in_year(gregory.as_ptr(), 2024), 366);
+
+        assert_eq!(temporal_calendar_days_in_month(gregory.as_ptr(), 2024, 13), 0);
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::RangeError as i32);
+        assert_eq!(temporal_calendar_days_in_year(ptr::null(), 2024), 366);
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::None as i32);
     }
 
-    /// JNI function for `com.temporal.TemporalNative.durationNegated()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_durationNegated(
-        mut env: JNIEnv,
-        _class: JClass,
-        s: JString,
-    ) -> jstring {
-        let duration = match parse_duration(&mut env, &s, "duration") {
-            Some(d) => d,
-            None => return ptr::null_mut(),
-        };
+    #[test]
+    fn test_calendar_leap_year_and_months() {
+        assert_eq!(temporal_calendar_in_leap_year(ptr::null(), 2024), 1);
+        assert_eq!(temporal_calendar_in_leap_year(ptr::null(), 2023), 0);
+        assert_eq!(temporal_calendar_months_in_year(ptr::null(), 2023), 12);
+
+        let hebrew = CString::new("hebrew").unwrap();
+        assert_eq!(temporal_calendar_in_leap_year(hebrew.as_ptr(), 5784), 1);
+        assert_eq!(temporal_calendar_months_in_year(hebrew.as_ptr(), 5784), 13);
+        assert_eq!(temporal_calendar_months_in_year(hebrew.as_ptr(), 5785), 12);
+
+        let bogus = CString::new("not-a-calendar").unwrap();
+        assert_eq!(temporal_calendar_in_leap_year(bogus.as_ptr(), 2024), -1);
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::RangeError as i32);
+    }
 
-        env.new_string(duration.negated().to_string())
-            .map(|js| js.into_raw())
-            .unwrap_or_else(|_| {
-                throw_range_error(&mut env, "Failed to create result string");
-                ptr::null_mut()
-            })
+    #[test]
+    fn test_calendar_valid_days_in_year() {
+        let mut days = [0u8; 13];
+        let count = temporal_calendar_valid_days_in_year(ptr::null(), 2024, days.as_mut_ptr(), days.len());
+        assert_eq!(count, 12);
+        assert_eq!(&days[..12], &[31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31]);
+        assert_eq!(temporal_calendar_valid_days_in_month(ptr::null(), 2023, 2), 28);
+
+        let hebrew = CString::new("hebrew").unwrap();
+        let mut short = [0u8; 12];
+        assert_eq!(temporal_calendar_valid_days_in_year(hebrew.as_ptr(), 5784, short.as_mut_ptr(), short.len()), 13);
+        assert_eq!(short, [0u8; 12]);
     }
 
-    /// JNI function for `com.temporal.TemporalNative.durationAbs()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_durationAbs(
-        mut env: JNIEnv,
-        _class: JClass,
-        s: JString,
-    ) -> jstring {
-        let duration = match parse_duration(&mut env, &s, "duration") {
-            Some(d) => d,
-            None => return ptr::null_mut(),
-        };
+    #[test]
+    fn test_plain_month_day_reference_year_and_overflow() {
+        let reject = CString::new("reject").unwrap();
 
-        env.new_string(duration.abs().to_string())
-            .map(|js| js.into_raw())
-            .unwrap_or_else(|_| {
-                throw_range_error(&mut env, "Failed to create result string");
-                ptr::null_mut()
-            })
-    }
+        let result = temporal_plain_month_day_from_components(2, 29, ptr::null(), TEMPORAL_NO_REFERENCE_YEAR, reject.as_ptr());
+        assert_eq!(extract_result(result), "02-29");
 
-    /// JNI function for `com.temporal.TemporalNative.durationCompare()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_durationCompare(
-        mut env: JNIEnv,
-        _class: JClass,
-        a: JString,
-        b: JString,
-    ) -> jint {
-        let duration_a = match parse_duration(&mut env, &a, "first duration") {
-            Some(d) => d,
-            None => return 0,
-        };
-        let duration_b = match parse_duration(&mut env, &b, "second duration") {
-            Some(d) => d,
-            None => return 0,
-        };
+        // Year 0 is a real ISO year (a leap year), not "no reference year"
+        let result = temporal_plain_month_day_from_components(2, 29, ptr::null(), 0, reject.as_ptr());
+        assert_eq!(extract_result(result), "02-29");
+        let result = temporal_plain_month_day_from_components(2, 29, ptr::null(), -1, reject.as_ptr());
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut { result }) };
 
-        // Check if durations have calendar units
-        let has_calendar_a = duration_a.years() != 0 || duration_a.months() != 0 || duration_a.weeks() != 0;
-        let has_calendar_b = duration_b.years() != 0 || duration_b.months() != 0 || duration_b.weeks() != 0;
+        let result = temporal_plain_month_day_from_components(2, 29, ptr::null(), 2023, reject.as_ptr());
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut { result }) };
 
-        if has_calendar_a || has_calendar_b {
-            throw_range_error(&mut env, "Comparing durations with years, months, or weeks requires a relativeTo option (not yet supported)");
-            return 0;
-        }
+        let result = temporal_plain_month_day_from_components(2, 29, ptr::null(), 2023, ptr::null());
+        assert_eq!(extract_result(result), "02-28");
+        let result = temporal_plain_month_day_from_components(2, 30, ptr::null(), TEMPORAL_NO_REFERENCE_YEAR, ptr::null());
+        assert_eq!(extract_result(result), "02-29");
+    }
 
-        // For time-only durations, compare by total nanoseconds
-        let total_a = duration_a.days() as i128 * 86_400_000_000_000
-            + duration_a.hours() as i128 * 3_600_000_000_000
-            + duration_a.minutes() as i128 * 60_000_000_000
-            + duration_a.seconds() as i128 * 1_000_000_000
-            + duration_a.milliseconds() as i128 * 1_000_000
-            + duration_a.microseconds() * 1_000
-            + duration_a.nanoseconds();
+    #[test]
+    fn test_duration_sum() {
+        let list = CString::new("PT1H30M\nPT45M\n\nP1DT1S\n").unwrap();
+        let result = temporal_duration_sum(list.as_ptr(), ptr::null());
+        assert_eq!(extract_result(result), "P1DT2H15M1S");
 
-        let total_b = duration_b.days() as i128 * 86_400_000_000_000
-            + duration_b.hours() as i128 * 3_600_000_000_000
-            + duration_b.minutes() as i128 * 60_000_000_000
-            + duration_b.seconds() as i128 * 1_000_000_000
-            + duration_b.milliseconds() as i128 * 1_000_000
-            + duration_b.microseconds() * 1_000
-            + duration_b.nanoseconds();
+        let calendar = CString::new("P1M\nP20D").unwrap();
+        let result = temporal_duration_sum(calendar.as_ptr(), ptr::null());
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut { result }) };
 
-        total_a.cmp(&total_b) as jint
+        let relative_to = CString::new("2024-01-31").unwrap();
+        let result = temporal_duration_sum(calendar.as_ptr(), relative_to.as_ptr());
+        assert_eq!(extract_result(result), "P1M20D");
     }
 
-    /// Sentinel value for "unchanged" component in durationWith.
-    /// Matches JavaScript's Number.MIN_SAFE_INTEGER (-(2^53 - 1)).
-    const UNCHANGED_SENTINEL: i64 = -9007199254740991;
+    #[test]
+    fn test_min_max_lists() {
+        let instants = CString::new("2024-03-01T00:00:00Z\n2023-12-31T23:00:00-02:00\n2024-01-01T00:30:00Z").unwrap();
+        assert_eq!(extract_result(temporal_instant_min(instants.as_ptr())), "2024-01-01T00:30:00Z");
+        assert_eq!(extract_result(temporal_instant_max(instants.as_ptr())), "2024-03-01T00:00:00Z");
 
-    /// JNI function for `com.temporal.TemporalNative.durationWith()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_durationWith(
-        mut env: JNIEnv,
-        _class: JClass,
-        original: JString,
-        years: jlong,
-        months: jlong,
-        weeks: jlong,
-        days: jlong,
-        hours: jlong,
-        minutes: jlong,
-        seconds: jlong,
-        milliseconds: jlong,
-        microseconds: jlong,
-        nanoseconds: jlong,
-    ) -> jstring {
-        let duration = match parse_duration(&mut env, &original, "duration") {
-            Some(d) => d,
-            None => return ptr::null_mut(),
-        };
+        let dates = CString::new("2024-05-01\n2023-01-15\n2024-12-24").unwrap();
+        assert_eq!(extract_result(temporal_plain_date_min(dates.as_ptr())), "2023-01-15");
+        assert_eq!(extract_result(temporal_plain_date_max(dates.as_ptr())), "2024-12-24");
 
-        // Use original values for any component set to UNCHANGED_SENTINEL (sentinel)
-        let new_years = if years == UNCHANGED_SENTINEL { duration.years() } else { years };
-        let new_months = if months == UNCHANGED_SENTINEL { duration.months() } else { months };
-        let new_weeks = if weeks == UNCHANGED_SENTINEL { duration.weeks() } else { weeks };
-        let new_days = if days == UNCHANGED_SENTINEL { duration.days() } else { days };
-        let new_hours = if hours == UNCHANGED_SENTINEL { duration.hours() } else { hours };
-        let new_minutes = if minutes == UNCHANGED_SENTINEL { duration.minutes() } else { minutes };
-        let new_seconds = if seconds == UNCHANGED_SENTINEL { duration.seconds() } else { seconds };
-        let new_milliseconds = if milliseconds == UNCHANGED_SENTINEL { duration.milliseconds() } else { milliseconds };
-        let new_microseconds = if microseconds == UNCHANGED_SENTINEL {
-            duration.microseconds().clamp(i64::MIN as i128, i64::MAX as i128) as i64
-        } else {
-            microseconds
-        };
-        let new_nanoseconds = if nanoseconds == UNCHANGED_SENTINEL {
-            duration.nanoseconds().clamp(i64::MIN as i128, i64::MAX as i128) as i64
-        } else {
-            nanoseconds
-        };
+        let empty = CString::new("").unwrap();
+        let result = temporal_plain_date_min(empty.as_ptr());
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut { result }) };
+    }
 
-        // Check for mixed signs
-        let values = [new_years, new_months, new_weeks, new_days, new_hours, new_minutes,
-                      new_seconds, new_milliseconds, new_microseconds, new_nanoseconds];
-        let non_zero: Vec<i64> = values.iter().copied().filter(|&v| v != 0).collect();
+    #[test]
+    fn test_clamp() {
+        let min = CString::new("2024-06-01").unwrap();
+        let max = CString::new("2024-06-30").unwrap();
+        let before = CString::new("2024-05-20").unwrap();
+        let inside = CString::new("2024-06-15").unwrap();
+        assert_eq!(extract_result(temporal_plain_date_clamp(before.as_ptr(), min.as_ptr(), max.as_ptr())), "2024-06-01");
+        assert_eq!(extract_result(temporal_plain_date_clamp(inside.as_ptr(), min.as_ptr(), max.as_ptr())), "2024-06-15");
+
+        let result = temporal_plain_date_clamp(inside.as_ptr(), max.as_ptr(), min.as_ptr());
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut { result }) };
 
-        if !non_zero.is_empty() {
-            let first_sign = non_zero[0].signum();
-            if !non_zero.iter().all(|&v| v.signum() == first_sign) {
-                throw_range_error(&mut env, "All non-zero duration values must have the same sign");
-                return ptr::null_mut();
-            }
-        }
+        let value = CString::new("2024-01-01T12:00:00Z").unwrap();
+        let lo = CString::new("2024-01-01T00:00:00Z").unwrap();
+        let hi = CString::new("2024-01-01T08:00:00Z").unwrap();
+        assert_eq!(extract_result(temporal_instant_clamp(value.as_ptr(), lo.as_ptr(), hi.as_ptr())), "2024-01-01T08:00:00Z");
+    }
 
-        match Duration::new(
-            new_years,
-            new_months,
-            new_weeks,
-            new_days,
-            new_hours,
-            new_minutes,
-            new_seconds,
-            new_milliseconds,
-            new_microseconds as i128,
-            new_nanoseconds as i128,
-        ) {
-            Ok(result) => env
-                .new_string(result.to_string())
-                .map(|js| js.into_raw())
-                .unwrap_or_else(|_| {
-                    throw_range_error(&mut env, "Failed to create result string");
-                    ptr::null_mut()
-                }),
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid duration: {}", e));
-                ptr::null_mut()
+    #[test]
+    fn test_plain_date_weeks_between() {
+        // 2024-06-09 is a Sunday, 2024-06-10 a Monday.
+        let sunday = CString::new("2024-06-09").unwrap();
+        let monday = CString::new("2024-06-10").unwrap();
+        let later = CString::new("2024-06-30").unwrap();
+        assert_eq!(temporal_plain_date_weeks_between(sunday.as_ptr(), monday.as_ptr(), 1), 1);
+        assert_eq!(temporal_plain_date_weeks_between(sunday.as_ptr(), monday.as_ptr(), 7), 0);
+        assert_eq!(temporal_plain_date_weeks_between(monday.as_ptr(), later.as_ptr(), 1), 2);
+        assert_eq!(temporal_plain_date_weeks_between(later.as_ptr(), sunday.as_ptr(), 1), -3);
+
+        assert_eq!(temporal_plain_date_weeks_between(sunday.as_ptr(), monday.as_ptr(), 0), TEMPORAL_WEEKS_BETWEEN_ERROR);
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::RangeError as i32);
+    }
+
+    #[test]
+    fn test_plain_date_quarters() {
+        let date = CString::new("2024-05-15").unwrap();
+        let mut components = PlainDateComponents::default();
+        temporal_plain_date_get_components(date.as_ptr(), &mut components);
+        assert_eq!(components.quarter, 2);
+        assert_eq!(components.day_of_quarter, 45);
+
+        assert_eq!(extract_result(temporal_plain_date_start_of_quarter(date.as_ptr())), "2024-04-01");
+        assert_eq!(extract_result(temporal_plain_date_end_of_quarter(date.as_ptr())), "2024-06-30");
+
+        let leap = CString::new("2024-02-10").unwrap();
+        assert_eq!(extract_result(temporal_plain_date_end_of_quarter(leap.as_ptr())), "2024-03-31");
+
+        // The arithmetic agrees with counting from the quarter's first day
+        let one_day = Duration::new(0, 0, 0, 1, 0, 0, 0, 0, 0, 0).unwrap();
+        for calendar in ["iso8601", "japanese", "buddhist", "hebrew", "islamic-umalqura", "persian"] {
+            let mut date = PlainDate::from_str(&format!("2023-01-01[u-ca={}]", calendar)).unwrap();
+            for _ in 0..800 {
+                let expected = match quarter_bounds(&date) {
+                    Ok((start, _)) => ((date.month() - 1) / 3 + 1, (date.day_of_year() - start.day_of_year() + 1) as u8),
+                    Err(_) => (0, 0),
+                };
+                assert_eq!(quarter_info(&date), expected, "{}", date);
+                date = date.add(&one_day, None).unwrap();
             }
         }
+    }
+
+    #[test]
+    fn test_start_and_end_of_unit() {
+        let date = CString::new("2024-06-13").unwrap();
+        let week = CString::new("week").unwrap();
+        let month = CString::new("month").unwrap();
+        assert_eq!(extract_result(temporal_plain_date_start_of(date.as_ptr(), week.as_ptr(), 1)), "2024-06-10");
+        assert_eq!(extract_result(temporal_plain_date_end_of(date.as_ptr(), week.as_ptr(), 7)), "2024-06-15");
+        assert_eq!(extract_result(temporal_plain_date_end_of(date.as_ptr(), month.as_ptr(), 1)), "2024-06-30");
+
+        // Sao Paulo skipped midnight when DST started on 2018-11-04.
+        let zdt = CString::new("2018-11-04T12:00:00-02:00[America/Sao_Paulo]").unwrap();
+        let day = CString::new("day").unwrap();
+        assert_eq!(
+            extract_result(temporal_zoned_date_time_start_of(zdt.as_ptr(), day.as_ptr(), 1)),
+            "2018-11-04T01:00:00-02:00[America/Sao_Paulo]"
+        );
+        assert_eq!(
+            extract_result(temporal_zoned_date_time_end_of(zdt.as_ptr(), day.as_ptr(), 1)),
+            "2018-11-04T23:59:59.999999999-02:00[America/Sao_Paulo]"
+        );
 
+        let hour = CString::new("hour").unwrap();
+        let result = temporal_plain_date_start_of(date.as_ptr(), hour.as_ptr(), 1);
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut { result }) };
     }
 
-    /// JNI function for `com.temporal.TemporalNative.timeZoneFromString()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_timeZoneFromString(
-        mut env: JNIEnv,
-        _class: JClass,
-        s: JString,
-    ) -> jstring {
-        let s_str = parse_jstring(&mut env, &s, "timezone string");
-        let s_val = match s_str {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        match TimeZone::try_from_str(&s_val) {
-            Ok(tz) => match tz.identifier() {
-                Ok(id) => env.new_string(id)
-                    .map(|js| js.into_raw())
-                    .unwrap_or(ptr::null_mut()),
-                Err(e) => {
-                    throw_range_error(&mut env, &format!("Failed to get timezone id: {}", e));
-                    ptr::null_mut()
-                }
-            },
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid timezone '{}': {}", s_val, e));
-                ptr::null_mut()
-            }
+    #[test]
+    fn test_format_duration() {
+        let duration = CString::new("PT1H30M5S").unwrap();
+        let styles = [("digital", "1:30:05"), ("long", "1 hour, 30 minutes, 5 seconds"), ("short", "1 hr, 30 min, 5 sec"), ("narrow", "1h 30m 5s")];
+        for (style, expected) in styles {
+            let style = CString::new(style).unwrap();
+            let result = temporal_format_duration_en(duration.as_ptr(), style.as_ptr());
+            assert_eq!(extract_result(result), expected);
         }
-    }
+        assert_eq!(extract_result(temporal_format_duration_en(duration.as_ptr(), ptr::null())), "1 hr, 30 min, 5 sec");
+
+        let negative = CString::new("-P2DT0.25S").unwrap();
+        let digital = CString::new("digital").unwrap();
+        assert_eq!(extract_result(temporal_format_duration_en(negative.as_ptr(), digital.as_ptr())), "-2d 0:00:00.25");
 
-    /// JNI function for `com.temporal.TemporalNative.timeZoneGetId()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_timeZoneGetId(
-        env: JNIEnv,
-        _class: JClass,
-        s: JString,
-    ) -> jstring {
-        Java_com_temporal_TemporalNative_timeZoneFromString(env, _class, s)
+        let clock = CString::new("clock").unwrap();
+        let result = temporal_format_duration_en(duration.as_ptr(), clock.as_ptr());
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut { result }) };
     }
 
-    /// JNI function for `com.temporal.TemporalNative.timeZoneGetOffsetNanosecondsFor()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_timeZoneGetOffsetNanosecondsFor(
-        mut env: JNIEnv,
-        _class: JClass,
-        tz_id: JString,
-        instant_str: JString,
-    ) -> jlong {
-        let tz_s = parse_jstring(&mut env, &tz_id, "timezone");
-        let tz_val = match tz_s {
-            Some(s) => s,
-            None => return 0,
-        };
-        let tz = match TimeZone::try_from_str(&tz_val) {
-            Ok(t) => t,
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid timezone: {}", e));
-                return 0;
-            }
-        };
+    #[test]
+    fn test_parse_with_pattern() {
+        let cases = [
+            ("31/12/2024", "dd/MM/yyyy", "2024-12-31"),
+            ("3.7.24", "d.M.yy", "2024-07-03"),
+            ("2024 day 05 of 02", "yyyy 'day' dd 'of' MM", "2024-02-05"),
+        ];
+        for (input, pattern, expected) in cases {
+            let input = CString::new(input).unwrap();
+            let pattern = CString::new(pattern).unwrap();
+            let result = temporal_parse_with_pattern(input.as_ptr(), pattern.as_ptr(), ptr::null());
+            assert_eq!(extract_result(result), expected);
+        }
 
-        let inst_s = parse_jstring(&mut env, &instant_str, "instant");
-        let inst_val = match inst_s {
-            Some(s) => s,
-            None => return 0,
-        };
-        let instant = match Instant::from_str(&inst_val) {
-            Ok(i) => i,
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid instant: {}", e));
-                return 0;
-            }
-        };
+        let pattern = CString::new("dd/MM/yyyy").unwrap();
+        for bad in ["31/02/2024", "1/12/2024", "31/12/2024x"] {
+            let input = CString::new(bad).unwrap();
+            let result = temporal_parse_with_pattern(input.as_ptr(), pattern.as_ptr(), ptr::null());
+            assert_eq!(result.error_type, TemporalErrorType::RangeError as i32, "{}", bad);
+            unsafe { temporal_free_result(&mut { result }) };
+        }
+    }
 
-        match ZonedDateTime::try_new(instant.epoch_nanoseconds().0, tz, Calendar::default()) {
-            Ok(zdt) => zdt.offset_nanoseconds() as jlong,
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to get offset: {}", e));
-                0
-            }
+    #[test]
+    fn test_format_pattern() {
+        let cases = [
+            ("PlainDate", "2024-03-05", "yyyyMMdd'.log'", "20240305.log"),
+            ("PlainDateTime", "2024-03-05T07:08:09.123", "d/M/yy H:mm:ss.SSS", "5/3/24 7:08:09.123"),
+            ("ZonedDateTime", "2024-03-05T07:08:09+05:30[Asia/Kolkata]", "yyyy-MM-dd HH:mm ZZZZ", "2024-03-05 07:08 GMT+05:30"),
+            ("Instant", "2024-03-05T07:08:09Z", "HH:mm:ss ZZZZ", "07:08:09 GMT"),
+        ];
+        for (kind, value, pattern, expected) in cases {
+            let kind = CString::new(kind).unwrap();
+            let value = CString::new(value).unwrap();
+            let pattern = CString::new(pattern).unwrap();
+            let result = temporal_format_pattern(kind.as_ptr(), value.as_ptr(), pattern.as_ptr());
+            assert_eq!(extract_result(result), expected);
         }
+
+        let kind = CString::new("PlainDate").unwrap();
+        let value = CString::new("2024-03-05").unwrap();
+        let pattern = CString::new("yyyy HH").unwrap();
+        let result = temporal_format_pattern(kind.as_ptr(), value.as_ptr(), pattern.as_ptr());
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut { result }) };
     }
 
-    /// JNI function for `com.temporal.TemporalNative.timeZoneGetOffsetStringFor()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_timeZoneGetOffsetStringFor(
-        mut env: JNIEnv,
-        _class: JClass,
-        tz_id: JString,
-        instant_str: JString,
-    ) -> jstring {
-        let tz_s = parse_jstring(&mut env, &tz_id, "timezone");
-        let tz_val = match tz_s {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        let tz = match TimeZone::try_from_str(&tz_val) {
-            Ok(t) => t,
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid timezone: {}", e));
-                return ptr::null_mut();
-            }
-        };
+    #[test]
+    fn test_holiday_sets() {
+        use crate::holidays::{
+            temporal_holidays_register, temporal_holidays_unregister, temporal_is_holiday, temporal_next_holiday,
+        };
+
+        let set_id = CString::new("test-us").unwrap();
+        let json = CString::new(r#"{"dates": ["2024-03-29"], "rules": [{"month": 12, "day": 25}, {"month": 11, "weekday": 4, "nth": 4}, {"month": 5, "weekday": 1, "nth": -1}]}"#).unwrap();
+        assert_eq!(temporal_holidays_register(set_id.as_ptr(), json.as_ptr()), 4);
+
+        let thanksgiving = CString::new("2024-11-28").unwrap();
+        let memorial_day = CString::new("2024-05-27").unwrap();
+        let ordinary = CString::new("2024-05-20").unwrap();
+        assert_eq!(temporal_is_holiday(thanksgiving.as_ptr(), set_id.as_ptr()), 1);
+        assert_eq!(temporal_is_holiday(memorial_day.as_ptr(), set_id.as_ptr()), 1);
+        assert_eq!(temporal_is_holiday(ordinary.as_ptr(), set_id.as_ptr()), 0);
+
+        assert_eq!(extract_result(temporal_next_holiday(ordinary.as_ptr(), set_id.as_ptr())), "2024-05-27");
+        let after_christmas = CString::new("2024-12-25").unwrap();
+        assert_eq!(extract_result(temporal_next_holiday(after_christmas.as_ptr(), set_id.as_ptr())), "2025-05-26");
+
+        let bad_json = CString::new(r#"{"rules": [{"month": 13, "day": 1}]}"#).unwrap();
+        assert_eq!(temporal_holidays_register(set_id.as_ptr(), bad_json.as_ptr()), -1);
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::RangeError as i32);
+        let deep_json = CString::new(format!("{}{}", "[".repeat(1_000), "]".repeat(1_000))).unwrap();
+        assert_eq!(temporal_holidays_register(set_id.as_ptr(), deep_json.as_ptr()), -1);
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::RangeError as i32);
+
+        // Success clears the error left by the failed registration
+        assert_eq!(temporal_holidays_unregister(set_id.as_ptr()), 1);
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::None as i32);
+        assert_eq!(temporal_is_holiday(thanksgiving.as_ptr(), set_id.as_ptr()), -1);
+
+        // One-off dates are found however far ahead they are
+        let far_json = CString::new(r#"["2024-01-01", "2100-07-04"]"#).unwrap();
+        assert_eq!(temporal_holidays_register(set_id.as_ptr(), far_json.as_ptr()), 2);
+        assert_eq!(extract_result(temporal_next_holiday(ordinary.as_ptr(), set_id.as_ptr())), "2100-07-04");
+        assert_eq!(temporal_holidays_unregister(set_id.as_ptr()), 1);
+    }
 
-        let inst_s = parse_jstring(&mut env, &instant_str, "instant");
-        let inst_val = match inst_s {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        let instant = match Instant::from_str(&inst_val) {
-            Ok(i) => i,
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid instant: {}", e));
-                return ptr::null_mut();
-            }
-        };
+    #[test]
+    fn test_working_time_between() {
+        let work_start = CString::new("09:00").unwrap();
+        let work_end = CString::new("17:00").unwrap();
+        let working_time = |start: &str, end: &str, mask: u8| {
+            let start = CString::new(start).unwrap();
+            let end = CString::new(end).unwrap();
+            extract_result(temporal_working_time_between(
+                start.as_ptr(),
+                end.as_ptr(),
+                work_start.as_ptr(),
+                work_end.as_ptr(),
+                mask,
+            ))
+        };
+
+        // Friday 15:30 to Monday 10:15 with Saturday/Sunday off.
+        assert_eq!(
+            working_time("2024-03-01T15:30:00+01:00[Europe/Warsaw]", "2024-03-04T10:15:00+01:00[Europe/Warsaw]", 0x60),
+            "PT2H45M"
+        );
+        // Same range with no weekend counts the full Saturday and Sunday too.
+        assert_eq!(
+            working_time("2024-03-01T15:30:00+01:00[Europe/Warsaw]", "2024-03-04T10:15:00+01:00[Europe/Warsaw]", 0),
+            "PT18H45M"
+        );
+        // Outside working hours only.
+        assert_eq!(
+            working_time("2024-03-01T18:00:00+01:00[Europe/Warsaw]", "2024-03-01T20:00:00+01:00[Europe/Warsaw]", 0x60),
+            "PT0S"
+        );
 
-        match ZonedDateTime::try_new(instant.epoch_nanoseconds().0, tz, Calendar::default()) {
-            Ok(zdt) => env.new_string(zdt.offset().to_string())
-                .map(|js| js.into_raw())
-                .unwrap_or(ptr::null_mut()),
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to get offset string: {}", e));
-                ptr::null_mut()
-            }
+        let start = CString::new("2024-03-04T10:00:00+01:00[Europe/Warsaw]").unwrap();
+        let end = CString::new("2024-03-01T10:00:00+01:00[Europe/Warsaw]").unwrap();
+        let result = temporal_working_time_between(start.as_ptr(), end.as_ptr(), work_start.as_ptr(), work_end.as_ptr(), 0);
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut { result }) };
+
+        // Ranges longer than the day limit are rejected up front instead of walked
+        for (start, end) in [
+            ("2000-01-01T00:00:00+00:00[UTC]", "2100-01-01T00:00:00+00:00[UTC]"),
+            ("-271821-04-20T00:00:00+00:00[UTC]", "+275760-09-13T00:00:00+00:00[UTC]"),
+        ] {
+            let start = CString::new(start).unwrap();
+            let end = CString::new(end).unwrap();
+            let result = temporal_working_time_between(start.as_ptr(), end.as_ptr(), work_start.as_ptr(), work_end.as_ptr(), 0);
+            assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+            let message = unsafe { std::ffi::CStr::from_ptr(result.error_message) }.to_str().unwrap().to_string();
+            assert_eq!(message, "range must span fewer than 36525 days");
+            unsafe { temporal_free_result(&mut { result }) };
         }
+        assert_eq!(
+            working_time("2000-01-01T00:00:00+00:00[UTC]", "2000-12-31T00:00:00+00:00[UTC]", 0x60),
+            "PT2080H"
+        );
     }
 
-    /// JNI function for `com.temporal.TemporalNative.timeZoneGetPlainDateTimeFor()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_timeZoneGetPlainDateTimeFor(
-        mut env: JNIEnv,
-        _class: JClass,
-        tz_id: JString,
-        instant_str: JString,
-        calendar_id: JString,
-    ) -> jstring {
-        let tz_s = parse_jstring(&mut env, &tz_id, "timezone");
-        let tz_val = match tz_s {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        let tz = match TimeZone::try_from_str(&tz_val) {
-            Ok(t) => t,
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid timezone: {}", e));
-                return ptr::null_mut();
-            }
-        };
+    #[test]
+    fn test_plain_date_compare_iso_and_equals() {
+        let hebrew = CString::new("2024-01-01[u-ca=hebrew]").unwrap();
+        let iso = CString::new("2024-01-01[u-ca=iso8601]").unwrap();
+        let plain = CString::new("2024-01-01").unwrap();
+        let later = CString::new("2024-01-02[u-ca=hebrew]").unwrap();
+
+        let result = temporal_plain_date_compare_iso(hebrew.as_ptr(), iso.as_ptr());
+        assert_eq!(result.error_type, TemporalErrorType::None as i32);
+        assert_eq!(result.value, 0);
+        assert_eq!(temporal_plain_date_compare_iso(hebrew.as_ptr(), later.as_ptr()).value, -1);
+
+        assert_eq!(temporal_plain_date_equals(hebrew.as_ptr(), iso.as_ptr()), 0);
+        assert_eq!(temporal_plain_date_equals(iso.as_ptr(), plain.as_ptr()), 1);
+        assert_eq!(temporal_plain_date_equals(hebrew.as_ptr(), hebrew.as_ptr()), 1);
+
+        let invalid = CString::new("2024-13-01").unwrap();
+        assert_eq!(temporal_plain_date_equals(invalid.as_ptr(), iso.as_ptr()), -1);
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::RangeError as i32);
+    }
 
-        let inst_s = parse_jstring(&mut env, &instant_str, "instant");
-        let inst_val = match inst_s {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        let instant = match Instant::from_str(&inst_val) {
-            Ok(i) => i,
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid instant: {}", e));
-                return ptr::null_mut();
-            }
-        };
+    #[test]
+    fn test_zoned_date_time_add_arithmetic() {
+        let zdt = CString::new("2024-03-09T12:00:00-05:00[America/New_York]").unwrap();
+        let one_day = CString::new("P1D").unwrap();
+        let twenty_four_hours = CString::new("PT24H").unwrap();
+        let calendar = CString::new("calendar").unwrap();
+        let exact = CString::new("exact").unwrap();
+
+        // Across the spring-forward change P1D keeps the wall-clock time (23 hours)...
+        let result = extract_result(temporal_zoned_date_time_add(zdt.as_ptr(), one_day.as_ptr(), ptr::null()));
+        assert_eq!(result, "2024-03-10T12:00:00-04:00[America/New_York]");
+        let result = extract_result(temporal_zoned_date_time_add(zdt.as_ptr(), one_day.as_ptr(), calendar.as_ptr()));
+        assert_eq!(result, "2024-03-10T12:00:00-04:00[America/New_York]");
+        // ...while exact arithmetic makes it 24 elapsed hours, same as PT24H.
+        let result = extract_result(temporal_zoned_date_time_add(zdt.as_ptr(), one_day.as_ptr(), exact.as_ptr()));
+        assert_eq!(result, "2024-03-10T13:00:00-04:00[America/New_York]");
+        let result = extract_result(temporal_zoned_date_time_add(zdt.as_ptr(), twenty_four_hours.as_ptr(), calendar.as_ptr()));
+        assert_eq!(result, "2024-03-10T13:00:00-04:00[America/New_York]");
+
+        // Fall back: 25 hours with calendar arithmetic, 24 with exact.
+        let fall = CString::new("2024-11-02T12:00:00-04:00[America/New_York]").unwrap();
+        let result = extract_result(temporal_zoned_date_time_add(fall.as_ptr(), one_day.as_ptr(), calendar.as_ptr()));
+        assert_eq!(result, "2024-11-03T12:00:00-05:00[America/New_York]");
+        let result = extract_result(temporal_zoned_date_time_add(fall.as_ptr(), one_day.as_ptr(), exact.as_ptr()));
+        assert_eq!(result, "2024-11-03T11:00:00-05:00[America/New_York]");
+
+        let one_month = CString::new("P1M").unwrap();
+        let result = temporal_zoned_date_time_add(zdt.as_ptr(), one_month.as_ptr(), exact.as_ptr());
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut { result }) };
 
-        let calendar = if !calendar_id.is_null() {
-            let id_str = parse_jstring(&mut env, &calendar_id, "calendar id");
-            match id_str {
-                Some(s) => match Calendar::from_str(&s) {
-                    Ok(c) => c,
-                    Err(e) => {
-                        throw_range_error(&mut env, &format!("Invalid calendar: {}", e));
-                        return ptr::null_mut();
-                    }
-                },
-                None => return ptr::null_mut(),
-            }
-        } else {
-            Calendar::default()
-        };
+        let invalid = CString::new("wall").unwrap();
+        let result = temporal_zoned_date_time_add(zdt.as_ptr(), one_day.as_ptr(), invalid.as_ptr());
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut { result }) };
+    }
 
-        match ZonedDateTime::try_new(instant.epoch_nanoseconds().0, tz, calendar) {
-            Ok(zdt) => {
-                let dt = zdt.to_plain_date_time();
-                match dt.to_ixdtf_string(ToStringRoundingOptions::default(), DisplayCalendar::Auto) {
-                    Ok(s) => env.new_string(s)
-                        .map(|js| js.into_raw())
-                        .unwrap_or(ptr::null_mut()),
-                    Err(e) => {
-                        throw_range_error(&mut env, &format!("Failed to format plain date time: {}", e));
-                        ptr::null_mut()
-                    }
-                }
-            },
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to get plain date time: {}", e));
-                ptr::null_mut()
-            }
-        }
+    #[test]
+    #[cfg(feature = "locale-data")]
+    fn test_time_zone_display_name() {
+        let display_name = |tz: &str, locale: Option<&str>, style: &str, instant: &str| {
+            let tz = CString::new(tz).unwrap();
+            let locale = locale.map(|l| CString::new(l).unwrap());
+            let style = CString::new(style).unwrap();
+            let instant = CString::new(instant).unwrap();
+            extract_result(temporal_time_zone_display_name(
+                tz.as_ptr(),
+                locale.as_ref().map_or(ptr::null(), |l| l.as_ptr()),
+                style.as_ptr(),
+                instant.as_ptr(),
+            ))
+        };
+        let winter = "2024-01-15T12:00:00Z";
+        let summer = "2024-07-15T12:00:00Z";
+
+        assert_eq!(display_name("America/Los_Angeles", None, "long", winter), "Pacific Standard Time");
+        assert_eq!(display_name("America/Los_Angeles", Some("en"), "long", summer), "Pacific Daylight Time");
+        assert_eq!(display_name("America/Los_Angeles", Some("en"), "short", summer), "PDT");
+        assert_eq!(display_name("America/Los_Angeles", Some("en"), "longGeneric", summer), "Pacific Time");
+        assert_eq!(display_name("America/Los_Angeles", Some("en"), "longOffset", winter), "GMT-08:00");
+        assert_eq!(display_name("Europe/Warsaw", Some("en"), "shortOffset", summer), "GMT+2");
+        assert_eq!(display_name("Asia/Kolkata", Some("en"), "shortOffset", summer), "GMT+5:30");
+        assert_eq!(display_name("Europe/Paris", Some("en"), "shortOffset", "1900-01-01T00:00:00Z"), "GMT+0:09:21");
+        assert_eq!(display_name("Europe/Warsaw", Some("fr"), "shortOffset", summer), "UTC+2");
+        assert_eq!(display_name("Europe/Berlin", Some("de"), "long", winter), "Mitteleuropäische Normalzeit");
+        // Aliases and other spellings resolve to the zone's names, not the GMT fallback
+        assert_eq!(display_name("Asia/Kolkata", Some("en"), "long", winter), "India Standard Time");
+        assert_eq!(display_name("Asia/Calcutta", Some("en"), "long", winter), "India Standard Time");
+        assert_eq!(display_name("america/los_angeles", Some("en"), "long", winter), "Pacific Standard Time");
+
+        let tz = CString::new("Europe/Warsaw").unwrap();
+        let style = CString::new("medium").unwrap();
+        let result = temporal_time_zone_display_name(tz.as_ptr(), ptr::null(), style.as_ptr(), ptr::null());
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut { result }) };
     }
 
-    /// JNI function for `com.temporal.TemporalNative.timeZoneGetInstantFor()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_timeZoneGetInstantFor(
-        mut env: JNIEnv,
-        _class: JClass,
-        tz_id: JString,
-        dt_str: JString,
-        disambiguation: JString,
-    ) -> jstring {
-        let tz_s = parse_jstring(&mut env, &tz_id, "timezone");
-        let tz_val = match tz_s {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        let tz = match TimeZone::try_from_str(&tz_val) {
-            Ok(t) => t,
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid timezone: {}", e));
-                return ptr::null_mut();
-            }
-        };
+    #[test]
+    fn test_time_zone_offsets_for_year() {
+        let warsaw = CString::new("Europe/Warsaw").unwrap();
+        let result = extract_result(temporal_time_zone_offsets_for_year(warsaw.as_ptr(), 2024));
+        assert_eq!(
+            result,
+            "+01:00,+02:00\n2024-03-31T03:00:00+02:00[Europe/Warsaw]\n2024-10-27T02:00:00+01:00[Europe/Warsaw]"
+        );
 
-        let dt_s = parse_jstring(&mut env, &dt_str, "plain date time");
-        let dt_val = match dt_s {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        let dt = match PlainDateTime::from_str(&dt_val) {
-            Ok(d) => d,
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid plain date time: {}", e));
-                return ptr::null_mut();
-            }
-        };
+        let tokyo = CString::new("Asia/Tokyo").unwrap();
+        assert_eq!(extract_result(temporal_time_zone_offsets_for_year(tokyo.as_ptr(), 2024)), "+09:00");
 
-        // Disambiguation handling... assumes Compatible default or parse string
-        let disambig_enum = if !disambiguation.is_null() {
-            match parse_jstring(&mut env, &disambiguation, "disambiguation") {
-                Some(s) => match s.as_str() {
-                    "compatible" => Disambiguation::Compatible,
-                    "earlier" => Disambiguation::Earlier,
-                    "later" => Disambiguation::Later,
-                    "reject" => Disambiguation::Reject,
-                    _ => Disambiguation::Compatible,
-                },
-                None => return ptr::null_mut(),
-            }
-        } else {
-            Disambiguation::Compatible
-        };
+        let fixed = CString::new("+05:30").unwrap();
+        assert_eq!(extract_result(temporal_time_zone_offsets_for_year(fixed.as_ptr(), 2024)), "+05:30");
+    }
 
-        match dt.to_zoned_date_time(tz, disambig_enum) {
-            Ok(zdt) => {
-                let instant = zdt.to_instant();
-                let provider = &*COMPILED_TZ_PROVIDER;
-                match instant.to_ixdtf_string_with_provider(None, Default::default(), &provider) {
-                    Ok(s) => env.new_string(s)
-                        .map(|js| js.into_raw())
-                        .unwrap_or(ptr::null_mut()),
-                    Err(e) => {
-                        throw_range_error(&mut env, &format!("Failed to format instant: {}", e));
-                        ptr::null_mut()
-                    }
-                }
-            },
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to get instant: {}", e));
-                ptr::null_mut()
-            }
+    #[test]
+    fn test_tz_generation() {
+        let before = temporal_tz_generation();
+        assert_eq!(temporal_notify_tz_changed(), before + 1);
+        assert_eq!(temporal_tz_generation(), before + 1);
+    }
+
+    #[test]
+    fn test_binary_encoding_round_trip() {
+        let zdt = CString::new("2024-03-10T03:30:00.123456789-04:00[America/New_York][u-ca=hebrew]").unwrap();
+        let len = temporal_zoned_date_time_to_bytes(zdt.as_ptr(), ptr::null_mut(), 0);
+        assert_eq!(len, 18 + 1 + "America/New_York".len() + 1 + "hebrew".len());
+        let mut buffer = vec![0u8; len];
+        assert_eq!(temporal_zoned_date_time_to_bytes(zdt.as_ptr(), buffer.as_mut_ptr(), buffer.len()), len);
+        assert_eq!(buffer[0], 1);
+        let decoded = extract_result(temporal_zoned_date_time_from_bytes(buffer.as_ptr(), buffer.len()));
+        assert_eq!(decoded, "2024-03-10T03:30:00.123456789-04:00[America/New_York][u-ca=hebrew]");
+
+        let instant = CString::new("1969-07-20T20:17:40Z").unwrap();
+        let mut buffer = [0u8; 18];
+        assert_eq!(temporal_instant_to_bytes(instant.as_ptr(), buffer.as_mut_ptr(), buffer.len()), 18);
+        assert_eq!(extract_result(temporal_instant_from_bytes(buffer.as_ptr(), buffer.len())), "1969-07-20T20:17:40Z");
+
+        // Wrong kind, truncated input and future versions are rejected.
+        for bytes in [&buffer[..], &buffer[..10]] {
+            let result = temporal_zoned_date_time_from_bytes(bytes.as_ptr(), bytes.len());
+            assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+            unsafe { temporal_free_result(&mut { result }) };
         }
+        buffer[0] = 2;
+        let result = temporal_instant_from_bytes(buffer.as_ptr(), buffer.len());
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut { result }) };
+
+        let invalid = CString::new("not an instant").unwrap();
+        assert_eq!(temporal_instant_to_bytes(invalid.as_ptr(), buffer.as_mut_ptr(), buffer.len()), 0);
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::RangeError as i32);
+
+        // Ids must fit their one-byte length prefix rather than wrap
+        let longest = "x".repeat(255);
+        assert_eq!(encode_epoch(ENCODING_KIND_ZONED_DATE_TIME, 0, &[&longest]).map(|bytes| bytes.len()), Ok(18 + 1 + 255));
+        let too_long = "x".repeat(256);
+        assert!(encode_epoch(ENCODING_KIND_ZONED_DATE_TIME, 0, &[&too_long]).is_err());
     }
 
-    /// JNI function for `com.temporal.TemporalNative.timeZoneGetNextTransition()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_timeZoneGetNextTransition(
-        mut env: JNIEnv,
-        _class: JClass,
-        tz_id: JString,
-        instant_str: JString,
-    ) -> jstring {
-        let tz_s = parse_jstring(&mut env, &tz_id, "timezone");
-        let tz_val = match tz_s {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        let tz = match TimeZone::try_from_str(&tz_val) {
-            Ok(t) => t,
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid timezone: {}", e));
-                return ptr::null_mut();
-            }
-        };
+    #[test]
+    fn test_zoned_date_time_sort_key() {
+        let sort_key = |s: &str| {
+            let s = CString::new(s).unwrap();
+            extract_result(temporal_zoned_date_time_sort_key(s.as_ptr()))
+        };
+        let epoch = sort_key("1970-01-01T00:00:00+00:00[UTC]");
+        assert_eq!(epoch, "08640000000000000000000");
+
+        // IXDTF strings with different offsets sort wrongly as text; keys do not.
+        let tokyo = sort_key("2024-01-01T08:00:00+09:00[Asia/Tokyo]");
+        let new_york = sort_key("2023-12-31T20:00:00-05:00[America/New_York]");
+        let before_epoch = sort_key("1900-01-01T00:00:00+00:00[UTC]");
+        assert!(before_epoch < epoch);
+        assert!(tokyo < new_york);
+        assert_eq!(tokyo.len(), 23);
+        assert_eq!(before_epoch.len(), 23);
+        assert_eq!(sort_key("-271821-04-20T00:00:00+00:00[UTC]"), "00000000000000000000000");
+    }
 
-        let inst_s = parse_jstring(&mut env, &instant_str, "instant");
-        let inst_val = match inst_s {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        let instant = match Instant::from_str(&inst_val) {
-            Ok(i) => i,
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid instant: {}", e));
-                return ptr::null_mut();
-            }
-        };
+    #[test]
+    fn test_batch_parse() {
+        let kind = CString::new("Instant").unwrap();
+        let input = CString::new("2024-01-01T00:00:00+01:00\nnot a date\n2024-06-01T12:30:00.500Z").unwrap();
+        let delimiter = CString::new("\n").unwrap();
+        let mut codes = [-1i32; 3];
+        let result = extract_result(temporal_batch_parse(
+            kind.as_ptr(),
+            input.as_ptr(),
+            delimiter.as_ptr(),
+            codes.as_mut_ptr(),
+            codes.len(),
+        ));
+        assert_eq!(result, "2023-12-31T23:00:00Z\n\n2024-06-01T12:30:00.5Z");
+        assert_eq!(codes, [0, TemporalErrorType::RangeError as i32, 0]);
+
+        let kind = CString::new("PlainDate").unwrap();
+        let input = CString::new("2024-02-29|2024-02-30").unwrap();
+        let delimiter = CString::new("|").unwrap();
+        let result = extract_result(temporal_batch_parse(kind.as_ptr(), input.as_ptr(), delimiter.as_ptr(), ptr::null_mut(), 0));
+        assert_eq!(result, "2024-02-29|");
+
+        let kind = CString::new("Date").unwrap();
+        let result = temporal_batch_parse(kind.as_ptr(), input.as_ptr(), delimiter.as_ptr(), ptr::null_mut(), 0);
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut { result }) };
+    }
 
-        let provider = &*COMPILED_TZ_PROVIDER;
-        
-        let result = match tz {
-            TimeZone::IanaIdentifier(id) => {
-                provider.get_time_zone_transition(id, instant.as_i128(), TransitionDirection::Next)
-            }
-            TimeZone::UtcOffset(_) => Ok(None),
-        };
+    #[test]
+    fn test_normalize_json_timestamps() {
+        let json = CString::new(
+            r#"{"events": [{"id": 12345678901234567890, "start": "2024-01-01T10:00:00+01:00", "day": "2024-01-01"},
+                {"id": 2, "start": "2024-03-10T03:30-04:00[America/New_York]", "end": null, "title": "Say \"hi\"\n"}],
+               "synced": "2024-06-01 12:30:00", "a/b": "2024-06-01t00:00z"}"#,
+        )
+        .unwrap();
+        let paths = CString::new("/events/*/start\n/events/*/end\n/events/0/day\n/synced\n/a~1b\n/missing/path").unwrap();
+        let result = extract_result(temporal_normalize_json_timestamps(json.as_ptr(), paths.as_ptr()));
+        assert_eq!(
+            result,
+            concat!(
+                r#"{"events":[{"id":12345678901234567890,"start":"2024-01-01T09:00:00Z","day":"2024-01-01"},"#,
+                r#"{"id":2,"start":"2024-03-10T03:30:00-04:00[America/New_York]","end":null,"title":"Say \"hi\"\n"}],"#,
+                r#""synced":"2024-06-01T12:30:00","a/b":"2024-06-01T00:00:00Z"}"#
+            )
+        );
 
-        match result {
-            Ok(Some(ns)) => {
-                let instant_next = match Instant::try_new(ns.0) {
-                    Ok(i) => i,
-                    Err(e) => {
-                        throw_range_error(&mut env, &format!("Invalid instant: {}", e));
-                        return ptr::null_mut();
-                    }
-                };
-                match instant_next.to_ixdtf_string_with_provider(None, Default::default(), &provider) {
-                    Ok(s) => env.new_string(s)
-                        .map(|js| js.into_raw())
-                        .unwrap_or(ptr::null_mut()),
-                    Err(e) => {
-                        throw_range_error(&mut env, &format!("Failed to format instant: {}", e));
-                        ptr::null_mut()
-                    }
-                }
-            },
-            Ok(None) => ptr::null_mut(),
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to get next transition: {:?}", e));
-                ptr::null_mut()
-            }
-        }
+        let json = CString::new(r#"{"events": [{"start": "yesterday"}]}"#).unwrap();
+        let paths = CString::new("/events/*/start").unwrap();
+        let result = temporal_normalize_json_timestamps(json.as_ptr(), paths.as_ptr());
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        let message = unsafe { std::ffi::CStr::from_ptr(result.error_message) }.to_string_lossy().into_owned();
+        assert!(message.contains("/events/0/start"), "{}", message);
+        unsafe { temporal_free_result(&mut { result }) };
     }
 
-    /// JNI function for `com.temporal.TemporalNative.timeZoneGetPreviousTransition()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_timeZoneGetPreviousTransition(
-        mut env: JNIEnv,
-        _class: JClass,
-        tz_id: JString,
-        instant_str: JString,
-    ) -> jstring {
-        let tz_s = parse_jstring(&mut env, &tz_id, "timezone");
-        let tz_val = match tz_s {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        let tz = match TimeZone::try_from_str(&tz_val) {
-            Ok(t) => t,
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid timezone: {}", e));
-                return ptr::null_mut();
-            }
-        };
+    #[test]
+    fn test_duration_get_components_split_fields() {
+        let input = CString::new("-PT0.000001002S").unwrap();
+        let mut components = DurationComponents::default();
+        temporal_duration_get_components(input.as_ptr(), &mut components);
+        assert_eq!(components.is_valid, 1);
+        assert_eq!(components.microseconds, -1);
+        assert_eq!((components.microseconds_high, components.microseconds_low), (-1, u64::MAX));
+        assert_eq!((components.nanoseconds_high, components.nanoseconds_low), (-1, u64::MAX - 1));
+        assert_eq!(components.is_clamped, 0);
+
+        let big = i64::MAX as i128 * 1000 + 7;
+        let (high, low) = split_i128(big);
+        assert_eq!(((high as i128) << 64) | low as i128, big);
+        assert_eq!(clamp_i128(big), i64::MAX);
+        let (high, low) = split_i128(-big);
+        assert_eq!(((high as i128) << 64) | low as i128, -big);
+
+        // Duration strings balance into seconds, so only computed durations carry
+        // sub-second fields past i64
+        let just_fits = Duration::new(0, 0, 0, 0, 0, 0, 0, 0, i64::MAX as i128, 0).unwrap();
+        assert_eq!(duration_components(&just_fits).is_clamped, 0);
+        let micros = i64::MAX as i128 + 1;
+        let nanos = i64::MAX as i128 * 3;
+        let over = Duration::new(0, 0, 0, 0, 0, 0, 0, 0, micros, nanos).unwrap();
+        let components = duration_components(&over);
+        assert_eq!(components.is_clamped, 1);
+        assert_eq!((components.microseconds, components.nanoseconds), (i64::MAX, i64::MAX));
+        assert_eq!(join_i128(components.microseconds_high, components.microseconds_low), micros);
+        assert_eq!(join_i128(components.nanoseconds_high, components.nanoseconds_low), nanos);
+        let under = Duration::new(0, 0, 0, 0, 0, 0, 0, 0, 0, -nanos).unwrap();
+        let components = duration_components(&under);
+        assert_eq!((components.is_clamped, components.nanoseconds, components.sign), (1, i64::MIN, -1));
+        assert_eq!(join_i128(components.nanoseconds_high, components.nanoseconds_low), -nanos);
+    }
 
-        let inst_s = parse_jstring(&mut env, &instant_str, "instant");
-        let inst_val = match inst_s {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        let instant = match Instant::from_str(&inst_val) {
-            Ok(i) => i,
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid instant: {}", e));
-                return ptr::null_mut();
+    #[test]
+    fn test_compare_invalid_input_never_returns_equal() {
+        type CompareFn = extern "C" fn(*const c_char, *const c_char) -> CompareResult;
+        type CodeFn = extern "C" fn(*const c_char, *const c_char) -> i32;
+        let cases: [(&str, CompareFn, CodeFn); 7] = [
+            ("2024-01-01T00:00:00Z", temporal_instant_compare, temporal_instant_compare_code),
+            ("10:00", temporal_plain_time_compare, temporal_plain_time_compare_code),
+            ("2024-01-01", temporal_plain_date_compare, temporal_plain_date_compare_code),
+            ("2024-01-01T10:00", temporal_plain_date_time_compare, temporal_plain_date_time_compare_code),
+            ("2024-01", temporal_plain_year_month_compare, temporal_plain_year_month_compare_code),
+            ("2024-01-01T00:00:00+00:00[UTC]", temporal_zoned_date_time_compare, temporal_zoned_date_time_compare_code),
+            ("PT1H", temporal_duration_compare, temporal_duration_compare_code),
+        ];
+        let invalid = CString::new("garbage").unwrap();
+
+        for (valid, compare, compare_code) in cases {
+            let valid = CString::new(valid).unwrap();
+            assert_eq!(compare(valid.as_ptr(), valid.as_ptr()).error_type, TemporalErrorType::None as i32);
+
+            for (a, b) in [(valid.as_ptr(), invalid.as_ptr()), (invalid.as_ptr(), valid.as_ptr())] {
+                let mut result = compare(a, b);
+                assert_eq!(result.error_type, TemporalErrorType::RangeError as i32, "{:?}", valid);
+                assert!(!result.error_message.is_null());
+                unsafe { temporal_free_compare_result(&mut result) };
+                assert_eq!(compare_code(a, b), TEMPORAL_COMPARE_ERROR, "{:?}", valid);
             }
-        };
 
-        let provider = &*COMPILED_TZ_PROVIDER;
-        
-        let result = match tz {
-            TimeZone::IanaIdentifier(id) => {
-                provider.get_time_zone_transition(id, instant.as_i128(), TransitionDirection::Previous)
-            }
-            TimeZone::UtcOffset(_) => Ok(None),
-        };
+            let mut result = compare(ptr::null(), valid.as_ptr());
+            assert_eq!(result.error_type, TemporalErrorType::TypeError as i32, "{:?}", valid);
+            unsafe { temporal_free_compare_result(&mut result) };
+            assert_eq!(compare_code(valid.as_ptr(), ptr::null()), TEMPORAL_COMPARE_ERROR);
+            assert_eq!(temporal_last_error_type(), TemporalErrorType::TypeError as i32);
+        }
+    }
 
-        match result {
-            Ok(Some(ns)) => {
-                let instant_prev = match Instant::try_new(ns.0) {
-                    Ok(i) => i,
-                    Err(e) => {
-                        throw_range_error(&mut env, &format!("Invalid instant: {}", e));
-                        return ptr::null_mut();
-                    }
-                };
-                match instant_prev.to_ixdtf_string_with_provider(None, Default::default(), &provider) {
-                    Ok(s) => env.new_string(s)
-                        .map(|js| js.into_raw())
-                        .unwrap_or(ptr::null_mut()),
-                    Err(e) => {
-                        throw_range_error(&mut env, &format!("Failed to format instant: {}", e));
-                        ptr::null_mut()
-                    }
-                }
-            },
-            Ok(None) => ptr::null_mut(),
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to get previous transition: {:?}", e));
-                ptr::null_mut()
-            }
+    #[test]
+    fn test_plain_year_month_compare_signed_years() {
+        let c = |s: &str| CString::new(s).unwrap();
+        let cases = [
+            ("-000001-06", "-000002-06", 1),
+            ("-000002-06", "-000001-06", -1),
+            ("+275760-09", "2024-01", 1),
+            ("-271821-04", "2024-01", -1),
+            ("-000001-12", "0000-01", -1),
+            ("+010000-01", "9999-12", 1),
+            ("-000001-06", "-000001-06", 0),
+        ];
+        for (a, b, expected) in cases {
+            let (a_c, b_c) = (c(a), c(b));
+            let result = temporal_plain_year_month_compare(a_c.as_ptr(), b_c.as_ptr());
+            assert_eq!(result.error_type, TemporalErrorType::None as i32, "{} vs {}", a, b);
+            assert_eq!(result.value, expected, "{} vs {}", a, b);
+            assert_eq!(temporal_plain_year_month_compare_code(a_c.as_ptr(), b_c.as_ptr()), expected, "{} vs {}", a, b);
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeFromString()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeFromString(
-        mut env: JNIEnv,
-        _class: JClass,
-        s: JString,
-    ) -> jstring {
-        let s_str = parse_jstring(&mut env, &s, "zoned date time string");
-        let s_val = match s_str {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        
-        match ZonedDateTime::from_utf8(s_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
-            Ok(zdt) => match zdt.to_ixdtf_string(DisplayOffset::Auto, DisplayTimeZone::Auto, DisplayCalendar::Auto, ToStringRoundingOptions::default()) {
-                Ok(s) => env.new_string(s)
-                    .map(|js| js.into_raw())
-                    .unwrap_or(ptr::null_mut()),
-                Err(e) => {
-                    throw_range_error(&mut env, &format!("Failed to format zoned date time: {}", e));
-                    ptr::null_mut()
-                }
-            },
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid zoned date time '{}': {}", s_val, e));
-                ptr::null_mut()
-            }
+    #[test]
+    fn test_supported_option_lists() {
+        let modes = extract_result(temporal_supported_rounding_modes());
+        assert_eq!(modes.lines().count(), ROUNDING_MODE_CODES.len());
+        for mode in modes.lines() {
+            assert!(RoundingMode::from_str(mode).is_ok(), "{}", mode);
+        }
+
+        let units = extract_result(temporal_supported_units(ptr::null()));
+        assert_eq!(units.lines().next(), Some("year"));
+        assert_eq!(units.lines().count(), 10);
+        for unit in units.lines() {
+            assert!(Unit::from_str(unit).is_ok(), "{}", unit);
         }
+
+        let kind = CString::new("PlainTime").unwrap();
+        assert_eq!(
+            extract_result(temporal_supported_units(kind.as_ptr())),
+            "hour\nminute\nsecond\nmillisecond\nmicrosecond\nnanosecond"
+        );
+        let kind = CString::new("PlainYearMonth").unwrap();
+        assert_eq!(extract_result(temporal_supported_units(kind.as_ptr())), "year\nmonth");
+
+        let kind = CString::new("Calendar").unwrap();
+        let result = temporal_supported_units(kind.as_ptr());
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut { result }) };
     }
 
-    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeFromComponents()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeFromComponents(
-        mut env: JNIEnv,
-        _class: JClass,
-        year: jint,
-        month: jint,
-        day: jint,
-        hour: jint,
-        minute: jint,
-        second: jint,
-        millisecond: jint,
-        microsecond: jint,
-        nanosecond: jint,
-        calendar_id: JString,
-        time_zone_id: JString,
-        offset_nanoseconds: jlong,
-    ) -> jstring {
-        let calendar = if !calendar_id.is_null() {
-            let id_str = parse_jstring(&mut env, &calendar_id, "calendar id");
-            match id_str {
-                Some(s) => match Calendar::from_str(&s) {
-                    Ok(c) => c,
-                    Err(e) => {
-                        throw_range_error(&mut env, &format!("Invalid calendar: {}", e));
-                        return ptr::null_mut();
-                    }
-                },
-                None => return ptr::null_mut(),
-            }
-        } else {
-            Calendar::default()
-        };
+    #[test]
+    fn test_time_zone_canonicalize() {
+        let canonicalize = |id: &str, preserve: i8| {
+            let id = CString::new(id).unwrap();
+            extract_result(temporal_time_zone_canonicalize(id.as_ptr(), preserve))
+        };
+        assert_eq!(canonicalize("Asia/Calcutta", 0), "Asia/Kolkata");
+        assert_eq!(canonicalize("US/Pacific", 0), "America/Los_Angeles");
+        assert_eq!(canonicalize("america/new_york", 0), "America/New_York");
+        assert_eq!(canonicalize("+05:30", 0), "+05:30");
+        assert_eq!(canonicalize("asia/calcutta", 1), "Asia/Calcutta");
+
+        let invalid = CString::new("Mars/Olympus_Mons").unwrap();
+        let result = temporal_time_zone_canonicalize(invalid.as_ptr(), 0);
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut { result }) };
+    }
+
+    #[test]
+    fn test_lenient_identifier_lookup() {
+        let padded = CString::new("  Europe/Paris\n").unwrap();
+        let result = temporal_time_zone_from_string(padded.as_ptr(), 0);
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut { result }) };
+        assert_eq!(extract_result(temporal_time_zone_from_string(padded.as_ptr(), 1)), "Europe/Paris");
+
+        let shouting = CString::new(" AMERICA/NEW_YORK ").unwrap();
+        assert_eq!(extract_result(temporal_time_zone_from_string(shouting.as_ptr(), 1)), "America/New_York");
+
+        let calendar = CString::new(" Gregory\t").unwrap();
+        let result = temporal_calendar_from(calendar.as_ptr(), 0);
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut { result }) };
+        assert_eq!(extract_result(temporal_calendar_from(calendar.as_ptr(), 1)), "gregory");
+    }
+
+    #[test]
+    fn test_duration_compare_at_limits() {
+        let compare = |a: &str, b: &str| {
+            let a = CString::new(a).unwrap();
+            let b = CString::new(b).unwrap();
+            let mut result = temporal_duration_compare(a.as_ptr(), b.as_ptr());
+            let outcome = (result.error_type == TemporalErrorType::None as i32).then_some(result.value);
+            unsafe { temporal_free_compare_result(&mut result) };
+            outcome
+        };
+        // Largest whole number of days whose total seconds stay below 2^53
+        assert_eq!(compare("P104249991374D", "PT9007199254713600S"), Some(0));
+        assert_eq!(compare("P104249991374D", "PT9007199254740991S"), Some(-1));
+        assert_eq!(compare("-P104249991374D", "-PT9007199254740991S"), Some(1));
+        assert_eq!(compare("P1D", "PT86399.999999999S"), Some(1));
+        assert_eq!(compare("PT1000000000000000000000S", "PT1S"), None);
+        assert_eq!(compare("P9007199254740992D", "PT1S"), None);
+        assert_eq!(compare("P1M", "P30D"), None);
+    }
+
+    #[test]
+    fn test_plain_date_time_to_epoch_ns() {
+        let tz = CString::new("America/New_York").unwrap();
+        let to_epoch_ns = |dt: &str, disambiguation: Option<&str>| {
+            let dt = CString::new(dt).unwrap();
+            let disambiguation = disambiguation.map(|d| CString::new(d).unwrap());
+            temporal_plain_date_time_to_epoch_ns(
+                dt.as_ptr(),
+                tz.as_ptr(),
+                disambiguation.as_ref().map_or(ptr::null(), |d| d.as_ptr()),
+            )
+        };
+
+        assert_eq!(extract_result(to_epoch_ns("2024-01-15T12:00", None)), "1705338000000000000");
+        // 2024-11-03T01:30 happens twice in New York
+        assert_eq!(extract_result(to_epoch_ns("2024-11-03T01:30", Some("earlier"))), "1730611800000000000");
+        assert_eq!(extract_result(to_epoch_ns("2024-11-03T01:30", Some("later"))), "1730615400000000000");
+
+        let result = to_epoch_ns("2024-11-03T01:30", Some("reject"));
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut { result }) };
+        let result = to_epoch_ns("2024-01-15T12:00", Some("latest"));
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut { result }) };
+    }
+
+    #[test]
+    fn test_struct_sizes_match_layout() {
+        let sizes = temporal_struct_sizes();
+        assert_eq!(sizes.temporal_result as usize, 3 * std::mem::size_of::<usize>());
+        assert_eq!(sizes.plain_time_components, 12);
+        assert_eq!(sizes.plain_date_components, 32);
+        assert_eq!(sizes.plain_date_time_components, 40);
+        assert_eq!(sizes.plain_year_month_components, 24);
+        assert_eq!(sizes.plain_month_day_components, 3);
+        assert_eq!(sizes.duration_components as usize, std::mem::size_of::<DurationComponents>());
+        assert_eq!(sizes.duration_components_f64 as usize, std::mem::size_of::<DurationComponentsF64>());
+        assert_eq!(sizes.zoned_date_time_components as usize, std::mem::size_of::<ZonedDateTimeComponents>());
+        assert_eq!(sizes.clock_fields as usize, std::mem::size_of::<ClockFields>());
+    }
+
+    #[test]
+    fn test_interior_nul_is_replaced_not_dropped() {
+        let result = TemporalResult::success("a\0b".to_string());
+        assert_eq!(result.error_type, TemporalErrorType::None as i32);
+        assert_eq!(extract_result(result), "a\u{FFFD}b");
+
+        let mut result = TemporalResult::range_error("bad\0input");
+        let message = unsafe { std::ffi::CStr::from_ptr(result.error_message) };
+        assert_eq!(message.to_str().unwrap(), "bad\u{FFFD}input");
+        unsafe { temporal_free_result(&mut result) };
+    }
+
+    #[test]
+    fn test_set_default_display_options_validation() {
+        use crate::display::temporal_set_default_display_options;
+        let _defaults = lock_display_defaults();
+
+        let bad = CString::new("sometimes").unwrap();
+        assert_eq!(temporal_set_default_display_options(bad.as_ptr(), ptr::null(), ptr::null(), -1), -1);
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::RangeError as i32);
+        assert_eq!(temporal_set_default_display_options(ptr::null(), ptr::null(), ptr::null(), 10), -1);
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::RangeError as i32);
+
+        // Failed calls leave the spec defaults in place; other tests format concurrently,
+        // so only the reset is exercised here.
+        let auto = CString::new("auto").unwrap();
+        assert_eq!(temporal_set_default_display_options(auto.as_ptr(), auto.as_ptr(), auto.as_ptr(), -1), 0);
+        assert_eq!(temporal_set_default_display_options(ptr::null(), ptr::null(), ptr::null(), -1), 0);
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::None as i32);
+
+        let date = CString::new("2024-01-15").unwrap();
+        assert_eq!(extract_result(temporal_plain_date_from_string(date.as_ptr())), "2024-01-15");
+    }
+
+    #[test]
+    fn test_zoned_date_time_clock_fields() {
+        let mut fields = ClockFields::default();
+
+        // 2024-07-04T12:34:56.789Z is 08:34:56 in New York (EDT, -04:00)
+        let epoch_ns = CString::new("1720096496789000000").unwrap();
+        let tz = CString::new("America/New_York").unwrap();
+        temporal_zoned_date_time_clock_fields(epoch_ns.as_ptr(), tz.as_ptr(), &mut fields);
+        assert_eq!(fields.is_valid, 1);
+        assert_eq!((fields.hour, fields.minute, fields.second), (8, 34, 56));
+        assert_eq!(fields.offset_nanoseconds, -4 * 3_600_000_000_000);
+
+        let zdt = CString::new("1969-12-31T23:59:59.5+05:30[Asia/Kolkata]").unwrap();
+        temporal_zoned_date_time_clock_fields(zdt.as_ptr(), std::ptr::null(), &mut fields);
+        assert_eq!(fields.is_valid, 1);
+        assert_eq!((fields.hour, fields.minute, fields.second), (23, 59, 59));
+        assert_eq!(fields.offset_nanoseconds, 19_800_000_000_000);
+
+        let garbage = CString::new("not a number").unwrap();
+        temporal_zoned_date_time_clock_fields(garbage.as_ptr(), tz.as_ptr(), &mut fields);
+        assert_eq!(fields.is_valid, 0);
+    }
+
+    #[test]
+    fn test_ms_until_next_boundary() {
+        let tz = CString::new("America/New_York").unwrap();
+        let next = |epoch_ms: i64, unit: &str| {
+            let unit = CString::new(unit).unwrap();
+            temporal_ms_until_next_boundary(epoch_ms, tz.as_ptr(), unit.as_ptr())
+        };
+
+        // 2024-03-10T01:59:59.250-05:00, just before the spring-forward gap
+        let before_gap = 1_710_053_999_250;
+        assert_eq!(next(before_gap, "second"), 750);
+        assert_eq!(next(before_gap, "minute"), 750);
+        assert_eq!(next(before_gap, "hour"), 750);
+        // The local day is 23 hours long, so midnight is 21h0m0.75s away
+        assert_eq!(next(before_gap, "day"), 21 * 3_600_000 + 750);
+        // Exactly on a boundary, the next one is a full unit away
+        assert_eq!(next(before_gap + 750, "minute"), 60_000);
+
+        let kolkata = CString::new("Asia/Kolkata").unwrap();
+        let hour = CString::new("hour").unwrap();
+        assert_eq!(temporal_ms_until_next_boundary(0, kolkata.as_ptr(), hour.as_ptr()), 1_800_000);
+
+        assert_eq!(next(0, "week"), -1);
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::RangeError as i32);
+        assert_eq!(temporal_ms_until_next_boundary(0, ptr::null(), hour.as_ptr()), -1);
+    }
 
-        let pdt = match PlainDateTime::new(
-            year, month as u8, day as u8, 
-            hour as u8, minute as u8, second as u8, 
-            millisecond as u16, microsecond as u16, nanosecond as u16, 
-            calendar
-        ) {
-            Ok(d) => d,
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid components: {}", e));
-                return ptr::null_mut();
-            }
+    #[test]
+    fn test_split_range_by_local_days() {
+        let split = |start: &str, end: &str| {
+            let start = CString::new(start).unwrap();
+            let end = CString::new(end).unwrap();
+            temporal_split_range_by_local_days(start.as_ptr(), end.as_ptr())
         };
 
-        let tz_s = parse_jstring(&mut env, &time_zone_id, "timezone id");
-        let tz_val = match tz_s {
-            Some(s) => s,
-            None => {
-                throw_type_error(&mut env, "Timezone ID is required");
-                return ptr::null_mut();
-            }
-        };
+        let result = split(
+            "2024-03-09T22:00:00-05:00[America/New_York]",
+            "2024-03-11T06:00:00+00:00[UTC]",
+        );
+        assert_eq!(
+            extract_result(result),
+            "2024-03-09T22:00:00-05:00[America/New_York]\n\
+             2024-03-10T00:00:00-05:00[America/New_York]\n\
+             2024-03-11T00:00:00-04:00[America/New_York]\n\
+             2024-03-11T02:00:00-04:00[America/New_York]"
+        );
 
-        let tz = match TimeZone::try_from_str(&tz_val) {
-            Ok(t) => t,
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid timezone: {}", e));
-                return ptr::null_mut();
-            }
-        };
+        // Sao Paulo skipped midnight when DST started, so that day began at 01:00
+        let result = split(
+            "2018-11-03T12:00:00-03:00[America/Sao_Paulo]",
+            "2018-11-04T12:00:00-02:00[America/Sao_Paulo]",
+        );
+        assert_eq!(
+            extract_result(result),
+            "2018-11-03T12:00:00-03:00[America/Sao_Paulo]\n\
+             2018-11-04T01:00:00-02:00[America/Sao_Paulo]\n\
+             2018-11-04T12:00:00-02:00[America/Sao_Paulo]"
+        );
 
-        match pdt.to_zoned_date_time(tz, Disambiguation::Compatible) {
-            Ok(zdt) => match zdt.to_ixdtf_string(DisplayOffset::Auto, DisplayTimeZone::Auto, DisplayCalendar::Auto, ToStringRoundingOptions::default()) {
-                Ok(s) => env.new_string(s)
-                    .map(|js| js.into_raw())
-                    .unwrap_or(ptr::null_mut()),
-                Err(e) => {
-                    throw_range_error(&mut env, &format!("Failed to format zoned date time: {}", e));
-                    ptr::null_mut()
-                }
-            },
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to create zoned date time: {}", e));
-                ptr::null_mut()
-            }
+        // An end exactly at midnight does not produce an empty trailing segment
+        let result = split("2024-01-01T12:00:00+00:00[UTC]", "2024-01-02T00:00:00+00:00[UTC]");
+        assert_eq!(
+            extract_result(result),
+            "2024-01-01T12:00:00+00:00[UTC]\n2024-01-02T00:00:00+00:00[UTC]"
+        );
+
+        let result = split("2024-01-02T00:00:00+00:00[UTC]", "2024-01-01T00:00:00+00:00[UTC]");
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut { result }) };
+
+        // Ranges longer than the day limit fail before any boundary is built
+        for (start, end) in [
+            ("2000-01-01T00:00:00+00:00[UTC]", "2100-01-01T00:00:00+00:00[UTC]"),
+            ("-271821-04-20T00:00:00+00:00[UTC]", "+275760-09-13T00:00:00+00:00[UTC]"),
+        ] {
+            let result = split(start, end);
+            assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+            let message = unsafe { std::ffi::CStr::from_ptr(result.error_message) }.to_str().unwrap().to_string();
+            assert_eq!(message, "range must span fewer than 36525 days");
+            unsafe { temporal_free_result(&mut { result }) };
         }
+        let result = split("2000-01-01T00:00:00+00:00[UTC]", "2099-12-31T00:00:00+00:00[UTC]");
+        assert_eq!(extract_result(result).lines().count(), 36525);
     }
 
-    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeGetAllComponents()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeGetAllComponents(
-        mut env: JNIEnv,
-        _class: JClass,
-        s: JString,
-    ) -> jlongArray {
-        let s_str = parse_jstring(&mut env, &s, "zoned date time string");
-        let s_val = match s_str {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        
-        // Use default provider
-        let zdt = match ZonedDateTime::from_utf8(s_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
-            Ok(z) => z,
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid zoned date time: {}", e));
-                return ptr::null_mut();
-            }
-        };
+    #[test]
+    fn test_duration_to_string_matches_polyfill() {
+        // Outputs recorded from `Temporal.Duration.from(input).toString()` in @js-temporal/polyfill
+        let strings = [
+            ("PT0S", "PT0S"),
+            ("-PT0S", "PT0S"),
+            ("P0D", "PT0S"),
+            ("-P0Y0M0W0DT0H0M0S", "PT0S"),
+            ("P1DT0S", "P1D"),
+            ("PT1.000S", "PT1S"),
+            ("PT0.100S", "PT0.1S"),
+            ("PT0,5S", "PT0.5S"),
+            ("-PT0.000000001S", "-PT0.000000001S"),
+            ("P1Y2M3W4DT5H6M7.008009010S", "P1Y2M3W4DT5H6M7.00800901S"),
+            ("-P1DT0.5S", "-P1DT0.5S"),
+            ("PT1.5H", "PT1H30M"),
+            ("-PT1.5H", "-PT1H30M"),
+            ("PT0.5M", "PT30S"),
+            ("PT1.000000001H", "PT1H0.0000036S"),
+            ("PT1.123456789M", "PT1M7.40740734S"),
+            ("PT36H", "PT36H"),
+            ("pt1h", "PT1H"),
+            ("+PT1S", "PT1S"),
+            ("PT9007199254740991.999999999S", "PT9007199254740991.999999999S"),
+        ];
+        for (input, expected) in strings {
+            let c_input = CString::new(input).unwrap();
+            assert_eq!(extract_result(temporal_duration_from_string(c_input.as_ptr())), expected, "{}", input);
+        }
 
-        let components: [i64; 19] = [
-            zdt.year() as i64,
-            zdt.month() as i64,
-            zdt.day() as i64,
-            zdt.day_of_week() as i64,
-            zdt.day_of_year() as i64,
-            zdt.week_of_year().unwrap_or(0) as i64,
-            zdt.year_of_week().unwrap_or(0) as i64,
-            zdt.days_in_week() as i64,
-            zdt.days_in_month() as i64,
-            zdt.days_in_year() as i64,
-            zdt.months_in_year() as i64,
-            if zdt.in_leap_year() { 1 } else { 0 },
-            zdt.hour() as i64,
-            zdt.minute() as i64,
-            zdt.second() as i64,
-            zdt.millisecond() as i64,
-            zdt.microsecond() as i64,
-            zdt.nanosecond() as i64,
-            zdt.offset_nanoseconds() as i64,
+        // `Temporal.Duration.from({ ... }).toString()`: sub-second units are folded into seconds
+        let components: [([i64; 10], &str); 7] = [
+            ([0, 0, 0, 0, 0, 0, 0, 1500, 0, 0], "PT1.5S"),
+            ([0, 0, 0, 0, 0, 0, 0, -1500, 0, 0], "-PT1.5S"),
+            ([0, 0, 0, 0, 0, 0, 0, 0, 0, 1_000_000_000], "PT1S"),
+            ([0, 0, 0, 0, 0, 0, 59, 999, 999, 1000], "PT60S"),
+            ([0, 0, 0, 0, 0, 0, 0, 0, 1, 0], "PT0.000001S"),
+            ([0, 0, 0, 0, 0, 90, 0, 0, 0, 0], "PT90M"),
+            ([0, 0, 0, 0, 0, 0, 0, 9_007_199_254_740_991, 0, 0], "PT9007199254740.991S"),
         ];
+        for ([y, mo, w, d, h, mi, s, ms, us, ns], expected) in components {
+            let result = temporal_duration_from_components(y, mo, w, d, h, mi, s, ms, us, ns);
+            assert_eq!(extract_result(result), expected);
+        }
+    }
 
-        match env.new_long_array(19) {
-            Ok(arr) => {
-                if env.set_long_array_region(&arr, 0, &components).is_err() {
-                    throw_range_error(&mut env, "Failed to set array elements");
-                    return ptr::null_mut();
-                }
-                arr.into_raw()
-            }
-            Err(_) => {
-                throw_range_error(&mut env, "Failed to create result array");
-                ptr::null_mut()
-            }
+    #[test]
+    fn test_instant_to_string_precision() {
+        let _defaults = lock_display_defaults();
+        let instant = CString::new("2024-01-15T10:30:45.1236Z").unwrap();
+        let format = |precision: &str| {
+            let precision = CString::new(precision).unwrap();
+            temporal_instant_to_string(instant.as_ptr(), precision.as_ptr(), ptr::null())
+        };
+        assert_eq!(extract_result(format("auto")), "2024-01-15T10:30:45.1236Z");
+        assert_eq!(extract_result(format("0")), "2024-01-15T10:30:45Z");
+        assert_eq!(extract_result(format("3")), "2024-01-15T10:30:45.123Z");
+        assert_eq!(extract_result(format("9")), "2024-01-15T10:30:45.123600000Z");
+        assert_eq!(extract_result(format("minute")), "2024-01-15T10:30Z");
+        assert_eq!(extract_result(format("millisecond")), "2024-01-15T10:30:45.123Z");
+        assert_eq!(extract_result(format("microsecond")), "2024-01-15T10:30:45.123600Z");
+        assert_eq!(
+            extract_result(temporal_instant_to_string(instant.as_ptr(), ptr::null(), ptr::null())),
+            "2024-01-15T10:30:45.1236Z"
+        );
+
+        for invalid in ["10", "hour", "-1", ""] {
+            let mut result = format(invalid);
+            assert_eq!(result.error_type, TemporalErrorType::RangeError as i32, "{}", invalid);
+            unsafe { temporal_free_result(&mut result) };
         }
+
+        let millis = CString::new("millisecond").unwrap();
+        let now = extract_result(temporal_instant_now_with_precision(millis.as_ptr()));
+        assert_eq!(now.len(), "2024-01-15T10:30:45.123Z".len(), "{}", now);
     }
 
-    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeEpochMilliseconds()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeEpochMilliseconds(
-        mut env: JNIEnv,
-        _class: JClass,
-        s: JString,
-    ) -> jstring {
-        let s_str = parse_jstring(&mut env, &s, "zoned date time string");
-        let s_val = match s_str {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        let zdt = match ZonedDateTime::from_utf8(s_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
-            Ok(z) => z,
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid zoned date time: {}", e));
-                return ptr::null_mut();
-            }
-        };
-        env.new_string(zdt.epoch_milliseconds().to_string())
-            .map(|js| js.into_raw())
-            .unwrap_or(ptr::null_mut())
+    #[test]
+    fn test_zoned_date_time_era_getters() {
+        let japanese = CString::new("2019-05-01T09:00:00+09:00[Asia/Tokyo][u-ca=japanese]").unwrap();
+        assert_eq!(extract_result(temporal_zoned_date_time_get_era(japanese.as_ptr())), "reiwa");
+        assert_eq!(extract_result(temporal_zoned_date_time_get_era_year(japanese.as_ptr())), "1");
+        assert_eq!(extract_result(temporal_zoned_date_time_get_month_code(japanese.as_ptr())), "M05");
+
+        let iso = CString::new("2024-02-10T12:00:00+00:00[UTC]").unwrap();
+        assert_eq!(extract_result(temporal_zoned_date_time_get_era(iso.as_ptr())), "");
+        assert_eq!(extract_result(temporal_zoned_date_time_get_era_year(iso.as_ptr())), "");
+        assert_eq!(extract_result(temporal_zoned_date_time_get_month_code(iso.as_ptr())), "M02");
+
+        // Adar I of 5784 is a leap month in the hebrew calendar
+        let hebrew = CString::new("2024-02-10T12:00:00+00:00[UTC][u-ca=hebrew]").unwrap();
+        assert_eq!(extract_result(temporal_zoned_date_time_get_month_code(hebrew.as_ptr())), "M05L");
     }
 
-    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeEpochNanoseconds()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeEpochNanoseconds(
-        mut env: JNIEnv,
-        _class: JClass,
-        s: JString,
-    ) -> jstring {
-        let s_str = parse_jstring(&mut env, &s, "zoned date time string");
-        let s_val = match s_str {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        let zdt = match ZonedDateTime::from_utf8(s_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
-            Ok(z) => z,
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid zoned date time: {}", e));
-                return ptr::null_mut();
-            }
-        };
-        env.new_string(zdt.epoch_nanoseconds().0.to_string())
-            .map(|js| js.into_raw())
-            .unwrap_or(ptr::null_mut())
+    #[test]
+    fn test_plain_date_time_split_and_combine() {
+        let dt = CString::new("2024-01-15T10:30:00.5[u-ca=hebrew]").unwrap();
+        let split = extract_result(temporal_plain_date_time_split(dt.as_ptr()));
+        assert_eq!(split, "2024-01-15[u-ca=hebrew]\n10:30:00.5");
+
+        let (date, time) = split.split_once('\n').unwrap();
+        let date = CString::new(date).unwrap();
+        let time = CString::new(time).unwrap();
+        let combined = temporal_plain_date_time_combine(date.as_ptr(), time.as_ptr());
+        assert_eq!(extract_result(combined), "2024-01-15T10:30:00.5[u-ca=hebrew]");
+
+        let midnight = temporal_plain_date_time_combine(date.as_ptr(), ptr::null());
+        assert_eq!(extract_result(midnight), "2024-01-15T00:00:00[u-ca=hebrew]");
+
+        let mut invalid = temporal_plain_date_time_combine(date.as_ptr(), date.as_ptr());
+        assert_eq!(invalid.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut invalid) };
     }
 
-    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeGetCalendar()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeGetCalendar(
-        mut env: JNIEnv,
-        _class: JClass,
-        s: JString,
-    ) -> jstring {
-        let s_str = parse_jstring(&mut env, &s, "zoned date time string");
-        let s_val = match s_str {
-            Some(s) => s,
-            None => return ptr::null_mut(),
+    #[test]
+    fn test_instant_add_rejects_date_units() {
+        let instant = CString::new("2024-01-15T10:30:00Z").unwrap();
+        let message = |mut result: TemporalResult| {
+            assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+            let message = unsafe { std::ffi::CStr::from_ptr(result.error_message) }.to_string_lossy().into_owned();
+            unsafe { temporal_free_result(&mut result) };
+            message
         };
-        match ZonedDateTime::from_utf8(s_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
-            Ok(z) => env.new_string(z.calendar().identifier())
-                .map(|js| js.into_raw())
-                .unwrap_or(ptr::null_mut()),
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid zoned date time: {}", e));
-                ptr::null_mut()
-            }
-        }
+
+        let days = CString::new("P1DT1H").unwrap();
+        assert!(message(temporal_instant_add(instant.as_ptr(), days.as_ptr())).starts_with("Instants cannot add days;"));
+        let mixed = CString::new("-P1Y2W").unwrap();
+        assert!(message(temporal_instant_subtract(instant.as_ptr(), mixed.as_ptr()))
+            .starts_with("Instants cannot subtract years/weeks;"));
+
+        let hours = CString::new("PT48H").unwrap();
+        assert_eq!(extract_result(temporal_instant_add(instant.as_ptr(), hours.as_ptr())), "2024-01-17T10:30:00Z");
     }
 
-    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeGetTimeZone()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeGetTimeZone(
-        mut env: JNIEnv,
-        _class: JClass,
-        s: JString,
-    ) -> jstring {
-        let s_str = parse_jstring(&mut env, &s, "zoned date time string");
-        let s_val = match s_str {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        match ZonedDateTime::from_utf8(s_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
-            Ok(z) => match z.time_zone().identifier() {
-                Ok(id) => env.new_string(id)
-                    .map(|js| js.into_raw())
-                    .unwrap_or(ptr::null_mut()),
-                Err(e) => {
-                    throw_range_error(&mut env, &format!("Failed to get identifier: {}", e));
-                    ptr::null_mut()
-                }
-            },
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid zoned date time: {}", e));
-                ptr::null_mut()
-            }
+    #[test]
+    fn test_plain_year_month_add_overflow() {
+        let add = |ym: &str, duration: &str, overflow: Option<&str>| {
+            let ym = CString::new(ym).unwrap();
+            let duration = CString::new(duration).unwrap();
+            let overflow = overflow.map(|o| CString::new(o).unwrap());
+            let overflow_ptr = overflow.as_ref().map_or(ptr::null(), |o| o.as_ptr());
+            temporal_plain_year_month_add(ym.as_ptr(), duration.as_ptr(), overflow_ptr)
+        };
+        let subtract = |ym: &str, duration: &str, overflow: Option<&str>| {
+            let ym = CString::new(ym).unwrap();
+            let duration = CString::new(duration).unwrap();
+            let overflow = overflow.map(|o| CString::new(o).unwrap());
+            let overflow_ptr = overflow.as_ref().map_or(ptr::null(), |o| o.as_ptr());
+            temporal_plain_year_month_subtract(ym.as_ptr(), duration.as_ptr(), overflow_ptr)
+        };
+
+        // Landing on a shorter month is fine in either mode, in both directions
+        for overflow in [None, Some("constrain"), Some("reject")] {
+            assert_eq!(extract_result(add("2024-01", "P1M", overflow)), "2024-02");
+            assert_eq!(extract_result(add("2024-03", "-P1M", overflow)), "2024-02");
+            assert_eq!(extract_result(subtract("2024-03", "P1M", overflow)), "2024-02");
+            assert_eq!(extract_result(subtract("2024-01", "-P1M", overflow)), "2024-02");
+            assert_eq!(extract_result(subtract("2024-01", "P1M", overflow)), "2023-12");
+            assert_eq!(extract_result(add("2024-02", "-P1Y", overflow)), "2023-02");
+        }
+
+        // Adar I (M05L) of 5784 has no counterpart in 5785: constrain picks Adar, reject fails
+        let adar_i = "2024-02-10[u-ca=hebrew]";
+        assert_eq!(extract_result(add(adar_i, "P1Y", None)), "2025-03-01[u-ca=hebrew]");
+        assert_eq!(extract_result(add(adar_i, "P1Y", Some("constrain"))), "2025-03-01[u-ca=hebrew]");
+        assert_eq!(extract_result(subtract(adar_i, "-P1Y", None)), "2025-03-01[u-ca=hebrew]");
+        let failures = [
+            add(adar_i, "P1Y", Some("reject")),
+            subtract(adar_i, "P1Y", Some("reject")),
+            add("2024-01", "P1M", Some("balance")),
+        ];
+        for mut result in failures {
+            assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+            unsafe { temporal_free_result(&mut result) };
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeGetOffset()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeGetOffset(
-        mut env: JNIEnv,
-        _class: JClass,
-        s: JString,
-    ) -> jstring {
-        let s_str = parse_jstring(&mut env, &s, "zoned date time string");
-        let s_val = match s_str {
-            Some(s) => s,
-            None => return ptr::null_mut(),
+    #[test]
+    fn test_julian_day_conversions() {
+        use crate::astronomy::{
+            temporal_plain_date_from_julian_day, temporal_plain_date_time_from_julian_date,
+            temporal_plain_date_time_to_julian_date, temporal_plain_date_to_julian_day,
         };
-        match ZonedDateTime::from_utf8(s_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
-            Ok(z) => env.new_string(z.offset().to_string())
-                .map(|js| js.into_raw())
-                .unwrap_or(ptr::null_mut()),
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid zoned date time: {}", e));
-                ptr::null_mut()
-            }
+
+        let dates = [
+            ("2024-01-15", 2_460_325),
+            ("2000-01-01", 2_451_545),
+            ("1970-01-01", 2_440_588),
+            ("-004713-11-24", 0),
+        ];
+        for (date, jdn) in dates {
+            let c_date = CString::new(date).unwrap();
+            assert_eq!(extract_result(temporal_plain_date_to_julian_day(c_date.as_ptr())), jdn.to_string());
+            assert_eq!(extract_result(temporal_plain_date_from_julian_day(jdn, ptr::null())), date);
         }
+        let hebrew = CString::new("hebrew").unwrap();
+        assert_eq!(
+            extract_result(temporal_plain_date_from_julian_day(2_460_325, hebrew.as_ptr())),
+            "2024-01-15[u-ca=hebrew]"
+        );
+
+        // J2000.0 is 2000-01-01T12:00 UT
+        let j2000 = CString::new("2000-01-01T12:00").unwrap();
+        assert_eq!(extract_result(temporal_plain_date_time_to_julian_date(j2000.as_ptr())), "2451545");
+        let evening = CString::new("2024-01-15T18:00").unwrap();
+        assert_eq!(extract_result(temporal_plain_date_time_to_julian_date(evening.as_ptr())), "2460325.25");
+        assert_eq!(
+            extract_result(temporal_plain_date_time_from_julian_date(2_460_325.25, ptr::null())),
+            "2024-01-15T18:00:00"
+        );
+        assert_eq!(
+            extract_result(temporal_plain_date_time_from_julian_date(2_460_324.5, ptr::null())),
+            "2024-01-15T00:00:00"
+        );
+
+        let mut result = temporal_plain_date_time_from_julian_date(f64::NAN, ptr::null());
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut result) };
     }
 
-    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeAdd()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeAdd(
-        mut env: JNIEnv,
-        _class: JClass,
-        zdt_str: JString,
-        duration_str: JString,
-    ) -> jstring {
-        let zdt_s = parse_jstring(&mut env, &zdt_str, "zoned date time");
-        let zdt_val = match zdt_s {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        let zdt = match ZonedDateTime::from_utf8(zdt_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
-            Ok(z) => z,
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid zoned date time: {}", e));
-                return ptr::null_mut();
-            }
+    #[test]
+    fn test_solar_mean_time() {
+        use crate::astronomy::{
+            temporal_local_mean_time_to_zoned_date_time, temporal_solar_mean_noon, temporal_solar_mean_time_offset,
+            temporal_zoned_date_time_to_local_mean_time,
         };
 
-        let dur_s = parse_jstring(&mut env, &duration_str, "duration");
-        let dur_val = match dur_s {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        let duration = match Duration::from_str(&dur_val) {
-            Ok(d) => d,
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid duration: {}", e));
-                return ptr::null_mut();
-            }
-        };
+        assert_eq!(extract_result(temporal_solar_mean_time_offset(15.0)), "PT1H");
+        assert_eq!(extract_result(temporal_solar_mean_time_offset(-74.006)), "-PT4H56M1.44S");
+        assert_eq!(extract_result(temporal_solar_mean_time_offset(0.0)), "PT0S");
 
-        match zdt.add(&duration, Some(Overflow::Reject)) {
-            Ok(result) => match result.to_ixdtf_string(DisplayOffset::Auto, DisplayTimeZone::Auto, DisplayCalendar::Auto, ToStringRoundingOptions::default()) {
-                Ok(s) => env.new_string(s)
-                    .map(|js| js.into_raw())
-                    .unwrap_or(ptr::null_mut()),
-                Err(e) => {
-                    throw_range_error(&mut env, &format!("Failed to format result: {}", e));
-                    ptr::null_mut()
-                }
-            },
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to add duration: {}", e));
-                ptr::null_mut()
-            }
+        // Berlin (13.405 E) is 53m37.2s ahead of UT in mean time, and CET is UT+1
+        let zdt = CString::new("2024-01-15T12:00:00+01:00[Europe/Berlin]").unwrap();
+        assert_eq!(
+            extract_result(temporal_zoned_date_time_to_local_mean_time(zdt.as_ptr(), 13.405)),
+            "2024-01-15T11:53:37.2"
+        );
+        let lmt = CString::new("2024-01-15T11:53:37.2").unwrap();
+        let tz = CString::new("Europe/Berlin").unwrap();
+        assert_eq!(
+            extract_result(temporal_local_mean_time_to_zoned_date_time(lmt.as_ptr(), 13.405, tz.as_ptr())),
+            "2024-01-15T12:00:00+01:00[Europe/Berlin]"
+        );
+
+        let date = CString::new("2024-06-21").unwrap();
+        let new_york = CString::new("America/New_York").unwrap();
+        assert_eq!(
+            extract_result(temporal_solar_mean_noon(date.as_ptr(), -74.006, new_york.as_ptr())),
+            "2024-06-21T12:56:01.44-04:00[America/New_York]"
+        );
+
+        let mut result = temporal_solar_mean_time_offset(200.0);
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut result) };
+    }
+
+    #[test]
+    fn test_plain_date_two_digit_year_pivot() {
+        let parse = |s: &str, pivot: i32| {
+            let c_s = CString::new(s).unwrap();
+            temporal_plain_date_from_string_lenient(c_s.as_ptr(), pivot)
+        };
+        assert_eq!(extract_result(parse("49-03-01", 2049)), "2049-03-01");
+        assert_eq!(extract_result(parse("50-03-01", 2049)), "1950-03-01");
+        assert_eq!(extract_result(parse("00-02-29", 2030)), "2000-02-29");
+        assert_eq!(extract_result(parse("99-12-31", 1999)), "1999-12-31");
+        // Four-digit years are unaffected by the pivot
+        assert_eq!(extract_result(parse("1850-06-01", 2049)), "1850-06-01");
+        assert_eq!(extract_result(parse("2024-01-15", -1)), "2024-01-15");
+
+        for (input, pivot) in [("24-01-15", -1), ("24-13-01", 2049), ("01-02-29", 2049)] {
+            let mut result = parse(input, pivot);
+            assert_eq!(result.error_type, TemporalErrorType::RangeError as i32, "{}", input);
+            unsafe { temporal_free_result(&mut result) };
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeSubtract()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeSubtract(
-        mut env: JNIEnv,
-        _class: JClass,
-        zdt_str: JString,
-        duration_str: JString,
-    ) -> jstring {
-        let zdt_s = parse_jstring(&mut env, &zdt_str, "zoned date time");
-        let zdt_val = match zdt_s {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        let zdt = match ZonedDateTime::from_utf8(zdt_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
-            Ok(z) => z,
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid zoned date time: {}", e));
-                return ptr::null_mut();
-            }
-        };
+    #[test]
+    fn test_until_since_spec_default_largest_unit() {
+        const UNSET: i32 = TEMPORAL_OPTION_UNSET;
+        let c = |s: &str| CString::new(s).unwrap();
+
+        // Spec defaults: hour for ZonedDateTime and PlainTime, day for PlainDate and
+        // PlainDateTime, year for PlainYearMonth, second for Instant
+        let (a, b) = (c("2024-01-01T00:00:00+00:00[UTC]"), c("2024-01-03T06:00:00+00:00[UTC]"));
+        let nulls = (ptr::null(), ptr::null(), 0, ptr::null());
+        assert_eq!(
+            extract_result(temporal_zoned_date_time_until(a.as_ptr(), b.as_ptr(), nulls.0, nulls.1, nulls.2, nulls.3)),
+            "PT54H"
+        );
+        assert_eq!(
+            extract_result(temporal_zoned_date_time_since(b.as_ptr(), a.as_ptr(), nulls.0, nulls.1, nulls.2, nulls.3)),
+            "PT54H"
+        );
+        assert_eq!(extract_result(temporal_zoned_date_time_until_coded(a.as_ptr(), b.as_ptr(), UNSET, UNSET, 1, UNSET)), "PT54H");
+        let day = c("day");
+        assert_eq!(
+            extract_result(temporal_zoned_date_time_until(a.as_ptr(), b.as_ptr(), day.as_ptr(), ptr::null(), 1, ptr::null())),
+            "P2DT6H"
+        );
+        // largestUnit defaults to the larger of "hour" and smallestUnit
+        assert_eq!(
+            extract_result(temporal_zoned_date_time_since(b.as_ptr(), a.as_ptr(), ptr::null(), day.as_ptr(), 1, ptr::null())),
+            "P2D"
+        );
 
-        let dur_s = parse_jstring(&mut env, &duration_str, "duration");
-        let dur_val = match dur_s {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        let duration = match Duration::from_str(&dur_val) {
-            Ok(d) => d,
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid duration: {}", e));
-                return ptr::null_mut();
-            }
-        };
+        let (a, b) = (c("2024-01-01T00:00"), c("2025-03-05T10:00"));
+        assert_eq!(extract_result(temporal_plain_date_time_until(a.as_ptr(), b.as_ptr())), "P429DT10H");
+        assert_eq!(extract_result(temporal_plain_date_time_until_coded(a.as_ptr(), b.as_ptr(), 10, UNSET, 1, UNSET)), "P1Y2M4DT10H");
+        let (a, b) = (c("2024-01-01"), c("2025-03-05"));
+        assert_eq!(extract_result(temporal_plain_date_until(a.as_ptr(), b.as_ptr())), "P429D");
+        assert_eq!(extract_result(temporal_plain_date_since_coded(b.as_ptr(), a.as_ptr(), 9, UNSET, 1, UNSET)), "P14M4D");
+        let (a, b) = (c("2024-01"), c("2025-03"));
+        assert_eq!(extract_result(temporal_plain_year_month_until(a.as_ptr(), b.as_ptr())), "P1Y2M");
+        assert_eq!(extract_result(temporal_plain_year_month_until_coded(a.as_ptr(), b.as_ptr(), 9, UNSET, 1, UNSET)), "P14M");
+        let (a, b) = (c("2024-01-01T00:00:00Z"), c("2024-01-03T06:00:00Z"));
+        assert_eq!(extract_result(temporal_instant_until(a.as_ptr(), b.as_ptr(), nulls.0, nulls.1, nulls.2, nulls.3)), "PT194400S");
+        let (a, b) = (c("01:00"), c("10:30"));
+        assert_eq!(extract_result(temporal_plain_time_until_coded(a.as_ptr(), b.as_ptr(), UNSET, UNSET, 1, UNSET)), "PT9H30M");
+
+        let (a, b) = (c("2024-01-01T00:00:00+00:00[UTC]"), c("2024-01-03T06:00:00+00:00[UTC]"));
+        let bogus = c("fortnight");
+        let mut result = temporal_zoned_date_time_until(a.as_ptr(), b.as_ptr(), bogus.as_ptr(), ptr::null(), 1, ptr::null());
+        let message = unsafe { std::ffi::CStr::from_ptr(result.error_message) }.to_string_lossy().into_owned();
+        assert!(message.contains("Invalid largest unit"), "{}", message);
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut result) };
+    }
 
-        match zdt.subtract(&duration, Some(Overflow::Reject)) {
-            Ok(result) => match result.to_ixdtf_string(DisplayOffset::Auto, DisplayTimeZone::Auto, DisplayCalendar::Auto, ToStringRoundingOptions::default()) {
-                Ok(s) => env.new_string(s)
-                    .map(|js| js.into_raw())
-                    .unwrap_or(ptr::null_mut()),
-                Err(e) => {
-                    throw_range_error(&mut env, &format!("Failed to format result: {}", e));
-                    ptr::null_mut()
-                }
-            },
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to subtract duration: {}", e));
-                ptr::null_mut()
-            }
+    #[test]
+    fn test_validate() {
+        let c = |s: &str| CString::new(s).unwrap();
+        let cases = [
+            ("PlainDate", "2024-01-15", 0),
+            ("PlainDate", "2024-01-", 1),
+            ("PlainDate", "2024-02-30", 1),
+            ("Instant", "2024-01-15T10:30:00Z", 0),
+            ("Instant", "2024-01-15T10:30:00", 1),
+            ("ZonedDateTime", "2024-01-15T10:30:00+01:00[Europe/Paris]", 0),
+            ("ZonedDateTime", "2024-01-15T10:30:00+01:00[Mars/Olympus]", 1),
+            ("Duration", "P1DT2H", 0),
+            ("PlainMonthDay", "02-29", 0),
+            ("Weekday", "2024-01-15", 1),
+        ];
+        for (kind, input, expected) in cases {
+            let (kind, input) = (c(kind), c(input));
+            assert_eq!(temporal_validate(kind.as_ptr(), input.as_ptr()), expected, "{:?} {:?}", kind, input);
         }
+
+        let kind = c("PlainTime");
+        assert_eq!(temporal_validate(kind.as_ptr(), ptr::null()), TemporalErrorType::TypeError as i32);
+        let bad = c("25:00");
+        assert_eq!(temporal_validate(kind.as_ptr(), bad.as_ptr()), TemporalErrorType::RangeError as i32);
+        let message = temporal_last_error_message();
+        assert!(unsafe { std::ffi::CStr::from_ptr(message) }.to_string_lossy().contains("Invalid PlainTime '25:00'"));
+        unsafe { temporal_free_string(message) };
+        let good = c("23:59");
+        assert_eq!(temporal_validate(kind.as_ptr(), good.as_ptr()), 0);
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::None as i32);
     }
 
-    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeCompare()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeCompare(
-        mut env: JNIEnv,
-        _class: JClass,
-        a: JString,
-        b: JString,
-    ) -> jint {
-        let a_str = parse_jstring(&mut env, &a, "first zoned date time");
-        let a_val = match a_str {
-            Some(s) => s,
-            None => return 0,
-        };
-        let zdt_a = match ZonedDateTime::from_utf8(a_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
-            Ok(z) => z,
-            Err(_) => return 0,
-        };
+    #[test]
+    fn test_compare_sub_second_tie_breaks() {
+        let c = |s: &str| CString::new(s).unwrap();
+        let compare_time = |a: &str, b: &str| {
+            let (a, b) = (c(a), c(b));
+            let result = temporal_plain_time_compare(a.as_ptr(), b.as_ptr());
+            assert_eq!(result.error_type, TemporalErrorType::None as i32);
+            let code = temporal_plain_time_compare_code(a.as_ptr(), b.as_ptr());
+            assert_eq!(code, result.value);
+            code
+        };
+        assert_eq!(compare_time("10:30:00.000000001", "10:30:00.000000002"), -1);
+        assert_eq!(compare_time("10:30:00.000000002", "10:30:00.000000001"), 1);
+        assert_eq!(compare_time("10:30:00.123456789", "10:30:00.123456789"), 0);
+        // Higher fields win over lower ones regardless of magnitude
+        assert_eq!(compare_time("10:30:00.000001", "10:30:00.000000999"), 1);
+        assert_eq!(compare_time("10:30:00.001", "10:30:00.000999999"), 1);
+        assert_eq!(compare_time("10:30:01", "10:30:00.999999999"), 1);
+        // Trailing zeros do not change the value
+        assert_eq!(compare_time("10:30:00.1", "10:30:00.100000000"), 0);
+
+        let compare_date_time = |a: &str, b: &str| {
+            let (a, b) = (c(a), c(b));
+            let result = temporal_plain_date_time_compare(a.as_ptr(), b.as_ptr());
+            assert_eq!(result.error_type, TemporalErrorType::None as i32);
+            let code = temporal_plain_date_time_compare_code(a.as_ptr(), b.as_ptr());
+            assert_eq!(code, result.value);
+            code
+        };
+        assert_eq!(compare_date_time("2024-01-15T10:30:00.000000001", "2024-01-15T10:30:00.000000002"), -1);
+        assert_eq!(compare_date_time("2024-01-15T23:59:59.999999999", "2024-01-16T00:00"), -1);
+        assert_eq!(compare_date_time("2024-01-15T10:30:00.5", "2024-01-15T10:30:00.500000000"), 0);
+        assert_eq!(compare_date_time("2024-01-15T10:30:00.000000001[u-ca=hebrew]", "2024-01-15T10:30:00.000000001"), 0);
+
+        // Sorting log entries by time keeps nanosecond order
+        let mut entries = ["10:30:00.000000003", "10:30:00.000000001", "10:30:00.000000002", "10:29:59.999999999"];
+        entries.sort_by(|a, b| compare_time(a, b).cmp(&0));
+        assert_eq!(entries, ["10:29:59.999999999", "10:30:00.000000001", "10:30:00.000000002", "10:30:00.000000003"]);
+    }
 
-        let b_str = parse_jstring(&mut env, &b, "second zoned date time");
-        let b_val = match b_str {
-            Some(s) => s,
-            None => return 0,
-        };
-        let zdt_b = match ZonedDateTime::from_utf8(b_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
-            Ok(z) => z,
-            Err(_) => return 0,
-        };
+    #[test]
+    fn test_seconds_until_next_transition() {
+        let c = |s: &str| CString::new(s).unwrap();
+        let paris = c("Europe/Paris");
+        // Clocks go forward at 2024-03-31T01:00:00Z
+        let hour_before = c("2024-03-31T00:00:00Z");
+        assert_eq!(temporal_time_zone_seconds_until_next_transition(paris.as_ptr(), hour_before.as_ptr()), 3600);
+        let just_before = c("2024-03-31T00:59:59.5Z");
+        assert_eq!(temporal_time_zone_seconds_until_next_transition(paris.as_ptr(), just_before.as_ptr()), 0);
+        // At the transition itself the next one is the autumn change, 2024-10-27T01:00:00Z
+        let at = c("2024-03-31T01:00:00Z");
+        assert_eq!(temporal_time_zone_seconds_until_next_transition(paris.as_ptr(), at.as_ptr()), 210 * 86_400);
+
+        for tz in ["UTC", "+05:30"] {
+            let tz = c(tz);
+            assert_eq!(temporal_time_zone_seconds_until_next_transition(tz.as_ptr(), at.as_ptr()), -1);
+        }
 
-        zdt_a.epoch_nanoseconds().0.cmp(&zdt_b.epoch_nanoseconds().0) as jint
+        let bogus = c("Mars/Olympus");
+        assert_eq!(
+            temporal_time_zone_seconds_until_next_transition(bogus.as_ptr(), at.as_ptr()),
+            TEMPORAL_SECONDS_UNTIL_TRANSITION_ERROR
+        );
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::RangeError as i32);
+        assert_eq!(
+            temporal_time_zone_seconds_until_next_transition(paris.as_ptr(), ptr::null()),
+            TEMPORAL_SECONDS_UNTIL_TRANSITION_ERROR
+        );
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::TypeError as i32);
     }
 
-    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeWith()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeWith(
-        mut env: JNIEnv,
-        _class: JClass,
-        zdt_str: JString,
-        year: jint,
-        month: jint,
-        day: jint,
-        hour: jint,
-        minute: jint,
-        second: jint,
-        millisecond: jint,
-        microsecond: jint,
-        nanosecond: jint,
-        _offset_ns: jlong,
-        calendar_id: JString,
-        time_zone_id: JString,
-    ) -> jstring {
-        let zdt_s = parse_jstring(&mut env, &zdt_str, "zoned date time");
-        let zdt_val = match zdt_s {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        let zdt = match ZonedDateTime::from_utf8(zdt_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
-            Ok(z) => z,
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid zoned date time: {}", e));
-                return ptr::null_mut();
-            }
-        };
-        
-        let current_pdt = zdt.to_plain_date_time();
-    
-        let new_year = if year == i32::MIN { current_pdt.year() } else { year };
-        let new_month = if month == i32::MIN { current_pdt.month() } else { month as u8 };
-        let new_day = if day == i32::MIN { current_pdt.day() } else { day as u8 };
-        
-        let new_hour = if hour == i32::MIN { current_pdt.hour() } else { hour as u8 };
-        let new_minute = if minute == i32::MIN { current_pdt.minute() } else { minute as u8 };
-        let new_second = if second == i32::MIN { current_pdt.second() } else { second as u8 };
-        let new_millisecond = if millisecond == i32::MIN { current_pdt.millisecond() } else { millisecond as u16 };
-        let new_microsecond = if microsecond == i32::MIN { current_pdt.microsecond() } else { microsecond as u16 };
-        let new_nanosecond = if nanosecond == i32::MIN { current_pdt.nanosecond() } else { nanosecond as u16 };
+    #[test]
+    #[cfg(feature = "locale-data")]
+    fn test_formatter_handles() {
+        use crate::formatter::{
+            temporal_formatter_create, temporal_formatter_destroy, temporal_formatter_format,
+            temporal_formatter_format_epoch_ns,
+        };
+
+        let c = |s: &str| CString::new(s).unwrap();
+        let options = c(r#"{"dateStyle":"medium","timeStyle":"short","timeZone":"Europe/Paris"}"#);
+        let en = c("en");
+        let handle = temporal_formatter_create(en.as_ptr(), options.as_ptr());
+        assert_ne!(handle, 0);
+
+        let value = c("2024-01-15T10:30:00Z");
+        assert_eq!(extract_result(temporal_formatter_format(handle, value.as_ptr())), "Jan 15, 2024, 11:30\u{202f}AM");
+        let zdt = c("2024-01-15T10:30:00-05:00[America/New_York]");
+        assert_eq!(extract_result(temporal_formatter_format(handle, zdt.as_ptr())), "Jan 15, 2024, 10:30\u{202f}AM");
+        // 2024-01-15T10:30:00Z
+        assert_eq!(
+            extract_result(temporal_formatter_format_epoch_ns(handle, 1_705_314_600_000_000_000)),
+            "Jan 15, 2024, 11:30\u{202f}AM"
+        );
+        let time_only = c("10:30");
+        let mut result = temporal_formatter_format(handle, time_only.as_ptr());
+        assert_eq!(result.error_type, TemporalErrorType::TypeError as i32);
+        unsafe { temporal_free_result(&mut result) };
 
-        let new_calendar = if !calendar_id.is_null() {
-            let id_str = parse_jstring(&mut env, &calendar_id, "calendar id");
-            match id_str {
-                Some(s) => match Calendar::from_str(&s) {
-                    Ok(c) => c,
-                    Err(e) => {
-                        throw_range_error(&mut env, &format!("Invalid calendar: {}", e));
-                        return ptr::null_mut();
-                    }
-                },
-                None => return ptr::null_mut(),
-            }
-        } else {
-            zdt.calendar().clone()
-        };
-        
-        let new_timezone = if !time_zone_id.is_null() {
-            let id_str = parse_jstring(&mut env, &time_zone_id, "timezone id");
-            match id_str {
-                Some(s) => match TimeZone::try_from_str(&s) {
-                    Ok(t) => t,
-                    Err(e) => {
-                        throw_range_error(&mut env, &format!("Invalid timezone: {}", e));
-                        return ptr::null_mut();
-                    }
-                },
-                None => return ptr::null_mut(),
-            }
-        } else {
-            zdt.time_zone().clone()
-        };
+        // Handles are shared across threads
+        let from_threads: Vec<String> = (0..4)
+            .map(|_| std::thread::spawn(move || {
+                extract_result(temporal_formatter_format_epoch_ns(handle, 1_705_314_600_000_000_000))
+            }))
+            .map(|t| t.join().unwrap())
+            .collect();
+        assert!(from_threads.iter().all(|s| s == "Jan 15, 2024, 11:30\u{202f}AM"));
+
+        assert_eq!(temporal_formatter_destroy(handle), 1);
+        assert_eq!(temporal_formatter_destroy(handle), 0);
+        let mut result = temporal_formatter_format(handle, value.as_ptr());
+        assert_eq!(result.error_type, TemporalErrorType::TypeError as i32);
+        unsafe { temporal_free_result(&mut result) };
 
-        let pdt = match PlainDateTime::new(
-            new_year, new_month, new_day, 
-            new_hour, new_minute, new_second, 
-            new_millisecond, new_microsecond, new_nanosecond, 
-            new_calendar
-        ) {
-            Ok(d) => d,
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid components: {}", e));
-                return ptr::null_mut();
-            }
-        };
-        
-        match pdt.to_zoned_date_time(new_timezone, Disambiguation::Compatible) {
-            Ok(new_zdt) => match new_zdt.to_ixdtf_string(DisplayOffset::Auto, DisplayTimeZone::Auto, DisplayCalendar::Auto, ToStringRoundingOptions::default()) {
-                Ok(s) => env.new_string(s)
-                    .map(|js| js.into_raw())
-                    .unwrap_or(ptr::null_mut()),
-                Err(e) => {
-                    throw_range_error(&mut env, &format!("Failed to format: {}", e));
-                    ptr::null_mut()
-                }
-            },
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to create zoned date time: {}", e));
-                ptr::null_mut()
-            }
+        let de = c("de");
+        let handle = temporal_formatter_create(de.as_ptr(), ptr::null());
+        let date = c("2024-01-15[u-ca=iso8601]");
+        assert_eq!(extract_result(temporal_formatter_format(handle, date.as_ptr())), "15.01.24");
+        // Other calendars are not shown by their ISO fields
+        for value in ["2024-01-15[u-ca=hebrew]", "2024-01-15T10:30:00[u-ca=japanese]", "2024-01-15T10:30:00+01:00[Europe/Paris][u-ca=hebrew]"] {
+            let value = c(value);
+            let mut result = temporal_formatter_format(handle, value.as_ptr());
+            assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+            unsafe { temporal_free_result(&mut result) };
         }
-    }
+        temporal_formatter_destroy(handle);
 
-    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeUntil()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeUntil(
-        mut env: JNIEnv,
-        _class: JClass,
-        one: JString,
-        two: JString,
-    ) -> jstring {
-        let one_str = parse_jstring(&mut env, &one, "first zoned date time");
-        let one_val = match one_str {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        let zdt1 = match ZonedDateTime::from_utf8(one_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
-            Ok(z) => z,
-            Err(_) => return ptr::null_mut(),
-        };
+        let full = c(r#"{"dateStyle":"full"}"#);
+        let handle = temporal_formatter_create(en.as_ptr(), full.as_ptr());
+        assert_eq!(extract_result(temporal_formatter_format(handle, value.as_ptr())), "Monday, January 15, 2024");
+        temporal_formatter_destroy(handle);
 
-        let two_str = parse_jstring(&mut env, &two, "second zoned date time");
-        let two_val = match two_str {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        let zdt2 = match ZonedDateTime::from_utf8(two_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
-            Ok(z) => z,
-            Err(_) => return ptr::null_mut(),
-        };
+        let bad = c(r#"{"timeStyle":"full"}"#);
+        assert_eq!(temporal_formatter_create(en.as_ptr(), bad.as_ptr()), 0);
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::RangeError as i32);
+    }
 
-        match zdt1.until(&zdt2, Default::default()) {
-            Ok(d) => env.new_string(d.to_string())
-                .map(|js| js.into_raw())
-                .unwrap_or(ptr::null_mut()),
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to compute until: {}", e));
-                ptr::null_mut()
+    #[test]
+    #[cfg(feature = "custom-calendars")]
+    fn test_custom_calendar_445() {
+        use crate::custom_calendar::{
+            temporal_custom_calendar_add, temporal_custom_calendar_date_to_iso, temporal_custom_calendar_get_components,
+            temporal_register_custom_calendar, temporal_unregister_custom_calendar, TemporalCustomCalendarVTable,
+        };
+        use std::ffi::{c_void, CStr};
+        use std::sync::atomic::AtomicUsize;
+
+        // 4-4-5 fiscal calendar: the year starts on the Monday of ISO week 1, and the
+        // last month takes the extra week of 53-week years.
+        fn days_from_civil(y: i32, m: u8, d: u8) -> i64 {
+            let y = y as i64 - (m <= 2) as i64;
+            let era = y.div_euclid(400);
+            let yoe = y - era * 400;
+            let mp = (m as i64 + 9) % 12;
+            let doy = (153 * mp + 2) / 5 + d as i64 - 1;
+            era * 146_097 + yoe * 365 + yoe / 4 - yoe / 100 + doy - 719_468
+        }
+        fn civil_from_days(z: i64) -> (i32, u8, u8) {
+            let z = z + 719_468;
+            let era = z.div_euclid(146_097);
+            let doe = z - era * 146_097;
+            let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+            let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+            let mp = (5 * doy + 2) / 153;
+            let d = (doy - (153 * mp + 2) / 5 + 1) as u8;
+            let m = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+            ((yoe + era * 400 + (m <= 2) as i64) as i32, m, d)
+        }
+        fn fy_start(year: i32) -> i64 {
+            let jan4 = days_from_civil(year, 1, 4);
+            jan4 - (jan4 + 3).rem_euclid(7)
+        }
+        extern "C" fn months_in_year(_: *mut c_void, _: i32) -> u8 {
+            12
+        }
+        extern "C" fn days_in_month(_: *mut c_void, year: i32, month: u8) -> u8 {
+            let long_year = fy_start(year + 1) - fy_start(year) == 371;
+            let weeks = if month.is_multiple_of(3) { 5 } else { 4 } + (month == 12 && long_year) as u8;
+            weeks * 7
+        }
+        extern "C" fn date_from_iso(
+            ctx: *mut c_void, iso_year: i32, iso_month: u8, iso_day: u8, year: *mut i32, month: *mut u8, day: *mut u8,
+        ) -> i8 {
+            let days = days_from_civil(iso_year, iso_month, iso_day);
+            let mut fy = iso_year;
+            if days < fy_start(fy) {
+                fy -= 1;
+            } else if days >= fy_start(fy + 1) {
+                fy += 1;
+            }
+            let mut offset = days - fy_start(fy);
+            let mut m = 1;
+            while offset >= days_in_month(ctx, fy, m) as i64 {
+                offset -= days_in_month(ctx, fy, m) as i64;
+                m += 1;
             }
+            unsafe { (*year, *month, *day) = (fy, m, offset as u8 + 1) };
+            1
+        }
+        extern "C" fn date_to_iso(
+            ctx: *mut c_void, year: i32, month: u8, day: u8, iso_year: *mut i32, iso_month: *mut u8, iso_day: *mut u8,
+        ) -> i8 {
+            let before: i64 = (1..month).map(|m| days_in_month(ctx, year, m) as i64).sum();
+            unsafe { (*iso_year, *iso_month, *iso_day) = civil_from_days(fy_start(year) + before + day as i64 - 1) };
+            1
+        }
+        static RELEASED: AtomicUsize = AtomicUsize::new(0);
+        extern "C" fn release(ctx: *mut c_void) {
+            unsafe { &*(ctx as *const AtomicUsize) }.fetch_add(1, AtomicOrdering::SeqCst);
+        }
+
+        let vtable = TemporalCustomCalendarVTable {
+            context: &RELEASED as *const AtomicUsize as *mut c_void,
+            date_from_iso: Some(date_from_iso),
+            date_to_iso: Some(date_to_iso),
+            months_in_year: Some(months_in_year),
+            days_in_month: Some(days_in_month),
+            release: Some(release),
+        };
+        let c = |s: &str| CString::new(s).unwrap();
+        let id = c("fiscal-445");
+        assert_eq!(temporal_register_custom_calendar(id.as_ptr(), &vtable), 0);
+
+        let mut out = PlainDateComponents::default();
+        let date = c("2024-03-15");
+        temporal_custom_calendar_get_components(id.as_ptr(), date.as_ptr(), &mut out);
+        assert_eq!(out.is_valid, 1);
+        assert_eq!((out.year, out.month, out.day), (2024, 3, 19));
+        assert_eq!((out.day_of_year, out.days_in_month, out.days_in_year), (75, 35, 364));
+        assert_eq!((out.quarter, out.day_of_quarter, out.day_of_week), (1, 75, 5));
+        // FY2020 has 53 weeks and starts in the previous ISO year
+        let date = c("2019-12-30");
+        temporal_custom_calendar_get_components(id.as_ptr(), date.as_ptr(), &mut out);
+        assert_eq!((out.year, out.month, out.day, out.days_in_year), (2020, 1, 1, 371));
+
+        let to_iso = |y, m, d, overflow: Option<&str>| {
+            let overflow = overflow.map(c);
+            let mut result = temporal_custom_calendar_date_to_iso(
+                id.as_ptr(), y, m, d, overflow.as_ref().map_or(ptr::null(), |o| o.as_ptr()),
+            );
+            let value = (result.error_type == 0).then(|| unsafe { CStr::from_ptr(result.value) }.to_str().unwrap().to_string());
+            unsafe { temporal_free_result(&mut result) };
+            value
+        };
+        assert_eq!(to_iso(2020, 12, 42, None).as_deref(), Some("2021-01-03"));
+        assert_eq!(to_iso(2024, 12, 42, None).as_deref(), Some("2024-12-29"));
+        assert_eq!(to_iso(2024, 12, 42, Some("reject")), None);
+        assert_eq!(to_iso(2024, 13, 1, Some("reject")), None);
+
+        let add = |date: &str, duration: &str, overflow: Option<&str>| {
+            let (date, duration, overflow) = (c(date), c(duration), overflow.map(c));
+            let mut result = temporal_custom_calendar_add(
+                id.as_ptr(), date.as_ptr(), duration.as_ptr(), overflow.as_ref().map_or(ptr::null(), |o| o.as_ptr()),
+            );
+            let value = (result.error_type == 0).then(|| unsafe { CStr::from_ptr(result.value) }.to_str().unwrap().to_string());
+            unsafe { temporal_free_result(&mut result) };
+            value
+        };
+        assert_eq!(add("2024-01-31", "P1M", None).as_deref(), Some("2024-02-28"));
+        // M3 D35 constrains to M4 D28
+        assert_eq!(add("2024-03-31", "P1M", None).as_deref(), Some("2024-04-28"));
+        assert_eq!(add("2024-03-31", "P1M", Some("reject")), None);
+        assert_eq!(add("2024-11-25", "P1M", None).as_deref(), Some("2024-12-30"));
+        assert_eq!(add("2024-12-30", "-P1M", None).as_deref(), Some("2024-11-25"));
+        assert_eq!(add("2024-03-15", "P1Y1M1D", None).as_deref(), Some("2025-04-19"));
+
+        // The same calendar through the PlainDate functions, via the annotation
+        let plain = |f: extern "C" fn(*const c_char, *const c_char) -> TemporalResult, date: &str, duration: &str| {
+            let (date, duration) = (c(date), c(duration));
+            let mut result = f(date.as_ptr(), duration.as_ptr());
+            let value = (result.error_type == 0).then(|| unsafe { CStr::from_ptr(result.value) }.to_str().unwrap().to_string());
+            unsafe { temporal_free_result(&mut result) };
+            value
+        };
+        let annotated = c("2024-03-15[u-ca=fiscal-445]");
+        assert_eq!(extract_result(temporal_plain_date_from_string(annotated.as_ptr())), "2024-03-15[u-ca=fiscal-445]");
+        assert_eq!(extract_result(temporal_plain_date_get_calendar(annotated.as_ptr())), "fiscal-445");
+        temporal_plain_date_get_components(annotated.as_ptr(), &mut out);
+        assert_eq!((out.is_valid, out.year, out.month, out.day), (1, 2024, 3, 19));
+        assert_eq!(
+            plain(temporal_plain_date_add, "2024-03-31[u-ca=fiscal-445]", "P1M").as_deref(),
+            Some("2024-04-28[u-ca=fiscal-445]")
+        );
+        assert_eq!(
+            plain(temporal_plain_date_subtract, "2024-12-30[u-ca=fiscal-445]", "P1M").as_deref(),
+            Some("2024-11-25[u-ca=fiscal-445]")
+        );
+        // Month counts beyond any representable date fail instead of stepping for ever
+        assert_eq!(plain(temporal_plain_date_add, "2024-03-15[u-ca=fiscal-445]", "P100000000M"), None);
+        assert_eq!(plain(temporal_plain_date_add, "2024-03-15[u-ca=fiscal-unknown]", "P1M"), None);
+
+        // Fields the callbacks report inconsistently are errors, not panics
+        extern "C" fn month_zero(
+            _: *mut c_void, iso_year: i32, _: u8, iso_day: u8, year: *mut i32, month: *mut u8, day: *mut u8,
+        ) -> i8 {
+            unsafe { (*year, *month, *day) = (iso_year, 0, iso_day) };
+            1
+        }
+        extern "C" fn no_days(_: *mut c_void, _: i32, _: u8) -> u8 {
+            0
+        }
+        let broken_id = c("broken-fiscal");
+        let broken = TemporalCustomCalendarVTable { date_from_iso: Some(month_zero), release: None, ..vtable };
+        assert_eq!(temporal_register_custom_calendar(broken_id.as_ptr(), &broken), 0);
+        temporal_custom_calendar_get_components(broken_id.as_ptr(), date.as_ptr(), &mut out);
+        assert_eq!(out.is_valid, 0);
+        let broken = TemporalCustomCalendarVTable { days_in_month: Some(no_days), release: None, ..vtable };
+        assert_eq!(temporal_register_custom_calendar(broken_id.as_ptr(), &broken), 0);
+        let mut result = temporal_custom_calendar_date_to_iso(broken_id.as_ptr(), 2024, 1, 1, ptr::null());
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut result) };
+        assert_eq!(temporal_unregister_custom_calendar(broken_id.as_ptr()), 1);
+
+        let unknown = c("fiscal-unknown");
+        assert_eq!(add_unknown(&unknown, &date), TemporalErrorType::RangeError as i32);
+        fn add_unknown(id: &CString, date: &CString) -> i32 {
+            let duration = CString::new("P1M").unwrap();
+            let mut result = temporal_custom_calendar_add(id.as_ptr(), date.as_ptr(), duration.as_ptr(), ptr::null());
+            let error_type = result.error_type;
+            unsafe { temporal_free_result(&mut result) };
+            error_type
         }
+
+        let builtin = c("gregory");
+        assert_eq!(temporal_register_custom_calendar(builtin.as_ptr(), &vtable), -1);
+        let incomplete = TemporalCustomCalendarVTable { days_in_month: None, ..vtable };
+        assert_eq!(temporal_register_custom_calendar(id.as_ptr(), &incomplete), -1);
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::TypeError as i32);
+
+        assert_eq!(RELEASED.load(AtomicOrdering::SeqCst), 0);
+        assert_eq!(temporal_register_custom_calendar(id.as_ptr(), &vtable), 0);
+        assert_eq!(RELEASED.load(AtomicOrdering::SeqCst), 1);
+        assert_eq!(temporal_unregister_custom_calendar(id.as_ptr()), 1);
+        assert_eq!(temporal_unregister_custom_calendar(id.as_ptr()), 0);
+        assert_eq!(RELEASED.load(AtomicOrdering::SeqCst), 2);
     }
 
-    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeSince()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeSince(
-        mut env: JNIEnv,
-        _class: JClass,
-        one: JString,
-        two: JString,
-    ) -> jstring {
-        let one_str = parse_jstring(&mut env, &one, "first zoned date time");
-        let one_val = match one_str {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        let zdt1 = match ZonedDateTime::from_utf8(one_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
-            Ok(z) => z,
-            Err(_) => return ptr::null_mut(),
-        };
+    #[test]
+    fn test_fiscal_year_and_quarter() {
+        let c = |s: &str| CString::new(s).unwrap();
+        let cases = [
+            ("2024-09-30", 10, 2024, 4),
+            ("2024-10-01", 10, 2025, 1),
+            ("2025-01-15", 10, 2025, 2),
+            ("2024-04-01", 4, 2025, 1),
+            ("2024-03-31", 4, 2024, 4),
+            ("2024-07-04", 7, 2025, 1),
+            ("2024-02-29", 1, 2024, 1),
+            ("2024-12-31", 1, 2024, 4),
+        ];
+        for (date, start, year, quarter) in cases {
+            let date = c(date);
+            assert_eq!(temporal_fiscal_year_of(date.as_ptr(), start), year, "{:?} from {}", date, start);
+            assert_eq!(temporal_fiscal_quarter_of(date.as_ptr(), start), quarter, "{:?} from {}", date, start);
+        }
 
-        let two_str = parse_jstring(&mut env, &two, "second zoned date time");
-        let two_val = match two_str {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        let zdt2 = match ZonedDateTime::from_utf8(two_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
-            Ok(z) => z,
-            Err(_) => return ptr::null_mut(),
-        };
+        let date = c("2024-10-01");
+        assert_eq!(temporal_fiscal_year_of(date.as_ptr(), 0), TEMPORAL_FISCAL_ERROR);
+        assert_eq!(temporal_fiscal_quarter_of(date.as_ptr(), 13), TEMPORAL_FISCAL_ERROR);
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::RangeError as i32);
+        assert_eq!(temporal_fiscal_year_of(ptr::null(), 10), TEMPORAL_FISCAL_ERROR);
+        // Lunisolar and 13-month calendars are rejected in every year: 5784 is a Hebrew
+        // leap year and 5785 a common one
+        for date in ["2024-03-15[u-ca=hebrew]", "2025-03-15[u-ca=hebrew]", "2024-03-15[u-ca=chinese]", "2024-03-15[u-ca=coptic]"] {
+            let date = c(date);
+            assert_eq!(temporal_fiscal_year_of(date.as_ptr(), 1), TEMPORAL_FISCAL_ERROR, "{:?}", date);
+            assert_eq!(temporal_last_error_type(), TemporalErrorType::RangeError as i32);
+            assert_eq!(temporal_fiscal_quarter_of(date.as_ptr(), 1), TEMPORAL_FISCAL_ERROR, "{:?}", date);
+        }
+        let persian = c("2024-03-15[u-ca=persian]");
+        assert_eq!(temporal_fiscal_year_of(persian.as_ptr(), 1), 1402);
+    }
 
-        match zdt1.since(&zdt2, Default::default()) {
-            Ok(d) => env.new_string(d.to_string())
-                .map(|js| js.into_raw())
-                .unwrap_or(ptr::null_mut()),
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to compute since: {}", e));
-                ptr::null_mut()
-            }
+    #[test]
+    fn test_world_clock() {
+        let ids = CString::new("UTC\nAsia/Kolkata\n\nAmerica/New_York\nMars/Olympus\nAsia/Tokyo").unwrap();
+        // 2024-01-15T23:30:00Z
+        let epoch_ns = 1_705_361_400_000_000_000;
+        assert_eq!(temporal_world_clock(epoch_ns, ids.as_ptr(), ptr::null_mut(), 0), 5);
+
+        let mut out: Vec<ZonedDateTimeComponents> = (0..5).map(|_| ZonedDateTimeComponents::default()).collect();
+        assert_eq!(temporal_world_clock(epoch_ns, ids.as_ptr(), out.as_mut_ptr(), out.len()), 5);
+        let local = |c: &ZonedDateTimeComponents| (c.is_valid, c.day, c.hour, c.minute, c.offset_nanoseconds / 60_000_000_000);
+        assert_eq!(local(&out[0]), (1, 15, 23, 30, 0));
+        assert_eq!(local(&out[1]), (1, 16, 5, 0, 330));
+        assert_eq!(local(&out[2]), (1, 15, 18, 30, -300));
+        assert_eq!(local(&out[3]).0, 0);
+        assert_eq!(local(&out[4]), (1, 16, 8, 30, 540));
+        assert_eq!(out[4].day_of_week, 2);
+
+        // A short buffer is filled as far as it goes
+        let mut short = [ZonedDateTimeComponents::default(), ZonedDateTimeComponents::default()];
+        assert_eq!(temporal_world_clock(epoch_ns, ids.as_ptr(), short.as_mut_ptr(), short.len()), 5);
+        assert_eq!(local(&short[1]), (1, 16, 5, 0, 330));
+
+        // NULL is a TypeError, while an empty list is not an error
+        assert_eq!(temporal_world_clock(epoch_ns, ptr::null(), short.as_mut_ptr(), short.len()), 0);
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::TypeError as i32);
+        let empty = CString::new("").unwrap();
+        assert_eq!(temporal_world_clock(epoch_ns, empty.as_ptr(), short.as_mut_ptr(), short.len()), 0);
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::None as i32);
+    }
+
+    #[test]
+    fn test_overlap_daily_windows() {
+        use crate::meetings::temporal_overlap_daily_windows;
+
+        let overlap = |json: &str, date: &str| {
+            let (json, date) = (CString::new(json).unwrap(), CString::new(date).unwrap());
+            temporal_overlap_daily_windows(json.as_ptr(), date.as_ptr())
+        };
+        let berlin_new_york = r#"[
+            {"timeZone": "Europe/Berlin", "start": "09:00", "end": "17:00"},
+            {"timeZone": "America/New_York", "windows": [
+                {"start": "08:00", "end": "10:00"}, {"start": "10:30", "end": "18:00"}
+            ]}
+        ]"#;
+        assert_eq!(
+            extract_result(overlap(berlin_new_york, "2024-01-15")),
+            "2024-01-15T13:00:00Z/2024-01-15T15:00:00Z\n2024-01-15T15:30:00Z/2024-01-15T16:00:00Z"
+        );
+        // US DST starts two weeks before Europe's, shifting New York an hour earlier
+        assert_eq!(
+            extract_result(overlap(berlin_new_york, "2024-03-18")),
+            "2024-03-18T12:00:00Z/2024-03-18T14:00:00Z\n2024-03-18T14:30:00Z/2024-03-18T16:00:00Z"
+        );
+        // An overnight window runs into the next day
+        let overnight = r#"[
+            {"timeZone": "Asia/Tokyo", "start": "22:00", "end": "02:00"},
+            {"timeZone": "UTC", "start": "12:00", "end": "16:00"}
+        ]"#;
+        assert_eq!(extract_result(overlap(overnight, "2024-01-15")), "2024-01-15T13:00:00Z/2024-01-15T16:00:00Z");
+        let disjoint = r#"[
+            {"timeZone": "Asia/Tokyo", "start": "09:00", "end": "17:00"},
+            {"timeZone": "America/Los_Angeles", "start": "09:00", "end": "17:00"}
+        ]"#;
+        assert_eq!(extract_result(overlap(disjoint, "2024-01-15")), "");
+
+        let invalid_inputs = [
+            "[]",
+            "{}",
+            r#"[{"timeZone": "Nowhere/City", "start": "09:00", "end": "17:00"}]"#,
+            r#"[{"timeZone": "UTC", "start": "9am", "end": "17:00"}]"#,
+        ];
+        for invalid in invalid_inputs {
+            let mut result = overlap(invalid, "2024-01-15");
+            assert_eq!(result.error_type, TemporalErrorType::RangeError as i32, "{}", invalid);
+            unsafe { temporal_free_result(&mut result) };
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeRound()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeRound(
-        mut env: JNIEnv,
-        _class: JClass,
-        zdt_str: JString,
-        smallest_unit: JString,
-        rounding_increment: jlong,
-        rounding_mode: JString,
-    ) -> jstring {
-        let s_str = parse_jstring(&mut env, &zdt_str, "zoned date time");
-        let s_val = match s_str {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        let zdt = match ZonedDateTime::from_utf8(s_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
-            Ok(z) => z,
-            Err(_) => return ptr::null_mut(),
-        };
+    #[test]
+    fn test_plain_date_ordinal() {
+        let c = |s: &str| CString::new(s).unwrap();
+        assert_eq!(extract_result(temporal_plain_date_from_ordinal(2024, 75, ptr::null())), "2024-03-15");
+        assert_eq!(extract_result(temporal_plain_date_from_ordinal(2024, 366, ptr::null())), "2024-12-31");
+        for (year, day) in [(2023, 366), (2024, 0)] {
+            let mut result = temporal_plain_date_from_ordinal(year, day, ptr::null());
+            assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+            unsafe { temporal_free_result(&mut result) };
+        }
+        // Day 1 of Hebrew year 5785 is 1 Tishrei
+        let hebrew = c("hebrew");
+        assert_eq!(
+            extract_result(temporal_plain_date_from_ordinal(5785, 1, hebrew.as_ptr())),
+            "2024-10-03[u-ca=hebrew]"
+        );
 
-        let unit = if !smallest_unit.is_null() {
-            let s = parse_jstring(&mut env, &smallest_unit, "smallest unit");
-            match s {
-                Some(s) => match Unit::from_str(&s) {
-                    Ok(u) => u,
-                    Err(_) => {
-                        throw_range_error(&mut env, &format!("Invalid smallest unit: {}", s));
-                        return ptr::null_mut();
-                    }
-                },
-                None => {
-                    throw_type_error(&mut env, "smallestUnit is required");
-                    return ptr::null_mut();
-                }
-            }
-        } else {
-            throw_type_error(&mut env, "smallestUnit is required");
-            return ptr::null_mut();
-        };
+        for (input, expected) in [("2024-075", "2024-03-15"), ("2024075", "2024-03-15"), ("+010000-001", "+010000-01-01")] {
+            let input = c(input);
+            assert_eq!(extract_result(temporal_plain_date_from_ordinal_string(input.as_ptr())), expected);
+        }
+        for invalid in ["2024-75", "2024-0750", "24-075", "2023-366", "-000000-001", "2024-W11"] {
+            let input = c(invalid);
+            let mut result = temporal_plain_date_from_ordinal_string(input.as_ptr());
+            assert_eq!(result.error_type, TemporalErrorType::RangeError as i32, "{}", invalid);
+            unsafe { temporal_free_result(&mut result) };
+        }
 
-        let mode = if !rounding_mode.is_null() {
-            let s = parse_jstring(&mut env, &rounding_mode, "rounding mode");
-            match s {
-                Some(s) => match RoundingMode::from_str(&s) {
-                    Ok(m) => m,
-                    Err(_) => {
-                        throw_range_error(&mut env, &format!("Invalid rounding mode: {}", s));
-                        return ptr::null_mut();
-                    }
-                },
-                None => RoundingMode::HalfExpand,
-            }
-        } else {
-            RoundingMode::HalfExpand
-        };
+        for (input, expected) in [("2024-03-15", "2024-075"), ("2024-10-03[u-ca=hebrew]", "2024-277"), ("-000001-12-31", "-000001-365")] {
+            let input = c(input);
+            assert_eq!(extract_result(temporal_plain_date_to_ordinal_string(input.as_ptr())), expected);
+        }
+    }
 
-        let increment = if rounding_increment > 0 {
-            rounding_increment as u32
-        } else {
-            1
-        };
-        
-        let increment_opt = match RoundingIncrement::try_new(increment) {
-            Ok(i) => i,
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid rounding increment: {}", e));
-                return ptr::null_mut();
-            }
-        };
+    #[test]
+    fn test_max_input_length() {
+        // Restoring the default is a no-op; other values would race with parallel tests
+        assert_eq!(temporal_set_max_input_length(0), TEMPORAL_DEFAULT_MAX_INPUT_LENGTH);
+
+        let long = CString::new(format!("2024-01-15{}", " ".repeat(TEMPORAL_DEFAULT_MAX_INPUT_LENGTH))).unwrap();
+        let mut result = temporal_plain_date_from_string(long.as_ptr());
+        assert_eq!(result.error_type, TemporalErrorType::InputTooLong as i32);
+        let message = unsafe { std::ffi::CStr::from_ptr(result.error_message) }.to_string_lossy().into_owned();
+        assert!(message.contains("is too long (4106 bytes, the limit is 4096)"), "{}", message);
+        unsafe { temporal_free_result(&mut result) };
 
-        let mut options = RoundingOptions::default();
-        options.smallest_unit = Some(unit);
-        options.rounding_mode = Some(mode);
-        options.increment = Some(increment_opt);
+        let kind = CString::new("PlainDate").unwrap();
+        assert_eq!(temporal_validate(kind.as_ptr(), long.as_ptr()), TemporalErrorType::InputTooLong as i32);
+        let mut components = DurationComponents::default();
+        temporal_duration_get_components(long.as_ptr(), &mut components);
+        assert_eq!(components.is_valid, 0);
+
+        // Bulk arguments have their own, larger limit; batch items are checked one by one
+        let mut items = vec!["2024-01-15".to_string(); 500];
+        items[1] = long.to_str().unwrap().to_string();
+        let joined = CString::new(items.join("\n")).unwrap();
+        let delimiter = CString::new("\n").unwrap();
+        let mut codes = [-1; 3];
+        let values = extract_result(temporal_batch_parse(
+            kind.as_ptr(),
+            joined.as_ptr(),
+            delimiter.as_ptr(),
+            codes.as_mut_ptr(),
+            codes.len(),
+        ));
+        assert_eq!(codes, [0, TemporalErrorType::InputTooLong as i32, 0]);
+        assert!(values.starts_with("2024-01-15\n\n2024-01-15\n"));
+        let list = CString::new(vec!["2024-01-15"; 500].join("\n")).unwrap();
+        assert_eq!(extract_result(temporal_plain_date_min(list.as_ptr())), "2024-01-15");
+
+        assert_eq!(temporal_set_max_bulk_input_length(0), TEMPORAL_DEFAULT_MAX_BULK_INPUT_LENGTH);
+        let huge_json = CString::new(format!("[{}]", " ".repeat(TEMPORAL_DEFAULT_MAX_BULK_INPUT_LENGTH))).unwrap();
+        let paths = CString::new("").unwrap();
+        let mut result = temporal_normalize_json_timestamps(huge_json.as_ptr(), paths.as_ptr());
+        assert_eq!(result.error_type, TemporalErrorType::InputTooLong as i32);
+        unsafe { temporal_free_result(&mut result) };
+        let set_id = CString::new("test-huge").unwrap();
+        assert_eq!(crate::holidays::temporal_holidays_register(set_id.as_ptr(), huge_json.as_ptr()), -1);
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::InputTooLong as i32);
+    }
 
-        match zdt.round(options) {
-            Ok(result) => match result.to_ixdtf_string(DisplayOffset::Auto, DisplayTimeZone::Auto, DisplayCalendar::Auto, ToStringRoundingOptions::default()) {
-                Ok(s) => env
-                    .new_string(s)
-                    .map(|js| js.into_raw())
-                    .unwrap_or(ptr::null_mut()),
-                Err(e) => {
-                    throw_range_error(&mut env, &format!("Failed to format zoned date time: {}", e));
-                    ptr::null_mut()
-                }
-            },
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Failed to round: {}", e));
-                ptr::null_mut()
-            }
+    #[test]
+    fn test_duration_get_components_f64() {
+        let get = |s: &str| {
+            let s = CString::new(s).unwrap();
+            let mut out = DurationComponentsF64::default();
+            temporal_duration_get_components_f64(s.as_ptr(), &mut out);
+            out
+        };
+        let out = get("-P1Y2M3W4DT5H6M7.008009010S");
+        assert_eq!((out.is_valid, out.is_exact, out.inexact_fields, out.sign), (1, 1, 0, -1));
+        assert_eq!(
+            [out.years, out.months, out.weeks, out.days, out.hours, out.minutes, out.seconds],
+            [-1.0, -2.0, -3.0, -4.0, -5.0, -6.0, -7.0]
+        );
+        assert_eq!([out.milliseconds, out.microseconds, out.nanoseconds], [-8.0, -9.0, -10.0]);
+
+        // The largest valid duration still converts exactly
+        let out = get("PT9007199254740991.999999999S");
+        assert_eq!((out.is_valid, out.is_exact, out.inexact_fields), (1, 1, 0));
+        assert_eq!([out.seconds, out.nanoseconds], [9_007_199_254_740_991.0, 999.0]);
+        let out = get("PT9007199254740992S");
+        assert_eq!(out.is_valid, 0);
+
+        let out = get("P1Y2X");
+        assert_eq!(out.is_valid, 0);
+        temporal_duration_get_components_f64(ptr::null(), ptr::null_mut());
+    }
+
+    #[test]
+    fn test_now_epoch_fast_path() {
+        let before = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap();
+        let ms = temporal_now_epoch_milliseconds();
+        let ns: i128 = extract_result(temporal_now_epoch_nanoseconds_string()).parse().unwrap();
+        let after = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap();
+
+        assert!(before.as_millis() as i64 <= ms && ms <= after.as_millis() as i64);
+        assert!(before.as_nanos() as i128 <= ns && ns <= after.as_nanos() as i128);
+        assert!(ms as i128 * 1_000_000 <= ns);
+    }
+
+    #[test]
+    fn test_instant_to_string_numeric_offset() {
+        let instant = CString::new("2024-01-15T10:30:45.1236+05:30").unwrap();
+        let format = |precision: Option<&str>, style: Option<&str>| {
+            let precision = precision.map(|p| CString::new(p).unwrap());
+            let style = style.map(|s| CString::new(s).unwrap());
+            temporal_instant_to_string(
+                instant.as_ptr(),
+                precision.as_ref().map_or(ptr::null(), |p| p.as_ptr()),
+                style.as_ref().map_or(ptr::null(), |s| s.as_ptr()),
+            )
+        };
+        assert_eq!(extract_result(format(None, None)), "2024-01-15T05:00:45.1236Z");
+        assert_eq!(extract_result(format(None, Some("Z"))), "2024-01-15T05:00:45.1236Z");
+        assert_eq!(extract_result(format(None, Some("numeric"))), "2024-01-15T05:00:45.1236+00:00");
+        assert_eq!(extract_result(format(Some("0"), Some("numeric"))), "2024-01-15T05:00:45+00:00");
+
+        for invalid in ["z", "+00:00", "UTC", ""] {
+            let mut result = format(None, Some(invalid));
+            assert_eq!(result.error_type, TemporalErrorType::RangeError as i32, "{}", invalid);
+            unsafe { temporal_free_result(&mut result) };
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeToInstant()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeToInstant(
-        mut env: JNIEnv,
-        _class: JClass,
-        s: JString,
-    ) -> jstring {
-        let s_str = parse_jstring(&mut env, &s, "zoned date time string");
-        let s_val = match s_str {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        match ZonedDateTime::from_utf8(s_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
-            Ok(zdt) => {
-                let provider = &*COMPILED_TZ_PROVIDER;
-                match zdt.to_instant().to_ixdtf_string_with_provider(None, Default::default(), &provider) {
-                    Ok(s) => env.new_string(s)
-                        .map(|js| js.into_raw())
-                        .unwrap_or(ptr::null_mut()),
-                    Err(e) => {
-                        throw_range_error(&mut env, &format!("Failed to format instant: {}", e));
-                        ptr::null_mut()
-                    }
-                }
-            },
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid zoned date time: {}", e));
-                ptr::null_mut()
-            }
+    #[test]
+    fn test_option_structs() {
+        let tz = CString::new("America/New_York").unwrap();
+        let from = |hour: u8, options: *const ZdtFromOptions| {
+            temporal_zoned_date_time_from_components_with_options(2024, 11, 3, hour, 30, 0, 0, 0, 0, ptr::null(), tz.as_ptr(), options)
+        };
+        let later = ZdtFromOptions { disambiguation: 2, ..Default::default() };
+        assert_eq!(extract_result(from(1, ptr::null())), "2024-11-03T01:30:00-04:00[America/New_York]");
+        assert_eq!(extract_result(from(1, &later)), "2024-11-03T01:30:00-05:00[America/New_York]");
+
+        // An explicit offset picks the matching instant and is rejected if it never applies
+        let est = ZdtFromOptions { has_offset: 1, offset_nanoseconds: -5 * 3_600_000_000_000, ..Default::default() };
+        assert_eq!(extract_result(from(1, &est)), "2024-11-03T01:30:00-05:00[America/New_York]");
+        let cet = ZdtFromOptions { has_offset: 1, offset_nanoseconds: 3_600_000_000_000, ..Default::default() };
+        let mut result = from(1, &cet);
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut result) };
+        let cet_use = ZdtFromOptions { offset: 0, ..cet };
+        assert_eq!(extract_result(from(1, &cet_use)), "2024-11-02T20:30:00-04:00[America/New_York]");
+        let cet_ignore = ZdtFromOptions { offset: 2, ..cet };
+        assert_eq!(extract_result(from(1, &cet_ignore)), "2024-11-03T01:30:00-04:00[America/New_York]");
+
+        // A struct newer than the library is rejected, and so is a size that is not a
+        // released version's, since it would cut a field short
+        for (size, error_type) in [(0, TemporalErrorType::TypeError), (8, TemporalErrorType::TypeError), (12, TemporalErrorType::TypeError), (25, TemporalErrorType::RangeError)] {
+            let mut result = from(1, &ZdtFromOptions { size, ..cet });
+            assert_eq!(result.error_type, error_type as i32, "{}", size);
+            unsafe { temporal_free_result(&mut result) };
         }
+
+        let one = CString::new("2024-01-01T00:00:00Z").unwrap();
+        let two = CString::new("2024-01-02T01:30:00Z").unwrap();
+        let hours = DiffOptions { largest_unit: 6, ..Default::default() };
+        assert_eq!(extract_result(temporal_instant_until_with_options(one.as_ptr(), two.as_ptr(), ptr::null())), "PT91800S");
+        assert_eq!(extract_result(temporal_instant_until_with_options(one.as_ptr(), two.as_ptr(), &hours)), "PT25H30M");
+        assert_eq!(extract_result(temporal_instant_since_with_options(one.as_ptr(), two.as_ptr(), &hours)), "-PT25H30M");
+
+        let time = CString::new("10:37:00").unwrap();
+        let quarter_hours = RoundOptions { smallest_unit: 5, rounding_increment: 15, ..Default::default() };
+        assert_eq!(extract_result(temporal_plain_time_round_with_options(time.as_ptr(), &quarter_hours)), "10:30:00");
+        let mut result = temporal_plain_time_round_with_options(time.as_ptr(), ptr::null());
+        assert_eq!(result.error_type, TemporalErrorType::TypeError as i32);
+        unsafe { temporal_free_result(&mut result) };
     }
 
-    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeToPlainDate()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeToPlainDate(
-        mut env: JNIEnv,
-        _class: JClass,
-        s: JString,
-    ) -> jstring {
-        let s_str = parse_jstring(&mut env, &s, "zoned date time string");
-        let s_val = match s_str {
-            Some(s) => s,
-            None => return ptr::null_mut(),
+    #[test]
+    fn test_utc_and_gmt_identifiers() {
+        let call = |f: extern "C" fn(*const c_char) -> TemporalResult, id: &str| {
+            let id = CString::new(id).unwrap();
+            extract_result(f(id.as_ptr()))
+        };
+        // "UTC" is a named zone and keeps its spelling; offsets are never renamed to it
+        for (id, expected) in [("UTC", "UTC"), ("utc", "UTC"), ("Etc/UTC", "Etc/UTC"), ("GMT", "GMT"), ("+00:00", "+00:00"), ("-00:00", "+00:00")] {
+            assert_eq!(call(temporal_time_zone_get_id, id), expected, "{}", id);
+        }
+        let canonical = |id: &str| {
+            let id = CString::new(id).unwrap();
+            extract_result(temporal_time_zone_canonicalize(id.as_ptr(), 0))
         };
-        match ZonedDateTime::from_utf8(s_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
-            Ok(zdt) => env.new_string(zdt.to_plain_date().to_ixdtf_string(DisplayCalendar::Auto))
-                .map(|js| js.into_raw())
-                .unwrap_or(ptr::null_mut()),
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid zoned date time: {}", e));
-                ptr::null_mut()
-            }
+        for id in ["UTC", "Etc/UTC", "GMT", "Etc/GMT", "Etc/Zulu"] {
+            assert_eq!(canonical(id), "UTC", "{}", id);
+        }
+        assert_eq!(canonical("+00:00"), "+00:00");
+
+        let equals = |a: &str, b: &str| {
+            let (a, b) = (CString::new(a).unwrap(), CString::new(b).unwrap());
+            temporal_time_zone_equals(a.as_ptr(), b.as_ptr())
+        };
+        assert_eq!(equals("UTC", "Etc/UTC"), 1);
+        assert_eq!(equals("UTC", "GMT"), 1);
+        assert_eq!(equals("etc/utc", "Etc/GMT"), 1);
+        assert_eq!(equals("+00:00", "-00:00"), 1);
+        assert_eq!(equals("UTC", "+00:00"), 0);
+        assert_eq!(equals("GMT", "Europe/London"), 0);
+        assert_eq!(equals("Asia/Calcutta", "Asia/Kolkata"), 1);
+        assert_eq!(equals("+05:30", "Asia/Kolkata"), 0);
+        assert_eq!(equals("UTC", "Mars/Olympus"), -1);
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::RangeError as i32);
+
+        // Both spellings round-trip through a ZonedDateTime unchanged
+        for tz in ["UTC", "+00:00"] {
+            let zdt = format!("2024-01-01T00:00:00+00:00[{}]", tz);
+            assert_eq!(call(temporal_zoned_date_time_from_string, &zdt), zdt);
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeToPlainTime()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeToPlainTime(
-        mut env: JNIEnv,
-        _class: JClass,
-        s: JString,
-    ) -> jstring {
-        let s_str = parse_jstring(&mut env, &s, "zoned date time string");
-        let s_val = match s_str {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        match ZonedDateTime::from_utf8(s_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
-            Ok(zdt) => match zdt.to_plain_time().to_ixdtf_string(ToStringRoundingOptions::default()) {
-                Ok(s) => env.new_string(s)
-                    .map(|js| js.into_raw())
-                    .unwrap_or(ptr::null_mut()),
-                Err(e) => {
-                    throw_range_error(&mut env, &format!("Failed to format plain time: {}", e));
-                    ptr::null_mut()
-                }
-            },
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid zoned date time: {}", e));
-                ptr::null_mut()
-            }
+    #[test]
+    fn test_convert_zone() {
+        let convert = |ns: &str, tz: &str, calendar: Option<&str>| {
+            let (ns, tz) = (CString::new(ns).unwrap(), CString::new(tz).unwrap());
+            let calendar = calendar.map(|c| CString::new(c).unwrap());
+            let mut out = ZonedDateTimeComponents::default();
+            temporal_convert_zone(ns.as_ptr(), tz.as_ptr(), calendar.as_ref().map_or(ptr::null(), |c| c.as_ptr()), &mut out);
+            out
+        };
+        // 2024-03-10T07:30:00.000000001Z, just after New York springs forward
+        let ns = "1710055800000000001";
+        let ny = convert(ns, "America/New_York", None);
+        assert_eq!(ny.is_valid, 1);
+        assert_eq!((ny.year, ny.month, ny.day, ny.hour, ny.minute), (2024, 3, 10, 3, 30));
+        assert_eq!((ny.millisecond, ny.microsecond, ny.nanosecond), (0, 0, 1));
+        assert_eq!(ny.offset_nanoseconds, -4 * 3_600_000_000_000);
+
+        // Same components as converting a parsed ZonedDateTime
+        let zdt = CString::new("2024-03-10T03:30:00.000000001-04:00[America/New_York]").unwrap();
+        let mut parsed = ZonedDateTimeComponents::default();
+        temporal_zoned_date_time_get_components(zdt.as_ptr(), &mut parsed);
+        assert_eq!(
+            (parsed.day_of_year, parsed.week_of_year, parsed.days_in_month, parsed.hour, parsed.offset_nanoseconds),
+            (ny.day_of_year, ny.week_of_year, ny.days_in_month, ny.hour, ny.offset_nanoseconds)
+        );
+
+        let tokyo = convert(ns, "Asia/Tokyo", None);
+        assert_eq!((tokyo.day, tokyo.hour, tokyo.offset_nanoseconds), (10, 16, 9 * 3_600_000_000_000));
+        let hebrew = convert(ns, "UTC", Some("hebrew"));
+        // 30 Adar I, the sixth month of the leap year
+        assert_eq!((hebrew.year, hebrew.month, hebrew.day), (5784, 6, 30));
+
+        for (ns, tz, calendar) in [("abc", "UTC", None), (ns, "Mars/Olympus", None), (ns, "UTC", Some("mayan")), ("9000000000000000000000", "UTC", None)] {
+            assert_eq!(convert(ns, tz, calendar).is_valid, 0, "{} {}", ns, tz);
         }
     }
 
-    /// JNI function for `com.temporal.TemporalNative.zonedDateTimeToPlainDateTime()`
-    #[no_mangle]
-    pub extern "system" fn Java_com_temporal_TemporalNative_zonedDateTimeToPlainDateTime(
-        mut env: JNIEnv,
-        _class: JClass,
-        s: JString,
-    ) -> jstring {
-        let s_str = parse_jstring(&mut env, &s, "zoned date time string");
-        let s_val = match s_str {
-            Some(s) => s,
-            None => return ptr::null_mut(),
-        };
-        match ZonedDateTime::from_utf8(s_val.as_bytes(), Disambiguation::Compatible, OffsetDisambiguation::Reject) {
-            Ok(zdt) => match zdt.to_plain_date_time().to_ixdtf_string(ToStringRoundingOptions::default(), DisplayCalendar::Auto) {
-                Ok(s) => env.new_string(s)
-                    .map(|js| js.into_raw())
-                    .unwrap_or(ptr::null_mut()),
-                Err(e) => {
-                    throw_range_error(&mut env, &format!("Failed to format plain date time: {}", e));
-                    ptr::null_mut()
-                }
-            },
-            Err(e) => {
-                throw_range_error(&mut env, &format!("Invalid zoned date time: {}", e));
-                ptr::null_mut()
-            }
+    #[test]
+    fn test_epoch_nanoseconds_parts() {
+        for ns in [0i128, 1, -1, 1_705_314_645_123_456_789, -8_640_000_000_000_000_000_000, 8_640_000_000_000_000_000_000] {
+            let (high, low) = split_i128(ns);
+            assert_eq!(join_i128(high, low), ns);
+            let instant = extract_result(temporal_instant_from_epoch_nanoseconds_parts(high, low));
+            let ns_str = CString::new(ns.to_string()).unwrap();
+            assert_eq!(instant, extract_result(temporal_instant_from_epoch_nanoseconds(ns_str.as_ptr())));
+
+            let instant = CString::new(instant).unwrap();
+            let mut parts = EpochNanosecondsParts::default();
+            temporal_instant_epoch_nanoseconds_parts(instant.as_ptr(), &mut parts);
+            assert_eq!((parts.high, parts.low, parts.is_valid), (high, low, 1));
         }
+
+        let tz = CString::new("Europe/Paris").unwrap();
+        let calendar = CString::new("japanese").unwrap();
+        let (high, low) = split_i128(-1);
+        let zdt = extract_result(temporal_zoned_date_time_from_epoch_ns_parts(high, low, tz.as_ptr(), calendar.as_ptr()));
+        assert_eq!(zdt, "1970-01-01T00:59:59.999999999+01:00[Europe/Paris][u-ca=japanese]");
+        let zdt = CString::new(zdt).unwrap();
+        let mut parts = EpochNanosecondsParts::default();
+        temporal_zoned_date_time_epoch_nanoseconds_parts(zdt.as_ptr(), &mut parts);
+        assert_eq!((parts.high, parts.low, parts.is_valid), (-1, u64::MAX, 1));
+
+        // Out of the Temporal range, and unparsable input
+        let (high, low) = split_i128(8_640_000_000_000_000_000_001);
+        let mut result = temporal_instant_from_epoch_nanoseconds_parts(high, low);
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut result) };
+        let invalid = CString::new("not an instant").unwrap();
+        let mut parts = EpochNanosecondsParts { is_valid: 1, ..Default::default() };
+        temporal_instant_epoch_nanoseconds_parts(invalid.as_ptr(), &mut parts);
+        assert_eq!(parts.is_valid, 0);
+        assert_eq!(temporal_struct_sizes().epoch_nanoseconds_parts as usize, std::mem::size_of::<EpochNanosecondsParts>());
     }
-}
 
-mod tests {
-    use super::*;
-    use std::ffi::CString;
+    #[test]
+    fn test_duration_millis_conversion() {
+        let to_millis = |s: &str| {
+            let s = CString::new(s).unwrap();
+            temporal_duration_to_millis_f64(s.as_ptr())
+        };
+        assert_eq!(to_millis("PT1.5S"), 1500.0);
+        assert_eq!(to_millis("PT0.000250S"), 0.25);
+        assert_eq!(to_millis("-PT2M"), -120_000.0);
+        assert_eq!(to_millis("P1DT1H"), 90_000_000.0);
+        assert_eq!(to_millis("PT0S"), 0.0);
+        for invalid in ["P1M", "P1W", "P1Y", "1500ms"] {
+            assert!(to_millis(invalid).is_nan(), "{}", invalid);
+            assert_eq!(temporal_last_error_type(), TemporalErrorType::RangeError as i32);
+        }
 
-    // Helper to extract value from TemporalResult or panic with error message
-    fn extract_result(mut result: TemporalResult) -> String {
-        if result.error_type != TemporalErrorType::None as i32 {
-            let error_msg = if !result.error_message.is_null() {
-                unsafe { std::ffi::CStr::from_ptr(result.error_message) }
-                    .to_string_lossy()
-                    .to_string()
-            } else {
-                "Unknown error".to_string()
-            };
+        assert_eq!(extract_result(temporal_duration_from_millis(1500.0)), "PT1.5S");
+        assert_eq!(extract_result(temporal_duration_from_millis(-16.7)), "-PT0.0167S");
+        assert_eq!(extract_result(temporal_duration_from_millis(0.0)), "PT0S");
+        assert_eq!(extract_result(temporal_duration_from_millis(90_061_000.0)), "PT90061S");
+        for ms in [1500.0, -16.7, 0.25, 123_456_789.125] {
+            let d = CString::new(extract_result(temporal_duration_from_millis(ms))).unwrap();
+            assert_eq!(temporal_duration_to_millis_f64(d.as_ptr()), ms);
+        }
+        for invalid in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 1e30] {
+            let mut result = temporal_duration_from_millis(invalid);
+            assert_eq!(result.error_type, TemporalErrorType::RangeError as i32, "{}", invalid);
             unsafe { temporal_free_result(&mut result) };
-            panic!("TemporalResult error: {}", error_msg);
         }
+    }
 
-        let value = if !result.value.is_null() {
-            unsafe { std::ffi::CStr::from_ptr(result.value) }
-                .to_string_lossy()
-                .to_string()
-        } else {
-            String::new()
-        };
-        
+    #[test]
+    fn test_month_day_occurrences() {
+        let call = |next: bool, md: &str, date: &str, calendar: Option<&str>, policy: Option<&str>| {
+            let (md, date) = (CString::new(md).unwrap(), CString::new(date).unwrap());
+            let calendar = calendar.map(|c| CString::new(c).unwrap());
+            let policy = policy.map(|p| CString::new(p).unwrap());
+            let f = if next { temporal_next_occurrence_of_month_day } else { temporal_previous_occurrence_of_month_day };
+            f(
+                md.as_ptr(),
+                date.as_ptr(),
+                calendar.as_ref().map_or(ptr::null(), |c| c.as_ptr()),
+                policy.as_ref().map_or(ptr::null(), |p| p.as_ptr()),
+            )
+        };
+        let next = |md, date, policy| extract_result(call(true, md, date, None, policy));
+        let previous = |md, date, policy| extract_result(call(false, md, date, None, policy));
+
+        assert_eq!(next("12-25", "2024-12-24", None), "2024-12-25");
+        assert_eq!(next("12-25", "2024-12-25", None), "2025-12-25");
+        assert_eq!(previous("12-25", "2024-12-25", None), "2023-12-25");
+        assert_eq!(previous("--12-25", "2024-12-26", None), "2024-12-25");
+
+        assert_eq!(next("02-29", "2025-01-10", None), "2025-02-28");
+        assert_eq!(next("02-29", "2025-01-10", Some("previous-day")), "2025-02-28");
+        assert_eq!(next("02-29", "2025-01-10", Some("next-day")), "2025-03-01");
+        assert_eq!(next("02-29", "2025-01-10", Some("strict")), "2028-02-29");
+        assert_eq!(next("02-29", "2024-02-29", Some("strict")), "2028-02-29");
+        // 2100 is not a leap year, so the strict gap is eight years
+        assert_eq!(next("02-29", "2096-03-01", Some("strict")), "2104-02-29");
+        assert_eq!(previous("02-29", "2027-06-01", Some("strict")), "2024-02-29");
+        assert_eq!(previous("02-29", "2027-06-01", Some("next-day")), "2027-03-01");
+
+        // 30 Adar I only exists in Hebrew leap years (5784, 5787, ...)
+        let adar_i = extract_result(call(true, "2024-03-10[u-ca=hebrew]", "2024-03-10", None, Some("strict")));
+        let date = PlainDate::from_str(&adar_i).unwrap();
+        assert_eq!((date.year(), date.month_code().as_str(), date.day()), (5787, "M05L", 30));
+        assert!(adar_i.ends_with("[u-ca=hebrew]"), "{}", adar_i);
+        // An ISO-syntax month-day read in another calendar: 1 Tishrei is Rosh Hashanah
+        assert_eq!(extract_result(call(true, "01-01", "2024-09-01", Some("hebrew"), None)), "2024-10-03[u-ca=hebrew]");
+
+        for (md, date, calendar, policy) in [
+            ("02-29", "2025-01-10", None, Some("nearest")),
+            ("02-30", "2025-01-10", None, None),
+            ("02-28", "2025-13-10", None, None),
+            ("02-28", "2025-01-10", Some("klingon"), None),
+        ] {
+            let mut result = call(true, md, date, calendar, policy);
+            assert_eq!(result.error_type, TemporalErrorType::RangeError as i32, "{} {}", md, date);
+            unsafe { temporal_free_result(&mut result) };
+        }
+    }
+
+    #[test]
+    fn test_bucket_epoch_ms() {
+        use std::ffi::CStr;
+        let ms = |s: &str| Instant::from_str(s).unwrap().epoch_milliseconds();
+        let kolkata = CString::new("Asia/Kolkata").unwrap();
+        let new_york = CString::new("America/New_York").unwrap();
+
+        // Buckets follow the local clock, not UTC: Kolkata is +05:30
+        let value = ms("2024-01-15T10:52:13.500+05:30");
+        assert_eq!(temporal_bucket_epoch_ms(value, kolkata.as_ptr(), 15), ms("2024-01-15T10:45:00+05:30"));
+        assert_eq!(temporal_bucket_epoch_ms(value, kolkata.as_ptr(), 60), ms("2024-01-15T10:00:00+05:30"));
+        assert_eq!(temporal_bucket_epoch_ms(value, kolkata.as_ptr(), 1440), ms("2024-01-15T00:00:00+05:30"));
+        // Buckets that do not divide a day restart at local midnight
+        assert_eq!(temporal_bucket_epoch_ms(ms("2024-01-15T00:05:00+05:30"), kolkata.as_ptr(), 7), ms("2024-01-15T00:00:00+05:30"));
+        let utc = CString::new("UTC").unwrap();
+        assert_eq!(temporal_bucket_epoch_ms(ms("1969-12-31T23:59:59.999Z"), utc.as_ptr(), 1), ms("1969-12-31T23:59:00Z"));
+
+        // The repeated 01:xx hour on 2024-11-03 gives two distinct buckets
+        let first = temporal_bucket_epoch_ms(ms("2024-11-03T01:40:00-04:00"), new_york.as_ptr(), 30);
+        let second = temporal_bucket_epoch_ms(ms("2024-11-03T01:40:00-05:00"), new_york.as_ptr(), 30);
+        assert_eq!(first, ms("2024-11-03T01:30:00-04:00"));
+        assert_eq!(second, ms("2024-11-03T01:30:00-05:00"));
+
+        let mut values = [value, ms("2024-01-15T23:59:59+05:30"), ms("2024-01-16T00:00:00+05:30")];
+        let expected = [ms("2024-01-15T10:45:00+05:30"), ms("2024-01-15T23:45:00+05:30"), ms("2024-01-16T00:00:00+05:30")];
+        let mut out = [0i64; 3];
+        assert_eq!(temporal_bucket_epoch_ms_batch(values.as_ptr(), 3, kolkata.as_ptr(), 15, out.as_mut_ptr()), 0);
+        assert_eq!(out, expected);
+        assert_eq!(temporal_bucket_epoch_ms_batch(values.as_ptr(), 3, kolkata.as_ptr(), 15, values.as_mut_ptr()), 0);
+        assert_eq!(values, expected);
+
+        assert_eq!(temporal_bucket_epoch_ms(value, kolkata.as_ptr(), 0), TEMPORAL_BUCKET_ERROR);
+        assert_eq!(temporal_bucket_epoch_ms(value, kolkata.as_ptr(), 1441), TEMPORAL_BUCKET_ERROR);
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::RangeError as i32);
+        let unknown = CString::new("Mars/Olympus").unwrap();
+        assert_eq!(temporal_bucket_epoch_ms(value, unknown.as_ptr(), 15), TEMPORAL_BUCKET_ERROR);
+        assert_eq!(temporal_bucket_epoch_ms_batch(ptr::null(), 1, kolkata.as_ptr(), 15, out.as_mut_ptr()), -1);
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::TypeError as i32);
+        let mut bad = [value, i64::MAX];
+        assert_eq!(temporal_bucket_epoch_ms_batch(bad.as_ptr(), 2, kolkata.as_ptr(), 15, bad.as_mut_ptr()), -1);
+        assert_eq!(bad[0], expected[0]);
+        let message = temporal_last_error_message();
+        assert!(unsafe { CStr::from_ptr(message) }.to_str().unwrap().contains("item 1"));
+        unsafe { temporal_free_string(message) };
+    }
+
+    #[test]
+    fn test_warmup() {
+        use std::ffi::CStr;
+        assert_eq!(temporal_warmup(ptr::null()), 0);
+        let ids = CString::new("Europe/Paris\n\nAmerica/New_York\n").unwrap();
+        assert_eq!(temporal_warmup(ids.as_ptr()), 2);
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::None as i32);
+
+        let ids = CString::new("UTC\nMars/Olympus_Mons").unwrap();
+        assert_eq!(temporal_warmup(ids.as_ptr()), -1);
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::RangeError as i32);
+        let message = temporal_last_error_message();
+        assert!(unsafe { CStr::from_ptr(message) }.to_str().unwrap().contains("index 1"));
+        unsafe { temporal_free_string(message) };
+
+        // A list longer than the per-string limit is fine; only single ids are held to it
+        let ids = CString::new(vec!["Europe/Paris"; 400].join("\n")).unwrap();
+        assert!(ids.as_bytes().len() > TEMPORAL_DEFAULT_MAX_INPUT_LENGTH);
+        assert_eq!(temporal_warmup(ids.as_ptr()), 400);
+        let ids = CString::new(format!("UTC\n{}", "a".repeat(TEMPORAL_DEFAULT_MAX_INPUT_LENGTH + 1))).unwrap();
+        assert_eq!(temporal_warmup(ids.as_ptr()), -1);
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::InputTooLong as i32);
+        let message = temporal_last_error_message();
+        assert!(unsafe { CStr::from_ptr(message) }.to_str().unwrap().starts_with("time zone id at index 1 is too long"));
+        unsafe { temporal_free_string(message) };
+    }
+
+    #[test]
+    fn test_calendar_date_until() {
+        let until = |calendar: Option<&str>, one: &str, two: &str, unit: Option<&str>| {
+            let calendar = calendar.map(|c| CString::new(c).unwrap());
+            let one = CString::new(one).unwrap();
+            let two = CString::new(two).unwrap();
+            let unit = unit.map(|u| CString::new(u).unwrap());
+            temporal_calendar_date_until(
+                calendar.as_ref().map_or(ptr::null(), |c| c.as_ptr()),
+                one.as_ptr(),
+                two.as_ptr(),
+                unit.as_ref().map_or(ptr::null(), |u| u.as_ptr()),
+            )
+        };
+
+        assert_eq!(extract_result(until(None, "2024-01-31", "2024-03-01", None)), "P30D");
+        assert_eq!(extract_result(until(None, "2024-01-31", "2024-03-01", Some("month"))), "P1M1D");
+        // 30 Adar I to 29 Adar II 5784 is not a whole month, as Adar II has no 30th;
+        // 30 Shevat to 30 Adar I is
+        assert_eq!(extract_result(until(Some("hebrew"), "2024-03-10", "2024-04-08", Some("month"))), "P29D");
+        assert_eq!(extract_result(until(Some("hebrew"), "2024-02-09", "2024-03-10", Some("month"))), "P1M");
+        assert_eq!(extract_result(until(Some("hebrew"), "2023-09-16", "2024-10-03", Some("year"))), "P1Y");
+
+        let mut result = until(None, "2024-01-01", "2024-02-01", Some("hour"));
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut result) };
+        let mut result = until(Some("martian"), "2024-01-01", "2024-02-01", None);
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
         unsafe { temporal_free_result(&mut result) };
-        value
     }
 
     #[test]
-    fn test_instant_now() {
-        let result = get_instant_now_string().unwrap();
-        // Should be in ISO 8601 format like "2024-01-15T10:30:45.123456789Z"
-        assert!(result.ends_with('Z'), "Expected UTC timestamp: {}", result);
-        assert!(result.contains('T'), "Expected ISO format: {}", result);
-        println!("Current instant: {}", result);
+    fn test_duration_divmod() {
+        let divmod = |duration: &str, unit: &str, relative_to: Option<&str>| {
+            let duration = CString::new(duration).unwrap();
+            let unit = CString::new(unit).unwrap();
+            let relative_to = relative_to.map(|s| CString::new(s).unwrap());
+            let mut quotient = -1;
+            let result = temporal_duration_divmod(
+                duration.as_ptr(),
+                unit.as_ptr(),
+                relative_to.as_ref().map_or(ptr::null(), |s| s.as_ptr()),
+                &mut quotient,
+            );
+            if result.error_type != TemporalErrorType::None as i32 {
+                unsafe { temporal_free_result(&mut { result }) };
+                return None;
+            }
+            Some((quotient, extract_result(result)))
+        };
+
+        assert_eq!(divmod("PT2H30M", "hour", None), Some((2, "PT30M".to_string())));
+        assert_eq!(divmod("P1DT1H", "hour", None), Some((25, "PT0S".to_string())));
+        assert_eq!(divmod("-PT90.5S", "minute", None), Some((-1, "-PT30.5S".to_string())));
+        assert_eq!(divmod("P17D", "week", None), None);
+        assert_eq!(divmod("P2W3D", "day", None), None);
+
+        assert_eq!(divmod("P17D", "week", Some("2024-01-01")), Some((2, "P3D".to_string())));
+        assert_eq!(divmod("P40D", "month", Some("2024-01-01")), Some((1, "P9D".to_string())));
+        assert_eq!(divmod("P1Y3M10D", "year", Some("2024-01-01")), Some((1, "P3M10D".to_string())));
+        assert_eq!(divmod("-P17D", "week", Some("2024-01-01")), Some((-2, "-P3D".to_string())));
+        assert_eq!(divmod("P1D", "auto", None), None);
     }
 
     #[test]
-    fn test_duration_from_string_valid() {
-        let input = CString::new("P1Y2M3DT4H5M6S").unwrap();
-        let result = temporal_duration_from_string(input.as_ptr());
-        let result_string = extract_result(result);
-        
-        // Should parse and normalize the duration
-        assert!(result_string.starts_with('P'), "Should start with P: {}", result_string);
+    fn test_time_zone_abbreviation() {
+        let abbreviation = |tz: &str, instant: &str| {
+            let tz = CString::new(tz).unwrap();
+            let instant = CString::new(instant).unwrap();
+            let mut info = TimeZoneAbbreviationInfo::default();
+            let result = temporal_time_zone_abbreviation(tz.as_ptr(), instant.as_ptr(), &mut info);
+            (extract_result(result), info.offset_nanoseconds / 60_000_000_000, info.is_dst, info.non_authoritative)
+        };
+
+        assert_eq!(abbreviation("America/Los_Angeles", "2024-01-15T12:00:00Z"), ("PST".to_string(), -480, 0, 0));
+        assert_eq!(abbreviation("America/Los_Angeles", "2024-07-15T12:00:00Z"), ("PDT".to_string(), -420, 1, 0));
+        // Far past the transition table, from the POSIX rule
+        assert_eq!(abbreviation("Europe/Berlin", "2300-07-01T00:00:00Z"), ("CEST".to_string(), 120, 1, 0));
+        assert_eq!(abbreviation("Asia/Kolkata", "2024-01-15T12:00:00Z"), ("IST".to_string(), 330, 0, 0));
+        assert_eq!(abbreviation("Asia/Kathmandu", "2024-01-15T12:00:00Z"), ("+0545".to_string(), 345, 0, 1));
+        assert_eq!(abbreviation("-03:30", "2024-01-15T12:00:00Z"), ("-0330".to_string(), -210, 0, 1));
+        assert_eq!(abbreviation("UTC", "2024-01-15T12:00:00Z"), ("UTC".to_string(), 0, 0, 0));
+
+        let tz = CString::new("Europe/Paris").unwrap();
+        let result = temporal_time_zone_abbreviation(tz.as_ptr(), ptr::null(), ptr::null_mut());
+        assert!(extract_result(result).starts_with("CE"));
     }
 
     #[test]
-    fn test_duration_from_string_invalid() {
-        let input = CString::new("invalid").unwrap();
-        let result = temporal_duration_from_string(input.as_ptr());
-        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32, "Invalid duration should return RangeError");
-        assert!(!result.error_message.is_null(), "Should have error message");
+    fn test_calendar_annotation_case_and_critical_flag() {
+        let _defaults = lock_display_defaults();
+        let parse = |f: extern "C" fn(*const c_char) -> TemporalResult, s: &str| {
+            let s = CString::new(s).unwrap();
+            let result = f(s.as_ptr());
+            if result.error_type != TemporalErrorType::None as i32 {
+                unsafe { temporal_free_result(&mut { result }) };
+                return None;
+            }
+            Some(extract_result(result))
+        };
+
+        // The critical flag is accepted and the calendar id is case-insensitive
+        for input in ["2024-03-15[!u-ca=iso8601]", "2024-03-15[u-ca=ISO8601]", "2024-03-15[!u-ca=Iso8601]"] {
+            assert_eq!(parse(temporal_plain_date_from_string, input).as_deref(), Some("2024-03-15"), "{}", input);
+            assert_eq!(parse(temporal_plain_date_time_from_string, input).as_deref(), Some("2024-03-15T00:00:00"), "{}", input);
+            assert_eq!(parse(temporal_plain_year_month_from_string, input).as_deref(), Some("2024-03"), "{}", input);
+            assert_eq!(parse(temporal_plain_month_day_from_string, input).as_deref(), Some("03-15"), "{}", input);
+        }
+        assert_eq!(parse(temporal_plain_date_from_string, "2024-03-15[!u-ca=HEBREW]").as_deref(), Some("2024-03-15[u-ca=hebrew]"));
+        assert_eq!(
+            parse(temporal_zoned_date_time_from_string, "2024-03-15T12:00:00+01:00[!Europe/Paris][!u-ca=Japanese]").as_deref(),
+            Some("2024-03-15T12:00:00+01:00[Europe/Paris][u-ca=japanese]")
+        );
+
+        // Annotation keys are lowercase only, and a critical calendar may not conflict
+        for input in ["2024-03-15[U-CA=iso8601]", "2024-03-15[!U-ca=iso8601]", "2024-03-15[u-ca=iso8601][!u-ca=gregory]", "2024-03-15[!x-foo=bar]"] {
+            assert_eq!(parse(temporal_plain_date_from_string, input), None, "{}", input);
+            assert_eq!(parse(temporal_plain_date_time_from_string, input), None, "{}", input);
+            assert_eq!(parse(temporal_plain_year_month_from_string, input), None, "{}", input);
+            assert_eq!(parse(temporal_plain_month_day_from_string, input), None, "{}", input);
+        }
+        // Without the flag, the first of several calendars wins and unknown keys are ignored
+        assert_eq!(parse(temporal_plain_date_from_string, "2024-03-15[u-ca=iso8601][u-ca=gregory][x-foo=bar]").as_deref(), Some("2024-03-15"));
+
+        let c = |s: &str| CString::new(s).unwrap();
+        let critical = c("critical");
+        let date = c("2024-03-15");
+        assert_eq!(extract_result(temporal_plain_date_to_string(date.as_ptr(), critical.as_ptr())), "2024-03-15[!u-ca=iso8601]");
+        assert_eq!(extract_result(temporal_plain_date_to_string(date.as_ptr(), ptr::null())), "2024-03-15");
+        let dt = c("2024-03-15T10:30:00.123[u-ca=hebrew]");
+        assert_eq!(
+            extract_result(temporal_plain_date_time_to_string(dt.as_ptr(), c("second").as_ptr(), critical.as_ptr())),
+            "2024-03-15T10:30:00[!u-ca=hebrew]"
+        );
+        assert_eq!(extract_result(temporal_plain_year_month_to_string(c("2024-03").as_ptr(), critical.as_ptr())), "2024-03-01[!u-ca=iso8601]");
+        assert_eq!(extract_result(temporal_plain_month_day_to_string(c("03-15").as_ptr(), c("always").as_ptr())), "1972-03-15[u-ca=iso8601]");
+        let zdt = c("2024-03-15T12:00:00+01:00[Europe/Paris]");
+        assert_eq!(
+            extract_result(temporal_zoned_date_time_to_string(zdt.as_ptr(), ptr::null(), critical.as_ptr())),
+            "2024-03-15T12:00:00+01:00[Europe/Paris][!u-ca=iso8601]"
+        );
+        // The emitted form parses back to the same value
+        assert_eq!(parse(temporal_plain_date_from_string, "2024-03-15[!u-ca=iso8601]").as_deref(), Some("2024-03-15"));
+
+        let result = temporal_plain_date_to_string(date.as_ptr(), c("Critical").as_ptr());
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
         unsafe { temporal_free_result(&mut { result }) };
     }
 
     #[test]
-    fn test_duration_from_string_null() {
-        let result = temporal_duration_from_string(ptr::null());
-        assert_eq!(result.error_type, TemporalErrorType::TypeError as i32, "Null input should return TypeError");
+    fn test_plain_date_pack() {
+        let pack = |s: &str| temporal_plain_date_pack(CString::new(s).unwrap().as_ptr());
+        let unpack = |packed: i64, calendar: Option<&str>| {
+            let calendar = calendar.map(|c| CString::new(c).unwrap());
+            temporal_plain_date_unpack(packed, calendar.as_ref().map_or(ptr::null(), |c| c.as_ptr()))
+        };
+
+        let packed = pack("2024-03-15");
+        assert_eq!(packed, 2024 << 16 | 3 << 8 | 15);
+        assert_eq!((packed >> 16, packed >> 8 & 0xff, packed & 0xff), (2024, 3, 15));
+        assert_eq!(extract_result(unpack(packed, None)), "2024-03-15");
+
+        // Negative years keep their order
+        let dates = ["-271821-04-19", "-000001-12-31", "0000-01-01", "2024-02-29", "+275760-09-13"];
+        let packed: Vec<i64> = dates.iter().map(|d| pack(d)).collect();
+        assert!(packed.windows(2).all(|pair| pair[0] < pair[1]), "{:?}", packed);
+        assert!(packed.iter().all(|p| p.unsigned_abs() < 1 << 53));
+        for (date, packed) in dates.iter().zip(&packed) {
+            assert_eq!(extract_result(unpack(*packed, None)), *date);
+        }
+
+        // Calendar fields, read back in the same calendar
+        let hebrew = pack("2024-03-15[u-ca=hebrew]");
+        assert_eq!((hebrew >> 16, hebrew >> 8 & 0xff, hebrew & 0xff), (5784, 7, 5));
+        assert_eq!(extract_result(unpack(hebrew, Some("hebrew"))), "2024-03-15[u-ca=hebrew]");
+
+        assert_eq!(pack("2024-13-01"), TEMPORAL_PACKED_DATE_ERROR);
+        assert_eq!(temporal_last_error_type(), TemporalErrorType::RangeError as i32);
+        let result = unpack(2024 << 16 | 2 << 8 | 30, None);
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
         unsafe { temporal_free_result(&mut { result }) };
     }
 
     #[test]
-    fn test_duration_get_components() {
-        let input = CString::new("P1Y2M3W4DT5H6M7S").unwrap();
-        let mut components = DurationComponents::default();
-        
-        temporal_duration_get_components(input.as_ptr(), &mut components);
-        
-        assert_eq!(components.is_valid, 1, "Should be valid");
-        assert_eq!(components.years, 1);
-        assert_eq!(components.months, 2);
-        assert_eq!(components.weeks, 3);
-        assert_eq!(components.days, 4);
-        assert_eq!(components.hours, 5);
-        assert_eq!(components.minutes, 6);
-        assert_eq!(components.seconds, 7);
-        assert_eq!(components.sign, 1, "Positive duration should have sign 1");
+    fn test_rounding_increment_must_divide_unit() {
+        let error_type = |result: TemporalResult| {
+            let kind = result.error_type;
+            unsafe { temporal_free_result(&mut { result }) };
+            kind
+        };
+        let range_error = TemporalErrorType::RangeError as i32;
+        let zdt = CString::new("2024-03-15T10:37:00+01:00[Europe/Paris]").unwrap();
+        let time = CString::new("10:37:00").unwrap();
+        let instant = CString::new("2024-03-15T10:37:00Z").unwrap();
+        let minute = CString::new("minute").unwrap();
+        let hour = CString::new("hour").unwrap();
+        let day = CString::new("day").unwrap();
+
+        assert_eq!(error_type(temporal_zoned_date_time_round(zdt.as_ptr(), minute.as_ptr(), 7, ptr::null())), range_error);
+        assert_eq!(error_type(temporal_zoned_date_time_round(zdt.as_ptr(), hour.as_ptr(), 5, ptr::null())), range_error);
+        assert_eq!(error_type(temporal_zoned_date_time_round(zdt.as_ptr(), day.as_ptr(), 2, ptr::null())), range_error);
+        assert_eq!(
+            extract_result(temporal_zoned_date_time_round(zdt.as_ptr(), minute.as_ptr(), 15, ptr::null())),
+            "2024-03-15T10:30:00+01:00[Europe/Paris]"
+        );
+
+        // The next larger unit bounds the increment, exclusively, except for Instant,
+        // where a whole day of the unit is allowed.
+        assert_eq!(error_type(temporal_plain_time_round(time.as_ptr(), minute.as_ptr(), 60, ptr::null())), range_error);
+        assert_eq!(extract_result(temporal_instant_round(instant.as_ptr(), minute.as_ptr(), 60, ptr::null())), "2024-03-15T11:00:00Z");
+        assert_eq!(error_type(temporal_instant_round(instant.as_ptr(), minute.as_ptr(), 7, ptr::null())), range_error);
+
+        // 0 is the default of 1; negative and out-of-range values are errors, not 1.
+        assert_eq!(extract_result(temporal_plain_time_round(time.as_ptr(), minute.as_ptr(), 0, ptr::null())), "10:37:00");
+        assert_eq!(error_type(temporal_plain_time_round(time.as_ptr(), minute.as_ptr(), -5, ptr::null())), range_error);
+        assert_eq!(error_type(temporal_plain_time_round(time.as_ptr(), minute.as_ptr(), 4_294_967_297, ptr::null())), range_error);
+
+        // until/since and the coded variants check the same way.
+        let later = CString::new("12:00:00").unwrap();
+        assert_eq!(
+            error_type(temporal_plain_time_until(time.as_ptr(), later.as_ptr(), ptr::null(), minute.as_ptr(), 7, ptr::null())),
+            range_error
+        );
+        assert_eq!(extract_result(temporal_plain_time_until(time.as_ptr(), later.as_ptr(), ptr::null(), minute.as_ptr(), 20, ptr::null())), "PT1H20M");
+        assert_eq!(error_type(temporal_plain_time_round_coded(time.as_ptr(), 5, 7, TEMPORAL_OPTION_UNSET)), range_error);
+        assert_eq!(error_type(temporal_instant_round_coded(instant.as_ptr(), 6, 5, TEMPORAL_OPTION_UNSET)), range_error);
+        assert_eq!(extract_result(temporal_instant_round_coded(instant.as_ptr(), 6, 24, TEMPORAL_OPTION_UNSET)), "2024-03-15T00:00:00Z");
+
+        let message = validate_rounding_increment(7, Some(Unit::Minute), IncrementContext::Round).unwrap_err();
+        assert_eq!(message, "roundingIncrement 7 does not divide 60 evenly for minute");
+        let message = validate_rounding_increment(7, Some(Unit::Millisecond), IncrementContext::Round).unwrap_err();
+        assert_eq!(message, "roundingIncrement 7 does not divide 1000 evenly for millisecond");
+        let message = validate_rounding_increment(1000, Some(Unit::Millisecond), IncrementContext::Difference).unwrap_err();
+        assert_eq!(message, "roundingIncrement 1000 is too large for millisecond; the maximum is 999");
+        let message = validate_rounding_increment(2, Some(Unit::Day), IncrementContext::Round).unwrap_err();
+        assert_eq!(message, "roundingIncrement 2 is too large for day; the maximum is 1");
     }
 
     #[test]
-    fn test_duration_get_components_negative() {
-        let input = CString::new("-P1Y2M").unwrap();
-        let mut components = DurationComponents::default();
-        
-        temporal_duration_get_components(input.as_ptr(), &mut components);
-        
-        assert_eq!(components.is_valid, 1);
-        assert_eq!(components.years, -1);
-        assert_eq!(components.months, -2);
-        assert_eq!(components.sign, -1, "Negative duration should have sign -1");
+    fn test_offsets_with_seconds() {
+        let c = |s: &str| CString::new(s).unwrap();
+        let error_type = |result: TemporalResult| {
+            let kind = result.error_type;
+            unsafe { temporal_free_result(&mut { result }) };
+            kind
+        };
+
+        // Paris kept local mean time, +00:09:21, until 1911. toString rounds the offset
+        // to minutes as the spec does; the offset getter keeps the seconds, and the
+        // rounded string parses back to the same instant.
+        let paris = c("1900-01-01T12:00:00+00:09:21[Europe/Paris]");
+        let formatted = extract_result(temporal_zoned_date_time_from_string(paris.as_ptr()));
+        assert_eq!(formatted, "1900-01-01T12:00:00+00:09[Europe/Paris]");
+        assert_eq!(extract_result(temporal_zoned_date_time_get_offset(paris.as_ptr())), "+00:09:21");
+        let reparsed = c(&formatted);
+        assert_eq!(extract_result(temporal_zoned_date_time_get_offset(reparsed.as_ptr())), "+00:09:21");
+        assert_eq!(
+            extract_result(temporal_zoned_date_time_to_instant(reparsed.as_ptr())),
+            extract_result(temporal_zoned_date_time_to_instant(paris.as_ptr()))
+        );
+        assert_eq!(extract_result(temporal_zoned_date_time_to_instant(paris.as_ptr())), "1900-01-01T11:50:39Z");
+
+        // Liberia used -00:44:30 until 1972; a seconds offset must match exactly.
+        let monrovia = c("1960-01-01T12:00:00-00:44:30[Africa/Monrovia]");
+        assert_eq!(extract_result(temporal_zoned_date_time_get_offset(monrovia.as_ptr())), "-00:44:30");
+        assert_eq!(extract_result(temporal_zoned_date_time_to_instant(monrovia.as_ptr())), "1960-01-01T12:44:30Z");
+        for mismatched in ["1960-01-01T12:00:00-00:44:31[Africa/Monrovia]", "1900-01-01T12:00:00+00:09:21.5[Europe/Paris]"] {
+            let mismatched = c(mismatched);
+            assert_eq!(error_type(temporal_zoned_date_time_from_string(mismatched.as_ptr())), TemporalErrorType::RangeError as i32);
+        }
+
+        // Zero seconds are accepted and re-emitted in the short form.
+        let kolkata = c("2024-01-15T12:00:00+05:30:00[Asia/Kolkata]");
+        assert_eq!(extract_result(temporal_zoned_date_time_from_string(kolkata.as_ptr())), "2024-01-15T12:00:00+05:30[Asia/Kolkata]");
+        assert_eq!(extract_result(temporal_zoned_date_time_get_offset(kolkata.as_ptr())), "+05:30");
+
+        let instant = c("2024-01-15T12:00:00+05:30:15.123");
+        assert_eq!(extract_result(temporal_instant_from_string(instant.as_ptr())), "2024-01-15T06:29:44.877Z");
+
+        let tz = c("Europe/Paris");
+        let lmt = c("1900-01-01T00:00:00Z");
+        assert_eq!(extract_result(temporal_time_zone_get_offset_string_for(tz.as_ptr(), lmt.as_ptr())), "+00:09:21");
+        assert_eq!(extract_result(temporal_time_zone_get_offset_nanoseconds_for(tz.as_ptr(), lmt.as_ptr())), "561000000000");
+        let options = ZdtFromOptions { has_offset: 1, offset_nanoseconds: 561_000_000_000, ..Default::default() };
+        assert_eq!(
+            extract_result(temporal_zoned_date_time_from_components_with_options(1900, 1, 1, 12, 0, 0, 0, 0, 0, ptr::null(), tz.as_ptr(), &options)),
+            "1900-01-01T12:00:00+00:09[Europe/Paris]"
+        );
+
+        let kind = c("ZonedDateTime");
+        let pattern = c("HH:mm ZZZZ");
+        assert_eq!(extract_result(temporal_format_pattern(kind.as_ptr(), paris.as_ptr(), pattern.as_ptr())), "12:00 GMT+00:09:21");
     }
 
     #[test]
-    fn test_duration_get_components_zero() {
-        let input = CString::new("PT0S").unwrap();
-        let mut components = DurationComponents::default();
-        
-        temporal_duration_get_components(input.as_ptr(), &mut components);
-        
-        assert_eq!(components.is_valid, 1);
-        assert_eq!(components.sign, 0, "Zero duration should have sign 0");
+    fn test_calendar_eras() {
+        let eras = |calendar: &str| {
+            let calendar = CString::new(calendar).unwrap();
+            extract_result(temporal_calendar_eras(calendar.as_ptr()))
+        };
+        assert_eq!(eras("gregory"), "bce,,0000-12-31,-1\nce,0001-01-01,,1");
+        assert_eq!(
+            eras("japanese"),
+            "bce,,0000-12-31,-1\nce,0001-01-01,1872-12-31,1\nmeiji,1873-01-01,1912-07-29,1\ntaisho,1912-07-30,1926-12-24,1\n\
+             showa,1926-12-25,1989-01-07,1\nheisei,1989-01-08,2019-04-30,1\nreiwa,2019-05-01,,1"
+        );
+        assert_eq!(eras("roc"), "broc,,1911-12-31,-1\nroc,1912-01-01,,1");
+        assert_eq!(eras("islamic-civil"), "bh,,0622-07-18,-1\nah,0622-07-19,,1");
+        assert_eq!(eras("ethiopic"), "aa,,0008-08-26,1\nam,0008-08-27,,1");
+        assert_eq!(eras("hebrew"), "am,,,1");
+        assert_eq!(eras("chinese"), "");
+        assert_eq!(eras("iso8601"), "");
+        assert_eq!(extract_result(temporal_calendar_eras(ptr::null())), "");
+
+        let invalid = CString::new("julian").unwrap();
+        let result = temporal_calendar_eras(invalid.as_ptr());
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut { result }) };
     }
 
     #[test]
-    fn test_duration_get_components_invalid() {
-        let input = CString::new("invalid").unwrap();
-        let mut components = DurationComponents::default();
-        
-        temporal_duration_get_components(input.as_ptr(), &mut components);
-        
-        assert_eq!(components.is_valid, 0, "Invalid duration should set is_valid to 0");
+    fn test_plain_date_add_months_constrained() {
+        let add = |date: &str, months: i64| {
+            let date = CString::new(date).unwrap();
+            extract_result(temporal_plain_date_add_months_constrained(date.as_ptr(), months))
+        };
+        assert_eq!(add("2024-01-31", 1), "2024-02-29");
+        assert_eq!(add("2023-01-31", 1), "2023-02-28");
+        assert_eq!(add("2024-03-31", -1), "2024-02-29");
+        assert_eq!(add("2024-01-31", 13), "2025-02-28");
+        assert_eq!(add("2024-05-31", 1), "2024-06-30");
+        assert_eq!(add("2024-01-15", 0), "2024-01-15");
+        assert_eq!(add("2024-01-31[u-ca=japanese]", 1), "2024-02-29[u-ca=japanese]");
+
+        // 30 Shevat 5785: Adar has 29 days going forward, and Tevet 29 going back.
+        assert_eq!(add("2025-02-28[u-ca=hebrew]", 1), "2025-03-29[u-ca=hebrew]");
+        assert_eq!(add("2025-02-28[u-ca=hebrew]", -1), "2025-01-29[u-ca=hebrew]");
+
+        let date = CString::new("2024-01-31").unwrap();
+        let result = temporal_plain_date_add_months_constrained(date.as_ptr(), i64::MAX);
+        assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+        unsafe { temporal_free_result(&mut { result }) };
     }
 
     #[test]
-    fn test_duration_add() {
-        // Use time-only durations which don't require relative context
-        let a = CString::new("PT1H30M").unwrap();
-        let b = CString::new("PT2H15M").unwrap();
-        
-        let result = temporal_duration_add(a.as_ptr(), b.as_ptr());
-        let result_string = extract_result(result);
-        
-        // PT1H30M + PT2H15M = PT3H45M
-        assert!(result_string.contains("3H"), "1H30M + 2H15M should contain 3H: {}", result_string);
-        assert!(result_string.contains("45M"), "1H30M + 2H15M should contain 45M: {}", result_string);
+    fn test_duration_fractional_units() {
+        let components = |s: &str| {
+            let s = CString::new(s).unwrap();
+            let mut out = DurationComponents::default();
+            temporal_duration_get_components(s.as_ptr(), &mut out);
+            assert_eq!(out.is_valid, 1);
+            (out.hours, out.minutes, out.seconds, out.milliseconds, out.microseconds, out.nanoseconds)
+        };
+        // A fraction is carried into the smaller units, not kept on the one written.
+        assert_eq!(components("PT0.5H"), (0, 30, 0, 0, 0, 0));
+        assert_eq!(components("PT1.5S"), (0, 0, 1, 500, 0, 0));
+        assert_eq!(components("PT0.25M"), (0, 0, 15, 0, 0, 0));
+        assert_eq!(components("PT0.000000001H"), (0, 0, 0, 0, 3, 600));
+
+        let instant = CString::new("2024-01-01T00:00:00Z").unwrap();
+        let half_hour = CString::new("PT0.5H").unwrap();
+        assert_eq!(extract_result(temporal_instant_add(instant.as_ptr(), half_hour.as_ptr())), "2024-01-01T00:30:00Z");
+
+        let error = |s: &str| {
+            let s = CString::new(s).unwrap();
+            let mut result = temporal_duration_from_string(s.as_ptr());
+            assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+            let message = unsafe { std::ffi::CStr::from_ptr(result.error_message) }.to_string_lossy().into_owned();
+            unsafe { temporal_free_result(&mut result) };
+            message
+        };
+        for multiple in ["PT0.5H30M", "PT0.5H0.5M", "PT1H0.5M1S", "PT1.5M30S"] {
+            assert!(error(multiple).contains("only the last time unit of a duration may have a fraction"), "{}", multiple);
+        }
+        for other in ["P0.5D", "P0.5Y", "PT1.H", "PT0.1234567891S"] {
+            assert!(!error(other).contains("only the last time unit"), "{}", other);
+        }
     }
 
     #[test]
-    fn test_duration_subtract() {
-        // Use time-only durations which don't require relative context
-        let a = CString::new("PT3H45M").unwrap();
-        let b = CString::new("PT1H15M").unwrap();
-        
-        let result = temporal_duration_subtract(a.as_ptr(), b.as_ptr());
-        let result_string = extract_result(result);
-        
-        // PT3H45M - PT1H15M = PT2H30M
-        assert!(result_string.contains("2H"), "3H45M - 1H15M should contain 2H: {}", result_string);
-        assert!(result_string.contains("30M"), "3H45M - 1H15M should contain 30M: {}", result_string);
+    fn test_compare_pdt_in_zone_to_instant() {
+        let compare = |dt: &str, tz: &str, disambiguation: Option<&str>, instant: &str| {
+            let dt = CString::new(dt).unwrap();
+            let tz = CString::new(tz).unwrap();
+            let disambiguation = disambiguation.map(|d| CString::new(d).unwrap());
+            let instant = CString::new(instant).unwrap();
+            let mut result = temporal_compare_pdt_in_zone_to_instant(
+                dt.as_ptr(),
+                tz.as_ptr(),
+                disambiguation.as_ref().map_or(ptr::null(), |d| d.as_ptr()),
+                instant.as_ptr(),
+            );
+            let outcome = (result.error_type, result.value);
+            unsafe { temporal_free_compare_result(&mut result) };
+            outcome
+        };
+        let ok = |value| (TemporalErrorType::None as i32, value);
+        let range_error = (TemporalErrorType::RangeError as i32, 0);
+
+        // 09:00 in Kolkata is 03:30Z
+        assert_eq!(compare("2024-06-01T09:00", "Asia/Kolkata", None, "2024-06-01T03:30:00Z"), ok(0));
+        assert_eq!(compare("2024-06-01T09:00", "Asia/Kolkata", None, "2024-06-01T03:30:00.000000001Z"), ok(-1));
+        assert_eq!(compare("2024-06-01T09:00", "Asia/Kolkata", None, "2024-06-01T03:29:59Z"), ok(1));
+
+        // 02:30 does not exist in New York on 2024-03-10: earlier is 06:30Z, later 07:30Z
+        let gap = ("2024-03-10T02:30", "America/New_York");
+        assert_eq!(compare(gap.0, gap.1, Some("earlier"), "2024-03-10T07:00:00Z"), ok(-1));
+        assert_eq!(compare(gap.0, gap.1, Some("later"), "2024-03-10T07:00:00Z"), ok(1));
+        assert_eq!(compare(gap.0, gap.1, None, "2024-03-10T07:30:00Z"), ok(0));
+        assert_eq!(compare(gap.0, gap.1, Some("reject"), "2024-03-10T07:00:00Z"), range_error);
+
+        assert_eq!(compare("2024-06-01T09:00", "UTC", Some("sooner"), "2024-06-01T09:00:00Z"), range_error);
+        assert_eq!(compare("2024-06-01T09:00", "Mars/Olympus", None, "2024-06-01T09:00:00Z"), range_error);
+        assert_eq!(compare("2024-06-01T09:00", "UTC", None, "2024-06-01T09:00"), range_error);
+        assert_eq!(compare("2024-13-01T09:00", "UTC", None, "2024-06-01T09:00:00Z"), range_error);
     }
 
     #[test]
-    fn test_duration_negated() {
-        let input = CString::new("P1Y2M").unwrap();
-        
-        let result = temporal_duration_negated(input.as_ptr());
-        let result_string = extract_result(result);
-        
-        // Negation should produce negative duration
-        assert!(result_string.starts_with("-P"), "Negated should start with -P: {}", result_string);
+    fn test_schema_json() {
+        let schema = json::parse(&extract_result(temporal_schema_json())).unwrap();
+        assert_eq!(schema.get("version").and_then(JsonValue::as_i64), Some(TEMPORAL_FFI_API_VERSION as i64));
+
+        let slots = |name: &str| -> Vec<String> {
+            let array = schema.get("arrays").and_then(|arrays| arrays.get(name)).and_then(JsonValue::as_array).unwrap();
+            array.iter().map(|slot| slot.as_str().unwrap().to_string()).collect()
+        };
+        // The indices TemporalNative.kt reads
+        for name in ["plainDate", "plainDateTime", "zonedDateTime"] {
+            let slots = slots(name);
+            assert_eq!((slots[5].as_str(), slots[6].as_str()), ("weekOfYear", "yearOfWeek"), "{}", name);
+            assert_eq!(slots.last().unwrap(), "weekMask", "{}", name);
+        }
+        assert_eq!(slots("epochNanosecondsParts"), ["high", "low"]);
+        assert_eq!(slots("duration").len(), 17);
+        assert_eq!(slots("duration")[16], "clamped");
+
+        let week_mask = schema.get("weekMask").unwrap();
+        assert_eq!(week_mask.get("weekOfYear").and_then(JsonValue::as_i64), Some(1));
+        assert_eq!(week_mask.get("yearOfWeek").and_then(JsonValue::as_i64), Some(2));
     }
 
     #[test]
-    fn test_duration_abs() {
-        let input = CString::new("-P1Y2M").unwrap();
-        
-        let result = temporal_duration_abs(input.as_ptr());
-        let result_string = extract_result(result);
-        
-        // Absolute value should be positive
-        assert!(result_string.starts_with('P') && !result_string.starts_with("-P"), 
-                "Abs should be positive: {}", result_string);
+    fn test_plain_year_month_add_with_reference_day() {
+        let call = |f: extern "C" fn(*const c_char, *const c_char, *const c_char, i32) -> TemporalResult,
+                    ym: &str,
+                    duration: &str,
+                    overflow: Option<&str>,
+                    reference_day: i32| {
+            let ym = CString::new(ym).unwrap();
+            let duration = CString::new(duration).unwrap();
+            let overflow = overflow.map(|o| CString::new(o).unwrap());
+            let result = f(ym.as_ptr(), duration.as_ptr(), overflow.as_ref().map_or(ptr::null(), |o| o.as_ptr()), reference_day);
+            if result.error_type == TemporalErrorType::None as i32 {
+                Ok(extract_result(result))
+            } else {
+                assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+                unsafe { temporal_free_result(&mut { result }) };
+                Err(())
+            }
+        };
+        let add = |ym, duration, overflow, day| call(temporal_plain_year_month_add_with_reference_day, ym, duration, overflow, day);
+        let subtract = |ym, duration, overflow, day| call(temporal_plain_year_month_subtract_with_reference_day, ym, duration, overflow, day);
+        let plain_add = |ym: &str, duration: &str| {
+            let (ym, duration) = (CString::new(ym).unwrap(), CString::new(duration).unwrap());
+            let result = temporal_plain_year_month_add(ym.as_ptr(), duration.as_ptr(), ptr::null());
+            if result.error_type == TemporalErrorType::None as i32 {
+                return Some(extract_result(result));
+            }
+            unsafe { temporal_free_result(&mut { result }) };
+            None
+        };
+
+        // The spec path enters the month on day 1 and only takes years and months
+        assert_eq!(plain_add("2024-01", "P1M").as_deref(), Some("2024-02"));
+        assert_eq!(plain_add("2024-03", "-P1M").as_deref(), Some("2024-02"));
+        assert_eq!(plain_add("2024-01", "P1D"), None);
+
+        // Month end: 2024-01-31 + P1M constrains to February, or fails with reject
+        assert_eq!(add("2024-01", "P1M", None, 31), Ok("2024-02".to_string()));
+        assert_eq!(add("2024-01", "P1M", Some("reject"), 31), Err(()));
+        assert_eq!(add("2024-01", "P1M", Some("reject"), 0), Ok("2024-02".to_string()));
+        assert_eq!(add("2023-01", "P1Y1M", None, 31), Ok("2024-02".to_string()));
+
+        // 0 picks the first day for positive durations and the last for negative ones
+        assert_eq!(add("2024-01", "P30D", None, 0), Ok("2024-01".to_string()));
+        assert_eq!(add("2024-01", "P31D", None, 0), Ok("2024-02".to_string()));
+        assert_eq!(add("2024-03", "-P1D", None, 0), Ok("2024-03".to_string()));
+        assert_eq!(subtract("2024-03", "P1D", None, 0), Ok("2024-03".to_string()));
+        assert_eq!(subtract("2024-03", "P1D", None, 1), Ok("2024-02".to_string()));
+        assert_eq!(subtract("2024-03", "P31D", None, 0), Ok("2024-02".to_string()));
+        assert_eq!(add("2024-01", "P1W", None, 25), Ok("2024-02".to_string()));
+
+        // The reference day is constrained to the month: February 2024 ends on the 29th
+        assert_eq!(add("2024-02", "P1D", None, 31), Ok("2024-03".to_string()));
+        assert_eq!(add("2024-02", "P1D", Some("reject"), 28), Ok("2024-02".to_string()));
+
+        assert_eq!(add("2024-01", "PT1H", None, 0), Err(()));
+        assert_eq!(add("2024-01", "P1M", None, 32), Err(()));
+        assert_eq!(add("2024-01", "P1M", None, -1), Err(()));
+        assert_eq!(add("2024-01", "P1M", Some("sideways"), 0), Err(()));
     }
 
     #[test]
-    fn test_error_types() {
-        // Test TypeError for null input
-        let result = temporal_duration_from_string(ptr::null());
-        assert_eq!(result.error_type, TemporalErrorType::TypeError as i32);
-        unsafe { temporal_free_result(&mut { result }) };
-        
-        // Test RangeError for invalid format
-        let invalid = CString::new("not-a-duration").unwrap();
-        let result = temporal_duration_from_string(invalid.as_ptr());
+    fn test_canonical_cache() {
+        use crate::canonical_cache::{Cache, Stats, CAPACITY};
+
+        let mut cache = Cache::new();
+        cache.revalidate(0);
+        assert_eq!(cache.get("PT1H"), None);
+        cache.insert("PT1H", "PT1H".to_string());
+        cache.insert("PT60M", "PT60M".to_string());
+        assert_eq!(cache.get("PT60M").as_deref(), Some("PT60M"));
+        assert_eq!(cache.stats(), Stats { calls: 0, hits: 1, misses: 1, evictions: 0, resets: 0, entries: 2 });
+
+        // Full: the oldest input goes first
+        for i in 0..CAPACITY - 1 {
+            cache.insert(&format!("PT{}S", i), String::new());
+        }
+        assert_eq!(cache.stats().evictions, 1);
+        assert_eq!(cache.stats().entries, CAPACITY);
+        assert_eq!(cache.get("PT1H"), None);
+        assert!(cache.get("PT60M").is_some());
+
+        // A new time zone generation empties it
+        cache.revalidate(0);
+        assert_eq!(cache.stats().entries, CAPACITY);
+        cache.revalidate(1);
+        assert_eq!((cache.stats().entries, cache.stats().resets), (0, 1));
+
+        // Cached and uncached results agree, errors are never cached
+        let input = CString::new("2031-07-04T05:06:07.891+02:00").unwrap();
+        let first = extract_result(temporal_instant_from_string(input.as_ptr()));
+        assert_eq!(first, "2031-07-04T03:06:07.891Z");
+        assert_eq!(extract_result(temporal_instant_from_string(input.as_ptr())), first);
+        let invalid = CString::new("2031-02-30").unwrap();
+        for _ in 0..2 {
+            let mut result = temporal_plain_date_from_string(invalid.as_ptr());
+            assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+            unsafe { temporal_free_result(&mut result) };
+        }
+
+        let stats = json::parse(&extract_result(canonical_cache::temporal_canonical_cache_stats())).unwrap();
+        let JsonValue::Object(kinds) = &stats else { panic!("{:?}", stats) };
+        assert_eq!(kinds.len(), 8);
+        let instant = stats.get("Instant").unwrap();
+        assert!(instant.get("misses").and_then(JsonValue::as_i64).unwrap() >= 1);
+        assert!(instant.get("entries").and_then(JsonValue::as_i64).unwrap() <= CAPACITY as i64);
+    }
+
+    #[test]
+    fn test_metrics_snapshot() {
+        let snapshot = || json::parse(&extract_result(metrics::temporal_metrics_snapshot())).unwrap();
+        let count = |snapshot: &JsonValue, path: [&str; 2]| {
+            snapshot.get(path[0]).and_then(|v| v.get(path[1])).and_then(JsonValue::as_i64).unwrap()
+        };
+
+        let before = snapshot();
+        let JsonValue::Object(kinds) = before.get("parse").unwrap() else { panic!("{:?}", before) };
+        assert_eq!(kinds.len(), 8);
+        assert!(before.get("timeZoneChanges").and_then(JsonValue::as_i64).is_some());
+
+        // Counters are process-wide and other tests run in parallel, so only check
+        // that each one moved by at least this test's share
+        let input = CString::new("2031-07-04").unwrap();
+        extract_result(temporal_plain_date_from_string(input.as_ptr()));
+        let invalid = CString::new("2031-02-30").unwrap();
+        let mut result = temporal_plain_date_from_string(invalid.as_ptr());
         assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
-        
-        // Check error message contains useful info
-        let error_msg = unsafe { std::ffi::CStr::from_ptr(result.error_message) }
-            .to_string_lossy()
-            .to_string();
-        assert!(error_msg.contains("not-a-duration"), "Error message should include input: {}", error_msg);
-        unsafe { temporal_free_result(&mut { result }) };
+        unsafe { temporal_free_result(&mut result) };
+        let mut result = temporal_plain_date_from_string(ptr::null());
+        assert_eq!(result.error_type, TemporalErrorType::TypeError as i32);
+        unsafe { temporal_free_result(&mut result) };
+        // Too long to be cached, but still a call
+        let long = CString::new(format!("2031-07-04[u-ca=iso8601]{}", "[x-note=long]".repeat(12))).unwrap();
+        let mut result = temporal_plain_date_from_string(long.as_ptr());
+        unsafe { temporal_free_result(&mut result) };
+
+        let after = snapshot();
+        assert!(count(&after, ["errors", "RangeError"]) > count(&before, ["errors", "RangeError"]));
+        assert!(count(&after, ["errors", "TypeError"]) > count(&before, ["errors", "TypeError"]));
+        let calls = |snapshot: &JsonValue| {
+            snapshot.get("parse").and_then(|p| p.get("PlainDate")).and_then(|d| d.get("calls")).and_then(JsonValue::as_i64).unwrap()
+        };
+        assert!(calls(&after) >= calls(&before) + 3);
+    }
+
+    #[test]
+    fn test_display_defaults_only_affect_to_string() {
+        use crate::display::temporal_set_default_display_options;
+
+        let _defaults = lock_display_defaults();
+        let (always, never) = (CString::new("always").unwrap(), CString::new("never").unwrap());
+        assert_eq!(temporal_set_default_display_options(always.as_ptr(), never.as_ptr(), never.as_ptr(), 0), 0);
+
+        // Values keep the offset, zone and fraction they need to round-trip, cached or not
+        let zdt = CString::new("2024-01-15T10:30:45.123-05:00[America/New_York]").unwrap();
+        for _ in 0..2 {
+            assert_eq!(extract_result(temporal_zoned_date_time_from_string(zdt.as_ptr())), zdt.to_str().unwrap());
+        }
+        let hour = CString::new("PT1H").unwrap();
+        let later = extract_result(temporal_zoned_date_time_add(zdt.as_ptr(), hour.as_ptr(), ptr::null()));
+        assert_eq!(later, "2024-01-15T11:30:45.123-05:00[America/New_York]");
+        let later = CString::new(later).unwrap();
+        assert_eq!(temporal_zoned_date_time_compare_code(zdt.as_ptr(), later.as_ptr()), -1);
+        let instant = CString::new("2024-01-15T15:30:45.5Z").unwrap();
+        let rounded = CString::new("2024-01-15T15:30:45Z").unwrap();
+        assert_eq!(extract_result(temporal_instant_from_string(instant.as_ptr())), "2024-01-15T15:30:45.5Z");
+        assert_eq!(temporal_instant_compare_code(instant.as_ptr(), rounded.as_ptr()), 1);
+
+        // Only the explicit toString functions apply them
+        assert_eq!(
+            extract_result(temporal_zoned_date_time_to_string(zdt.as_ptr(), ptr::null(), ptr::null())),
+            "2024-01-15T10:30:45[u-ca=iso8601]"
+        );
+        assert_eq!(extract_result(temporal_instant_to_string(instant.as_ptr(), ptr::null(), ptr::null())), "2024-01-15T15:30:45Z");
+
+        assert_eq!(temporal_set_default_display_options(ptr::null(), ptr::null(), ptr::null(), -1), 0);
     }
 }
@@ -0,0 +1,135 @@
+//! Meeting planning across time zones: where do everyone's working hours overlap?
+//!
+//! Participants are described by JSON, one object per participant:
+//!
+//! ```json
+//! [
+//!   { "timeZone": "Europe/Berlin", "start": "09:00", "end": "17:00" },
+//!   { "timeZone": "America/New_York", "windows": [
+//!     { "start": "08:00", "end": "12:00" },
+//!     { "start": "13:00", "end": "18:00" }
+//!   ] }
+//! ]
+//! ```
+//!
+//! Each window is local wall-clock time on the requested date in the participant's
+//! zone. A window whose end is not after its start runs past midnight into the next
+//! day. Local times skipped by a DST change resolve as with "compatible"
+//! disambiguation.
+
+use std::ffi::c_char;
+use std::str::FromStr;
+
+use temporal_rs::options::{Disambiguation, Unit};
+use temporal_rs::{Instant, PlainDate, PlainTime, TemporalError, TimeZone};
+
+use crate::json::{self, JsonValue};
+use crate::{format_instant, parse_bulk_c_str, parse_plain_date, unit_duration, TemporalResult};
+
+/// A half-open interval of epoch nanoseconds.
+type Window = (i128, i128);
+
+/// Returns the UTC windows in which every participant's working hours on `date`
+/// intersect, one per line as an ISO 8601 interval of Instants
+/// ("2024-01-15T14:00:00Z/2024-01-15T16:00:00Z"), in chronological order. No common
+/// time gives an empty string; invalid JSON, zones or times are a RangeError.
+#[no_mangle]
+pub extern "C" fn temporal_overlap_daily_windows(participants_json: *const c_char, date: *const c_char) -> TemporalResult {
+    let text = match parse_bulk_c_str(participants_json, "participants json") {
+        Ok(s) => s,
+        Err(e) => return e,
+    };
+    let date = match parse_plain_date(date, "plain date") {
+        Ok(d) => d,
+        Err(e) => return e,
+    };
+    let participants = match json::parse(text) {
+        Ok(JsonValue::Array(items)) if !items.is_empty() => items,
+        Ok(_) => return TemporalResult::range_error("Invalid participants: expected a non-empty array"),
+        Err(message) => return TemporalResult::range_error(&format!("Invalid participants: {}", message)),
+    };
+
+    let mut overlap: Option<Vec<Window>> = None;
+    for (index, participant) in participants.iter().enumerate() {
+        let windows = match participant_windows(participant, &date) {
+            Ok(windows) => windows,
+            Err(message) => return TemporalResult::range_error(&format!("Invalid participant {}: {}", index, message)),
+        };
+        overlap = Some(match overlap {
+            None => windows,
+            Some(current) => intersect(&current, &windows),
+        });
+    }
+
+    let format = |ns: i128| Instant::try_new(ns).and_then(|instant| format_instant(&instant));
+    let mut lines = Vec::new();
+    for (start, end) in overlap.unwrap_or_default() {
+        match format(start).and_then(|start| Ok(format!("{}/{}", start, format(end)?))) {
+            Ok(line) => lines.push(line),
+            Err(e) => return TemporalResult::range_error(&format!("Failed to format window: {}", e)),
+        }
+    }
+    TemporalResult::success(lines.join("\n"))
+}
+
+/// Converts one participant's windows to sorted, merged UTC windows.
+fn participant_windows(participant: &JsonValue, date: &PlainDate) -> Result<Vec<Window>, String> {
+    let tz_id = participant
+        .get("timeZone")
+        .and_then(JsonValue::as_str)
+        .ok_or("timeZone must be a string")?;
+    let tz = TimeZone::try_from_str(tz_id).map_err(|e| format!("invalid timeZone '{}': {}", tz_id, e))?;
+    let entries = match participant.get("windows") {
+        Some(windows) => windows.as_array().ok_or("windows must be an array")?,
+        None => std::slice::from_ref(participant),
+    };
+
+    let next_day = date.add(&unit_duration(Unit::Day), None).map_err(|e| e.to_string())?;
+    let mut windows = Vec::with_capacity(entries.len());
+    for entry in entries {
+        let time = |key: &str| -> Result<PlainTime, String> {
+            let text = entry.get(key).and_then(JsonValue::as_str).ok_or(format!("{} must be a time string", key))?;
+            PlainTime::from_str(text).map_err(|e| format!("invalid {} '{}': {}", key, text, e))
+        };
+        let (start, end) = (time("start")?, time("end")?);
+        let end_date = if end > start { date } else { &next_day };
+        let start = local_epoch_ns(date, start, tz).map_err(|e| e.to_string())?;
+        let end = local_epoch_ns(end_date, end, tz).map_err(|e| e.to_string())?;
+        if start < end {
+            windows.push((start, end));
+        }
+    }
+    windows.sort_unstable();
+    let mut merged: Vec<Window> = Vec::with_capacity(windows.len());
+    for (start, end) in windows {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    Ok(merged)
+}
+
+fn local_epoch_ns(date: &PlainDate, time: PlainTime, tz: TimeZone) -> Result<i128, TemporalError> {
+    let zdt = date.to_plain_date_time(Some(time))?.to_zoned_date_time(tz, Disambiguation::Compatible)?;
+    Ok(zdt.epoch_nanoseconds().as_i128())
+}
+
+/// Intersects two sorted lists of disjoint windows.
+fn intersect(a: &[Window], b: &[Window]) -> Vec<Window> {
+    let (mut i, mut j) = (0, 0);
+    let mut result = Vec::new();
+    while i < a.len() && j < b.len() {
+        let start = a[i].0.max(b[j].0);
+        let end = a[i].1.min(b[j].1);
+        if start < end {
+            result.push((start, end));
+        }
+        if a[i].1 < b[j].1 {
+            i += 1;
+        } else {
+            j += 1;
+        }
+    }
+    result
+}
@@ -0,0 +1,82 @@
+//! Process-wide counters for app telemetry, read with `temporal_metrics_snapshot`.
+//!
+//! Errors are counted where they are created: the error constructors of
+//! `TemporalResult` and `CompareResult`, `set_last_error` for the code-returning
+//! functions, and the JNI bindings when they throw. Counting uses relaxed atomics, so
+//! a snapshot taken while other threads run may be a few calls behind.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::canonical_cache;
+use crate::json::JsonValue;
+use crate::{temporal_tz_generation, TemporalErrorType, TemporalResult};
+
+/// Indexed by `TemporalErrorType as usize - 1`.
+static ERRORS: [AtomicU64; 3] = [const { AtomicU64::new(0) }; 3];
+
+const ERROR_TYPES: [TemporalErrorType; 3] =
+    [TemporalErrorType::RangeError, TemporalErrorType::TypeError, TemporalErrorType::InputTooLong];
+
+pub(crate) fn record_error(error_type: TemporalErrorType) {
+    if let Some(counter) = (error_type as usize).checked_sub(1).and_then(|index| ERRORS.get(index)) {
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+fn error_name(error_type: TemporalErrorType) -> &'static str {
+    match error_type {
+        TemporalErrorType::None => "None",
+        TemporalErrorType::RangeError => "RangeError",
+        TemporalErrorType::TypeError => "TypeError",
+        TemporalErrorType::InputTooLong => "InputTooLong",
+    }
+}
+
+/// The snapshot as compact JSON; see `temporal_metrics_snapshot`.
+pub(crate) fn snapshot_json() -> String {
+    let number = |n: u64| JsonValue::Number(n.to_string());
+    let parse = canonical_cache::stats()
+        .into_iter()
+        .map(|(kind, stats)| {
+            let fields = vec![
+                ("calls".to_string(), number(stats.calls)),
+                ("cacheHits".to_string(), number(stats.hits)),
+                ("cacheEvictions".to_string(), number(stats.evictions)),
+                ("cacheResets".to_string(), number(stats.resets)),
+                ("cacheEntries".to_string(), number(stats.entries as u64)),
+            ];
+            (kind.name().to_string(), JsonValue::Object(fields))
+        })
+        .collect();
+    let errors = ERROR_TYPES
+        .into_iter()
+        .zip(&ERRORS)
+        .map(|(error_type, count)| (error_name(error_type).to_string(), number(count.load(Ordering::Relaxed))))
+        .collect();
+    JsonValue::Object(vec![
+        ("parse".to_string(), JsonValue::Object(parse)),
+        ("errors".to_string(), JsonValue::Object(errors)),
+        ("timeZoneChanges".to_string(), number(temporal_tz_generation())),
+    ])
+    .to_string()
+}
+
+/// Returns the native counters as JSON, counted from process start:
+///
+/// ```json
+/// {
+///   "parse": { "Instant": { "calls": 4, "cacheHits": 3, "cacheEvictions": 0, "cacheResets": 0, "cacheEntries": 1 }, ... },
+///   "errors": { "RangeError": 2, "TypeError": 0, "InputTooLong": 0 },
+///   "timeZoneChanges": 1
+/// }
+/// ```
+///
+/// `parse` has one entry per kind of `temporal_batch_parse`, counting the
+/// `*_from_string` calls (C and JNI) and batch items; every call that is not a cache
+/// hit parses and formats its input. The time zone database is compiled in and
+/// never rebuilt; `timeZoneChanges` counts the host-reported device zone changes,
+/// each of which resets the caches.
+#[no_mangle]
+pub extern "C" fn temporal_metrics_snapshot() -> TemporalResult {
+    TemporalResult::success(snapshot_json())
+}
@@ -0,0 +1,104 @@
+//! Ownership tests for the strings handed across the C boundary.
+//!
+//! These only call the free/take functions the way a well-behaved caller would, so
+//! any leak or double free they expose is a bug in the library. Run them on their
+//! own under Miri or AddressSanitizer:
+//!
+//! ```sh
+//! cargo +nightly miri test ownership_tests
+//! RUSTFLAGS=-Zsanitizer=address cargo +nightly test -Zbuild-std --target x86_64-unknown-linux-gnu ownership_tests
+//! ```
+
+use std::ffi::{CStr, CString};
+use std::ptr;
+
+use crate::*;
+
+fn read(s: *const c_char) -> String {
+    assert!(!s.is_null());
+    unsafe { CStr::from_ptr(s) }.to_string_lossy().into_owned()
+}
+
+#[test]
+fn free_result_nulls_pointers_so_second_free_is_noop() {
+    let input = CString::new("2024-01-15").unwrap();
+    let mut result = temporal_plain_date_from_string(input.as_ptr());
+    assert!(!result.value.is_null());
+    unsafe { temporal_free_result(&mut result) };
+    assert!(result.value.is_null());
+    assert!(result.error_message.is_null());
+    unsafe { temporal_free_result(&mut result) };
+
+    let input = CString::new("not a date").unwrap();
+    let mut result = temporal_plain_date_from_string(input.as_ptr());
+    assert!(!result.error_message.is_null());
+    unsafe { temporal_free_result(&mut result) };
+    assert!(result.error_message.is_null());
+    unsafe { temporal_free_result(&mut result) };
+}
+
+#[test]
+fn free_compare_result_nulls_message_so_second_free_is_noop() {
+    let valid = CString::new("2024-01-15").unwrap();
+    let mut result = temporal_plain_date_compare(valid.as_ptr(), ptr::null());
+    assert!(!result.error_message.is_null());
+    unsafe { temporal_free_compare_result(&mut result) };
+    assert!(result.error_message.is_null());
+    unsafe { temporal_free_compare_result(&mut result) };
+}
+
+#[test]
+fn free_functions_accept_null() {
+    unsafe {
+        temporal_free_result(ptr::null_mut());
+        temporal_free_compare_result(ptr::null_mut());
+        temporal_free_string(ptr::null_mut());
+        assert!(temporal_result_take_value(ptr::null_mut()).is_null());
+        assert!(temporal_result_take_error_message(ptr::null_mut()).is_null());
+    }
+}
+
+#[test]
+fn take_value_transfers_ownership() {
+    let input = CString::new("2024-01-15T10:30:00Z").unwrap();
+    let mut result = temporal_instant_from_string(input.as_ptr());
+    let value = unsafe { temporal_result_take_value(&mut result) };
+    assert!(result.value.is_null());
+    assert!(unsafe { temporal_result_take_value(&mut result) }.is_null());
+
+    // The result no longer owns the value, so freeing it must leave the string intact
+    unsafe { temporal_free_result(&mut result) };
+    assert_eq!(read(value), "2024-01-15T10:30:00Z");
+    unsafe { temporal_free_string(value) };
+}
+
+#[test]
+fn take_error_message_transfers_ownership() {
+    let input = CString::new("garbage").unwrap();
+    let mut result = temporal_instant_from_string(input.as_ptr());
+    assert_eq!(result.error_type, TemporalErrorType::RangeError as i32);
+    let message = unsafe { temporal_result_take_error_message(&mut result) };
+    assert!(result.error_message.is_null());
+    unsafe { temporal_free_result(&mut result) };
+    assert!(read(message).contains("garbage"));
+    unsafe { temporal_free_string(message) };
+}
+
+#[test]
+fn standalone_strings_are_freed_with_free_string() {
+    let now = temporal_instant_now();
+    assert!(read(now).ends_with('Z'));
+    unsafe { temporal_free_string(now) };
+
+    let input = CString::new("not a date").unwrap();
+    assert_eq!(temporal_plain_date_compare_code(input.as_ptr(), input.as_ptr()), TEMPORAL_COMPARE_ERROR);
+    // Each call returns a fresh copy, so both must be freed
+    let first = temporal_last_error_message();
+    let second = temporal_last_error_message();
+    assert_ne!(first, second);
+    assert_eq!(read(first), read(second));
+    unsafe {
+        temporal_free_string(first);
+        temporal_free_string(second);
+    }
+}
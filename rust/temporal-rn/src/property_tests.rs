@@ -0,0 +1,178 @@
+//! Property tests for the arithmetic entry points: adding a duration and then
+//! subtracting it gives back the starting value.
+//!
+//! That only holds where the spec makes add and subtract inverses, so the inputs are
+//! kept to those ranges: calendar durations (years and months) start on a day that
+//! every month has, durations mixing calendar and clock units are not generated, and
+//! ZonedDateTime calendar arithmetic starts at noon, away from DST transitions.
+//!
+//! Europe/Paris and America/New_York are left out of the ZonedDateTime properties:
+//! temporal_rs 0.2.6 resolves wall-clock times in the week before a rule-based
+//! transition with the wrong offset (2024-03-25T12:00[Europe/Paris] comes back as
+//! 11:00+01:00, which then fails to parse), so they fail for reasons unrelated to
+//! add and subtract. Put them back once that is fixed upstream.
+//!
+//! Failing cases are shrunk and printed by proptest:
+//!
+//! ```sh
+//! cargo test property_tests
+//! ```
+
+use std::ffi::{CStr, CString};
+
+use proptest::prelude::*;
+
+use crate::*;
+
+const ZONES: [&str; 5] = ["UTC", "Asia/Kolkata", "Asia/Kathmandu", "Australia/Lord_Howe", "+05:45"];
+
+type Call1 = extern "C" fn(*const c_char) -> TemporalResult;
+type Call2 = extern "C" fn(*const c_char, *const c_char) -> TemporalResult;
+
+fn take(mut result: TemporalResult) -> Result<String, TestCaseError> {
+    let outcome = if result.error_type == TemporalErrorType::None as i32 {
+        Ok(unsafe { CStr::from_ptr(result.value) }.to_string_lossy().into_owned())
+    } else {
+        Err(TestCaseError::fail(unsafe { CStr::from_ptr(result.error_message) }.to_string_lossy().into_owned()))
+    };
+    unsafe { temporal_free_result(&mut result) };
+    outcome
+}
+
+/// Returns the value as the library formats it and the result of adding and then
+/// subtracting `duration`.
+fn add_then_subtract(
+    value: &str,
+    duration: &str,
+    from_string: Call1,
+    add: Call2,
+    subtract: Call2,
+) -> Result<(String, String), TestCaseError> {
+    let value = take(from_string(CString::new(value).unwrap().as_ptr()))?;
+    let duration = CString::new(duration).unwrap();
+    let added = take(add(CString::new(value.as_str()).unwrap().as_ptr(), duration.as_ptr()))?;
+    let back = take(subtract(CString::new(added).unwrap().as_ptr(), duration.as_ptr()))?;
+    Ok((value, back))
+}
+
+/// "YYYY-MM-DD", on a day every month has so calendar arithmetic never constrains.
+fn date() -> impl Strategy<Value = String> {
+    (1900..=2100i32, 1..=12u8, 1..=28u8).prop_map(|(year, month, day)| format!("{:04}-{:02}-{:02}", year, month, day))
+}
+
+/// "HH:MM:SS.fffffffff"
+fn time() -> impl Strategy<Value = String> {
+    (0..24u8, 0..60u8, 0..60u8, 0..1_000_000_000u32)
+        .prop_map(|(hour, minute, second, fraction)| format!("{:02}:{:02}:{:02}.{:09}", hour, minute, second, fraction))
+}
+
+fn sign() -> impl Strategy<Value = &'static str> {
+    prop_oneof![Just(""), Just("-")]
+}
+
+/// Hours down to nanoseconds, up to about ten years.
+fn clock_duration() -> impl Strategy<Value = String> {
+    (sign(), 0..=87_600u32, 0..=1_000u32, 0..=100_000u32, 0..1_000_000_000u32).prop_map(
+        |(sign, hours, minutes, seconds, fraction)| format!("{}PT{}H{}M{}.{:09}S", sign, hours, minutes, seconds, fraction),
+    )
+}
+
+/// Weeks and days followed by clock units, so everything balances through nanoseconds.
+fn day_and_clock_duration() -> impl Strategy<Value = String> {
+    (sign(), 0..=520u32, 0..=3_650u32, 0..=48u32, 0..1_000_000_000u64).prop_map(|(sign, weeks, days, hours, nanoseconds)| {
+        format!("{}P{}W{}DT{}H{}.{:09}S", sign, weeks, days, hours, nanoseconds / 1_000_000_000, nanoseconds % 1_000_000_000)
+    })
+}
+
+fn calendar_duration() -> impl Strategy<Value = String> {
+    (sign(), 0..=50u32, 0..=600u32).prop_map(|(sign, years, months)| format!("{}P{}Y{}M", sign, years, months))
+}
+
+/// Weeks and days only, which land on the same wall-clock time.
+fn day_duration() -> impl Strategy<Value = String> {
+    (sign(), 0..=520u32, 0..=3_650u32).prop_map(|(sign, weeks, days)| format!("{}P{}W{}D", sign, weeks, days))
+}
+
+/// Calendar arithmetic, the default for temporal_zoned_date_time_add.
+extern "C" fn zoned_date_time_add(zdt: *const c_char, duration: *const c_char) -> TemporalResult {
+    temporal_zoned_date_time_add(zdt, duration, ptr::null())
+}
+
+fn zone() -> impl Strategy<Value = &'static str> {
+    proptest::sample::select(&ZONES[..])
+}
+
+proptest! {
+    #[test]
+    fn instant_add_subtract(date in date(), time in time(), duration in clock_duration()) {
+        let (value, back) = add_then_subtract(
+            &format!("{}T{}Z", date, time),
+            &duration,
+            temporal_instant_from_string,
+            temporal_instant_add,
+            temporal_instant_subtract,
+        )?;
+        prop_assert_eq!(back, value);
+    }
+
+    #[test]
+    fn plain_time_add_subtract(time in time(), duration in clock_duration()) {
+        let (value, back) =
+            add_then_subtract(&time, &duration, temporal_plain_time_from_string, temporal_plain_time_add, temporal_plain_time_subtract)?;
+        prop_assert_eq!(back, value);
+    }
+
+    #[test]
+    fn plain_date_add_subtract(date in date(), duration in prop_oneof![day_duration(), calendar_duration()]) {
+        let (value, back) =
+            add_then_subtract(&date, &duration, temporal_plain_date_from_string, temporal_plain_date_add, temporal_plain_date_subtract)?;
+        prop_assert_eq!(back, value);
+    }
+
+    #[test]
+    fn plain_date_time_add_subtract(
+        date in date(),
+        time in time(),
+        duration in prop_oneof![day_and_clock_duration(), calendar_duration()],
+    ) {
+        let (value, back) = add_then_subtract(
+            &format!("{}T{}", date, time),
+            &duration,
+            temporal_plain_date_time_from_string,
+            temporal_plain_date_time_add,
+            temporal_plain_date_time_subtract,
+        )?;
+        prop_assert_eq!(back, value);
+    }
+
+    /// Clock units are exact time, so any starting time in any zone round-trips.
+    #[test]
+    fn zoned_date_time_add_subtract_clock(date in date(), time in time(), zone in zone(), duration in clock_duration()) {
+        let (value, back) = add_then_subtract(
+            &format!("{}T{}[{}]", date, time, zone),
+            &duration,
+            temporal_zoned_date_time_from_string,
+            zoned_date_time_add,
+            temporal_zoned_date_time_subtract,
+        )?;
+        prop_assert_eq!(back, value);
+    }
+
+    /// Calendar units keep the wall-clock time, which is only reversible away from
+    /// transitions; none of the zones has one at noon.
+    #[test]
+    fn zoned_date_time_add_subtract_calendar(
+        date in date(),
+        zone in zone(),
+        duration in prop_oneof![day_duration(), calendar_duration()],
+    ) {
+        let (value, back) = add_then_subtract(
+            &format!("{}T12:00:00[{}]", date, zone),
+            &duration,
+            temporal_zoned_date_time_from_string,
+            zoned_date_time_add,
+            temporal_zoned_date_time_subtract,
+        )?;
+        prop_assert_eq!(back, value);
+    }
+}
@@ -0,0 +1,282 @@
+//! Builds the native libraries the React Native package ships: `cargo xtask <command>`
+//! from `rust/temporal-rn`.
+//!
+//! Builds go through the `release-mobile` profile by default, and source paths are
+//! remapped in the artifacts so two checkouts of the same commit produce the same
+//! libraries.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{exit, Command};
+
+const USAGE: &str = "\
+usage: cargo xtask <command> [options] [-- <cargo build args>]
+
+commands:
+  build-android   JNI libraries for every ABI, via cargo-ndk, into android/src/main/jniLibs
+  build-ios       static libraries for device and simulator into ios/libs
+  build-all       build-android, then build-ios (skipped when not on macOS)
+
+options:
+  --profile <name>   cargo profile (default: release-mobile)
+  --abi <abi>        build-android: only this ABI; repeatable (default: all)
+  --xcframework      build-ios: also package ios/libs/TemporalRN.xcframework";
+
+/// Android ABIs and their Rust targets.
+const ANDROID_ABIS: [(&str, &str); 4] = [
+    ("arm64-v8a", "aarch64-linux-android"),
+    ("armeabi-v7a", "armv7-linux-androideabi"),
+    ("x86", "i686-linux-android"),
+    ("x86_64", "x86_64-linux-android"),
+];
+const IOS_DEVICE: &str = "aarch64-apple-ios";
+const IOS_SIMULATORS: [&str; 2] = ["aarch64-apple-ios-sim", "x86_64-apple-ios"];
+const STATIC_LIB: &str = "libtemporal_rn.a";
+const SHARED_LIB: &str = "libtemporal_rn.so";
+const HEADER: &str = "temporal_rn.h";
+
+struct Options {
+    profile: String,
+    abis: Vec<String>,
+    xcframework: bool,
+    cargo_args: Vec<String>,
+}
+
+/// Directories the commands read from and write to.
+struct Paths {
+    /// `rust/temporal-rn`, where cargo runs.
+    crate_dir: PathBuf,
+    jni_libs: PathBuf,
+    ios: PathBuf,
+}
+
+type Result<T = ()> = std::result::Result<T, String>;
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let command = args.next().unwrap_or_default();
+    let options = match parse_options(args) {
+        Ok(options) => options,
+        Err(message) => usage_error(&message),
+    };
+    let paths = paths();
+    let result = match command.as_str() {
+        "build-android" => build_android(&paths, &options),
+        "build-ios" => build_ios(&paths, &options),
+        "build-all" => build_all(&paths, &options),
+        "" | "help" | "--help" | "-h" => {
+            println!("{}", USAGE);
+            return;
+        }
+        other => usage_error(&format!("unknown command '{}'", other)),
+    };
+    if let Err(message) = result {
+        eprintln!("xtask: {}", message);
+        exit(1);
+    }
+}
+
+fn usage_error(message: &str) -> ! {
+    eprintln!("xtask: {}\n\n{}", message, USAGE);
+    exit(2);
+}
+
+fn parse_options(mut args: impl Iterator<Item = String>) -> Result<Options> {
+    let mut options = Options {
+        profile: "release-mobile".to_string(),
+        abis: Vec::new(),
+        xcframework: false,
+        cargo_args: Vec::new(),
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--profile" => options.profile = args.next().ok_or("--profile needs a value")?,
+            "--abi" => {
+                let abi = args.next().ok_or("--abi needs a value")?;
+                if !ANDROID_ABIS.iter().any(|(known, _)| *known == abi) {
+                    return Err(format!("unknown ABI '{}'", abi));
+                }
+                options.abis.push(abi);
+            }
+            "--xcframework" => options.xcframework = true,
+            "--" => options.cargo_args.extend(args.by_ref()),
+            other => return Err(format!("unknown option '{}'", other)),
+        }
+    }
+    Ok(options)
+}
+
+fn paths() -> Paths {
+    let crate_dir = Path::new(env!("CARGO_MANIFEST_DIR")).parent().expect("xtask lives inside the crate").to_path_buf();
+    let project_root = crate_dir.join("../..");
+    Paths {
+        jni_libs: project_root.join("android/src/main/jniLibs"),
+        ios: project_root.join("ios"),
+        crate_dir,
+    }
+}
+
+/// Android first: it builds on any host, so a missing Xcode never holds it up.
+fn build_all(paths: &Paths, options: &Options) -> Result {
+    build_android(paths, options)?;
+    if !cfg!(target_os = "macos") {
+        println!("\nSkipping iOS: build-ios needs macOS with Xcode");
+        return Ok(());
+    }
+    build_ios(paths, options)
+}
+
+fn build_android(paths: &Paths, options: &Options) -> Result {
+    let ndk = find_ndk().ok_or("Android NDK not found; set ANDROID_NDK_HOME")?;
+    println!("Using NDK: {}", ndk.display());
+    if !succeeds(Command::new(cargo()).args(["ndk", "--version"])) {
+        return Err("cargo-ndk not found; install it with `cargo install cargo-ndk`".to_string());
+    }
+    let abis: Vec<(&str, &str)> = ANDROID_ABIS
+        .into_iter()
+        .filter(|(abi, _)| options.abis.is_empty() || options.abis.iter().any(|wanted| wanted == abi))
+        .collect();
+    add_rust_targets(abis.iter().map(|(_, target)| *target));
+
+    let mut command = Command::new(cargo());
+    command.current_dir(&paths.crate_dir).env("ANDROID_NDK_HOME", &ndk).arg("ndk");
+    for (abi, _) in &abis {
+        command.args(["-t", abi]);
+    }
+    command.arg("-o").arg(&paths.jni_libs).args(["build", "--profile", &options.profile]);
+    command.args(&options.cargo_args);
+    run(reproducible(&mut command, paths))?;
+
+    println!("\nAndroid libraries:");
+    for (abi, _) in &abis {
+        report(&paths.jni_libs.join(abi).join(SHARED_LIB))?;
+    }
+    Ok(())
+}
+
+fn build_ios(paths: &Paths, options: &Options) -> Result {
+    if !cfg!(target_os = "macos") {
+        return Err("build-ios needs macOS with Xcode".to_string());
+    }
+    let header = paths.ios.join(HEADER);
+    // The header is maintained by hand, so a missing one is a broken checkout
+    if !header.is_file() {
+        return Err(format!("{} is missing", header.display()));
+    }
+    let targets: Vec<&str> = std::iter::once(IOS_DEVICE).chain(IOS_SIMULATORS).collect();
+    add_rust_targets(targets.iter().copied());
+    for target in &targets {
+        let mut command = Command::new(cargo());
+        command.current_dir(&paths.crate_dir).args(["build", "--lib", "--profile", &options.profile, "--target", target]);
+        command.args(&options.cargo_args);
+        run(reproducible(&mut command, paths))?;
+    }
+
+    let target_dir = env::var_os("CARGO_TARGET_DIR").map_or_else(|| paths.crate_dir.join("target"), PathBuf::from);
+    let built = |target: &str| target_dir.join(target).join(&options.profile).join(STATIC_LIB);
+    let libs = paths.ios.join("libs");
+    fs::create_dir_all(&libs).map_err(|e| format!("failed to create {}: {}", libs.display(), e))?;
+    let device = libs.join("libtemporal_rn_device.a");
+    let simulator = libs.join("libtemporal_rn_sim.a");
+    fs::copy(built(IOS_DEVICE), &device).map_err(|e| format!("failed to copy the device library: {}", e))?;
+    let mut lipo = Command::new("lipo");
+    lipo.arg("-create");
+    for target in IOS_SIMULATORS {
+        lipo.arg(built(target));
+    }
+    run(lipo.arg("-output").arg(&simulator))?;
+
+    println!("\niOS libraries:");
+    report(&device)?;
+    report(&simulator)?;
+
+    if options.xcframework {
+        // xcodebuild copies the whole headers directory, so give it just the header
+        let headers = paths.crate_dir.join("target/xtask/headers");
+        let _ = fs::remove_dir_all(&headers);
+        fs::create_dir_all(&headers).map_err(|e| format!("failed to create {}: {}", headers.display(), e))?;
+        fs::copy(&header, headers.join(HEADER)).map_err(|e| format!("failed to copy the header: {}", e))?;
+        let xcframework = libs.join("TemporalRN.xcframework");
+        let _ = fs::remove_dir_all(&xcframework);
+        let mut command = Command::new("xcodebuild");
+        command.arg("-create-xcframework");
+        for library in [&device, &simulator] {
+            command.arg("-library").arg(library).arg("-headers").arg(&headers);
+        }
+        run(command.arg("-output").arg(&xcframework))?;
+        println!("{}", xcframework.display());
+    }
+    Ok(())
+}
+
+/// ANDROID_NDK_HOME or NDK_HOME, else the newest NDK in the usual SDK locations.
+fn find_ndk() -> Option<PathBuf> {
+    if let Some(home) = env::var_os("ANDROID_NDK_HOME").or_else(|| env::var_os("NDK_HOME")) {
+        return Some(PathBuf::from(home));
+    }
+    let sdks = [
+        env::var_os("ANDROID_HOME").map(PathBuf::from),
+        env::var_os("HOME").map(|home| Path::new(&home).join("Library/Android/sdk")),
+    ];
+    sdks.into_iter().flatten().find_map(|sdk| {
+        let versions = fs::read_dir(sdk.join("ndk")).ok()?;
+        versions.filter_map(|entry| entry.ok().map(|entry| entry.path())).max_by_key(|path| version_key(path))
+    })
+}
+
+/// "26.1.10909125" -> [26, 1, 10909125], so versions sort numerically.
+fn version_key(path: &Path) -> Vec<u64> {
+    let name = path.file_name().unwrap_or_default().to_string_lossy();
+    name.split('.').map(|part| part.parse().unwrap_or(0)).collect()
+}
+
+/// Installs the Rust targets if rustup is available; a missing rustup is left for
+/// cargo to report.
+fn add_rust_targets<'a>(targets: impl Iterator<Item = &'a str>) {
+    let mut command = Command::new("rustup");
+    command.args(["target", "add"]).args(targets);
+    let _ = command.output();
+}
+
+/// Remaps the checkout and cargo home out of embedded paths (panic locations, debug
+/// info), appending to any flags the caller already set.
+fn reproducible<'a>(command: &'a mut Command, paths: &Paths) -> &'a mut Command {
+    let crate_dir = fs::canonicalize(&paths.crate_dir).unwrap_or_else(|_| paths.crate_dir.clone());
+    let cargo_home = env::var_os("CARGO_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".cargo")));
+    let mut flags: Vec<String> = match env::var("CARGO_ENCODED_RUSTFLAGS") {
+        Ok(encoded) => encoded.split('\x1f').filter(|flag| !flag.is_empty()).map(String::from).collect(),
+        Err(_) => env::var("RUSTFLAGS").unwrap_or_default().split_whitespace().map(String::from).collect(),
+    };
+    flags.push(format!("--remap-path-prefix={}=temporal-rn", crate_dir.display()));
+    if let Some(cargo_home) = cargo_home {
+        flags.push(format!("--remap-path-prefix={}=cargo", cargo_home.display()));
+    }
+    command.env_remove("RUSTFLAGS").env("CARGO_ENCODED_RUSTFLAGS", flags.join("\x1f"))
+}
+
+fn cargo() -> String {
+    env::var("CARGO").unwrap_or_else(|_| "cargo".to_string())
+}
+
+fn run(command: &mut Command) -> Result {
+    let program = command.get_program().to_string_lossy().into_owned();
+    let status = command.status().map_err(|e| format!("failed to run {}: {}", program, e))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("{} failed ({})", program, status))
+    }
+}
+
+fn succeeds(command: &mut Command) -> bool {
+    command.output().is_ok_and(|output| output.status.success())
+}
+
+fn report(path: &Path) -> Result {
+    let metadata = fs::metadata(path).map_err(|e| format!("missing output {}: {}", path.display(), e))?;
+    println!("  {} ({:.1} MB)", path.display(), metadata.len() as f64 / 1_000_000.0);
+    Ok(())
+}
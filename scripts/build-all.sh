@@ -1,23 +1,12 @@
 #!/bin/bash
 set -e
 
-SCRIPT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)"
-
-echo "============================================"
-echo "Building temporal-rn for all platforms"
-echo "============================================"
-echo ""
+# Builds the Android libraries, then the iOS ones when running on macOS.
+# Thin wrapper around `cargo xtask build-all`; arguments are passed through,
+# e.g. `--profile release`.
 
-# Build iOS
-echo ">>> Building for iOS..."
-"$SCRIPT_DIR/build-ios.sh"
-echo ""
-
-# Build Android
-echo ">>> Building for Android..."
-"$SCRIPT_DIR/build-android.sh"
-echo ""
+SCRIPT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)"
+RUST_DIR="$(cd "$SCRIPT_DIR/.." && pwd)/rust/temporal-rn"
 
-echo "============================================"
-echo "All builds completed successfully!"
-echo "============================================"
+cd "$RUST_DIR"
+cargo xtask build-all "$@"
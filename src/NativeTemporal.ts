@@ -91,7 +91,7 @@ export interface Spec extends TurboModule {
     day: number,
     calendarId: string | null
   ): string;
-  plainDateGetAllComponents(s: string): number[];
+  plainDateGetAllComponents(s: string): Array<number | null>;
   plainDateGetMonthCode(s: string): string;
   plainDateGetCalendar(s: string): string;
   plainDateAdd(date: string, duration: string): string;
@@ -121,7 +121,7 @@ export interface Spec extends TurboModule {
     nanosecond: number,
     calendarId: string | null
   ): string;
-  plainDateTimeGetAllComponents(s: string): number[];
+  plainDateTimeGetAllComponents(s: string): Array<number | null>;
   plainDateTimeGetMonthCode(s: string): string;
   plainDateTimeGetCalendar(s: string): string;
   plainDateTimeAdd(dt: string, duration: string): string;
@@ -154,8 +154,16 @@ export interface Spec extends TurboModule {
   plainYearMonthGetAllComponents(s: string): number[];
   plainYearMonthGetMonthCode(s: string): string;
   plainYearMonthGetCalendar(s: string): string;
-  plainYearMonthAdd(ym: string, duration: string): string;
-  plainYearMonthSubtract(ym: string, duration: string): string;
+  plainYearMonthAdd(
+    ym: string,
+    duration: string,
+    overflow: string | null
+  ): string;
+  plainYearMonthSubtract(
+    ym: string,
+    duration: string,
+    overflow: string | null
+  ): string;
   plainYearMonthCompare(a: string, b: string): number;
   plainYearMonthWith(
     ym: string,
@@ -169,11 +177,13 @@ export interface Spec extends TurboModule {
 
   // PlainMonthDay methods
   plainMonthDayFromString(s: string): string;
+  /** referenceYear is -2147483648 when there is none; 0 is the ISO year 0. */
   plainMonthDayFromComponents(
     month: number,
     day: number,
     calendarId: string | null,
-    referenceYear: number
+    referenceYear: number,
+    overflow: string | null
   ): string;
   plainMonthDayGetAllComponents(s: string): number[];
   plainMonthDayGetMonthCode(s: string): string;
@@ -217,13 +227,22 @@ export interface Spec extends TurboModule {
     timeZoneId: string,
     offsetNanoseconds: number
   ): string;
-  zonedDateTimeGetAllComponents(s: string): number[];
+  zonedDateTimeGetAllComponents(s: string): Array<number | null>;
   zonedDateTimeEpochMilliseconds(s: string): number;
   zonedDateTimeEpochNanoseconds(s: string): string;
   zonedDateTimeGetCalendar(s: string): string;
   zonedDateTimeGetTimeZone(s: string): string;
   zonedDateTimeGetOffset(s: string): string;
-  zonedDateTimeAdd(zdt: string, duration: string): string;
+  zonedDateTimeGetMonthCode(s: string): string;
+  /** Empty when the calendar has no eras. */
+  zonedDateTimeGetEra(s: string): string;
+  /** Decimal era year, or empty when the calendar has no eras. */
+  zonedDateTimeGetEraYear(s: string): string;
+  zonedDateTimeAdd(
+    zdt: string,
+    duration: string,
+    arithmetic: string | null
+  ): string;
   zonedDateTimeSubtract(zdt: string, duration: string): string;
   zonedDateTimeCompare(a: string, b: string): number;
   zonedDateTimeWith(
@@ -241,8 +260,22 @@ export interface Spec extends TurboModule {
     calendarId: string | null,
     timeZoneId: string | null
   ): string;
-  zonedDateTimeUntil(one: string, two: string): string;
-  zonedDateTimeSince(one: string, two: string): string;
+  zonedDateTimeUntil(
+    one: string,
+    two: string,
+    largestUnit: string | null,
+    smallestUnit: string | null,
+    roundingIncrement: number,
+    roundingMode: string | null
+  ): string;
+  zonedDateTimeSince(
+    one: string,
+    two: string,
+    largestUnit: string | null,
+    smallestUnit: string | null,
+    roundingIncrement: number,
+    roundingMode: string | null
+  ): string;
   zonedDateTimeRound(
     zdt: string,
     smallestUnit: string,
@@ -287,7 +320,11 @@ export interface Spec extends TurboModule {
   /**
    * Gets all component values from a duration string in a single call.
    * Returns array: [years, months, weeks, days, hours, minutes, seconds,
-   *                 milliseconds, microseconds, nanoseconds, sign, blank]
+   *                 milliseconds, microseconds, nanoseconds, sign, blank,
+   *                 microsecondsHigh, microsecondsMid, microsecondsLow,
+   *                 nanosecondsHigh, nanosecondsMid, nanosecondsLow, clamped]
+   * The full microseconds/nanoseconds are high * 2^64 + mid * 2^32 + low, with
+   * mid and low unsigned 32-bit words so every slot is exact as a double.
    * Throws RangeError for invalid duration, TypeError for null input.
    */
   durationGetAllComponents(durationStr: string): number[];
@@ -0,0 +1,24 @@
+import { Duration } from '../types/Duration';
+
+describe('Duration', () => {
+  it('should parse ISO string', () => {
+    const duration = Duration.from('PT1.000002003S');
+    expect(duration.seconds).toBe(1);
+    expect(duration.microseconds).toBe(2);
+    expect(duration.nanoseconds).toBe(3);
+  });
+
+  it('should keep nanoseconds above 2^53 intact', () => {
+    // 2^63 - 1024 is exact as a double; three of them overflow int64
+    const part = Duration.from({ nanoseconds: 2 ** 63 - 1024 });
+    const sum = part.add(part).add(part);
+    expect(sum.nanoseconds).toBe(Number(3n * (2n ** 63n - 1024n)));
+    expect(sum.nanoseconds).toBeGreaterThan(2 ** 64);
+  });
+
+  it('should keep negative microseconds above 2^53 intact', () => {
+    const part = Duration.from({ microseconds: -(2 ** 62) });
+    const sum = part.add(part).add(part).add({ microseconds: -3 });
+    expect(sum.microseconds).toBe(Number(-(3n * 2n ** 62n) - 3n));
+  });
+});
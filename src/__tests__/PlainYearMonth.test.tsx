@@ -25,6 +25,13 @@ describe('PlainYearMonth', () => {
     expect(subtracted.toString()).toBe('2024-02');
   });
 
+  it('should constrain by default when the month does not exist', () => {
+    // Adar I only exists in hebrew leap years
+    const adarI = PlainYearMonth.from('2024-02-10[u-ca=hebrew]');
+    expect(adarI.add('P1Y').monthCode).toBe('M06');
+    expect(() => adarI.add('P1Y', { overflow: 'reject' })).toThrow(RangeError);
+  });
+
   it('should compare', () => {
     const ym1 = PlainYearMonth.from('2024-03');
     const ym2 = PlainYearMonth.from('2024-04');
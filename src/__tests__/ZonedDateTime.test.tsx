@@ -15,6 +15,27 @@ describe('ZonedDateTime', () => {
     expect(added.hour).toBe(11);
   });
 
+  it('should default until/since to hours', () => {
+    const start = ZonedDateTime.from('2024-01-01T00:00:00+00:00[UTC]');
+    const end = ZonedDateTime.from('2024-01-03T06:00:00+00:00[UTC]');
+    expect(start.until(end).toString()).toBe('PT54H');
+    expect(start.until(end, { largestUnit: 'day' }).toString()).toBe('P2DT6H');
+    expect(end.since(start, { smallestUnit: 'day' }).toString()).toBe('P2D');
+  });
+
+  it('should expose era, era year and month code', () => {
+    const zdt = ZonedDateTime.from(
+      '2024-01-15T10:30:00+09:00[Asia/Tokyo][u-ca=japanese]'
+    );
+    expect(zdt.era).toBe('reiwa');
+    expect(zdt.eraYear).toBe(6);
+    expect(zdt.monthCode).toBe('M01');
+
+    const iso = ZonedDateTime.from('2024-01-15T10:30:00+01:00[Europe/Paris]');
+    expect(iso.era).toBeUndefined();
+    expect(iso.eraYear).toBeUndefined();
+  });
+
   it('should convert to PlainDateTime', () => {
     const zdt = ZonedDateTime.from('2024-01-15T10:30:00+01:00[Europe/Paris]');
     const pdt = zdt.toPlainDateTime();
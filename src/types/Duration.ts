@@ -23,6 +23,15 @@ const enum ComponentIndex {
   Nanoseconds = 9,
   Sign = 10,
   Blank = 11,
+  // Full 128-bit microseconds/nanoseconds as high * 2^64 + mid * 2^32 + low,
+  // and a flag set when the Microseconds/Nanoseconds slots had to be clamped.
+  MicrosecondsHigh = 12,
+  MicrosecondsMid = 13,
+  MicrosecondsLow = 14,
+  NanosecondsHigh = 15,
+  NanosecondsMid = 16,
+  NanosecondsLow = 17,
+  Clamped = 18,
 }
 
 export type DurationLike = {
@@ -209,13 +218,29 @@ export class Duration {
   }
 
   get microseconds(): number {
+    if (this.#components[ComponentIndex.Clamped] === 1) {
+      return Number(this.#wide(ComponentIndex.MicrosecondsHigh));
+    }
     return this.#components[ComponentIndex.Microseconds]!;
   }
 
   get nanoseconds(): number {
+    if (this.#components[ComponentIndex.Clamped] === 1) {
+      return Number(this.#wide(ComponentIndex.NanosecondsHigh));
+    }
     return this.#components[ComponentIndex.Nanoseconds]!;
   }
 
+  /**
+   * Reassembles a 128-bit component from its high, mid and low words.
+   */
+  #wide(highIndex: ComponentIndex): bigint {
+    const high = BigInt(this.#components[highIndex]!);
+    const mid = BigInt(this.#components[highIndex + 1]!);
+    const low = BigInt(this.#components[highIndex + 2]!);
+    return (high << 64n) + (mid << 32n) + low;
+  }
+
   /**
    * Returns the sign of the duration: -1 for negative, 0 for zero, 1 for positive.
    */
@@ -23,15 +23,20 @@ const enum ComponentIndex {
   DaysInYear = 9,
   MonthsInYear = 10,
   InLeapYear = 11,
+  Quarter = 12,
+  DayOfQuarter = 13,
 }
 
 export class PlainDate {
   readonly #isoString: string;
-  readonly #components: number[];
+  readonly #components: Array<number | null>;
   #monthCode: string | undefined;
   #calendarId: string | undefined;
 
-  private constructor(isoString: string, components: number[]) {
+  private constructor(
+    isoString: string,
+    components: Array<number | null>
+  ) {
     this.#isoString = isoString;
     this.#components = components;
   }
@@ -122,11 +127,11 @@ export class PlainDate {
   get dayOfYear(): number {
     return this.#components[ComponentIndex.DayOfYear]!;
   }
-  get weekOfYear(): number {
-    return this.#components[ComponentIndex.WeekOfYear]!;
+  get weekOfYear(): number | undefined {
+    return this.#components[ComponentIndex.WeekOfYear] ?? undefined;
   }
-  get yearOfWeek(): number {
-    return this.#components[ComponentIndex.YearOfWeek]!;
+  get yearOfWeek(): number | undefined {
+    return this.#components[ComponentIndex.YearOfWeek] ?? undefined;
   }
   get daysInWeek(): number {
     return this.#components[ComponentIndex.DaysInWeek]!;
@@ -143,6 +148,12 @@ export class PlainDate {
   get inLeapYear(): boolean {
     return this.#components[ComponentIndex.InLeapYear]! === 1;
   }
+  get quarter(): number {
+    return this.#components[ComponentIndex.Quarter]!;
+  }
+  get dayOfQuarter(): number {
+    return this.#components[ComponentIndex.DayOfQuarter]!;
+  }
 
   add(duration: Duration | DurationLike | string): PlainDate {
     const d = duration instanceof Duration ? duration : Duration.from(duration);
@@ -37,15 +37,20 @@ const enum ComponentIndex {
   Millisecond = 15,
   Microsecond = 16,
   Nanosecond = 17,
+  Quarter = 18,
+  DayOfQuarter = 19,
 }
 
 export class PlainDateTime {
   readonly #isoString: string;
-  readonly #components: number[];
+  readonly #components: Array<number | null>;
   #monthCode: string | undefined;
   #calendarId: string | undefined;
 
-  private constructor(isoString: string, components: number[]) {
+  private constructor(
+    isoString: string,
+    components: Array<number | null>
+  ) {
     this.#isoString = isoString;
     this.#components = components;
   }
@@ -100,6 +105,10 @@ export class PlainDateTime {
     );
   }
 
+  /**
+   * Compares two PlainDateTime objects with nanosecond resolution. The calendar
+   * is ignored, so equal ISO fields in different calendars compare as 0.
+   */
   static compare(
     one: PlainDateTime | string | PlainDateTimeLike,
     two: PlainDateTime | string | PlainDateTimeLike
@@ -164,11 +173,11 @@ export class PlainDateTime {
   get dayOfYear(): number {
     return this.#components[ComponentIndex.DayOfYear]!;
   }
-  get weekOfYear(): number {
-    return this.#components[ComponentIndex.WeekOfYear]!;
+  get weekOfYear(): number | undefined {
+    return this.#components[ComponentIndex.WeekOfYear] ?? undefined;
   }
-  get yearOfWeek(): number {
-    return this.#components[ComponentIndex.YearOfWeek]!;
+  get yearOfWeek(): number | undefined {
+    return this.#components[ComponentIndex.YearOfWeek] ?? undefined;
   }
   get daysInWeek(): number {
     return this.#components[ComponentIndex.DaysInWeek]!;
@@ -185,6 +194,12 @@ export class PlainDateTime {
   get inLeapYear(): boolean {
     return this.#components[ComponentIndex.InLeapYear]! === 1;
   }
+  get quarter(): number {
+    return this.#components[ComponentIndex.Quarter]!;
+  }
+  get dayOfQuarter(): number {
+    return this.#components[ComponentIndex.DayOfQuarter]!;
+  }
 
   add(duration: Duration | DurationLike | string): PlainDateTime {
     const d = duration instanceof Duration ? duration : Duration.from(duration);
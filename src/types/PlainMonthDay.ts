@@ -6,9 +6,17 @@ export type PlainMonthDayLike = {
   monthCode?: string;
   day?: number;
   month?: number;
+  year?: number;
   calendar?: string;
 };
 
+export type PlainMonthDayOptions = {
+  overflow?: 'constrain' | 'reject';
+};
+
+// Matches TEMPORAL_NO_REFERENCE_YEAR; year 0 is a valid reference year
+const NO_REFERENCE_YEAR = -2147483648;
+
 const enum ComponentIndex {
   Month = 0,
   Day = 1,
@@ -25,7 +33,10 @@ export class PlainMonthDay {
     this.#components = components;
   }
 
-  static from(item: string | PlainMonthDayLike | PlainMonthDay): PlainMonthDay {
+  static from(
+    item: string | PlainMonthDayLike | PlainMonthDay,
+    options?: PlainMonthDayOptions
+  ): PlainMonthDay {
     if (item instanceof PlainMonthDay) return item;
 
     if (typeof item === 'string') {
@@ -50,7 +61,8 @@ export class PlainMonthDay {
             item.month ?? 0,
             item.day ?? 0,
             item.calendar ?? null,
-            0 // referenceYear
+            item.year ?? NO_REFERENCE_YEAR,
+            options?.overflow ?? null
           ),
         'Invalid plain month day components'
       );
@@ -90,7 +90,8 @@ export class PlainTime {
   }
 
   /**
-   * Compares two PlainTime objects.
+   * Compares two PlainTime objects with nanosecond resolution: times that differ
+   * only in their last fractional digit are ordered, never equal.
    */
   static compare(
     one: PlainTime | string | PlainTimeLike,
@@ -10,6 +10,10 @@ export type PlainYearMonthLike = {
   calendar?: string;
 };
 
+export type PlainYearMonthArithmeticOptions = {
+  overflow?: 'constrain' | 'reject';
+};
+
 const enum ComponentIndex {
   Year = 0,
   Month = 1,
@@ -134,10 +138,18 @@ export class PlainYearMonth {
     return val === 0 ? undefined : val;
   }
 
-  add(duration: Duration | DurationLike | string): PlainYearMonth {
+  add(
+    duration: Duration | DurationLike | string,
+    options?: PlainYearMonthArithmeticOptions
+  ): PlainYearMonth {
     const d = duration instanceof Duration ? duration : Duration.from(duration);
     const isoString = wrapNativeCall(
-      () => NativeTemporal.plainYearMonthAdd(this.#isoString, d.toString()),
+      () =>
+        NativeTemporal.plainYearMonthAdd(
+          this.#isoString,
+          d.toString(),
+          options?.overflow ?? null
+        ),
       'Failed to add duration'
     );
     const components = wrapNativeCall(
@@ -147,11 +159,18 @@ export class PlainYearMonth {
     return new PlainYearMonth(isoString, components);
   }
 
-  subtract(duration: Duration | DurationLike | string): PlainYearMonth {
+  subtract(
+    duration: Duration | DurationLike | string,
+    options?: PlainYearMonthArithmeticOptions
+  ): PlainYearMonth {
     const d = duration instanceof Duration ? duration : Duration.from(duration);
     const isoString = wrapNativeCall(
       () =>
-        NativeTemporal.plainYearMonthSubtract(this.#isoString, d.toString()),
+        NativeTemporal.plainYearMonthSubtract(
+          this.#isoString,
+          d.toString(),
+          options?.overflow ?? null
+        ),
       'Failed to subtract duration'
     );
     const components = wrapNativeCall(
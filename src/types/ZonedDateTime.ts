@@ -8,6 +8,23 @@ import { PlainDate } from './PlainDate';
 import { PlainTime } from './PlainTime';
 import { PlainDateTime } from './PlainDateTime';
 
+export type ZonedDateTimeAddOptions = {
+  /**
+   * 'calendar' (default) adds days in wall-clock time, so P1D keeps the local
+   * time across a DST change. 'exact' treats each day as 24 hours of elapsed
+   * time, like PT24H; years, months and weeks are rejected.
+   */
+  arithmetic?: 'calendar' | 'exact';
+};
+
+export type ZonedDateTimeDifferenceOptions = {
+  /** Defaults to 'hour', so days are never assumed to be 24 hours long. */
+  largestUnit?: string;
+  smallestUnit?: string;
+  roundingIncrement?: number;
+  roundingMode?: string;
+};
+
 export class ZonedDateTime {
   readonly #iso: string;
   readonly #calendar: Calendar;
@@ -49,6 +66,16 @@ export class ZonedDateTime {
   get month(): number {
     return this.#getComponent(1);
   }
+  get monthCode(): string {
+    return NativeTemporal.zonedDateTimeGetMonthCode(this.#iso);
+  }
+  get era(): string | undefined {
+    return NativeTemporal.zonedDateTimeGetEra(this.#iso) || undefined;
+  }
+  get eraYear(): number | undefined {
+    const eraYear = NativeTemporal.zonedDateTimeGetEraYear(this.#iso);
+    return eraYear === '' ? undefined : Number(eraYear);
+  }
   get day(): number {
     return this.#getComponent(2);
   }
@@ -58,11 +85,11 @@ export class ZonedDateTime {
   get dayOfYear(): number {
     return this.#getComponent(4);
   }
-  get weekOfYear(): number {
-    return this.#getComponent(5);
+  get weekOfYear(): number | undefined {
+    return this.#getComponents()[5] ?? undefined;
   }
-  get yearOfWeek(): number {
-    return this.#getComponent(6);
+  get yearOfWeek(): number | undefined {
+    return this.#getComponents()[6] ?? undefined;
   }
   get daysInWeek(): number {
     return this.#getComponent(7);
@@ -143,20 +170,31 @@ export class ZonedDateTime {
     return this.#timeZone.id;
   }
 
-  #components: number[] | null = null;
-  #getComponent(index: number): number {
+  #components: Array<number | null> | null = null;
+  #getComponents(): Array<number | null> {
     if (!this.#components) {
       this.#components = NativeTemporal.zonedDateTimeGetAllComponents(
         this.#iso
       );
     }
-    return this.#components[index]!;
+    return this.#components;
+  }
+  #getComponent(index: number): number {
+    return this.#getComponents()[index]!;
   }
 
-  add(duration: Duration | string | object): ZonedDateTime {
+  add(
+    duration: Duration | string | object,
+    options?: ZonedDateTimeAddOptions
+  ): ZonedDateTime {
     const d = Duration.from(duration);
     const newIso = wrapNativeCall(
-      () => NativeTemporal.zonedDateTimeAdd(this.#iso, d.toString()),
+      () =>
+        NativeTemporal.zonedDateTimeAdd(
+          this.#iso,
+          d.toString(),
+          options?.arithmetic ?? null
+        ),
       'Add failed'
     );
     return this.#clone(newIso);
@@ -171,17 +209,39 @@ export class ZonedDateTime {
     return this.#clone(newIso);
   }
 
-  until(other: ZonedDateTime, _options?: any): Duration {
+  until(
+    other: ZonedDateTime,
+    options?: ZonedDateTimeDifferenceOptions
+  ): Duration {
     const durStr = wrapNativeCall(
-      () => NativeTemporal.zonedDateTimeUntil(this.#iso, other.toString()),
+      () =>
+        NativeTemporal.zonedDateTimeUntil(
+          this.#iso,
+          other.toString(),
+          options?.largestUnit ?? null,
+          options?.smallestUnit ?? null,
+          options?.roundingIncrement ?? 1,
+          options?.roundingMode ?? null
+        ),
       'Until failed'
     );
     return Duration.from(durStr);
   }
 
-  since(other: ZonedDateTime, _options?: any): Duration {
+  since(
+    other: ZonedDateTime,
+    options?: ZonedDateTimeDifferenceOptions
+  ): Duration {
     const durStr = wrapNativeCall(
-      () => NativeTemporal.zonedDateTimeSince(this.#iso, other.toString()),
+      () =>
+        NativeTemporal.zonedDateTimeSince(
+          this.#iso,
+          other.toString(),
+          options?.largestUnit ?? null,
+          options?.smallestUnit ?? null,
+          options?.roundingIncrement ?? 1,
+          options?.roundingMode ?? null
+        ),
       'Since failed'
     );
     return Duration.from(durStr);